@@ -423,8 +423,7 @@ impl Not for APInt {
     type Output = APInt;
 
     fn not(self) -> Self {
-        let Self { value: [v0, v1, v2, v3], bits } = self;
-        Self { value: [!v0, !v1, !v2, !v3], bits }
+        Self::new(!self.value_raw(), self.bits)
     }
 }
 
@@ -0,0 +1,62 @@
+//! rig-gen: 把 RIG 指令描述编译成 Rust 代码.
+//!
+//! 用法: `rig-gen <input.rig> [-o <output.rs>]`. 不指定输出文件时写到标准输出.
+
+use remusys_ir::rig::compile_rig;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: rig-gen <input.rig> [-o <output.rs>]";
+
+fn main() -> ExitCode {
+    let mut input = None;
+    let mut output = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => match args.next() {
+                Some(path) => output = Some(path),
+                None => return usage_error("missing file name after `-o`"),
+            },
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return usage_error(&format!("unexpected argument `{arg}`")),
+        }
+    }
+    let Some(input) = input else {
+        return usage_error("missing input file");
+    };
+
+    let source = match std::fs::read_to_string(&input) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: cannot read `{input}`: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let code = match compile_rig(&source, &input) {
+        Ok(code) => code,
+        Err(errors) => {
+            eprint!("{}", errors.render(&input, &source));
+            eprintln!("error: {errors}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match output {
+        Some(path) => {
+            if let Err(err) = std::fs::write(&path, code) {
+                eprintln!("error: cannot write `{path}`: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{code}"),
+    }
+    ExitCode::SUCCESS
+}
+
+fn usage_error(msg: &str) -> ExitCode {
+    eprintln!("error: {msg}\n{USAGE}");
+    ExitCode::FAILURE
+}
@@ -82,11 +82,13 @@ pub use self::{
         UserList,
     },
     utils::{
+        block_clone::clone_blocks,
         builder::{
             FocusDegradeConfig, FocusDegradeOp, IRBuildError, IRBuildRes, IRBuilder, IRFocus,
             IRFullFocus, InstIDSummary, InstInsertPos, TermiBuildRes,
         },
         func_clone::{FuncClone, FuncCloneErr, FuncCloneMapping},
+        inst_clone::{clone_inst, remap_inst_operands},
         llvm_adapt::LLVMAdaptMapping,
         module_clone::ModuleClone,
         serialize::{
@@ -97,6 +99,7 @@ pub use self::{
             FuncNumberMap, IRNameMap, IRSourcePos, IRSourceRange, NumberOption, SourceMapWriter,
             SourceRangeMap,
         },
+        ssa_updater::SSAUpdater,
     },
 };
 
@@ -289,6 +289,11 @@ impl AttrSet {
         self.bits.set(AttrSetBits::NO_UNDEF, val);
     }
 
+    /// `alignstack` 要求的栈对齐的 log2. 没有设置时是 3.
+    pub fn get_func_align_stack(&self) -> u8 {
+        self.func_align
+    }
+
     const INTEXT_SHIFT: u32 = 32;
     pub fn get_int_ext(&self) -> Option<IntExtAttr> {
         let bits = (self.bits & AttrSetBits::MASK_INT_EXT).bits() >> Self::INTEXT_SHIFT;
@@ -545,10 +545,22 @@ impl<'ir> SanityCheckCtx<'ir> {
             InstObj::Call(call) => self.inst_sane_callop(call),
             InstObj::Cast(cast) => self.inst_sane_cast(inst_id, cast),
             InstObj::Cmp(cmp) => self.inst_sane_cmp(inst_id, cmp),
-            InstObj::IndexExtract(_)
-            | InstObj::FieldExtract(_)
-            | InstObj::IndexInsert(_)
-            | InstObj::FieldInsert(_) => todo!("How to implement?"),
+            InstObj::IndexExtract(extract) => {
+                self.use_type_match(extract.aggr_use(), extract.aggr_type.into_ir())?;
+                self.use_typeclass_match(extract.index_use(), ValTypeClass::Int)
+            }
+            InstObj::FieldExtract(extract) => {
+                self.use_type_match(extract.aggr_use(), extract.aggr_type.into_ir())
+            }
+            InstObj::IndexInsert(insert) => {
+                self.use_type_match(insert.aggr_use(), insert.get_valtype())?;
+                self.use_type_match(insert.elem_use(), insert.elem_type)?;
+                self.use_typeclass_match(insert.index_use(), ValTypeClass::Int)
+            }
+            InstObj::FieldInsert(insert) => {
+                self.use_type_match(insert.aggr_use(), insert.get_valtype())?;
+                self.use_type_match(insert.elem_use(), insert.elem_type)
+            }
             InstObj::Phi(phi) => self.inst_sane_phi(inst_id, phi),
             InstObj::Select(select) => {
                 let valty = select.get_valtype();
@@ -718,7 +730,7 @@ impl<'ir> SanityCheckCtx<'ir> {
             };
             phi_has_incoming.insert(from_bb, false);
         }
-        for [ublk, uval] in &*phi.incoming_uses() {
+        for [uval, ublk] in &*phi.incoming_uses() {
             let ublk_op = ublk.get_operand(allocs);
             let ValueSSA::Block(bb) = ublk_op else {
                 let ublk_kind = ublk.get_kind(allocs);
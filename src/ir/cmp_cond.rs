@@ -3,7 +3,7 @@ use std::str::FromStr;
 
 bitflags! {
     /// IR 比较条件.
-    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
     pub struct CmpCond: u8 {
         const LT = 0b00_001;
         const EQ = 0b00_010;
@@ -126,6 +126,24 @@ impl CmpCond {
         self
     }
 
+    /// 逻辑取反: `!(a cond b)` 等价于 `a cond.invert() b`.
+    /// 浮点比较取反时, 有序 (ordered) 与无序 (unordered) 也会互换.
+    pub fn invert(self) -> Self {
+        let flipped = self ^ Self::ALWAYS;
+        if self.is_float() { flipped ^ Self::SIGNED_ORDERED } else { flipped }
+    }
+    /// 交换操作数: `a cond b` 等价于 `b cond.swap_operands() a`.
+    pub fn swap_operands(self) -> Self {
+        let mut ret = self - (Self::LT | Self::GT);
+        if self.contains(Self::LT) {
+            ret |= Self::GT;
+        }
+        if self.contains(Self::GT) {
+            ret |= Self::LT;
+        }
+        ret
+    }
+
     pub fn as_str(self) -> &'static str {
         #[rustfmt::skip]
         return match self {
@@ -471,7 +471,11 @@ impl FuncBuilder {
         let args = {
             let mut v = Vec::with_capacity(self.arg_types.len());
             for (i, &ty) in self.arg_types.iter().enumerate() {
-                v.push(FuncArg::new(allocs, ty, i as u32));
+                let arg = FuncArg::new(allocs, ty, i as u32);
+                if let Some(attrs) = self.arg_attrs.get(i) {
+                    *arg.attrs.borrow_mut() = attrs.clone();
+                }
+                v.push(arg);
             }
             v.into_boxed_slice()
         };
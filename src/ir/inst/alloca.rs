@@ -10,8 +10,8 @@ use crate::{
 /// 在栈上分配一段固定大小的内存. 这个指令的特殊之处在于, 该指令分配得到的内存
 /// 在函数内全局有效、全局存活, 直到函数返回或被销毁.
 ///
-/// 想要分配动态大小的栈内存或者控制分配内存的生命周期, 请使用 `DynAlloca` 指令.
-/// 只不过时间实在来不及了, `DynAlloca` 在此版本中不实现.
+/// 操作码为 `DynAlloca` 时, 每次执行都在栈上分配一段新的内存, 直到函数返回才一起释放.
+/// 这样的指令可以放在循环里, 机器层面需要在运行时移动栈指针.
 ///
 /// * 操作数布局: 没有操作数.
 ///
@@ -19,6 +19,7 @@ use crate::{
 ///
 /// ```llvm
 /// %<result> = alloca <pointee_ty>, align <alignment>
+/// %<result> = dyn-alloca <pointee_ty>, align <alignment>
 /// ```
 pub struct AllocaInst {
     pub common: InstCommon,
@@ -84,12 +85,28 @@ impl ISubInst for AllocaInst {
 impl AllocaInst {
     /// 创建一个新的 Alloca 指令, 分配指定类型的内存.
     pub fn new(pointee_ty: ValTypeID, align_log2: u8) -> Self {
+        Self::with_opcode(Opcode::Alloca, pointee_ty, align_log2)
+    }
+    /// 创建一个 `DynAlloca` 指令, 每次执行都分配新的内存.
+    pub fn new_dyn(pointee_ty: ValTypeID, align_log2: u8) -> Self {
+        Self::with_opcode(Opcode::DynAlloca, pointee_ty, align_log2)
+    }
+    /// `opcode` 只能是 `Alloca` 或者 `DynAlloca`.
+    pub fn with_opcode(opcode: Opcode, pointee_ty: ValTypeID, align_log2: u8) -> Self {
+        assert!(
+            matches!(opcode, Opcode::Alloca | Opcode::DynAlloca),
+            "opcode {opcode:?} is not an alloca"
+        );
         Self {
-            common: InstCommon::new(Opcode::Alloca, ValTypeID::Ptr),
+            common: InstCommon::new(opcode, ValTypeID::Ptr),
             pointee_ty,
             align_log2,
         }
     }
+
+    pub fn is_dynamic(&self) -> bool {
+        self.get_opcode() == Opcode::DynAlloca
+    }
 }
 
 _remusys_ir_subinst!(AllocaInstID, AllocaInst, section = Body);
@@ -97,6 +114,24 @@ impl AllocaInstID {
     pub fn new(allocs: &IRAllocs, pointee_ty: ValTypeID, align_log2: u8) -> Self {
         Self::allocate(allocs, AllocaInst::new(pointee_ty, align_log2))
     }
+    pub fn new_dyn(allocs: &IRAllocs, pointee_ty: ValTypeID, align_log2: u8) -> Self {
+        Self::allocate(allocs, AllocaInst::new_dyn(pointee_ty, align_log2))
+    }
+    pub fn with_opcode(
+        allocs: &IRAllocs,
+        opcode: Opcode,
+        pointee_ty: ValTypeID,
+        align_log2: u8,
+    ) -> Self {
+        Self::allocate(
+            allocs,
+            AllocaInst::with_opcode(opcode, pointee_ty, align_log2),
+        )
+    }
+
+    pub fn is_dynamic(self, allocs: &IRAllocs) -> bool {
+        self.deref_ir(allocs).is_dynamic()
+    }
 
     pub fn get_pointee_ty(self, allocs: &IRAllocs) -> ValTypeID {
         self.deref_ir(allocs).pointee_ty
@@ -1,13 +1,17 @@
 use crate::{
     _remusys_ir_subinst,
     ir::{
-        BlockSection, IFuncUniqueUser, IPtrUniqueUser, IRAllocs, ISubInst, ISubInstID, IUser,
-        InstCommon, InstObj, JumpTargets, Opcode, OperandSet, UseID, UseKind, ValueSSA,
+        AttrSet, AttributePos, BlockSection, IFuncUniqueUser, IPtrUniqueUser, IRAllocs, ISubInst,
+        ISubInstID, IUser, InstCommon, InstObj, JumpTargets, Opcode, OperandSet, UseID, UseKind,
+        ValueSSA,
     },
     typing::{FuncTypeID, IValType, TypeContext, ValTypeID},
 };
 use smallvec::{SmallVec, smallvec};
-use std::{cell::Cell, ops::RangeFrom};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    ops::RangeFrom,
+};
 
 /// 函数调用指令
 ///
@@ -30,8 +34,10 @@ use std::{cell::Cell, ops::RangeFrom};
 /// - `callee`: 被调用的函数（全局函数引用）
 /// - `arg0..argN`: 传递给函数的参数
 ///
-/// - `callee`: 被调用函数在此次调用时附加的额外属性
-/// - `arg0..argN`: 本次调用每个实参的额外属性
+/// ## 调用点属性
+///
+/// - `ret_attrs`: 被调用函数在此次调用时对返回值附加的额外属性
+/// - `arg_attrs`: 本次调用每个实参的额外属性, 与 `arg0..argN` 一一对应
 pub struct CallInst {
     pub common: InstCommon,
     pub operands: SmallVec<[UseID; 4]>,
@@ -39,6 +45,8 @@ pub struct CallInst {
     pub fixed_nargs: u32,
     pub is_vararg: bool,
     pub is_tail_call: Cell<bool>,
+    pub ret_attrs: RefCell<AttrSet>,
+    pub arg_attrs: Box<[RefCell<AttrSet>]>,
 }
 
 impl IUser for CallInst {
@@ -125,6 +133,19 @@ impl CallInst {
     pub fn set_arg(&self, allocs: &IRAllocs, index: usize, arg: ValueSSA) {
         self.arg_uses()[index].set_operand(allocs, arg);
     }
+
+    pub fn ret_attrs(&self) -> Ref<'_, AttrSet> {
+        self.ret_attrs.borrow()
+    }
+    pub fn ret_attrs_mut(&self) -> RefMut<'_, AttrSet> {
+        self.ret_attrs.borrow_mut()
+    }
+    pub fn arg_attrs(&self, index: usize) -> Ref<'_, AttrSet> {
+        self.arg_attrs[index].borrow()
+    }
+    pub fn arg_attrs_mut(&self, index: usize) -> RefMut<'_, AttrSet> {
+        self.arg_attrs[index].borrow_mut()
+    }
 }
 
 _remusys_ir_subinst!(CallInstID, CallInst, section = Body);
@@ -183,6 +204,8 @@ pub struct CallInstBuilder {
     args: SmallVec<[ValueSSA; 4]>,
     is_tail_call: bool,
     builder_uninit: bool,
+    ret_attrs: AttrSet,
+    arg_attrs: SmallVec<[AttrSet; 4]>,
 }
 impl CallInstBuilder {
     pub fn new(tctx: &TypeContext, callee_ty: FuncTypeID) -> Self {
@@ -199,6 +222,8 @@ impl CallInstBuilder {
             args: SmallVec::new(),
             is_tail_call: false,
             builder_uninit: false,
+            ret_attrs: AttrSet::new(AttributePos::CALLARG),
+            arg_attrs: SmallVec::new(),
         }
     }
     pub fn resize_nargs(&mut self, new_nargs: u32) -> Option<&mut Self> {
@@ -237,6 +262,20 @@ impl CallInstBuilder {
         self.builder_uninit = require;
         self
     }
+    pub fn ret_attrs(&mut self, attrs: AttrSet) -> &mut Self {
+        self.ret_attrs = attrs;
+        self.ret_attrs.set_pos(AttributePos::CALLARG);
+        self
+    }
+    pub fn set_arg_attrs(&mut self, index: usize, attrs: AttrSet) -> &mut Self {
+        if self.arg_attrs.len() <= index {
+            self.arg_attrs
+                .resize(index + 1, AttrSet::new(AttributePos::CALLARG));
+        }
+        self.arg_attrs[index] = attrs;
+        self.arg_attrs[index].set_pos(AttributePos::CALLARG);
+        self
+    }
 
     pub fn build_obj(&mut self, allocs: &IRAllocs) -> CallInst {
         let nargs = if self.args.is_empty() { self.fixed_nargs as usize } else { self.args.len() };
@@ -249,6 +288,12 @@ impl CallInstBuilder {
             }
             ops
         };
+        let arg_attrs = (0..nargs)
+            .map(|i| {
+                let attrs = self.arg_attrs.get(i).cloned();
+                RefCell::new(attrs.unwrap_or_else(|| AttrSet::new(AttributePos::CALLARG)))
+            })
+            .collect();
         let ret = CallInst {
             common: InstCommon::new(Opcode::Call, self.ret_ty),
             operands,
@@ -256,6 +301,8 @@ impl CallInstBuilder {
             fixed_nargs: self.fixed_nargs,
             is_vararg: self.is_vararg,
             is_tail_call: Cell::new(self.is_tail_call),
+            ret_attrs: RefCell::new(self.ret_attrs.clone()),
+            arg_attrs,
         };
         if self.callee != ValueSSA::None {
             ret.set_callee(allocs, self.callee);
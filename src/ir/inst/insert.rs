@@ -1,8 +1,8 @@
 use crate::{
     _remusys_ir_subinst,
     ir::{
-        BlockSection, IRAllocs, ISubInst, ISubInstID, ITraceableValue, IUser, InstCommon, InstObj,
-        JumpTargets, Opcode, OperandSet, UseID, UseKind, ValueSSA,
+        BlockSection, IRAllocs, ISubInst, ISubInstID, ISubValueSSA, ITraceableValue, IUser,
+        InstCommon, InstObj, JumpTargets, Opcode, OperandSet, UseID, UseKind, ValueSSA,
         inst::{
             AggrFieldInstBuilderCommon, IAggrFieldInst, IAggrFieldInstBuildable, IAggrIndexInst,
             IAggregateInst,
@@ -108,7 +108,7 @@ impl IAggrIndexInst for IndexInsertInst {
         Self {
             common: InstCommon::new(Opcode::IndexInsert, aggr_type.into_ir()),
             operands: [
-                UseID::new(allocs, UseKind::IndexExtractAggr),
+                UseID::new(allocs, UseKind::IndexInsertAggr),
                 UseID::new(allocs, UseKind::IndexInsertElem),
                 UseID::new(allocs, UseKind::IndexInsertIndex),
             ],
@@ -139,6 +139,22 @@ impl IndexInsertInstID {
         Self::allocate(allocs, inst)
     }
 
+    pub fn new(
+        allocs: &IRAllocs,
+        tctx: &TypeContext,
+        aggr: ValueSSA,
+        elem: ValueSSA,
+        index: ValueSSA,
+    ) -> Self {
+        let aggr_ty = aggr.get_valtype(allocs);
+        let aggr_ty = AggrType::try_from_ir(aggr_ty).unwrap();
+        let inst = Self::new_uninit(allocs, tctx, aggr_ty);
+        inst.set_aggr(allocs, aggr);
+        inst.set_elem(allocs, elem);
+        inst.set_index(allocs, index);
+        inst
+    }
+
     pub fn aggr_use(self, allocs: &IRAllocs) -> UseID {
         self.deref_ir(allocs).aggr_use()
     }
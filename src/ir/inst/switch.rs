@@ -1,5 +1,6 @@
 use crate::{
     _remusys_ir_subinst,
+    base::APInt,
     ir::{
         BlockID, BlockSection, IRAllocs, ISubInst, ISubInstID, ISubValueSSA, ITerminatorInst,
         IUser, InstCommon, InstObj, JumpTargetID, JumpTargetKind, JumpTargets, Opcode, OperandSet,
@@ -182,6 +183,14 @@ impl SwitchInst {
         self.find_case_jt(allocs, case_val)
             .and_then(|jt| jt.get_block(allocs))
     }
+    /// 判别值为常量 `discrim` 时实际跳往的基本块. case 值会先按判别值的位宽
+    /// 截断或符号扩展, 再与 `discrim` 比较.
+    pub fn find_const_case_or_default(&self, allocs: &IRAllocs, discrim: APInt) -> Option<BlockID> {
+        self.cases_iter(allocs)
+            .find(|&(_, val, _)| APInt::new(val, discrim.bits()) == discrim)
+            .and_then(|(_, _, bb)| bb)
+            .or_else(|| self.get_default_bb(allocs))
+    }
     pub fn find_or_insert_case(&self, allocs: &IRAllocs, case_val: i64) -> JumpTargetID {
         if let Some(jt) = self.find_case_jt(allocs, case_val) {
             jt
@@ -303,6 +312,10 @@ impl SwitchInstID {
         self.find_case(allocs, case_val)
             .or_else(|| self.get_default_bb(allocs))
     }
+    pub fn find_const_case_or_default(self, allocs: &IRAllocs, discrim: APInt) -> Option<BlockID> {
+        self.deref_ir(allocs)
+            .find_const_case_or_default(allocs, discrim)
+    }
     pub fn find_set_case(self, allocs: &IRAllocs, case_val: i64, bb: BlockID) -> JumpTargetID {
         self.deref_ir(allocs).find_set_case(allocs, case_val, bb)
     }
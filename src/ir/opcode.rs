@@ -1,7 +1,7 @@
 use std::{collections::HashMap, fmt::Display, str::FromStr, sync::OnceLock};
 
 #[rustfmt::skip]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 /// Enum representing the various opcodes in the Remusys IR.
 pub enum Opcode {
     None,
@@ -48,7 +48,8 @@ impl Opcode {
         #[rustfmt::skip]
         return matches!(
             self,
-            BitAnd | BitOr | BitXor | Add | Sub | Mul | Sdiv | Udiv
+            BitAnd | BitOr | BitXor | Shl | Lshr | Ashr
+            | Add | Sub | Mul | Sdiv | Udiv
             | Srem | Urem | Fadd | Fsub | Fmul | Fdiv | Frem
         );
     }
@@ -1,6 +1,9 @@
+pub mod block_clone;
 pub mod builder;
 pub mod func_clone;
+pub mod inst_clone;
 pub mod llvm_adapt;
 pub mod module_clone;
 pub mod serialize;
 pub mod source_map;
+pub mod ssa_updater;
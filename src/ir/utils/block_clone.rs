@@ -0,0 +1,92 @@
+//! 函数内部基本块的复制.
+
+use crate::ir::{inst::*, *};
+use smallvec::SmallVec;
+
+/// 在同一个函数内复制一组基本块, 新块按 `blocks` 的顺序插入到 `insert_before` 之前.
+///
+/// 新块中所有引用了被复制的块或指令的地方 (操作数、跳转目标以及 Phi 的传入块) 都会改为引用对应的新值,
+/// 其他值保持不变. 因此从复制区域外部流入的 Phi 传入值、以及离开复制区域的跳转需要由调用者自己修复.
+pub fn clone_blocks(
+    module: &Module,
+    blocks: &[BlockID],
+    insert_before: BlockID,
+) -> FuncCloneMapping {
+    let (allocs, tctx) = (&module.allocs, &module.tctx);
+    let func = insert_before
+        .get_parent_func(allocs)
+        .expect("Internal error: insert position is not attached to a function");
+    let mut mapping = FuncCloneMapping::new_local(func);
+    for &block in blocks {
+        mapping.blocks.insert(block, BlockID::new_uninit(allocs));
+    }
+
+    let mut builder = IRBuilder::new(module);
+    let mut new_insts: Vec<InstID> = Vec::new();
+    for &old_bb in blocks {
+        let new_bb = mapping.blocks[&old_bb];
+        let terminator = clone_terminator(allocs, old_bb.get_terminator(allocs), &mapping);
+        new_bb.set_terminator_inst(allocs, terminator);
+        func.blocks_unwrap(allocs)
+            .node_add_prev(insert_before, new_bb, &allocs.blocks)
+            .expect("Internal error: failed to insert the cloned block");
+        new_insts.push(terminator);
+        mapping
+            .insts
+            .insert(old_bb.get_terminator_inst(allocs), terminator);
+
+        builder.set_focus(IRFocus::Block(new_bb));
+        for (old_inst, obj) in old_bb.insts_iter(allocs) {
+            if matches!(obj.get_block_section(), BlockSection::Terminator)
+                || matches!(obj, InstObj::GuideNode(_) | InstObj::PhiInstEnd(_))
+            {
+                continue;
+            }
+            let new_inst = clone_inst(allocs, tctx, old_inst);
+            builder
+                .insert_inst(new_inst)
+                .expect("Internal error: failed to insert cloned instruction");
+            mapping.insts.insert(old_inst, new_inst);
+            new_insts.push(new_inst);
+        }
+    }
+    // 所有指令都复制完之后才能重映射操作数, 因为 Phi 可能引用后面的块中定义的值
+    for inst in new_insts {
+        remap_inst_operands(allocs, inst, |v| Some(mapping.map_or_keep(v)));
+    }
+    mapping
+}
+
+fn clone_terminator(allocs: &IRAllocs, term: TerminatorID, mapping: &FuncCloneMapping) -> InstID {
+    let map_bb = |bb: Option<BlockID>| {
+        let bb = bb.expect("Internal error: terminator has an empty jump target");
+        mapping.map_block(bb)
+    };
+    match term {
+        TerminatorID::Unreachable(_) => UnreachableInstID::new(allocs).raw_into(),
+        TerminatorID::Ret(ret) => {
+            let new_ret = RetInstID::new_uninit(allocs, ret.get_rettype(allocs));
+            new_ret
+                .retval_use(allocs)
+                .set_operand(allocs, ret.get_retval(allocs));
+            new_ret.raw_into()
+        }
+        TerminatorID::Jump(jump) => {
+            JumpInstID::with_target(allocs, map_bb(jump.get_target(allocs))).raw_into()
+        }
+        TerminatorID::Br(br) => {
+            let then_bb = map_bb(br.get_then(allocs));
+            let else_bb = map_bb(br.get_else(allocs));
+            BrInstID::new(allocs, br.get_cond(allocs), then_bb, else_bb).raw_into()
+        }
+        TerminatorID::Switch(switch) => {
+            let cases: SmallVec<[(i64, BlockID); 8]> = switch
+                .cases_iter(allocs)
+                .map(|(jt, val, _)| (val, map_bb(jt.get_block(allocs))))
+                .collect();
+            let default_bb = map_bb(switch.get_default_bb(allocs));
+            SwitchInstID::from_cases(allocs, switch.get_discrim(allocs), cases, default_bb)
+                .raw_into()
+        }
+    }
+}
@@ -2,7 +2,8 @@ use crate::{
     ir::{
         BlockID, FuncBuilder, FuncID, GlobalID, GlobalVar, GlobalVarBuilder, GlobalVarID,
         IGlobalVarBuildable, IRAllocs, ISubInstID, ISubValueSSA, ITraceableValue, InstID, InstObj,
-        ManagedInst, Module, PoolAllocatedDisposeErr, TerminatorID, UseID, UseKind, ValueSSA,
+        JumpTargetID, ManagedInst, Module, PoolAllocatedDisposeErr, TerminatorID, UseID, UseKind,
+        ValueSSA,
         inst::{BrInstID, JumpInstID, PhiInstID, SwitchInstID, UnreachableInstID},
     },
     typing::{ArchInfo, FuncTypeID, TypeContext, ValTypeID},
@@ -139,6 +140,8 @@ pub enum IRBuildError {
 
     #[error("Block has no terminator: {0:?}")]
     BlockHasNoTerminator(BlockID),
+    #[error("Jump target is not attached to a terminator or a block: {0:?}")]
+    JumpTargetDetached(JumpTargetID),
     #[error("Instruction is terminator: %inst{0:p}")]
     InstIsTerminator(InstID),
     #[error("Instruction is guide node: %inst{0:p}")]
@@ -540,6 +543,55 @@ impl<ModuleT: AsRef<Module>> IRBuilder<ModuleT> {
         }
         Ok(back_half)
     }
+
+    /// 在跳转目标 `jt` 表示的 CFG 边上插入一个新基本块. 与焦点无关, 也不会修改焦点.
+    ///
+    /// `jt` 可以属于 `jump`、`br` 或 `switch` 的任意一个分支. 新块插入到边的起点之后,
+    /// 只包含一条跳转到原目标的 `jump`; `jt` 改为指向新块. 原目标中 Phi 来自起点的传入值会转移到新块上,
+    /// 如果起点还有其他边指向原目标, 则同时保留起点的传入值.
+    ///
+    /// ### Return
+    ///
+    /// - **Success branch**: 新创建的基本块 ID.
+    /// - **Error branch**: `jt` 没有连接到终结指令或目标基本块.
+    pub fn split_edge(&mut self, jt: JumpTargetID) -> IRBuildRes<BlockID> {
+        let allocs = self.allocs();
+        let (Some(term), Some(to)) = (jt.get_terminator(allocs), jt.get_block(allocs)) else {
+            return Err(IRBuildError::JumpTargetDetached(jt));
+        };
+        let from = term
+            .get_parent(allocs)
+            .ok_or(IRBuildError::JumpTargetDetached(jt))?;
+        let func = from
+            .get_parent_func(allocs)
+            .ok_or(IRBuildError::JumpTargetDetached(jt))?;
+
+        let new_bb = BlockID::new_with_terminator(allocs, JumpInstID::with_target(allocs, to));
+        func.blocks_unwrap(allocs)
+            .node_add_next(from, new_bb, &allocs.blocks)?;
+        jt.set_block(allocs, new_bb);
+
+        let from_still_jumps = from
+            .get_succs(allocs)
+            .iter()
+            .any(|jt| jt.get_block(allocs) == Some(to));
+        for (_, inst) in to.insts_iter(allocs) {
+            let phi = match inst {
+                InstObj::Phi(phi) => phi,
+                InstObj::PhiInstEnd(_) => break,
+                _ => continue,
+            };
+            let value = if from_still_jumps {
+                phi.find_incoming_value(allocs, from)
+            } else {
+                phi.remove_incoming(allocs, from)
+            };
+            if let Some(value) = value {
+                phi.set_incoming(allocs, new_bb, value);
+            }
+        }
+        Ok(new_bb)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
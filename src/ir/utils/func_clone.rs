@@ -3,7 +3,7 @@ use smol_str::SmolStr;
 
 use crate::{
     SymbolStr,
-    ir::{inst::*, *},
+    ir::{inst::*, utils::inst_clone::clone_inst, *},
 };
 use std::{collections::HashMap, rc::Rc};
 
@@ -44,6 +44,17 @@ pub struct FuncCloneMapping {
     pub keep_recurse: bool,
 }
 impl FuncCloneMapping {
+    /// 在同一个函数内部复制基本块时使用的空映射.
+    pub fn new_local(func: FuncID) -> Self {
+        Self {
+            insts: HashMap::new(),
+            blocks: HashMap::new(),
+            old_func: func,
+            new_func: func,
+            keep_recurse: false,
+        }
+    }
+
     /// Map a value from the old function to the new function.
     /// Unmapped instructions and blocks will return `None`, while other unmapped values will return themselves.
     /// For global values, if `keep_recurse` is true and the value is the old function,
@@ -73,6 +84,13 @@ impl FuncCloneMapping {
             old => Some(old),
         }
     }
+    /// 与 `map_get` 相同, 但没有映射的指令和基本块会原样返回.
+    pub fn map_or_keep(&self, old: ValueSSA) -> ValueSSA {
+        self.map_get(old).unwrap_or(old)
+    }
+    pub fn map_block(&self, old: BlockID) -> BlockID {
+        self.blocks.get(&old).copied().unwrap_or(old)
+    }
 }
 
 pub struct FuncClone<'ir> {
@@ -439,147 +457,6 @@ impl<'ir> Inner<'ir> {
                 let opcode = oinst.get_opcode();
                 unreachable!("internal error: inst {opcode:?} mentioned obove should be handled")
             }
-            InstObj::Alloca(alloca) => {
-                AllocaInstID::new(allocs, alloca.pointee_ty, alloca.align_log2).raw_into()
-            }
-            InstObj::GEP(gepinst) => {
-                let id = GEPInstID::new_uninit(
-                    allocs,
-                    gepinst.initial_ty,
-                    gepinst.final_ty,
-                    gepinst.index_uses().len(),
-                    gepinst.align_log2,
-                    gepinst.pointee_align_log2,
-                );
-                let oldops = gepinst.operands_iter();
-                let newops = id.deref_ir(allocs).operands_iter();
-                for (olduid, newuid) in oldops.zip(newops) {
-                    self.use_setval(newuid, olduid.get_operand(allocs));
-                }
-                id.raw_into()
-            }
-            InstObj::Load(load) => {
-                let load_inst = LoadInstID::new_uninit(allocs, load.get_valtype(), load.align_log2);
-                let old_source = load.get_source(allocs);
-                self.use_setval(load_inst.source_use(allocs), old_source);
-                load_inst.raw_into()
-            }
-            InstObj::Store(store) => {
-                let store_inst = StoreInstID::new_uninit(allocs, store.source_ty, store.align_log2);
-                let old_src = store.get_source(allocs);
-                let old_dst = store.get_target(allocs);
-                self.use_setval(store_inst.source_use(allocs), old_src);
-                self.use_setval(store_inst.target_use(allocs), old_dst);
-                store_inst.raw_into()
-            }
-            InstObj::AmoRmw(amormw) => {
-                let amormw_inst = AmoRmwInst::builder(amormw.get_opcode(), amormw.value_ty)
-                    .align_log2(amormw.align_log2)
-                    .is_volatile(amormw.is_volatile)
-                    .ordering(amormw.ordering)
-                    .scope(amormw.scope)
-                    .build_id(allocs);
-                let old_ptr = amormw.pointer_use().get_operand(allocs);
-                let old_val = amormw.value_use().get_operand(allocs);
-                self.use_setval(amormw_inst.pointer_use(allocs), old_ptr);
-                self.use_setval(amormw_inst.value_use(allocs), old_val);
-                amormw_inst.raw_into()
-            }
-            InstObj::BinOP(binop) => {
-                let opcode = binop.get_opcode();
-                let flags = binop.get_flags();
-                let new_binop = BinOPInstID::new_uninit(allocs, opcode, binop.get_valtype());
-                new_binop.set_flags(allocs, flags);
-                let old_lhs = binop.get_lhs(allocs);
-                let old_rhs = binop.get_rhs(allocs);
-                self.use_setval(new_binop.lhs_use(allocs), old_lhs);
-                self.use_setval(new_binop.rhs_use(allocs), old_rhs);
-                new_binop.raw_into()
-            }
-            InstObj::Call(call) => {
-                let mut call_builder = CallInst::builder(tctx, call.callee_ty);
-                call_builder
-                    .is_tail_call(call.is_tail_call.get())
-                    .resize_nargs(call.arg_uses().len() as u32)
-                    .expect(
-                        "internal error: failed to resize call instruction when cloning function",
-                    );
-                let old_callee = call.get_callee(allocs);
-                let call_inst = call_builder
-                    .is_tail_call(call.is_tail_call.get())
-                    .builder_uninit(true)
-                    .build_id(allocs);
-                self.use_setval(call_inst.callee_use(allocs), old_callee);
-
-                let call = CallInstID::raw_from(oinst_id).deref_ir(allocs);
-                for (idx, old_arg_use) in call.arg_uses().iter().enumerate() {
-                    let old_arg = old_arg_use.get_operand(allocs);
-                    let new_arg_use = call_inst.arg_uses(allocs)[idx];
-                    self.use_setval(new_arg_use, old_arg);
-                }
-                call_inst.raw_into()
-            }
-            InstObj::Cast(cast) => {
-                let cast_inst = CastInstID::new_uninit(
-                    allocs,
-                    cast.get_opcode(),
-                    cast.from_ty,
-                    cast.get_valtype(),
-                );
-                let old_from = cast.get_from(allocs);
-                self.use_setval(cast_inst.from_use(allocs), old_from);
-                cast_inst.raw_into()
-            }
-            InstObj::Cmp(cmp) => {
-                let cmp_inst =
-                    CmpInstID::new_uninit(allocs, cmp.get_opcode(), cmp.cond, cmp.operand_ty);
-                let old_lhs = cmp.get_lhs(allocs);
-                let old_rhs = cmp.get_rhs(allocs);
-                self.use_setval(cmp_inst.lhs_use(allocs), old_lhs);
-                self.use_setval(cmp_inst.rhs_use(allocs), old_rhs);
-                cmp_inst.raw_into()
-            }
-            InstObj::IndexExtract(index_extract) => {
-                let extract_inst =
-                    IndexExtractInstID::new_uninit(allocs, tctx, index_extract.aggr_type);
-                let old_aggr = index_extract.get_aggr(allocs);
-                let old_index = index_extract.get_index(allocs);
-                self.use_setval(extract_inst.aggr_use(allocs), old_aggr);
-                self.use_setval(extract_inst.index_use(allocs), old_index);
-                extract_inst.raw_into()
-            }
-            InstObj::FieldExtract(field_extract) => {
-                let extract_inst = FieldExtractInstID::builder(field_extract.aggr_type)
-                    .reserve_steps(field_extract.fields.len())
-                    .add_steps(tctx, field_extract.fields.iter().cloned())
-                    .build_id(allocs);
-                let old_aggr = field_extract.get_aggr(allocs);
-                self.use_setval(extract_inst.aggr_use(allocs), old_aggr);
-                extract_inst.raw_into()
-            }
-            InstObj::IndexInsert(index_insert) => {
-                let aggr_type = index_insert.get_aggr_operand_type();
-                let insert_inst = IndexInsertInstID::new_uninit(allocs, tctx, aggr_type);
-                let old_aggr = index_insert.get_aggr(allocs);
-                let old_index = index_insert.get_index(allocs);
-                let old_elem = index_insert.get_elem(allocs);
-                self.use_setval(insert_inst.aggr_use(allocs), old_aggr);
-                self.use_setval(insert_inst.index_use(allocs), old_index);
-                self.use_setval(insert_inst.elem_use(allocs), old_elem);
-                insert_inst.raw_into()
-            }
-            InstObj::FieldInsert(field_insert) => {
-                let aggr_type = field_insert.get_aggr_operand_type();
-                let insert_inst = FieldInsertInstID::builder(aggr_type)
-                    .reserve_steps(field_insert.fields.len())
-                    .add_steps(tctx, field_insert.fields.iter().cloned())
-                    .build_id(allocs);
-                let old_aggr = field_insert.get_aggr(allocs);
-                let old_elem = field_insert.get_elem(allocs);
-                self.use_setval(insert_inst.aggr_use(allocs), old_aggr);
-                self.use_setval(insert_inst.elem_use(allocs), old_elem);
-                insert_inst.raw_into()
-            }
             InstObj::Phi(phi) => {
                 let mut builder = PhiInst::builder(allocs, phi.get_valtype());
                 builder.allow_uninit(true);
@@ -607,15 +484,12 @@ impl<'ir> Inner<'ir> {
                 }
                 new_phi.raw_into()
             }
-            InstObj::Select(select) => {
-                let select_inst = SelectInstID::new_uninit(allocs, select.get_valtype());
-                let old_cond = select.get_cond(allocs);
-                let old_then = select.get_then(allocs);
-                let old_else = select.get_else(allocs);
-                self.use_setval(select_inst.cond_use(allocs), old_cond);
-                self.use_setval(select_inst.then_use(allocs), old_then);
-                self.use_setval(select_inst.else_use(allocs), old_else);
-                select_inst.raw_into()
+            _ => {
+                let inst = clone_inst(allocs, tctx, oinst_id);
+                for new_use in inst.deref_ir(allocs).operands_iter() {
+                    self.use_setval(new_use, new_use.get_operand(allocs));
+                }
+                inst
             }
         };
         Ok(Some(inst))
@@ -0,0 +1,135 @@
+//! 单条指令的复制与操作数重映射.
+
+use crate::{
+    ir::{inst::*, *},
+    typing::TypeContext,
+};
+
+/// 复制一条非终结指令. 新指令的操作数与原指令完全相同, 并且不会被插入到任何基本块中.
+///
+/// Phi 指令会连同传入块一起复制. 终结指令和引导结点不能用这个函数复制.
+pub fn clone_inst(allocs: &IRAllocs, tctx: &TypeContext, inst_id: InstID) -> InstID {
+    let inst = inst_id.deref_ir(allocs);
+    let new_inst: InstID = match inst {
+        InstObj::PhiInstEnd(_)
+        | InstObj::Unreachable(_)
+        | InstObj::Ret(_)
+        | InstObj::Jump(_)
+        | InstObj::Br(_)
+        | InstObj::Switch(_)
+        | InstObj::GuideNode(_) => {
+            let opcode = inst.get_opcode();
+            panic!("clone_inst: cannot clone terminator or guide node {opcode:?}")
+        }
+        InstObj::Alloca(alloca) => {
+            let opcode = alloca.get_opcode();
+            AllocaInstID::with_opcode(allocs, opcode, alloca.pointee_ty, alloca.align_log2)
+                .raw_into()
+        }
+        InstObj::GEP(gep) => {
+            let new_gep = GEPInstID::new_uninit(
+                allocs,
+                gep.initial_ty,
+                gep.final_ty,
+                gep.index_uses().len(),
+                gep.align_log2,
+                gep.pointee_align_log2,
+            );
+            new_gep.deref_ir(allocs).set_inbounds(gep.get_inbounds());
+            new_gep.raw_into()
+        }
+        InstObj::Load(load) => {
+            LoadInstID::new_uninit(allocs, load.get_valtype(), load.align_log2).raw_into()
+        }
+        InstObj::Store(store) => {
+            StoreInstID::new_uninit(allocs, store.source_ty, store.align_log2).raw_into()
+        }
+        InstObj::AmoRmw(amormw) => AmoRmwInst::builder(amormw.get_opcode(), amormw.value_ty)
+            .align_log2(amormw.align_log2)
+            .is_volatile(amormw.is_volatile)
+            .ordering(amormw.ordering)
+            .scope(amormw.scope)
+            .build_id(allocs)
+            .raw_into(),
+        InstObj::BinOP(binop) => {
+            let new_binop =
+                BinOPInstID::new_uninit(allocs, binop.get_opcode(), binop.get_valtype());
+            new_binop.set_flags(allocs, binop.get_flags());
+            new_binop.raw_into()
+        }
+        InstObj::Call(call) => {
+            let mut call_builder = CallInst::builder(tctx, call.callee_ty);
+            call_builder
+                .is_tail_call(call.is_tail_call.get())
+                .ret_attrs(call.ret_attrs().clone())
+                .resize_nargs(call.arg_uses().len() as u32)
+                .expect("internal error: failed to resize call instruction when cloning");
+            for i in 0..call.arg_uses().len() {
+                call_builder.set_arg_attrs(i, call.arg_attrs(i).clone());
+            }
+            call_builder
+                .builder_uninit(true)
+                .build_id(allocs)
+                .raw_into()
+        }
+        InstObj::Cast(cast) => {
+            CastInstID::new_uninit(allocs, cast.get_opcode(), cast.from_ty, cast.get_valtype())
+                .raw_into()
+        }
+        InstObj::Cmp(cmp) => {
+            CmpInstID::new_uninit(allocs, cmp.get_opcode(), cmp.cond, cmp.operand_ty).raw_into()
+        }
+        InstObj::IndexExtract(index_extract) => {
+            IndexExtractInstID::new_uninit(allocs, tctx, index_extract.aggr_type).raw_into()
+        }
+        InstObj::FieldExtract(field_extract) => {
+            FieldExtractInstID::builder(field_extract.aggr_type)
+                .reserve_steps(field_extract.fields.len())
+                .add_steps(tctx, field_extract.fields.iter().cloned())
+                .build_id(allocs)
+                .raw_into()
+        }
+        InstObj::IndexInsert(index_insert) => {
+            let aggr_type = index_insert.get_aggr_operand_type();
+            IndexInsertInstID::new_uninit(allocs, tctx, aggr_type).raw_into()
+        }
+        InstObj::FieldInsert(field_insert) => {
+            FieldInsertInstID::builder(field_insert.get_aggr_operand_type())
+                .reserve_steps(field_insert.fields.len())
+                .add_steps(tctx, field_insert.fields.iter().cloned())
+                .build_id(allocs)
+                .raw_into()
+        }
+        InstObj::Phi(phi) => {
+            let mut builder = PhiInst::builder(allocs, phi.get_valtype());
+            for &[uval, ublk] in &*phi.incoming_uses() {
+                let ValueSSA::Block(block) = ublk.get_operand(allocs) else {
+                    panic!("internal error: expect phi incoming block");
+                };
+                builder.add_incoming(block, uval.get_operand(allocs));
+            }
+            return builder.build_id().raw_into();
+        }
+        InstObj::Select(select) => {
+            SelectInstID::new_uninit(allocs, select.get_valtype()).raw_into()
+        }
+    };
+    let new_obj = new_inst.deref_ir(allocs);
+    for (old_use, new_use) in inst.operands_iter().zip(new_obj.operands_iter()) {
+        new_use.set_operand(allocs, old_use.get_operand(allocs));
+    }
+    new_inst
+}
+
+/// 按 `map` 重写指令的所有操作数 (包括 Phi 的传入块). `map` 返回 `None` 的操作数保持不变.
+pub fn remap_inst_operands(
+    allocs: &IRAllocs,
+    inst: InstID,
+    mut map: impl FnMut(ValueSSA) -> Option<ValueSSA>,
+) {
+    for use_id in inst.deref_ir(allocs).operands_iter() {
+        if let Some(new_val) = map(use_id.get_operand(allocs)) {
+            use_id.set_operand(allocs, new_val);
+        }
+    }
+}
@@ -406,7 +406,9 @@ impl<'old> ModuleClone<'old> {
                 InstObj::Switch(inst) => self.clone_switch(inst),
                 InstObj::Alloca(inst) => {
                     let pointee_ty = self.clone_type(inst.pointee_ty);
-                    AllocaInstID::new(new_allocs, pointee_ty, inst.align_log2).raw_into()
+                    let opcode = inst.get_opcode();
+                    AllocaInstID::with_opcode(new_allocs, opcode, pointee_ty, inst.align_log2)
+                        .raw_into()
                 }
                 InstObj::GEP(inst) => self.clone_gep_inst(inst),
                 InstObj::Load(inst) => self.clone_load_inst(inst),
@@ -608,8 +610,12 @@ impl<'old> ModuleClone<'old> {
         let new_tctx = &self.new_module.tctx;
         let mut call_builder = CallInst::builder(new_tctx, callee_ty);
         call_builder.resize_nargs(old_inst.arg_uses().len() as u32);
+        for i in 0..old_inst.arg_uses().len() {
+            call_builder.set_arg_attrs(i, self.clone_attr(&old_inst.arg_attrs(i)));
+        }
         let call_inst = call_builder
             .is_tail_call(old_inst.is_tail_call.get())
+            .ret_attrs(self.clone_attr(&old_inst.ret_attrs()))
             .builder_uninit(true)
             .build_id(new_allocs);
 
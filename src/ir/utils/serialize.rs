@@ -882,7 +882,8 @@ impl IRSerializeInst for AllocaInst {
     }
 
     fn serialize_ir<W: Write>(&self, ctx: &mut FmtCtx<'_, '_, '_, W>) -> IRWriteRes {
-        ctx.write_str("alloca ")?;
+        ctx.write_str(self.get_opcode().get_name())?;
+        ctx.write_str(" ")?;
         ctx.fmt_type(self.pointee_ty)?;
         ctx.write_str(" ")?;
         write!(ctx, ", align {}", self.get_ptr_pointee_align())?;
@@ -1001,10 +1002,12 @@ impl IRSerializeInst for CallInst {
 
     fn serialize_ir<W: Write>(&self, ctx: &mut FmtCtx<'_, '_, '_, W>) -> IRWriteRes {
         let ret_ty = ctx.type_name(self.get_valtype());
+        ctx.write_str("call ")?;
+        ctx.fmt_attrs(&self.ret_attrs())?;
         if self.is_vararg {
-            write!(ctx, "call {ret_ty} (...) ")?;
+            write!(ctx, "{ret_ty} (...) ")?;
         } else {
-            write!(ctx, "call {ret_ty} ")?;
+            write!(ctx, "{ret_ty} ")?;
         }
         ctx.fmt_use(self.callee_use())?;
         ctx.write_str("(")?;
@@ -1024,6 +1027,7 @@ impl IRSerializeInst for CallInst {
                 .unwrap_or(arg.get_valtype(allocs));
             let arg_ty = ctx.type_name(arg_ty);
             write!(ctx, "{arg_ty} ")?;
+            ctx.fmt_attrs(&self.arg_attrs(i))?;
             ctx.fmt_use(arg_use)?;
         }
         ctx.write_str(")")
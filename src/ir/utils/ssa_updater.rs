@@ -0,0 +1,318 @@
+//! 一个值有多个定义时的 SSA 重建.
+//!
+//! 复制基本块之后, 原来的值和它的副本分别在不同的块中定义, 汇合点需要插入 Phi 才能重新满足 SSA 形式.
+//! [`SSAUpdater`] 记录每个块末尾可用的定义, 按需在这些块的迭代支配边界上插入 Phi,
+//! 再把各个使用改写为到达该位置的定义.
+
+use crate::{
+    ir::{
+        BlockID, ConstData, IRAllocs, ISubInstID, ITraceableValue, InstObj, UseID, UseKind, UserID,
+        ValueSSA, inst::PhiInstID,
+    },
+    opt::{CfgBlockStat, DominanceFrontier},
+    typing::ValTypeID,
+};
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+
+/// SSA 重建器. 每个实例只负责一个值.
+///
+/// 使用方法: 先用 [`SSAUpdater::add_def`] 登记每个块末尾可用的定义, 再用 [`SSAUpdater::rewrite_use`]
+/// (或 [`SSAUpdater::value_at_entry`] 等) 改写需要修复的使用, 最后调用 [`SSAUpdater::finish`] 删除平凡 Phi.
+/// Phi 只在真正被用到的迭代支配边界块中创建.
+///
+/// `df` 必须是在 CFG 修改完成之后计算的. 与定义位于同一个块的普通使用被认为在定义之后,
+/// 直接使用该块的定义.
+pub struct SSAUpdater<'ir> {
+    allocs: &'ir IRAllocs,
+    df: &'ir DominanceFrontier<'ir>,
+    valty: ValTypeID,
+    defs: HashMap<BlockID, ValueSSA>,
+    /// 定义块的迭代支配边界, 第一次查询时计算.
+    phi_blocks: Option<HashSet<BlockID>>,
+    phis: HashMap<BlockID, PhiInstID>,
+}
+
+impl<'ir> SSAUpdater<'ir> {
+    pub fn new(allocs: &'ir IRAllocs, df: &'ir DominanceFrontier<'ir>, valty: ValTypeID) -> Self {
+        Self {
+            allocs,
+            df,
+            valty,
+            defs: HashMap::new(),
+            phi_blocks: None,
+            phis: HashMap::new(),
+        }
+    }
+
+    /// 登记 `block` 末尾可用的定义. 所有定义必须在第一次查询之前登记.
+    pub fn add_def(&mut self, block: BlockID, value: ValueSSA) {
+        assert!(
+            self.phi_blocks.is_none(),
+            "SSAUpdater: definitions must be added before any query"
+        );
+        self.defs.insert(block, value);
+    }
+    pub fn has_def(&self, block: BlockID) -> bool {
+        self.defs.contains_key(&block)
+    }
+
+    /// 迄今为止插入的 Phi.
+    pub fn inserted_phis(&self) -> impl Iterator<Item = PhiInstID> + '_ {
+        self.phis.values().copied()
+    }
+
+    /// `block` 末尾可用的值.
+    pub fn value_at_end(&mut self, block: BlockID) -> ValueSSA {
+        match self.defs.get(&block) {
+            Some(&value) => value,
+            None => self.value_at_entry(block),
+        }
+    }
+
+    /// `block` 入口处可用的值. 必要时在 `block` 中插入 Phi.
+    pub fn value_at_entry(&mut self, block: BlockID) -> ValueSSA {
+        if let Some(&phi) = self.phis.get(&block) {
+            return ValueSSA::Inst(phi.raw_into());
+        }
+        let dom_tree = self.df.dom_tree;
+        let Some(dfn) = dom_tree.dfs.try_block_dfn(block) else {
+            // 不可达的块
+            return self.undef();
+        };
+        if self.idf_blocks().contains(&block) {
+            return self.insert_phi(block);
+        }
+        match dom_tree.nodes[dfn].idom {
+            CfgBlockStat::Block(idom) if dfn != 0 => self.value_at_end(idom),
+            _ => self.undef(),
+        }
+    }
+
+    /// 在 `use_id` 处可用的值. Phi 的传入值在对应前驱块的末尾使用.
+    pub fn value_for_use(&mut self, use_id: UseID) -> ValueSSA {
+        let allocs = self.allocs;
+        let Some(UserID::Inst(user)) = use_id.get_user(allocs) else {
+            panic!("SSAUpdater: use {use_id:?} is not used by an instruction");
+        };
+        if let (UseKind::PhiIncomingValue(idx), InstObj::Phi(phi)) =
+            (use_id.get_kind(allocs), user.deref_ir(allocs))
+        {
+            let [_, block_use] = phi.incoming_uses()[idx as usize];
+            let ValueSSA::Block(pred) = block_use.get_operand(allocs) else {
+                panic!("SSAUpdater: phi incoming block is not a block");
+            };
+            return self.value_at_end(pred);
+        }
+        let block = user
+            .get_parent(allocs)
+            .expect("SSAUpdater: user instruction is not attached to a block");
+        self.value_at_end(block)
+    }
+
+    /// 把 `use_id` 改写为在该处可用的值.
+    pub fn rewrite_use(&mut self, use_id: UseID) {
+        let value = self.value_for_use(use_id);
+        use_id.set_operand(self.allocs, value);
+    }
+
+    /// 结束重建, 删除插入的平凡 Phi (除自身外只有一个传入值). 返回删除的 Phi 数量.
+    ///
+    /// 查询得到的值必须已经写回 IR 中, 否则被删除的 Phi 不会被替换掉.
+    pub fn finish(self) -> usize {
+        let allocs = self.allocs;
+        let mut phis: Vec<PhiInstID> = self.phis.into_values().collect();
+        let mut num_removed = 0;
+        loop {
+            let before = phis.len();
+            phis.retain(|&phi| {
+                let Some(value) = trivial_phi_value(allocs, phi, self.valty) else {
+                    return true;
+                };
+                let inst = phi.raw_into();
+                phi.deref_ir(allocs)
+                    .replace_self_with(allocs, value)
+                    .expect("SSAUpdater: failed to replace trivial phi");
+                let parent = inst.get_parent(allocs).unwrap();
+                parent
+                    .get_insts(allocs)
+                    .node_unplug(inst, &allocs.insts)
+                    .expect("SSAUpdater: failed to remove trivial phi");
+                inst.dispose(allocs).unwrap();
+                false
+            });
+            num_removed += before - phis.len();
+            if phis.len() == before {
+                break num_removed;
+            }
+        }
+    }
+
+    fn undef(&self) -> ValueSSA {
+        ValueSSA::ConstData(ConstData::Undef(self.valty))
+    }
+
+    fn idf_blocks(&mut self) -> &HashSet<BlockID> {
+        if self.phi_blocks.is_none() {
+            let dfs = &self.df.dom_tree.dfs;
+            let mut worklist: SmallVec<[usize; 16]> = self
+                .defs
+                .keys()
+                .filter_map(|&block| dfs.try_block_dfn(block))
+                .collect();
+            let mut phi_dfns = HashSet::new();
+            while let Some(dfn) = worklist.pop() {
+                for &frontier in &self.df.df[dfn] {
+                    if phi_dfns.insert(frontier) {
+                        worklist.push(frontier);
+                    }
+                }
+            }
+            let blocks = phi_dfns
+                .into_iter()
+                .filter_map(|dfn| match dfs.dfn_block(dfn) {
+                    CfgBlockStat::Block(block) => Some(block),
+                    CfgBlockStat::Virtual => None,
+                })
+                .collect();
+            self.phi_blocks = Some(blocks);
+        }
+        self.phi_blocks.as_ref().unwrap()
+    }
+
+    fn insert_phi(&mut self, block: BlockID) -> ValueSSA {
+        let allocs = self.allocs;
+        let phi = PhiInstID::new_empty(allocs, self.valty);
+        block
+            .get_insts(allocs)
+            .node_add_prev(block.get_phi_end(allocs), phi.raw_into(), &allocs.insts)
+            .expect("SSAUpdater: failed to insert phi");
+        // 先登记再填写传入值, 这样环路上的查询会找到这条 Phi
+        self.phis.insert(block, phi);
+        let preds: SmallVec<[BlockID; 4]> = self
+            .df
+            .cfg
+            .pred_of(block)
+            .unwrap_or(&[])
+            .iter()
+            .copied()
+            .collect();
+        for pred in preds {
+            let value = self.value_at_end(pred);
+            phi.set_incoming(allocs, pred, value);
+        }
+        ValueSSA::Inst(phi.raw_into())
+    }
+}
+
+/// 平凡 Phi 的唯一传入值. 没有其他传入值时是 undef.
+fn trivial_phi_value(allocs: &IRAllocs, phi: PhiInstID, valty: ValTypeID) -> Option<ValueSSA> {
+    let this = ValueSSA::Inst(phi.raw_into());
+    let mut unique = None;
+    for [value_use, _] in phi.deref_ir(allocs).incoming_uses().iter() {
+        let value = value_use.get_operand(allocs);
+        if value == this || unique == Some(value) {
+            continue;
+        }
+        if unique.is_some() {
+            return None;
+        }
+        unique = Some(value);
+    }
+    Some(unique.unwrap_or(ValueSSA::ConstData(ConstData::Undef(valty))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::APInt,
+        ir::{FuncID, ISubGlobalID, IUser, checking::assert_module_sane},
+        opt::DominatorTree,
+        testing::cases::test_case_jump_thread,
+    };
+
+    /// `main` 的块依次是 entry, L, R, M, X, Y, Z. L 和 R 中各定义了一个值.
+    fn main_blocks(module: &crate::ir::Module) -> (FuncID, Vec<BlockID>) {
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        let blocks = func.blocks_iter(&module.allocs).map(|(b, _)| b).collect();
+        (func, blocks)
+    }
+    fn first_inst(allocs: &IRAllocs, block: BlockID) -> ValueSSA {
+        let (inst, _) = block
+            .insts_iter(allocs)
+            .find(|(_, inst)| !matches!(inst, InstObj::Phi(_) | InstObj::PhiInstEnd(_)))
+            .unwrap();
+        ValueSSA::Inst(inst)
+    }
+
+    #[test]
+    fn test_ssa_updater_diamond() {
+        let module = test_case_jump_thread().module;
+        let allocs = &module.allocs;
+        let (func, blocks) = main_blocks(&module);
+        let dom = DominatorTree::builder(allocs, func).unwrap().build();
+        let df = DominanceFrontier::new(&dom, allocs).unwrap();
+        let (left, right, merge, exit) = (blocks[1], blocks[2], blocks[3], blocks[6]);
+        let (add, sub) = (first_inst(allocs, left), first_inst(allocs, right));
+
+        let mut updater = SSAUpdater::new(allocs, &df, ValTypeID::Int(32));
+        updater.add_def(left, add);
+        updater.add_def(right, sub);
+        assert_eq!(updater.value_at_end(left), add);
+        let ValueSSA::Inst(phi) = updater.value_at_entry(merge) else {
+            panic!("expected a phi at the merge block");
+        };
+        assert_eq!(phi.get_parent(allocs), Some(merge));
+        let phi_obj = PhiInstID::raw_from(phi).deref_ir(allocs);
+        assert_eq!(phi_obj.find_incoming_value(allocs, left), Some(add));
+        assert_eq!(phi_obj.find_incoming_value(allocs, right), Some(sub));
+        // Z 的两个前驱都由 M 支配, 不需要再插入 Phi
+        assert_eq!(updater.value_at_entry(exit), ValueSSA::Inst(phi));
+        assert_eq!(updater.value_at_entry(blocks[0]), updater.undef());
+        assert_eq!(updater.inserted_phis().count(), 1);
+
+        let ret = exit.get_terminator_inst(allocs);
+        let InstObj::Ret(ret_obj) = ret.deref_ir(allocs) else {
+            panic!("exit block does not end with ret");
+        };
+        let retval = ret_obj.retval_use();
+        updater.rewrite_use(retval);
+        assert_eq!(retval.get_operand(allocs), ValueSSA::Inst(phi));
+        assert_eq!(updater.finish(), 0);
+        assert_module_sane(&module);
+    }
+
+    #[test]
+    fn test_ssa_updater_trivial_phi() {
+        let module = test_case_jump_thread().module;
+        let allocs = &module.allocs;
+        let (func, blocks) = main_blocks(&module);
+        let dom = DominatorTree::builder(allocs, func).unwrap().build();
+        let df = DominanceFrontier::new(&dom, allocs).unwrap();
+        let (left, right, merge) = (blocks[1], blocks[2], blocks[3]);
+        let value = ValueSSA::from(APInt::new(7u32, 32));
+
+        let mut updater = SSAUpdater::new(allocs, &df, ValTypeID::Int(32));
+        updater.add_def(left, value);
+        updater.add_def(right, value);
+        let phi = updater.value_at_entry(merge);
+        assert_ne!(phi, value);
+        let ValueSSA::Inst(phi_inst) = phi else {
+            panic!("expected a phi at the merge block");
+        };
+        assert_eq!(phi_inst.deref_ir(allocs).get_valtype(), ValTypeID::Int(32));
+
+        // 两个前驱传入同一个值, 结束时 Phi 被删除, 使用者改为使用这个值
+        let user = first_inst(allocs, merge);
+        let ValueSSA::Inst(user) = user else { unreachable!() };
+        let lhs = user.deref_ir(allocs).operands_iter().next().unwrap();
+        lhs.set_operand(allocs, phi);
+        assert_eq!(updater.finish(), 1);
+        assert_eq!(lhs.get_operand(allocs), value);
+        assert_module_sane(&module);
+    }
+}
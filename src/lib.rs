@@ -2,7 +2,9 @@ pub use {mtb_entity_slab, slab, smol_str};
 
 pub mod base;
 pub mod ir;
+pub mod mir;
 pub mod opt;
+pub mod rig;
 pub mod testing;
 pub mod typing;
 
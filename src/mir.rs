@@ -0,0 +1,188 @@
+//! ## Remusys-MIR
+//!
+//! 非 SSA 的中层机器代码, 面向 AArch64. 指令集由 `rigs/mir.rig` 描述.
+//!
+//! * `MirModule` 持有全部全局符号 (`MirFunc` 和 `MirGlobalVar`) 以及指令和基本块的分配器
+//!   `MirAllocs`. 基本块和指令的组织方式与 IR 相同: 分配在 slab 里, 用 `EntityList` 串起来.
+//! * 操作数可以是虚拟寄存器 `VReg`、物理寄存器 `PReg`、立即数和各种符号引用, 见 `MirOperand`.
+//!   虚拟寄存器不要求只定义一次.
+//! * `MirWriter` 输出文本格式, `verify_mir_module` 检查操作数约束和控制流结构.
+//! * `select_module` 把 IR 模块翻译成 MIR, 见 `isel`.
+//! * `allocate_regs` 用线性扫描把虚拟寄存器分配到物理寄存器, 见 `regalloc`.
+//! * `lower_frame` 按 `MirFrameLayout` 把栈槽引用换成基址寄存器加立即数偏移, 见 `frame`.
+//! * `emit_asm` 把分配好寄存器的模块输出成 GNU as 汇编, 见 `asm`.
+//! * `CallAbi` 描述 AAPCS64 下参数和返回值的位置, 见 `abi`. 指令选择按它搬运参数、返回值和 `x8` 中的结果地址.
+
+mod abi;
+mod asm;
+mod block;
+mod frame;
+mod func;
+mod global;
+mod imm;
+mod inst;
+mod isel;
+mod module;
+mod operand;
+mod printer;
+mod regalloc;
+mod utils;
+mod verify;
+
+pub use self::{
+    abi::{
+        AbiArg, AbiLoc, AbiLowerErr, AbiLowerRes, AbiPart, AbiPassKind, ArgCursor, CallAbi,
+        NUM_ARG_REGS, SRET_GPR,
+    },
+    asm::{AsmEmitErr, AsmEmitRes, AsmWriter, emit_asm},
+    block::{MirBlockAlloc, MirBlockBody, MirBlockID, MirBlockInnerID, MirBlockObj},
+    frame::{BASE_REG, MirFrameLayout, lower_frame, lower_func_frame},
+    func::{MirFunc, MirStackSlot, MirStackSlotKind, MirSwitchTab},
+    global::{MirDataUnit, MirGlobal, MirGlobalID, MirGlobalVar, MirSection},
+    imm::{
+        is_calc_imm, is_fmov_imm32, is_fmov_imm64, is_logic_imm, is_lsp_imm, is_mov_imm,
+        is_movznk_imm,
+    },
+    inst::*,
+    isel::{MirISelErr, MirISelRes, select_module},
+    module::{MirAllocs, MirModule},
+    operand::{
+        MirOperand, MirOperandKind, MirRegBank, MirRegClass, MirSwitchTabID, PReg, StackSlotID,
+        VReg,
+    },
+    printer::MirWriter,
+    regalloc::{RegAllocConfig, RegAllocErr, RegAllocRes, allocate_func_regs, allocate_regs},
+    verify::{MirVerifyErr, MirVerifyRes, verify_mir_func, verify_mir_module},
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 检入的 `inst/generated.rs` 必须与 `mir.rig` 的生成结果一致.
+    #[test]
+    fn test_generated_up_to_date() {
+        let source = include_str!("../rigs/mir.rig");
+        let generated = crate::rig::compile_rig(source, "rigs/mir.rig")
+            .unwrap_or_else(|errs| panic!("{}", errs.render("rigs/mir.rig", source)));
+        let checked_in = include_str!("mir/inst/generated.rs");
+        assert!(
+            generated == checked_in,
+            "src/mir/inst/generated.rs is out of date, regenerate it with rig-gen"
+        );
+    }
+
+    fn build_max_func(module: &mut MirModule) -> MirGlobalID {
+        let func = MirFunc::new_defined(&module.allocs, "max", true);
+        let func_id = module.add_func(func);
+        let allocs = &module.allocs;
+        let func = module.get_func(func_id);
+        let entry = MirBlockID::new(allocs, "entry");
+        let ret_lhs = MirBlockID::new(allocs, "ret_lhs");
+        let ret_rhs = MirBlockID::new(allocs, "ret_rhs");
+        for bb in [entry, ret_lhs, ret_rhs] {
+            func.push_block(allocs, bb);
+        }
+
+        let lhs = func.new_vreg(MirRegClass::GPR64);
+        let rhs = func.new_vreg(MirRegClass::GPR64);
+        let x0 = MirOperand::PReg(PReg::x(0));
+        let x1 = MirOperand::PReg(PReg::x(1));
+        let nzcv = MirOperand::PReg(PReg::NZCV);
+        let copy = |dst: MirOperand, src| MirCopy64::new(MirOP::MirCopy64, dst, src).into_mir();
+        entry.push_inst(allocs, copy(lhs.into(), x0));
+        entry.push_inst(allocs, copy(rhs.into(), x1));
+        let cmp = ICmp64R::new(MirOP::ICmp64R, nzcv, lhs.into(), rhs.into());
+        entry.push_inst(allocs, cmp.into_mir());
+        let br = CondBr::new(MirOP::BCond, MirOperand::Label(ret_lhs), nzcv);
+        br.set_cond(MirCondFlag::GE);
+        entry.push_inst(allocs, br.into_mir());
+        let jump = UncondBr::new(MirOP::B, MirOperand::Label(ret_rhs));
+        entry.push_inst(allocs, jump.into_mir());
+
+        for (bb, val) in [(ret_lhs, lhs), (ret_rhs, rhs)] {
+            bb.push_inst(allocs, copy(x0, val.into()));
+            bb.push_inst(allocs, MirReturn::new(&[x0]).into_mir());
+        }
+        func_id
+    }
+
+    #[test]
+    fn test_build_print_verify() {
+        let mut module = MirModule::new("test");
+        let func_id = build_max_func(&mut module);
+        verify_mir_module(&module).expect("MIR should be valid");
+
+        let text = module.to_string();
+        let expected = "\
+; MIR module `test`
+
+func @max exported {
+.entry:
+    %v0:gpr64 = MirCopy64 $x0
+    %v1:gpr64 = MirCopy64 $x1
+    ICmp64R %v0:gpr64, %v1:gpr64 -> $nzcv {rm_op: None}
+    BCond .ret_lhs, $nzcv {cond: GE}
+    B .ret_rhs
+.ret_lhs:
+    $x0 = MirCopy64 %v0:gpr64
+    MirReturn $x0
+.ret_rhs:
+    $x0 = MirCopy64 %v1:gpr64
+    MirReturn $x0
+}
+";
+        assert_eq!(text, expected);
+
+        // 跳转之后还有普通指令
+        let allocs = &module.allocs;
+        let func = module.get_func(func_id);
+        let entry = func.get_entry(allocs).unwrap();
+        let bogus = func.new_vreg(MirRegClass::GPR64);
+        let mov = Mov64I::new(MirOP::Mov64I, bogus.into(), MirOperand::Imm(1));
+        let mov = entry.push_inst(allocs, mov.into_mir());
+        let err = verify_mir_module(&module).unwrap_err();
+        assert!(matches!(err, MirVerifyErr::TerminatorInMiddle(bb, _) if bb == entry));
+        mov.remove(&mut module.allocs);
+        verify_mir_module(&module).unwrap();
+    }
+
+    #[test]
+    fn test_verify_operand_kinds() {
+        let mut module = MirModule::new("test");
+        let func_id = build_max_func(&mut module);
+        let allocs = &module.allocs;
+        let func = module.get_func(func_id);
+        let entry = func.get_entry(allocs).unwrap();
+        let dst = func.new_vreg(MirRegClass::GPR64);
+
+        // 12 位以外的立即数不能用于 `add`
+        let add = Bin64RC::new(
+            MirOP::Add64I,
+            dst.into(),
+            dst.into(),
+            MirOperand::Imm(0x1001),
+        );
+        let first = entry.get_insts(allocs).get_front_id(&allocs.insts).unwrap();
+        let add = MirInstID::new(allocs, add);
+        first.insert_before(allocs, add);
+        let err = verify_mir_module(&module).unwrap_err();
+        assert!(matches!(
+            err,
+            MirVerifyErr::OperandKindMismatch { index: 2, kind: MirOperandKind::ImmCalc, .. }
+        ));
+        add.get_inst(allocs).ins()[1].set(MirOperand::Imm(0x1000));
+        verify_mir_module(&module).unwrap();
+
+        // 同一个虚拟寄存器不能同时当作通用寄存器和浮点寄存器使用
+        let fdst = VReg::new(dst.get_id(), MirRegClass::FPR64);
+        let fmov = FMov64I::new(
+            MirOP::FMov64I,
+            fdst.into(),
+            MirOperand::Imm(1.0f64.to_bits() as i64),
+        );
+        add.insert_after(allocs, MirInstID::new(allocs, fmov));
+        let err = verify_mir_module(&module).unwrap_err();
+        assert!(matches!(err, MirVerifyErr::VRegClassConflict(id, ..) if id == dst.get_id()));
+    }
+}
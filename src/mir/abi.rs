@@ -0,0 +1,441 @@
+//! AAPCS64 调用约定: 把函数类型的参数和返回值分配到寄存器和栈上.
+//!
+//! 结果 [`CallAbi`] 只描述值的每一段放在哪里, 不依赖 MIR, 指令选择和解释器调用本地函数时都按它来搬运数据.
+//!
+//! * 整数、指针放进 `x0`..`x7`, 浮点数和 8/16 字节的短向量放进 `v0`..`v7`. 128 位整数和 16 字节对齐的
+//!   复合类型占一对从偶数号开始的通用寄存器.
+//! * 同构浮点聚合 (HFA/HVA, 成员都是同一种浮点数或者短向量, 不超过 4 个) 逐个成员放进连续的浮点寄存器.
+//! * 其他不超过 16 字节的复合类型按 8 字节一段放进通用寄存器, 超过 16 字节的由调用者复制一份, 传递副本的地址.
+//!   返回这样的值时调用者分配内存, 把地址放在 `x8` 里.
+//! * 寄存器不够时整个值放到栈上, 并且这一类寄存器不再分配. 栈上每个值按 8 字节和自身对齐中较大的一个对齐,
+//!   最多 16 字节, 占用的大小向上取整到 8 字节.
+//! * 可变参数与固定参数的规则相同 (Linux 的约定, 与 Apple 平台不同).
+//! * 标有 `zeroext`/`signext` 的不足 32 位的整数参数由调用者扩展到 32 位.
+
+use crate::{
+    ir::{inst::*, *},
+    typing::{AggrType, FPKind, FuncTypeID, IValType, TypeContext, ValTypeID},
+};
+
+/// 每类参数寄存器的个数: `x0`..`x7` 和 `v0`..`v7`.
+pub const NUM_ARG_REGS: u8 = 8;
+/// 返回复合类型时存放结果地址的寄存器 `x8`.
+pub const SRET_GPR: u8 = 8;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AbiLowerErr {
+    #[error("type {0:?} cannot be passed as an argument or a return value")]
+    UnsupportedType(ValTypeID),
+}
+pub type AbiLowerRes<T = ()> = Result<T, AbiLowerErr>;
+
+/// 值的一段所在的位置.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbiLoc {
+    /// 通用寄存器 `x<n>`.
+    GPR(u8),
+    /// 浮点/SIMD 寄存器 `v<n>`.
+    FPR(u8),
+    /// 调用时 `sp` 之上的偏移量.
+    Stack(u32),
+}
+
+impl AbiLoc {
+    pub fn is_reg(self) -> bool {
+        !matches!(self, AbiLoc::Stack(_))
+    }
+}
+
+/// 值从 `offset` 字节开始的 `size` 字节放在 `loc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AbiPart {
+    pub offset: u32,
+    pub size: u32,
+    pub loc: AbiLoc,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiPassKind {
+    /// 大小为 0 的值, 不占任何位置.
+    Ignore,
+    /// 按段直接传递.
+    Direct(Vec<AbiPart>),
+    /// 值放在内存里, 只传递它的地址. 参数的地址放在 `loc`, 返回值的地址总是放在 `x8`.
+    Indirect(AbiLoc),
+}
+
+impl AbiPassKind {
+    /// 整个值放在一个寄存器里时返回这个寄存器.
+    pub fn as_single_reg(&self) -> Option<AbiLoc> {
+        self.as_single_loc().filter(|loc| loc.is_reg())
+    }
+    /// 整个值放在一个寄存器或者一块连续的栈空间里时返回它的位置.
+    pub fn as_single_loc(&self) -> Option<AbiLoc> {
+        match self {
+            AbiPassKind::Direct(parts) => match parts.as_slice() {
+                [part] if part.offset == 0 => Some(part.loc),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiArg {
+    pub ty: ValTypeID,
+    /// 调用者要做的扩展. 只有不足 32 位的整数可能不是 `NoExt`.
+    pub ext: IntExtAttr,
+    pub kind: AbiPassKind,
+}
+
+/// 参数分配的进度: 下一个通用寄存器、浮点寄存器的编号和下一个栈上参数的偏移.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArgCursor {
+    pub ngrn: u8,
+    pub nsrn: u8,
+    pub nsaa: u32,
+}
+
+/// 一次调用 (或一个函数定义) 的参数和返回值布局.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallAbi {
+    /// 固定参数在前, 可变参数在后.
+    pub args: Vec<AbiArg>,
+    pub nfixed: usize,
+    pub ret_ty: ValTypeID,
+    pub ret: AbiPassKind,
+    /// 固定参数分配完以后的进度. 可变参数函数据此初始化 `va_list`.
+    pub va_cursor: ArgCursor,
+    /// 栈上参数区的大小, 是 16 的倍数.
+    pub stack_size: u32,
+}
+
+/// 同构聚合的成员类型. 向量只看大小.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HomoBase {
+    Float(FPKind),
+    Vector(usize),
+}
+
+impl HomoBase {
+    fn size(self) -> usize {
+        match self {
+            HomoBase::Float(FPKind::Ieee32) => 4,
+            HomoBase::Float(FPKind::Ieee64) => 8,
+            HomoBase::Vector(size) => size,
+        }
+    }
+}
+
+/// 值在分配寄存器时的类别.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueClass {
+    Empty,
+    /// `nregs` 个连续的通用寄存器, `even` 表示要从偶数号开始.
+    IntRegs {
+        nregs: u8,
+        even: bool,
+    },
+    /// `count` 个连续的浮点寄存器, 每个放 `size` 字节.
+    FloatRegs {
+        count: u8,
+        size: u32,
+    },
+    /// 超过 16 字节的复合类型, 放在内存里传地址.
+    Memory,
+}
+
+/// 同构聚合的成员类型和成员个数. 成员超过 4 个或者不同构时返回 `None`.
+fn homo_aggr(tctx: &TypeContext, ty: ValTypeID) -> Option<(HomoBase, usize)> {
+    match ty {
+        ValTypeID::Float(kind) => Some((HomoBase::Float(kind), 1)),
+        ValTypeID::FixVec(vec) => match vec.get_size(tctx) {
+            size @ (8 | 16) => Some((HomoBase::Vector(size), 1)),
+            _ => None,
+        },
+        ValTypeID::Array(_) | ValTypeID::Struct(_) | ValTypeID::StructAlias(_) => {
+            let aggr = AggrType::from_ir(ty);
+            let (mut base, mut count) = (None, 0);
+            for index in 0..aggr.nfields(tctx) {
+                let (field_base, n) = homo_aggr(tctx, aggr.get_field(tctx, index))?;
+                if base.replace(field_base).is_some_and(|b| b != field_base) {
+                    return None;
+                }
+                count += n;
+                if count > 4 {
+                    return None;
+                }
+            }
+            Some((base?, count))
+        }
+        _ => None,
+    }
+}
+
+/// 类型的类别和它在内存里的大小、对齐.
+fn classify(tctx: &TypeContext, ty: ValTypeID) -> AbiLowerRes<(ValueClass, u32, u32)> {
+    let (Some(size), Some(align)) = (ty.try_get_aligned_size(tctx), ty.try_get_align(tctx)) else {
+        return Err(AbiLowerErr::UnsupportedType(ty));
+    };
+    let class = match ty {
+        _ if size == 0 => ValueClass::Empty,
+        ValTypeID::Float(_) => ValueClass::FloatRegs { count: 1, size: size as u32 },
+        ValTypeID::FixVec(_) if size == 8 || size == 16 => {
+            ValueClass::FloatRegs { count: 1, size: size as u32 }
+        }
+        ValTypeID::Array(_) | ValTypeID::Struct(_) | ValTypeID::StructAlias(_)
+            if homo_aggr(tctx, ty).is_some_and(|(base, n)| base.size() * n == size) =>
+        {
+            let (base, count) = homo_aggr(tctx, ty).unwrap();
+            ValueClass::FloatRegs { count: count as u8, size: base.size() as u32 }
+        }
+        _ if size > 16 => ValueClass::Memory,
+        _ => ValueClass::IntRegs { nregs: size.div_ceil(8) as u8, even: align >= 16 },
+    };
+    Ok((class, size as u32, align as u32))
+}
+
+/// 从 `first` 号寄存器开始, 按 `class` 把 `size` 字节的值拆进寄存器.
+fn reg_parts(class: ValueClass, size: u32, first: u8) -> Vec<AbiPart> {
+    match class {
+        ValueClass::IntRegs { nregs, .. } => (0..nregs)
+            .map(|i| {
+                let offset = i as u32 * 8;
+                AbiPart {
+                    offset,
+                    size: (size - offset).min(8),
+                    loc: AbiLoc::GPR(first + i),
+                }
+            })
+            .collect(),
+        ValueClass::FloatRegs { count, size } => (0..count)
+            .map(|i| AbiPart { offset: i as u32 * size, size, loc: AbiLoc::FPR(first + i) })
+            .collect(),
+        ValueClass::Empty | ValueClass::Memory => Vec::new(),
+    }
+}
+
+impl ArgCursor {
+    /// 给一个 `ty` 类型的参数分配位置.
+    pub fn assign(&mut self, tctx: &TypeContext, ty: ValTypeID) -> AbiLowerRes<AbiPassKind> {
+        let (class, size, align) = classify(tctx, ty)?;
+        let kind = match class {
+            ValueClass::Empty => AbiPassKind::Ignore,
+            ValueClass::FloatRegs { count, .. } if self.nsrn + count <= NUM_ARG_REGS => {
+                let parts = reg_parts(class, size, self.nsrn);
+                self.nsrn += count;
+                AbiPassKind::Direct(parts)
+            }
+            ValueClass::FloatRegs { .. } => {
+                self.nsrn = NUM_ARG_REGS;
+                self.stack_part(size, align)
+            }
+            ValueClass::IntRegs { nregs, even } => {
+                if even {
+                    self.ngrn = self.ngrn.next_multiple_of(2);
+                }
+                if self.ngrn + nregs <= NUM_ARG_REGS {
+                    let parts = reg_parts(class, size, self.ngrn);
+                    self.ngrn += nregs;
+                    AbiPassKind::Direct(parts)
+                } else {
+                    self.ngrn = NUM_ARG_REGS;
+                    self.stack_part(size, align)
+                }
+            }
+            ValueClass::Memory if self.ngrn < NUM_ARG_REGS => {
+                self.ngrn += 1;
+                AbiPassKind::Indirect(AbiLoc::GPR(self.ngrn - 1))
+            }
+            ValueClass::Memory => AbiPassKind::Indirect(self.stack_slot(8, 8)),
+        };
+        Ok(kind)
+    }
+
+    fn stack_slot(&mut self, size: u32, align: u32) -> AbiLoc {
+        let offset = self.nsaa.next_multiple_of(align.clamp(8, 16));
+        self.nsaa = offset + size.next_multiple_of(8);
+        AbiLoc::Stack(offset)
+    }
+    fn stack_part(&mut self, size: u32, align: u32) -> AbiPassKind {
+        let loc = self.stack_slot(size, align);
+        AbiPassKind::Direct(vec![AbiPart { offset: 0, size, loc }])
+    }
+}
+
+/// 返回值的位置.
+fn lower_ret(tctx: &TypeContext, ty: ValTypeID) -> AbiLowerRes<AbiPassKind> {
+    if ty == ValTypeID::Void {
+        return Ok(AbiPassKind::Ignore);
+    }
+    let (class, size, _) = classify(tctx, ty)?;
+    let kind = match class {
+        ValueClass::Empty => AbiPassKind::Ignore,
+        ValueClass::Memory => AbiPassKind::Indirect(AbiLoc::GPR(SRET_GPR)),
+        _ => AbiPassKind::Direct(reg_parts(class, size, 0)),
+    };
+    Ok(kind)
+}
+
+impl CallAbi {
+    /// 按函数类型求出布局. `exts` 是各个固定参数的扩展属性, 缺省的视为 `NoExt`;
+    /// `varargs` 是调用处传入的可变参数的类型.
+    pub fn new(
+        tctx: &TypeContext,
+        fty: FuncTypeID,
+        exts: &[IntExtAttr],
+        varargs: &[ValTypeID],
+    ) -> AbiLowerRes<Self> {
+        let fixed = fty.get_args(tctx).to_vec();
+        let mut cursor = ArgCursor::default();
+        let mut args = Vec::with_capacity(fixed.len() + varargs.len());
+        for (index, &ty) in fixed.iter().enumerate() {
+            let ext = match ty {
+                ValTypeID::Int(bits) if bits < 32 => exts.get(index).copied().unwrap_or_default(),
+                _ => IntExtAttr::NoExt,
+            };
+            args.push(AbiArg { ty, ext, kind: cursor.assign(tctx, ty)? });
+        }
+        let va_cursor = cursor;
+        for &ty in varargs {
+            args.push(AbiArg { ty, ext: IntExtAttr::NoExt, kind: cursor.assign(tctx, ty)? });
+        }
+        let ret_ty = fty.get_ret_type(tctx);
+        Ok(Self {
+            args,
+            nfixed: fixed.len(),
+            ret_ty,
+            ret: lower_ret(tctx, ret_ty)?,
+            va_cursor,
+            stack_size: cursor.nsaa.next_multiple_of(16),
+        })
+    }
+
+    /// 函数定义处的布局, 扩展属性取自各个参数.
+    pub fn of_func(module: &Module, func: FuncID) -> AbiLowerRes<Self> {
+        let exts = Self::arg_exts(&module.allocs, func);
+        Self::new(&module.tctx, func.get_functype(&module.allocs), &exts, &[])
+    }
+
+    /// 调用处的布局. 直接调用函数时扩展属性取自被调用函数的参数, 间接调用时都是 `NoExt`.
+    pub fn of_call(module: &Module, call: CallInstID) -> AbiLowerRes<Self> {
+        let allocs = &module.allocs;
+        let fty = call.callee_ty(allocs);
+        let exts = match call.get_callee(allocs) {
+            ValueSSA::Global(g) => match FuncID::try_from_global(allocs, g) {
+                Some(func) => Self::arg_exts(allocs, func),
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+        let nfixed = fty.get_nargs(&module.tctx);
+        let varargs: Vec<_> = (nfixed..call.nargs(allocs))
+            .map(|i| call.get_arg(allocs, i).get_valtype(allocs))
+            .collect();
+        Self::new(&module.tctx, fty, &exts, &varargs)
+    }
+
+    fn arg_exts(allocs: &IRAllocs, func: FuncID) -> Vec<IntExtAttr> {
+        func.args(allocs)
+            .iter()
+            .map(|arg| arg.attrs().get_int_ext().unwrap_or_default())
+            .collect()
+    }
+
+    /// 返回值是否经由 `x8` 传来的地址写回内存.
+    pub fn has_sret(&self) -> bool {
+        matches!(self.ret, AbiPassKind::Indirect(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typing::{ArchInfo, ArrayTypeID, FixVecType, ScalarType, StructTypeID};
+
+    fn gpr(offset: u32, size: u32, n: u8) -> AbiPart {
+        AbiPart { offset, size, loc: AbiLoc::GPR(n) }
+    }
+    fn fpr(offset: u32, size: u32, n: u8) -> AbiPart {
+        AbiPart { offset, size, loc: AbiLoc::FPR(n) }
+    }
+    fn stack(size: u32, offset: u32) -> AbiPassKind {
+        AbiPassKind::Direct(vec![AbiPart {
+            offset: 0,
+            size,
+            loc: AbiLoc::Stack(offset),
+        }])
+    }
+
+    #[test]
+    fn test_scalar_args() {
+        let tctx = TypeContext::new(ArchInfo::new_host());
+        let (i8ty, i64ty, i128ty) = (ValTypeID::Int(8), ValTypeID::Int(64), ValTypeID::Int(128));
+        let f32ty = ValTypeID::Float(FPKind::Ieee32);
+        let v4f32 = ValTypeID::FixVec(FixVecType(ScalarType::Float(FPKind::Ieee32), 2));
+        let mut args = vec![i8ty, f32ty, i128ty, v4f32];
+        args.extend([i64ty; 5]);
+        args.extend([f32ty; 7]);
+        let fty = FuncTypeID::new(&tctx, i128ty, false, args);
+        let abi = CallAbi::new(&tctx, fty, &[IntExtAttr::SignExt], &[]).unwrap();
+        let kinds: Vec<_> = abi.args.iter().map(|a| a.kind.clone()).collect();
+
+        assert_eq!(abi.args[0].ext, IntExtAttr::SignExt);
+        assert_eq!(kinds[0], AbiPassKind::Direct(vec![gpr(0, 1, 0)]));
+        assert_eq!(kinds[1], AbiPassKind::Direct(vec![fpr(0, 4, 0)]));
+        // i128 跳过 x1, 从 x2 开始.
+        assert_eq!(
+            kinds[2],
+            AbiPassKind::Direct(vec![gpr(0, 8, 2), gpr(8, 8, 3)])
+        );
+        assert_eq!(kinds[3], AbiPassKind::Direct(vec![fpr(0, 16, 1)]));
+        assert_eq!(kinds[7].as_single_reg(), Some(AbiLoc::GPR(7)));
+        assert_eq!(kinds[8], stack(8, 0));
+        assert_eq!(kinds[14].as_single_reg(), Some(AbiLoc::FPR(7)));
+        assert_eq!(kinds[15], stack(4, 8));
+        assert_eq!(abi.stack_size, 16);
+        assert_eq!(
+            abi.ret,
+            AbiPassKind::Direct(vec![gpr(0, 8, 0), gpr(8, 8, 1)])
+        );
+    }
+
+    #[test]
+    fn test_aggregate_args() {
+        let tctx = TypeContext::new(ArchInfo::new_host());
+        let (i32ty, i64ty) = (ValTypeID::Int(32), ValTypeID::Int(64));
+        let (f32ty, f64ty) = (
+            ValTypeID::Float(FPKind::Ieee32),
+            ValTypeID::Float(FPKind::Ieee64),
+        );
+        let hfa = ValTypeID::Struct(StructTypeID::new(&tctx, false, [f32ty, f32ty, f32ty]));
+        let hfa4 = ValTypeID::Array(ArrayTypeID::new(&tctx, f64ty, 4));
+        let mixed = ValTypeID::Struct(StructTypeID::new(&tctx, false, [f32ty, i32ty, i64ty]));
+        let big = ValTypeID::Array(ArrayTypeID::new(&tctx, i64ty, 3));
+        let empty = ValTypeID::Struct(StructTypeID::new(&tctx, false, []));
+
+        let args = [hfa, hfa4, mixed, big, empty, hfa];
+        let fty = FuncTypeID::new(&tctx, big, true, args);
+        let abi = CallAbi::new(&tctx, fty, &[], &[f64ty, i32ty]).unwrap();
+        let kinds: Vec<_> = abi.args.iter().map(|a| a.kind.clone()).collect();
+
+        let hfa_parts = vec![fpr(0, 4, 0), fpr(4, 4, 1), fpr(8, 4, 2)];
+        assert_eq!(kinds[0], AbiPassKind::Direct(hfa_parts));
+        let hfa4_parts = (0..4).map(|i| fpr(i * 8, 8, 3 + i as u8)).collect();
+        assert_eq!(kinds[1], AbiPassKind::Direct(hfa4_parts));
+        assert_eq!(
+            kinds[2],
+            AbiPassKind::Direct(vec![gpr(0, 8, 0), gpr(8, 8, 1)])
+        );
+        assert_eq!(kinds[3], AbiPassKind::Indirect(AbiLoc::GPR(2)));
+        assert_eq!(kinds[4], AbiPassKind::Ignore);
+        // v7 放不下 3 个成员, 整个 HFA 放到栈上, 之后的浮点参数也不再用寄存器.
+        assert_eq!(kinds[5], stack(12, 0));
+        assert_eq!(abi.va_cursor, ArgCursor { ngrn: 3, nsrn: 8, nsaa: 16 });
+        assert_eq!(kinds[6], stack(8, 16));
+        assert_eq!(kinds[7].as_single_reg(), Some(AbiLoc::GPR(3)));
+        assert_eq!(abi.stack_size, 32);
+        assert!(abi.has_sret());
+    }
+}
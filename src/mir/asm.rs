@@ -0,0 +1,420 @@
+//! 输出 GNU as 语法的 AArch64 汇编.
+//!
+//! 输入是寄存器分配之后的 MIR 模块, 操作数里不能再有虚拟寄存器.
+//!
+//! * 栈帧按 `MirFrameLayout` 布局, 序言、尾声和 `MirDynAlloca` 在这里生成, 见 `frame`.
+//!   没有经过 `lower_frame` 的栈槽引用在输出时按同样的布局消解.
+//! * MIR 伪指令在输出时展开, 需要的临时寄存器用寄存器分配保留的 `x16`/`x17`.
+//!   `LoadConst64` 的常量放在函数末尾的常量池里, `MirSwitch` 按分支的疏密选用跳转表或比较链.
+//! * 全局变量按 `MirSection` 放进 `.data`/`.rodata`/`.bss`/`.tdata`/`.tbss`,
+//!   初始值逐字节写出, 地址写成 `.xword`.
+//!
+//! 基本块的标签是 `.LBB<函数编号>_<基本块序号>`, 常量池和跳转表分别是 `.LCPI` 和 `.LJTI`,
+//! 编号规则相同.
+
+use crate::{
+    SymbolStr,
+    mir::{
+        MirDataUnit, MirGlobal, MirGlobalID, MirGlobalVar, MirModule, MirOP, MirOperand, MirSection,
+    },
+};
+use std::fmt::Write;
+
+mod frame;
+mod func;
+mod inst;
+mod mem;
+mod pseudo;
+
+use self::func::FuncEmitter;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AsmEmitErr {
+    #[error("function `{0}` still uses virtual registers, run register allocation first")]
+    VRegLeft(SymbolStr),
+    #[error("instruction {1:?} in function `{0}` has no machine code form")]
+    UnsupportedInst(SymbolStr, MirOP),
+    #[error("operand {2:?} of instruction {1:?} in function `{0}` cannot be encoded")]
+    InvalidOperand(SymbolStr, MirOP, MirOperand),
+    #[error("failed to write assembly")]
+    Fmt(#[from] std::fmt::Error),
+}
+pub type AsmEmitRes<T = ()> = Result<T, AsmEmitErr>;
+
+/// 把整个模块输出成汇编文本.
+pub fn emit_asm(module: &MirModule) -> AsmEmitRes<String> {
+    let mut out = String::new();
+    AsmWriter::new(module).write_module(&mut out)?;
+    Ok(out)
+}
+
+pub struct AsmWriter<'mir> {
+    pub module: &'mir MirModule,
+}
+
+impl<'mir> AsmWriter<'mir> {
+    pub fn new(module: &'mir MirModule) -> Self {
+        Self { module }
+    }
+
+    pub fn write_module(&self, out: &mut dyn Write) -> AsmEmitRes {
+        writeln!(out, "\t.file\t\"{}\"", self.module.name)?;
+        for (id, global) in self.module.globals_iter() {
+            match global {
+                MirGlobal::Func(func) if !func.is_extern() => {
+                    writeln!(out)?;
+                    FuncEmitter::new(self.module, id, out)?.run()?;
+                }
+                MirGlobal::Var(var) if var.init.is_some() => {
+                    writeln!(out)?;
+                    self.write_var(var, out)?;
+                }
+                // 外部符号不需要声明, 汇编器把未定义的符号当作外部符号.
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn write_var(&self, var: &MirGlobalVar, out: &mut dyn Write) -> AsmEmitRes {
+        let name = &var.name;
+        let section = match var.section {
+            MirSection::Data => "\t.data",
+            MirSection::RoData => "\t.section\t.rodata",
+            MirSection::Bss => "\t.bss",
+            MirSection::TData => "\t.section\t.tdata,\"awT\",@progbits",
+            MirSection::TBss => "\t.section\t.tbss,\"awT\",@nobits",
+        };
+        writeln!(out, "{section}")?;
+        if var.exported {
+            writeln!(out, "\t.globl\t{name}")?;
+        }
+        writeln!(out, "\t.type\t{name},@object")?;
+        writeln!(out, "\t.p2align\t{}", var.align_log2)?;
+        writeln!(out, "{name}:")?;
+        for unit in var.init.iter().flatten() {
+            match unit {
+                MirDataUnit::Bytes(bytes) => {
+                    for line in bytes.chunks(16) {
+                        let line: Vec<String> = line.iter().map(u8::to_string).collect();
+                        writeln!(out, "\t.byte\t{}", line.join(", "))?;
+                    }
+                }
+                MirDataUnit::Zeros(size) => writeln!(out, "\t.zero\t{size}")?,
+                MirDataUnit::Addr(global, offset) => {
+                    writeln!(out, "\t.xword\t{}", self.global_ref(*global, *offset))?;
+                }
+            }
+        }
+        writeln!(out, "\t.size\t{name}, {}", var.get_size())?;
+        Ok(())
+    }
+
+    /// 全局符号加偏移量, 用在 `.xword` 和重定位修饰符里.
+    fn global_ref(&self, global: MirGlobalID, offset: i64) -> String {
+        let name = self.module.get_global(global).get_name();
+        match offset {
+            0 => name.to_string(),
+            _ => format!("{name}{offset:+}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::APInt,
+        ir::{
+            ExprID, FuncID, GlobalVarID, IGlobalVarBuildable, IRBuilder, ISubExprID, ISubGlobalID,
+            ISubInstID, Module, StructExprID, TLSModel, ValueSSA,
+            inst::{AllocaInstID, CallInst, CallInstID, LoadInstID, StoreInstID},
+        },
+        mir::{
+            IMirSubInst, LoadConst64, MirBlockID, MirDynAlloca, MirFunc, MirOP, MirReturn,
+            MirStackSlot, MirStackSlotKind, MirSwitch, MirSwitchTab, PReg, RegAllocConfig,
+            StoreGr64BaseS, allocate_regs, lower_frame, select_module,
+        },
+        testing::cases::*,
+        typing::{
+            AggrType, ArchInfo, ArrayTypeID, FPKind, FixVecType, FuncTypeID, ScalarType,
+            StructTypeID, ValTypeID,
+        },
+    };
+
+    fn compile(module: &mut Module) -> String {
+        let mut mir = select_module(module).unwrap();
+        allocate_regs(&mut mir, RegAllocConfig { verify: true }).unwrap();
+        lower_frame(&mut mir);
+        emit_asm(&mir).unwrap()
+    }
+
+    #[test]
+    fn test_emit_cases() {
+        let cases = [
+            test_case_cfg_deep_while_br(),
+            test_case_minmax(),
+            test_case_array_sum(),
+            test_case_matrix_fill(),
+            test_case_phi_swap(),
+        ];
+        for mut builder in cases {
+            let asm = compile(&mut builder.module);
+            assert!(!asm.contains('%') && !asm.contains('$'), "{asm}");
+            assert!(asm.contains("\t.globl\tmain\n"), "{asm}");
+            assert!(asm.contains("\tstp\tx29, x30, [sp, #-16]!\n"), "{asm}");
+            assert!(asm.contains("\tldp\tx29, x30, [sp], #16\n\tret\n"), "{asm}");
+        }
+    }
+
+    #[test]
+    fn test_global_layout() {
+        let mut module = Module::new(ArchInfo::new_host(), "data");
+        let (allocs, tctx) = (&module.allocs, &module.tctx);
+        let (i8ty, i16ty, i32ty, i64ty) = (
+            ValTypeID::Int(8),
+            ValTypeID::Int(16),
+            ValTypeID::Int(32),
+            ValTypeID::Int(64),
+        );
+
+        // { i8, i32, ptr, i16 }: `i8` 之后填充 3 字节
+        let sty = StructTypeID::new(tctx, false, [i8ty, i32ty, ValTypeID::Ptr, i16ty]);
+        let target = GlobalVarID::builder("target", i64ty)
+            .initval(APInt::new(5u64, 64).into())
+            .build_id(&module)
+            .unwrap();
+        let expr = StructExprID::new_uninit(allocs, tctx, sty);
+        expr.set_field(allocs, 0, APInt::new(1u8, 8).into());
+        expr.set_field(allocs, 1, APInt::new(0x0403_0201u32, 32).into());
+        expr.set_field(allocs, 2, ValueSSA::Global(target.raw_into()));
+        expr.set_field(allocs, 3, APInt::new(0xffffu16, 16).into());
+        let expr: ExprID = expr.raw_into();
+        GlobalVarID::builder("s", ValTypeID::Struct(sty))
+            .initval(ValueSSA::ConstExpr(expr))
+            .build_id(&module)
+            .unwrap();
+
+        let arrty = ArrayTypeID::new(tctx, i16ty, 3);
+        GlobalVarID::builder("table", ValTypeID::Array(arrty))
+            .initval(ValueSSA::AggrZero(AggrType::Array(arrty)))
+            .readonly(true)
+            .build_id(&module)
+            .unwrap();
+        GlobalVarID::builder("counter", i32ty)
+            .initval(APInt::new(0u32, 32).into())
+            .enable_tls(TLSModel::LocalExec)
+            .build_id(&module)
+            .unwrap();
+
+        let asm = compile(&mut module);
+        let s = asm.split("s:\n").nth(1).unwrap();
+        let expected = [
+            "\t.byte\t1",
+            "\t.zero\t3",
+            "\t.byte\t1, 2, 3, 4",
+            "\t.xword\ttarget",
+            "\t.byte\t255, 255",
+            "\t.size\ts, 18",
+        ];
+        assert!(s.starts_with(&expected.join("\n")), "{asm}");
+        assert!(
+            asm.contains("\t.section\t.rodata\n\t.globl\ttable\n"),
+            "{asm}"
+        );
+        assert!(asm.contains("\t.zero\t6\n\t.size\ttable, 6"), "{asm}");
+        assert!(asm.contains("\t.section\t.tbss,\"awT\",@nobits"), "{asm}");
+        assert!(asm.contains("\t.xword\ttarget\n"), "{asm}");
+    }
+
+    /// 入口块按 `$x0` 分派到各个 case, 每个 case 都把一个大常量写进 `$x0` 返回.
+    fn build_switch_func(module: &mut MirModule, name: &str, cases: &[i64]) {
+        let func = MirFunc::new_defined(&module.allocs, name, true);
+        let func_id = module.add_func(func);
+        let entry = MirBlockID::new(&module.allocs, "entry");
+        let default = MirBlockID::new(&module.allocs, "default");
+        let mut tab = MirSwitchTab { default, cases: Vec::new() };
+        let func = module.get_func(func_id);
+        func.push_block(&module.allocs, entry);
+        func.push_block(&module.allocs, default);
+        let x0 = MirOperand::PReg(PReg::x(0));
+        for &value in cases {
+            let bb = MirBlockID::new(&module.allocs, format!("case{value}"));
+            func.push_block(&module.allocs, bb);
+            let imm = MirOperand::Imm(0x1234_5678_9abc_def0);
+            let load = LoadConst64::new(MirOP::LoadConst64, x0, imm);
+            bb.push_inst(&module.allocs, load.into_mir());
+            bb.push_inst(&module.allocs, MirReturn::new(&[x0]).into_mir());
+            tab.cases.push((value, bb));
+        }
+        default.push_inst(&module.allocs, MirReturn::new(&[x0]).into_mir());
+        let tab = module.get_func_mut(func_id).add_switch_tab(tab);
+        let switch = MirSwitch::new(x0, tab);
+        entry.push_inst(&module.allocs, switch.into_mir());
+    }
+
+    #[test]
+    fn test_switch_and_const_pool() {
+        let mut mir = MirModule::new("switch");
+        build_switch_func(&mut mir, "dense", &[3, 4, 5, 7, 8]);
+        build_switch_func(&mut mir, "sparse", &[-1, 1000, 1 << 40]);
+        let asm = emit_asm(&mir).unwrap();
+
+        let (dense, sparse) = asm.split_once("sparse:\n").unwrap();
+        assert!(
+            dense.contains("\tsub\tx16, x0, #3\n\tcmp\tx16, #5\n"),
+            "{asm}"
+        );
+        assert!(
+            dense.contains("\tldrsw\tx16, [x17, x16, lsl #2]\n"),
+            "{asm}"
+        );
+        // 6 不在 case 里, 跳到 default
+        let table = dense.split(".LJTI0_0:\n").nth(1).unwrap();
+        let entries: Vec<&str> = table.lines().take(6).collect();
+        assert_eq!(entries[3], "\t.word\t.LBB0_1-.LJTI0_0", "{asm}");
+        // 相同的常量只放一份
+        assert_eq!(
+            dense.matches("\t.xword\t0x123456789abcdef0").count(),
+            1,
+            "{asm}"
+        );
+        assert!(dense.contains("\tldr\tx0, .LCPI0_0\n"), "{asm}");
+
+        assert!(!sparse.contains(".LJTI"), "{asm}");
+        assert!(sparse.contains("\tcmn\tx0, #1\n"), "{asm}");
+        assert!(sparse.contains("\tcmp\tx0, #1000\n"), "{asm}");
+        assert!(
+            sparse.contains("\tmov\tx17, #1099511627776\n\tcmp\tx0, x17\n"),
+            "{asm}"
+        );
+    }
+
+    /// `aligned` 要求 32 字节对齐的栈帧, `dynamic` 动态分配栈内存. 两者都有 16 字节的出参区.
+    fn build_frame_funcs(module: &mut MirModule) {
+        let x0 = MirOperand::PReg(PReg::x(0));
+        for (name, dynamic) in [("aligned", false), ("dynamic", true)] {
+            let mut func = MirFunc::new_defined(&module.allocs, name, true);
+            func.outgoing_size = 16;
+            func.stack_align_log2 = if dynamic { 3 } else { 5 };
+            let slot = MirOperand::StackSlot(func.add_stack_slot(MirStackSlot {
+                kind: MirStackSlotKind::Local,
+                size: 8,
+                align_log2: 3,
+            }));
+            let func_id = module.add_func(func);
+            let allocs = &module.allocs;
+            let entry = MirBlockID::new(allocs, "entry");
+            module.get_func(func_id).push_block(allocs, entry);
+            if dynamic {
+                let alloca = MirDynAlloca::new(MirOP::MirDynAlloca, x0, x0);
+                alloca.set_align_log2(5);
+                entry.push_inst(allocs, alloca.into_mir());
+            }
+            let sp = PReg::SP.into();
+            let store = StoreGr64BaseS::new(MirOP::StrGr64BaseS, x0, sp, slot);
+            entry.push_inst(allocs, store.into_mir());
+            entry.push_inst(allocs, MirReturn::new(&[x0]).into_mir());
+        }
+    }
+
+    #[test]
+    fn test_frame_realign_and_dyn_alloca() {
+        let mut mir = MirModule::new("frame");
+        build_frame_funcs(&mut mir);
+        let asm = emit_asm(&mir).unwrap();
+        let (aligned, dynamic) = asm.split_once("dynamic:\n").unwrap();
+        // 帧大小 32, 序言把 `sp` 向下对齐到 32 字节
+        assert!(
+            aligned.contains("\tsub\tx16, sp, #32\n\tand\tsp, x16, #0xffffffffffffffe0\n"),
+            "{asm}"
+        );
+        assert!(aligned.contains("\tstr\tx0, [sp, #16]\n"), "{asm}");
+        assert!(aligned.contains("\tmov\tsp, x29\n"), "{asm}");
+        // 新内存对齐到 32 字节, 下面再留出 32 字节的出参区; 栈槽改以 `x29` 为基址
+        let expected = [
+            "\tsub\tx16, sp, x0",
+            "\tand\tx0, x16, #0xffffffffffffffe0",
+            "\tsub\tsp, x0, #32",
+            "\tstur\tx0, [x29, #-16]",
+            "\tmov\tsp, x29",
+        ];
+        assert!(dynamic.contains(&expected.join("\n")), "{asm}");
+    }
+
+    #[test]
+    fn test_base_reg() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "base_reg");
+        let i64ty = ValTypeID::Int(64);
+        let func = new_func(&mut builder, "f", i64ty, &[i64ty]);
+        let arg = ValueSSA::FuncArg(func, 0);
+        let aligned = insert(&mut builder, AllocaInstID::new(builder.allocs(), i64ty, 5));
+        let buf = insert(
+            &mut builder,
+            AllocaInstID::new_dyn(builder.allocs(), i64ty, 3),
+        );
+        for ptr in [aligned, buf] {
+            insert(
+                &mut builder,
+                StoreInstID::new(builder.allocs(), arg, ptr, 3),
+            );
+        }
+        let load = LoadInstID::new_uninit(builder.allocs(), i64ty, 3);
+        load.set_source(builder.allocs(), aligned);
+        let load = insert(&mut builder, load);
+        ret(&mut builder, load);
+
+        // `align 32` 的栈槽以对齐之后存进 `x19` 的 `sp` 为基址, 不受动态分配影响
+        let asm = compile(&mut builder.module);
+        let prologue =
+            ["\tand\tsp, x16, #0xffffffffffffffe0", "\tstur\tx19, [x29, #-16]", "\tmov\tx19, sp"];
+        assert!(asm.contains(&prologue.join("\n")), "{asm}");
+        assert!(
+            asm.contains(", [x19]\n") || asm.contains(", [x19, #"),
+            "{asm}"
+        );
+        let epilogue = ["\tldur\tx19, [x29, #-16]", "\tmov\tsp, x29"];
+        assert!(asm.contains(&epilogue.join("\n")), "{asm}");
+    }
+
+    #[test]
+    fn test_vector_regs() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "vector");
+        let v4f32 = ValTypeID::FixVec(FixVecType(ScalarType::Float(FPKind::Ieee32), 2));
+        let fty = FuncTypeID::new(builder.tctx(), v4f32, false, [v4f32, v4f32]);
+        let g = FuncID::builder(builder.tctx(), "g", fty)
+            .make_extern()
+            .build_id(&builder.module)
+            .unwrap();
+        let call = |builder: &mut IRBuilder, args: &[ValueSSA]| {
+            builder
+                .build_inst(|allocs, tctx| {
+                    let mut cb = CallInst::builder(tctx, fty);
+                    cb.callee(ValueSSA::Global(g.raw_into()));
+                    cb.with_args(args);
+                    CallInstID::allocate(allocs, cb.build_obj(allocs)).raw_into()
+                })
+                .map(ValueSSA::Inst)
+                .unwrap()
+        };
+        let f = new_func(&mut builder, "f", v4f32, &[v4f32, v4f32]);
+        let (a, b) = (ValueSSA::FuncArg(f, 0), ValueSSA::FuncArg(f, 1));
+        let first = call(&mut builder, &[b, a]);
+        let second = call(&mut builder, &[first, a]);
+        ret(&mut builder, second);
+
+        // `a` 跨过第一个调用, 整个 128 位存进栈槽; 向量寄存器之间用 `mov` 的向量形式复制.
+        let asm = compile(&mut builder.module);
+        assert!(asm.contains("\tstr\tq"), "{asm}");
+        assert!(asm.contains("\tldr\tq"), "{asm}");
+        assert!(asm.contains(".16b, v"), "{asm}");
+        assert!(!asm.contains("fmov\td"), "{asm}");
+    }
+
+    #[test]
+    fn test_vreg_rejected() {
+        let mut module = test_case_minmax().module;
+        let mir = select_module(&mut module).unwrap();
+        assert!(matches!(emit_asm(&mir), Err(AsmEmitErr::VRegLeft(_))));
+    }
+}
@@ -0,0 +1,91 @@
+//! 序言、尾声、寄存器保存和动态栈分配. 栈帧布局见 `mir::frame`.
+//! 栈槽的基址寄存器不属于 `MirSaveRegs`, 由序言保存、尾声恢复.
+
+use crate::mir::{
+    MirDynAlloca, MirRestoreRegs, MirSaveRegs, PReg,
+    asm::{AsmEmitRes, func::FuncEmitter},
+};
+
+/// 重新对齐 `sp` 时的临时寄存器. 序言和动态栈分配里 `x17` 可能被 `add_imm` 占用, 所以用 `x16`.
+const ALIGN_TMP: PReg = PReg::x(16);
+
+impl FuncEmitter<'_> {
+    pub(super) fn emit_prologue(&mut self) -> AsmEmitRes {
+        self.ins(format_args!("stp\tx29, x30, [sp, #-16]!"))?;
+        self.ins(format_args!("mov\tx29, sp"))?;
+        let size = self.frame.size as i64;
+        if !self.frame.needs_realign() {
+            return self.add_imm(PReg::SP, PReg::SP, -size);
+        }
+        let mask = !((1u64 << self.frame.align_log2) - 1);
+        self.add_imm(ALIGN_TMP, PReg::SP, -size)?;
+        self.ins(format_args!(
+            "and\tsp, {}, #{mask:#x}",
+            ALIGN_TMP.get_name()
+        ))?;
+        // 后面还有动态栈分配, 把对齐之后的 `sp` 留在基址寄存器里访问栈槽
+        let Some(base) = self.frame.base_reg else {
+            return Ok(());
+        };
+        let (base, offset) = (base.get_name(), self.frame.save_offset(base));
+        self.ins(format_args!("stur\t{base}, [x29, #{offset}]"))?;
+        self.ins(format_args!("mov\t{base}, sp"))
+    }
+    pub(super) fn emit_epilogue(&mut self) -> AsmEmitRes {
+        if let Some(base) = self.frame.base_reg {
+            let offset = self.frame.save_offset(base);
+            self.ins(format_args!("ldur\t{}, [x29, #{offset}]", base.get_name()))?;
+        }
+        let frame = &self.frame;
+        if frame.size != 0 || frame.has_dyn_alloca || frame.needs_realign() {
+            self.ins(format_args!("mov\tsp, x29"))?;
+        }
+        self.ins(format_args!("ldp\tx29, x30, [sp], #16"))?;
+        self.ins(format_args!("ret"))
+    }
+
+    pub(super) fn emit_save_regs(&mut self, save: &MirSaveRegs) -> AsmEmitRes {
+        let regs: Vec<PReg> = save.regs().collect();
+        for (first, second, offset) in self.frame.pairs(&regs) {
+            match second {
+                Some(second) => self.ins(format_args!(
+                    "stp\t{}, {}, [x29, #{offset}]",
+                    first.get_name(),
+                    second.get_name()
+                ))?,
+                None => self.ins(format_args!("stur\t{}, [x29, #{offset}]", first.get_name()))?,
+            }
+        }
+        Ok(())
+    }
+    pub(super) fn emit_restore_regs(&mut self, restore: &MirRestoreRegs) -> AsmEmitRes {
+        let regs: Vec<PReg> = restore.regs().collect();
+        for (first, second, offset) in self.frame.pairs(&regs) {
+            match second {
+                Some(second) => self.ins(format_args!(
+                    "ldp\t{}, {}, [x29, #{offset}]",
+                    first.get_name(),
+                    second.get_name()
+                ))?,
+                None => self.ins(format_args!("ldur\t{}, [x29, #{offset}]", first.get_name()))?,
+            }
+        }
+        Ok(())
+    }
+
+    /// `MirDynAlloca`: 先在 `sp` 之下划出 `size` 字节并对齐得到新内存的地址,
+    /// 再在它下面留出出参区作为新的 `sp`.
+    pub(super) fn emit_dyn_alloca(&mut self, alloca: &MirDynAlloca) -> AsmEmitRes {
+        let (rd, size) = (self.reg(alloca.get_rd())?, self.reg(alloca.get_size())?);
+        let align = 1u64 << alloca.get_align_log2().max(4);
+        let tmp = ALIGN_TMP.get_name();
+        self.ins(format_args!("sub\t{tmp}, sp, {}", size.get_name()))?;
+        self.ins(format_args!(
+            "and\t{}, {tmp}, #{:#x}",
+            rd.get_name(),
+            !(align - 1)
+        ))?;
+        let outgoing = self.frame.outgoing_size.next_multiple_of(align);
+        self.add_imm(PReg::SP, rd, -(outgoing as i64))
+    }
+}
@@ -0,0 +1,233 @@
+//! 单个函数的输出: 符号声明、基本块、常量池和跳转表.
+
+use crate::mir::{
+    MirBlockID, MirFrameLayout, MirFunc, MirGlobalID, MirModule, MirOP, MirOperand, MirRegClass,
+    PReg,
+    asm::{AsmEmitErr, AsmEmitRes},
+    is_calc_imm, is_mov_imm,
+};
+use std::{
+    collections::HashMap,
+    fmt::{Arguments, Write},
+};
+
+/// 伪指令展开时使用的临时寄存器. 并行复制只用 `x16`, 所以 `x17` 在展开任何指令时都是空闲的.
+pub(super) const SCRATCH: PReg = PReg::x(17);
+
+/// 常量池里的一项, 都占 8 字节.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum PoolEntry {
+    Imm(u64),
+    Symbol(String),
+}
+
+pub(super) struct FuncEmitter<'a> {
+    pub module: &'a MirModule,
+    pub func: &'a MirFunc,
+    pub func_id: MirGlobalID,
+    pub frame: MirFrameLayout,
+    out: &'a mut dyn Write,
+    block_index: HashMap<MirBlockID, usize>,
+    pool: Vec<PoolEntry>,
+    /// 跳转表的各项, 依次对应下标 `0, 1, 2, ...`.
+    jump_tables: Vec<Vec<MirBlockID>>,
+    /// 正在输出的指令, 用于报错.
+    pub curr_op: MirOP,
+}
+
+impl<'a> FuncEmitter<'a> {
+    pub fn new(
+        module: &'a MirModule,
+        func_id: MirGlobalID,
+        out: &'a mut dyn Write,
+    ) -> AsmEmitRes<Self> {
+        let func = module.get_func(func_id);
+        let block_index = func
+            .blocks_iter(&module.allocs)
+            .enumerate()
+            .map(|(i, (bb, _))| (bb, i))
+            .collect();
+        Ok(Self {
+            module,
+            func,
+            func_id,
+            frame: MirFrameLayout::new(func, &module.allocs),
+            out,
+            block_index,
+            pool: Vec::new(),
+            jump_tables: Vec::new(),
+            curr_op: MirOP::MirComment,
+        })
+    }
+
+    pub fn run(mut self) -> AsmEmitRes {
+        let (module, func) = (self.module, self.func);
+        let (name, allocs) = (&func.name, &module.allocs);
+        writeln!(self.out, "\t.text")?;
+        if func.exported {
+            writeln!(self.out, "\t.globl\t{name}")?;
+        }
+        writeln!(self.out, "\t.p2align\t2")?;
+        writeln!(self.out, "\t.type\t{name},@function")?;
+        writeln!(self.out, "{name}:")?;
+        self.emit_prologue()?;
+        for (bb, _) in func.blocks_iter(allocs) {
+            let label = self.block_label(bb);
+            writeln!(self.out, "{label}:\t// {}", bb.get_name(allocs))?;
+            for (_, inst) in bb.insts_iter(allocs) {
+                self.emit_inst(inst.get_inst())?;
+            }
+        }
+        self.write_jump_tables()?;
+        self.write_pool()?;
+        let end = format!(".Lfunc_end{}", self.func_id.0);
+        writeln!(self.out, "{end}:")?;
+        writeln!(self.out, "\t.size\t{name}, {end}-{name}")?;
+        Ok(())
+    }
+
+    fn write_jump_tables(&mut self) -> AsmEmitRes {
+        if self.jump_tables.is_empty() {
+            return Ok(());
+        }
+        writeln!(self.out, "\t.p2align\t2")?;
+        for (i, targets) in self.jump_tables.iter().enumerate() {
+            let table = format!(".LJTI{}_{i}", self.func_id.0);
+            writeln!(self.out, "{table}:")?;
+            for &bb in targets {
+                writeln!(self.out, "\t.word\t{}-{table}", self.block_label(bb))?;
+            }
+        }
+        Ok(())
+    }
+    fn write_pool(&mut self) -> AsmEmitRes {
+        if self.pool.is_empty() {
+            return Ok(());
+        }
+        writeln!(self.out, "\t.p2align\t3")?;
+        for (i, entry) in self.pool.iter().enumerate() {
+            writeln!(self.out, ".LCPI{}_{i}:", self.func_id.0)?;
+            match entry {
+                PoolEntry::Imm(imm) => writeln!(self.out, "\t.xword\t{imm:#x}")?,
+                PoolEntry::Symbol(sym) => writeln!(self.out, "\t.xword\t{sym}")?,
+            }
+        }
+        Ok(())
+    }
+
+    /// 写出一行指令, `text` 不含缩进和换行.
+    pub fn ins(&mut self, text: Arguments) -> AsmEmitRes {
+        writeln!(self.out, "\t{text}")?;
+        Ok(())
+    }
+    pub fn comment(&mut self, text: &str) -> AsmEmitRes {
+        writeln!(self.out, "\t// {text}")?;
+        Ok(())
+    }
+
+    pub fn block_label(&self, bb: MirBlockID) -> String {
+        format!(".LBB{}_{}", self.func_id.0, self.block_index[&bb])
+    }
+    /// 登记一个新的跳转表, 返回它的标签.
+    pub fn add_jump_table(&mut self, targets: Vec<MirBlockID>) -> String {
+        self.jump_tables.push(targets);
+        format!(".LJTI{}_{}", self.func_id.0, self.jump_tables.len() - 1)
+    }
+    /// 常量池中 `entry` 的标签. 相同的常量只放一份.
+    pub fn pool_label(&mut self, entry: PoolEntry) -> String {
+        let index = match self.pool.iter().position(|e| *e == entry) {
+            Some(index) => index,
+            None => {
+                self.pool.push(entry);
+                self.pool.len() - 1
+            }
+        };
+        format!(".LCPI{}_{index}", self.func_id.0)
+    }
+
+    pub fn invalid(&self, op: MirOperand) -> AsmEmitErr {
+        AsmEmitErr::InvalidOperand(self.func.name.clone(), self.curr_op, op)
+    }
+    pub fn unsupported(&self) -> AsmEmitErr {
+        AsmEmitErr::UnsupportedInst(self.func.name.clone(), self.curr_op)
+    }
+    pub fn reg(&self, op: MirOperand) -> AsmEmitRes<PReg> {
+        match op {
+            MirOperand::PReg(preg) => Ok(preg),
+            MirOperand::VReg(_) => Err(AsmEmitErr::VRegLeft(self.func.name.clone())),
+            _ => Err(self.invalid(op)),
+        }
+    }
+    pub fn imm(&self, op: MirOperand) -> AsmEmitRes<i64> {
+        op.as_imm().ok_or_else(|| self.invalid(op))
+    }
+    /// 可以直接写进汇编的符号: 全局量名字或者基本块标签.
+    pub fn symbol(&self, op: MirOperand) -> AsmEmitRes<String> {
+        match op {
+            MirOperand::Global(global) => Ok(self.module.get_global(global).get_name().to_string()),
+            MirOperand::Label(bb) => Ok(self.block_label(bb)),
+            _ => Err(self.invalid(op)),
+        }
+    }
+
+    /// 把 `imm` 写进 `dst`. 32 位寄存器只取低 32 位.
+    pub fn mov_imm(&mut self, dst: PReg, imm: u64) -> AsmEmitRes {
+        let name = dst.get_name();
+        if dst.get_class() == MirRegClass::GPR32 {
+            let imm = imm as u32;
+            if is_mov_imm(imm as u64, 32) {
+                return self.ins(format_args!("mov\t{name}, #{}", imm as i32));
+            }
+            self.ins(format_args!("movz\t{name}, #{}", imm & 0xffff))?;
+            return self.ins(format_args!("movk\t{name}, #{}, lsl #16", imm >> 16));
+        }
+        if is_mov_imm(imm, 64) {
+            return self.ins(format_args!("mov\t{name}, #{}", imm as i64));
+        }
+        if is_mov_imm(imm, 32) {
+            // 写 32 位寄存器会把高 32 位清零.
+            return self.mov_imm(dst.with_class(MirRegClass::GPR32), imm);
+        }
+        let mut first = true;
+        for shift in (0..64).step_by(16) {
+            let chunk = (imm >> shift) & 0xffff;
+            if chunk == 0 {
+                continue;
+            }
+            let op = if first { "movz" } else { "movk" };
+            self.ins(format_args!("{op}\t{name}, #{chunk}, lsl #{shift}"))?;
+            first = false;
+        }
+        Ok(())
+    }
+
+    /// `dst = src + imm`. 立即数放不进 `add`/`sub` 时借用 `SCRATCH`.
+    pub fn add_imm(&mut self, dst: PReg, src: PReg, imm: i64) -> AsmEmitRes {
+        let (dst_name, src_name) = (dst.get_name(), src.get_name());
+        if imm == 0 {
+            if dst != src {
+                self.ins(format_args!("mov\t{dst_name}, {src_name}"))?;
+            }
+            return Ok(());
+        }
+        let (op, abs) = if imm < 0 { ("sub", imm.unsigned_abs()) } else { ("add", imm as u64) };
+        if is_calc_imm(abs as i64) {
+            return self.ins(format_args!("{op}\t{dst_name}, {src_name}, #{abs}"));
+        }
+        if abs < 1 << 24 {
+            self.ins(format_args!(
+                "{op}\t{dst_name}, {src_name}, #{}, lsl #12",
+                abs >> 12
+            ))?;
+            return self.ins(format_args!(
+                "{op}\t{dst_name}, {dst_name}, #{}",
+                abs & 0xfff
+            ));
+        }
+        self.mov_imm(SCRATCH, abs)?;
+        self.ins(format_args!(
+            "{op}\t{dst_name}, {src_name}, {}",
+            SCRATCH.get_name()
+        ))
+    }
+}
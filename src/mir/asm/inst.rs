@@ -0,0 +1,326 @@
+//! 逐条指令输出. 大多数机器指令的汇编形式就是助记符加上按 "输出, 输入" 顺序排列的操作数,
+//! 由 `emit_plain` 统一处理; 其余指令和伪指令各自展开.
+
+use crate::mir::{
+    MirCondFlag, MirInst, MirOP, MirOperand, MirRegClass, NZCV, PReg,
+    asm::{AsmEmitErr, AsmEmitRes, func::FuncEmitter},
+};
+use std::cell::Cell;
+
+impl FuncEmitter<'_> {
+    pub(super) fn emit_inst(&mut self, inst: &MirInst) -> AsmEmitRes {
+        use MirInst as I;
+        self.curr_op = inst.get_opcode();
+        let ops: Vec<MirOperand> = inst.operands().iter().map(Cell::get).collect();
+        if ops.iter().any(|op| matches!(op, MirOperand::VReg(_))) {
+            return Err(AsmEmitErr::VRegLeft(self.func.name.clone()));
+        }
+        match inst {
+            I::MirComment(comment) => self.comment(comment.get_text()),
+            I::MirCommentedInst(commented) => {
+                self.comment(commented.get_comment())?;
+                self.emit_inst(commented.get_inner())
+            }
+
+            /* 跳转 */
+            I::CondBr(br) => {
+                let mn = if self.curr_op == MirOP::BCCond { "bc" } else { "b" };
+                let label = self.symbol(br.get_label())?;
+                self.ins(format_args!("{mn}.{}\t{label}", br.get_cond().get_name()))
+            }
+            I::UncondBr(br) => {
+                let label = self.symbol(br.get_target())?;
+                self.ins(format_args!("b\t{label}"))
+            }
+            I::BReg(br) => {
+                let target = self.reg(br.get_target())?;
+                match self.curr_op {
+                    MirOP::Ret if target == PReg::x(30) => self.ins(format_args!("ret")),
+                    MirOP::Ret => self.ins(format_args!("ret\t{}", target.get_name())),
+                    _ => self.ins(format_args!("br\t{}", target.get_name())),
+                }
+            }
+            I::BLinkLabel(_) | I::BLinkGlobal(_) => {
+                let target = self.symbol(ops[1])?;
+                self.ins(format_args!("bl\t{target}"))
+            }
+            I::BLinkReg(bl) => {
+                let target = self.reg(bl.get_target())?;
+                self.ins(format_args!("blr\t{}", target.get_name()))
+            }
+            I::CBZs(cbz) => {
+                let mn = if self.curr_op == MirOP::CBZ { "cbz" } else { "cbnz" };
+                let cond = self.reg(cbz.get_cond())?;
+                let label = self.symbol(cbz.get_target())?;
+                self.ins(format_args!("{mn}\t{}, {label}", cond.get_name()))
+            }
+            I::TBZ64(_) | I::TBZ32(_) => {
+                let mn = match self.curr_op {
+                    MirOP::TBZ64 | MirOP::TBZ32 => "tbz",
+                    _ => "tbnz",
+                };
+                let (cond, bit) = (self.reg(ops[0])?, self.imm(ops[1])?);
+                let label = self.symbol(ops[2])?;
+                self.ins(format_args!("{mn}\t{}, #{bit}, {label}", cond.get_name()))
+            }
+            I::MirCall(call) => match call.get_callee() {
+                MirOperand::PReg(callee) => self.ins(format_args!("blr\t{}", callee.get_name())),
+                callee => {
+                    let callee = self.symbol(callee)?;
+                    self.ins(format_args!("bl\t{callee}"))
+                }
+            },
+            I::MirReturn(_) => self.emit_epilogue(),
+            I::MirSwitch(switch) => self.emit_switch(switch),
+            I::MirDynAlloca(alloca) => self.emit_dyn_alloca(alloca),
+
+            /* 带附加属性的算术指令 */
+            I::ICmp64R(i) => self.emit_plain(&ops, i.get_rm_op().map(|op| op.to_string())),
+            I::ICmp32R(i) => self.emit_plain(&ops, i.get_rm_op().map(|op| op.to_string())),
+            I::Bin64R(i) => self.emit_plain(&ops, i.get_rm_op().map(|op| op.to_string())),
+            I::Bin32R(i) => self.emit_plain(&ops, i.get_rm_op().map(|op| op.to_string())),
+            I::Una64R(_) | I::Una32R(_) | I::UnaF64(_) | I::UnaF32(_)
+                if is_stack_pos(self.curr_op) =>
+            {
+                Err(self.unsupported())
+            }
+            I::Una64R(i) => self.emit_plain(&ops, i.get_dst_op().map(|op| op.to_string())),
+            I::Una32R(i) => self.emit_plain(&ops, i.get_dst_op().map(|op| op.to_string())),
+            I::ICCmp64R(i) => self.emit_plain(&ops, Some(ccmp_suffix(i.get_nzcv(), i.get_cond()))),
+            I::ICCmp32R(i) => self.emit_plain(&ops, Some(ccmp_suffix(i.get_nzcv(), i.get_cond()))),
+            I::ICCmp64I(i) => self.emit_plain(&ops, Some(ccmp_suffix(i.get_nzcv(), i.get_cond()))),
+            I::ICCmp32I(i) => self.emit_plain(&ops, Some(ccmp_suffix(i.get_nzcv(), i.get_cond()))),
+            I::FCCmp32(i) => self.emit_plain(&ops, Some(ccmp_suffix(i.get_nzcv(), i.get_cond()))),
+            I::FCCmp64(i) => self.emit_plain(&ops, Some(ccmp_suffix(i.get_nzcv(), i.get_cond()))),
+            I::CSel64(i) => self.emit_plain(&ops, Some(i.get_cond().get_name().into())),
+            I::CSel32(i) => self.emit_plain(&ops, Some(i.get_cond().get_name().into())),
+            I::CSelF64(i) => self.emit_plain(&ops, Some(i.get_cond().get_name().into())),
+            I::CSelF32(i) => self.emit_plain(&ops, Some(i.get_cond().get_name().into())),
+            I::CSet64(i) => self.emit_plain(&ops, Some(i.get_cond().get_name().into())),
+            I::CSet32(i) => self.emit_plain(&ops, Some(i.get_cond().get_name().into())),
+
+            /* 形式特殊的算术指令 */
+            I::ExtR(_) if matches!(self.curr_op, MirOP::UXTB64 | MirOP::UXTH64) => {
+                // `uxtb`/`uxth` 只有 32 位形式, 写 32 位寄存器会清零高 32 位.
+                let rd = self.reg(ops[0])?.with_class(MirRegClass::GPR32);
+                self.emit_plain(&[rd.into(), ops[1]], None)
+            }
+            I::Bin64RL(_) | I::Bin32RL(_) => self.emit_logic_imm(&ops),
+            I::Bin64RSym(_) => self.emit_add_symbol(&ops),
+            I::Mov64I(_) | I::Mov32I(_) => {
+                let (rd, imm) = (self.reg(ops[0])?, self.imm(ops[1])?);
+                self.mov_imm(rd, imm as u64)
+            }
+            I::MovZNK64(_) | I::MovZNK32(_) => {
+                let (rd, imm) = (self.reg(ops[0])?, self.imm(ops[1])? as u64);
+                let shift = (0..64)
+                    .step_by(16)
+                    .find(|s| imm >> s != 0 && imm >> s <= 0xffff);
+                let shift = shift.unwrap_or(0);
+                let mn = mnemonic(self.curr_op);
+                let rd = rd.get_name();
+                self.ins(format_args!("{mn}\t{rd}, #{}, lsl #{shift}", imm >> shift))
+            }
+            I::Adr(_) => {
+                let (rd, sym) = (self.reg(ops[0])?, self.symbol(ops[1])?);
+                let mn = if self.curr_op == MirOP::AdrP { "adrp" } else { "adr" };
+                self.ins(format_args!("{mn}\t{}, {sym}", rd.get_name()))
+            }
+            I::FMov64I(_) | I::FMov32I(_) => {
+                let (rd, bits) = (self.reg(ops[0])?, self.imm(ops[1])?);
+                let value = match rd.get_class() {
+                    MirRegClass::FPR32 => f32::from_bits(bits as u32) as f64,
+                    _ => f64::from_bits(bits as u64),
+                };
+                self.ins(format_args!("fmov\t{}, #{value:?}", rd.get_name()))
+            }
+
+            /* 访存 */
+            I::LoadGr64(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::LoadGr32(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::LoadF64(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::LoadF32(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::StoreGr64(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::StoreGr32(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::StoreF64(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::StoreF32(i) => self.emit_mem_reg(&ops, i.get_rm_op()),
+            I::LoadGr64Base(_)
+            | I::LoadGr32Base(_)
+            | I::LoadF128Base(_)
+            | I::LoadF64Base(_)
+            | I::LoadF32Base(_)
+            | I::LoadGr64BaseS(_)
+            | I::LoadGr32BaseS(_)
+            | I::LoadF128BaseS(_)
+            | I::LoadF64BaseS(_)
+            | I::LoadF32BaseS(_)
+            | I::StoreGr64Base(_)
+            | I::StoreGr32Base(_)
+            | I::StoreF128Base(_)
+            | I::StoreF64Base(_)
+            | I::StoreF32Base(_)
+            | I::StoreGr64BaseS(_)
+            | I::StoreGr32BaseS(_)
+            | I::StoreF128BaseS(_)
+            | I::StoreF64BaseS(_)
+            | I::StoreF32BaseS(_) => self.emit_mem_offset(&ops),
+            I::LoadGr64Indexed(i) => {
+                self.emit_mem_indexed(ops[0], ops[1], ops[2], i.get_addr_mode())
+            }
+            I::LoadGr32Indexed(i) => {
+                self.emit_mem_indexed(ops[0], ops[1], ops[2], i.get_addr_mode())
+            }
+            I::LoadF64Indexed(i) => {
+                self.emit_mem_indexed(ops[0], ops[1], ops[2], i.get_addr_mode())
+            }
+            I::LoadF32Indexed(i) => {
+                self.emit_mem_indexed(ops[0], ops[1], ops[2], i.get_addr_mode())
+            }
+            I::StoreGr64Indexed(i) => {
+                self.emit_mem_indexed(ops[1], ops[0], ops[2], i.get_addr_mode())
+            }
+            I::StoreGr32Indexed(i) => {
+                self.emit_mem_indexed(ops[1], ops[0], ops[2], i.get_addr_mode())
+            }
+            I::StoreF64Indexed(i) => {
+                self.emit_mem_indexed(ops[1], ops[0], ops[2], i.get_addr_mode())
+            }
+            I::StoreF32Indexed(i) => {
+                self.emit_mem_indexed(ops[1], ops[0], ops[2], i.get_addr_mode())
+            }
+            I::LoadGr64Literal(_)
+            | I::LoadGr32Literal(_)
+            | I::LoadF64Literal(_)
+            | I::LoadF32Literal(_) => self.emit_load_literal(&ops),
+
+            /* 伪指令 */
+            I::MirLdrLitG64(_) | I::MirLdrLitG32(_) | I::MirLdrLitF64(_) | I::MirLdrLitF32(_) => {
+                self.emit_mir_load_literal(&ops)
+            }
+            I::MirStrLitG64(_) | I::MirStrLitG32(_) | I::MirStrLitF64(_) | I::MirStrLitF32(_) => {
+                self.emit_mir_store_literal(&ops)
+            }
+            I::MirStImm64(_) | I::MirStSym64(_) | I::MirStImm32(_) => self.emit_store_imm(&ops),
+            I::MirStImm64Sym(_) | I::MirStImm32Sym(_) | I::MirStSym64Sym(_) => {
+                self.emit_store_imm_symbol(&ops)
+            }
+            I::LoadConst64(_) | I::LoadConst64Symbol(_) => self.emit_load_const(&ops),
+            I::MirLdImmF64(_) | I::MirLdImmF32(_) => self.emit_load_fimm(&ops),
+            I::MirCopy64(_)
+            | I::MirCopy32(_)
+            | I::MirFCopy128(_)
+            | I::MirFCopy64(_)
+            | I::MirFCopy32(_)
+            | I::MirPCopy(_) => self.emit_copy(ops[0], ops[1]),
+            I::MirGEP(gep) => self.emit_gep(gep),
+            I::MirSaveRegs(save) => self.emit_save_regs(save),
+            I::MirRestoreRegs(restore) => self.emit_restore_regs(restore),
+            I::MirRestoreHostRegs(_) => Err(self.unsupported()),
+
+            _ => self.emit_plain(&ops, None),
+        }
+    }
+
+    /// 助记符后面依次写出所有非 PState 操作数, 最后是附加的移位、条件码等.
+    fn emit_plain(&mut self, ops: &[MirOperand], suffix: Option<String>) -> AsmEmitRes {
+        let mut args = Vec::with_capacity(ops.len() + 1);
+        for &op in ops {
+            match op {
+                MirOperand::PReg(reg) if reg.get_class() == MirRegClass::PState => {}
+                MirOperand::PReg(reg) => args.push(reg.get_name()),
+                MirOperand::Imm(imm) => args.push(format!("#{imm}")),
+                _ => return Err(self.invalid(op)),
+            }
+        }
+        args.extend(suffix);
+        let mn = mnemonic(self.curr_op);
+        self.ins(format_args!("{mn}\t{}", args.join(", ")))
+    }
+
+    /// 位掩码立即数的逻辑运算. `bic`/`eon`/`orn` 没有立即数形式, 换成对取反的立即数做 `and`/`eor`/`orr`.
+    fn emit_logic_imm(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (rd, rn, imm) = (
+            self.reg(ops[0])?,
+            self.reg(ops[1])?,
+            self.imm(ops[2])? as u64,
+        );
+        let mask = match rd.get_class() {
+            MirRegClass::GPR32 => u32::MAX as u64,
+            _ => u64::MAX,
+        };
+        let (mn, imm) = match self.curr_op {
+            MirOP::And64I | MirOP::And32I => ("and", imm),
+            MirOP::EOR64I | MirOP::EOR32I => ("eor", imm),
+            MirOP::ORR64I | MirOP::ORR32I => ("orr", imm),
+            MirOP::Bic64I | MirOP::Bic32I => ("and", !imm),
+            MirOP::EON64I | MirOP::EON32I => ("eor", !imm),
+            _ => ("orr", !imm),
+        };
+        let (rd, rn) = (rd.get_name(), rn.get_name());
+        self.ins(format_args!("{mn}\t{rd}, {rn}, #{:#x}", imm & mask))
+    }
+
+    /// `add rd, rn, <symbol>`: 全局量取页内偏移, 栈槽取帧内偏移.
+    fn emit_add_symbol(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (rd, rn) = (self.reg(ops[0])?, self.reg(ops[1])?);
+        let is_sub = self.curr_op == MirOP::Sub64Sym;
+        match ops[2] {
+            MirOperand::StackSlot(slot) => {
+                let (rn, offset) = self.frame.slot_base(rn, slot);
+                self.add_imm(rd, rn, if is_sub { -offset } else { offset })
+            }
+            MirOperand::Global(_) if !is_sub => {
+                let sym = self.symbol(ops[2])?;
+                let (rd, rn) = (rd.get_name(), rn.get_name());
+                self.ins(format_args!("add\t{rd}, {rn}, :lo12:{sym}"))
+            }
+            op => Err(self.invalid(op)),
+        }
+    }
+}
+
+fn is_stack_pos(op: MirOP) -> bool {
+    use MirOP::*;
+    matches!(
+        op,
+        LoadStackPosGr64
+            | StoreStackPosGr64
+            | LoadStackPosGr32
+            | StoreStackPosGr32
+            | LoadStackPosF64
+            | StoreStackPosF64
+            | LoadStackPosF32
+            | StoreStackPosF32
+    )
+}
+
+fn ccmp_suffix(nzcv: NZCV, cond: MirCondFlag) -> String {
+    format!("#{}, {}", nzcv.bits(), cond.get_name())
+}
+
+/// 汇编助记符. 一般是操作码名去掉位宽等后缀之后的小写形式, 例如 `Add64R` -> `add`.
+pub(super) fn mnemonic(op: MirOP) -> String {
+    use MirOP::*;
+    let name = match op {
+        ICmp64R | ICmp32R | ICmp64I | ICmp32I => "cmp",
+        ICmn64R | ICmn32R | ICmn64I | ICmn32I => "cmn",
+        ICCmp64R | ICCmp32R | ICCmp64I | ICCmp32I => "ccmp",
+        ICCmn64R | ICCmn32R | ICCmn64I | ICCmn32I => "ccmn",
+        FMovFG64 | FMovGF64 | FMovFG32 | FMovGF32 | FMov64R | FMov32R => "fmov",
+        CSelF64 | CSelF32 => "fcsel",
+        FRInt32X64 | FRInt32X32 => "frint32x",
+        FRIntZ32X64 | FRIntZ32X32 => "frint32z",
+        FRInt64X64 | FRInt64X32 => "frint64x",
+        FRIntZ64X64 | FRIntZ64X32 => "frint64z",
+        // 32 位寄存器的符号扩展到 32 位就是复制.
+        SXTW32 => "mov",
+        _ => {
+            let name = op.get_name();
+            let end = name
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(name.len());
+            return name[..end].to_lowercase();
+        }
+    };
+    name.to_string()
+}
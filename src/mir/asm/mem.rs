@@ -0,0 +1,170 @@
+//! 访存指令和访存伪指令. 立即数偏移按范围依次选用缩放偏移、`ldur`/`stur` 非缩放偏移,
+//! 放不下时把偏移量写进 `SCRATCH` 用寄存器偏移寻址.
+
+use crate::mir::{
+    AddrMode, MirOP, MirOperand, MirRegClass, PReg, RegOP,
+    asm::{
+        AsmEmitRes,
+        func::{FuncEmitter, SCRATCH},
+    },
+};
+
+/// 访存指令的助记符 (`ldr`, `ldrsb`, `strh`...), 访问宽度的 log2 和实际写出的数据寄存器.
+fn access_of(op: MirOP, rd: PReg) -> (String, u32, PReg) {
+    let (prefix, rest) = op.get_name().split_at(3);
+    let (suffix, scale) = if rest.starts_with("SB") {
+        ("sb", 0)
+    } else if rest.starts_with("SH") {
+        ("sh", 1)
+    } else if rest.starts_with('B') {
+        ("b", 0)
+    } else if rest.starts_with('H') {
+        ("h", 1)
+    } else {
+        ("", rd.get_class().get_bits().ilog2() - 3)
+    };
+    // 零扩展的窄访存只有 32 位寄存器形式.
+    let rd = match suffix {
+        "b" | "h" => rd.with_class(MirRegClass::GPR32),
+        _ => rd,
+    };
+    (format!("{}{suffix}", prefix.to_lowercase()), scale, rd)
+}
+
+impl FuncEmitter<'_> {
+    /// `[rn, rm{, rm_op}]`
+    pub(super) fn emit_mem_reg(&mut self, ops: &[MirOperand], rm_op: Option<RegOP>) -> AsmEmitRes {
+        let (rd, rn, rm) = (self.reg(ops[0])?, self.reg(ops[1])?, self.reg(ops[2])?);
+        let (mn, _, rd) = access_of(self.curr_op, rd);
+        let (rd, rn, rm) = (rd.get_name(), rn.get_name(), rm.get_name());
+        match rm_op {
+            Some(rm_op) => self.ins(format_args!("{mn}\t{rd}, [{rn}, {rm}, {rm_op}]")),
+            None => self.ins(format_args!("{mn}\t{rd}, [{rn}, {rm}]")),
+        }
+    }
+
+    /// `[rn, #imm]` 或 `[rn, <symbol>]`
+    pub(super) fn emit_mem_offset(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (rd, rn) = (self.reg(ops[0])?, self.reg(ops[1])?);
+        let (mn, scale, rd) = access_of(self.curr_op, rd);
+        match ops[2] {
+            MirOperand::Imm(offset) => self.emit_mem_imm(&mn, scale, rd, rn, offset),
+            MirOperand::StackSlot(slot) => {
+                let (rn, offset) = self.frame.slot_base(rn, slot);
+                self.emit_mem_imm(&mn, scale, rd, rn, offset)
+            }
+            MirOperand::Global(_) => {
+                let sym = self.symbol(ops[2])?;
+                let (rd, rn) = (rd.get_name(), rn.get_name());
+                self.ins(format_args!("{mn}\t{rd}, [{rn}, :lo12:{sym}]"))
+            }
+            op => Err(self.invalid(op)),
+        }
+    }
+
+    /// 基址加立即数偏移的访存, 宽度为 `1 << scale` 字节.
+    pub(super) fn emit_mem_imm(
+        &mut self,
+        mn: &str,
+        scale: u32,
+        rd: PReg,
+        rn: PReg,
+        offset: i64,
+    ) -> AsmEmitRes {
+        let (rd_name, rn_name) = (rd.get_name(), rn.get_name());
+        let size = 1i64 << scale;
+        if offset == 0 {
+            self.ins(format_args!("{mn}\t{rd_name}, [{rn_name}]"))
+        } else if offset > 0 && offset % size == 0 && offset / size <= 4095 {
+            self.ins(format_args!("{mn}\t{rd_name}, [{rn_name}, #{offset}]"))
+        } else if (-256..256).contains(&offset) {
+            let (head, tail) = mn.split_at(2);
+            self.ins(format_args!(
+                "{head}u{tail}\t{rd_name}, [{rn_name}, #{offset}]"
+            ))
+        } else {
+            self.mov_imm(SCRATCH, offset as u64)?;
+            let scratch = SCRATCH.get_name();
+            self.ins(format_args!("{mn}\t{rd_name}, [{rn_name}, {scratch}]"))
+        }
+    }
+
+    /// 前变址 `[rn, #imm]!` 和后变址 `[rn], #imm`.
+    pub(super) fn emit_mem_indexed(
+        &mut self,
+        rd: MirOperand,
+        rn: MirOperand,
+        rm: MirOperand,
+        mode: AddrMode,
+    ) -> AsmEmitRes {
+        let (rd, rn, offset) = (self.reg(rd)?, self.reg(rn)?, self.imm(rm)?);
+        let (mn, _, rd) = access_of(self.curr_op, rd);
+        let (rd, rn) = (rd.get_name(), rn.get_name());
+        match mode {
+            AddrMode::PreIndex => self.ins(format_args!("{mn}\t{rd}, [{rn}, #{offset}]!")),
+            AddrMode::PostIndex => self.ins(format_args!("{mn}\t{rd}, [{rn}], #{offset}")),
+        }
+    }
+
+    /// PC 相对的字面量读取. 窄访存没有字面量形式, 先用 `adr` 求出地址.
+    pub(super) fn emit_load_literal(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (rd, sym) = (self.reg(ops[0])?, self.symbol(ops[1])?);
+        let (mn, _, rd) = access_of(self.curr_op, rd);
+        let rd = rd.get_name();
+        if mn == "ldr" {
+            return self.ins(format_args!("ldr\t{rd}, {sym}"));
+        }
+        let scratch = SCRATCH.get_name();
+        self.ins(format_args!("adr\t{scratch}, {sym}"))?;
+        self.ins(format_args!("{mn}\t{rd}, [{scratch}]"))
+    }
+
+    /// `MirLdrLit*`: `adrp` 求出符号所在的页, 再按页内偏移读取.
+    pub(super) fn emit_mir_load_literal(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (dst, tmp, sym) = (self.reg(ops[0])?, self.reg(ops[1])?, self.symbol(ops[2])?);
+        let (dst, tmp) = (dst.get_name(), tmp.get_name());
+        self.ins(format_args!("adrp\t{tmp}, {sym}"))?;
+        self.ins(format_args!("ldr\t{dst}, [{tmp}, :lo12:{sym}]"))
+    }
+    /// `MirStrLit*`: 同 `MirLdrLit*`, 方向相反.
+    pub(super) fn emit_mir_store_literal(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (tmp, rd, sym) = (self.reg(ops[0])?, self.reg(ops[1])?, self.symbol(ops[2])?);
+        let (rd, tmp) = (rd.get_name(), tmp.get_name());
+        self.ins(format_args!("adrp\t{tmp}, {sym}"))?;
+        self.ins(format_args!("str\t{rd}, [{tmp}, :lo12:{sym}]"))
+    }
+
+    /// `MirStImm*`/`MirStSym64`: 先把立即数或者符号地址写进临时寄存器, 再存到 `[base, #offset]`.
+    pub(super) fn emit_store_imm(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (tmp, base, offset) = (self.reg(ops[0])?, self.reg(ops[2])?, self.imm(ops[3])?);
+        let tmp = self.write_store_value(tmp, ops[1])?;
+        let scale = tmp.get_class().get_bits().ilog2() - 3;
+        self.emit_mem_imm("str", scale, tmp, base, offset)
+    }
+    /// `MirStImm*Sym`: 存到全局量里.
+    pub(super) fn emit_store_imm_symbol(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (tmp, addr, sym) = (self.reg(ops[0])?, self.reg(ops[1])?, self.symbol(ops[3])?);
+        let tmp = self.write_store_value(tmp, ops[2])?.get_name();
+        let addr = addr.get_name();
+        self.ins(format_args!("adrp\t{addr}, {sym}"))?;
+        self.ins(format_args!("str\t{tmp}, [{addr}, :lo12:{sym}]"))
+    }
+    /// 把要存储的值写进 `tmp`, 返回实际要存的寄存器视图.
+    fn write_store_value(&mut self, tmp: PReg, value: MirOperand) -> AsmEmitRes<PReg> {
+        match (self.curr_op, value) {
+            (MirOP::MirStImm32 | MirOP::MirStImm32Sym, MirOperand::Imm(imm)) => {
+                let tmp = tmp.with_class(MirRegClass::GPR32);
+                self.mov_imm(tmp, imm as u64)?;
+                Ok(tmp)
+            }
+            (_, MirOperand::Imm(imm)) => {
+                self.mov_imm(tmp, imm as u64)?;
+                Ok(tmp)
+            }
+            (_, value) => {
+                self.load_address(tmp, value)?;
+                Ok(tmp)
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! 不访存的伪指令展开: 复制、常量、地址计算和多路跳转.
+
+use crate::mir::{
+    MirBlockID, MirGEP, MirOperand, MirRegClass, MirSwitch, PReg,
+    asm::{
+        AsmEmitRes,
+        func::{FuncEmitter, PoolEntry, SCRATCH},
+    },
+    is_calc_imm,
+};
+
+/// `MirSwitch` 和 `MirGEP` 展开时的累加寄存器. 与 `SCRATCH` 一样由寄存器分配保留.
+const ACCUM: PReg = PReg::x(16);
+
+impl FuncEmitter<'_> {
+    /// 把符号操作数表示的地址写进 `dst`.
+    pub(super) fn load_address(&mut self, dst: PReg, op: MirOperand) -> AsmEmitRes {
+        match op {
+            MirOperand::Global(_) => {
+                let (name, sym) = (dst.get_name(), self.symbol(op)?);
+                self.ins(format_args!("adrp\t{name}, {sym}"))?;
+                self.ins(format_args!("add\t{name}, {name}, :lo12:{sym}"))
+            }
+            MirOperand::Label(_) => {
+                let (name, sym) = (dst.get_name(), self.symbol(op)?);
+                self.ins(format_args!("adr\t{name}, {sym}"))
+            }
+            MirOperand::StackSlot(slot) => {
+                let (base, offset) = self.frame.slot_addr(slot);
+                self.add_imm(dst, base, offset)
+            }
+            _ => Err(self.invalid(op)),
+        }
+    }
+
+    /// `LoadConst64*`: 从常量池读取.
+    pub(super) fn emit_load_const(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let rd = self.reg(ops[0])?;
+        let entry = match ops[1] {
+            MirOperand::Imm(imm) => PoolEntry::Imm(imm as u64),
+            sym => PoolEntry::Symbol(self.symbol(sym)?),
+        };
+        let label = self.pool_label(entry);
+        self.ins(format_args!("ldr\t{}, {label}", rd.get_name()))
+    }
+
+    /// `MirLdImmF*`: 位模式先写进整数寄存器, 再 `fmov` 过去.
+    pub(super) fn emit_load_fimm(&mut self, ops: &[MirOperand]) -> AsmEmitRes {
+        let (rd, tmp, bits) = (self.reg(ops[0])?, self.reg(ops[1])?, self.imm(ops[2])?);
+        let tmp = match rd.get_class() {
+            MirRegClass::FPR32 => tmp.with_class(MirRegClass::GPR32),
+            _ => tmp,
+        };
+        self.mov_imm(tmp, bits as u64)?;
+        self.ins(format_args!("fmov\t{}, {}", rd.get_name(), tmp.get_name()))
+    }
+
+    /// `MirCopy*`: 源操作数可以是寄存器、立即数或者符号.
+    pub(super) fn emit_copy(&mut self, dst: MirOperand, src: MirOperand) -> AsmEmitRes {
+        let dst = self.reg(dst)?;
+        let class = dst.get_class();
+        match src {
+            MirOperand::PReg(src) if src == dst => Ok(()),
+            MirOperand::PReg(src) if class == MirRegClass::PState => match src.get_class() {
+                MirRegClass::PState => Ok(()),
+                MirRegClass::GPR64 => self.ins(format_args!("msr\tnzcv, {}", src.get_name())),
+                _ => Err(self.invalid(src.into())),
+            },
+            MirOperand::PReg(src) if src.get_class() == MirRegClass::PState => {
+                self.ins(format_args!("mrs\t{}, nzcv", dst.get_name()))
+            }
+            // `fmov` 只能搬运低 64 位, 整个向量寄存器要用 `mov` 的向量形式.
+            MirOperand::PReg(src) if class == MirRegClass::FPR128 => {
+                let (d, n) = (dst.get_num(), src.get_num());
+                self.ins(format_args!("mov\tv{d}.16b, v{n}.16b"))
+            }
+            MirOperand::PReg(src) => {
+                let mn = if class.is_fpr() || src.get_class().is_fpr() { "fmov" } else { "mov" };
+                self.ins(format_args!("{mn}\t{}, {}", dst.get_name(), src.get_name()))
+            }
+            MirOperand::Imm(imm) if class.is_fpr() => {
+                let tmp = match class {
+                    MirRegClass::FPR32 => SCRATCH.with_class(MirRegClass::GPR32),
+                    _ => SCRATCH,
+                };
+                self.mov_imm(tmp, imm as u64)?;
+                self.ins(format_args!("fmov\t{}, {}", dst.get_name(), tmp.get_name()))
+            }
+            MirOperand::Imm(imm) if class.is_gpr() => self.mov_imm(dst, imm as u64),
+            _ if class == MirRegClass::GPR64 => self.load_address(dst, src),
+            _ => Err(self.invalid(src)),
+        }
+    }
+
+    /// `MirGEP`: 先求出基址加常量偏移, 再依次累加各个下标.
+    /// 目标寄存器与某个下标相同时在 `ACCUM` 里累加, 最后再复制过去.
+    pub(super) fn emit_gep(&mut self, gep: &MirGEP) -> AsmEmitRes {
+        let dst = self.reg(gep.dst().get())?;
+        let mut indices = Vec::new();
+        for (index, scale) in gep.indices() {
+            indices.push((self.reg(index.get())?, scale));
+        }
+        let acc = if indices.iter().any(|&(index, _)| index == dst) { ACCUM } else { dst };
+        let offset = gep.get_offset();
+        match gep.base().get() {
+            MirOperand::PReg(base) => self.add_imm(acc, base, offset)?,
+            MirOperand::StackSlot(slot) => {
+                let (base, slot_offset) = self.frame.slot_addr(slot);
+                self.add_imm(acc, base, slot_offset.wrapping_add(offset))?;
+            }
+            base => {
+                self.load_address(acc, base)?;
+                self.add_imm(acc, acc, offset)?;
+            }
+        }
+        let acc_name = acc.get_name();
+        for (index, scale) in indices {
+            let index = index.get_name();
+            if scale.is_power_of_two() {
+                let shift = scale.trailing_zeros();
+                self.ins(format_args!(
+                    "add\t{acc_name}, {acc_name}, {index}, lsl #{shift}"
+                ))?;
+            } else {
+                self.mov_imm(SCRATCH, scale)?;
+                let scratch = SCRATCH.get_name();
+                self.ins(format_args!(
+                    "madd\t{acc_name}, {index}, {scratch}, {acc_name}"
+                ))?;
+            }
+        }
+        if acc != dst {
+            self.ins(format_args!("mov\t{}, {acc_name}", dst.get_name()))?;
+        }
+        Ok(())
+    }
+
+    /// `MirSwitch`: 分支密集时查跳转表, 否则逐个比较.
+    pub(super) fn emit_switch(&mut self, switch: &MirSwitch) -> AsmEmitRes {
+        let index = self.reg(switch.get_index())?;
+        let tab = self.func.get_switch_tab(switch.get_table()).clone();
+        let default = self.block_label(tab.default);
+        // 下标统一按有符号数比较, 32 位的下标先扩展成 64 位.
+        let index = if index.get_class() == MirRegClass::GPR32 {
+            self.ins(format_args!(
+                "sxtw\t{}, {}",
+                ACCUM.get_name(),
+                index.get_name()
+            ))?;
+            ACCUM
+        } else {
+            index
+        };
+        let (Some(min), Some(max)) = (
+            tab.cases.iter().map(|&(v, _)| v).min(),
+            tab.cases.iter().map(|&(v, _)| v).max(),
+        ) else {
+            return self.ins(format_args!("b\t{default}"));
+        };
+        let range = max.wrapping_sub(min) as u64;
+        let num_cases = tab.cases.len() as u64;
+        if num_cases < 4 || range >= num_cases * 3 {
+            return self.emit_switch_chain(index, &tab.cases, &default);
+        }
+
+        let mut targets = vec![tab.default; range as usize + 1];
+        for &(value, bb) in &tab.cases {
+            targets[value.wrapping_sub(min) as usize] = bb;
+        }
+        let table = self.add_jump_table(targets);
+        let (acc, scratch) = (ACCUM.get_name(), SCRATCH.get_name());
+        self.add_imm(ACCUM, index, min.wrapping_neg())?;
+        if is_calc_imm(range as i64) {
+            self.ins(format_args!("cmp\t{acc}, #{range}"))?;
+        } else {
+            self.mov_imm(SCRATCH, range)?;
+            self.ins(format_args!("cmp\t{acc}, {scratch}"))?;
+        }
+        self.ins(format_args!("b.hi\t{default}"))?;
+        self.ins(format_args!("adr\t{scratch}, {table}"))?;
+        self.ins(format_args!("ldrsw\t{acc}, [{scratch}, {acc}, lsl #2]"))?;
+        self.ins(format_args!("add\t{acc}, {scratch}, {acc}"))?;
+        self.ins(format_args!("br\t{acc}"))
+    }
+
+    fn emit_switch_chain(
+        &mut self,
+        index: PReg,
+        cases: &[(i64, MirBlockID)],
+        default: &str,
+    ) -> AsmEmitRes {
+        let (name, scratch) = (index.get_name(), SCRATCH.get_name());
+        for &(value, bb) in cases {
+            if is_calc_imm(value) {
+                self.ins(format_args!("cmp\t{name}, #{value}"))?;
+            } else if value < 0 && is_calc_imm(value.wrapping_neg()) {
+                self.ins(format_args!("cmn\t{name}, #{}", value.wrapping_neg()))?;
+            } else {
+                self.mov_imm(SCRATCH, value as u64)?;
+                self.ins(format_args!("cmp\t{name}, {scratch}"))?;
+            }
+            let label = self.block_label(bb);
+            self.ins(format_args!("b.eq\t{label}"))?;
+        }
+        self.ins(format_args!("b\t{default}"))
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    SymbolStr,
+    mir::{MirAllocs, MirGlobalID, MirInst, MirInstID},
+};
+use mtb_entity_slab::{
+    EntityList, EntityListError, EntityListIter, EntityListNodeHead, EntityListRes,
+    IBasicEntityListID, IEntityAllocID, IEntityListNodeID, IPoliciedID, entity_id,
+};
+use std::cell::Cell;
+
+#[entity_id(MirBlockID, policy = 256, allocator_type = MirBlockAlloc, backend = index)]
+pub struct MirBlockObj {
+    pub(crate) head: Cell<EntityListNodeHead<MirBlockID>>,
+    pub(crate) parent_func: Cell<Option<MirGlobalID>>,
+    /// 为 `None` 时是基本块链表的首尾哨兵结点.
+    pub(crate) body: Option<MirBlockBody>,
+}
+pub type MirBlockInnerID = <MirBlockID as IPoliciedID>::BackID;
+
+pub struct MirBlockBody {
+    pub insts: EntityList<MirInstID>,
+    /// 基本块名称, 在所属函数内唯一. 输出汇编时作为局部标签的一部分.
+    pub name: SymbolStr,
+}
+
+impl IBasicEntityListID for MirBlockID {
+    fn obj_load_head(obj: &MirBlockObj) -> EntityListNodeHead<Self> {
+        obj.head.get()
+    }
+    fn obj_store_head(obj: &MirBlockObj, head: EntityListNodeHead<Self>) {
+        obj.head.set(head);
+    }
+    fn obj_is_sentinel(obj: &MirBlockObj) -> bool {
+        obj.body.is_none()
+    }
+    fn new_sentinel_obj() -> MirBlockObj {
+        MirBlockObj {
+            head: Cell::new(EntityListNodeHead::none()),
+            parent_func: Cell::new(None),
+            body: None,
+        }
+    }
+    fn on_push_prev(self, prev: Self, alloc: &MirBlockAlloc) -> EntityListRes<Self> {
+        if self == prev {
+            return Err(EntityListError::RepeatedNode);
+        }
+        let parent = self.deref_alloc(alloc).parent_func.get();
+        prev.deref_alloc(alloc).parent_func.set(parent);
+        Ok(())
+    }
+    fn on_push_next(self, next: Self, alloc: &MirBlockAlloc) -> EntityListRes<Self> {
+        if self == next {
+            return Err(EntityListError::RepeatedNode);
+        }
+        let parent = self.deref_alloc(alloc).parent_func.get();
+        next.deref_alloc(alloc).parent_func.set(parent);
+        Ok(())
+    }
+    fn on_unplug(self, alloc: &MirBlockAlloc) -> EntityListRes<Self> {
+        let obj = self.deref_alloc(alloc);
+        if obj.body.is_none() {
+            return Err(EntityListError::ItemFalselyDetached(self));
+        }
+        obj.parent_func.set(None);
+        Ok(())
+    }
+}
+impl IEntityListNodeID for MirBlockID {}
+
+impl MirBlockObj {
+    pub fn get_parent_func(&self) -> Option<MirGlobalID> {
+        self.parent_func.get()
+    }
+    pub fn get_body(&self) -> &MirBlockBody {
+        self.body
+            .as_ref()
+            .expect("Error: Attempted to access body of sentinel MirBlockObj")
+    }
+}
+
+impl MirBlockID {
+    pub fn inner(self) -> MirBlockInnerID {
+        self.0
+    }
+
+    /// 分配一个尚未加入任何函数的空基本块.
+    pub fn new(allocs: &MirAllocs, name: impl Into<SymbolStr>) -> Self {
+        let body = MirBlockBody { insts: EntityList::new(&allocs.insts), name: name.into() };
+        let obj = MirBlockObj {
+            head: Cell::new(EntityListNodeHead::none()),
+            parent_func: Cell::new(None),
+            body: Some(body),
+        };
+        let id = MirBlockID(MirBlockInnerID::allocate_from(&allocs.blocks, obj));
+        id.get_insts(allocs)
+            .forall_with_sentinel(&allocs.insts, |_, inst| {
+                inst.parent.set(Some(id));
+                Ok(())
+            })
+            .expect("Failed to initialize MirBlock instruction list");
+        id
+    }
+
+    pub fn deref_mir(self, allocs: &MirAllocs) -> &MirBlockObj {
+        self.inner().deref(&allocs.blocks)
+    }
+    pub fn try_deref_mir(self, allocs: &MirAllocs) -> Option<&MirBlockObj> {
+        self.inner().try_deref(&allocs.blocks)
+    }
+    pub fn is_alive(self, allocs: &MirAllocs) -> bool {
+        self.try_deref_mir(allocs).is_some()
+    }
+    pub fn get_entity_index(self) -> usize {
+        self.inner().get_order()
+    }
+
+    pub fn get_parent_func(self, allocs: &MirAllocs) -> Option<MirGlobalID> {
+        self.deref_mir(allocs).get_parent_func()
+    }
+    pub fn get_body(self, allocs: &MirAllocs) -> &MirBlockBody {
+        self.deref_mir(allocs).get_body()
+    }
+    pub fn get_name(self, allocs: &MirAllocs) -> &SymbolStr {
+        &self.get_body(allocs).name
+    }
+    pub fn get_insts(self, allocs: &MirAllocs) -> &EntityList<MirInstID> {
+        &self.get_body(allocs).insts
+    }
+    pub fn insts_iter(self, allocs: &MirAllocs) -> EntityListIter<'_, MirInstID> {
+        self.get_insts(allocs).iter(&allocs.insts)
+    }
+
+    /// 在基本块末尾追加一条新指令.
+    pub fn push_inst(self, allocs: &MirAllocs, inst: MirInst) -> MirInstID {
+        let id = MirInstID::from_mir(allocs, inst);
+        self.push_inst_id(allocs, id);
+        id
+    }
+    pub fn push_inst_id(self, allocs: &MirAllocs, inst: MirInstID) {
+        self.get_insts(allocs)
+            .push_back_id(inst, &allocs.insts)
+            .expect("Failed to push MirInst into MirBlock");
+    }
+
+    /// 基本块末尾连续的跳转指令中的第一条. 新指令应该插在它前面.
+    pub fn get_first_terminator(self, allocs: &MirAllocs) -> Option<MirInstID> {
+        let mut first = None;
+        let mut curr = self.get_insts(allocs).get_back_id(&allocs.insts);
+        while let Some(inst) = curr {
+            if !inst.get_inst(allocs).is_terminator() {
+                break;
+            }
+            first = Some(inst);
+            curr = inst.get_prev_id(&allocs.insts);
+        }
+        first
+    }
+    /// 在基本块末尾的跳转指令之前插入一条新指令. 基本块没有跳转指令时追加到末尾.
+    pub fn insert_before_terminator(self, allocs: &MirAllocs, inst: MirInst) -> MirInstID {
+        match self.get_first_terminator(allocs) {
+            Some(pos) => {
+                let id = MirInstID::from_mir(allocs, inst);
+                pos.insert_before(allocs, id);
+                id
+            }
+            None => self.push_inst(allocs, inst),
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! 栈帧布局和栈槽引用的消解.
+//!
+//! ```text
+//! 高地址 +--------------------------+
+//!        | 栈上传入的参数            | <- x29 + 16
+//!        | x29, x30 (帧记录)         | <- x29
+//!        | 被调用者保存寄存器        |
+//!        | 对齐填充                  |
+//!        | 栈槽 (局部变量, 溢出)     |
+//!        | 出参区                    |
+//! 低地址 +--------------------------+ <- sp
+//!        | 动态栈分配 (向下增长)     |
+//! ```
+//!
+//! 没有动态栈分配时函数体内 `sp` 保持不变, 栈槽以 `sp` 为基址寻址;
+//! 有 `MirDynAlloca` 时 `sp` 会移动, 栈槽改以 `x29` 为基址. 保存的寄存器总是以 `x29` 为基址.
+//! 栈帧要求的对齐超过 16 字节时序言把 `sp` 向下对齐, `x29` 到栈槽的距离不再固定;
+//! 如果同时还有动态栈分配, 序言把对齐后的 `sp` 存进 `x19`, 栈槽以 `x19` 为基址.
+//! 这样的函数里寄存器分配不使用 `x19`, 由序言和尾声负责保存和恢复它.
+//!
+//! 指令选择和寄存器分配用符号操作数 `StackSlot` 引用栈槽. `lower_frame` 在寄存器分配之后
+//! 把它们换成 "基址寄存器 + 立即数", 偏移量放不进指令时先把地址算进 `x17`.
+
+use crate::mir::{
+    Bin64R, Bin64RC, IMirSubInst, LoadF32Base, LoadF64Base, LoadF128Base, LoadGr32Base,
+    LoadGr64Base, MirAllocs, MirCopy64, MirFunc, MirGEP, MirGlobalID, MirInst, MirInstID,
+    MirModule, MirOP, MirOperand, MirOperandKind, PReg, StackSlotID, StoreF32Base, StoreF64Base,
+    StoreF128Base, StoreGr32Base, StoreGr64Base, is_calc_imm,
+};
+
+/// 偏移量太大时计算地址用的寄存器, 与汇编输出时伪指令展开用的相同, 由寄存器分配保留.
+const SCRATCH: PReg = PReg::x(17);
+
+/// 既要重新对齐又有动态栈分配的栈帧里, 栈槽的基址寄存器.
+pub const BASE_REG: PReg = PReg::x(19);
+
+#[derive(Debug, Clone)]
+pub struct MirFrameLayout {
+    /// 各个栈槽相对于 `sp` 的偏移量.
+    slot_offsets: Vec<i64>,
+    /// 保存的寄存器和它相对于 `x29` 的偏移量, 按 `MirSaveRegs` 中的顺序排列.
+    saved: Vec<(PReg, i64)>,
+    /// 出参区的大小, 是 16 的倍数.
+    pub outgoing_size: u64,
+    /// 序言在帧记录之下分配的字节数, 是 16 的倍数. 不含重新对齐产生的填充.
+    pub size: u64,
+    /// 栈帧的对齐, 至少是 16 字节.
+    pub align_log2: u8,
+    pub has_dyn_alloca: bool,
+    /// 栈槽的基址寄存器不是 `sp` 和 `x29` 时, 序言设置、尾声恢复的那个寄存器.
+    pub base_reg: Option<PReg>,
+}
+
+impl MirFrameLayout {
+    pub fn new(func: &MirFunc, allocs: &MirAllocs) -> Self {
+        let mut saved_regs: Vec<PReg> = Vec::new();
+        for (bb, _) in func.blocks_iter(allocs) {
+            for (_, inst) in bb.insts_iter(allocs) {
+                if let MirInst::MirSaveRegs(save) = inst.get_inst().strip_comment() {
+                    let regs = save.regs().filter(|r| !saved_regs.contains(r));
+                    saved_regs.extend(regs.collect::<Vec<_>>());
+                }
+            }
+        }
+        let has_dyn_alloca = has_dyn_alloca(func, allocs);
+        let align_log2 = frame_align_log2(func);
+        let base_reg = (align_log2 > 4 && has_dyn_alloca).then_some(BASE_REG);
+        saved_regs.extend(base_reg.filter(|r| !saved_regs.contains(r)));
+        let save_size = (saved_regs.len() as u64 * 8).next_multiple_of(16);
+        let saved = saved_regs
+            .into_iter()
+            .enumerate()
+            .map(|(i, reg)| (reg, i as i64 * 8 - save_size as i64))
+            .collect();
+
+        let outgoing_size = (func.outgoing_size as u64).next_multiple_of(16);
+        let mut slot_offsets = Vec::with_capacity(func.stack_slots.len());
+        let mut top = outgoing_size;
+        for slot in &func.stack_slots {
+            let offset = top.next_multiple_of(1 << slot.align_log2);
+            slot_offsets.push(offset as i64);
+            top = offset + slot.size;
+        }
+        Self {
+            slot_offsets,
+            saved,
+            outgoing_size,
+            size: top.next_multiple_of(16) + save_size,
+            align_log2,
+            has_dyn_alloca,
+            base_reg,
+        }
+    }
+
+    /// 函数是否要把 `BASE_REG` 留作栈槽的基址. 溢出槽的对齐不超过 16 字节,
+    /// 所以寄存器分配之前就能确定.
+    pub fn needs_base_reg(func: &MirFunc, allocs: &MirAllocs) -> bool {
+        frame_align_log2(func) > 4 && has_dyn_alloca(func, allocs)
+    }
+
+    /// 栈帧要求的对齐超过了 AAPCS64 保证的 16 字节, 序言需要把 `sp` 向下对齐.
+    pub fn needs_realign(&self) -> bool {
+        self.align_log2 > 4
+    }
+    /// 栈槽相对于序言结束时的 `sp` 的偏移量.
+    pub fn slot_offset(&self, slot: StackSlotID) -> i64 {
+        self.slot_offsets[slot.get_index()]
+    }
+    /// 访问栈槽时使用的基址寄存器和偏移量.
+    pub fn slot_addr(&self, slot: StackSlotID) -> (PReg, i64) {
+        let offset = self.slot_offset(slot);
+        match self.base_reg {
+            Some(base) => (base, offset),
+            None if self.has_dyn_alloca => (PReg::FP, offset - self.size as i64),
+            None => (PReg::SP, offset),
+        }
+    }
+    /// 以 `rn` 为基址的栈槽引用实际使用的基址和偏移量. 以 `sp` 为基址时按 `slot_addr` 换算.
+    pub fn slot_base(&self, rn: PReg, slot: StackSlotID) -> (PReg, i64) {
+        match rn {
+            PReg::SP => self.slot_addr(slot),
+            _ => (rn, self.slot_offset(slot)),
+        }
+    }
+    pub fn save_offset(&self, reg: PReg) -> i64 {
+        self.saved
+            .iter()
+            .find(|(r, _)| *r == reg)
+            .map(|&(_, offset)| offset)
+            .unwrap_or_else(|| panic!("{reg} is restored but never saved"))
+    }
+
+    /// 把要保存的寄存器按 `stp`/`ldp` 两两配对: 同一个寄存器堆并且位置相邻.
+    pub fn pairs(&self, regs: &[PReg]) -> Vec<(PReg, Option<PReg>, i64)> {
+        let mut pairs = Vec::new();
+        let mut i = 0;
+        while i < regs.len() {
+            let (first, offset) = (regs[i], self.save_offset(regs[i]));
+            let second = regs.get(i + 1).copied().filter(|&next| {
+                next.get_class() == first.get_class() && self.save_offset(next) == offset + 8
+            });
+            pairs.push((first, second, offset));
+            i += if second.is_some() { 2 } else { 1 };
+        }
+        pairs
+    }
+}
+
+/// 栈帧的对齐: 函数要求的对齐和所有栈槽的对齐中最大的, 至少是 16 字节.
+fn frame_align_log2(func: &MirFunc) -> u8 {
+    let slot_aligns = func.stack_slots.iter().map(|slot| slot.align_log2);
+    slot_aligns.fold(func.stack_align_log2.max(4), u8::max)
+}
+
+fn has_dyn_alloca(func: &MirFunc, allocs: &MirAllocs) -> bool {
+    func.blocks_iter(allocs).any(|(bb, _)| {
+        bb.insts_iter(allocs)
+            .any(|(_, inst)| matches!(inst.get_inst().strip_comment(), MirInst::MirDynAlloca(_)))
+    })
+}
+
+/// 消解模块中所有函数的栈槽引用. 必须在寄存器分配之后运行.
+pub fn lower_frame(module: &mut MirModule) {
+    let funcs: Vec<MirGlobalID> = module
+        .funcs_iter()
+        .filter(|(_, f)| !f.is_extern())
+        .map(|(id, _)| id)
+        .collect();
+    for func in funcs {
+        lower_func_frame(module, func);
+    }
+}
+
+/// 把单个函数中的栈槽符号操作数换成基址寄存器加立即数偏移, 返回函数的栈帧布局.
+pub fn lower_func_frame(module: &mut MirModule, func_id: MirGlobalID) -> MirFrameLayout {
+    let (func, allocs) = (module.get_func(func_id), &module.allocs);
+    let layout = MirFrameLayout::new(func, allocs);
+    let mut replaced = Vec::new();
+    for (bb, _) in func.blocks_iter(allocs) {
+        for (id, inst) in bb.insts_iter(allocs) {
+            let Some(new_insts) = lower_slot_ref(&layout, inst.get_inst()) else {
+                continue;
+            };
+            for new_inst in new_insts {
+                id.insert_before(allocs, MirInstID::from_mir(allocs, new_inst));
+            }
+            replaced.push(id);
+        }
+    }
+    for id in replaced {
+        id.remove(&mut module.allocs);
+    }
+    layout
+}
+
+/// 引用了栈槽的指令的替代指令序列. 不引用栈槽时返回 `None`.
+fn lower_slot_ref(layout: &MirFrameLayout, inst: &MirInst) -> Option<Vec<MirInst>> {
+    let ops: Vec<MirOperand> = inst.operands().iter().map(|op| op.get()).collect();
+    match inst {
+        MirInst::MirGEP(gep) => {
+            let MirOperand::StackSlot(slot) = ops[1] else {
+                return None;
+            };
+            let (base, offset) = layout.slot_addr(slot);
+            let indices: Vec<_> = gep
+                .indices()
+                .map(|(index, scale)| (index.get(), scale))
+                .collect();
+            let offset = offset.wrapping_add(gep.get_offset());
+            Some(vec![
+                MirGEP::new(ops[0], base.into(), offset, &indices).into_mir(),
+            ])
+        }
+        MirInst::Bin64RSym(_) => {
+            let (MirOperand::PReg(rd), MirOperand::PReg(rn), MirOperand::StackSlot(slot)) =
+                (ops[0], ops[1], ops[2])
+            else {
+                return None;
+            };
+            let (base, offset) = layout.slot_base(rn, slot);
+            let offset = if inst.get_opcode() == MirOP::Sub64Sym { -offset } else { offset };
+            Some(add_offset(rd, base, offset))
+        }
+        _ => {
+            let (Some(&MirOperand::PReg(rn)), Some(&MirOperand::StackSlot(slot))) =
+                (ops.get(1), ops.get(2))
+            else {
+                return None;
+            };
+            let (base, offset) = layout.slot_base(rn, slot);
+            let name = inst.get_opcode().get_name();
+            let opcode = MirOP::from_name(name.strip_suffix('S')?)?;
+            let mut insts = Vec::new();
+            let (base, offset) = if mem_offset_kind(opcode)?.accepts(MirOperand::Imm(offset)) {
+                (base, offset)
+            } else {
+                insts.extend(add_offset(SCRATCH, base, offset));
+                (SCRATCH, 0)
+            };
+            insts.push(mem_base_inst(
+                opcode,
+                ops[0],
+                base.into(),
+                MirOperand::Imm(offset),
+            ));
+            Some(insts)
+        }
+    }
+}
+
+/// `dst = base + offset`. 加减立即数放不下时分成高低 12 位两步, 再不行就借用 `x16`.
+fn add_offset(dst: PReg, base: PReg, offset: i64) -> Vec<MirInst> {
+    let (opcode, abs) = if offset < 0 {
+        (MirOP::Sub64I, offset.unsigned_abs() as i64)
+    } else {
+        (MirOP::Add64I, offset)
+    };
+    let add = |dst: PReg, src: PReg, imm: i64| {
+        Bin64RC::new(opcode, dst.into(), src.into(), MirOperand::Imm(imm)).into_mir()
+    };
+    if is_calc_imm(abs) {
+        return vec![add(dst, base, abs)];
+    }
+    if abs < 1 << 24 {
+        return vec![add(dst, base, abs & !0xfff), add(dst, dst, abs & 0xfff)];
+    }
+    let tmp = PReg::x(16);
+    let opcode = if offset < 0 { MirOP::Sub64R } else { MirOP::Add64R };
+    vec![
+        MirCopy64::new(MirOP::MirCopy64, tmp.into(), MirOperand::Imm(abs)).into_mir(),
+        Bin64RC::new(MirOP::Add64I, dst.into(), base.into(), MirOperand::Imm(0)).into_mir(),
+        Bin64R::new(opcode, dst.into(), dst.into(), tmp.into()).into_mir(),
+    ]
+}
+
+/// 基址加立即数偏移的访存指令的偏移量约束.
+fn mem_offset_kind(opcode: MirOP) -> Option<MirOperandKind> {
+    let kinds = if LoadGr64Base::accepts_opcode(opcode) {
+        LoadGr64Base::OPERAND_KINDS
+    } else if LoadGr32Base::accepts_opcode(opcode) {
+        LoadGr32Base::OPERAND_KINDS
+    } else if LoadF128Base::accepts_opcode(opcode) {
+        LoadF128Base::OPERAND_KINDS
+    } else if LoadF64Base::accepts_opcode(opcode) {
+        LoadF64Base::OPERAND_KINDS
+    } else if LoadF32Base::accepts_opcode(opcode) {
+        LoadF32Base::OPERAND_KINDS
+    } else if StoreGr64Base::accepts_opcode(opcode) {
+        StoreGr64Base::OPERAND_KINDS
+    } else if StoreGr32Base::accepts_opcode(opcode) {
+        StoreGr32Base::OPERAND_KINDS
+    } else if StoreF128Base::accepts_opcode(opcode) {
+        StoreF128Base::OPERAND_KINDS
+    } else if StoreF64Base::accepts_opcode(opcode) {
+        StoreF64Base::OPERAND_KINDS
+    } else if StoreF32Base::accepts_opcode(opcode) {
+        StoreF32Base::OPERAND_KINDS
+    } else {
+        return None;
+    };
+    Some(kinds[2])
+}
+
+fn mem_base_inst(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> MirInst {
+    if LoadGr64Base::accepts_opcode(opcode) {
+        LoadGr64Base::new(opcode, rd, rn, rm).into_mir()
+    } else if LoadGr32Base::accepts_opcode(opcode) {
+        LoadGr32Base::new(opcode, rd, rn, rm).into_mir()
+    } else if LoadF128Base::accepts_opcode(opcode) {
+        LoadF128Base::new(opcode, rd, rn, rm).into_mir()
+    } else if LoadF64Base::accepts_opcode(opcode) {
+        LoadF64Base::new(opcode, rd, rn, rm).into_mir()
+    } else if LoadF32Base::accepts_opcode(opcode) {
+        LoadF32Base::new(opcode, rd, rn, rm).into_mir()
+    } else if StoreGr64Base::accepts_opcode(opcode) {
+        StoreGr64Base::new(opcode, rd, rn, rm).into_mir()
+    } else if StoreGr32Base::accepts_opcode(opcode) {
+        StoreGr32Base::new(opcode, rd, rn, rm).into_mir()
+    } else if StoreF128Base::accepts_opcode(opcode) {
+        StoreF128Base::new(opcode, rd, rn, rm).into_mir()
+    } else if StoreF64Base::accepts_opcode(opcode) {
+        StoreF64Base::new(opcode, rd, rn, rm).into_mir()
+    } else {
+        StoreF32Base::new(opcode, rd, rn, rm).into_mir()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mir::{
+        LoadGr64BaseS, MirBlockID, MirDynAlloca, MirReturn, MirSaveRegs, MirStackSlot,
+        MirStackSlotKind, MirWriter, StoreF64BaseS,
+    };
+
+    fn slot(size: u64, align_log2: u8) -> MirStackSlot {
+        MirStackSlot { kind: MirStackSlotKind::Local, size, align_log2 }
+    }
+
+    /// 一个出参区 32 字节、保存 `x20`/`x21` 的函数, 依次访问一个小栈槽和一个很大的栈槽之后的栈槽.
+    fn build_func(module: &mut MirModule, big_size: u64) -> MirGlobalID {
+        let mut func = MirFunc::new_defined(&module.allocs, "f", true);
+        func.outgoing_size = 24;
+        let small = func.add_stack_slot(slot(4, 2));
+        func.add_stack_slot(slot(big_size, 4));
+        let last = func.add_stack_slot(slot(8, 3));
+        let func_id = module.add_func(func);
+        let allocs = &module.allocs;
+        let entry = MirBlockID::new(allocs, "entry");
+        module.get_func(func_id).push_block(allocs, entry);
+
+        let (x0, d0, sp) = (PReg::x(0), PReg::d(0), PReg::SP);
+        let push = |inst: MirInst| entry.push_inst(allocs, inst);
+        push(MirSaveRegs::new(&[PReg::x(20), PReg::x(21)]).into_mir());
+        let small = MirOperand::StackSlot(small);
+        let store = StoreF64BaseS::new(MirOP::StrF64BaseS, d0.into(), sp.into(), small);
+        push(store.into_mir());
+        let last = MirOperand::StackSlot(last);
+        let load = LoadGr64BaseS::new(MirOP::LdrGr64BaseS, x0.into(), sp.into(), last);
+        push(load.into_mir());
+        push(MirGEP::new(x0.into(), small, 4, &[]).into_mir());
+        push(MirReturn::new(&[x0.into()]).into_mir());
+        func_id
+    }
+
+    /// 在入口的 `MirSaveRegs` 之后插入一条动态栈分配.
+    fn add_dyn_alloca(module: &MirModule, func_id: MirGlobalID) {
+        let allocs = &module.allocs;
+        let entry = module.get_func(func_id).get_entry(allocs).unwrap();
+        let (x1, x2) = (PReg::x(1).into(), PReg::x(2).into());
+        let alloca = MirDynAlloca::new(MirOP::MirDynAlloca, x1, x2);
+        let first = entry.get_insts(allocs).get_front_id(&allocs.insts).unwrap();
+        first.insert_after(allocs, MirInstID::new(allocs, alloca));
+    }
+
+    fn func_text(module: &MirModule, func_id: MirGlobalID) -> String {
+        let mut text = String::new();
+        MirWriter::new(module)
+            .write_global(func_id, &mut text)
+            .unwrap();
+        text
+    }
+
+    #[test]
+    fn test_layout_and_lower() {
+        let mut module = MirModule::new("frame");
+        let func_id = build_func(&mut module, 40000);
+        let layout = lower_func_frame(&mut module, func_id);
+        // 出参区向上取整到 32, 三个栈槽分别从 32、48、40048 开始, 保存区 16 字节
+        assert_eq!(layout.outgoing_size, 32);
+        assert_eq!(layout.size, 40064 + 16);
+        assert_eq!(layout.save_offset(PReg::x(21)), -8);
+        assert!(!layout.needs_realign());
+
+        let text = func_text(&module, func_id);
+        assert!(text.contains("StrF64Base $d0, $sp, #32"), "{text}");
+        // 40048 放不进 `ldr` 的立即数字段, 先把地址算进 `x17`.
+        assert!(text.contains("$x17 = Add64I $sp, #36864"), "{text}");
+        assert!(text.contains("$x17 = Add64I $x17, #3184"), "{text}");
+        assert!(text.contains("$x0 = LdrGr64Base $x17, #0"), "{text}");
+        assert!(text.contains("$x0 = MirGEP $sp {offset: 36,"), "{text}");
+    }
+
+    #[test]
+    fn test_dyn_alloca_frame() {
+        let mut module = MirModule::new("frame");
+        let func_id = build_func(&mut module, 16);
+        add_dyn_alloca(&module, func_id);
+
+        // 有动态栈分配时栈槽以 `x29` 为基址. 栈槽到 72 为止, 帧大小 80 + 16 = 96
+        let layout = MirFrameLayout::new(module.get_func(func_id), &module.allocs);
+        assert!(layout.has_dyn_alloca);
+        assert_eq!(layout.base_reg, None);
+        assert_eq!(layout.slot_addr(StackSlotID(0)), (PReg::FP, 32 - 96));
+        lower_func_frame(&mut module, func_id);
+        let text = func_text(&module, func_id);
+        assert!(text.contains("StrF64Base $d0, $x29, #-64"), "{text}");
+    }
+
+    #[test]
+    fn test_realign_with_dyn_alloca() {
+        let mut module = MirModule::new("frame");
+        let func_id = build_func(&mut module, 16);
+        add_dyn_alloca(&module, func_id);
+        module.get_func_mut(func_id).stack_slots[1].align_log2 = 5;
+        let func = module.get_func(func_id);
+        assert!(MirFrameLayout::needs_base_reg(func, &module.allocs));
+
+        // 对齐之后的 `sp` 存在 `x19` 里, 栈槽以它为基址. `x19` 排在 `MirSaveRegs` 的寄存器之后保存
+        let layout = lower_func_frame(&mut module, func_id);
+        assert_eq!(layout.align_log2, 5);
+        assert_eq!(layout.base_reg, Some(BASE_REG));
+        assert_eq!(layout.save_offset(BASE_REG), -16);
+        assert_eq!(layout.slot_addr(StackSlotID(2)), (BASE_REG, 80));
+        let text = func_text(&module, func_id);
+        assert!(text.contains("StrF64Base $d0, $x19, #32"), "{text}");
+        assert!(text.contains("$x0 = LdrGr64Base $x19, #80"), "{text}");
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    SymbolStr,
+    mir::{MirAllocs, MirBlockID, MirRegClass, MirSwitchTabID, StackSlotID, VReg},
+};
+use mtb_entity_slab::{EntityList, EntityListIter};
+use std::cell::Cell;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirStackSlotKind {
+    /// 源程序里的局部变量 (IR 中的 `alloca`).
+    Local,
+    /// 寄存器分配产生的溢出槽.
+    Spill,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MirStackSlot {
+    pub kind: MirStackSlotKind,
+    pub size: u64,
+    pub align_log2: u8,
+}
+
+/// 跳转表. `cases` 中的值互不相同, 没有匹配时跳到 `default`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirSwitchTab {
+    pub default: MirBlockID,
+    pub cases: Vec<(i64, MirBlockID)>,
+}
+
+impl MirSwitchTab {
+    /// 所有可能的跳转目标, `default` 在最前面.
+    pub fn iter_targets(&self) -> impl Iterator<Item = MirBlockID> + '_ {
+        std::iter::once(self.default).chain(self.cases.iter().map(|&(_, bb)| bb))
+    }
+}
+
+pub struct MirFunc {
+    pub name: SymbolStr,
+    pub exported: bool,
+    /// 基本块列表, 第一个基本块是入口. 为 `None` 时是外部声明.
+    pub blocks: Option<EntityList<MirBlockID>>,
+    pub stack_slots: Vec<MirStackSlot>,
+    pub switch_tabs: Vec<MirSwitchTab>,
+    /// 栈帧至少要对齐到 `1 << stack_align_log2` 字节, 来自 IR 的 `alignstack` 属性.
+    pub stack_align_log2: u8,
+    /// 调用其他函数时栈上传参需要的最大字节数, 即出参区的大小.
+    pub outgoing_size: u32,
+    num_vregs: Cell<u32>,
+}
+
+impl MirFunc {
+    pub fn new_extern(name: impl Into<SymbolStr>) -> Self {
+        Self {
+            name: name.into(),
+            exported: false,
+            blocks: None,
+            stack_slots: Vec::new(),
+            switch_tabs: Vec::new(),
+            stack_align_log2: 4,
+            outgoing_size: 0,
+            num_vregs: Cell::new(0),
+        }
+    }
+    /// 新建一个还没有基本块的函数定义. 加入模块之后才能往里面放基本块.
+    pub fn new_defined(allocs: &MirAllocs, name: impl Into<SymbolStr>, exported: bool) -> Self {
+        Self {
+            exported,
+            blocks: Some(EntityList::new(&allocs.blocks)),
+            ..Self::new_extern(name)
+        }
+    }
+
+    pub fn is_extern(&self) -> bool {
+        self.blocks.is_none()
+    }
+    pub fn blocks_unwrap(&self) -> &EntityList<MirBlockID> {
+        self.blocks
+            .as_ref()
+            .expect("Error: Attempted to access blocks of extern MirFunc")
+    }
+    pub fn blocks_iter<'mir>(&self, allocs: &'mir MirAllocs) -> EntityListIter<'mir, MirBlockID> {
+        self.blocks_unwrap().iter(&allocs.blocks)
+    }
+    pub fn get_entry(&self, allocs: &MirAllocs) -> Option<MirBlockID> {
+        self.blocks.as_ref()?.get_front_id(&allocs.blocks)
+    }
+    pub fn push_block(&self, allocs: &MirAllocs, block: MirBlockID) {
+        self.blocks_unwrap()
+            .push_back_id(block, &allocs.blocks)
+            .expect("Failed to push MirBlock into MirFunc");
+    }
+
+    pub fn new_vreg(&self, class: MirRegClass) -> VReg {
+        let id = self.num_vregs.get();
+        self.num_vregs.set(id + 1);
+        VReg::new(id, class)
+    }
+    /// 已经分配的虚拟寄存器个数. 虚拟寄存器编号都小于这个值.
+    pub fn num_vregs(&self) -> u32 {
+        self.num_vregs.get()
+    }
+
+    pub fn add_stack_slot(&mut self, slot: MirStackSlot) -> StackSlotID {
+        self.stack_slots.push(slot);
+        StackSlotID(self.stack_slots.len() as u32 - 1)
+    }
+    pub fn get_stack_slot(&self, id: StackSlotID) -> &MirStackSlot {
+        &self.stack_slots[id.get_index()]
+    }
+    pub fn add_switch_tab(&mut self, tab: MirSwitchTab) -> MirSwitchTabID {
+        self.switch_tabs.push(tab);
+        MirSwitchTabID(self.switch_tabs.len() as u32 - 1)
+    }
+    pub fn get_switch_tab(&self, id: MirSwitchTabID) -> &MirSwitchTab {
+        &self.switch_tabs[id.get_index()]
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{SymbolStr, mir::MirFunc};
+
+/// 模块内全局符号的编号, 下标即 `MirModule::globals` 中的位置.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MirGlobalID(pub u32);
+
+impl MirGlobalID {
+    pub fn get_index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+pub enum MirGlobal {
+    /// `MirFunc` 比全局变量大得多, 装箱以免拖大整个枚举.
+    Func(Box<MirFunc>),
+    Var(MirGlobalVar),
+}
+
+impl MirGlobal {
+    pub fn get_name(&self) -> &SymbolStr {
+        match self {
+            MirGlobal::Func(func) => &func.name,
+            MirGlobal::Var(var) => &var.name,
+        }
+    }
+    /// 是否在其他编译单元中定义.
+    pub fn is_extern(&self) -> bool {
+        match self {
+            MirGlobal::Func(func) => func.is_extern(),
+            MirGlobal::Var(var) => var.init.is_none(),
+        }
+    }
+    /// 是否对其他编译单元可见.
+    pub fn is_exported(&self) -> bool {
+        match self {
+            MirGlobal::Func(func) => func.exported,
+            MirGlobal::Var(var) => var.exported,
+        }
+    }
+    pub fn as_func(&self) -> Option<&MirFunc> {
+        match self {
+            MirGlobal::Func(func) => Some(func.as_ref()),
+            MirGlobal::Var(_) => None,
+        }
+    }
+    pub fn as_func_mut(&mut self) -> Option<&mut MirFunc> {
+        match self {
+            MirGlobal::Func(func) => Some(func.as_mut()),
+            MirGlobal::Var(_) => None,
+        }
+    }
+    pub fn as_var(&self) -> Option<&MirGlobalVar> {
+        match self {
+            MirGlobal::Var(var) => Some(var),
+            MirGlobal::Func(_) => None,
+        }
+    }
+}
+
+/// 全局变量所在的段.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirSection {
+    Data,
+    RoData,
+    Bss,
+    /// 有初始值的线程局部变量.
+    TData,
+    /// 初始值全零的线程局部变量.
+    TBss,
+}
+
+impl MirSection {
+    pub fn get_name(self) -> &'static str {
+        match self {
+            MirSection::Data => "data",
+            MirSection::RoData => "rodata",
+            MirSection::Bss => "bss",
+            MirSection::TData => "tdata",
+            MirSection::TBss => "tbss",
+        }
+    }
+    pub fn is_tls(self) -> bool {
+        matches!(self, MirSection::TData | MirSection::TBss)
+    }
+}
+
+/// 全局变量初始值的一段.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MirDataUnit {
+    Bytes(Vec<u8>),
+    Zeros(u64),
+    /// 64 位地址: 全局符号加偏移量.
+    Addr(MirGlobalID, i64),
+}
+
+impl MirDataUnit {
+    pub fn get_size(&self) -> u64 {
+        match self {
+            MirDataUnit::Bytes(bytes) => bytes.len() as u64,
+            MirDataUnit::Zeros(size) => *size,
+            MirDataUnit::Addr(..) => 8,
+        }
+    }
+}
+
+pub struct MirGlobalVar {
+    pub name: SymbolStr,
+    pub exported: bool,
+    pub section: MirSection,
+    pub align_log2: u8,
+    /// 初始值. 为 `None` 时是外部声明.
+    pub init: Option<Vec<MirDataUnit>>,
+}
+
+impl MirGlobalVar {
+    pub fn get_size(&self) -> u64 {
+        self.init.iter().flatten().map(MirDataUnit::get_size).sum()
+    }
+}
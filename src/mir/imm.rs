@@ -0,0 +1,158 @@
+//! AArch64 立即数编码规则. 判断一个整数能否直接编码进某种指令的立即数字段.
+
+/// `add`/`sub`/`cmp` 的 12 位无符号立即数, 可以左移 12 位.
+pub fn is_calc_imm(imm: i64) -> bool {
+    if imm < 0 {
+        return false;
+    }
+    imm <= 0xfff || (imm & 0xfff == 0 && imm >> 12 <= 0xfff)
+}
+
+/// 逻辑运算 (`and`/`orr`/`eor`...) 的位掩码立即数.
+///
+/// 这种立即数由 2、4、8、16、32 或 64 位的元素重复填满 `bits` 位得到,
+/// 每个元素是一段循环移位过的连续 1. 全 0 和全 1 都不能编码.
+pub fn is_logic_imm(imm: u64, bits: u32) -> bool {
+    assert!(
+        bits == 32 || bits == 64,
+        "logic immediates are 32 or 64 bits wide"
+    );
+    let mask = if bits == 64 { u64::MAX } else { (1u64 << bits) - 1 };
+    if imm & !mask != 0 {
+        return false;
+    }
+    if imm == 0 || imm == mask {
+        return false;
+    }
+    // 找到最小的重复元素
+    let mut size = bits;
+    while size > 2 {
+        let half = size / 2;
+        let half_mask = (1u64 << half) - 1;
+        if (imm & half_mask) != ((imm >> half) & half_mask) {
+            break;
+        }
+        size = half;
+    }
+    let elem_mask = if size == 64 { u64::MAX } else { (1u64 << size) - 1 };
+    let elem = imm & elem_mask;
+    // 元素循环右移若干位之后应该是 "低位全 1, 高位全 0" 的形式, 即 2^n - 1.
+    (0..size).any(|r| {
+        let e = rotate_right(elem, r, size);
+        e & e.wrapping_add(1) == 0
+    })
+}
+
+fn rotate_right(elem: u64, amount: u32, size: u32) -> u64 {
+    if amount == 0 {
+        return elem;
+    }
+    let mask = if size == 64 { u64::MAX } else { (1u64 << size) - 1 };
+    ((elem >> amount) | (elem << (size - amount))) & mask
+}
+
+/// 最多只有一个非零 16 位片段的立即数, 可以由一条 `movz` (或配合移位的 `movk`) 写入.
+pub fn is_movznk_imm(imm: u64) -> bool {
+    (0..4).filter(|i| (imm >> (i * 16)) & 0xffff != 0).count() <= 1
+}
+
+/// 能用一条 `mov` 别名写入 `bits` 位寄存器的立即数: `movz`、`movn` 或 `orr` 位掩码.
+pub fn is_mov_imm(imm: u64, bits: u32) -> bool {
+    let mask = if bits == 64 { u64::MAX } else { (1u64 << bits) - 1 };
+    if bits == 32 && imm & !mask != 0 {
+        return false;
+    }
+    is_movznk_imm(imm) || is_movznk_imm(!imm & mask) || is_logic_imm(imm, bits)
+}
+
+/// `fmov` 单精度浮点立即数: 形如 `±n/16 × 2^r`, 其中 `16 <= n <= 31`, `-3 <= r <= 4`.
+pub fn is_fmov_imm32(bits: u32) -> bool {
+    if bits & 0x7ffff != 0 {
+        return false;
+    }
+    // 指数部分: bit 30 与 bit 29..25 相反, bit 29..25 全部相同
+    let b = (bits >> 25) & 0x1f;
+    let not_b = (bits >> 30) & 1;
+    (b == 0 && not_b == 1) || (b == 0x1f && not_b == 0)
+}
+
+/// `fmov` 双精度浮点立即数, 取值范围与单精度相同.
+pub fn is_fmov_imm64(bits: u64) -> bool {
+    if bits & 0xffff_ffff_ffff != 0 {
+        return false;
+    }
+    let b = (bits >> 54) & 0xff;
+    let not_b = (bits >> 62) & 1;
+    (b == 0 && not_b == 1) || (b == 0xff && not_b == 0)
+}
+
+/// `ldr`/`str` 的偏移量: 9 位有符号非缩放偏移 (`ldur`/前后变址),
+/// 或按访问宽度 `1 << scale_log2` 缩放的 12 位无符号偏移.
+pub fn is_lsp_imm(imm: i64, scale_log2: u32) -> bool {
+    if (-256..=255).contains(&imm) {
+        return true;
+    }
+    let scale = 1i64 << scale_log2;
+    imm >= 0 && imm % scale == 0 && imm / scale <= 0xfff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calc_imm() {
+        assert!(is_calc_imm(0));
+        assert!(is_calc_imm(4095));
+        assert!(!is_calc_imm(4097));
+        assert!(is_calc_imm(0x123000));
+        assert!(!is_calc_imm(0x0100_0000));
+        assert!(!is_calc_imm(-1));
+    }
+
+    #[test]
+    fn test_logic_imm() {
+        assert!(is_logic_imm(0xff, 64));
+        assert!(is_logic_imm(0x5555_5555_5555_5555, 64));
+        assert!(is_logic_imm(0xf000_0000_0000_000f, 64));
+        assert!(is_logic_imm(0x00ff_00ff, 32));
+        assert!(is_logic_imm(0x8000_0001, 32));
+        assert!(!is_logic_imm(0, 64));
+        assert!(!is_logic_imm(u64::MAX, 64));
+        assert!(!is_logic_imm(0xffff_ffff, 32));
+        assert!(!is_logic_imm(0x1234, 64));
+        assert!(!is_logic_imm(0x1_0000_0000, 32));
+    }
+
+    #[test]
+    fn test_mov_imm() {
+        assert!(is_movznk_imm(0));
+        assert!(is_movznk_imm(0xabcd_0000));
+        assert!(!is_movznk_imm(0x1_0001));
+        assert!(is_mov_imm(0xffff_ffff_ffff_1234, 64));
+        assert!(is_mov_imm(0xffff_1234, 32));
+        assert!(is_mov_imm(0x5555_5555_5555_5555, 64));
+        assert!(!is_mov_imm(0x1234_5678, 64));
+    }
+
+    #[test]
+    fn test_fmov_imm() {
+        for v in [1.0f32, -2.0, 0.5, 31.0, 0.125, 1.9375] {
+            assert!(is_fmov_imm32(v.to_bits()), "{v}");
+            assert!(is_fmov_imm64((v as f64).to_bits()), "{v}");
+        }
+        for v in [0.0f32, 0.1, 32.0, 100.0, 1.0 / 3.0] {
+            assert!(!is_fmov_imm32(v.to_bits()), "{v}");
+            assert!(!is_fmov_imm64((v as f64).to_bits()), "{v}");
+        }
+    }
+
+    #[test]
+    fn test_lsp_imm() {
+        assert!(is_lsp_imm(-256, 3));
+        assert!(is_lsp_imm(32760, 3));
+        assert!(!is_lsp_imm(32761, 3));
+        assert!(is_lsp_imm(16380, 2));
+        assert!(!is_lsp_imm(16384, 2));
+    }
+}
@@ -0,0 +1,446 @@
+//! MIR 指令.
+//!
+//! 绝大多数指令类由 `rigs/mir.rig` 描述, 对应的 Rust 代码由 `rig-gen` 生成并检入在
+//! `inst/generated.rs` 中. 修改 `mir.rig` 之后需要重新生成:
+//!
+//! ```sh
+//! cargo run --bin rig-gen -- rigs/mir.rig -o src/mir/inst/generated.rs
+//! ```
+//!
+//! `mir.rig` 中的 `extern class` 操作数个数不固定, 在本模块的子模块里手写.
+
+use crate::mir::{MirAllocs, MirBlockID, MirFunc, MirOperand, MirOperandKind};
+use bitflags::bitflags;
+use mtb_entity_slab::{
+    EntityListError, EntityListNodeHead, EntityListRes, IBasicEntityListID, IEntityAllocID,
+    IEntityListNodeID, IPoliciedID, entity_id,
+};
+use std::fmt::{Display, Formatter};
+
+mod call;
+mod comment;
+mod gep;
+mod regsave;
+mod switch;
+
+pub use self::{
+    call::{MirCall, MirReturn},
+    comment::{MirComment, MirCommentedInst},
+    gep::MirGEP,
+    regsave::{MirRestoreHostRegs, MirRestoreRegs, MirSaveRegs},
+    switch::MirSwitch,
+};
+
+// 生成的代码自带 `use std::cell::Cell;`, 本模块的其余部分也使用这个导入.
+include!("inst/generated.rs");
+
+/// 所有指令共有的部分.
+#[derive(Debug, Clone)]
+pub struct MirInstCommon {
+    pub opcode: MirOP,
+}
+
+impl MirInstCommon {
+    pub fn new(opcode: MirOP) -> Self {
+        Self { opcode }
+    }
+}
+
+/// 指令类的公共接口. 操作数按 "输出在前, 输入在后" 的顺序存放.
+pub trait IMirSubInst {
+    fn get_common(&self) -> &MirInstCommon;
+    fn operands(&self) -> &[Cell<MirOperand>];
+    fn num_outs(&self) -> usize;
+    /// 每个操作数位置的类型约束, 长度与 `operands()` 相同.
+    fn operand_kinds(&self) -> &[MirOperandKind];
+    fn accepts_opcode(opcode: MirOP) -> bool
+    where
+        Self: Sized;
+    /// 以 `name: value` 的形式写出指令的属性, 多个属性之间用 `, ` 分隔.
+    fn fmt_props(&self, _out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        Ok(())
+    }
+    fn into_mir(self) -> MirInst
+    where
+        Self: Sized;
+    fn try_from_mir(inst: &MirInst) -> Option<&Self>
+    where
+        Self: Sized;
+
+    fn get_opcode(&self) -> MirOP {
+        self.get_common().opcode
+    }
+    fn outs(&self) -> &[Cell<MirOperand>] {
+        &self.operands()[..self.num_outs()]
+    }
+    fn ins(&self) -> &[Cell<MirOperand>] {
+        &self.operands()[self.num_outs()..]
+    }
+}
+
+impl MirOP {
+    /// 能结束基本块的跳转指令, 包括条件跳转.
+    pub fn is_terminator(self) -> bool {
+        use MirOP::*;
+        matches!(
+            self,
+            B | Br
+                | Ret
+                | BCond
+                | BCCond
+                | CBZ
+                | CBNZ
+                | TBZ64
+                | TBNZ64
+                | TBZ32
+                | TBNZ32
+                | Brk
+                | Udf
+                | MirReturn
+                | MirSwitch
+        )
+    }
+    /// 执行之后一定不会落到下一条指令的跳转. 基本块必须以这样的指令结尾.
+    pub fn is_uncond_terminator(self) -> bool {
+        use MirOP::*;
+        matches!(self, B | Br | Ret | Brk | Udf | MirReturn | MirSwitch)
+    }
+    pub fn is_call(self) -> bool {
+        use MirOP::*;
+        matches!(self, BLink | BLinkGlobal | BLinkReg | MirCall)
+    }
+}
+
+impl Display for MirOP {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.get_name())
+    }
+}
+
+impl MirInst {
+    pub fn get_opcode(&self) -> MirOP {
+        self.get_common().opcode
+    }
+    pub fn outs(&self) -> &[Cell<MirOperand>] {
+        &self.operands()[..self.num_outs()]
+    }
+    pub fn ins(&self) -> &[Cell<MirOperand>] {
+        &self.operands()[self.num_outs()..]
+    }
+
+    /// 去掉 `MirCommentedInst` 包装之后的指令.
+    pub fn strip_comment(&self) -> &MirInst {
+        match self {
+            MirInst::MirCommentedInst(inst) => inst.get_inner().strip_comment(),
+            inst => inst,
+        }
+    }
+    pub fn is_terminator(&self) -> bool {
+        self.strip_comment().get_opcode().is_terminator()
+    }
+    pub fn is_uncond_terminator(&self) -> bool {
+        self.strip_comment().get_opcode().is_uncond_terminator()
+    }
+
+    /// 跳转指令的所有目标基本块, 按操作数顺序排列, 可能有重复. 非跳转指令返回空列表.
+    pub fn branch_targets(&self, func: &MirFunc) -> Vec<MirBlockID> {
+        let inst = self.strip_comment();
+        if !inst.is_terminator() {
+            return Vec::new();
+        }
+        let mut targets = Vec::new();
+        for operand in inst.ins() {
+            match operand.get() {
+                MirOperand::Label(bb) => targets.push(bb),
+                MirOperand::SwitchTab(tab) => {
+                    targets.extend(func.get_switch_tab(tab).iter_targets());
+                }
+                _ => {}
+            }
+        }
+        targets
+    }
+}
+
+/// 条件码. 与 AArch64 的 `cond` 字段一一对应.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirCondFlag {
+    EQ,
+    NE,
+    /// 无符号 `>=`, 也写作 `HS`.
+    CS,
+    /// 无符号 `<`, 也写作 `LO`.
+    CC,
+    MI,
+    PL,
+    VS,
+    VC,
+    HI,
+    LS,
+    GE,
+    LT,
+    GT,
+    LE,
+    AL,
+    NV,
+}
+
+impl MirCondFlag {
+    pub fn get_name(self) -> &'static str {
+        use MirCondFlag::*;
+        match self {
+            EQ => "eq",
+            NE => "ne",
+            CS => "cs",
+            CC => "cc",
+            MI => "mi",
+            PL => "pl",
+            VS => "vs",
+            VC => "vc",
+            HI => "hi",
+            LS => "ls",
+            GE => "ge",
+            LT => "lt",
+            GT => "gt",
+            LE => "le",
+            AL => "al",
+            NV => "nv",
+        }
+    }
+    /// 取反之后的条件. `AL` 和 `NV` 在 AArch64 中都表示 "总是成立", 这里仍然互为相反.
+    pub fn inverse(self) -> Self {
+        use MirCondFlag::*;
+        match self {
+            EQ => NE,
+            NE => EQ,
+            CS => CC,
+            CC => CS,
+            MI => PL,
+            PL => MI,
+            VS => VC,
+            VC => VS,
+            HI => LS,
+            LS => HI,
+            GE => LT,
+            LT => GE,
+            GT => LE,
+            LE => GT,
+            AL => NV,
+            NV => AL,
+        }
+    }
+}
+
+bitflags! {
+    /// `ccmp` 条件不成立时写入 PState 的 NZCV 标志位.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct NZCV: u8 {
+        const N = 0b1000;
+        const Z = 0b0100;
+        const C = 0b0010;
+        const V = 0b0001;
+    }
+}
+
+/// 移位运算.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirShiftOP {
+    LSL,
+    LSR,
+    ASR,
+    ROR,
+}
+
+/// 扩展运算.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirExtendOP {
+    UXTB,
+    UXTH,
+    UXTW,
+    UXTX,
+    SXTB,
+    SXTH,
+    SXTW,
+    SXTX,
+}
+
+/// 寄存器操作数的附加运算, 例如 `add x0, x1, x2, lsl #3` 里的 `lsl #3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegOP {
+    Shift(MirShiftOP, u8),
+    Extend(MirExtendOP, u8),
+}
+
+impl Display for RegOP {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (name, amount) = match self {
+            RegOP::Shift(op, amount) => (format!("{op:?}"), *amount),
+            RegOP::Extend(op, amount) => (format!("{op:?}"), *amount),
+        };
+        let name = name.to_lowercase();
+        match (self, amount) {
+            (RegOP::Extend(..), 0) => f.write_str(&name),
+            _ => write!(f, "{name} #{amount}"),
+        }
+    }
+}
+
+/// 带写回的访存指令的变址方式.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddrMode {
+    /// `[rn, #imm]!`: 先更新基址寄存器, 再访存.
+    PreIndex,
+    /// `[rn], #imm`: 先访存, 再更新基址寄存器.
+    PostIndex,
+}
+
+#[entity_id(MirInstID, policy = 512, allocator_type = MirInstAlloc, backend = index)]
+pub struct MirInstObj {
+    pub(crate) head: Cell<EntityListNodeHead<MirInstID>>,
+    pub(crate) parent: Cell<Option<MirBlockID>>,
+    /// 为 `None` 时是指令链表的首尾哨兵结点.
+    pub(crate) inst: Option<MirInst>,
+}
+pub type MirInstInnerID = <MirInstID as IPoliciedID>::BackID;
+
+impl IBasicEntityListID for MirInstID {
+    fn obj_load_head(obj: &MirInstObj) -> EntityListNodeHead<Self> {
+        obj.head.get()
+    }
+    fn obj_store_head(obj: &MirInstObj, head: EntityListNodeHead<Self>) {
+        obj.head.set(head);
+    }
+    fn obj_is_sentinel(obj: &MirInstObj) -> bool {
+        obj.inst.is_none()
+    }
+    fn new_sentinel_obj() -> MirInstObj {
+        MirInstObj {
+            head: Cell::new(EntityListNodeHead::none()),
+            parent: Cell::new(None),
+            inst: None,
+        }
+    }
+    fn on_push_prev(self, prev: Self, alloc: &MirInstAlloc) -> EntityListRes<Self> {
+        if self == prev {
+            return Err(EntityListError::RepeatedNode);
+        }
+        let parent = self.deref_alloc(alloc).parent.get();
+        prev.deref_alloc(alloc).parent.set(parent);
+        Ok(())
+    }
+    fn on_push_next(self, next: Self, alloc: &MirInstAlloc) -> EntityListRes<Self> {
+        if self == next {
+            return Err(EntityListError::RepeatedNode);
+        }
+        let parent = self.deref_alloc(alloc).parent.get();
+        next.deref_alloc(alloc).parent.set(parent);
+        Ok(())
+    }
+    fn on_unplug(self, alloc: &MirInstAlloc) -> EntityListRes<Self> {
+        let obj = self.deref_alloc(alloc);
+        if obj.inst.is_none() {
+            return Err(EntityListError::ItemFalselyDetached(self));
+        }
+        obj.parent.set(None);
+        Ok(())
+    }
+}
+impl IEntityListNodeID for MirInstID {}
+
+impl MirInstObj {
+    pub fn new(inst: MirInst) -> Self {
+        Self {
+            head: Cell::new(EntityListNodeHead::none()),
+            parent: Cell::new(None),
+            inst: Some(inst),
+        }
+    }
+    pub fn get_inst(&self) -> &MirInst {
+        self.inst
+            .as_ref()
+            .expect("Error: Attempted to access instruction of sentinel MirInstObj")
+    }
+    pub fn get_parent(&self) -> Option<MirBlockID> {
+        self.parent.get()
+    }
+}
+
+impl MirInstID {
+    pub fn inner(self) -> MirInstInnerID {
+        self.0
+    }
+    /// 分配一条尚未插入任何基本块的指令.
+    pub fn new(allocs: &MirAllocs, inst: impl IMirSubInst) -> Self {
+        Self::from_mir(allocs, inst.into_mir())
+    }
+    pub fn from_mir(allocs: &MirAllocs, inst: MirInst) -> Self {
+        MirInstID(MirInstInnerID::allocate_from(
+            &allocs.insts,
+            MirInstObj::new(inst),
+        ))
+    }
+
+    pub fn deref_mir(self, allocs: &MirAllocs) -> &MirInstObj {
+        self.inner().deref(&allocs.insts)
+    }
+    pub fn try_deref_mir(self, allocs: &MirAllocs) -> Option<&MirInstObj> {
+        self.inner().try_deref(&allocs.insts)
+    }
+    pub fn is_alive(self, allocs: &MirAllocs) -> bool {
+        self.try_deref_mir(allocs).is_some()
+    }
+    pub fn get_entity_index(self) -> usize {
+        self.inner().get_order()
+    }
+
+    pub fn get_inst(self, allocs: &MirAllocs) -> &MirInst {
+        self.deref_mir(allocs).get_inst()
+    }
+    pub fn get_opcode(self, allocs: &MirAllocs) -> MirOP {
+        self.get_inst(allocs).get_opcode()
+    }
+    pub fn get_parent(self, allocs: &MirAllocs) -> Option<MirBlockID> {
+        self.deref_mir(allocs).get_parent()
+    }
+
+    /// 在本指令之前插入 `inst`. 本指令必须已经在某个基本块里.
+    pub fn insert_before(self, allocs: &MirAllocs, inst: MirInstID) {
+        let parent = self
+            .get_parent(allocs)
+            .expect("Error: Attempted to insert before a detached MirInst");
+        parent
+            .get_insts(allocs)
+            .node_add_prev(self, inst, &allocs.insts)
+            .expect("Failed to insert MirInst");
+    }
+    /// 在本指令之后插入 `inst`. 本指令必须已经在某个基本块里.
+    pub fn insert_after(self, allocs: &MirAllocs, inst: MirInstID) {
+        let parent = self
+            .get_parent(allocs)
+            .expect("Error: Attempted to insert after a detached MirInst");
+        parent
+            .get_insts(allocs)
+            .node_add_next(self, inst, &allocs.insts)
+            .expect("Failed to insert MirInst");
+    }
+    /// 把指令从所在基本块里摘下来, 不释放.
+    pub fn detach(self, allocs: &MirAllocs) {
+        let Some(parent) = self.get_parent(allocs) else {
+            return;
+        };
+        parent
+            .get_insts(allocs)
+            .node_unplug(self, &allocs.insts)
+            .expect("Failed to detach MirInst");
+    }
+    /// 把指令从所在基本块里摘下来并释放, 返回指令本身.
+    pub fn remove(self, allocs: &mut MirAllocs) -> MirInst {
+        self.detach(allocs);
+        let obj = self
+            .inner()
+            .free(&mut allocs.insts)
+            .expect("Error: Attempted to remove a freed MirInst");
+        obj.inst
+            .expect("Error: Attempted to remove a sentinel MirInst")
+    }
+}
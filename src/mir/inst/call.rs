@@ -0,0 +1,132 @@
+//! 函数调用和返回伪指令. 参数和返回值的个数由调用约定决定, 所以操作数个数不固定.
+
+use crate::mir::{IMirSubInst, MirInst, MirInstCommon, MirOP, MirOperand, MirOperandKind};
+use std::cell::Cell;
+
+/// 寄存器操作数按自己的寄存器类约束, 其余操作数不受约束.
+pub(super) fn operand_kind_of(op: MirOperand) -> MirOperandKind {
+    match op.get_reg_class() {
+        Some(class) => MirOperandKind::from_reg_class(class),
+        None => MirOperandKind::Any,
+    }
+}
+
+/// `MirCall`: 调用函数.
+///
+/// * 输出: 调用之后被写入的返回值寄存器;
+/// * 输入: 被调函数 (`Global` 或 `GPR64`), 之后是传参用的寄存器.
+///
+/// 调用约定规定的其他被破坏的寄存器不出现在操作数里, 由寄存器分配按调用约定处理.
+#[derive(Clone)]
+pub struct MirCall {
+    common: MirInstCommon,
+    operands: Box<[Cell<MirOperand>]>,
+    kinds: Box<[MirOperandKind]>,
+    num_rets: usize,
+}
+
+impl MirCall {
+    pub fn new(callee: MirOperand, rets: &[MirOperand], args: &[MirOperand]) -> Self {
+        let callee_kind = match callee {
+            MirOperand::Global(_) => MirOperandKind::Global,
+            _ => MirOperandKind::GPR64,
+        };
+        let operands = rets.iter().chain([&callee]).chain(args).copied();
+        let kinds = rets
+            .iter()
+            .map(|&op| operand_kind_of(op))
+            .chain([callee_kind])
+            .chain(args.iter().map(|&op| operand_kind_of(op)));
+        Self {
+            common: MirInstCommon::new(MirOP::MirCall),
+            operands: operands.map(Cell::new).collect(),
+            kinds: kinds.collect(),
+            num_rets: rets.len(),
+        }
+    }
+
+    pub fn callee(&self) -> &Cell<MirOperand> {
+        &self.operands[self.num_rets]
+    }
+    pub fn get_callee(&self) -> MirOperand {
+        self.callee().get()
+    }
+    pub fn rets(&self) -> &[Cell<MirOperand>] {
+        &self.operands[..self.num_rets]
+    }
+    pub fn args(&self) -> &[Cell<MirOperand>] {
+        &self.operands[self.num_rets + 1..]
+    }
+}
+
+impl IMirSubInst for MirCall {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        self.num_rets
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &self.kinds
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        opcode == MirOP::MirCall
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirCall(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirCall(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirReturn`: 从函数返回. 输入是存放返回值的寄存器, 没有输出.
+#[derive(Clone)]
+pub struct MirReturn {
+    common: MirInstCommon,
+    operands: Box<[Cell<MirOperand>]>,
+    kinds: Box<[MirOperandKind]>,
+}
+
+impl MirReturn {
+    pub fn new(rets: &[MirOperand]) -> Self {
+        Self {
+            common: MirInstCommon::new(MirOP::MirReturn),
+            operands: rets.iter().copied().map(Cell::new).collect(),
+            kinds: rets.iter().map(|&op| operand_kind_of(op)).collect(),
+        }
+    }
+}
+
+impl IMirSubInst for MirReturn {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        0
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &self.kinds
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        opcode == MirOP::MirReturn
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirReturn(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirReturn(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! 注释伪指令. 注释不改变程序语义, 只会原样输出到汇编里.
+
+use crate::{
+    SymbolStr,
+    mir::{IMirSubInst, MirInst, MirInstCommon, MirOP, MirOperand, MirOperandKind},
+};
+use std::cell::Cell;
+
+/// `MirComment`: 独占一行的注释, 没有操作数.
+#[derive(Clone)]
+pub struct MirComment {
+    common: MirInstCommon,
+    text: SymbolStr,
+}
+
+impl MirComment {
+    pub fn new(text: impl Into<SymbolStr>) -> Self {
+        Self {
+            common: MirInstCommon::new(MirOP::MirComment),
+            text: text.into(),
+        }
+    }
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl IMirSubInst for MirComment {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &[]
+    }
+    fn num_outs(&self) -> usize {
+        0
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &[]
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        opcode == MirOP::MirComment
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirComment(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirComment(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirCommentedInst`: 带行尾注释的指令. 操作数和语义都与内层指令相同.
+#[derive(Clone)]
+pub struct MirCommentedInst {
+    common: MirInstCommon,
+    inner: Box<MirInst>,
+    comment: SymbolStr,
+}
+
+impl MirCommentedInst {
+    pub fn new(inner: impl IMirSubInst, comment: impl Into<SymbolStr>) -> Self {
+        Self {
+            common: MirInstCommon::new(MirOP::MirCommentedInst),
+            inner: Box::new(inner.into_mir()),
+            comment: comment.into(),
+        }
+    }
+    pub fn get_inner(&self) -> &MirInst {
+        &self.inner
+    }
+    pub fn get_comment(&self) -> &str {
+        &self.comment
+    }
+}
+
+impl IMirSubInst for MirCommentedInst {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        self.inner.operands()
+    }
+    fn num_outs(&self) -> usize {
+        self.inner.num_outs()
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        self.inner.operand_kinds()
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        opcode == MirOP::MirCommentedInst
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        self.inner.fmt_props(out)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirCommentedInst(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirCommentedInst(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
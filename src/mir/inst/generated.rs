@@ -0,0 +1,15944 @@
+// @generated by rig-gen from `rigs/mir.rig`. Do not edit by hand.
+
+use std::cell::Cell;
+
+/// MIR 操作码.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MirOP {
+    BCond,
+    BCCond,
+    B,
+    Br,
+    Ret,
+    BLink,
+    BLinkGlobal,
+    BLinkReg,
+    CBZ,
+    CBNZ,
+    TBZ64,
+    TBNZ64,
+    TBZ32,
+    TBNZ32,
+    Brk,
+    Udf,
+    ICmp64R,
+    ICmn64R,
+    ICmp32R,
+    ICmn32R,
+    ICmp64I,
+    ICmn64I,
+    ICmp32I,
+    ICmn32I,
+    FCmp32,
+    FCmpE32,
+    FCmp64,
+    FCmpE64,
+    ICCmp64R,
+    ICCmn64R,
+    ICCmp32R,
+    ICCmn32R,
+    ICCmp64I,
+    ICCmn64I,
+    ICCmp32I,
+    ICCmn32I,
+    FCCmp32,
+    FCCmpE32,
+    FCCmp64,
+    FCCmpE64,
+    Add64R,
+    Sub64R,
+    SMax64R,
+    SMin64R,
+    UMax64R,
+    UMin64R,
+    And64R,
+    Bic64R,
+    EON64R,
+    EOR64R,
+    ORR64R,
+    ORN64R,
+    Asr64R,
+    Lsr64R,
+    Lsl64R,
+    Ror64R,
+    Mul64,
+    MNeg64,
+    SDiv64,
+    UDiv64,
+    SMulH,
+    UMulH,
+    Add32R,
+    Sub32R,
+    SMax32R,
+    SMin32R,
+    UMax32R,
+    UMin32R,
+    And32R,
+    Bic32R,
+    EON32R,
+    EOR32R,
+    ORR32R,
+    ORN32R,
+    Asr32R,
+    Lsr32R,
+    Lsl32R,
+    Ror32R,
+    Mul32,
+    MNeg32,
+    SDiv32,
+    UDiv32,
+    SMULL,
+    UMULL,
+    SMNegL,
+    UMNegL,
+    Add64I,
+    Sub64I,
+    Add32I,
+    Sub32I,
+    Add64Sym,
+    Sub64Sym,
+    And64I,
+    Bic64I,
+    EON64I,
+    EOR64I,
+    ORR64I,
+    ORN64I,
+    And32I,
+    Bic32I,
+    EON32I,
+    EOR32I,
+    ORR32I,
+    ORN32I,
+    SMax64I,
+    SMin64I,
+    UMax64I,
+    UMin64I,
+    SMax32I,
+    SMin32I,
+    UMax32I,
+    UMin32I,
+    Asr64I,
+    Lsr64I,
+    Lsl64I,
+    Ror64I,
+    Asr32I,
+    Lsr32I,
+    Lsl32I,
+    Ror32I,
+    FAdd64,
+    FDiv64,
+    FMul64,
+    FNMul64,
+    FSub64,
+    FAdd32,
+    FDiv32,
+    FMul32,
+    FNMul32,
+    FSub32,
+    MirCopy64,
+    MirCopy32,
+    MirFCopy128,
+    MirFCopy64,
+    MirFCopy32,
+    MirPCopy,
+    Neg64R,
+    MVN64R,
+    Mov64R,
+    Abs64R,
+    CLS64,
+    CLZ64,
+    CNT64,
+    CTZ64,
+    RBit64,
+    LoadStackPosGr64,
+    StoreStackPosGr64,
+    Neg32R,
+    MVN32R,
+    Mov32R,
+    Abs32R,
+    CLS32,
+    CLZ32,
+    CNT32,
+    CTZ32,
+    RBit32,
+    SXTB32,
+    SXTH32,
+    SXTW32,
+    UXTB32,
+    UXTH32,
+    LoadStackPosGr32,
+    StoreStackPosGr32,
+    SXTB64,
+    SXTH64,
+    SXTW64,
+    UXTB64,
+    UXTH64,
+    Mov64I,
+    MovZ64,
+    MovN64,
+    MovK64,
+    Mov32I,
+    MovZ32,
+    MovN32,
+    MovK32,
+    AdrP,
+    Adr,
+    FMovFG64,
+    SCvtF64,
+    UCvtF64,
+    FMovGF64,
+    FCvtAS64,
+    FCvtAU64,
+    FCvtMS64,
+    FCvtMU64,
+    FCvtNS64,
+    FCvtNU64,
+    FCvtPS64,
+    FCvtPU64,
+    FCvtZS64,
+    FCvtZU64,
+    SCvtF64G32,
+    UCvtF64G32,
+    FMovFG32,
+    SCvtF32,
+    UCvtF32,
+    SCvtF32G64,
+    UCvtF32G64,
+    FMovGF32,
+    FCvtAS32,
+    FCvtAU32,
+    FCvtMS32,
+    FCvtMU32,
+    FCvtNS32,
+    FCvtNU32,
+    FCvtPS32,
+    FCvtPU32,
+    FCvtZS32,
+    FCvtZU32,
+    FCvtAS64F32,
+    FCvtAU64F32,
+    FCvtMS64F32,
+    FCvtMU64F32,
+    FCvtNS64F32,
+    FCvtNU64F32,
+    FCvtPS64F32,
+    FCvtPU64F32,
+    FCvtZS64F32,
+    FCvtZU64F32,
+    FCvtAS32F64,
+    FCvtAU32F64,
+    FMov64R,
+    FRIntA64,
+    FRIntI64,
+    FRIntM64,
+    FRIntN64,
+    FRIntP64,
+    FRIntX64,
+    FRIntZ64,
+    FRInt32X64,
+    FRIntZ32X64,
+    FRInt64X64,
+    FRIntZ64X64,
+    FAbs64,
+    FNeg64,
+    FSqrt64,
+    LoadStackPosF64,
+    StoreStackPosF64,
+    FMov32R,
+    FRIntA32,
+    FRIntI32,
+    FRIntM32,
+    FRIntN32,
+    FRIntP32,
+    FRIntX32,
+    FRIntZ32,
+    FRInt32X32,
+    FRIntZ32X32,
+    FRInt64X32,
+    FRIntZ64X32,
+    FAbs32,
+    FNeg32,
+    FSqrt32,
+    LoadStackPosF32,
+    StoreStackPosF32,
+    FCvt32F64,
+    FCvt64F32,
+    FMov64I,
+    FMov32I,
+    MAdd64,
+    MSub64,
+    SMAddL,
+    SMSubL,
+    UMAddL,
+    UMSubL,
+    MAdd32,
+    MSub32,
+    FMAdd64,
+    FMSub64,
+    FNMAdd64,
+    FNMSub64,
+    FMAdd32,
+    FMSub32,
+    FNMAdd32,
+    FNMSub32,
+    LdrGr64,
+    LdrBGr64,
+    LdrHGr64,
+    LdrSBGr64,
+    LdrSHGr64,
+    LdrGr32,
+    LdrBGr32,
+    LdrHGr32,
+    LdrSBGr32,
+    LdrSHGr32,
+    LdrF64,
+    LdrF32,
+    StrGr64,
+    StrBGr64,
+    StrHGr64,
+    StrGr32,
+    StrBGr32,
+    StrHGr32,
+    StrF64,
+    StrF32,
+    LdrGr64Base,
+    LdrBGr64Base,
+    LdrHGr64Base,
+    LdrSBGr64Base,
+    LdrSHGr64Base,
+    LdrGr32Base,
+    LdrBGr32Base,
+    LdrHGr32Base,
+    LdrSBGr32Base,
+    LdrSHGr32Base,
+    LdrF128Base,
+    LdrF64Base,
+    LdrF32Base,
+    LdrGr64BaseS,
+    LdrBGr64BaseS,
+    LdrHGr64BaseS,
+    LdrSBGr64BaseS,
+    LdrSHGr64BaseS,
+    LdrGr32BaseS,
+    LdrBGr32BaseS,
+    LdrHGr32BaseS,
+    LdrSBGr32BaseS,
+    LdrSHGr32BaseS,
+    LdrF128BaseS,
+    LdrF64BaseS,
+    LdrF32BaseS,
+    StrGr64Base,
+    StrBGr64Base,
+    StrHGr64Base,
+    StrGr32Base,
+    StrBGr32Base,
+    StrHGr32Base,
+    StrF128Base,
+    StrF64Base,
+    StrF32Base,
+    StrGr64BaseS,
+    StrBGr64BaseS,
+    StrHGr64BaseS,
+    StrGr32BaseS,
+    StrBGr32BaseS,
+    StrHGr32BaseS,
+    StrF128BaseS,
+    StrF64BaseS,
+    StrF32BaseS,
+    LdrGr64Indexed,
+    LdrBGr64Indexed,
+    LdrHGr64Indexed,
+    LdrSBGr64Indexed,
+    LdrSHGr64Indexed,
+    LdrGr32Indexed,
+    LdrBGr32Indexed,
+    LdrHGr32Indexed,
+    LdrSBGr32Indexed,
+    LdrSHGr32Indexed,
+    LdrF64Indexed,
+    LdrF32Indexed,
+    StrGr64Indexed,
+    StrBGr64Indexed,
+    StrHGr64Indexed,
+    StrGr32Indexed,
+    StrBGr32Indexed,
+    StrHGr32Indexed,
+    StrF64Indexed,
+    StrF32Indexed,
+    LdrGr64Literal,
+    LdrBGr64Literal,
+    LdrHGr64Literal,
+    LdrSBGr64Literal,
+    LdrSHGr64Literal,
+    LdrGr32Literal,
+    LdrBGr32Literal,
+    LdrHGr32Literal,
+    LdrSBGr32Literal,
+    LdrSHGr32Literal,
+    LdrF64Literal,
+    LdrF32Literal,
+    MirLdrLitG64,
+    MirLdrLitG32,
+    MirLdrLitF64,
+    MirLdrLitF32,
+    MirStrLitG64,
+    MirStrLitG32,
+    MirStrLitF64,
+    MirStrLitF32,
+    MirStImm64,
+    MirStSym64,
+    MirStImm32,
+    MirStImm64Sym,
+    MirStImm32Sym,
+    MirStSymSym,
+    LoadConst64,
+    LoadConst64Symbol,
+    MirLdImmF64,
+    MirLdImmF32,
+    MirDynAlloca,
+    CSel64,
+    CSInc64,
+    CSInv64,
+    CSNeg64,
+    CSel32,
+    CSInc32,
+    CSInv32,
+    CSNeg32,
+    CSelF64,
+    CSelF32,
+    CSet64,
+    CSet32,
+    MirCall,
+    MirReturn,
+    MirSwitch,
+    MirSaveRegs,
+    MirRestoreRegs,
+    MirRestoreHostRegs,
+    MirGEP,
+    MirComment,
+    MirCommentedInst,
+}
+
+impl MirOP {
+    pub const ALL: [MirOP; 412] = [
+        MirOP::BCond,
+        MirOP::BCCond,
+        MirOP::B,
+        MirOP::Br,
+        MirOP::Ret,
+        MirOP::BLink,
+        MirOP::BLinkGlobal,
+        MirOP::BLinkReg,
+        MirOP::CBZ,
+        MirOP::CBNZ,
+        MirOP::TBZ64,
+        MirOP::TBNZ64,
+        MirOP::TBZ32,
+        MirOP::TBNZ32,
+        MirOP::Brk,
+        MirOP::Udf,
+        MirOP::ICmp64R,
+        MirOP::ICmn64R,
+        MirOP::ICmp32R,
+        MirOP::ICmn32R,
+        MirOP::ICmp64I,
+        MirOP::ICmn64I,
+        MirOP::ICmp32I,
+        MirOP::ICmn32I,
+        MirOP::FCmp32,
+        MirOP::FCmpE32,
+        MirOP::FCmp64,
+        MirOP::FCmpE64,
+        MirOP::ICCmp64R,
+        MirOP::ICCmn64R,
+        MirOP::ICCmp32R,
+        MirOP::ICCmn32R,
+        MirOP::ICCmp64I,
+        MirOP::ICCmn64I,
+        MirOP::ICCmp32I,
+        MirOP::ICCmn32I,
+        MirOP::FCCmp32,
+        MirOP::FCCmpE32,
+        MirOP::FCCmp64,
+        MirOP::FCCmpE64,
+        MirOP::Add64R,
+        MirOP::Sub64R,
+        MirOP::SMax64R,
+        MirOP::SMin64R,
+        MirOP::UMax64R,
+        MirOP::UMin64R,
+        MirOP::And64R,
+        MirOP::Bic64R,
+        MirOP::EON64R,
+        MirOP::EOR64R,
+        MirOP::ORR64R,
+        MirOP::ORN64R,
+        MirOP::Asr64R,
+        MirOP::Lsr64R,
+        MirOP::Lsl64R,
+        MirOP::Ror64R,
+        MirOP::Mul64,
+        MirOP::MNeg64,
+        MirOP::SDiv64,
+        MirOP::UDiv64,
+        MirOP::SMulH,
+        MirOP::UMulH,
+        MirOP::Add32R,
+        MirOP::Sub32R,
+        MirOP::SMax32R,
+        MirOP::SMin32R,
+        MirOP::UMax32R,
+        MirOP::UMin32R,
+        MirOP::And32R,
+        MirOP::Bic32R,
+        MirOP::EON32R,
+        MirOP::EOR32R,
+        MirOP::ORR32R,
+        MirOP::ORN32R,
+        MirOP::Asr32R,
+        MirOP::Lsr32R,
+        MirOP::Lsl32R,
+        MirOP::Ror32R,
+        MirOP::Mul32,
+        MirOP::MNeg32,
+        MirOP::SDiv32,
+        MirOP::UDiv32,
+        MirOP::SMULL,
+        MirOP::UMULL,
+        MirOP::SMNegL,
+        MirOP::UMNegL,
+        MirOP::Add64I,
+        MirOP::Sub64I,
+        MirOP::Add32I,
+        MirOP::Sub32I,
+        MirOP::Add64Sym,
+        MirOP::Sub64Sym,
+        MirOP::And64I,
+        MirOP::Bic64I,
+        MirOP::EON64I,
+        MirOP::EOR64I,
+        MirOP::ORR64I,
+        MirOP::ORN64I,
+        MirOP::And32I,
+        MirOP::Bic32I,
+        MirOP::EON32I,
+        MirOP::EOR32I,
+        MirOP::ORR32I,
+        MirOP::ORN32I,
+        MirOP::SMax64I,
+        MirOP::SMin64I,
+        MirOP::UMax64I,
+        MirOP::UMin64I,
+        MirOP::SMax32I,
+        MirOP::SMin32I,
+        MirOP::UMax32I,
+        MirOP::UMin32I,
+        MirOP::Asr64I,
+        MirOP::Lsr64I,
+        MirOP::Lsl64I,
+        MirOP::Ror64I,
+        MirOP::Asr32I,
+        MirOP::Lsr32I,
+        MirOP::Lsl32I,
+        MirOP::Ror32I,
+        MirOP::FAdd64,
+        MirOP::FDiv64,
+        MirOP::FMul64,
+        MirOP::FNMul64,
+        MirOP::FSub64,
+        MirOP::FAdd32,
+        MirOP::FDiv32,
+        MirOP::FMul32,
+        MirOP::FNMul32,
+        MirOP::FSub32,
+        MirOP::MirCopy64,
+        MirOP::MirCopy32,
+        MirOP::MirFCopy128,
+        MirOP::MirFCopy64,
+        MirOP::MirFCopy32,
+        MirOP::MirPCopy,
+        MirOP::Neg64R,
+        MirOP::MVN64R,
+        MirOP::Mov64R,
+        MirOP::Abs64R,
+        MirOP::CLS64,
+        MirOP::CLZ64,
+        MirOP::CNT64,
+        MirOP::CTZ64,
+        MirOP::RBit64,
+        MirOP::LoadStackPosGr64,
+        MirOP::StoreStackPosGr64,
+        MirOP::Neg32R,
+        MirOP::MVN32R,
+        MirOP::Mov32R,
+        MirOP::Abs32R,
+        MirOP::CLS32,
+        MirOP::CLZ32,
+        MirOP::CNT32,
+        MirOP::CTZ32,
+        MirOP::RBit32,
+        MirOP::SXTB32,
+        MirOP::SXTH32,
+        MirOP::SXTW32,
+        MirOP::UXTB32,
+        MirOP::UXTH32,
+        MirOP::LoadStackPosGr32,
+        MirOP::StoreStackPosGr32,
+        MirOP::SXTB64,
+        MirOP::SXTH64,
+        MirOP::SXTW64,
+        MirOP::UXTB64,
+        MirOP::UXTH64,
+        MirOP::Mov64I,
+        MirOP::MovZ64,
+        MirOP::MovN64,
+        MirOP::MovK64,
+        MirOP::Mov32I,
+        MirOP::MovZ32,
+        MirOP::MovN32,
+        MirOP::MovK32,
+        MirOP::AdrP,
+        MirOP::Adr,
+        MirOP::FMovFG64,
+        MirOP::SCvtF64,
+        MirOP::UCvtF64,
+        MirOP::FMovGF64,
+        MirOP::FCvtAS64,
+        MirOP::FCvtAU64,
+        MirOP::FCvtMS64,
+        MirOP::FCvtMU64,
+        MirOP::FCvtNS64,
+        MirOP::FCvtNU64,
+        MirOP::FCvtPS64,
+        MirOP::FCvtPU64,
+        MirOP::FCvtZS64,
+        MirOP::FCvtZU64,
+        MirOP::SCvtF64G32,
+        MirOP::UCvtF64G32,
+        MirOP::FMovFG32,
+        MirOP::SCvtF32,
+        MirOP::UCvtF32,
+        MirOP::SCvtF32G64,
+        MirOP::UCvtF32G64,
+        MirOP::FMovGF32,
+        MirOP::FCvtAS32,
+        MirOP::FCvtAU32,
+        MirOP::FCvtMS32,
+        MirOP::FCvtMU32,
+        MirOP::FCvtNS32,
+        MirOP::FCvtNU32,
+        MirOP::FCvtPS32,
+        MirOP::FCvtPU32,
+        MirOP::FCvtZS32,
+        MirOP::FCvtZU32,
+        MirOP::FCvtAS64F32,
+        MirOP::FCvtAU64F32,
+        MirOP::FCvtMS64F32,
+        MirOP::FCvtMU64F32,
+        MirOP::FCvtNS64F32,
+        MirOP::FCvtNU64F32,
+        MirOP::FCvtPS64F32,
+        MirOP::FCvtPU64F32,
+        MirOP::FCvtZS64F32,
+        MirOP::FCvtZU64F32,
+        MirOP::FCvtAS32F64,
+        MirOP::FCvtAU32F64,
+        MirOP::FMov64R,
+        MirOP::FRIntA64,
+        MirOP::FRIntI64,
+        MirOP::FRIntM64,
+        MirOP::FRIntN64,
+        MirOP::FRIntP64,
+        MirOP::FRIntX64,
+        MirOP::FRIntZ64,
+        MirOP::FRInt32X64,
+        MirOP::FRIntZ32X64,
+        MirOP::FRInt64X64,
+        MirOP::FRIntZ64X64,
+        MirOP::FAbs64,
+        MirOP::FNeg64,
+        MirOP::FSqrt64,
+        MirOP::LoadStackPosF64,
+        MirOP::StoreStackPosF64,
+        MirOP::FMov32R,
+        MirOP::FRIntA32,
+        MirOP::FRIntI32,
+        MirOP::FRIntM32,
+        MirOP::FRIntN32,
+        MirOP::FRIntP32,
+        MirOP::FRIntX32,
+        MirOP::FRIntZ32,
+        MirOP::FRInt32X32,
+        MirOP::FRIntZ32X32,
+        MirOP::FRInt64X32,
+        MirOP::FRIntZ64X32,
+        MirOP::FAbs32,
+        MirOP::FNeg32,
+        MirOP::FSqrt32,
+        MirOP::LoadStackPosF32,
+        MirOP::StoreStackPosF32,
+        MirOP::FCvt32F64,
+        MirOP::FCvt64F32,
+        MirOP::FMov64I,
+        MirOP::FMov32I,
+        MirOP::MAdd64,
+        MirOP::MSub64,
+        MirOP::SMAddL,
+        MirOP::SMSubL,
+        MirOP::UMAddL,
+        MirOP::UMSubL,
+        MirOP::MAdd32,
+        MirOP::MSub32,
+        MirOP::FMAdd64,
+        MirOP::FMSub64,
+        MirOP::FNMAdd64,
+        MirOP::FNMSub64,
+        MirOP::FMAdd32,
+        MirOP::FMSub32,
+        MirOP::FNMAdd32,
+        MirOP::FNMSub32,
+        MirOP::LdrGr64,
+        MirOP::LdrBGr64,
+        MirOP::LdrHGr64,
+        MirOP::LdrSBGr64,
+        MirOP::LdrSHGr64,
+        MirOP::LdrGr32,
+        MirOP::LdrBGr32,
+        MirOP::LdrHGr32,
+        MirOP::LdrSBGr32,
+        MirOP::LdrSHGr32,
+        MirOP::LdrF64,
+        MirOP::LdrF32,
+        MirOP::StrGr64,
+        MirOP::StrBGr64,
+        MirOP::StrHGr64,
+        MirOP::StrGr32,
+        MirOP::StrBGr32,
+        MirOP::StrHGr32,
+        MirOP::StrF64,
+        MirOP::StrF32,
+        MirOP::LdrGr64Base,
+        MirOP::LdrBGr64Base,
+        MirOP::LdrHGr64Base,
+        MirOP::LdrSBGr64Base,
+        MirOP::LdrSHGr64Base,
+        MirOP::LdrGr32Base,
+        MirOP::LdrBGr32Base,
+        MirOP::LdrHGr32Base,
+        MirOP::LdrSBGr32Base,
+        MirOP::LdrSHGr32Base,
+        MirOP::LdrF128Base,
+        MirOP::LdrF64Base,
+        MirOP::LdrF32Base,
+        MirOP::LdrGr64BaseS,
+        MirOP::LdrBGr64BaseS,
+        MirOP::LdrHGr64BaseS,
+        MirOP::LdrSBGr64BaseS,
+        MirOP::LdrSHGr64BaseS,
+        MirOP::LdrGr32BaseS,
+        MirOP::LdrBGr32BaseS,
+        MirOP::LdrHGr32BaseS,
+        MirOP::LdrSBGr32BaseS,
+        MirOP::LdrSHGr32BaseS,
+        MirOP::LdrF128BaseS,
+        MirOP::LdrF64BaseS,
+        MirOP::LdrF32BaseS,
+        MirOP::StrGr64Base,
+        MirOP::StrBGr64Base,
+        MirOP::StrHGr64Base,
+        MirOP::StrGr32Base,
+        MirOP::StrBGr32Base,
+        MirOP::StrHGr32Base,
+        MirOP::StrF128Base,
+        MirOP::StrF64Base,
+        MirOP::StrF32Base,
+        MirOP::StrGr64BaseS,
+        MirOP::StrBGr64BaseS,
+        MirOP::StrHGr64BaseS,
+        MirOP::StrGr32BaseS,
+        MirOP::StrBGr32BaseS,
+        MirOP::StrHGr32BaseS,
+        MirOP::StrF128BaseS,
+        MirOP::StrF64BaseS,
+        MirOP::StrF32BaseS,
+        MirOP::LdrGr64Indexed,
+        MirOP::LdrBGr64Indexed,
+        MirOP::LdrHGr64Indexed,
+        MirOP::LdrSBGr64Indexed,
+        MirOP::LdrSHGr64Indexed,
+        MirOP::LdrGr32Indexed,
+        MirOP::LdrBGr32Indexed,
+        MirOP::LdrHGr32Indexed,
+        MirOP::LdrSBGr32Indexed,
+        MirOP::LdrSHGr32Indexed,
+        MirOP::LdrF64Indexed,
+        MirOP::LdrF32Indexed,
+        MirOP::StrGr64Indexed,
+        MirOP::StrBGr64Indexed,
+        MirOP::StrHGr64Indexed,
+        MirOP::StrGr32Indexed,
+        MirOP::StrBGr32Indexed,
+        MirOP::StrHGr32Indexed,
+        MirOP::StrF64Indexed,
+        MirOP::StrF32Indexed,
+        MirOP::LdrGr64Literal,
+        MirOP::LdrBGr64Literal,
+        MirOP::LdrHGr64Literal,
+        MirOP::LdrSBGr64Literal,
+        MirOP::LdrSHGr64Literal,
+        MirOP::LdrGr32Literal,
+        MirOP::LdrBGr32Literal,
+        MirOP::LdrHGr32Literal,
+        MirOP::LdrSBGr32Literal,
+        MirOP::LdrSHGr32Literal,
+        MirOP::LdrF64Literal,
+        MirOP::LdrF32Literal,
+        MirOP::MirLdrLitG64,
+        MirOP::MirLdrLitG32,
+        MirOP::MirLdrLitF64,
+        MirOP::MirLdrLitF32,
+        MirOP::MirStrLitG64,
+        MirOP::MirStrLitG32,
+        MirOP::MirStrLitF64,
+        MirOP::MirStrLitF32,
+        MirOP::MirStImm64,
+        MirOP::MirStSym64,
+        MirOP::MirStImm32,
+        MirOP::MirStImm64Sym,
+        MirOP::MirStImm32Sym,
+        MirOP::MirStSymSym,
+        MirOP::LoadConst64,
+        MirOP::LoadConst64Symbol,
+        MirOP::MirLdImmF64,
+        MirOP::MirLdImmF32,
+        MirOP::MirDynAlloca,
+        MirOP::CSel64,
+        MirOP::CSInc64,
+        MirOP::CSInv64,
+        MirOP::CSNeg64,
+        MirOP::CSel32,
+        MirOP::CSInc32,
+        MirOP::CSInv32,
+        MirOP::CSNeg32,
+        MirOP::CSelF64,
+        MirOP::CSelF32,
+        MirOP::CSet64,
+        MirOP::CSet32,
+        MirOP::MirCall,
+        MirOP::MirReturn,
+        MirOP::MirSwitch,
+        MirOP::MirSaveRegs,
+        MirOP::MirRestoreRegs,
+        MirOP::MirRestoreHostRegs,
+        MirOP::MirGEP,
+        MirOP::MirComment,
+        MirOP::MirCommentedInst,
+    ];
+
+    pub fn get_name(self) -> &'static str {
+        match self {
+            MirOP::BCond => "BCond",
+            MirOP::BCCond => "BCCond",
+            MirOP::B => "B",
+            MirOP::Br => "Br",
+            MirOP::Ret => "Ret",
+            MirOP::BLink => "BLink",
+            MirOP::BLinkGlobal => "BLinkGlobal",
+            MirOP::BLinkReg => "BLinkReg",
+            MirOP::CBZ => "CBZ",
+            MirOP::CBNZ => "CBNZ",
+            MirOP::TBZ64 => "TBZ64",
+            MirOP::TBNZ64 => "TBNZ64",
+            MirOP::TBZ32 => "TBZ32",
+            MirOP::TBNZ32 => "TBNZ32",
+            MirOP::Brk => "Brk",
+            MirOP::Udf => "Udf",
+            MirOP::ICmp64R => "ICmp64R",
+            MirOP::ICmn64R => "ICmn64R",
+            MirOP::ICmp32R => "ICmp32R",
+            MirOP::ICmn32R => "ICmn32R",
+            MirOP::ICmp64I => "ICmp64I",
+            MirOP::ICmn64I => "ICmn64I",
+            MirOP::ICmp32I => "ICmp32I",
+            MirOP::ICmn32I => "ICmn32I",
+            MirOP::FCmp32 => "FCmp32",
+            MirOP::FCmpE32 => "FCmpE32",
+            MirOP::FCmp64 => "FCmp64",
+            MirOP::FCmpE64 => "FCmpE64",
+            MirOP::ICCmp64R => "ICCmp64R",
+            MirOP::ICCmn64R => "ICCmn64R",
+            MirOP::ICCmp32R => "ICCmp32R",
+            MirOP::ICCmn32R => "ICCmn32R",
+            MirOP::ICCmp64I => "ICCmp64I",
+            MirOP::ICCmn64I => "ICCmn64I",
+            MirOP::ICCmp32I => "ICCmp32I",
+            MirOP::ICCmn32I => "ICCmn32I",
+            MirOP::FCCmp32 => "FCCmp32",
+            MirOP::FCCmpE32 => "FCCmpE32",
+            MirOP::FCCmp64 => "FCCmp64",
+            MirOP::FCCmpE64 => "FCCmpE64",
+            MirOP::Add64R => "Add64R",
+            MirOP::Sub64R => "Sub64R",
+            MirOP::SMax64R => "SMax64R",
+            MirOP::SMin64R => "SMin64R",
+            MirOP::UMax64R => "UMax64R",
+            MirOP::UMin64R => "UMin64R",
+            MirOP::And64R => "And64R",
+            MirOP::Bic64R => "Bic64R",
+            MirOP::EON64R => "EON64R",
+            MirOP::EOR64R => "EOR64R",
+            MirOP::ORR64R => "ORR64R",
+            MirOP::ORN64R => "ORN64R",
+            MirOP::Asr64R => "Asr64R",
+            MirOP::Lsr64R => "Lsr64R",
+            MirOP::Lsl64R => "Lsl64R",
+            MirOP::Ror64R => "Ror64R",
+            MirOP::Mul64 => "Mul64",
+            MirOP::MNeg64 => "MNeg64",
+            MirOP::SDiv64 => "SDiv64",
+            MirOP::UDiv64 => "UDiv64",
+            MirOP::SMulH => "SMulH",
+            MirOP::UMulH => "UMulH",
+            MirOP::Add32R => "Add32R",
+            MirOP::Sub32R => "Sub32R",
+            MirOP::SMax32R => "SMax32R",
+            MirOP::SMin32R => "SMin32R",
+            MirOP::UMax32R => "UMax32R",
+            MirOP::UMin32R => "UMin32R",
+            MirOP::And32R => "And32R",
+            MirOP::Bic32R => "Bic32R",
+            MirOP::EON32R => "EON32R",
+            MirOP::EOR32R => "EOR32R",
+            MirOP::ORR32R => "ORR32R",
+            MirOP::ORN32R => "ORN32R",
+            MirOP::Asr32R => "Asr32R",
+            MirOP::Lsr32R => "Lsr32R",
+            MirOP::Lsl32R => "Lsl32R",
+            MirOP::Ror32R => "Ror32R",
+            MirOP::Mul32 => "Mul32",
+            MirOP::MNeg32 => "MNeg32",
+            MirOP::SDiv32 => "SDiv32",
+            MirOP::UDiv32 => "UDiv32",
+            MirOP::SMULL => "SMULL",
+            MirOP::UMULL => "UMULL",
+            MirOP::SMNegL => "SMNegL",
+            MirOP::UMNegL => "UMNegL",
+            MirOP::Add64I => "Add64I",
+            MirOP::Sub64I => "Sub64I",
+            MirOP::Add32I => "Add32I",
+            MirOP::Sub32I => "Sub32I",
+            MirOP::Add64Sym => "Add64Sym",
+            MirOP::Sub64Sym => "Sub64Sym",
+            MirOP::And64I => "And64I",
+            MirOP::Bic64I => "Bic64I",
+            MirOP::EON64I => "EON64I",
+            MirOP::EOR64I => "EOR64I",
+            MirOP::ORR64I => "ORR64I",
+            MirOP::ORN64I => "ORN64I",
+            MirOP::And32I => "And32I",
+            MirOP::Bic32I => "Bic32I",
+            MirOP::EON32I => "EON32I",
+            MirOP::EOR32I => "EOR32I",
+            MirOP::ORR32I => "ORR32I",
+            MirOP::ORN32I => "ORN32I",
+            MirOP::SMax64I => "SMax64I",
+            MirOP::SMin64I => "SMin64I",
+            MirOP::UMax64I => "UMax64I",
+            MirOP::UMin64I => "UMin64I",
+            MirOP::SMax32I => "SMax32I",
+            MirOP::SMin32I => "SMin32I",
+            MirOP::UMax32I => "UMax32I",
+            MirOP::UMin32I => "UMin32I",
+            MirOP::Asr64I => "Asr64I",
+            MirOP::Lsr64I => "Lsr64I",
+            MirOP::Lsl64I => "Lsl64I",
+            MirOP::Ror64I => "Ror64I",
+            MirOP::Asr32I => "Asr32I",
+            MirOP::Lsr32I => "Lsr32I",
+            MirOP::Lsl32I => "Lsl32I",
+            MirOP::Ror32I => "Ror32I",
+            MirOP::FAdd64 => "FAdd64",
+            MirOP::FDiv64 => "FDiv64",
+            MirOP::FMul64 => "FMul64",
+            MirOP::FNMul64 => "FNMul64",
+            MirOP::FSub64 => "FSub64",
+            MirOP::FAdd32 => "FAdd32",
+            MirOP::FDiv32 => "FDiv32",
+            MirOP::FMul32 => "FMul32",
+            MirOP::FNMul32 => "FNMul32",
+            MirOP::FSub32 => "FSub32",
+            MirOP::MirCopy64 => "MirCopy64",
+            MirOP::MirCopy32 => "MirCopy32",
+            MirOP::MirFCopy128 => "MirFCopy128",
+            MirOP::MirFCopy64 => "MirFCopy64",
+            MirOP::MirFCopy32 => "MirFCopy32",
+            MirOP::MirPCopy => "MirPCopy",
+            MirOP::Neg64R => "Neg64R",
+            MirOP::MVN64R => "MVN64R",
+            MirOP::Mov64R => "Mov64R",
+            MirOP::Abs64R => "Abs64R",
+            MirOP::CLS64 => "CLS64",
+            MirOP::CLZ64 => "CLZ64",
+            MirOP::CNT64 => "CNT64",
+            MirOP::CTZ64 => "CTZ64",
+            MirOP::RBit64 => "RBit64",
+            MirOP::LoadStackPosGr64 => "LoadStackPosGr64",
+            MirOP::StoreStackPosGr64 => "StoreStackPosGr64",
+            MirOP::Neg32R => "Neg32R",
+            MirOP::MVN32R => "MVN32R",
+            MirOP::Mov32R => "Mov32R",
+            MirOP::Abs32R => "Abs32R",
+            MirOP::CLS32 => "CLS32",
+            MirOP::CLZ32 => "CLZ32",
+            MirOP::CNT32 => "CNT32",
+            MirOP::CTZ32 => "CTZ32",
+            MirOP::RBit32 => "RBit32",
+            MirOP::SXTB32 => "SXTB32",
+            MirOP::SXTH32 => "SXTH32",
+            MirOP::SXTW32 => "SXTW32",
+            MirOP::UXTB32 => "UXTB32",
+            MirOP::UXTH32 => "UXTH32",
+            MirOP::LoadStackPosGr32 => "LoadStackPosGr32",
+            MirOP::StoreStackPosGr32 => "StoreStackPosGr32",
+            MirOP::SXTB64 => "SXTB64",
+            MirOP::SXTH64 => "SXTH64",
+            MirOP::SXTW64 => "SXTW64",
+            MirOP::UXTB64 => "UXTB64",
+            MirOP::UXTH64 => "UXTH64",
+            MirOP::Mov64I => "Mov64I",
+            MirOP::MovZ64 => "MovZ64",
+            MirOP::MovN64 => "MovN64",
+            MirOP::MovK64 => "MovK64",
+            MirOP::Mov32I => "Mov32I",
+            MirOP::MovZ32 => "MovZ32",
+            MirOP::MovN32 => "MovN32",
+            MirOP::MovK32 => "MovK32",
+            MirOP::AdrP => "AdrP",
+            MirOP::Adr => "Adr",
+            MirOP::FMovFG64 => "FMovFG64",
+            MirOP::SCvtF64 => "SCvtF64",
+            MirOP::UCvtF64 => "UCvtF64",
+            MirOP::FMovGF64 => "FMovGF64",
+            MirOP::FCvtAS64 => "FCvtAS64",
+            MirOP::FCvtAU64 => "FCvtAU64",
+            MirOP::FCvtMS64 => "FCvtMS64",
+            MirOP::FCvtMU64 => "FCvtMU64",
+            MirOP::FCvtNS64 => "FCvtNS64",
+            MirOP::FCvtNU64 => "FCvtNU64",
+            MirOP::FCvtPS64 => "FCvtPS64",
+            MirOP::FCvtPU64 => "FCvtPU64",
+            MirOP::FCvtZS64 => "FCvtZS64",
+            MirOP::FCvtZU64 => "FCvtZU64",
+            MirOP::SCvtF64G32 => "SCvtF64G32",
+            MirOP::UCvtF64G32 => "UCvtF64G32",
+            MirOP::FMovFG32 => "FMovFG32",
+            MirOP::SCvtF32 => "SCvtF32",
+            MirOP::UCvtF32 => "UCvtF32",
+            MirOP::SCvtF32G64 => "SCvtF32G64",
+            MirOP::UCvtF32G64 => "UCvtF32G64",
+            MirOP::FMovGF32 => "FMovGF32",
+            MirOP::FCvtAS32 => "FCvtAS32",
+            MirOP::FCvtAU32 => "FCvtAU32",
+            MirOP::FCvtMS32 => "FCvtMS32",
+            MirOP::FCvtMU32 => "FCvtMU32",
+            MirOP::FCvtNS32 => "FCvtNS32",
+            MirOP::FCvtNU32 => "FCvtNU32",
+            MirOP::FCvtPS32 => "FCvtPS32",
+            MirOP::FCvtPU32 => "FCvtPU32",
+            MirOP::FCvtZS32 => "FCvtZS32",
+            MirOP::FCvtZU32 => "FCvtZU32",
+            MirOP::FCvtAS64F32 => "FCvtAS64F32",
+            MirOP::FCvtAU64F32 => "FCvtAU64F32",
+            MirOP::FCvtMS64F32 => "FCvtMS64F32",
+            MirOP::FCvtMU64F32 => "FCvtMU64F32",
+            MirOP::FCvtNS64F32 => "FCvtNS64F32",
+            MirOP::FCvtNU64F32 => "FCvtNU64F32",
+            MirOP::FCvtPS64F32 => "FCvtPS64F32",
+            MirOP::FCvtPU64F32 => "FCvtPU64F32",
+            MirOP::FCvtZS64F32 => "FCvtZS64F32",
+            MirOP::FCvtZU64F32 => "FCvtZU64F32",
+            MirOP::FCvtAS32F64 => "FCvtAS32F64",
+            MirOP::FCvtAU32F64 => "FCvtAU32F64",
+            MirOP::FMov64R => "FMov64R",
+            MirOP::FRIntA64 => "FRIntA64",
+            MirOP::FRIntI64 => "FRIntI64",
+            MirOP::FRIntM64 => "FRIntM64",
+            MirOP::FRIntN64 => "FRIntN64",
+            MirOP::FRIntP64 => "FRIntP64",
+            MirOP::FRIntX64 => "FRIntX64",
+            MirOP::FRIntZ64 => "FRIntZ64",
+            MirOP::FRInt32X64 => "FRInt32X64",
+            MirOP::FRIntZ32X64 => "FRIntZ32X64",
+            MirOP::FRInt64X64 => "FRInt64X64",
+            MirOP::FRIntZ64X64 => "FRIntZ64X64",
+            MirOP::FAbs64 => "FAbs64",
+            MirOP::FNeg64 => "FNeg64",
+            MirOP::FSqrt64 => "FSqrt64",
+            MirOP::LoadStackPosF64 => "LoadStackPosF64",
+            MirOP::StoreStackPosF64 => "StoreStackPosF64",
+            MirOP::FMov32R => "FMov32R",
+            MirOP::FRIntA32 => "FRIntA32",
+            MirOP::FRIntI32 => "FRIntI32",
+            MirOP::FRIntM32 => "FRIntM32",
+            MirOP::FRIntN32 => "FRIntN32",
+            MirOP::FRIntP32 => "FRIntP32",
+            MirOP::FRIntX32 => "FRIntX32",
+            MirOP::FRIntZ32 => "FRIntZ32",
+            MirOP::FRInt32X32 => "FRInt32X32",
+            MirOP::FRIntZ32X32 => "FRIntZ32X32",
+            MirOP::FRInt64X32 => "FRInt64X32",
+            MirOP::FRIntZ64X32 => "FRIntZ64X32",
+            MirOP::FAbs32 => "FAbs32",
+            MirOP::FNeg32 => "FNeg32",
+            MirOP::FSqrt32 => "FSqrt32",
+            MirOP::LoadStackPosF32 => "LoadStackPosF32",
+            MirOP::StoreStackPosF32 => "StoreStackPosF32",
+            MirOP::FCvt32F64 => "FCvt32F64",
+            MirOP::FCvt64F32 => "FCvt64F32",
+            MirOP::FMov64I => "FMov64I",
+            MirOP::FMov32I => "FMov32I",
+            MirOP::MAdd64 => "MAdd64",
+            MirOP::MSub64 => "MSub64",
+            MirOP::SMAddL => "SMAddL",
+            MirOP::SMSubL => "SMSubL",
+            MirOP::UMAddL => "UMAddL",
+            MirOP::UMSubL => "UMSubL",
+            MirOP::MAdd32 => "MAdd32",
+            MirOP::MSub32 => "MSub32",
+            MirOP::FMAdd64 => "FMAdd64",
+            MirOP::FMSub64 => "FMSub64",
+            MirOP::FNMAdd64 => "FNMAdd64",
+            MirOP::FNMSub64 => "FNMSub64",
+            MirOP::FMAdd32 => "FMAdd32",
+            MirOP::FMSub32 => "FMSub32",
+            MirOP::FNMAdd32 => "FNMAdd32",
+            MirOP::FNMSub32 => "FNMSub32",
+            MirOP::LdrGr64 => "LdrGr64",
+            MirOP::LdrBGr64 => "LdrBGr64",
+            MirOP::LdrHGr64 => "LdrHGr64",
+            MirOP::LdrSBGr64 => "LdrSBGr64",
+            MirOP::LdrSHGr64 => "LdrSHGr64",
+            MirOP::LdrGr32 => "LdrGr32",
+            MirOP::LdrBGr32 => "LdrBGr32",
+            MirOP::LdrHGr32 => "LdrHGr32",
+            MirOP::LdrSBGr32 => "LdrSBGr32",
+            MirOP::LdrSHGr32 => "LdrSHGr32",
+            MirOP::LdrF64 => "LdrF64",
+            MirOP::LdrF32 => "LdrF32",
+            MirOP::StrGr64 => "StrGr64",
+            MirOP::StrBGr64 => "StrBGr64",
+            MirOP::StrHGr64 => "StrHGr64",
+            MirOP::StrGr32 => "StrGr32",
+            MirOP::StrBGr32 => "StrBGr32",
+            MirOP::StrHGr32 => "StrHGr32",
+            MirOP::StrF64 => "StrF64",
+            MirOP::StrF32 => "StrF32",
+            MirOP::LdrGr64Base => "LdrGr64Base",
+            MirOP::LdrBGr64Base => "LdrBGr64Base",
+            MirOP::LdrHGr64Base => "LdrHGr64Base",
+            MirOP::LdrSBGr64Base => "LdrSBGr64Base",
+            MirOP::LdrSHGr64Base => "LdrSHGr64Base",
+            MirOP::LdrGr32Base => "LdrGr32Base",
+            MirOP::LdrBGr32Base => "LdrBGr32Base",
+            MirOP::LdrHGr32Base => "LdrHGr32Base",
+            MirOP::LdrSBGr32Base => "LdrSBGr32Base",
+            MirOP::LdrSHGr32Base => "LdrSHGr32Base",
+            MirOP::LdrF128Base => "LdrF128Base",
+            MirOP::LdrF64Base => "LdrF64Base",
+            MirOP::LdrF32Base => "LdrF32Base",
+            MirOP::LdrGr64BaseS => "LdrGr64BaseS",
+            MirOP::LdrBGr64BaseS => "LdrBGr64BaseS",
+            MirOP::LdrHGr64BaseS => "LdrHGr64BaseS",
+            MirOP::LdrSBGr64BaseS => "LdrSBGr64BaseS",
+            MirOP::LdrSHGr64BaseS => "LdrSHGr64BaseS",
+            MirOP::LdrGr32BaseS => "LdrGr32BaseS",
+            MirOP::LdrBGr32BaseS => "LdrBGr32BaseS",
+            MirOP::LdrHGr32BaseS => "LdrHGr32BaseS",
+            MirOP::LdrSBGr32BaseS => "LdrSBGr32BaseS",
+            MirOP::LdrSHGr32BaseS => "LdrSHGr32BaseS",
+            MirOP::LdrF128BaseS => "LdrF128BaseS",
+            MirOP::LdrF64BaseS => "LdrF64BaseS",
+            MirOP::LdrF32BaseS => "LdrF32BaseS",
+            MirOP::StrGr64Base => "StrGr64Base",
+            MirOP::StrBGr64Base => "StrBGr64Base",
+            MirOP::StrHGr64Base => "StrHGr64Base",
+            MirOP::StrGr32Base => "StrGr32Base",
+            MirOP::StrBGr32Base => "StrBGr32Base",
+            MirOP::StrHGr32Base => "StrHGr32Base",
+            MirOP::StrF128Base => "StrF128Base",
+            MirOP::StrF64Base => "StrF64Base",
+            MirOP::StrF32Base => "StrF32Base",
+            MirOP::StrGr64BaseS => "StrGr64BaseS",
+            MirOP::StrBGr64BaseS => "StrBGr64BaseS",
+            MirOP::StrHGr64BaseS => "StrHGr64BaseS",
+            MirOP::StrGr32BaseS => "StrGr32BaseS",
+            MirOP::StrBGr32BaseS => "StrBGr32BaseS",
+            MirOP::StrHGr32BaseS => "StrHGr32BaseS",
+            MirOP::StrF128BaseS => "StrF128BaseS",
+            MirOP::StrF64BaseS => "StrF64BaseS",
+            MirOP::StrF32BaseS => "StrF32BaseS",
+            MirOP::LdrGr64Indexed => "LdrGr64Indexed",
+            MirOP::LdrBGr64Indexed => "LdrBGr64Indexed",
+            MirOP::LdrHGr64Indexed => "LdrHGr64Indexed",
+            MirOP::LdrSBGr64Indexed => "LdrSBGr64Indexed",
+            MirOP::LdrSHGr64Indexed => "LdrSHGr64Indexed",
+            MirOP::LdrGr32Indexed => "LdrGr32Indexed",
+            MirOP::LdrBGr32Indexed => "LdrBGr32Indexed",
+            MirOP::LdrHGr32Indexed => "LdrHGr32Indexed",
+            MirOP::LdrSBGr32Indexed => "LdrSBGr32Indexed",
+            MirOP::LdrSHGr32Indexed => "LdrSHGr32Indexed",
+            MirOP::LdrF64Indexed => "LdrF64Indexed",
+            MirOP::LdrF32Indexed => "LdrF32Indexed",
+            MirOP::StrGr64Indexed => "StrGr64Indexed",
+            MirOP::StrBGr64Indexed => "StrBGr64Indexed",
+            MirOP::StrHGr64Indexed => "StrHGr64Indexed",
+            MirOP::StrGr32Indexed => "StrGr32Indexed",
+            MirOP::StrBGr32Indexed => "StrBGr32Indexed",
+            MirOP::StrHGr32Indexed => "StrHGr32Indexed",
+            MirOP::StrF64Indexed => "StrF64Indexed",
+            MirOP::StrF32Indexed => "StrF32Indexed",
+            MirOP::LdrGr64Literal => "LdrGr64Literal",
+            MirOP::LdrBGr64Literal => "LdrBGr64Literal",
+            MirOP::LdrHGr64Literal => "LdrHGr64Literal",
+            MirOP::LdrSBGr64Literal => "LdrSBGr64Literal",
+            MirOP::LdrSHGr64Literal => "LdrSHGr64Literal",
+            MirOP::LdrGr32Literal => "LdrGr32Literal",
+            MirOP::LdrBGr32Literal => "LdrBGr32Literal",
+            MirOP::LdrHGr32Literal => "LdrHGr32Literal",
+            MirOP::LdrSBGr32Literal => "LdrSBGr32Literal",
+            MirOP::LdrSHGr32Literal => "LdrSHGr32Literal",
+            MirOP::LdrF64Literal => "LdrF64Literal",
+            MirOP::LdrF32Literal => "LdrF32Literal",
+            MirOP::MirLdrLitG64 => "MirLdrLitG64",
+            MirOP::MirLdrLitG32 => "MirLdrLitG32",
+            MirOP::MirLdrLitF64 => "MirLdrLitF64",
+            MirOP::MirLdrLitF32 => "MirLdrLitF32",
+            MirOP::MirStrLitG64 => "MirStrLitG64",
+            MirOP::MirStrLitG32 => "MirStrLitG32",
+            MirOP::MirStrLitF64 => "MirStrLitF64",
+            MirOP::MirStrLitF32 => "MirStrLitF32",
+            MirOP::MirStImm64 => "MirStImm64",
+            MirOP::MirStSym64 => "MirStSym64",
+            MirOP::MirStImm32 => "MirStImm32",
+            MirOP::MirStImm64Sym => "MirStImm64Sym",
+            MirOP::MirStImm32Sym => "MirStImm32Sym",
+            MirOP::MirStSymSym => "MirStSymSym",
+            MirOP::LoadConst64 => "LoadConst64",
+            MirOP::LoadConst64Symbol => "LoadConst64Symbol",
+            MirOP::MirLdImmF64 => "MirLdImmF64",
+            MirOP::MirLdImmF32 => "MirLdImmF32",
+            MirOP::MirDynAlloca => "MirDynAlloca",
+            MirOP::CSel64 => "CSel64",
+            MirOP::CSInc64 => "CSInc64",
+            MirOP::CSInv64 => "CSInv64",
+            MirOP::CSNeg64 => "CSNeg64",
+            MirOP::CSel32 => "CSel32",
+            MirOP::CSInc32 => "CSInc32",
+            MirOP::CSInv32 => "CSInv32",
+            MirOP::CSNeg32 => "CSNeg32",
+            MirOP::CSelF64 => "CSelF64",
+            MirOP::CSelF32 => "CSelF32",
+            MirOP::CSet64 => "CSet64",
+            MirOP::CSet32 => "CSet32",
+            MirOP::MirCall => "MirCall",
+            MirOP::MirReturn => "MirReturn",
+            MirOP::MirSwitch => "MirSwitch",
+            MirOP::MirSaveRegs => "MirSaveRegs",
+            MirOP::MirRestoreRegs => "MirRestoreRegs",
+            MirOP::MirRestoreHostRegs => "MirRestoreHostRegs",
+            MirOP::MirGEP => "MirGEP",
+            MirOP::MirComment => "MirComment",
+            MirOP::MirCommentedInst => "MirCommentedInst",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "BCond" => Some(MirOP::BCond),
+            "BCCond" => Some(MirOP::BCCond),
+            "B" => Some(MirOP::B),
+            "Br" => Some(MirOP::Br),
+            "Ret" => Some(MirOP::Ret),
+            "BLink" => Some(MirOP::BLink),
+            "BLinkGlobal" => Some(MirOP::BLinkGlobal),
+            "BLinkReg" => Some(MirOP::BLinkReg),
+            "CBZ" => Some(MirOP::CBZ),
+            "CBNZ" => Some(MirOP::CBNZ),
+            "TBZ64" => Some(MirOP::TBZ64),
+            "TBNZ64" => Some(MirOP::TBNZ64),
+            "TBZ32" => Some(MirOP::TBZ32),
+            "TBNZ32" => Some(MirOP::TBNZ32),
+            "Brk" => Some(MirOP::Brk),
+            "Udf" => Some(MirOP::Udf),
+            "ICmp64R" => Some(MirOP::ICmp64R),
+            "ICmn64R" => Some(MirOP::ICmn64R),
+            "ICmp32R" => Some(MirOP::ICmp32R),
+            "ICmn32R" => Some(MirOP::ICmn32R),
+            "ICmp64I" => Some(MirOP::ICmp64I),
+            "ICmn64I" => Some(MirOP::ICmn64I),
+            "ICmp32I" => Some(MirOP::ICmp32I),
+            "ICmn32I" => Some(MirOP::ICmn32I),
+            "FCmp32" => Some(MirOP::FCmp32),
+            "FCmpE32" => Some(MirOP::FCmpE32),
+            "FCmp64" => Some(MirOP::FCmp64),
+            "FCmpE64" => Some(MirOP::FCmpE64),
+            "ICCmp64R" => Some(MirOP::ICCmp64R),
+            "ICCmn64R" => Some(MirOP::ICCmn64R),
+            "ICCmp32R" => Some(MirOP::ICCmp32R),
+            "ICCmn32R" => Some(MirOP::ICCmn32R),
+            "ICCmp64I" => Some(MirOP::ICCmp64I),
+            "ICCmn64I" => Some(MirOP::ICCmn64I),
+            "ICCmp32I" => Some(MirOP::ICCmp32I),
+            "ICCmn32I" => Some(MirOP::ICCmn32I),
+            "FCCmp32" => Some(MirOP::FCCmp32),
+            "FCCmpE32" => Some(MirOP::FCCmpE32),
+            "FCCmp64" => Some(MirOP::FCCmp64),
+            "FCCmpE64" => Some(MirOP::FCCmpE64),
+            "Add64R" => Some(MirOP::Add64R),
+            "Sub64R" => Some(MirOP::Sub64R),
+            "SMax64R" => Some(MirOP::SMax64R),
+            "SMin64R" => Some(MirOP::SMin64R),
+            "UMax64R" => Some(MirOP::UMax64R),
+            "UMin64R" => Some(MirOP::UMin64R),
+            "And64R" => Some(MirOP::And64R),
+            "Bic64R" => Some(MirOP::Bic64R),
+            "EON64R" => Some(MirOP::EON64R),
+            "EOR64R" => Some(MirOP::EOR64R),
+            "ORR64R" => Some(MirOP::ORR64R),
+            "ORN64R" => Some(MirOP::ORN64R),
+            "Asr64R" => Some(MirOP::Asr64R),
+            "Lsr64R" => Some(MirOP::Lsr64R),
+            "Lsl64R" => Some(MirOP::Lsl64R),
+            "Ror64R" => Some(MirOP::Ror64R),
+            "Mul64" => Some(MirOP::Mul64),
+            "MNeg64" => Some(MirOP::MNeg64),
+            "SDiv64" => Some(MirOP::SDiv64),
+            "UDiv64" => Some(MirOP::UDiv64),
+            "SMulH" => Some(MirOP::SMulH),
+            "UMulH" => Some(MirOP::UMulH),
+            "Add32R" => Some(MirOP::Add32R),
+            "Sub32R" => Some(MirOP::Sub32R),
+            "SMax32R" => Some(MirOP::SMax32R),
+            "SMin32R" => Some(MirOP::SMin32R),
+            "UMax32R" => Some(MirOP::UMax32R),
+            "UMin32R" => Some(MirOP::UMin32R),
+            "And32R" => Some(MirOP::And32R),
+            "Bic32R" => Some(MirOP::Bic32R),
+            "EON32R" => Some(MirOP::EON32R),
+            "EOR32R" => Some(MirOP::EOR32R),
+            "ORR32R" => Some(MirOP::ORR32R),
+            "ORN32R" => Some(MirOP::ORN32R),
+            "Asr32R" => Some(MirOP::Asr32R),
+            "Lsr32R" => Some(MirOP::Lsr32R),
+            "Lsl32R" => Some(MirOP::Lsl32R),
+            "Ror32R" => Some(MirOP::Ror32R),
+            "Mul32" => Some(MirOP::Mul32),
+            "MNeg32" => Some(MirOP::MNeg32),
+            "SDiv32" => Some(MirOP::SDiv32),
+            "UDiv32" => Some(MirOP::UDiv32),
+            "SMULL" => Some(MirOP::SMULL),
+            "UMULL" => Some(MirOP::UMULL),
+            "SMNegL" => Some(MirOP::SMNegL),
+            "UMNegL" => Some(MirOP::UMNegL),
+            "Add64I" => Some(MirOP::Add64I),
+            "Sub64I" => Some(MirOP::Sub64I),
+            "Add32I" => Some(MirOP::Add32I),
+            "Sub32I" => Some(MirOP::Sub32I),
+            "Add64Sym" => Some(MirOP::Add64Sym),
+            "Sub64Sym" => Some(MirOP::Sub64Sym),
+            "And64I" => Some(MirOP::And64I),
+            "Bic64I" => Some(MirOP::Bic64I),
+            "EON64I" => Some(MirOP::EON64I),
+            "EOR64I" => Some(MirOP::EOR64I),
+            "ORR64I" => Some(MirOP::ORR64I),
+            "ORN64I" => Some(MirOP::ORN64I),
+            "And32I" => Some(MirOP::And32I),
+            "Bic32I" => Some(MirOP::Bic32I),
+            "EON32I" => Some(MirOP::EON32I),
+            "EOR32I" => Some(MirOP::EOR32I),
+            "ORR32I" => Some(MirOP::ORR32I),
+            "ORN32I" => Some(MirOP::ORN32I),
+            "SMax64I" => Some(MirOP::SMax64I),
+            "SMin64I" => Some(MirOP::SMin64I),
+            "UMax64I" => Some(MirOP::UMax64I),
+            "UMin64I" => Some(MirOP::UMin64I),
+            "SMax32I" => Some(MirOP::SMax32I),
+            "SMin32I" => Some(MirOP::SMin32I),
+            "UMax32I" => Some(MirOP::UMax32I),
+            "UMin32I" => Some(MirOP::UMin32I),
+            "Asr64I" => Some(MirOP::Asr64I),
+            "Lsr64I" => Some(MirOP::Lsr64I),
+            "Lsl64I" => Some(MirOP::Lsl64I),
+            "Ror64I" => Some(MirOP::Ror64I),
+            "Asr32I" => Some(MirOP::Asr32I),
+            "Lsr32I" => Some(MirOP::Lsr32I),
+            "Lsl32I" => Some(MirOP::Lsl32I),
+            "Ror32I" => Some(MirOP::Ror32I),
+            "FAdd64" => Some(MirOP::FAdd64),
+            "FDiv64" => Some(MirOP::FDiv64),
+            "FMul64" => Some(MirOP::FMul64),
+            "FNMul64" => Some(MirOP::FNMul64),
+            "FSub64" => Some(MirOP::FSub64),
+            "FAdd32" => Some(MirOP::FAdd32),
+            "FDiv32" => Some(MirOP::FDiv32),
+            "FMul32" => Some(MirOP::FMul32),
+            "FNMul32" => Some(MirOP::FNMul32),
+            "FSub32" => Some(MirOP::FSub32),
+            "MirCopy64" => Some(MirOP::MirCopy64),
+            "MirCopy32" => Some(MirOP::MirCopy32),
+            "MirFCopy128" => Some(MirOP::MirFCopy128),
+            "MirFCopy64" => Some(MirOP::MirFCopy64),
+            "MirFCopy32" => Some(MirOP::MirFCopy32),
+            "MirPCopy" => Some(MirOP::MirPCopy),
+            "Neg64R" => Some(MirOP::Neg64R),
+            "MVN64R" => Some(MirOP::MVN64R),
+            "Mov64R" => Some(MirOP::Mov64R),
+            "Abs64R" => Some(MirOP::Abs64R),
+            "CLS64" => Some(MirOP::CLS64),
+            "CLZ64" => Some(MirOP::CLZ64),
+            "CNT64" => Some(MirOP::CNT64),
+            "CTZ64" => Some(MirOP::CTZ64),
+            "RBit64" => Some(MirOP::RBit64),
+            "LoadStackPosGr64" => Some(MirOP::LoadStackPosGr64),
+            "StoreStackPosGr64" => Some(MirOP::StoreStackPosGr64),
+            "Neg32R" => Some(MirOP::Neg32R),
+            "MVN32R" => Some(MirOP::MVN32R),
+            "Mov32R" => Some(MirOP::Mov32R),
+            "Abs32R" => Some(MirOP::Abs32R),
+            "CLS32" => Some(MirOP::CLS32),
+            "CLZ32" => Some(MirOP::CLZ32),
+            "CNT32" => Some(MirOP::CNT32),
+            "CTZ32" => Some(MirOP::CTZ32),
+            "RBit32" => Some(MirOP::RBit32),
+            "SXTB32" => Some(MirOP::SXTB32),
+            "SXTH32" => Some(MirOP::SXTH32),
+            "SXTW32" => Some(MirOP::SXTW32),
+            "UXTB32" => Some(MirOP::UXTB32),
+            "UXTH32" => Some(MirOP::UXTH32),
+            "LoadStackPosGr32" => Some(MirOP::LoadStackPosGr32),
+            "StoreStackPosGr32" => Some(MirOP::StoreStackPosGr32),
+            "SXTB64" => Some(MirOP::SXTB64),
+            "SXTH64" => Some(MirOP::SXTH64),
+            "SXTW64" => Some(MirOP::SXTW64),
+            "UXTB64" => Some(MirOP::UXTB64),
+            "UXTH64" => Some(MirOP::UXTH64),
+            "Mov64I" => Some(MirOP::Mov64I),
+            "MovZ64" => Some(MirOP::MovZ64),
+            "MovN64" => Some(MirOP::MovN64),
+            "MovK64" => Some(MirOP::MovK64),
+            "Mov32I" => Some(MirOP::Mov32I),
+            "MovZ32" => Some(MirOP::MovZ32),
+            "MovN32" => Some(MirOP::MovN32),
+            "MovK32" => Some(MirOP::MovK32),
+            "AdrP" => Some(MirOP::AdrP),
+            "Adr" => Some(MirOP::Adr),
+            "FMovFG64" => Some(MirOP::FMovFG64),
+            "SCvtF64" => Some(MirOP::SCvtF64),
+            "UCvtF64" => Some(MirOP::UCvtF64),
+            "FMovGF64" => Some(MirOP::FMovGF64),
+            "FCvtAS64" => Some(MirOP::FCvtAS64),
+            "FCvtAU64" => Some(MirOP::FCvtAU64),
+            "FCvtMS64" => Some(MirOP::FCvtMS64),
+            "FCvtMU64" => Some(MirOP::FCvtMU64),
+            "FCvtNS64" => Some(MirOP::FCvtNS64),
+            "FCvtNU64" => Some(MirOP::FCvtNU64),
+            "FCvtPS64" => Some(MirOP::FCvtPS64),
+            "FCvtPU64" => Some(MirOP::FCvtPU64),
+            "FCvtZS64" => Some(MirOP::FCvtZS64),
+            "FCvtZU64" => Some(MirOP::FCvtZU64),
+            "SCvtF64G32" => Some(MirOP::SCvtF64G32),
+            "UCvtF64G32" => Some(MirOP::UCvtF64G32),
+            "FMovFG32" => Some(MirOP::FMovFG32),
+            "SCvtF32" => Some(MirOP::SCvtF32),
+            "UCvtF32" => Some(MirOP::UCvtF32),
+            "SCvtF32G64" => Some(MirOP::SCvtF32G64),
+            "UCvtF32G64" => Some(MirOP::UCvtF32G64),
+            "FMovGF32" => Some(MirOP::FMovGF32),
+            "FCvtAS32" => Some(MirOP::FCvtAS32),
+            "FCvtAU32" => Some(MirOP::FCvtAU32),
+            "FCvtMS32" => Some(MirOP::FCvtMS32),
+            "FCvtMU32" => Some(MirOP::FCvtMU32),
+            "FCvtNS32" => Some(MirOP::FCvtNS32),
+            "FCvtNU32" => Some(MirOP::FCvtNU32),
+            "FCvtPS32" => Some(MirOP::FCvtPS32),
+            "FCvtPU32" => Some(MirOP::FCvtPU32),
+            "FCvtZS32" => Some(MirOP::FCvtZS32),
+            "FCvtZU32" => Some(MirOP::FCvtZU32),
+            "FCvtAS64F32" => Some(MirOP::FCvtAS64F32),
+            "FCvtAU64F32" => Some(MirOP::FCvtAU64F32),
+            "FCvtMS64F32" => Some(MirOP::FCvtMS64F32),
+            "FCvtMU64F32" => Some(MirOP::FCvtMU64F32),
+            "FCvtNS64F32" => Some(MirOP::FCvtNS64F32),
+            "FCvtNU64F32" => Some(MirOP::FCvtNU64F32),
+            "FCvtPS64F32" => Some(MirOP::FCvtPS64F32),
+            "FCvtPU64F32" => Some(MirOP::FCvtPU64F32),
+            "FCvtZS64F32" => Some(MirOP::FCvtZS64F32),
+            "FCvtZU64F32" => Some(MirOP::FCvtZU64F32),
+            "FCvtAS32F64" => Some(MirOP::FCvtAS32F64),
+            "FCvtAU32F64" => Some(MirOP::FCvtAU32F64),
+            "FMov64R" => Some(MirOP::FMov64R),
+            "FRIntA64" => Some(MirOP::FRIntA64),
+            "FRIntI64" => Some(MirOP::FRIntI64),
+            "FRIntM64" => Some(MirOP::FRIntM64),
+            "FRIntN64" => Some(MirOP::FRIntN64),
+            "FRIntP64" => Some(MirOP::FRIntP64),
+            "FRIntX64" => Some(MirOP::FRIntX64),
+            "FRIntZ64" => Some(MirOP::FRIntZ64),
+            "FRInt32X64" => Some(MirOP::FRInt32X64),
+            "FRIntZ32X64" => Some(MirOP::FRIntZ32X64),
+            "FRInt64X64" => Some(MirOP::FRInt64X64),
+            "FRIntZ64X64" => Some(MirOP::FRIntZ64X64),
+            "FAbs64" => Some(MirOP::FAbs64),
+            "FNeg64" => Some(MirOP::FNeg64),
+            "FSqrt64" => Some(MirOP::FSqrt64),
+            "LoadStackPosF64" => Some(MirOP::LoadStackPosF64),
+            "StoreStackPosF64" => Some(MirOP::StoreStackPosF64),
+            "FMov32R" => Some(MirOP::FMov32R),
+            "FRIntA32" => Some(MirOP::FRIntA32),
+            "FRIntI32" => Some(MirOP::FRIntI32),
+            "FRIntM32" => Some(MirOP::FRIntM32),
+            "FRIntN32" => Some(MirOP::FRIntN32),
+            "FRIntP32" => Some(MirOP::FRIntP32),
+            "FRIntX32" => Some(MirOP::FRIntX32),
+            "FRIntZ32" => Some(MirOP::FRIntZ32),
+            "FRInt32X32" => Some(MirOP::FRInt32X32),
+            "FRIntZ32X32" => Some(MirOP::FRIntZ32X32),
+            "FRInt64X32" => Some(MirOP::FRInt64X32),
+            "FRIntZ64X32" => Some(MirOP::FRIntZ64X32),
+            "FAbs32" => Some(MirOP::FAbs32),
+            "FNeg32" => Some(MirOP::FNeg32),
+            "FSqrt32" => Some(MirOP::FSqrt32),
+            "LoadStackPosF32" => Some(MirOP::LoadStackPosF32),
+            "StoreStackPosF32" => Some(MirOP::StoreStackPosF32),
+            "FCvt32F64" => Some(MirOP::FCvt32F64),
+            "FCvt64F32" => Some(MirOP::FCvt64F32),
+            "FMov64I" => Some(MirOP::FMov64I),
+            "FMov32I" => Some(MirOP::FMov32I),
+            "MAdd64" => Some(MirOP::MAdd64),
+            "MSub64" => Some(MirOP::MSub64),
+            "SMAddL" => Some(MirOP::SMAddL),
+            "SMSubL" => Some(MirOP::SMSubL),
+            "UMAddL" => Some(MirOP::UMAddL),
+            "UMSubL" => Some(MirOP::UMSubL),
+            "MAdd32" => Some(MirOP::MAdd32),
+            "MSub32" => Some(MirOP::MSub32),
+            "FMAdd64" => Some(MirOP::FMAdd64),
+            "FMSub64" => Some(MirOP::FMSub64),
+            "FNMAdd64" => Some(MirOP::FNMAdd64),
+            "FNMSub64" => Some(MirOP::FNMSub64),
+            "FMAdd32" => Some(MirOP::FMAdd32),
+            "FMSub32" => Some(MirOP::FMSub32),
+            "FNMAdd32" => Some(MirOP::FNMAdd32),
+            "FNMSub32" => Some(MirOP::FNMSub32),
+            "LdrGr64" => Some(MirOP::LdrGr64),
+            "LdrBGr64" => Some(MirOP::LdrBGr64),
+            "LdrHGr64" => Some(MirOP::LdrHGr64),
+            "LdrSBGr64" => Some(MirOP::LdrSBGr64),
+            "LdrSHGr64" => Some(MirOP::LdrSHGr64),
+            "LdrGr32" => Some(MirOP::LdrGr32),
+            "LdrBGr32" => Some(MirOP::LdrBGr32),
+            "LdrHGr32" => Some(MirOP::LdrHGr32),
+            "LdrSBGr32" => Some(MirOP::LdrSBGr32),
+            "LdrSHGr32" => Some(MirOP::LdrSHGr32),
+            "LdrF64" => Some(MirOP::LdrF64),
+            "LdrF32" => Some(MirOP::LdrF32),
+            "StrGr64" => Some(MirOP::StrGr64),
+            "StrBGr64" => Some(MirOP::StrBGr64),
+            "StrHGr64" => Some(MirOP::StrHGr64),
+            "StrGr32" => Some(MirOP::StrGr32),
+            "StrBGr32" => Some(MirOP::StrBGr32),
+            "StrHGr32" => Some(MirOP::StrHGr32),
+            "StrF64" => Some(MirOP::StrF64),
+            "StrF32" => Some(MirOP::StrF32),
+            "LdrGr64Base" => Some(MirOP::LdrGr64Base),
+            "LdrBGr64Base" => Some(MirOP::LdrBGr64Base),
+            "LdrHGr64Base" => Some(MirOP::LdrHGr64Base),
+            "LdrSBGr64Base" => Some(MirOP::LdrSBGr64Base),
+            "LdrSHGr64Base" => Some(MirOP::LdrSHGr64Base),
+            "LdrGr32Base" => Some(MirOP::LdrGr32Base),
+            "LdrBGr32Base" => Some(MirOP::LdrBGr32Base),
+            "LdrHGr32Base" => Some(MirOP::LdrHGr32Base),
+            "LdrSBGr32Base" => Some(MirOP::LdrSBGr32Base),
+            "LdrSHGr32Base" => Some(MirOP::LdrSHGr32Base),
+            "LdrF128Base" => Some(MirOP::LdrF128Base),
+            "LdrF64Base" => Some(MirOP::LdrF64Base),
+            "LdrF32Base" => Some(MirOP::LdrF32Base),
+            "LdrGr64BaseS" => Some(MirOP::LdrGr64BaseS),
+            "LdrBGr64BaseS" => Some(MirOP::LdrBGr64BaseS),
+            "LdrHGr64BaseS" => Some(MirOP::LdrHGr64BaseS),
+            "LdrSBGr64BaseS" => Some(MirOP::LdrSBGr64BaseS),
+            "LdrSHGr64BaseS" => Some(MirOP::LdrSHGr64BaseS),
+            "LdrGr32BaseS" => Some(MirOP::LdrGr32BaseS),
+            "LdrBGr32BaseS" => Some(MirOP::LdrBGr32BaseS),
+            "LdrHGr32BaseS" => Some(MirOP::LdrHGr32BaseS),
+            "LdrSBGr32BaseS" => Some(MirOP::LdrSBGr32BaseS),
+            "LdrSHGr32BaseS" => Some(MirOP::LdrSHGr32BaseS),
+            "LdrF128BaseS" => Some(MirOP::LdrF128BaseS),
+            "LdrF64BaseS" => Some(MirOP::LdrF64BaseS),
+            "LdrF32BaseS" => Some(MirOP::LdrF32BaseS),
+            "StrGr64Base" => Some(MirOP::StrGr64Base),
+            "StrBGr64Base" => Some(MirOP::StrBGr64Base),
+            "StrHGr64Base" => Some(MirOP::StrHGr64Base),
+            "StrGr32Base" => Some(MirOP::StrGr32Base),
+            "StrBGr32Base" => Some(MirOP::StrBGr32Base),
+            "StrHGr32Base" => Some(MirOP::StrHGr32Base),
+            "StrF128Base" => Some(MirOP::StrF128Base),
+            "StrF64Base" => Some(MirOP::StrF64Base),
+            "StrF32Base" => Some(MirOP::StrF32Base),
+            "StrGr64BaseS" => Some(MirOP::StrGr64BaseS),
+            "StrBGr64BaseS" => Some(MirOP::StrBGr64BaseS),
+            "StrHGr64BaseS" => Some(MirOP::StrHGr64BaseS),
+            "StrGr32BaseS" => Some(MirOP::StrGr32BaseS),
+            "StrBGr32BaseS" => Some(MirOP::StrBGr32BaseS),
+            "StrHGr32BaseS" => Some(MirOP::StrHGr32BaseS),
+            "StrF128BaseS" => Some(MirOP::StrF128BaseS),
+            "StrF64BaseS" => Some(MirOP::StrF64BaseS),
+            "StrF32BaseS" => Some(MirOP::StrF32BaseS),
+            "LdrGr64Indexed" => Some(MirOP::LdrGr64Indexed),
+            "LdrBGr64Indexed" => Some(MirOP::LdrBGr64Indexed),
+            "LdrHGr64Indexed" => Some(MirOP::LdrHGr64Indexed),
+            "LdrSBGr64Indexed" => Some(MirOP::LdrSBGr64Indexed),
+            "LdrSHGr64Indexed" => Some(MirOP::LdrSHGr64Indexed),
+            "LdrGr32Indexed" => Some(MirOP::LdrGr32Indexed),
+            "LdrBGr32Indexed" => Some(MirOP::LdrBGr32Indexed),
+            "LdrHGr32Indexed" => Some(MirOP::LdrHGr32Indexed),
+            "LdrSBGr32Indexed" => Some(MirOP::LdrSBGr32Indexed),
+            "LdrSHGr32Indexed" => Some(MirOP::LdrSHGr32Indexed),
+            "LdrF64Indexed" => Some(MirOP::LdrF64Indexed),
+            "LdrF32Indexed" => Some(MirOP::LdrF32Indexed),
+            "StrGr64Indexed" => Some(MirOP::StrGr64Indexed),
+            "StrBGr64Indexed" => Some(MirOP::StrBGr64Indexed),
+            "StrHGr64Indexed" => Some(MirOP::StrHGr64Indexed),
+            "StrGr32Indexed" => Some(MirOP::StrGr32Indexed),
+            "StrBGr32Indexed" => Some(MirOP::StrBGr32Indexed),
+            "StrHGr32Indexed" => Some(MirOP::StrHGr32Indexed),
+            "StrF64Indexed" => Some(MirOP::StrF64Indexed),
+            "StrF32Indexed" => Some(MirOP::StrF32Indexed),
+            "LdrGr64Literal" => Some(MirOP::LdrGr64Literal),
+            "LdrBGr64Literal" => Some(MirOP::LdrBGr64Literal),
+            "LdrHGr64Literal" => Some(MirOP::LdrHGr64Literal),
+            "LdrSBGr64Literal" => Some(MirOP::LdrSBGr64Literal),
+            "LdrSHGr64Literal" => Some(MirOP::LdrSHGr64Literal),
+            "LdrGr32Literal" => Some(MirOP::LdrGr32Literal),
+            "LdrBGr32Literal" => Some(MirOP::LdrBGr32Literal),
+            "LdrHGr32Literal" => Some(MirOP::LdrHGr32Literal),
+            "LdrSBGr32Literal" => Some(MirOP::LdrSBGr32Literal),
+            "LdrSHGr32Literal" => Some(MirOP::LdrSHGr32Literal),
+            "LdrF64Literal" => Some(MirOP::LdrF64Literal),
+            "LdrF32Literal" => Some(MirOP::LdrF32Literal),
+            "MirLdrLitG64" => Some(MirOP::MirLdrLitG64),
+            "MirLdrLitG32" => Some(MirOP::MirLdrLitG32),
+            "MirLdrLitF64" => Some(MirOP::MirLdrLitF64),
+            "MirLdrLitF32" => Some(MirOP::MirLdrLitF32),
+            "MirStrLitG64" => Some(MirOP::MirStrLitG64),
+            "MirStrLitG32" => Some(MirOP::MirStrLitG32),
+            "MirStrLitF64" => Some(MirOP::MirStrLitF64),
+            "MirStrLitF32" => Some(MirOP::MirStrLitF32),
+            "MirStImm64" => Some(MirOP::MirStImm64),
+            "MirStSym64" => Some(MirOP::MirStSym64),
+            "MirStImm32" => Some(MirOP::MirStImm32),
+            "MirStImm64Sym" => Some(MirOP::MirStImm64Sym),
+            "MirStImm32Sym" => Some(MirOP::MirStImm32Sym),
+            "MirStSymSym" => Some(MirOP::MirStSymSym),
+            "LoadConst64" => Some(MirOP::LoadConst64),
+            "LoadConst64Symbol" => Some(MirOP::LoadConst64Symbol),
+            "MirLdImmF64" => Some(MirOP::MirLdImmF64),
+            "MirLdImmF32" => Some(MirOP::MirLdImmF32),
+            "MirDynAlloca" => Some(MirOP::MirDynAlloca),
+            "CSel64" => Some(MirOP::CSel64),
+            "CSInc64" => Some(MirOP::CSInc64),
+            "CSInv64" => Some(MirOP::CSInv64),
+            "CSNeg64" => Some(MirOP::CSNeg64),
+            "CSel32" => Some(MirOP::CSel32),
+            "CSInc32" => Some(MirOP::CSInc32),
+            "CSInv32" => Some(MirOP::CSInv32),
+            "CSNeg32" => Some(MirOP::CSNeg32),
+            "CSelF64" => Some(MirOP::CSelF64),
+            "CSelF32" => Some(MirOP::CSelF32),
+            "CSet64" => Some(MirOP::CSet64),
+            "CSet32" => Some(MirOP::CSet32),
+            "MirCall" => Some(MirOP::MirCall),
+            "MirReturn" => Some(MirOP::MirReturn),
+            "MirSwitch" => Some(MirOP::MirSwitch),
+            "MirSaveRegs" => Some(MirOP::MirSaveRegs),
+            "MirRestoreRegs" => Some(MirOP::MirRestoreRegs),
+            "MirRestoreHostRegs" => Some(MirOP::MirRestoreHostRegs),
+            "MirGEP" => Some(MirOP::MirGEP),
+            "MirComment" => Some(MirOP::MirComment),
+            "MirCommentedInst" => Some(MirOP::MirCommentedInst),
+            _ => None,
+        }
+    }
+
+    /// 承载这个操作码的指令类.
+    pub fn get_class(self) -> MirInstClass {
+        match self {
+            MirOP::BCond | MirOP::BCCond => MirInstClass::CondBr,
+            MirOP::B => MirInstClass::UncondBr,
+            MirOP::Br | MirOP::Ret => MirInstClass::BReg,
+            MirOP::BLink => MirInstClass::BLinkLabel,
+            MirOP::BLinkGlobal => MirInstClass::BLinkGlobal,
+            MirOP::BLinkReg => MirInstClass::BLinkReg,
+            MirOP::CBZ | MirOP::CBNZ => MirInstClass::CBZs,
+            MirOP::TBZ64 | MirOP::TBNZ64 => MirInstClass::TBZ64,
+            MirOP::TBZ32 | MirOP::TBNZ32 => MirInstClass::TBZ32,
+            MirOP::Brk | MirOP::Udf => MirInstClass::Trap,
+            MirOP::ICmp64R | MirOP::ICmn64R => MirInstClass::ICmp64R,
+            MirOP::ICmp32R | MirOP::ICmn32R => MirInstClass::ICmp32R,
+            MirOP::ICmp64I | MirOP::ICmn64I => MirInstClass::ICmp64I,
+            MirOP::ICmp32I | MirOP::ICmn32I => MirInstClass::ICmp32I,
+            MirOP::FCmp32 | MirOP::FCmpE32 => MirInstClass::FCmp32,
+            MirOP::FCmp64 | MirOP::FCmpE64 => MirInstClass::FCmp64,
+            MirOP::ICCmp64R | MirOP::ICCmn64R => MirInstClass::ICCmp64R,
+            MirOP::ICCmp32R | MirOP::ICCmn32R => MirInstClass::ICCmp32R,
+            MirOP::ICCmp64I | MirOP::ICCmn64I => MirInstClass::ICCmp64I,
+            MirOP::ICCmp32I | MirOP::ICCmn32I => MirInstClass::ICCmp32I,
+            MirOP::FCCmp32 | MirOP::FCCmpE32 => MirInstClass::FCCmp32,
+            MirOP::FCCmp64 | MirOP::FCCmpE64 => MirInstClass::FCCmp64,
+            MirOP::Add64R | MirOP::Sub64R | MirOP::SMax64R | MirOP::SMin64R | MirOP::UMax64R | MirOP::UMin64R | MirOP::And64R | MirOP::Bic64R | MirOP::EON64R | MirOP::EOR64R | MirOP::ORR64R | MirOP::ORN64R | MirOP::Asr64R | MirOP::Lsr64R | MirOP::Lsl64R | MirOP::Ror64R | MirOP::Mul64 | MirOP::MNeg64 | MirOP::SDiv64 | MirOP::UDiv64 | MirOP::SMulH | MirOP::UMulH => MirInstClass::Bin64R,
+            MirOP::Add32R | MirOP::Sub32R | MirOP::SMax32R | MirOP::SMin32R | MirOP::UMax32R | MirOP::UMin32R | MirOP::And32R | MirOP::Bic32R | MirOP::EON32R | MirOP::EOR32R | MirOP::ORR32R | MirOP::ORN32R | MirOP::Asr32R | MirOP::Lsr32R | MirOP::Lsl32R | MirOP::Ror32R | MirOP::Mul32 | MirOP::MNeg32 | MirOP::SDiv32 | MirOP::UDiv32 => MirInstClass::Bin32R,
+            MirOP::SMULL | MirOP::UMULL | MirOP::SMNegL | MirOP::UMNegL => MirInstClass::MulL,
+            MirOP::Add64I | MirOP::Sub64I => MirInstClass::Bin64RC,
+            MirOP::Add32I | MirOP::Sub32I => MirInstClass::Bin32RC,
+            MirOP::Add64Sym | MirOP::Sub64Sym => MirInstClass::Bin64RSym,
+            MirOP::And64I | MirOP::Bic64I | MirOP::EON64I | MirOP::EOR64I | MirOP::ORR64I | MirOP::ORN64I => MirInstClass::Bin64RL,
+            MirOP::And32I | MirOP::Bic32I | MirOP::EON32I | MirOP::EOR32I | MirOP::ORR32I | MirOP::ORN32I => MirInstClass::Bin32RL,
+            MirOP::SMax64I | MirOP::SMin64I => MirInstClass::Bin64RS,
+            MirOP::UMax64I | MirOP::UMin64I => MirInstClass::Bin64RU,
+            MirOP::SMax32I | MirOP::SMin32I => MirInstClass::Bin32RS,
+            MirOP::UMax32I | MirOP::UMin32I => MirInstClass::Bin32RU,
+            MirOP::Asr64I | MirOP::Lsr64I | MirOP::Lsl64I | MirOP::Ror64I => MirInstClass::Bin64RShift,
+            MirOP::Asr32I | MirOP::Lsr32I | MirOP::Lsl32I | MirOP::Ror32I => MirInstClass::Bin32RShift,
+            MirOP::FAdd64 | MirOP::FDiv64 | MirOP::FMul64 | MirOP::FNMul64 | MirOP::FSub64 => MirInstClass::BinF64R,
+            MirOP::FAdd32 | MirOP::FDiv32 | MirOP::FMul32 | MirOP::FNMul32 | MirOP::FSub32 => MirInstClass::BinF32R,
+            MirOP::MirCopy64 => MirInstClass::MirCopy64,
+            MirOP::MirCopy32 => MirInstClass::MirCopy32,
+            MirOP::MirFCopy128 => MirInstClass::MirFCopy128,
+            MirOP::MirFCopy64 => MirInstClass::MirFCopy64,
+            MirOP::MirFCopy32 => MirInstClass::MirFCopy32,
+            MirOP::MirPCopy => MirInstClass::MirPCopy,
+            MirOP::Neg64R | MirOP::MVN64R | MirOP::Mov64R | MirOP::Abs64R | MirOP::CLS64 | MirOP::CLZ64 | MirOP::CNT64 | MirOP::CTZ64 | MirOP::RBit64 | MirOP::LoadStackPosGr64 | MirOP::StoreStackPosGr64 => MirInstClass::Una64R,
+            MirOP::Neg32R | MirOP::MVN32R | MirOP::Mov32R | MirOP::Abs32R | MirOP::CLS32 | MirOP::CLZ32 | MirOP::CNT32 | MirOP::CTZ32 | MirOP::RBit32 | MirOP::SXTB32 | MirOP::SXTH32 | MirOP::SXTW32 | MirOP::UXTB32 | MirOP::UXTH32 | MirOP::LoadStackPosGr32 | MirOP::StoreStackPosGr32 => MirInstClass::Una32R,
+            MirOP::SXTB64 | MirOP::SXTH64 | MirOP::SXTW64 | MirOP::UXTB64 | MirOP::UXTH64 => MirInstClass::ExtR,
+            MirOP::Mov64I => MirInstClass::Mov64I,
+            MirOP::MovZ64 | MirOP::MovN64 | MirOP::MovK64 => MirInstClass::MovZNK64,
+            MirOP::Mov32I => MirInstClass::Mov32I,
+            MirOP::MovZ32 | MirOP::MovN32 | MirOP::MovK32 => MirInstClass::MovZNK32,
+            MirOP::AdrP | MirOP::Adr => MirInstClass::Adr,
+            MirOP::FMovFG64 | MirOP::SCvtF64 | MirOP::UCvtF64 => MirInstClass::UnaFG64,
+            MirOP::FMovGF64 | MirOP::FCvtAS64 | MirOP::FCvtAU64 | MirOP::FCvtMS64 | MirOP::FCvtMU64 | MirOP::FCvtNS64 | MirOP::FCvtNU64 | MirOP::FCvtPS64 | MirOP::FCvtPU64 | MirOP::FCvtZS64 | MirOP::FCvtZU64 => MirInstClass::UnaGF64,
+            MirOP::SCvtF64G32 | MirOP::UCvtF64G32 => MirInstClass::UnaF64G32,
+            MirOP::FMovFG32 | MirOP::SCvtF32 | MirOP::UCvtF32 => MirInstClass::UnaFG32,
+            MirOP::SCvtF32G64 | MirOP::UCvtF32G64 => MirInstClass::UnaF32G64,
+            MirOP::FMovGF32 | MirOP::FCvtAS32 | MirOP::FCvtAU32 | MirOP::FCvtMS32 | MirOP::FCvtMU32 | MirOP::FCvtNS32 | MirOP::FCvtNU32 | MirOP::FCvtPS32 | MirOP::FCvtPU32 | MirOP::FCvtZS32 | MirOP::FCvtZU32 => MirInstClass::UnaGF32,
+            MirOP::FCvtAS64F32 | MirOP::FCvtAU64F32 | MirOP::FCvtMS64F32 | MirOP::FCvtMU64F32 | MirOP::FCvtNS64F32 | MirOP::FCvtNU64F32 | MirOP::FCvtPS64F32 | MirOP::FCvtPU64F32 | MirOP::FCvtZS64F32 | MirOP::FCvtZU64F32 => MirInstClass::UnaG64F32,
+            MirOP::FCvtAS32F64 | MirOP::FCvtAU32F64 => MirInstClass::UnaG32F64,
+            MirOP::FMov64R | MirOP::FRIntA64 | MirOP::FRIntI64 | MirOP::FRIntM64 | MirOP::FRIntN64 | MirOP::FRIntP64 | MirOP::FRIntX64 | MirOP::FRIntZ64 | MirOP::FRInt32X64 | MirOP::FRIntZ32X64 | MirOP::FRInt64X64 | MirOP::FRIntZ64X64 | MirOP::FAbs64 | MirOP::FNeg64 | MirOP::FSqrt64 | MirOP::LoadStackPosF64 | MirOP::StoreStackPosF64 => MirInstClass::UnaF64,
+            MirOP::FMov32R | MirOP::FRIntA32 | MirOP::FRIntI32 | MirOP::FRIntM32 | MirOP::FRIntN32 | MirOP::FRIntP32 | MirOP::FRIntX32 | MirOP::FRIntZ32 | MirOP::FRInt32X32 | MirOP::FRIntZ32X32 | MirOP::FRInt64X32 | MirOP::FRIntZ64X32 | MirOP::FAbs32 | MirOP::FNeg32 | MirOP::FSqrt32 | MirOP::LoadStackPosF32 | MirOP::StoreStackPosF32 => MirInstClass::UnaF32,
+            MirOP::FCvt32F64 => MirInstClass::UnaryF32F64,
+            MirOP::FCvt64F32 => MirInstClass::UnaryF64F32,
+            MirOP::FMov64I => MirInstClass::FMov64I,
+            MirOP::FMov32I => MirInstClass::FMov32I,
+            MirOP::MAdd64 | MirOP::MSub64 => MirInstClass::TenaryG64,
+            MirOP::SMAddL | MirOP::SMSubL | MirOP::UMAddL | MirOP::UMSubL => MirInstClass::TenaryG64G32,
+            MirOP::MAdd32 | MirOP::MSub32 => MirInstClass::TenaryG32,
+            MirOP::FMAdd64 | MirOP::FMSub64 | MirOP::FNMAdd64 | MirOP::FNMSub64 => MirInstClass::TenaryF64,
+            MirOP::FMAdd32 | MirOP::FMSub32 | MirOP::FNMAdd32 | MirOP::FNMSub32 => MirInstClass::TenaryF32,
+            MirOP::LdrGr64 | MirOP::LdrBGr64 | MirOP::LdrHGr64 | MirOP::LdrSBGr64 | MirOP::LdrSHGr64 => MirInstClass::LoadGr64,
+            MirOP::LdrGr32 | MirOP::LdrBGr32 | MirOP::LdrHGr32 | MirOP::LdrSBGr32 | MirOP::LdrSHGr32 => MirInstClass::LoadGr32,
+            MirOP::LdrF64 => MirInstClass::LoadF64,
+            MirOP::LdrF32 => MirInstClass::LoadF32,
+            MirOP::StrGr64 | MirOP::StrBGr64 | MirOP::StrHGr64 => MirInstClass::StoreGr64,
+            MirOP::StrGr32 | MirOP::StrBGr32 | MirOP::StrHGr32 => MirInstClass::StoreGr32,
+            MirOP::StrF64 => MirInstClass::StoreF64,
+            MirOP::StrF32 => MirInstClass::StoreF32,
+            MirOP::LdrGr64Base | MirOP::LdrBGr64Base | MirOP::LdrHGr64Base | MirOP::LdrSBGr64Base | MirOP::LdrSHGr64Base => MirInstClass::LoadGr64Base,
+            MirOP::LdrGr32Base | MirOP::LdrBGr32Base | MirOP::LdrHGr32Base | MirOP::LdrSBGr32Base | MirOP::LdrSHGr32Base => MirInstClass::LoadGr32Base,
+            MirOP::LdrF128Base => MirInstClass::LoadF128Base,
+            MirOP::LdrF64Base => MirInstClass::LoadF64Base,
+            MirOP::LdrF32Base => MirInstClass::LoadF32Base,
+            MirOP::LdrGr64BaseS | MirOP::LdrBGr64BaseS | MirOP::LdrHGr64BaseS | MirOP::LdrSBGr64BaseS | MirOP::LdrSHGr64BaseS => MirInstClass::LoadGr64BaseS,
+            MirOP::LdrGr32BaseS | MirOP::LdrBGr32BaseS | MirOP::LdrHGr32BaseS | MirOP::LdrSBGr32BaseS | MirOP::LdrSHGr32BaseS => MirInstClass::LoadGr32BaseS,
+            MirOP::LdrF128BaseS => MirInstClass::LoadF128BaseS,
+            MirOP::LdrF64BaseS => MirInstClass::LoadF64BaseS,
+            MirOP::LdrF32BaseS => MirInstClass::LoadF32BaseS,
+            MirOP::StrGr64Base | MirOP::StrBGr64Base | MirOP::StrHGr64Base => MirInstClass::StoreGr64Base,
+            MirOP::StrGr32Base | MirOP::StrBGr32Base | MirOP::StrHGr32Base => MirInstClass::StoreGr32Base,
+            MirOP::StrF128Base => MirInstClass::StoreF128Base,
+            MirOP::StrF64Base => MirInstClass::StoreF64Base,
+            MirOP::StrF32Base => MirInstClass::StoreF32Base,
+            MirOP::StrGr64BaseS | MirOP::StrBGr64BaseS | MirOP::StrHGr64BaseS => MirInstClass::StoreGr64BaseS,
+            MirOP::StrGr32BaseS | MirOP::StrBGr32BaseS | MirOP::StrHGr32BaseS => MirInstClass::StoreGr32BaseS,
+            MirOP::StrF128BaseS => MirInstClass::StoreF128BaseS,
+            MirOP::StrF64BaseS => MirInstClass::StoreF64BaseS,
+            MirOP::StrF32BaseS => MirInstClass::StoreF32BaseS,
+            MirOP::LdrGr64Indexed | MirOP::LdrBGr64Indexed | MirOP::LdrHGr64Indexed | MirOP::LdrSBGr64Indexed | MirOP::LdrSHGr64Indexed => MirInstClass::LoadGr64Indexed,
+            MirOP::LdrGr32Indexed | MirOP::LdrBGr32Indexed | MirOP::LdrHGr32Indexed | MirOP::LdrSBGr32Indexed | MirOP::LdrSHGr32Indexed => MirInstClass::LoadGr32Indexed,
+            MirOP::LdrF64Indexed => MirInstClass::LoadF64Indexed,
+            MirOP::LdrF32Indexed => MirInstClass::LoadF32Indexed,
+            MirOP::StrGr64Indexed | MirOP::StrBGr64Indexed | MirOP::StrHGr64Indexed => MirInstClass::StoreGr64Indexed,
+            MirOP::StrGr32Indexed | MirOP::StrBGr32Indexed | MirOP::StrHGr32Indexed => MirInstClass::StoreGr32Indexed,
+            MirOP::StrF64Indexed => MirInstClass::StoreF64Indexed,
+            MirOP::StrF32Indexed => MirInstClass::StoreF32Indexed,
+            MirOP::LdrGr64Literal | MirOP::LdrBGr64Literal | MirOP::LdrHGr64Literal | MirOP::LdrSBGr64Literal | MirOP::LdrSHGr64Literal => MirInstClass::LoadGr64Literal,
+            MirOP::LdrGr32Literal | MirOP::LdrBGr32Literal | MirOP::LdrHGr32Literal | MirOP::LdrSBGr32Literal | MirOP::LdrSHGr32Literal => MirInstClass::LoadGr32Literal,
+            MirOP::LdrF64Literal => MirInstClass::LoadF64Literal,
+            MirOP::LdrF32Literal => MirInstClass::LoadF32Literal,
+            MirOP::MirLdrLitG64 => MirInstClass::MirLdrLitG64,
+            MirOP::MirLdrLitG32 => MirInstClass::MirLdrLitG32,
+            MirOP::MirLdrLitF64 => MirInstClass::MirLdrLitF64,
+            MirOP::MirLdrLitF32 => MirInstClass::MirLdrLitF32,
+            MirOP::MirStrLitG64 => MirInstClass::MirStrLitG64,
+            MirOP::MirStrLitG32 => MirInstClass::MirStrLitG32,
+            MirOP::MirStrLitF64 => MirInstClass::MirStrLitF64,
+            MirOP::MirStrLitF32 => MirInstClass::MirStrLitF32,
+            MirOP::MirStImm64 => MirInstClass::MirStImm64,
+            MirOP::MirStSym64 => MirInstClass::MirStSym64,
+            MirOP::MirStImm32 => MirInstClass::MirStImm32,
+            MirOP::MirStImm64Sym => MirInstClass::MirStImm64Sym,
+            MirOP::MirStImm32Sym => MirInstClass::MirStImm32Sym,
+            MirOP::MirStSymSym => MirInstClass::MirStSym64Sym,
+            MirOP::LoadConst64 => MirInstClass::LoadConst64,
+            MirOP::LoadConst64Symbol => MirInstClass::LoadConst64Symbol,
+            MirOP::MirLdImmF64 => MirInstClass::MirLdImmF64,
+            MirOP::MirLdImmF32 => MirInstClass::MirLdImmF32,
+            MirOP::MirDynAlloca => MirInstClass::MirDynAlloca,
+            MirOP::CSel64 | MirOP::CSInc64 | MirOP::CSInv64 | MirOP::CSNeg64 => MirInstClass::CSel64,
+            MirOP::CSel32 | MirOP::CSInc32 | MirOP::CSInv32 | MirOP::CSNeg32 => MirInstClass::CSel32,
+            MirOP::CSelF64 => MirInstClass::CSelF64,
+            MirOP::CSelF32 => MirInstClass::CSelF32,
+            MirOP::CSet64 => MirInstClass::CSet64,
+            MirOP::CSet32 => MirInstClass::CSet32,
+            MirOP::MirCall => MirInstClass::MirCall,
+            MirOP::MirReturn => MirInstClass::MirReturn,
+            MirOP::MirSwitch => MirInstClass::MirSwitch,
+            MirOP::MirSaveRegs => MirInstClass::MirSaveRegs,
+            MirOP::MirRestoreRegs => MirInstClass::MirRestoreRegs,
+            MirOP::MirRestoreHostRegs => MirInstClass::MirRestoreHostRegs,
+            MirOP::MirGEP => MirInstClass::MirGEP,
+            MirOP::MirComment => MirInstClass::MirComment,
+            MirOP::MirCommentedInst => MirInstClass::MirCommentedInst,
+        }
+    }
+}
+
+/// MIR 指令类. 同一类的指令有相同的操作数布局和属性.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirInstClass {
+    CondBr,
+    UncondBr,
+    BReg,
+    BLinkLabel,
+    BLinkGlobal,
+    BLinkReg,
+    CBZs,
+    TBZ64,
+    TBZ32,
+    Trap,
+    ICmp64R,
+    ICmp32R,
+    ICmp64I,
+    ICmp32I,
+    FCmp32,
+    FCmp64,
+    ICCmp64R,
+    ICCmp32R,
+    ICCmp64I,
+    ICCmp32I,
+    FCCmp32,
+    FCCmp64,
+    Bin64R,
+    Bin32R,
+    MulL,
+    Bin64RC,
+    Bin32RC,
+    Bin64RSym,
+    Bin64RL,
+    Bin32RL,
+    Bin64RS,
+    Bin64RU,
+    Bin32RS,
+    Bin32RU,
+    Bin64RShift,
+    Bin32RShift,
+    BinF64R,
+    BinF32R,
+    MirCopy64,
+    MirCopy32,
+    MirFCopy128,
+    MirFCopy64,
+    MirFCopy32,
+    MirPCopy,
+    Una64R,
+    Una32R,
+    ExtR,
+    Mov64I,
+    MovZNK64,
+    Mov32I,
+    MovZNK32,
+    Adr,
+    UnaFG64,
+    UnaGF64,
+    UnaF64G32,
+    UnaFG32,
+    UnaF32G64,
+    UnaGF32,
+    UnaG64F32,
+    UnaG32F64,
+    UnaF64,
+    UnaF32,
+    UnaryF32F64,
+    UnaryF64F32,
+    FMov64I,
+    FMov32I,
+    TenaryG64,
+    TenaryG64G32,
+    TenaryG32,
+    TenaryF64,
+    TenaryF32,
+    LoadGr64,
+    LoadGr32,
+    LoadF64,
+    LoadF32,
+    StoreGr64,
+    StoreGr32,
+    StoreF64,
+    StoreF32,
+    LoadGr64Base,
+    LoadGr32Base,
+    LoadF128Base,
+    LoadF64Base,
+    LoadF32Base,
+    LoadGr64BaseS,
+    LoadGr32BaseS,
+    LoadF128BaseS,
+    LoadF64BaseS,
+    LoadF32BaseS,
+    StoreGr64Base,
+    StoreGr32Base,
+    StoreF128Base,
+    StoreF64Base,
+    StoreF32Base,
+    StoreGr64BaseS,
+    StoreGr32BaseS,
+    StoreF128BaseS,
+    StoreF64BaseS,
+    StoreF32BaseS,
+    LoadGr64Indexed,
+    LoadGr32Indexed,
+    LoadF64Indexed,
+    LoadF32Indexed,
+    StoreGr64Indexed,
+    StoreGr32Indexed,
+    StoreF64Indexed,
+    StoreF32Indexed,
+    LoadGr64Literal,
+    LoadGr32Literal,
+    LoadF64Literal,
+    LoadF32Literal,
+    MirLdrLitG64,
+    MirLdrLitG32,
+    MirLdrLitF64,
+    MirLdrLitF32,
+    MirStrLitG64,
+    MirStrLitG32,
+    MirStrLitF64,
+    MirStrLitF32,
+    MirStImm64,
+    MirStSym64,
+    MirStImm32,
+    MirStImm64Sym,
+    MirStImm32Sym,
+    MirStSym64Sym,
+    LoadConst64,
+    LoadConst64Symbol,
+    MirLdImmF64,
+    MirLdImmF32,
+    MirDynAlloca,
+    CSel64,
+    CSel32,
+    CSelF64,
+    CSelF32,
+    CSet64,
+    CSet32,
+    MirCall,
+    MirReturn,
+    MirSwitch,
+    MirSaveRegs,
+    MirRestoreRegs,
+    MirRestoreHostRegs,
+    MirGEP,
+    MirComment,
+    MirCommentedInst,
+}
+
+impl MirInstClass {
+    pub fn get_name(self) -> &'static str {
+        match self {
+            MirInstClass::CondBr => "CondBr",
+            MirInstClass::UncondBr => "UncondBr",
+            MirInstClass::BReg => "BReg",
+            MirInstClass::BLinkLabel => "BLinkLabel",
+            MirInstClass::BLinkGlobal => "BLinkGlobal",
+            MirInstClass::BLinkReg => "BLinkReg",
+            MirInstClass::CBZs => "CBZs",
+            MirInstClass::TBZ64 => "TBZ64",
+            MirInstClass::TBZ32 => "TBZ32",
+            MirInstClass::Trap => "Trap",
+            MirInstClass::ICmp64R => "ICmp64R",
+            MirInstClass::ICmp32R => "ICmp32R",
+            MirInstClass::ICmp64I => "ICmp64I",
+            MirInstClass::ICmp32I => "ICmp32I",
+            MirInstClass::FCmp32 => "FCmp32",
+            MirInstClass::FCmp64 => "FCmp64",
+            MirInstClass::ICCmp64R => "ICCmp64R",
+            MirInstClass::ICCmp32R => "ICCmp32R",
+            MirInstClass::ICCmp64I => "ICCmp64I",
+            MirInstClass::ICCmp32I => "ICCmp32I",
+            MirInstClass::FCCmp32 => "FCCmp32",
+            MirInstClass::FCCmp64 => "FCCmp64",
+            MirInstClass::Bin64R => "Bin64R",
+            MirInstClass::Bin32R => "Bin32R",
+            MirInstClass::MulL => "MulL",
+            MirInstClass::Bin64RC => "Bin64RC",
+            MirInstClass::Bin32RC => "Bin32RC",
+            MirInstClass::Bin64RSym => "Bin64RSym",
+            MirInstClass::Bin64RL => "Bin64RL",
+            MirInstClass::Bin32RL => "Bin32RL",
+            MirInstClass::Bin64RS => "Bin64RS",
+            MirInstClass::Bin64RU => "Bin64RU",
+            MirInstClass::Bin32RS => "Bin32RS",
+            MirInstClass::Bin32RU => "Bin32RU",
+            MirInstClass::Bin64RShift => "Bin64RShift",
+            MirInstClass::Bin32RShift => "Bin32RShift",
+            MirInstClass::BinF64R => "BinF64R",
+            MirInstClass::BinF32R => "BinF32R",
+            MirInstClass::MirCopy64 => "MirCopy64",
+            MirInstClass::MirCopy32 => "MirCopy32",
+            MirInstClass::MirFCopy128 => "MirFCopy128",
+            MirInstClass::MirFCopy64 => "MirFCopy64",
+            MirInstClass::MirFCopy32 => "MirFCopy32",
+            MirInstClass::MirPCopy => "MirPCopy",
+            MirInstClass::Una64R => "Una64R",
+            MirInstClass::Una32R => "Una32R",
+            MirInstClass::ExtR => "ExtR",
+            MirInstClass::Mov64I => "Mov64I",
+            MirInstClass::MovZNK64 => "MovZNK64",
+            MirInstClass::Mov32I => "Mov32I",
+            MirInstClass::MovZNK32 => "MovZNK32",
+            MirInstClass::Adr => "Adr",
+            MirInstClass::UnaFG64 => "UnaFG64",
+            MirInstClass::UnaGF64 => "UnaGF64",
+            MirInstClass::UnaF64G32 => "UnaF64G32",
+            MirInstClass::UnaFG32 => "UnaFG32",
+            MirInstClass::UnaF32G64 => "UnaF32G64",
+            MirInstClass::UnaGF32 => "UnaGF32",
+            MirInstClass::UnaG64F32 => "UnaG64F32",
+            MirInstClass::UnaG32F64 => "UnaG32F64",
+            MirInstClass::UnaF64 => "UnaF64",
+            MirInstClass::UnaF32 => "UnaF32",
+            MirInstClass::UnaryF32F64 => "UnaryF32F64",
+            MirInstClass::UnaryF64F32 => "UnaryF64F32",
+            MirInstClass::FMov64I => "FMov64I",
+            MirInstClass::FMov32I => "FMov32I",
+            MirInstClass::TenaryG64 => "TenaryG64",
+            MirInstClass::TenaryG64G32 => "TenaryG64G32",
+            MirInstClass::TenaryG32 => "TenaryG32",
+            MirInstClass::TenaryF64 => "TenaryF64",
+            MirInstClass::TenaryF32 => "TenaryF32",
+            MirInstClass::LoadGr64 => "LoadGr64",
+            MirInstClass::LoadGr32 => "LoadGr32",
+            MirInstClass::LoadF64 => "LoadF64",
+            MirInstClass::LoadF32 => "LoadF32",
+            MirInstClass::StoreGr64 => "StoreGr64",
+            MirInstClass::StoreGr32 => "StoreGr32",
+            MirInstClass::StoreF64 => "StoreF64",
+            MirInstClass::StoreF32 => "StoreF32",
+            MirInstClass::LoadGr64Base => "LoadGr64Base",
+            MirInstClass::LoadGr32Base => "LoadGr32Base",
+            MirInstClass::LoadF128Base => "LoadF128Base",
+            MirInstClass::LoadF64Base => "LoadF64Base",
+            MirInstClass::LoadF32Base => "LoadF32Base",
+            MirInstClass::LoadGr64BaseS => "LoadGr64BaseS",
+            MirInstClass::LoadGr32BaseS => "LoadGr32BaseS",
+            MirInstClass::LoadF128BaseS => "LoadF128BaseS",
+            MirInstClass::LoadF64BaseS => "LoadF64BaseS",
+            MirInstClass::LoadF32BaseS => "LoadF32BaseS",
+            MirInstClass::StoreGr64Base => "StoreGr64Base",
+            MirInstClass::StoreGr32Base => "StoreGr32Base",
+            MirInstClass::StoreF128Base => "StoreF128Base",
+            MirInstClass::StoreF64Base => "StoreF64Base",
+            MirInstClass::StoreF32Base => "StoreF32Base",
+            MirInstClass::StoreGr64BaseS => "StoreGr64BaseS",
+            MirInstClass::StoreGr32BaseS => "StoreGr32BaseS",
+            MirInstClass::StoreF128BaseS => "StoreF128BaseS",
+            MirInstClass::StoreF64BaseS => "StoreF64BaseS",
+            MirInstClass::StoreF32BaseS => "StoreF32BaseS",
+            MirInstClass::LoadGr64Indexed => "LoadGr64Indexed",
+            MirInstClass::LoadGr32Indexed => "LoadGr32Indexed",
+            MirInstClass::LoadF64Indexed => "LoadF64Indexed",
+            MirInstClass::LoadF32Indexed => "LoadF32Indexed",
+            MirInstClass::StoreGr64Indexed => "StoreGr64Indexed",
+            MirInstClass::StoreGr32Indexed => "StoreGr32Indexed",
+            MirInstClass::StoreF64Indexed => "StoreF64Indexed",
+            MirInstClass::StoreF32Indexed => "StoreF32Indexed",
+            MirInstClass::LoadGr64Literal => "LoadGr64Literal",
+            MirInstClass::LoadGr32Literal => "LoadGr32Literal",
+            MirInstClass::LoadF64Literal => "LoadF64Literal",
+            MirInstClass::LoadF32Literal => "LoadF32Literal",
+            MirInstClass::MirLdrLitG64 => "MirLdrLitG64",
+            MirInstClass::MirLdrLitG32 => "MirLdrLitG32",
+            MirInstClass::MirLdrLitF64 => "MirLdrLitF64",
+            MirInstClass::MirLdrLitF32 => "MirLdrLitF32",
+            MirInstClass::MirStrLitG64 => "MirStrLitG64",
+            MirInstClass::MirStrLitG32 => "MirStrLitG32",
+            MirInstClass::MirStrLitF64 => "MirStrLitF64",
+            MirInstClass::MirStrLitF32 => "MirStrLitF32",
+            MirInstClass::MirStImm64 => "MirStImm64",
+            MirInstClass::MirStSym64 => "MirStSym64",
+            MirInstClass::MirStImm32 => "MirStImm32",
+            MirInstClass::MirStImm64Sym => "MirStImm64Sym",
+            MirInstClass::MirStImm32Sym => "MirStImm32Sym",
+            MirInstClass::MirStSym64Sym => "MirStSym64Sym",
+            MirInstClass::LoadConst64 => "LoadConst64",
+            MirInstClass::LoadConst64Symbol => "LoadConst64Symbol",
+            MirInstClass::MirLdImmF64 => "MirLdImmF64",
+            MirInstClass::MirLdImmF32 => "MirLdImmF32",
+            MirInstClass::MirDynAlloca => "MirDynAlloca",
+            MirInstClass::CSel64 => "CSel64",
+            MirInstClass::CSel32 => "CSel32",
+            MirInstClass::CSelF64 => "CSelF64",
+            MirInstClass::CSelF32 => "CSelF32",
+            MirInstClass::CSet64 => "CSet64",
+            MirInstClass::CSet32 => "CSet32",
+            MirInstClass::MirCall => "MirCall",
+            MirInstClass::MirReturn => "MirReturn",
+            MirInstClass::MirSwitch => "MirSwitch",
+            MirInstClass::MirSaveRegs => "MirSaveRegs",
+            MirInstClass::MirRestoreRegs => "MirRestoreRegs",
+            MirInstClass::MirRestoreHostRegs => "MirRestoreHostRegs",
+            MirInstClass::MirGEP => "MirGEP",
+            MirInstClass::MirComment => "MirComment",
+            MirInstClass::MirCommentedInst => "MirCommentedInst",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum MirInst {
+    CondBr(CondBr),
+    UncondBr(UncondBr),
+    BReg(BReg),
+    BLinkLabel(BLinkLabel),
+    BLinkGlobal(BLinkGlobal),
+    BLinkReg(BLinkReg),
+    CBZs(CBZs),
+    TBZ64(TBZ64),
+    TBZ32(TBZ32),
+    Trap(Trap),
+    ICmp64R(ICmp64R),
+    ICmp32R(ICmp32R),
+    ICmp64I(ICmp64I),
+    ICmp32I(ICmp32I),
+    FCmp32(FCmp32),
+    FCmp64(FCmp64),
+    ICCmp64R(ICCmp64R),
+    ICCmp32R(ICCmp32R),
+    ICCmp64I(ICCmp64I),
+    ICCmp32I(ICCmp32I),
+    FCCmp32(FCCmp32),
+    FCCmp64(FCCmp64),
+    Bin64R(Bin64R),
+    Bin32R(Bin32R),
+    MulL(MulL),
+    Bin64RC(Bin64RC),
+    Bin32RC(Bin32RC),
+    Bin64RSym(Bin64RSym),
+    Bin64RL(Bin64RL),
+    Bin32RL(Bin32RL),
+    Bin64RS(Bin64RS),
+    Bin64RU(Bin64RU),
+    Bin32RS(Bin32RS),
+    Bin32RU(Bin32RU),
+    Bin64RShift(Bin64RShift),
+    Bin32RShift(Bin32RShift),
+    BinF64R(BinF64R),
+    BinF32R(BinF32R),
+    MirCopy64(MirCopy64),
+    MirCopy32(MirCopy32),
+    MirFCopy128(MirFCopy128),
+    MirFCopy64(MirFCopy64),
+    MirFCopy32(MirFCopy32),
+    MirPCopy(MirPCopy),
+    Una64R(Una64R),
+    Una32R(Una32R),
+    ExtR(ExtR),
+    Mov64I(Mov64I),
+    MovZNK64(MovZNK64),
+    Mov32I(Mov32I),
+    MovZNK32(MovZNK32),
+    Adr(Adr),
+    UnaFG64(UnaFG64),
+    UnaGF64(UnaGF64),
+    UnaF64G32(UnaF64G32),
+    UnaFG32(UnaFG32),
+    UnaF32G64(UnaF32G64),
+    UnaGF32(UnaGF32),
+    UnaG64F32(UnaG64F32),
+    UnaG32F64(UnaG32F64),
+    UnaF64(UnaF64),
+    UnaF32(UnaF32),
+    UnaryF32F64(UnaryF32F64),
+    UnaryF64F32(UnaryF64F32),
+    FMov64I(FMov64I),
+    FMov32I(FMov32I),
+    TenaryG64(TenaryG64),
+    TenaryG64G32(TenaryG64G32),
+    TenaryG32(TenaryG32),
+    TenaryF64(TenaryF64),
+    TenaryF32(TenaryF32),
+    LoadGr64(LoadGr64),
+    LoadGr32(LoadGr32),
+    LoadF64(LoadF64),
+    LoadF32(LoadF32),
+    StoreGr64(StoreGr64),
+    StoreGr32(StoreGr32),
+    StoreF64(StoreF64),
+    StoreF32(StoreF32),
+    LoadGr64Base(LoadGr64Base),
+    LoadGr32Base(LoadGr32Base),
+    LoadF128Base(LoadF128Base),
+    LoadF64Base(LoadF64Base),
+    LoadF32Base(LoadF32Base),
+    LoadGr64BaseS(LoadGr64BaseS),
+    LoadGr32BaseS(LoadGr32BaseS),
+    LoadF128BaseS(LoadF128BaseS),
+    LoadF64BaseS(LoadF64BaseS),
+    LoadF32BaseS(LoadF32BaseS),
+    StoreGr64Base(StoreGr64Base),
+    StoreGr32Base(StoreGr32Base),
+    StoreF128Base(StoreF128Base),
+    StoreF64Base(StoreF64Base),
+    StoreF32Base(StoreF32Base),
+    StoreGr64BaseS(StoreGr64BaseS),
+    StoreGr32BaseS(StoreGr32BaseS),
+    StoreF128BaseS(StoreF128BaseS),
+    StoreF64BaseS(StoreF64BaseS),
+    StoreF32BaseS(StoreF32BaseS),
+    LoadGr64Indexed(LoadGr64Indexed),
+    LoadGr32Indexed(LoadGr32Indexed),
+    LoadF64Indexed(LoadF64Indexed),
+    LoadF32Indexed(LoadF32Indexed),
+    StoreGr64Indexed(StoreGr64Indexed),
+    StoreGr32Indexed(StoreGr32Indexed),
+    StoreF64Indexed(StoreF64Indexed),
+    StoreF32Indexed(StoreF32Indexed),
+    LoadGr64Literal(LoadGr64Literal),
+    LoadGr32Literal(LoadGr32Literal),
+    LoadF64Literal(LoadF64Literal),
+    LoadF32Literal(LoadF32Literal),
+    MirLdrLitG64(MirLdrLitG64),
+    MirLdrLitG32(MirLdrLitG32),
+    MirLdrLitF64(MirLdrLitF64),
+    MirLdrLitF32(MirLdrLitF32),
+    MirStrLitG64(MirStrLitG64),
+    MirStrLitG32(MirStrLitG32),
+    MirStrLitF64(MirStrLitF64),
+    MirStrLitF32(MirStrLitF32),
+    MirStImm64(MirStImm64),
+    MirStSym64(MirStSym64),
+    MirStImm32(MirStImm32),
+    MirStImm64Sym(MirStImm64Sym),
+    MirStImm32Sym(MirStImm32Sym),
+    MirStSym64Sym(MirStSym64Sym),
+    LoadConst64(LoadConst64),
+    LoadConst64Symbol(LoadConst64Symbol),
+    MirLdImmF64(MirLdImmF64),
+    MirLdImmF32(MirLdImmF32),
+    MirDynAlloca(MirDynAlloca),
+    CSel64(CSel64),
+    CSel32(CSel32),
+    CSelF64(CSelF64),
+    CSelF32(CSelF32),
+    CSet64(CSet64),
+    CSet32(CSet32),
+    MirCall(MirCall),
+    MirReturn(MirReturn),
+    MirSwitch(MirSwitch),
+    MirSaveRegs(MirSaveRegs),
+    MirRestoreRegs(MirRestoreRegs),
+    MirRestoreHostRegs(MirRestoreHostRegs),
+    MirGEP(MirGEP),
+    MirComment(MirComment),
+    MirCommentedInst(MirCommentedInst),
+}
+
+impl MirInst {
+    pub fn get_class(&self) -> MirInstClass {
+        match self {
+            MirInst::CondBr(_) => MirInstClass::CondBr,
+            MirInst::UncondBr(_) => MirInstClass::UncondBr,
+            MirInst::BReg(_) => MirInstClass::BReg,
+            MirInst::BLinkLabel(_) => MirInstClass::BLinkLabel,
+            MirInst::BLinkGlobal(_) => MirInstClass::BLinkGlobal,
+            MirInst::BLinkReg(_) => MirInstClass::BLinkReg,
+            MirInst::CBZs(_) => MirInstClass::CBZs,
+            MirInst::TBZ64(_) => MirInstClass::TBZ64,
+            MirInst::TBZ32(_) => MirInstClass::TBZ32,
+            MirInst::Trap(_) => MirInstClass::Trap,
+            MirInst::ICmp64R(_) => MirInstClass::ICmp64R,
+            MirInst::ICmp32R(_) => MirInstClass::ICmp32R,
+            MirInst::ICmp64I(_) => MirInstClass::ICmp64I,
+            MirInst::ICmp32I(_) => MirInstClass::ICmp32I,
+            MirInst::FCmp32(_) => MirInstClass::FCmp32,
+            MirInst::FCmp64(_) => MirInstClass::FCmp64,
+            MirInst::ICCmp64R(_) => MirInstClass::ICCmp64R,
+            MirInst::ICCmp32R(_) => MirInstClass::ICCmp32R,
+            MirInst::ICCmp64I(_) => MirInstClass::ICCmp64I,
+            MirInst::ICCmp32I(_) => MirInstClass::ICCmp32I,
+            MirInst::FCCmp32(_) => MirInstClass::FCCmp32,
+            MirInst::FCCmp64(_) => MirInstClass::FCCmp64,
+            MirInst::Bin64R(_) => MirInstClass::Bin64R,
+            MirInst::Bin32R(_) => MirInstClass::Bin32R,
+            MirInst::MulL(_) => MirInstClass::MulL,
+            MirInst::Bin64RC(_) => MirInstClass::Bin64RC,
+            MirInst::Bin32RC(_) => MirInstClass::Bin32RC,
+            MirInst::Bin64RSym(_) => MirInstClass::Bin64RSym,
+            MirInst::Bin64RL(_) => MirInstClass::Bin64RL,
+            MirInst::Bin32RL(_) => MirInstClass::Bin32RL,
+            MirInst::Bin64RS(_) => MirInstClass::Bin64RS,
+            MirInst::Bin64RU(_) => MirInstClass::Bin64RU,
+            MirInst::Bin32RS(_) => MirInstClass::Bin32RS,
+            MirInst::Bin32RU(_) => MirInstClass::Bin32RU,
+            MirInst::Bin64RShift(_) => MirInstClass::Bin64RShift,
+            MirInst::Bin32RShift(_) => MirInstClass::Bin32RShift,
+            MirInst::BinF64R(_) => MirInstClass::BinF64R,
+            MirInst::BinF32R(_) => MirInstClass::BinF32R,
+            MirInst::MirCopy64(_) => MirInstClass::MirCopy64,
+            MirInst::MirCopy32(_) => MirInstClass::MirCopy32,
+            MirInst::MirFCopy128(_) => MirInstClass::MirFCopy128,
+            MirInst::MirFCopy64(_) => MirInstClass::MirFCopy64,
+            MirInst::MirFCopy32(_) => MirInstClass::MirFCopy32,
+            MirInst::MirPCopy(_) => MirInstClass::MirPCopy,
+            MirInst::Una64R(_) => MirInstClass::Una64R,
+            MirInst::Una32R(_) => MirInstClass::Una32R,
+            MirInst::ExtR(_) => MirInstClass::ExtR,
+            MirInst::Mov64I(_) => MirInstClass::Mov64I,
+            MirInst::MovZNK64(_) => MirInstClass::MovZNK64,
+            MirInst::Mov32I(_) => MirInstClass::Mov32I,
+            MirInst::MovZNK32(_) => MirInstClass::MovZNK32,
+            MirInst::Adr(_) => MirInstClass::Adr,
+            MirInst::UnaFG64(_) => MirInstClass::UnaFG64,
+            MirInst::UnaGF64(_) => MirInstClass::UnaGF64,
+            MirInst::UnaF64G32(_) => MirInstClass::UnaF64G32,
+            MirInst::UnaFG32(_) => MirInstClass::UnaFG32,
+            MirInst::UnaF32G64(_) => MirInstClass::UnaF32G64,
+            MirInst::UnaGF32(_) => MirInstClass::UnaGF32,
+            MirInst::UnaG64F32(_) => MirInstClass::UnaG64F32,
+            MirInst::UnaG32F64(_) => MirInstClass::UnaG32F64,
+            MirInst::UnaF64(_) => MirInstClass::UnaF64,
+            MirInst::UnaF32(_) => MirInstClass::UnaF32,
+            MirInst::UnaryF32F64(_) => MirInstClass::UnaryF32F64,
+            MirInst::UnaryF64F32(_) => MirInstClass::UnaryF64F32,
+            MirInst::FMov64I(_) => MirInstClass::FMov64I,
+            MirInst::FMov32I(_) => MirInstClass::FMov32I,
+            MirInst::TenaryG64(_) => MirInstClass::TenaryG64,
+            MirInst::TenaryG64G32(_) => MirInstClass::TenaryG64G32,
+            MirInst::TenaryG32(_) => MirInstClass::TenaryG32,
+            MirInst::TenaryF64(_) => MirInstClass::TenaryF64,
+            MirInst::TenaryF32(_) => MirInstClass::TenaryF32,
+            MirInst::LoadGr64(_) => MirInstClass::LoadGr64,
+            MirInst::LoadGr32(_) => MirInstClass::LoadGr32,
+            MirInst::LoadF64(_) => MirInstClass::LoadF64,
+            MirInst::LoadF32(_) => MirInstClass::LoadF32,
+            MirInst::StoreGr64(_) => MirInstClass::StoreGr64,
+            MirInst::StoreGr32(_) => MirInstClass::StoreGr32,
+            MirInst::StoreF64(_) => MirInstClass::StoreF64,
+            MirInst::StoreF32(_) => MirInstClass::StoreF32,
+            MirInst::LoadGr64Base(_) => MirInstClass::LoadGr64Base,
+            MirInst::LoadGr32Base(_) => MirInstClass::LoadGr32Base,
+            MirInst::LoadF128Base(_) => MirInstClass::LoadF128Base,
+            MirInst::LoadF64Base(_) => MirInstClass::LoadF64Base,
+            MirInst::LoadF32Base(_) => MirInstClass::LoadF32Base,
+            MirInst::LoadGr64BaseS(_) => MirInstClass::LoadGr64BaseS,
+            MirInst::LoadGr32BaseS(_) => MirInstClass::LoadGr32BaseS,
+            MirInst::LoadF128BaseS(_) => MirInstClass::LoadF128BaseS,
+            MirInst::LoadF64BaseS(_) => MirInstClass::LoadF64BaseS,
+            MirInst::LoadF32BaseS(_) => MirInstClass::LoadF32BaseS,
+            MirInst::StoreGr64Base(_) => MirInstClass::StoreGr64Base,
+            MirInst::StoreGr32Base(_) => MirInstClass::StoreGr32Base,
+            MirInst::StoreF128Base(_) => MirInstClass::StoreF128Base,
+            MirInst::StoreF64Base(_) => MirInstClass::StoreF64Base,
+            MirInst::StoreF32Base(_) => MirInstClass::StoreF32Base,
+            MirInst::StoreGr64BaseS(_) => MirInstClass::StoreGr64BaseS,
+            MirInst::StoreGr32BaseS(_) => MirInstClass::StoreGr32BaseS,
+            MirInst::StoreF128BaseS(_) => MirInstClass::StoreF128BaseS,
+            MirInst::StoreF64BaseS(_) => MirInstClass::StoreF64BaseS,
+            MirInst::StoreF32BaseS(_) => MirInstClass::StoreF32BaseS,
+            MirInst::LoadGr64Indexed(_) => MirInstClass::LoadGr64Indexed,
+            MirInst::LoadGr32Indexed(_) => MirInstClass::LoadGr32Indexed,
+            MirInst::LoadF64Indexed(_) => MirInstClass::LoadF64Indexed,
+            MirInst::LoadF32Indexed(_) => MirInstClass::LoadF32Indexed,
+            MirInst::StoreGr64Indexed(_) => MirInstClass::StoreGr64Indexed,
+            MirInst::StoreGr32Indexed(_) => MirInstClass::StoreGr32Indexed,
+            MirInst::StoreF64Indexed(_) => MirInstClass::StoreF64Indexed,
+            MirInst::StoreF32Indexed(_) => MirInstClass::StoreF32Indexed,
+            MirInst::LoadGr64Literal(_) => MirInstClass::LoadGr64Literal,
+            MirInst::LoadGr32Literal(_) => MirInstClass::LoadGr32Literal,
+            MirInst::LoadF64Literal(_) => MirInstClass::LoadF64Literal,
+            MirInst::LoadF32Literal(_) => MirInstClass::LoadF32Literal,
+            MirInst::MirLdrLitG64(_) => MirInstClass::MirLdrLitG64,
+            MirInst::MirLdrLitG32(_) => MirInstClass::MirLdrLitG32,
+            MirInst::MirLdrLitF64(_) => MirInstClass::MirLdrLitF64,
+            MirInst::MirLdrLitF32(_) => MirInstClass::MirLdrLitF32,
+            MirInst::MirStrLitG64(_) => MirInstClass::MirStrLitG64,
+            MirInst::MirStrLitG32(_) => MirInstClass::MirStrLitG32,
+            MirInst::MirStrLitF64(_) => MirInstClass::MirStrLitF64,
+            MirInst::MirStrLitF32(_) => MirInstClass::MirStrLitF32,
+            MirInst::MirStImm64(_) => MirInstClass::MirStImm64,
+            MirInst::MirStSym64(_) => MirInstClass::MirStSym64,
+            MirInst::MirStImm32(_) => MirInstClass::MirStImm32,
+            MirInst::MirStImm64Sym(_) => MirInstClass::MirStImm64Sym,
+            MirInst::MirStImm32Sym(_) => MirInstClass::MirStImm32Sym,
+            MirInst::MirStSym64Sym(_) => MirInstClass::MirStSym64Sym,
+            MirInst::LoadConst64(_) => MirInstClass::LoadConst64,
+            MirInst::LoadConst64Symbol(_) => MirInstClass::LoadConst64Symbol,
+            MirInst::MirLdImmF64(_) => MirInstClass::MirLdImmF64,
+            MirInst::MirLdImmF32(_) => MirInstClass::MirLdImmF32,
+            MirInst::MirDynAlloca(_) => MirInstClass::MirDynAlloca,
+            MirInst::CSel64(_) => MirInstClass::CSel64,
+            MirInst::CSel32(_) => MirInstClass::CSel32,
+            MirInst::CSelF64(_) => MirInstClass::CSelF64,
+            MirInst::CSelF32(_) => MirInstClass::CSelF32,
+            MirInst::CSet64(_) => MirInstClass::CSet64,
+            MirInst::CSet32(_) => MirInstClass::CSet32,
+            MirInst::MirCall(_) => MirInstClass::MirCall,
+            MirInst::MirReturn(_) => MirInstClass::MirReturn,
+            MirInst::MirSwitch(_) => MirInstClass::MirSwitch,
+            MirInst::MirSaveRegs(_) => MirInstClass::MirSaveRegs,
+            MirInst::MirRestoreRegs(_) => MirInstClass::MirRestoreRegs,
+            MirInst::MirRestoreHostRegs(_) => MirInstClass::MirRestoreHostRegs,
+            MirInst::MirGEP(_) => MirInstClass::MirGEP,
+            MirInst::MirComment(_) => MirInstClass::MirComment,
+            MirInst::MirCommentedInst(_) => MirInstClass::MirCommentedInst,
+        }
+    }
+
+    pub fn get_common(&self) -> &MirInstCommon {
+        match self {
+            MirInst::CondBr(inst) => inst.get_common(),
+            MirInst::UncondBr(inst) => inst.get_common(),
+            MirInst::BReg(inst) => inst.get_common(),
+            MirInst::BLinkLabel(inst) => inst.get_common(),
+            MirInst::BLinkGlobal(inst) => inst.get_common(),
+            MirInst::BLinkReg(inst) => inst.get_common(),
+            MirInst::CBZs(inst) => inst.get_common(),
+            MirInst::TBZ64(inst) => inst.get_common(),
+            MirInst::TBZ32(inst) => inst.get_common(),
+            MirInst::Trap(inst) => inst.get_common(),
+            MirInst::ICmp64R(inst) => inst.get_common(),
+            MirInst::ICmp32R(inst) => inst.get_common(),
+            MirInst::ICmp64I(inst) => inst.get_common(),
+            MirInst::ICmp32I(inst) => inst.get_common(),
+            MirInst::FCmp32(inst) => inst.get_common(),
+            MirInst::FCmp64(inst) => inst.get_common(),
+            MirInst::ICCmp64R(inst) => inst.get_common(),
+            MirInst::ICCmp32R(inst) => inst.get_common(),
+            MirInst::ICCmp64I(inst) => inst.get_common(),
+            MirInst::ICCmp32I(inst) => inst.get_common(),
+            MirInst::FCCmp32(inst) => inst.get_common(),
+            MirInst::FCCmp64(inst) => inst.get_common(),
+            MirInst::Bin64R(inst) => inst.get_common(),
+            MirInst::Bin32R(inst) => inst.get_common(),
+            MirInst::MulL(inst) => inst.get_common(),
+            MirInst::Bin64RC(inst) => inst.get_common(),
+            MirInst::Bin32RC(inst) => inst.get_common(),
+            MirInst::Bin64RSym(inst) => inst.get_common(),
+            MirInst::Bin64RL(inst) => inst.get_common(),
+            MirInst::Bin32RL(inst) => inst.get_common(),
+            MirInst::Bin64RS(inst) => inst.get_common(),
+            MirInst::Bin64RU(inst) => inst.get_common(),
+            MirInst::Bin32RS(inst) => inst.get_common(),
+            MirInst::Bin32RU(inst) => inst.get_common(),
+            MirInst::Bin64RShift(inst) => inst.get_common(),
+            MirInst::Bin32RShift(inst) => inst.get_common(),
+            MirInst::BinF64R(inst) => inst.get_common(),
+            MirInst::BinF32R(inst) => inst.get_common(),
+            MirInst::MirCopy64(inst) => inst.get_common(),
+            MirInst::MirCopy32(inst) => inst.get_common(),
+            MirInst::MirFCopy128(inst) => inst.get_common(),
+            MirInst::MirFCopy64(inst) => inst.get_common(),
+            MirInst::MirFCopy32(inst) => inst.get_common(),
+            MirInst::MirPCopy(inst) => inst.get_common(),
+            MirInst::Una64R(inst) => inst.get_common(),
+            MirInst::Una32R(inst) => inst.get_common(),
+            MirInst::ExtR(inst) => inst.get_common(),
+            MirInst::Mov64I(inst) => inst.get_common(),
+            MirInst::MovZNK64(inst) => inst.get_common(),
+            MirInst::Mov32I(inst) => inst.get_common(),
+            MirInst::MovZNK32(inst) => inst.get_common(),
+            MirInst::Adr(inst) => inst.get_common(),
+            MirInst::UnaFG64(inst) => inst.get_common(),
+            MirInst::UnaGF64(inst) => inst.get_common(),
+            MirInst::UnaF64G32(inst) => inst.get_common(),
+            MirInst::UnaFG32(inst) => inst.get_common(),
+            MirInst::UnaF32G64(inst) => inst.get_common(),
+            MirInst::UnaGF32(inst) => inst.get_common(),
+            MirInst::UnaG64F32(inst) => inst.get_common(),
+            MirInst::UnaG32F64(inst) => inst.get_common(),
+            MirInst::UnaF64(inst) => inst.get_common(),
+            MirInst::UnaF32(inst) => inst.get_common(),
+            MirInst::UnaryF32F64(inst) => inst.get_common(),
+            MirInst::UnaryF64F32(inst) => inst.get_common(),
+            MirInst::FMov64I(inst) => inst.get_common(),
+            MirInst::FMov32I(inst) => inst.get_common(),
+            MirInst::TenaryG64(inst) => inst.get_common(),
+            MirInst::TenaryG64G32(inst) => inst.get_common(),
+            MirInst::TenaryG32(inst) => inst.get_common(),
+            MirInst::TenaryF64(inst) => inst.get_common(),
+            MirInst::TenaryF32(inst) => inst.get_common(),
+            MirInst::LoadGr64(inst) => inst.get_common(),
+            MirInst::LoadGr32(inst) => inst.get_common(),
+            MirInst::LoadF64(inst) => inst.get_common(),
+            MirInst::LoadF32(inst) => inst.get_common(),
+            MirInst::StoreGr64(inst) => inst.get_common(),
+            MirInst::StoreGr32(inst) => inst.get_common(),
+            MirInst::StoreF64(inst) => inst.get_common(),
+            MirInst::StoreF32(inst) => inst.get_common(),
+            MirInst::LoadGr64Base(inst) => inst.get_common(),
+            MirInst::LoadGr32Base(inst) => inst.get_common(),
+            MirInst::LoadF128Base(inst) => inst.get_common(),
+            MirInst::LoadF64Base(inst) => inst.get_common(),
+            MirInst::LoadF32Base(inst) => inst.get_common(),
+            MirInst::LoadGr64BaseS(inst) => inst.get_common(),
+            MirInst::LoadGr32BaseS(inst) => inst.get_common(),
+            MirInst::LoadF128BaseS(inst) => inst.get_common(),
+            MirInst::LoadF64BaseS(inst) => inst.get_common(),
+            MirInst::LoadF32BaseS(inst) => inst.get_common(),
+            MirInst::StoreGr64Base(inst) => inst.get_common(),
+            MirInst::StoreGr32Base(inst) => inst.get_common(),
+            MirInst::StoreF128Base(inst) => inst.get_common(),
+            MirInst::StoreF64Base(inst) => inst.get_common(),
+            MirInst::StoreF32Base(inst) => inst.get_common(),
+            MirInst::StoreGr64BaseS(inst) => inst.get_common(),
+            MirInst::StoreGr32BaseS(inst) => inst.get_common(),
+            MirInst::StoreF128BaseS(inst) => inst.get_common(),
+            MirInst::StoreF64BaseS(inst) => inst.get_common(),
+            MirInst::StoreF32BaseS(inst) => inst.get_common(),
+            MirInst::LoadGr64Indexed(inst) => inst.get_common(),
+            MirInst::LoadGr32Indexed(inst) => inst.get_common(),
+            MirInst::LoadF64Indexed(inst) => inst.get_common(),
+            MirInst::LoadF32Indexed(inst) => inst.get_common(),
+            MirInst::StoreGr64Indexed(inst) => inst.get_common(),
+            MirInst::StoreGr32Indexed(inst) => inst.get_common(),
+            MirInst::StoreF64Indexed(inst) => inst.get_common(),
+            MirInst::StoreF32Indexed(inst) => inst.get_common(),
+            MirInst::LoadGr64Literal(inst) => inst.get_common(),
+            MirInst::LoadGr32Literal(inst) => inst.get_common(),
+            MirInst::LoadF64Literal(inst) => inst.get_common(),
+            MirInst::LoadF32Literal(inst) => inst.get_common(),
+            MirInst::MirLdrLitG64(inst) => inst.get_common(),
+            MirInst::MirLdrLitG32(inst) => inst.get_common(),
+            MirInst::MirLdrLitF64(inst) => inst.get_common(),
+            MirInst::MirLdrLitF32(inst) => inst.get_common(),
+            MirInst::MirStrLitG64(inst) => inst.get_common(),
+            MirInst::MirStrLitG32(inst) => inst.get_common(),
+            MirInst::MirStrLitF64(inst) => inst.get_common(),
+            MirInst::MirStrLitF32(inst) => inst.get_common(),
+            MirInst::MirStImm64(inst) => inst.get_common(),
+            MirInst::MirStSym64(inst) => inst.get_common(),
+            MirInst::MirStImm32(inst) => inst.get_common(),
+            MirInst::MirStImm64Sym(inst) => inst.get_common(),
+            MirInst::MirStImm32Sym(inst) => inst.get_common(),
+            MirInst::MirStSym64Sym(inst) => inst.get_common(),
+            MirInst::LoadConst64(inst) => inst.get_common(),
+            MirInst::LoadConst64Symbol(inst) => inst.get_common(),
+            MirInst::MirLdImmF64(inst) => inst.get_common(),
+            MirInst::MirLdImmF32(inst) => inst.get_common(),
+            MirInst::MirDynAlloca(inst) => inst.get_common(),
+            MirInst::CSel64(inst) => inst.get_common(),
+            MirInst::CSel32(inst) => inst.get_common(),
+            MirInst::CSelF64(inst) => inst.get_common(),
+            MirInst::CSelF32(inst) => inst.get_common(),
+            MirInst::CSet64(inst) => inst.get_common(),
+            MirInst::CSet32(inst) => inst.get_common(),
+            MirInst::MirCall(inst) => inst.get_common(),
+            MirInst::MirReturn(inst) => inst.get_common(),
+            MirInst::MirSwitch(inst) => inst.get_common(),
+            MirInst::MirSaveRegs(inst) => inst.get_common(),
+            MirInst::MirRestoreRegs(inst) => inst.get_common(),
+            MirInst::MirRestoreHostRegs(inst) => inst.get_common(),
+            MirInst::MirGEP(inst) => inst.get_common(),
+            MirInst::MirComment(inst) => inst.get_common(),
+            MirInst::MirCommentedInst(inst) => inst.get_common(),
+        }
+    }
+
+    pub fn operands(&self) -> &[Cell<MirOperand>] {
+        match self {
+            MirInst::CondBr(inst) => inst.operands(),
+            MirInst::UncondBr(inst) => inst.operands(),
+            MirInst::BReg(inst) => inst.operands(),
+            MirInst::BLinkLabel(inst) => inst.operands(),
+            MirInst::BLinkGlobal(inst) => inst.operands(),
+            MirInst::BLinkReg(inst) => inst.operands(),
+            MirInst::CBZs(inst) => inst.operands(),
+            MirInst::TBZ64(inst) => inst.operands(),
+            MirInst::TBZ32(inst) => inst.operands(),
+            MirInst::Trap(inst) => inst.operands(),
+            MirInst::ICmp64R(inst) => inst.operands(),
+            MirInst::ICmp32R(inst) => inst.operands(),
+            MirInst::ICmp64I(inst) => inst.operands(),
+            MirInst::ICmp32I(inst) => inst.operands(),
+            MirInst::FCmp32(inst) => inst.operands(),
+            MirInst::FCmp64(inst) => inst.operands(),
+            MirInst::ICCmp64R(inst) => inst.operands(),
+            MirInst::ICCmp32R(inst) => inst.operands(),
+            MirInst::ICCmp64I(inst) => inst.operands(),
+            MirInst::ICCmp32I(inst) => inst.operands(),
+            MirInst::FCCmp32(inst) => inst.operands(),
+            MirInst::FCCmp64(inst) => inst.operands(),
+            MirInst::Bin64R(inst) => inst.operands(),
+            MirInst::Bin32R(inst) => inst.operands(),
+            MirInst::MulL(inst) => inst.operands(),
+            MirInst::Bin64RC(inst) => inst.operands(),
+            MirInst::Bin32RC(inst) => inst.operands(),
+            MirInst::Bin64RSym(inst) => inst.operands(),
+            MirInst::Bin64RL(inst) => inst.operands(),
+            MirInst::Bin32RL(inst) => inst.operands(),
+            MirInst::Bin64RS(inst) => inst.operands(),
+            MirInst::Bin64RU(inst) => inst.operands(),
+            MirInst::Bin32RS(inst) => inst.operands(),
+            MirInst::Bin32RU(inst) => inst.operands(),
+            MirInst::Bin64RShift(inst) => inst.operands(),
+            MirInst::Bin32RShift(inst) => inst.operands(),
+            MirInst::BinF64R(inst) => inst.operands(),
+            MirInst::BinF32R(inst) => inst.operands(),
+            MirInst::MirCopy64(inst) => inst.operands(),
+            MirInst::MirCopy32(inst) => inst.operands(),
+            MirInst::MirFCopy128(inst) => inst.operands(),
+            MirInst::MirFCopy64(inst) => inst.operands(),
+            MirInst::MirFCopy32(inst) => inst.operands(),
+            MirInst::MirPCopy(inst) => inst.operands(),
+            MirInst::Una64R(inst) => inst.operands(),
+            MirInst::Una32R(inst) => inst.operands(),
+            MirInst::ExtR(inst) => inst.operands(),
+            MirInst::Mov64I(inst) => inst.operands(),
+            MirInst::MovZNK64(inst) => inst.operands(),
+            MirInst::Mov32I(inst) => inst.operands(),
+            MirInst::MovZNK32(inst) => inst.operands(),
+            MirInst::Adr(inst) => inst.operands(),
+            MirInst::UnaFG64(inst) => inst.operands(),
+            MirInst::UnaGF64(inst) => inst.operands(),
+            MirInst::UnaF64G32(inst) => inst.operands(),
+            MirInst::UnaFG32(inst) => inst.operands(),
+            MirInst::UnaF32G64(inst) => inst.operands(),
+            MirInst::UnaGF32(inst) => inst.operands(),
+            MirInst::UnaG64F32(inst) => inst.operands(),
+            MirInst::UnaG32F64(inst) => inst.operands(),
+            MirInst::UnaF64(inst) => inst.operands(),
+            MirInst::UnaF32(inst) => inst.operands(),
+            MirInst::UnaryF32F64(inst) => inst.operands(),
+            MirInst::UnaryF64F32(inst) => inst.operands(),
+            MirInst::FMov64I(inst) => inst.operands(),
+            MirInst::FMov32I(inst) => inst.operands(),
+            MirInst::TenaryG64(inst) => inst.operands(),
+            MirInst::TenaryG64G32(inst) => inst.operands(),
+            MirInst::TenaryG32(inst) => inst.operands(),
+            MirInst::TenaryF64(inst) => inst.operands(),
+            MirInst::TenaryF32(inst) => inst.operands(),
+            MirInst::LoadGr64(inst) => inst.operands(),
+            MirInst::LoadGr32(inst) => inst.operands(),
+            MirInst::LoadF64(inst) => inst.operands(),
+            MirInst::LoadF32(inst) => inst.operands(),
+            MirInst::StoreGr64(inst) => inst.operands(),
+            MirInst::StoreGr32(inst) => inst.operands(),
+            MirInst::StoreF64(inst) => inst.operands(),
+            MirInst::StoreF32(inst) => inst.operands(),
+            MirInst::LoadGr64Base(inst) => inst.operands(),
+            MirInst::LoadGr32Base(inst) => inst.operands(),
+            MirInst::LoadF128Base(inst) => inst.operands(),
+            MirInst::LoadF64Base(inst) => inst.operands(),
+            MirInst::LoadF32Base(inst) => inst.operands(),
+            MirInst::LoadGr64BaseS(inst) => inst.operands(),
+            MirInst::LoadGr32BaseS(inst) => inst.operands(),
+            MirInst::LoadF128BaseS(inst) => inst.operands(),
+            MirInst::LoadF64BaseS(inst) => inst.operands(),
+            MirInst::LoadF32BaseS(inst) => inst.operands(),
+            MirInst::StoreGr64Base(inst) => inst.operands(),
+            MirInst::StoreGr32Base(inst) => inst.operands(),
+            MirInst::StoreF128Base(inst) => inst.operands(),
+            MirInst::StoreF64Base(inst) => inst.operands(),
+            MirInst::StoreF32Base(inst) => inst.operands(),
+            MirInst::StoreGr64BaseS(inst) => inst.operands(),
+            MirInst::StoreGr32BaseS(inst) => inst.operands(),
+            MirInst::StoreF128BaseS(inst) => inst.operands(),
+            MirInst::StoreF64BaseS(inst) => inst.operands(),
+            MirInst::StoreF32BaseS(inst) => inst.operands(),
+            MirInst::LoadGr64Indexed(inst) => inst.operands(),
+            MirInst::LoadGr32Indexed(inst) => inst.operands(),
+            MirInst::LoadF64Indexed(inst) => inst.operands(),
+            MirInst::LoadF32Indexed(inst) => inst.operands(),
+            MirInst::StoreGr64Indexed(inst) => inst.operands(),
+            MirInst::StoreGr32Indexed(inst) => inst.operands(),
+            MirInst::StoreF64Indexed(inst) => inst.operands(),
+            MirInst::StoreF32Indexed(inst) => inst.operands(),
+            MirInst::LoadGr64Literal(inst) => inst.operands(),
+            MirInst::LoadGr32Literal(inst) => inst.operands(),
+            MirInst::LoadF64Literal(inst) => inst.operands(),
+            MirInst::LoadF32Literal(inst) => inst.operands(),
+            MirInst::MirLdrLitG64(inst) => inst.operands(),
+            MirInst::MirLdrLitG32(inst) => inst.operands(),
+            MirInst::MirLdrLitF64(inst) => inst.operands(),
+            MirInst::MirLdrLitF32(inst) => inst.operands(),
+            MirInst::MirStrLitG64(inst) => inst.operands(),
+            MirInst::MirStrLitG32(inst) => inst.operands(),
+            MirInst::MirStrLitF64(inst) => inst.operands(),
+            MirInst::MirStrLitF32(inst) => inst.operands(),
+            MirInst::MirStImm64(inst) => inst.operands(),
+            MirInst::MirStSym64(inst) => inst.operands(),
+            MirInst::MirStImm32(inst) => inst.operands(),
+            MirInst::MirStImm64Sym(inst) => inst.operands(),
+            MirInst::MirStImm32Sym(inst) => inst.operands(),
+            MirInst::MirStSym64Sym(inst) => inst.operands(),
+            MirInst::LoadConst64(inst) => inst.operands(),
+            MirInst::LoadConst64Symbol(inst) => inst.operands(),
+            MirInst::MirLdImmF64(inst) => inst.operands(),
+            MirInst::MirLdImmF32(inst) => inst.operands(),
+            MirInst::MirDynAlloca(inst) => inst.operands(),
+            MirInst::CSel64(inst) => inst.operands(),
+            MirInst::CSel32(inst) => inst.operands(),
+            MirInst::CSelF64(inst) => inst.operands(),
+            MirInst::CSelF32(inst) => inst.operands(),
+            MirInst::CSet64(inst) => inst.operands(),
+            MirInst::CSet32(inst) => inst.operands(),
+            MirInst::MirCall(inst) => inst.operands(),
+            MirInst::MirReturn(inst) => inst.operands(),
+            MirInst::MirSwitch(inst) => inst.operands(),
+            MirInst::MirSaveRegs(inst) => inst.operands(),
+            MirInst::MirRestoreRegs(inst) => inst.operands(),
+            MirInst::MirRestoreHostRegs(inst) => inst.operands(),
+            MirInst::MirGEP(inst) => inst.operands(),
+            MirInst::MirComment(inst) => inst.operands(),
+            MirInst::MirCommentedInst(inst) => inst.operands(),
+        }
+    }
+
+    pub fn num_outs(&self) -> usize {
+        match self {
+            MirInst::CondBr(inst) => inst.num_outs(),
+            MirInst::UncondBr(inst) => inst.num_outs(),
+            MirInst::BReg(inst) => inst.num_outs(),
+            MirInst::BLinkLabel(inst) => inst.num_outs(),
+            MirInst::BLinkGlobal(inst) => inst.num_outs(),
+            MirInst::BLinkReg(inst) => inst.num_outs(),
+            MirInst::CBZs(inst) => inst.num_outs(),
+            MirInst::TBZ64(inst) => inst.num_outs(),
+            MirInst::TBZ32(inst) => inst.num_outs(),
+            MirInst::Trap(inst) => inst.num_outs(),
+            MirInst::ICmp64R(inst) => inst.num_outs(),
+            MirInst::ICmp32R(inst) => inst.num_outs(),
+            MirInst::ICmp64I(inst) => inst.num_outs(),
+            MirInst::ICmp32I(inst) => inst.num_outs(),
+            MirInst::FCmp32(inst) => inst.num_outs(),
+            MirInst::FCmp64(inst) => inst.num_outs(),
+            MirInst::ICCmp64R(inst) => inst.num_outs(),
+            MirInst::ICCmp32R(inst) => inst.num_outs(),
+            MirInst::ICCmp64I(inst) => inst.num_outs(),
+            MirInst::ICCmp32I(inst) => inst.num_outs(),
+            MirInst::FCCmp32(inst) => inst.num_outs(),
+            MirInst::FCCmp64(inst) => inst.num_outs(),
+            MirInst::Bin64R(inst) => inst.num_outs(),
+            MirInst::Bin32R(inst) => inst.num_outs(),
+            MirInst::MulL(inst) => inst.num_outs(),
+            MirInst::Bin64RC(inst) => inst.num_outs(),
+            MirInst::Bin32RC(inst) => inst.num_outs(),
+            MirInst::Bin64RSym(inst) => inst.num_outs(),
+            MirInst::Bin64RL(inst) => inst.num_outs(),
+            MirInst::Bin32RL(inst) => inst.num_outs(),
+            MirInst::Bin64RS(inst) => inst.num_outs(),
+            MirInst::Bin64RU(inst) => inst.num_outs(),
+            MirInst::Bin32RS(inst) => inst.num_outs(),
+            MirInst::Bin32RU(inst) => inst.num_outs(),
+            MirInst::Bin64RShift(inst) => inst.num_outs(),
+            MirInst::Bin32RShift(inst) => inst.num_outs(),
+            MirInst::BinF64R(inst) => inst.num_outs(),
+            MirInst::BinF32R(inst) => inst.num_outs(),
+            MirInst::MirCopy64(inst) => inst.num_outs(),
+            MirInst::MirCopy32(inst) => inst.num_outs(),
+            MirInst::MirFCopy128(inst) => inst.num_outs(),
+            MirInst::MirFCopy64(inst) => inst.num_outs(),
+            MirInst::MirFCopy32(inst) => inst.num_outs(),
+            MirInst::MirPCopy(inst) => inst.num_outs(),
+            MirInst::Una64R(inst) => inst.num_outs(),
+            MirInst::Una32R(inst) => inst.num_outs(),
+            MirInst::ExtR(inst) => inst.num_outs(),
+            MirInst::Mov64I(inst) => inst.num_outs(),
+            MirInst::MovZNK64(inst) => inst.num_outs(),
+            MirInst::Mov32I(inst) => inst.num_outs(),
+            MirInst::MovZNK32(inst) => inst.num_outs(),
+            MirInst::Adr(inst) => inst.num_outs(),
+            MirInst::UnaFG64(inst) => inst.num_outs(),
+            MirInst::UnaGF64(inst) => inst.num_outs(),
+            MirInst::UnaF64G32(inst) => inst.num_outs(),
+            MirInst::UnaFG32(inst) => inst.num_outs(),
+            MirInst::UnaF32G64(inst) => inst.num_outs(),
+            MirInst::UnaGF32(inst) => inst.num_outs(),
+            MirInst::UnaG64F32(inst) => inst.num_outs(),
+            MirInst::UnaG32F64(inst) => inst.num_outs(),
+            MirInst::UnaF64(inst) => inst.num_outs(),
+            MirInst::UnaF32(inst) => inst.num_outs(),
+            MirInst::UnaryF32F64(inst) => inst.num_outs(),
+            MirInst::UnaryF64F32(inst) => inst.num_outs(),
+            MirInst::FMov64I(inst) => inst.num_outs(),
+            MirInst::FMov32I(inst) => inst.num_outs(),
+            MirInst::TenaryG64(inst) => inst.num_outs(),
+            MirInst::TenaryG64G32(inst) => inst.num_outs(),
+            MirInst::TenaryG32(inst) => inst.num_outs(),
+            MirInst::TenaryF64(inst) => inst.num_outs(),
+            MirInst::TenaryF32(inst) => inst.num_outs(),
+            MirInst::LoadGr64(inst) => inst.num_outs(),
+            MirInst::LoadGr32(inst) => inst.num_outs(),
+            MirInst::LoadF64(inst) => inst.num_outs(),
+            MirInst::LoadF32(inst) => inst.num_outs(),
+            MirInst::StoreGr64(inst) => inst.num_outs(),
+            MirInst::StoreGr32(inst) => inst.num_outs(),
+            MirInst::StoreF64(inst) => inst.num_outs(),
+            MirInst::StoreF32(inst) => inst.num_outs(),
+            MirInst::LoadGr64Base(inst) => inst.num_outs(),
+            MirInst::LoadGr32Base(inst) => inst.num_outs(),
+            MirInst::LoadF128Base(inst) => inst.num_outs(),
+            MirInst::LoadF64Base(inst) => inst.num_outs(),
+            MirInst::LoadF32Base(inst) => inst.num_outs(),
+            MirInst::LoadGr64BaseS(inst) => inst.num_outs(),
+            MirInst::LoadGr32BaseS(inst) => inst.num_outs(),
+            MirInst::LoadF128BaseS(inst) => inst.num_outs(),
+            MirInst::LoadF64BaseS(inst) => inst.num_outs(),
+            MirInst::LoadF32BaseS(inst) => inst.num_outs(),
+            MirInst::StoreGr64Base(inst) => inst.num_outs(),
+            MirInst::StoreGr32Base(inst) => inst.num_outs(),
+            MirInst::StoreF128Base(inst) => inst.num_outs(),
+            MirInst::StoreF64Base(inst) => inst.num_outs(),
+            MirInst::StoreF32Base(inst) => inst.num_outs(),
+            MirInst::StoreGr64BaseS(inst) => inst.num_outs(),
+            MirInst::StoreGr32BaseS(inst) => inst.num_outs(),
+            MirInst::StoreF128BaseS(inst) => inst.num_outs(),
+            MirInst::StoreF64BaseS(inst) => inst.num_outs(),
+            MirInst::StoreF32BaseS(inst) => inst.num_outs(),
+            MirInst::LoadGr64Indexed(inst) => inst.num_outs(),
+            MirInst::LoadGr32Indexed(inst) => inst.num_outs(),
+            MirInst::LoadF64Indexed(inst) => inst.num_outs(),
+            MirInst::LoadF32Indexed(inst) => inst.num_outs(),
+            MirInst::StoreGr64Indexed(inst) => inst.num_outs(),
+            MirInst::StoreGr32Indexed(inst) => inst.num_outs(),
+            MirInst::StoreF64Indexed(inst) => inst.num_outs(),
+            MirInst::StoreF32Indexed(inst) => inst.num_outs(),
+            MirInst::LoadGr64Literal(inst) => inst.num_outs(),
+            MirInst::LoadGr32Literal(inst) => inst.num_outs(),
+            MirInst::LoadF64Literal(inst) => inst.num_outs(),
+            MirInst::LoadF32Literal(inst) => inst.num_outs(),
+            MirInst::MirLdrLitG64(inst) => inst.num_outs(),
+            MirInst::MirLdrLitG32(inst) => inst.num_outs(),
+            MirInst::MirLdrLitF64(inst) => inst.num_outs(),
+            MirInst::MirLdrLitF32(inst) => inst.num_outs(),
+            MirInst::MirStrLitG64(inst) => inst.num_outs(),
+            MirInst::MirStrLitG32(inst) => inst.num_outs(),
+            MirInst::MirStrLitF64(inst) => inst.num_outs(),
+            MirInst::MirStrLitF32(inst) => inst.num_outs(),
+            MirInst::MirStImm64(inst) => inst.num_outs(),
+            MirInst::MirStSym64(inst) => inst.num_outs(),
+            MirInst::MirStImm32(inst) => inst.num_outs(),
+            MirInst::MirStImm64Sym(inst) => inst.num_outs(),
+            MirInst::MirStImm32Sym(inst) => inst.num_outs(),
+            MirInst::MirStSym64Sym(inst) => inst.num_outs(),
+            MirInst::LoadConst64(inst) => inst.num_outs(),
+            MirInst::LoadConst64Symbol(inst) => inst.num_outs(),
+            MirInst::MirLdImmF64(inst) => inst.num_outs(),
+            MirInst::MirLdImmF32(inst) => inst.num_outs(),
+            MirInst::MirDynAlloca(inst) => inst.num_outs(),
+            MirInst::CSel64(inst) => inst.num_outs(),
+            MirInst::CSel32(inst) => inst.num_outs(),
+            MirInst::CSelF64(inst) => inst.num_outs(),
+            MirInst::CSelF32(inst) => inst.num_outs(),
+            MirInst::CSet64(inst) => inst.num_outs(),
+            MirInst::CSet32(inst) => inst.num_outs(),
+            MirInst::MirCall(inst) => inst.num_outs(),
+            MirInst::MirReturn(inst) => inst.num_outs(),
+            MirInst::MirSwitch(inst) => inst.num_outs(),
+            MirInst::MirSaveRegs(inst) => inst.num_outs(),
+            MirInst::MirRestoreRegs(inst) => inst.num_outs(),
+            MirInst::MirRestoreHostRegs(inst) => inst.num_outs(),
+            MirInst::MirGEP(inst) => inst.num_outs(),
+            MirInst::MirComment(inst) => inst.num_outs(),
+            MirInst::MirCommentedInst(inst) => inst.num_outs(),
+        }
+    }
+
+    pub fn operand_kinds(&self) -> &[MirOperandKind] {
+        match self {
+            MirInst::CondBr(inst) => inst.operand_kinds(),
+            MirInst::UncondBr(inst) => inst.operand_kinds(),
+            MirInst::BReg(inst) => inst.operand_kinds(),
+            MirInst::BLinkLabel(inst) => inst.operand_kinds(),
+            MirInst::BLinkGlobal(inst) => inst.operand_kinds(),
+            MirInst::BLinkReg(inst) => inst.operand_kinds(),
+            MirInst::CBZs(inst) => inst.operand_kinds(),
+            MirInst::TBZ64(inst) => inst.operand_kinds(),
+            MirInst::TBZ32(inst) => inst.operand_kinds(),
+            MirInst::Trap(inst) => inst.operand_kinds(),
+            MirInst::ICmp64R(inst) => inst.operand_kinds(),
+            MirInst::ICmp32R(inst) => inst.operand_kinds(),
+            MirInst::ICmp64I(inst) => inst.operand_kinds(),
+            MirInst::ICmp32I(inst) => inst.operand_kinds(),
+            MirInst::FCmp32(inst) => inst.operand_kinds(),
+            MirInst::FCmp64(inst) => inst.operand_kinds(),
+            MirInst::ICCmp64R(inst) => inst.operand_kinds(),
+            MirInst::ICCmp32R(inst) => inst.operand_kinds(),
+            MirInst::ICCmp64I(inst) => inst.operand_kinds(),
+            MirInst::ICCmp32I(inst) => inst.operand_kinds(),
+            MirInst::FCCmp32(inst) => inst.operand_kinds(),
+            MirInst::FCCmp64(inst) => inst.operand_kinds(),
+            MirInst::Bin64R(inst) => inst.operand_kinds(),
+            MirInst::Bin32R(inst) => inst.operand_kinds(),
+            MirInst::MulL(inst) => inst.operand_kinds(),
+            MirInst::Bin64RC(inst) => inst.operand_kinds(),
+            MirInst::Bin32RC(inst) => inst.operand_kinds(),
+            MirInst::Bin64RSym(inst) => inst.operand_kinds(),
+            MirInst::Bin64RL(inst) => inst.operand_kinds(),
+            MirInst::Bin32RL(inst) => inst.operand_kinds(),
+            MirInst::Bin64RS(inst) => inst.operand_kinds(),
+            MirInst::Bin64RU(inst) => inst.operand_kinds(),
+            MirInst::Bin32RS(inst) => inst.operand_kinds(),
+            MirInst::Bin32RU(inst) => inst.operand_kinds(),
+            MirInst::Bin64RShift(inst) => inst.operand_kinds(),
+            MirInst::Bin32RShift(inst) => inst.operand_kinds(),
+            MirInst::BinF64R(inst) => inst.operand_kinds(),
+            MirInst::BinF32R(inst) => inst.operand_kinds(),
+            MirInst::MirCopy64(inst) => inst.operand_kinds(),
+            MirInst::MirCopy32(inst) => inst.operand_kinds(),
+            MirInst::MirFCopy128(inst) => inst.operand_kinds(),
+            MirInst::MirFCopy64(inst) => inst.operand_kinds(),
+            MirInst::MirFCopy32(inst) => inst.operand_kinds(),
+            MirInst::MirPCopy(inst) => inst.operand_kinds(),
+            MirInst::Una64R(inst) => inst.operand_kinds(),
+            MirInst::Una32R(inst) => inst.operand_kinds(),
+            MirInst::ExtR(inst) => inst.operand_kinds(),
+            MirInst::Mov64I(inst) => inst.operand_kinds(),
+            MirInst::MovZNK64(inst) => inst.operand_kinds(),
+            MirInst::Mov32I(inst) => inst.operand_kinds(),
+            MirInst::MovZNK32(inst) => inst.operand_kinds(),
+            MirInst::Adr(inst) => inst.operand_kinds(),
+            MirInst::UnaFG64(inst) => inst.operand_kinds(),
+            MirInst::UnaGF64(inst) => inst.operand_kinds(),
+            MirInst::UnaF64G32(inst) => inst.operand_kinds(),
+            MirInst::UnaFG32(inst) => inst.operand_kinds(),
+            MirInst::UnaF32G64(inst) => inst.operand_kinds(),
+            MirInst::UnaGF32(inst) => inst.operand_kinds(),
+            MirInst::UnaG64F32(inst) => inst.operand_kinds(),
+            MirInst::UnaG32F64(inst) => inst.operand_kinds(),
+            MirInst::UnaF64(inst) => inst.operand_kinds(),
+            MirInst::UnaF32(inst) => inst.operand_kinds(),
+            MirInst::UnaryF32F64(inst) => inst.operand_kinds(),
+            MirInst::UnaryF64F32(inst) => inst.operand_kinds(),
+            MirInst::FMov64I(inst) => inst.operand_kinds(),
+            MirInst::FMov32I(inst) => inst.operand_kinds(),
+            MirInst::TenaryG64(inst) => inst.operand_kinds(),
+            MirInst::TenaryG64G32(inst) => inst.operand_kinds(),
+            MirInst::TenaryG32(inst) => inst.operand_kinds(),
+            MirInst::TenaryF64(inst) => inst.operand_kinds(),
+            MirInst::TenaryF32(inst) => inst.operand_kinds(),
+            MirInst::LoadGr64(inst) => inst.operand_kinds(),
+            MirInst::LoadGr32(inst) => inst.operand_kinds(),
+            MirInst::LoadF64(inst) => inst.operand_kinds(),
+            MirInst::LoadF32(inst) => inst.operand_kinds(),
+            MirInst::StoreGr64(inst) => inst.operand_kinds(),
+            MirInst::StoreGr32(inst) => inst.operand_kinds(),
+            MirInst::StoreF64(inst) => inst.operand_kinds(),
+            MirInst::StoreF32(inst) => inst.operand_kinds(),
+            MirInst::LoadGr64Base(inst) => inst.operand_kinds(),
+            MirInst::LoadGr32Base(inst) => inst.operand_kinds(),
+            MirInst::LoadF128Base(inst) => inst.operand_kinds(),
+            MirInst::LoadF64Base(inst) => inst.operand_kinds(),
+            MirInst::LoadF32Base(inst) => inst.operand_kinds(),
+            MirInst::LoadGr64BaseS(inst) => inst.operand_kinds(),
+            MirInst::LoadGr32BaseS(inst) => inst.operand_kinds(),
+            MirInst::LoadF128BaseS(inst) => inst.operand_kinds(),
+            MirInst::LoadF64BaseS(inst) => inst.operand_kinds(),
+            MirInst::LoadF32BaseS(inst) => inst.operand_kinds(),
+            MirInst::StoreGr64Base(inst) => inst.operand_kinds(),
+            MirInst::StoreGr32Base(inst) => inst.operand_kinds(),
+            MirInst::StoreF128Base(inst) => inst.operand_kinds(),
+            MirInst::StoreF64Base(inst) => inst.operand_kinds(),
+            MirInst::StoreF32Base(inst) => inst.operand_kinds(),
+            MirInst::StoreGr64BaseS(inst) => inst.operand_kinds(),
+            MirInst::StoreGr32BaseS(inst) => inst.operand_kinds(),
+            MirInst::StoreF128BaseS(inst) => inst.operand_kinds(),
+            MirInst::StoreF64BaseS(inst) => inst.operand_kinds(),
+            MirInst::StoreF32BaseS(inst) => inst.operand_kinds(),
+            MirInst::LoadGr64Indexed(inst) => inst.operand_kinds(),
+            MirInst::LoadGr32Indexed(inst) => inst.operand_kinds(),
+            MirInst::LoadF64Indexed(inst) => inst.operand_kinds(),
+            MirInst::LoadF32Indexed(inst) => inst.operand_kinds(),
+            MirInst::StoreGr64Indexed(inst) => inst.operand_kinds(),
+            MirInst::StoreGr32Indexed(inst) => inst.operand_kinds(),
+            MirInst::StoreF64Indexed(inst) => inst.operand_kinds(),
+            MirInst::StoreF32Indexed(inst) => inst.operand_kinds(),
+            MirInst::LoadGr64Literal(inst) => inst.operand_kinds(),
+            MirInst::LoadGr32Literal(inst) => inst.operand_kinds(),
+            MirInst::LoadF64Literal(inst) => inst.operand_kinds(),
+            MirInst::LoadF32Literal(inst) => inst.operand_kinds(),
+            MirInst::MirLdrLitG64(inst) => inst.operand_kinds(),
+            MirInst::MirLdrLitG32(inst) => inst.operand_kinds(),
+            MirInst::MirLdrLitF64(inst) => inst.operand_kinds(),
+            MirInst::MirLdrLitF32(inst) => inst.operand_kinds(),
+            MirInst::MirStrLitG64(inst) => inst.operand_kinds(),
+            MirInst::MirStrLitG32(inst) => inst.operand_kinds(),
+            MirInst::MirStrLitF64(inst) => inst.operand_kinds(),
+            MirInst::MirStrLitF32(inst) => inst.operand_kinds(),
+            MirInst::MirStImm64(inst) => inst.operand_kinds(),
+            MirInst::MirStSym64(inst) => inst.operand_kinds(),
+            MirInst::MirStImm32(inst) => inst.operand_kinds(),
+            MirInst::MirStImm64Sym(inst) => inst.operand_kinds(),
+            MirInst::MirStImm32Sym(inst) => inst.operand_kinds(),
+            MirInst::MirStSym64Sym(inst) => inst.operand_kinds(),
+            MirInst::LoadConst64(inst) => inst.operand_kinds(),
+            MirInst::LoadConst64Symbol(inst) => inst.operand_kinds(),
+            MirInst::MirLdImmF64(inst) => inst.operand_kinds(),
+            MirInst::MirLdImmF32(inst) => inst.operand_kinds(),
+            MirInst::MirDynAlloca(inst) => inst.operand_kinds(),
+            MirInst::CSel64(inst) => inst.operand_kinds(),
+            MirInst::CSel32(inst) => inst.operand_kinds(),
+            MirInst::CSelF64(inst) => inst.operand_kinds(),
+            MirInst::CSelF32(inst) => inst.operand_kinds(),
+            MirInst::CSet64(inst) => inst.operand_kinds(),
+            MirInst::CSet32(inst) => inst.operand_kinds(),
+            MirInst::MirCall(inst) => inst.operand_kinds(),
+            MirInst::MirReturn(inst) => inst.operand_kinds(),
+            MirInst::MirSwitch(inst) => inst.operand_kinds(),
+            MirInst::MirSaveRegs(inst) => inst.operand_kinds(),
+            MirInst::MirRestoreRegs(inst) => inst.operand_kinds(),
+            MirInst::MirRestoreHostRegs(inst) => inst.operand_kinds(),
+            MirInst::MirGEP(inst) => inst.operand_kinds(),
+            MirInst::MirComment(inst) => inst.operand_kinds(),
+            MirInst::MirCommentedInst(inst) => inst.operand_kinds(),
+        }
+    }
+
+    pub fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        match self {
+            MirInst::CondBr(inst) => inst.fmt_props(out),
+            MirInst::UncondBr(inst) => inst.fmt_props(out),
+            MirInst::BReg(inst) => inst.fmt_props(out),
+            MirInst::BLinkLabel(inst) => inst.fmt_props(out),
+            MirInst::BLinkGlobal(inst) => inst.fmt_props(out),
+            MirInst::BLinkReg(inst) => inst.fmt_props(out),
+            MirInst::CBZs(inst) => inst.fmt_props(out),
+            MirInst::TBZ64(inst) => inst.fmt_props(out),
+            MirInst::TBZ32(inst) => inst.fmt_props(out),
+            MirInst::Trap(inst) => inst.fmt_props(out),
+            MirInst::ICmp64R(inst) => inst.fmt_props(out),
+            MirInst::ICmp32R(inst) => inst.fmt_props(out),
+            MirInst::ICmp64I(inst) => inst.fmt_props(out),
+            MirInst::ICmp32I(inst) => inst.fmt_props(out),
+            MirInst::FCmp32(inst) => inst.fmt_props(out),
+            MirInst::FCmp64(inst) => inst.fmt_props(out),
+            MirInst::ICCmp64R(inst) => inst.fmt_props(out),
+            MirInst::ICCmp32R(inst) => inst.fmt_props(out),
+            MirInst::ICCmp64I(inst) => inst.fmt_props(out),
+            MirInst::ICCmp32I(inst) => inst.fmt_props(out),
+            MirInst::FCCmp32(inst) => inst.fmt_props(out),
+            MirInst::FCCmp64(inst) => inst.fmt_props(out),
+            MirInst::Bin64R(inst) => inst.fmt_props(out),
+            MirInst::Bin32R(inst) => inst.fmt_props(out),
+            MirInst::MulL(inst) => inst.fmt_props(out),
+            MirInst::Bin64RC(inst) => inst.fmt_props(out),
+            MirInst::Bin32RC(inst) => inst.fmt_props(out),
+            MirInst::Bin64RSym(inst) => inst.fmt_props(out),
+            MirInst::Bin64RL(inst) => inst.fmt_props(out),
+            MirInst::Bin32RL(inst) => inst.fmt_props(out),
+            MirInst::Bin64RS(inst) => inst.fmt_props(out),
+            MirInst::Bin64RU(inst) => inst.fmt_props(out),
+            MirInst::Bin32RS(inst) => inst.fmt_props(out),
+            MirInst::Bin32RU(inst) => inst.fmt_props(out),
+            MirInst::Bin64RShift(inst) => inst.fmt_props(out),
+            MirInst::Bin32RShift(inst) => inst.fmt_props(out),
+            MirInst::BinF64R(inst) => inst.fmt_props(out),
+            MirInst::BinF32R(inst) => inst.fmt_props(out),
+            MirInst::MirCopy64(inst) => inst.fmt_props(out),
+            MirInst::MirCopy32(inst) => inst.fmt_props(out),
+            MirInst::MirFCopy128(inst) => inst.fmt_props(out),
+            MirInst::MirFCopy64(inst) => inst.fmt_props(out),
+            MirInst::MirFCopy32(inst) => inst.fmt_props(out),
+            MirInst::MirPCopy(inst) => inst.fmt_props(out),
+            MirInst::Una64R(inst) => inst.fmt_props(out),
+            MirInst::Una32R(inst) => inst.fmt_props(out),
+            MirInst::ExtR(inst) => inst.fmt_props(out),
+            MirInst::Mov64I(inst) => inst.fmt_props(out),
+            MirInst::MovZNK64(inst) => inst.fmt_props(out),
+            MirInst::Mov32I(inst) => inst.fmt_props(out),
+            MirInst::MovZNK32(inst) => inst.fmt_props(out),
+            MirInst::Adr(inst) => inst.fmt_props(out),
+            MirInst::UnaFG64(inst) => inst.fmt_props(out),
+            MirInst::UnaGF64(inst) => inst.fmt_props(out),
+            MirInst::UnaF64G32(inst) => inst.fmt_props(out),
+            MirInst::UnaFG32(inst) => inst.fmt_props(out),
+            MirInst::UnaF32G64(inst) => inst.fmt_props(out),
+            MirInst::UnaGF32(inst) => inst.fmt_props(out),
+            MirInst::UnaG64F32(inst) => inst.fmt_props(out),
+            MirInst::UnaG32F64(inst) => inst.fmt_props(out),
+            MirInst::UnaF64(inst) => inst.fmt_props(out),
+            MirInst::UnaF32(inst) => inst.fmt_props(out),
+            MirInst::UnaryF32F64(inst) => inst.fmt_props(out),
+            MirInst::UnaryF64F32(inst) => inst.fmt_props(out),
+            MirInst::FMov64I(inst) => inst.fmt_props(out),
+            MirInst::FMov32I(inst) => inst.fmt_props(out),
+            MirInst::TenaryG64(inst) => inst.fmt_props(out),
+            MirInst::TenaryG64G32(inst) => inst.fmt_props(out),
+            MirInst::TenaryG32(inst) => inst.fmt_props(out),
+            MirInst::TenaryF64(inst) => inst.fmt_props(out),
+            MirInst::TenaryF32(inst) => inst.fmt_props(out),
+            MirInst::LoadGr64(inst) => inst.fmt_props(out),
+            MirInst::LoadGr32(inst) => inst.fmt_props(out),
+            MirInst::LoadF64(inst) => inst.fmt_props(out),
+            MirInst::LoadF32(inst) => inst.fmt_props(out),
+            MirInst::StoreGr64(inst) => inst.fmt_props(out),
+            MirInst::StoreGr32(inst) => inst.fmt_props(out),
+            MirInst::StoreF64(inst) => inst.fmt_props(out),
+            MirInst::StoreF32(inst) => inst.fmt_props(out),
+            MirInst::LoadGr64Base(inst) => inst.fmt_props(out),
+            MirInst::LoadGr32Base(inst) => inst.fmt_props(out),
+            MirInst::LoadF128Base(inst) => inst.fmt_props(out),
+            MirInst::LoadF64Base(inst) => inst.fmt_props(out),
+            MirInst::LoadF32Base(inst) => inst.fmt_props(out),
+            MirInst::LoadGr64BaseS(inst) => inst.fmt_props(out),
+            MirInst::LoadGr32BaseS(inst) => inst.fmt_props(out),
+            MirInst::LoadF128BaseS(inst) => inst.fmt_props(out),
+            MirInst::LoadF64BaseS(inst) => inst.fmt_props(out),
+            MirInst::LoadF32BaseS(inst) => inst.fmt_props(out),
+            MirInst::StoreGr64Base(inst) => inst.fmt_props(out),
+            MirInst::StoreGr32Base(inst) => inst.fmt_props(out),
+            MirInst::StoreF128Base(inst) => inst.fmt_props(out),
+            MirInst::StoreF64Base(inst) => inst.fmt_props(out),
+            MirInst::StoreF32Base(inst) => inst.fmt_props(out),
+            MirInst::StoreGr64BaseS(inst) => inst.fmt_props(out),
+            MirInst::StoreGr32BaseS(inst) => inst.fmt_props(out),
+            MirInst::StoreF128BaseS(inst) => inst.fmt_props(out),
+            MirInst::StoreF64BaseS(inst) => inst.fmt_props(out),
+            MirInst::StoreF32BaseS(inst) => inst.fmt_props(out),
+            MirInst::LoadGr64Indexed(inst) => inst.fmt_props(out),
+            MirInst::LoadGr32Indexed(inst) => inst.fmt_props(out),
+            MirInst::LoadF64Indexed(inst) => inst.fmt_props(out),
+            MirInst::LoadF32Indexed(inst) => inst.fmt_props(out),
+            MirInst::StoreGr64Indexed(inst) => inst.fmt_props(out),
+            MirInst::StoreGr32Indexed(inst) => inst.fmt_props(out),
+            MirInst::StoreF64Indexed(inst) => inst.fmt_props(out),
+            MirInst::StoreF32Indexed(inst) => inst.fmt_props(out),
+            MirInst::LoadGr64Literal(inst) => inst.fmt_props(out),
+            MirInst::LoadGr32Literal(inst) => inst.fmt_props(out),
+            MirInst::LoadF64Literal(inst) => inst.fmt_props(out),
+            MirInst::LoadF32Literal(inst) => inst.fmt_props(out),
+            MirInst::MirLdrLitG64(inst) => inst.fmt_props(out),
+            MirInst::MirLdrLitG32(inst) => inst.fmt_props(out),
+            MirInst::MirLdrLitF64(inst) => inst.fmt_props(out),
+            MirInst::MirLdrLitF32(inst) => inst.fmt_props(out),
+            MirInst::MirStrLitG64(inst) => inst.fmt_props(out),
+            MirInst::MirStrLitG32(inst) => inst.fmt_props(out),
+            MirInst::MirStrLitF64(inst) => inst.fmt_props(out),
+            MirInst::MirStrLitF32(inst) => inst.fmt_props(out),
+            MirInst::MirStImm64(inst) => inst.fmt_props(out),
+            MirInst::MirStSym64(inst) => inst.fmt_props(out),
+            MirInst::MirStImm32(inst) => inst.fmt_props(out),
+            MirInst::MirStImm64Sym(inst) => inst.fmt_props(out),
+            MirInst::MirStImm32Sym(inst) => inst.fmt_props(out),
+            MirInst::MirStSym64Sym(inst) => inst.fmt_props(out),
+            MirInst::LoadConst64(inst) => inst.fmt_props(out),
+            MirInst::LoadConst64Symbol(inst) => inst.fmt_props(out),
+            MirInst::MirLdImmF64(inst) => inst.fmt_props(out),
+            MirInst::MirLdImmF32(inst) => inst.fmt_props(out),
+            MirInst::MirDynAlloca(inst) => inst.fmt_props(out),
+            MirInst::CSel64(inst) => inst.fmt_props(out),
+            MirInst::CSel32(inst) => inst.fmt_props(out),
+            MirInst::CSelF64(inst) => inst.fmt_props(out),
+            MirInst::CSelF32(inst) => inst.fmt_props(out),
+            MirInst::CSet64(inst) => inst.fmt_props(out),
+            MirInst::CSet32(inst) => inst.fmt_props(out),
+            MirInst::MirCall(inst) => inst.fmt_props(out),
+            MirInst::MirReturn(inst) => inst.fmt_props(out),
+            MirInst::MirSwitch(inst) => inst.fmt_props(out),
+            MirInst::MirSaveRegs(inst) => inst.fmt_props(out),
+            MirInst::MirRestoreRegs(inst) => inst.fmt_props(out),
+            MirInst::MirRestoreHostRegs(inst) => inst.fmt_props(out),
+            MirInst::MirGEP(inst) => inst.fmt_props(out),
+            MirInst::MirComment(inst) => inst.fmt_props(out),
+            MirInst::MirCommentedInst(inst) => inst.fmt_props(out),
+        }
+    }
+}
+
+/// `CondBr`.
+///
+/// * 输入: `label: Label`, `csr: PState`
+/// * 操作码: `BCond`, `BCCond`
+#[derive(Clone)]
+pub struct CondBr {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+    cond: Cell<MirCondFlag>,
+}
+
+impl CondBr {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::BCond,
+        MirOP::BCCond,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::Label,
+        MirOperandKind::PState,
+    ];
+
+    pub fn new(opcode: MirOP, label: MirOperand, csr: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CondBr"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(label), Cell::new(csr)],
+            cond: Cell::new(MirCondFlag::AL),
+        }
+    }
+
+    pub fn label(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_label(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_label(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+}
+
+impl IMirSubInst for CondBr {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::BCond | MirOP::BCCond)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CondBr(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CondBr(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UncondBr`: 模板 `UnCondBranches[Label]` 的实例.
+///
+/// * 输入: `target: Label`
+/// * 操作码: `B`
+#[derive(Clone)]
+pub struct UncondBr {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 1],
+}
+
+impl UncondBr {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::B,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 1] = [
+        MirOperandKind::Label,
+    ];
+
+    pub fn new(opcode: MirOP, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UncondBr"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(target)],
+        }
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+}
+
+impl IMirSubInst for UncondBr {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::B)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UncondBr(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UncondBr(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `BReg`: 模板 `UnCondBranches[GPR64]` 的实例.
+///
+/// * 输入: `target: GPR64`
+/// * 操作码: `Br`, `Ret`
+#[derive(Clone)]
+pub struct BReg {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 1],
+}
+
+impl BReg {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::Br,
+        MirOP::Ret,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 1] = [
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class BReg"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(target)],
+        }
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+}
+
+impl IMirSubInst for BReg {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Br | MirOP::Ret)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::BReg(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::BReg(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `BLinkLabel`: 模板 `BranchLinks[Label]` 的实例.
+///
+/// * 输出: `ra: GPR64`
+/// * 输入: `target: Label`
+/// * 操作码: `BLink`
+#[derive(Clone)]
+pub struct BLinkLabel {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl BLinkLabel {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::BLink,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Label,
+    ];
+
+    pub fn new(opcode: MirOP, ra: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class BLinkLabel"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(ra), Cell::new(target)],
+        }
+    }
+
+    pub fn ra(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_ra(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_ra(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for BLinkLabel {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::BLink)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::BLinkLabel(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::BLinkLabel(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `BLinkGlobal`: 模板 `BranchLinks[Global]` 的实例.
+///
+/// * 输出: `ra: GPR64`
+/// * 输入: `target: Global`
+/// * 操作码: `BLinkGlobal`
+#[derive(Clone)]
+pub struct BLinkGlobal {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl BLinkGlobal {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::BLinkGlobal,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Global,
+    ];
+
+    pub fn new(opcode: MirOP, ra: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class BLinkGlobal"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(ra), Cell::new(target)],
+        }
+    }
+
+    pub fn ra(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_ra(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_ra(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for BLinkGlobal {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::BLinkGlobal)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::BLinkGlobal(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::BLinkGlobal(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `BLinkReg`: 模板 `BranchLinks[GPR64]` 的实例.
+///
+/// * 输出: `ra: GPR64`
+/// * 输入: `target: GPR64`
+/// * 操作码: `BLinkReg`
+#[derive(Clone)]
+pub struct BLinkReg {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl BLinkReg {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::BLinkReg,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, ra: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class BLinkReg"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(ra), Cell::new(target)],
+        }
+    }
+
+    pub fn ra(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_ra(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_ra(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for BLinkReg {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::BLinkReg)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::BLinkReg(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::BLinkReg(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `CBZs`.
+///
+/// * 输入: `cond: GPR64`, `target: Label`
+/// * 操作码: `CBZ`, `CBNZ`
+#[derive(Clone)]
+pub struct CBZs {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl CBZs {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::CBZ,
+        MirOP::CBNZ,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Label,
+    ];
+
+    pub fn new(opcode: MirOP, cond: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CBZs"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(cond), Cell::new(target)],
+        }
+    }
+
+    pub fn cond(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_cond(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_cond(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for CBZs {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::CBZ | MirOP::CBNZ)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CBZs(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CBZs(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `TBZ64`: 模板 `TBZs[GPR64]` 的实例.
+///
+/// * 输入: `cond: GPR64`, `bits: Imm32`, `target: Label`
+/// * 操作码: `TBZ64`, `TBNZ64`
+#[derive(Clone)]
+pub struct TBZ64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl TBZ64 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::TBZ64,
+        MirOP::TBNZ64,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm32,
+        MirOperandKind::Label,
+    ];
+
+    pub fn new(opcode: MirOP, cond: MirOperand, bits: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class TBZ64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(cond), Cell::new(bits), Cell::new(target)],
+        }
+    }
+
+    pub fn cond(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_cond(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_cond(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn bits(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_bits(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_bits(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for TBZ64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::TBZ64 | MirOP::TBNZ64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::TBZ64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::TBZ64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `TBZ32`: 模板 `TBZs[GPR32]` 的实例.
+///
+/// * 输入: `cond: GPR32`, `bits: Imm32`, `target: Label`
+/// * 操作码: `TBZ32`, `TBNZ32`
+#[derive(Clone)]
+pub struct TBZ32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl TBZ32 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::TBZ32,
+        MirOP::TBNZ32,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::Imm32,
+        MirOperandKind::Label,
+    ];
+
+    pub fn new(opcode: MirOP, cond: MirOperand, bits: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class TBZ32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(cond), Cell::new(bits), Cell::new(target)],
+        }
+    }
+
+    pub fn cond(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_cond(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_cond(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn bits(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_bits(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_bits(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for TBZ32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::TBZ32 | MirOP::TBNZ32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::TBZ32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::TBZ32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Trap`.
+///
+/// * 输入: `imm: Imm32`
+/// * 操作码: `Brk`, `Udf`
+#[derive(Clone)]
+pub struct Trap {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 1],
+}
+
+impl Trap {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::Brk,
+        MirOP::Udf,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 1] = [
+        MirOperandKind::Imm32,
+    ];
+
+    pub fn new(opcode: MirOP, imm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Trap"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(imm)],
+        }
+    }
+
+    pub fn imm(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_imm(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_imm(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+}
+
+impl IMirSubInst for Trap {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Brk | MirOP::Udf)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Trap(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Trap(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICmp64R`: 模板 `CompareInsts[GPR64, GPR64]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR64`, `rhs: GPR64`
+/// * 操作码: `ICmp64R`, `ICmn64R`
+#[derive(Clone)]
+pub struct ICmp64R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl ICmp64R {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICmp64R,
+        MirOP::ICmn64R,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICmp64R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for ICmp64R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICmp64R | MirOP::ICmn64R)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICmp64R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICmp64R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICmp32R`: 模板 `CompareInsts[GPR32, GPR32]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR32`, `rhs: GPR32`
+/// * 操作码: `ICmp32R`, `ICmn32R`
+#[derive(Clone)]
+pub struct ICmp32R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl ICmp32R {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICmp32R,
+        MirOP::ICmn32R,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICmp32R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for ICmp32R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICmp32R | MirOP::ICmn32R)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICmp32R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICmp32R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICmp64I`: 模板 `CompareInsts[GPR64, ImmCalc]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR64`, `rhs: ImmCalc`
+/// * 操作码: `ICmp64I`, `ICmn64I`
+#[derive(Clone)]
+pub struct ICmp64I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl ICmp64I {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICmp64I,
+        MirOP::ICmn64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmCalc,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICmp64I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for ICmp64I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICmp64I | MirOP::ICmn64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICmp64I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICmp64I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICmp32I`: 模板 `CompareInsts[GPR32, ImmCalc]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR32`, `rhs: ImmCalc`
+/// * 操作码: `ICmp32I`, `ICmn32I`
+#[derive(Clone)]
+pub struct ICmp32I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl ICmp32I {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICmp32I,
+        MirOP::ICmn32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmCalc,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICmp32I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for ICmp32I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICmp32I | MirOP::ICmn32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICmp32I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICmp32I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `FCmp32`: 模板 `CompareInsts[FPR32, FPR32]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: FPR32`, `rhs: FPR32`
+/// * 操作码: `FCmp32`, `FCmpE32`
+#[derive(Clone)]
+pub struct FCmp32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl FCmp32 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::FCmp32,
+        MirOP::FCmpE32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class FCmp32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for FCmp32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCmp32 | MirOP::FCmpE32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::FCmp32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::FCmp32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `FCmp64`: 模板 `CompareInsts[FPR64, FPR64]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: FPR64`, `rhs: FPR64`
+/// * 操作码: `FCmp64`, `FCmpE64`
+#[derive(Clone)]
+pub struct FCmp64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl FCmp64 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::FCmp64,
+        MirOP::FCmpE64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class FCmp64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for FCmp64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCmp64 | MirOP::FCmpE64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::FCmp64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::FCmp64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICCmp64R`: 模板 `CondCmpInsts[GPR64, GPR64]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR64`, `rhs: GPR64`
+/// * 操作码: `ICCmp64R`, `ICCmn64R`
+#[derive(Clone)]
+pub struct ICCmp64R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    cond: Cell<MirCondFlag>,
+    nzcv: Cell<NZCV>,
+}
+
+impl ICCmp64R {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICCmp64R,
+        MirOP::ICCmn64R,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICCmp64R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            cond: Cell::new(MirCondFlag::AL),
+            nzcv: Cell::new(NZCV::empty()),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+
+    pub fn get_nzcv(&self) -> NZCV {
+        self.nzcv.get()
+    }
+    pub fn set_nzcv(&self, value: NZCV) {
+        self.nzcv.set(value)
+    }
+}
+
+impl IMirSubInst for ICCmp64R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICCmp64R | MirOP::ICCmn64R)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())?;
+        write!(out, ", nzcv: {:?}", self.get_nzcv())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICCmp64R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICCmp64R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICCmp32R`: 模板 `CondCmpInsts[GPR32, GPR32]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR32`, `rhs: GPR32`
+/// * 操作码: `ICCmp32R`, `ICCmn32R`
+#[derive(Clone)]
+pub struct ICCmp32R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    cond: Cell<MirCondFlag>,
+    nzcv: Cell<NZCV>,
+}
+
+impl ICCmp32R {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICCmp32R,
+        MirOP::ICCmn32R,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICCmp32R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            cond: Cell::new(MirCondFlag::AL),
+            nzcv: Cell::new(NZCV::empty()),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+
+    pub fn get_nzcv(&self) -> NZCV {
+        self.nzcv.get()
+    }
+    pub fn set_nzcv(&self, value: NZCV) {
+        self.nzcv.set(value)
+    }
+}
+
+impl IMirSubInst for ICCmp32R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICCmp32R | MirOP::ICCmn32R)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())?;
+        write!(out, ", nzcv: {:?}", self.get_nzcv())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICCmp32R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICCmp32R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICCmp64I`: 模板 `CondCmpInsts[GPR64, ImmCCmp]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR64`, `rhs: ImmCCmp`
+/// * 操作码: `ICCmp64I`, `ICCmn64I`
+#[derive(Clone)]
+pub struct ICCmp64I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    cond: Cell<MirCondFlag>,
+    nzcv: Cell<NZCV>,
+}
+
+impl ICCmp64I {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICCmp64I,
+        MirOP::ICCmn64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmCCmp,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICCmp64I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            cond: Cell::new(MirCondFlag::AL),
+            nzcv: Cell::new(NZCV::empty()),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+
+    pub fn get_nzcv(&self) -> NZCV {
+        self.nzcv.get()
+    }
+    pub fn set_nzcv(&self, value: NZCV) {
+        self.nzcv.set(value)
+    }
+}
+
+impl IMirSubInst for ICCmp64I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICCmp64I | MirOP::ICCmn64I)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())?;
+        write!(out, ", nzcv: {:?}", self.get_nzcv())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICCmp64I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICCmp64I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ICCmp32I`: 模板 `CondCmpInsts[GPR32, ImmCCmp]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: GPR32`, `rhs: ImmCCmp`
+/// * 操作码: `ICCmp32I`, `ICCmn32I`
+#[derive(Clone)]
+pub struct ICCmp32I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    cond: Cell<MirCondFlag>,
+    nzcv: Cell<NZCV>,
+}
+
+impl ICCmp32I {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::ICCmp32I,
+        MirOP::ICCmn32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmCCmp,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ICCmp32I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            cond: Cell::new(MirCondFlag::AL),
+            nzcv: Cell::new(NZCV::empty()),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+
+    pub fn get_nzcv(&self) -> NZCV {
+        self.nzcv.get()
+    }
+    pub fn set_nzcv(&self, value: NZCV) {
+        self.nzcv.set(value)
+    }
+}
+
+impl IMirSubInst for ICCmp32I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::ICCmp32I | MirOP::ICCmn32I)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())?;
+        write!(out, ", nzcv: {:?}", self.get_nzcv())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ICCmp32I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ICCmp32I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `FCCmp32`: 模板 `CondCmpInsts[FPR32, FPR32]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: FPR32`, `rhs: FPR32`
+/// * 操作码: `FCCmp32`, `FCCmpE32`
+#[derive(Clone)]
+pub struct FCCmp32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    cond: Cell<MirCondFlag>,
+    nzcv: Cell<NZCV>,
+}
+
+impl FCCmp32 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::FCCmp32,
+        MirOP::FCCmpE32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class FCCmp32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            cond: Cell::new(MirCondFlag::AL),
+            nzcv: Cell::new(NZCV::empty()),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+
+    pub fn get_nzcv(&self) -> NZCV {
+        self.nzcv.get()
+    }
+    pub fn set_nzcv(&self, value: NZCV) {
+        self.nzcv.set(value)
+    }
+}
+
+impl IMirSubInst for FCCmp32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCCmp32 | MirOP::FCCmpE32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())?;
+        write!(out, ", nzcv: {:?}", self.get_nzcv())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::FCCmp32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::FCCmp32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `FCCmp64`: 模板 `CondCmpInsts[FPR64, FPR64]` 的实例.
+///
+/// * 输出: `csr: PState`
+/// * 输入: `rn: FPR64`, `rhs: FPR64`
+/// * 操作码: `FCCmp64`, `FCCmpE64`
+#[derive(Clone)]
+pub struct FCCmp64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    cond: Cell<MirCondFlag>,
+    nzcv: Cell<NZCV>,
+}
+
+impl FCCmp64 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::FCCmp64,
+        MirOP::FCCmpE64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::PState,
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, csr: MirOperand, rn: MirOperand, rhs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class FCCmp64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(csr), Cell::new(rn), Cell::new(rhs)],
+            cond: Cell::new(MirCondFlag::AL),
+            nzcv: Cell::new(NZCV::empty()),
+        }
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rhs(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rhs(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rhs(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+
+    pub fn get_nzcv(&self) -> NZCV {
+        self.nzcv.get()
+    }
+    pub fn set_nzcv(&self, value: NZCV) {
+        self.nzcv.set(value)
+    }
+}
+
+impl IMirSubInst for FCCmp64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCCmp64 | MirOP::FCCmpE64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())?;
+        write!(out, ", nzcv: {:?}", self.get_nzcv())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::FCCmp64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::FCCmp64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin64R`: 模板 `BinaryOP[GPR64, GPR64, GPR64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `Add64R`, `Sub64R`, `SMax64R`, `SMin64R`, `UMax64R`, `UMin64R`, `And64R`, `Bic64R`, `EON64R`, `EOR64R`, `ORR64R`, `ORN64R`, `Asr64R`, `Lsr64R`, `Lsl64R`, `Ror64R`, `Mul64`, `MNeg64`, `SDiv64`, `UDiv64`, `SMulH`, `UMulH`
+#[derive(Clone)]
+pub struct Bin64R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl Bin64R {
+    pub const OPCODES: [MirOP; 22] = [
+        MirOP::Add64R,
+        MirOP::Sub64R,
+        MirOP::SMax64R,
+        MirOP::SMin64R,
+        MirOP::UMax64R,
+        MirOP::UMin64R,
+        MirOP::And64R,
+        MirOP::Bic64R,
+        MirOP::EON64R,
+        MirOP::EOR64R,
+        MirOP::ORR64R,
+        MirOP::ORN64R,
+        MirOP::Asr64R,
+        MirOP::Lsr64R,
+        MirOP::Lsl64R,
+        MirOP::Ror64R,
+        MirOP::Mul64,
+        MirOP::MNeg64,
+        MirOP::SDiv64,
+        MirOP::UDiv64,
+        MirOP::SMulH,
+        MirOP::UMulH,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin64R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for Bin64R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Add64R | MirOP::Sub64R | MirOP::SMax64R | MirOP::SMin64R | MirOP::UMax64R | MirOP::UMin64R | MirOP::And64R | MirOP::Bic64R | MirOP::EON64R | MirOP::EOR64R | MirOP::ORR64R | MirOP::ORN64R | MirOP::Asr64R | MirOP::Lsr64R | MirOP::Lsl64R | MirOP::Ror64R | MirOP::Mul64 | MirOP::MNeg64 | MirOP::SDiv64 | MirOP::UDiv64 | MirOP::SMulH | MirOP::UMulH)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin64R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin64R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin32R`: 模板 `BinaryOP[GPR32, GPR32, GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: GPR32`
+/// * 操作码: `Add32R`, `Sub32R`, `SMax32R`, `SMin32R`, `UMax32R`, `UMin32R`, `And32R`, `Bic32R`, `EON32R`, `EOR32R`, `ORR32R`, `ORN32R`, `Asr32R`, `Lsr32R`, `Lsl32R`, `Ror32R`, `Mul32`, `MNeg32`, `SDiv32`, `UDiv32`
+#[derive(Clone)]
+pub struct Bin32R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl Bin32R {
+    pub const OPCODES: [MirOP; 20] = [
+        MirOP::Add32R,
+        MirOP::Sub32R,
+        MirOP::SMax32R,
+        MirOP::SMin32R,
+        MirOP::UMax32R,
+        MirOP::UMin32R,
+        MirOP::And32R,
+        MirOP::Bic32R,
+        MirOP::EON32R,
+        MirOP::EOR32R,
+        MirOP::ORR32R,
+        MirOP::ORN32R,
+        MirOP::Asr32R,
+        MirOP::Lsr32R,
+        MirOP::Lsl32R,
+        MirOP::Ror32R,
+        MirOP::Mul32,
+        MirOP::MNeg32,
+        MirOP::SDiv32,
+        MirOP::UDiv32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin32R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for Bin32R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Add32R | MirOP::Sub32R | MirOP::SMax32R | MirOP::SMin32R | MirOP::UMax32R | MirOP::UMin32R | MirOP::And32R | MirOP::Bic32R | MirOP::EON32R | MirOP::EOR32R | MirOP::ORR32R | MirOP::ORN32R | MirOP::Asr32R | MirOP::Lsr32R | MirOP::Lsl32R | MirOP::Ror32R | MirOP::Mul32 | MirOP::MNeg32 | MirOP::SDiv32 | MirOP::UDiv32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin32R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin32R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MulL`: 模板 `BinaryOP[GPR64, GPR32, GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR32`, `rm: GPR32`
+/// * 操作码: `SMULL`, `UMULL`, `SMNegL`, `UMNegL`
+#[derive(Clone)]
+pub struct MulL {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MulL {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::SMULL,
+        MirOP::UMULL,
+        MirOP::SMNegL,
+        MirOP::UMNegL,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MulL"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MulL {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::SMULL | MirOP::UMULL | MirOP::SMNegL | MirOP::UMNegL)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MulL(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MulL(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin64RC`: 模板 `BinaryOP[GSP64, GSP64, ImmCalc]` 的实例.
+///
+/// * 输出: `rd: GSP64`
+/// * 输入: `rn: GSP64`, `rm: ImmCalc`
+/// * 操作码: `Add64I`, `Sub64I`
+#[derive(Clone)]
+pub struct Bin64RC {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin64RC {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::Add64I,
+        MirOP::Sub64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GSP64,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmCalc,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin64RC"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin64RC {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Add64I | MirOP::Sub64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin64RC(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin64RC(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin32RC`: 模板 `BinaryOP[GPR32, GPR32, ImmCalc]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: ImmCalc`
+/// * 操作码: `Add32I`, `Sub32I`
+#[derive(Clone)]
+pub struct Bin32RC {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin32RC {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::Add32I,
+        MirOP::Sub32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmCalc,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin32RC"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin32RC {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Add32I | MirOP::Sub32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin32RC(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin32RC(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin64RSym`: 模板 `BinaryOP[GSP64, GSP64, Symbol]` 的实例.
+///
+/// * 输出: `rd: GSP64`
+/// * 输入: `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `Add64Sym`, `Sub64Sym`
+#[derive(Clone)]
+pub struct Bin64RSym {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin64RSym {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::Add64Sym,
+        MirOP::Sub64Sym,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GSP64,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin64RSym"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin64RSym {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Add64Sym | MirOP::Sub64Sym)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin64RSym(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin64RSym(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin64RL`: 模板 `BinaryOP[GPR64, GPR64, ImmLogic]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: ImmLogic`
+/// * 操作码: `And64I`, `Bic64I`, `EON64I`, `EOR64I`, `ORR64I`, `ORN64I`
+#[derive(Clone)]
+pub struct Bin64RL {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin64RL {
+    pub const OPCODES: [MirOP; 6] = [
+        MirOP::And64I,
+        MirOP::Bic64I,
+        MirOP::EON64I,
+        MirOP::EOR64I,
+        MirOP::ORR64I,
+        MirOP::ORN64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLogic,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin64RL"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin64RL {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::And64I | MirOP::Bic64I | MirOP::EON64I | MirOP::EOR64I | MirOP::ORR64I | MirOP::ORN64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin64RL(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin64RL(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin32RL`: 模板 `BinaryOP[GPR32, GPR32, ImmLogic]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: ImmLogic`
+/// * 操作码: `And32I`, `Bic32I`, `EON32I`, `EOR32I`, `ORR32I`, `ORN32I`
+#[derive(Clone)]
+pub struct Bin32RL {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin32RL {
+    pub const OPCODES: [MirOP; 6] = [
+        MirOP::And32I,
+        MirOP::Bic32I,
+        MirOP::EON32I,
+        MirOP::EOR32I,
+        MirOP::ORR32I,
+        MirOP::ORN32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmLogic,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin32RL"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin32RL {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::And32I | MirOP::Bic32I | MirOP::EON32I | MirOP::EOR32I | MirOP::ORR32I | MirOP::ORN32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin32RL(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin32RL(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin64RS`: 模板 `BinaryOP[GPR64, GPR64, ImmSMax]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: ImmSMax`
+/// * 操作码: `SMax64I`, `SMin64I`
+#[derive(Clone)]
+pub struct Bin64RS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin64RS {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::SMax64I,
+        MirOP::SMin64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmSMax,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin64RS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin64RS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::SMax64I | MirOP::SMin64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin64RS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin64RS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin64RU`: 模板 `BinaryOP[GPR64, GPR64, ImmUMax]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: ImmUMax`
+/// * 操作码: `UMax64I`, `UMin64I`
+#[derive(Clone)]
+pub struct Bin64RU {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin64RU {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::UMax64I,
+        MirOP::UMin64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmUMax,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin64RU"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin64RU {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::UMax64I | MirOP::UMin64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin64RU(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin64RU(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin32RS`: 模板 `BinaryOP[GPR32, GPR32, ImmSMax]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: ImmSMax`
+/// * 操作码: `SMax32I`, `SMin32I`
+#[derive(Clone)]
+pub struct Bin32RS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin32RS {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::SMax32I,
+        MirOP::SMin32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmSMax,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin32RS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin32RS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::SMax32I | MirOP::SMin32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin32RS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin32RS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin32RU`: 模板 `BinaryOP[GPR32, GPR32, ImmUMax]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: ImmUMax`
+/// * 操作码: `UMax32I`, `UMin32I`
+#[derive(Clone)]
+pub struct Bin32RU {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin32RU {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::UMax32I,
+        MirOP::UMin32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmUMax,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin32RU"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin32RU {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::UMax32I | MirOP::UMin32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin32RU(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin32RU(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin64RShift`: 模板 `BinaryOP[GPR64, GPR64, ImmShift]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: ImmShift`
+/// * 操作码: `Asr64I`, `Lsr64I`, `Lsl64I`, `Ror64I`
+#[derive(Clone)]
+pub struct Bin64RShift {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin64RShift {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::Asr64I,
+        MirOP::Lsr64I,
+        MirOP::Lsl64I,
+        MirOP::Ror64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmShift,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin64RShift"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin64RShift {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Asr64I | MirOP::Lsr64I | MirOP::Lsl64I | MirOP::Ror64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin64RShift(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin64RShift(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Bin32RShift`: 模板 `BinaryOP[GPR32, GPR32, ImmShift]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: ImmShift`
+/// * 操作码: `Asr32I`, `Lsr32I`, `Lsl32I`, `Ror32I`
+#[derive(Clone)]
+pub struct Bin32RShift {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl Bin32RShift {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::Asr32I,
+        MirOP::Lsr32I,
+        MirOP::Lsl32I,
+        MirOP::Ror32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmShift,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Bin32RShift"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for Bin32RShift {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Asr32I | MirOP::Lsr32I | MirOP::Lsl32I | MirOP::Ror32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Bin32RShift(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Bin32RShift(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `BinF64R`: 模板 `BinaryOP[FPR64, FPR64, FPR64]` 的实例.
+///
+/// * 输出: `rd: FPR64`
+/// * 输入: `rn: FPR64`, `rm: FPR64`
+/// * 操作码: `FAdd64`, `FDiv64`, `FMul64`, `FNMul64`, `FSub64`
+#[derive(Clone)]
+pub struct BinF64R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl BinF64R {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::FAdd64,
+        MirOP::FDiv64,
+        MirOP::FMul64,
+        MirOP::FNMul64,
+        MirOP::FSub64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class BinF64R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for BinF64R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FAdd64 | MirOP::FDiv64 | MirOP::FMul64 | MirOP::FNMul64 | MirOP::FSub64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::BinF64R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::BinF64R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `BinF32R`: 模板 `BinaryOP[FPR32, FPR32, FPR32]` 的实例.
+///
+/// * 输出: `rd: FPR32`
+/// * 输入: `rn: FPR32`, `rm: FPR32`
+/// * 操作码: `FAdd32`, `FDiv32`, `FMul32`, `FNMul32`, `FSub32`
+#[derive(Clone)]
+pub struct BinF32R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl BinF32R {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::FAdd32,
+        MirOP::FDiv32,
+        MirOP::FMul32,
+        MirOP::FNMul32,
+        MirOP::FSub32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class BinF32R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for BinF32R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FAdd32 | MirOP::FDiv32 | MirOP::FMul32 | MirOP::FNMul32 | MirOP::FSub32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::BinF32R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::BinF32R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirCopy64`: 模板 `MirCopy[GPR64]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: Any`
+/// * 操作码: `MirCopy64`
+#[derive(Clone)]
+pub struct MirCopy64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MirCopy64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirCopy64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Any,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirCopy64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MirCopy64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirCopy64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirCopy64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirCopy64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirCopy32`: 模板 `MirCopy[GPR32]` 的实例.
+///
+/// * 输出: `dst: GPR32`
+/// * 输入: `src: Any`
+/// * 操作码: `MirCopy32`
+#[derive(Clone)]
+pub struct MirCopy32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MirCopy32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirCopy32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::Any,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirCopy32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MirCopy32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirCopy32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirCopy32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirCopy32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirFCopy128`: 模板 `MirCopy[FPR128]` 的实例.
+///
+/// * 输出: `dst: FPR128`
+/// * 输入: `src: Any`
+/// * 操作码: `MirFCopy128`
+#[derive(Clone)]
+pub struct MirFCopy128 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MirFCopy128 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirFCopy128,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR128,
+        MirOperandKind::Any,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirFCopy128"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MirFCopy128 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirFCopy128)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirFCopy128(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirFCopy128(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirFCopy64`: 模板 `MirCopy[FPR64]` 的实例.
+///
+/// * 输出: `dst: FPR64`
+/// * 输入: `src: Any`
+/// * 操作码: `MirFCopy64`
+#[derive(Clone)]
+pub struct MirFCopy64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MirFCopy64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirFCopy64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::Any,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirFCopy64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MirFCopy64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirFCopy64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirFCopy64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirFCopy64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirFCopy32`: 模板 `MirCopy[FPR32]` 的实例.
+///
+/// * 输出: `dst: FPR32`
+/// * 输入: `src: Any`
+/// * 操作码: `MirFCopy32`
+#[derive(Clone)]
+pub struct MirFCopy32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MirFCopy32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirFCopy32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::Any,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirFCopy32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MirFCopy32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirFCopy32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirFCopy32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirFCopy32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirPCopy`: 模板 `MirCopy[PState]` 的实例.
+///
+/// * 输出: `dst: PState`
+/// * 输入: `src: Any`
+/// * 操作码: `MirPCopy`
+#[derive(Clone)]
+pub struct MirPCopy {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MirPCopy {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirPCopy,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::PState,
+        MirOperandKind::Any,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirPCopy"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MirPCopy {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirPCopy)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirPCopy(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirPCopy(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Una64R`: 模板 `UnaryOP[GPR64, GPR64]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: GPR64`
+/// * 操作码: `Neg64R`, `MVN64R`, `Mov64R`, `Abs64R`, `CLS64`, `CLZ64`, `CNT64`, `CTZ64`, `RBit64`, `LoadStackPosGr64`, `StoreStackPosGr64`
+#[derive(Clone)]
+pub struct Una64R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+    dst_op: Cell<Option<RegOP>>,
+}
+
+impl Una64R {
+    pub const OPCODES: [MirOP; 11] = [
+        MirOP::Neg64R,
+        MirOP::MVN64R,
+        MirOP::Mov64R,
+        MirOP::Abs64R,
+        MirOP::CLS64,
+        MirOP::CLZ64,
+        MirOP::CNT64,
+        MirOP::CTZ64,
+        MirOP::RBit64,
+        MirOP::LoadStackPosGr64,
+        MirOP::StoreStackPosGr64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Una64R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+            dst_op: Cell::new(None),
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn get_dst_op(&self) -> Option<RegOP> {
+        self.dst_op.get()
+    }
+    pub fn set_dst_op(&self, value: Option<RegOP>) {
+        self.dst_op.set(value)
+    }
+}
+
+impl IMirSubInst for Una64R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Neg64R | MirOP::MVN64R | MirOP::Mov64R | MirOP::Abs64R | MirOP::CLS64 | MirOP::CLZ64 | MirOP::CNT64 | MirOP::CTZ64 | MirOP::RBit64 | MirOP::LoadStackPosGr64 | MirOP::StoreStackPosGr64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "dst_op: {:?}", self.get_dst_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Una64R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Una64R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Una32R`: 模板 `UnaryOP[GPR32, GPR32]` 的实例.
+///
+/// * 输出: `dst: GPR32`
+/// * 输入: `src: GPR32`
+/// * 操作码: `Neg32R`, `MVN32R`, `Mov32R`, `Abs32R`, `CLS32`, `CLZ32`, `CNT32`, `CTZ32`, `RBit32`, `SXTB32`, `SXTH32`, `SXTW32`, `UXTB32`, `UXTH32`, `LoadStackPosGr32`, `StoreStackPosGr32`
+#[derive(Clone)]
+pub struct Una32R {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+    dst_op: Cell<Option<RegOP>>,
+}
+
+impl Una32R {
+    pub const OPCODES: [MirOP; 16] = [
+        MirOP::Neg32R,
+        MirOP::MVN32R,
+        MirOP::Mov32R,
+        MirOP::Abs32R,
+        MirOP::CLS32,
+        MirOP::CLZ32,
+        MirOP::CNT32,
+        MirOP::CTZ32,
+        MirOP::RBit32,
+        MirOP::SXTB32,
+        MirOP::SXTH32,
+        MirOP::SXTW32,
+        MirOP::UXTB32,
+        MirOP::UXTH32,
+        MirOP::LoadStackPosGr32,
+        MirOP::StoreStackPosGr32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Una32R"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+            dst_op: Cell::new(None),
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn get_dst_op(&self) -> Option<RegOP> {
+        self.dst_op.get()
+    }
+    pub fn set_dst_op(&self, value: Option<RegOP>) {
+        self.dst_op.set(value)
+    }
+}
+
+impl IMirSubInst for Una32R {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Neg32R | MirOP::MVN32R | MirOP::Mov32R | MirOP::Abs32R | MirOP::CLS32 | MirOP::CLZ32 | MirOP::CNT32 | MirOP::CTZ32 | MirOP::RBit32 | MirOP::SXTB32 | MirOP::SXTH32 | MirOP::SXTW32 | MirOP::UXTB32 | MirOP::UXTH32 | MirOP::LoadStackPosGr32 | MirOP::StoreStackPosGr32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "dst_op: {:?}", self.get_dst_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Una32R(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Una32R(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `ExtR`: 模板 `UnaryOP[GPR64, GPR32]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: GPR32`
+/// * 操作码: `SXTB64`, `SXTH64`, `SXTW64`, `UXTB64`, `UXTH64`
+#[derive(Clone)]
+pub struct ExtR {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl ExtR {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::SXTB64,
+        MirOP::SXTH64,
+        MirOP::SXTW64,
+        MirOP::UXTB64,
+        MirOP::UXTH64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class ExtR"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for ExtR {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::SXTB64 | MirOP::SXTH64 | MirOP::SXTW64 | MirOP::UXTB64 | MirOP::UXTH64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::ExtR(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::ExtR(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Mov64I`: 模板 `UnaryOP[GPR64, ImmMov]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: ImmMov`
+/// * 操作码: `Mov64I`
+#[derive(Clone)]
+pub struct Mov64I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl Mov64I {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::Mov64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmMov,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Mov64I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for Mov64I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Mov64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Mov64I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Mov64I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MovZNK64`: 模板 `UnaryOP[GPR64, ImmMovZNK]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: ImmMovZNK`
+/// * 操作码: `MovZ64`, `MovN64`, `MovK64`
+#[derive(Clone)]
+pub struct MovZNK64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MovZNK64 {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::MovZ64,
+        MirOP::MovN64,
+        MirOP::MovK64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmMovZNK,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MovZNK64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MovZNK64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MovZ64 | MirOP::MovN64 | MirOP::MovK64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MovZNK64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MovZNK64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Mov32I`: 模板 `UnaryOP[GPR32, ImmMov]` 的实例.
+///
+/// * 输出: `dst: GPR32`
+/// * 输入: `src: ImmMov`
+/// * 操作码: `Mov32I`
+#[derive(Clone)]
+pub struct Mov32I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl Mov32I {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::Mov32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmMov,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Mov32I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for Mov32I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::Mov32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Mov32I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Mov32I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MovZNK32`: 模板 `UnaryOP[GPR32, ImmMovZNK]` 的实例.
+///
+/// * 输出: `dst: GPR32`
+/// * 输入: `src: ImmMovZNK`
+/// * 操作码: `MovZ32`, `MovN32`, `MovK32`
+#[derive(Clone)]
+pub struct MovZNK32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl MovZNK32 {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::MovZ32,
+        MirOP::MovN32,
+        MirOP::MovK32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmMovZNK,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MovZNK32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for MovZNK32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MovZ32 | MirOP::MovN32 | MirOP::MovK32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MovZNK32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MovZNK32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `Adr`: 模板 `UnaryOP[GPR64, Symbol]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: Symbol`
+/// * 操作码: `AdrP`, `Adr`
+#[derive(Clone)]
+pub struct Adr {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl Adr {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::AdrP,
+        MirOP::Adr,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class Adr"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for Adr {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::AdrP | MirOP::Adr)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::Adr(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::Adr(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaFG64`: 模板 `UnaryOP[FPR64, GPR64]` 的实例.
+///
+/// * 输出: `dst: FPR64`
+/// * 输入: `src: GPR64`
+/// * 操作码: `FMovFG64`, `SCvtF64`, `UCvtF64`
+#[derive(Clone)]
+pub struct UnaFG64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaFG64 {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::FMovFG64,
+        MirOP::SCvtF64,
+        MirOP::UCvtF64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaFG64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaFG64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMovFG64 | MirOP::SCvtF64 | MirOP::UCvtF64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaFG64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaFG64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaGF64`: 模板 `UnaryOP[GPR64, FPR64]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: FPR64`
+/// * 操作码: `FMovGF64`, `FCvtAS64`, `FCvtAU64`, `FCvtMS64`, `FCvtMU64`, `FCvtNS64`, `FCvtNU64`, `FCvtPS64`, `FCvtPU64`, `FCvtZS64`, `FCvtZU64`
+#[derive(Clone)]
+pub struct UnaGF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaGF64 {
+    pub const OPCODES: [MirOP; 11] = [
+        MirOP::FMovGF64,
+        MirOP::FCvtAS64,
+        MirOP::FCvtAU64,
+        MirOP::FCvtMS64,
+        MirOP::FCvtMU64,
+        MirOP::FCvtNS64,
+        MirOP::FCvtNU64,
+        MirOP::FCvtPS64,
+        MirOP::FCvtPU64,
+        MirOP::FCvtZS64,
+        MirOP::FCvtZU64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaGF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaGF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMovGF64 | MirOP::FCvtAS64 | MirOP::FCvtAU64 | MirOP::FCvtMS64 | MirOP::FCvtMU64 | MirOP::FCvtNS64 | MirOP::FCvtNU64 | MirOP::FCvtPS64 | MirOP::FCvtPU64 | MirOP::FCvtZS64 | MirOP::FCvtZU64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaGF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaGF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaF64G32`: 模板 `UnaryOP[FPR64, GPR32]` 的实例.
+///
+/// * 输出: `dst: FPR64`
+/// * 输入: `src: GPR32`
+/// * 操作码: `SCvtF64G32`, `UCvtF64G32`
+#[derive(Clone)]
+pub struct UnaF64G32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaF64G32 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::SCvtF64G32,
+        MirOP::UCvtF64G32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaF64G32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaF64G32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::SCvtF64G32 | MirOP::UCvtF64G32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaF64G32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaF64G32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaFG32`: 模板 `UnaryOP[FPR32, GPR32]` 的实例.
+///
+/// * 输出: `dst: FPR32`
+/// * 输入: `src: GPR32`
+/// * 操作码: `FMovFG32`, `SCvtF32`, `UCvtF32`
+#[derive(Clone)]
+pub struct UnaFG32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaFG32 {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::FMovFG32,
+        MirOP::SCvtF32,
+        MirOP::UCvtF32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaFG32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaFG32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMovFG32 | MirOP::SCvtF32 | MirOP::UCvtF32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaFG32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaFG32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaF32G64`: 模板 `UnaryOP[FPR32, GPR64]` 的实例.
+///
+/// * 输出: `dst: FPR32`
+/// * 输入: `src: GPR64`
+/// * 操作码: `SCvtF32G64`, `UCvtF32G64`
+#[derive(Clone)]
+pub struct UnaF32G64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaF32G64 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::SCvtF32G64,
+        MirOP::UCvtF32G64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaF32G64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaF32G64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::SCvtF32G64 | MirOP::UCvtF32G64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaF32G64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaF32G64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaGF32`: 模板 `UnaryOP[GPR32, FPR32]` 的实例.
+///
+/// * 输出: `dst: GPR32`
+/// * 输入: `src: FPR32`
+/// * 操作码: `FMovGF32`, `FCvtAS32`, `FCvtAU32`, `FCvtMS32`, `FCvtMU32`, `FCvtNS32`, `FCvtNU32`, `FCvtPS32`, `FCvtPU32`, `FCvtZS32`, `FCvtZU32`
+#[derive(Clone)]
+pub struct UnaGF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaGF32 {
+    pub const OPCODES: [MirOP; 11] = [
+        MirOP::FMovGF32,
+        MirOP::FCvtAS32,
+        MirOP::FCvtAU32,
+        MirOP::FCvtMS32,
+        MirOP::FCvtMU32,
+        MirOP::FCvtNS32,
+        MirOP::FCvtNU32,
+        MirOP::FCvtPS32,
+        MirOP::FCvtPU32,
+        MirOP::FCvtZS32,
+        MirOP::FCvtZU32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaGF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaGF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMovGF32 | MirOP::FCvtAS32 | MirOP::FCvtAU32 | MirOP::FCvtMS32 | MirOP::FCvtMU32 | MirOP::FCvtNS32 | MirOP::FCvtNU32 | MirOP::FCvtPS32 | MirOP::FCvtPU32 | MirOP::FCvtZS32 | MirOP::FCvtZU32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaGF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaGF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaG64F32`: 模板 `UnaryOP[GPR64, FPR32]` 的实例.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `src: FPR32`
+/// * 操作码: `FCvtAS64F32`, `FCvtAU64F32`, `FCvtMS64F32`, `FCvtMU64F32`, `FCvtNS64F32`, `FCvtNU64F32`, `FCvtPS64F32`, `FCvtPU64F32`, `FCvtZS64F32`, `FCvtZU64F32`
+#[derive(Clone)]
+pub struct UnaG64F32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaG64F32 {
+    pub const OPCODES: [MirOP; 10] = [
+        MirOP::FCvtAS64F32,
+        MirOP::FCvtAU64F32,
+        MirOP::FCvtMS64F32,
+        MirOP::FCvtMU64F32,
+        MirOP::FCvtNS64F32,
+        MirOP::FCvtNU64F32,
+        MirOP::FCvtPS64F32,
+        MirOP::FCvtPU64F32,
+        MirOP::FCvtZS64F32,
+        MirOP::FCvtZU64F32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaG64F32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaG64F32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCvtAS64F32 | MirOP::FCvtAU64F32 | MirOP::FCvtMS64F32 | MirOP::FCvtMU64F32 | MirOP::FCvtNS64F32 | MirOP::FCvtNU64F32 | MirOP::FCvtPS64F32 | MirOP::FCvtPU64F32 | MirOP::FCvtZS64F32 | MirOP::FCvtZU64F32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaG64F32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaG64F32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaG32F64`: 模板 `UnaryOP[GPR32, FPR64]` 的实例.
+///
+/// * 输出: `dst: GPR32`
+/// * 输入: `src: FPR64`
+/// * 操作码: `FCvtAS32F64`, `FCvtAU32F64`
+#[derive(Clone)]
+pub struct UnaG32F64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaG32F64 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::FCvtAS32F64,
+        MirOP::FCvtAU32F64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaG32F64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaG32F64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCvtAS32F64 | MirOP::FCvtAU32F64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaG32F64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaG32F64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaF64`: 模板 `UnaryOP[FPR64, FPR64]` 的实例.
+///
+/// * 输出: `dst: FPR64`
+/// * 输入: `src: FPR64`
+/// * 操作码: `FMov64R`, `FRIntA64`, `FRIntI64`, `FRIntM64`, `FRIntN64`, `FRIntP64`, `FRIntX64`, `FRIntZ64`, `FRInt32X64`, `FRIntZ32X64`, `FRInt64X64`, `FRIntZ64X64`, `FAbs64`, `FNeg64`, `FSqrt64`, `LoadStackPosF64`, `StoreStackPosF64`
+#[derive(Clone)]
+pub struct UnaF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaF64 {
+    pub const OPCODES: [MirOP; 17] = [
+        MirOP::FMov64R,
+        MirOP::FRIntA64,
+        MirOP::FRIntI64,
+        MirOP::FRIntM64,
+        MirOP::FRIntN64,
+        MirOP::FRIntP64,
+        MirOP::FRIntX64,
+        MirOP::FRIntZ64,
+        MirOP::FRInt32X64,
+        MirOP::FRIntZ32X64,
+        MirOP::FRInt64X64,
+        MirOP::FRIntZ64X64,
+        MirOP::FAbs64,
+        MirOP::FNeg64,
+        MirOP::FSqrt64,
+        MirOP::LoadStackPosF64,
+        MirOP::StoreStackPosF64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMov64R | MirOP::FRIntA64 | MirOP::FRIntI64 | MirOP::FRIntM64 | MirOP::FRIntN64 | MirOP::FRIntP64 | MirOP::FRIntX64 | MirOP::FRIntZ64 | MirOP::FRInt32X64 | MirOP::FRIntZ32X64 | MirOP::FRInt64X64 | MirOP::FRIntZ64X64 | MirOP::FAbs64 | MirOP::FNeg64 | MirOP::FSqrt64 | MirOP::LoadStackPosF64 | MirOP::StoreStackPosF64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaF32`: 模板 `UnaryOP[FPR32, FPR32]` 的实例.
+///
+/// * 输出: `dst: FPR32`
+/// * 输入: `src: FPR32`
+/// * 操作码: `FMov32R`, `FRIntA32`, `FRIntI32`, `FRIntM32`, `FRIntN32`, `FRIntP32`, `FRIntX32`, `FRIntZ32`, `FRInt32X32`, `FRIntZ32X32`, `FRInt64X32`, `FRIntZ64X32`, `FAbs32`, `FNeg32`, `FSqrt32`, `LoadStackPosF32`, `StoreStackPosF32`
+#[derive(Clone)]
+pub struct UnaF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaF32 {
+    pub const OPCODES: [MirOP; 17] = [
+        MirOP::FMov32R,
+        MirOP::FRIntA32,
+        MirOP::FRIntI32,
+        MirOP::FRIntM32,
+        MirOP::FRIntN32,
+        MirOP::FRIntP32,
+        MirOP::FRIntX32,
+        MirOP::FRIntZ32,
+        MirOP::FRInt32X32,
+        MirOP::FRIntZ32X32,
+        MirOP::FRInt64X32,
+        MirOP::FRIntZ64X32,
+        MirOP::FAbs32,
+        MirOP::FNeg32,
+        MirOP::FSqrt32,
+        MirOP::LoadStackPosF32,
+        MirOP::StoreStackPosF32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMov32R | MirOP::FRIntA32 | MirOP::FRIntI32 | MirOP::FRIntM32 | MirOP::FRIntN32 | MirOP::FRIntP32 | MirOP::FRIntX32 | MirOP::FRIntZ32 | MirOP::FRInt32X32 | MirOP::FRIntZ32X32 | MirOP::FRInt64X32 | MirOP::FRIntZ64X32 | MirOP::FAbs32 | MirOP::FNeg32 | MirOP::FSqrt32 | MirOP::LoadStackPosF32 | MirOP::StoreStackPosF32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaryF32F64`: 模板 `UnaryOP[FPR32, FPR64]` 的实例.
+///
+/// * 输出: `dst: FPR32`
+/// * 输入: `src: FPR64`
+/// * 操作码: `FCvt32F64`
+#[derive(Clone)]
+pub struct UnaryF32F64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaryF32F64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::FCvt32F64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaryF32F64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaryF32F64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCvt32F64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaryF32F64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaryF32F64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `UnaryF64F32`: 模板 `UnaryOP[FPR64, FPR32]` 的实例.
+///
+/// * 输出: `dst: FPR64`
+/// * 输入: `src: FPR32`
+/// * 操作码: `FCvt64F32`
+#[derive(Clone)]
+pub struct UnaryF64F32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl UnaryF64F32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::FCvt64F32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class UnaryF64F32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for UnaryF64F32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FCvt64F32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::UnaryF64F32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::UnaryF64F32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `FMov64I`: 模板 `UnaryOP[FPR64, ImmFMov64]` 的实例.
+///
+/// * 输出: `dst: FPR64`
+/// * 输入: `src: ImmFMov64`
+/// * 操作码: `FMov64I`
+#[derive(Clone)]
+pub struct FMov64I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl FMov64I {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::FMov64I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::ImmFMov64,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class FMov64I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for FMov64I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMov64I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::FMov64I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::FMov64I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `FMov32I`: 模板 `UnaryOP[FPR32, ImmFMov32]` 的实例.
+///
+/// * 输出: `dst: FPR32`
+/// * 输入: `src: ImmFMov32`
+/// * 操作码: `FMov32I`
+#[derive(Clone)]
+pub struct FMov32I {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl FMov32I {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::FMov32I,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::ImmFMov32,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class FMov32I"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for FMov32I {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMov32I)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::FMov32I(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::FMov32I(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `TenaryG64`: 模板 `TenaryOP[GPR64, GPR64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: GPR64`, `rs: GPR64`
+/// * 操作码: `MAdd64`, `MSub64`
+#[derive(Clone)]
+pub struct TenaryG64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl TenaryG64 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::MAdd64,
+        MirOP::MSub64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, rs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class TenaryG64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(rs)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn rs(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_rs(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_rs(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for TenaryG64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MAdd64 | MirOP::MSub64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::TenaryG64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::TenaryG64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `TenaryG64G32`: 模板 `TenaryOP[GPR64, GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR32`, `rm: GPR32`, `rs: GPR64`
+/// * 操作码: `SMAddL`, `SMSubL`, `UMAddL`, `UMSubL`
+#[derive(Clone)]
+pub struct TenaryG64G32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl TenaryG64G32 {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::SMAddL,
+        MirOP::SMSubL,
+        MirOP::UMAddL,
+        MirOP::UMSubL,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, rs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class TenaryG64G32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(rs)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn rs(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_rs(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_rs(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for TenaryG64G32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::SMAddL | MirOP::SMSubL | MirOP::UMAddL | MirOP::UMSubL)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::TenaryG64G32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::TenaryG64G32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `TenaryG32`: 模板 `TenaryOP[GPR32, GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: GPR32`, `rs: GPR32`
+/// * 操作码: `MAdd32`, `MSub32`
+#[derive(Clone)]
+pub struct TenaryG32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl TenaryG32 {
+    pub const OPCODES: [MirOP; 2] = [
+        MirOP::MAdd32,
+        MirOP::MSub32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, rs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class TenaryG32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(rs)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn rs(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_rs(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_rs(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for TenaryG32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MAdd32 | MirOP::MSub32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::TenaryG32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::TenaryG32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `TenaryF64`: 模板 `TenaryOP[FPR64, FPR64]` 的实例.
+///
+/// * 输出: `rd: FPR64`
+/// * 输入: `rn: FPR64`, `rm: FPR64`, `rs: FPR64`
+/// * 操作码: `FMAdd64`, `FMSub64`, `FNMAdd64`, `FNMSub64`
+#[derive(Clone)]
+pub struct TenaryF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl TenaryF64 {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::FMAdd64,
+        MirOP::FMSub64,
+        MirOP::FNMAdd64,
+        MirOP::FNMSub64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, rs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class TenaryF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(rs)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn rs(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_rs(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_rs(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for TenaryF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMAdd64 | MirOP::FMSub64 | MirOP::FNMAdd64 | MirOP::FNMSub64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::TenaryF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::TenaryF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `TenaryF32`: 模板 `TenaryOP[FPR32, FPR32]` 的实例.
+///
+/// * 输出: `rd: FPR32`
+/// * 输入: `rn: FPR32`, `rm: FPR32`, `rs: FPR32`
+/// * 操作码: `FMAdd32`, `FMSub32`, `FNMAdd32`, `FNMSub32`
+#[derive(Clone)]
+pub struct TenaryF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl TenaryF32 {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::FMAdd32,
+        MirOP::FMSub32,
+        MirOP::FNMAdd32,
+        MirOP::FNMSub32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, rs: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class TenaryF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(rs)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn rs(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_rs(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_rs(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for TenaryF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::FMAdd32 | MirOP::FMSub32 | MirOP::FNMAdd32 | MirOP::FNMSub32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::TenaryF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::TenaryF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr64`: 模板 `LoadRRR[GPR64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `LdrGr64`, `LdrBGr64`, `LdrHGr64`, `LdrSBGr64`, `LdrSHGr64`
+#[derive(Clone)]
+pub struct LoadGr64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl LoadGr64 {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr64,
+        MirOP::LdrBGr64,
+        MirOP::LdrHGr64,
+        MirOP::LdrSBGr64,
+        MirOP::LdrSHGr64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr64 | MirOP::LdrBGr64 | MirOP::LdrHGr64 | MirOP::LdrSBGr64 | MirOP::LdrSHGr64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr32`: 模板 `LoadRRR[GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `LdrGr32`, `LdrBGr32`, `LdrHGr32`, `LdrSBGr32`, `LdrSHGr32`
+#[derive(Clone)]
+pub struct LoadGr32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl LoadGr32 {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr32,
+        MirOP::LdrBGr32,
+        MirOP::LdrHGr32,
+        MirOP::LdrSBGr32,
+        MirOP::LdrSHGr32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr32 | MirOP::LdrBGr32 | MirOP::LdrHGr32 | MirOP::LdrSBGr32 | MirOP::LdrSHGr32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF64`: 模板 `LoadRRR[FPR64]` 的实例.
+///
+/// * 输出: `rd: FPR64`
+/// * 输入: `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `LdrF64`
+#[derive(Clone)]
+pub struct LoadF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl LoadF64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for LoadF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF32`: 模板 `LoadRRR[FPR32]` 的实例.
+///
+/// * 输出: `rd: FPR32`
+/// * 输入: `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `LdrF32`
+#[derive(Clone)]
+pub struct LoadF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl LoadF32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for LoadF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr64`: 模板 `StoreRRR[GPR64]` 的实例.
+///
+/// * 输入: `rd: GPR64`, `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `StrGr64`, `StrBGr64`, `StrHGr64`
+#[derive(Clone)]
+pub struct StoreGr64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl StoreGr64 {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr64,
+        MirOP::StrBGr64,
+        MirOP::StrHGr64,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr64 | MirOP::StrBGr64 | MirOP::StrHGr64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr32`: 模板 `StoreRRR[GPR32]` 的实例.
+///
+/// * 输入: `rd: GPR32`, `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `StrGr32`, `StrBGr32`, `StrHGr32`
+#[derive(Clone)]
+pub struct StoreGr32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl StoreGr32 {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr32,
+        MirOP::StrBGr32,
+        MirOP::StrHGr32,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr32 | MirOP::StrBGr32 | MirOP::StrHGr32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF64`: 模板 `StoreRRR[FPR64]` 的实例.
+///
+/// * 输入: `rd: FPR64`, `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `StrF64`
+#[derive(Clone)]
+pub struct StoreF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl StoreF64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF64,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for StoreF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF32`: 模板 `StoreRRR[FPR32]` 的实例.
+///
+/// * 输入: `rd: FPR32`, `rn: GPR64`, `rm: GPR64`
+/// * 操作码: `StrF32`
+#[derive(Clone)]
+pub struct StoreF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    rm_op: Cell<Option<RegOP>>,
+}
+
+impl StoreF32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF32,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            rm_op: Cell::new(None),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_rm_op(&self) -> Option<RegOP> {
+        self.rm_op.get()
+    }
+    pub fn set_rm_op(&self, value: Option<RegOP>) {
+        self.rm_op.set(value)
+    }
+}
+
+impl IMirSubInst for StoreF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "rm_op: {:?}", self.get_rm_op())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr64Base`: 模板 `LoadRRIBaseOffset[GPR64, ImmLSP64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GSP64`, `rm: ImmLSP64`
+/// * 操作码: `LdrGr64Base`, `LdrBGr64Base`, `LdrHGr64Base`, `LdrSBGr64Base`, `LdrSHGr64Base`
+#[derive(Clone)]
+pub struct LoadGr64Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadGr64Base {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr64Base,
+        MirOP::LdrBGr64Base,
+        MirOP::LdrHGr64Base,
+        MirOP::LdrSBGr64Base,
+        MirOP::LdrSHGr64Base,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr64Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr64Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr64Base | MirOP::LdrBGr64Base | MirOP::LdrHGr64Base | MirOP::LdrSBGr64Base | MirOP::LdrSHGr64Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr64Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr64Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr32Base`: 模板 `LoadRRIBaseOffset[GPR32, ImmLSP32]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GSP64`, `rm: ImmLSP32`
+/// * 操作码: `LdrGr32Base`, `LdrBGr32Base`, `LdrHGr32Base`, `LdrSBGr32Base`, `LdrSHGr32Base`
+#[derive(Clone)]
+pub struct LoadGr32Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadGr32Base {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr32Base,
+        MirOP::LdrBGr32Base,
+        MirOP::LdrHGr32Base,
+        MirOP::LdrSBGr32Base,
+        MirOP::LdrSHGr32Base,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr32Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr32Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr32Base | MirOP::LdrBGr32Base | MirOP::LdrHGr32Base | MirOP::LdrSBGr32Base | MirOP::LdrSHGr32Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr32Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr32Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF128Base`: 模板 `LoadRRIBaseOffset[FPR128, ImmLSP128]` 的实例.
+///
+/// * 输出: `rd: FPR128`
+/// * 输入: `rn: GSP64`, `rm: ImmLSP128`
+/// * 操作码: `LdrF128Base`
+#[derive(Clone)]
+pub struct LoadF128Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadF128Base {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF128Base,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR128,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP128,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF128Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF128Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF128Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF128Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF128Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF64Base`: 模板 `LoadRRIBaseOffset[FPR64, ImmLSP64]` 的实例.
+///
+/// * 输出: `rd: FPR64`
+/// * 输入: `rn: GSP64`, `rm: ImmLSP64`
+/// * 操作码: `LdrF64Base`
+#[derive(Clone)]
+pub struct LoadF64Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadF64Base {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF64Base,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF64Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF64Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF64Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF64Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF64Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF32Base`: 模板 `LoadRRIBaseOffset[FPR32, ImmLSP32]` 的实例.
+///
+/// * 输出: `rd: FPR32`
+/// * 输入: `rn: GSP64`, `rm: ImmLSP32`
+/// * 操作码: `LdrF32Base`
+#[derive(Clone)]
+pub struct LoadF32Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadF32Base {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF32Base,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF32Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF32Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF32Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF32Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF32Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr64BaseS`: 模板 `LoadRRIBaseOffset[GPR64, Symbol]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `LdrGr64BaseS`, `LdrBGr64BaseS`, `LdrHGr64BaseS`, `LdrSBGr64BaseS`, `LdrSHGr64BaseS`
+#[derive(Clone)]
+pub struct LoadGr64BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadGr64BaseS {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr64BaseS,
+        MirOP::LdrBGr64BaseS,
+        MirOP::LdrHGr64BaseS,
+        MirOP::LdrSBGr64BaseS,
+        MirOP::LdrSHGr64BaseS,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr64BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr64BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr64BaseS | MirOP::LdrBGr64BaseS | MirOP::LdrHGr64BaseS | MirOP::LdrSBGr64BaseS | MirOP::LdrSHGr64BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr64BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr64BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr32BaseS`: 模板 `LoadRRIBaseOffset[GPR32, Symbol]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `LdrGr32BaseS`, `LdrBGr32BaseS`, `LdrHGr32BaseS`, `LdrSBGr32BaseS`, `LdrSHGr32BaseS`
+#[derive(Clone)]
+pub struct LoadGr32BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadGr32BaseS {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr32BaseS,
+        MirOP::LdrBGr32BaseS,
+        MirOP::LdrHGr32BaseS,
+        MirOP::LdrSBGr32BaseS,
+        MirOP::LdrSHGr32BaseS,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr32BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr32BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr32BaseS | MirOP::LdrBGr32BaseS | MirOP::LdrHGr32BaseS | MirOP::LdrSBGr32BaseS | MirOP::LdrSHGr32BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr32BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr32BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF128BaseS`: 模板 `LoadRRIBaseOffset[FPR128, Symbol]` 的实例.
+///
+/// * 输出: `rd: FPR128`
+/// * 输入: `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `LdrF128BaseS`
+#[derive(Clone)]
+pub struct LoadF128BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadF128BaseS {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF128BaseS,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR128,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF128BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF128BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF128BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF128BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF128BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF64BaseS`: 模板 `LoadRRIBaseOffset[FPR64, Symbol]` 的实例.
+///
+/// * 输出: `rd: FPR64`
+/// * 输入: `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `LdrF64BaseS`
+#[derive(Clone)]
+pub struct LoadF64BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadF64BaseS {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF64BaseS,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF64BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF64BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF64BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF64BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF64BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF32BaseS`: 模板 `LoadRRIBaseOffset[FPR32, Symbol]` 的实例.
+///
+/// * 输出: `rd: FPR32`
+/// * 输入: `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `LdrF32BaseS`
+#[derive(Clone)]
+pub struct LoadF32BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl LoadF32BaseS {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF32BaseS,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF32BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF32BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF32BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF32BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF32BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr64Base`: 模板 `StoreRRIBaseOffset[GPR64, ImmLSP64]` 的实例.
+///
+/// * 输入: `rd: GPR64`, `rn: GSP64`, `rm: ImmLSP64`
+/// * 操作码: `StrGr64Base`, `StrBGr64Base`, `StrHGr64Base`
+#[derive(Clone)]
+pub struct StoreGr64Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreGr64Base {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr64Base,
+        MirOP::StrBGr64Base,
+        MirOP::StrHGr64Base,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr64Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr64Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr64Base | MirOP::StrBGr64Base | MirOP::StrHGr64Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr64Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr64Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr32Base`: 模板 `StoreRRIBaseOffset[GPR32, ImmLSP32]` 的实例.
+///
+/// * 输入: `rd: GPR32`, `rn: GSP64`, `rm: ImmLSP32`
+/// * 操作码: `StrGr32Base`, `StrBGr32Base`, `StrHGr32Base`
+#[derive(Clone)]
+pub struct StoreGr32Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreGr32Base {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr32Base,
+        MirOP::StrBGr32Base,
+        MirOP::StrHGr32Base,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr32Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr32Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr32Base | MirOP::StrBGr32Base | MirOP::StrHGr32Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr32Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr32Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF128Base`: 模板 `StoreRRIBaseOffset[FPR128, ImmLSP128]` 的实例.
+///
+/// * 输入: `rd: FPR128`, `rn: GSP64`, `rm: ImmLSP128`
+/// * 操作码: `StrF128Base`
+#[derive(Clone)]
+pub struct StoreF128Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreF128Base {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF128Base,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR128,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP128,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF128Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreF128Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF128Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF128Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF128Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF64Base`: 模板 `StoreRRIBaseOffset[FPR64, ImmLSP64]` 的实例.
+///
+/// * 输入: `rd: FPR64`, `rn: GSP64`, `rm: ImmLSP64`
+/// * 操作码: `StrF64Base`
+#[derive(Clone)]
+pub struct StoreF64Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreF64Base {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF64Base,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF64Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreF64Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF64Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF64Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF64Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF32Base`: 模板 `StoreRRIBaseOffset[FPR32, ImmLSP32]` 的实例.
+///
+/// * 输入: `rd: FPR32`, `rn: GSP64`, `rm: ImmLSP32`
+/// * 操作码: `StrF32Base`
+#[derive(Clone)]
+pub struct StoreF32Base {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreF32Base {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF32Base,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF32Base"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreF32Base {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF32Base)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF32Base(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF32Base(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr64BaseS`: 模板 `StoreRRIBaseOffset[GPR64, Symbol]` 的实例.
+///
+/// * 输入: `rd: GPR64`, `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `StrGr64BaseS`, `StrBGr64BaseS`, `StrHGr64BaseS`
+#[derive(Clone)]
+pub struct StoreGr64BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreGr64BaseS {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr64BaseS,
+        MirOP::StrBGr64BaseS,
+        MirOP::StrHGr64BaseS,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr64BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr64BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr64BaseS | MirOP::StrBGr64BaseS | MirOP::StrHGr64BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr64BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr64BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr32BaseS`: 模板 `StoreRRIBaseOffset[GPR32, Symbol]` 的实例.
+///
+/// * 输入: `rd: GPR32`, `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `StrGr32BaseS`, `StrBGr32BaseS`, `StrHGr32BaseS`
+#[derive(Clone)]
+pub struct StoreGr32BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreGr32BaseS {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr32BaseS,
+        MirOP::StrBGr32BaseS,
+        MirOP::StrHGr32BaseS,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr32BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr32BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr32BaseS | MirOP::StrBGr32BaseS | MirOP::StrHGr32BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr32BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr32BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF128BaseS`: 模板 `StoreRRIBaseOffset[FPR128, Symbol]` 的实例.
+///
+/// * 输入: `rd: FPR128`, `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `StrF128BaseS`
+#[derive(Clone)]
+pub struct StoreF128BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreF128BaseS {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF128BaseS,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR128,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF128BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreF128BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF128BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF128BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF128BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF64BaseS`: 模板 `StoreRRIBaseOffset[FPR64, Symbol]` 的实例.
+///
+/// * 输入: `rd: FPR64`, `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `StrF64BaseS`
+#[derive(Clone)]
+pub struct StoreF64BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreF64BaseS {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF64BaseS,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF64BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreF64BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF64BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF64BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF64BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF32BaseS`: 模板 `StoreRRIBaseOffset[FPR32, Symbol]` 的实例.
+///
+/// * 输入: `rd: FPR32`, `rn: GSP64`, `rm: Symbol`
+/// * 操作码: `StrF32BaseS`
+#[derive(Clone)]
+pub struct StoreF32BaseS {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl StoreF32BaseS {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF32BaseS,
+    ];
+    pub const NUM_OUTS: usize = 0;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GSP64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF32BaseS"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for StoreF32BaseS {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF32BaseS)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF32BaseS(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF32BaseS(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr64Indexed`: 模板 `LoadRRIndexed[GPR64, ImmLSP64]` 的实例.
+///
+/// * 输出: `rd: GPR64`, `rn: GPR64`
+/// * 输入: `rm: ImmLSP64`
+/// * 操作码: `LdrGr64Indexed`, `LdrBGr64Indexed`, `LdrHGr64Indexed`, `LdrSBGr64Indexed`, `LdrSHGr64Indexed`
+#[derive(Clone)]
+pub struct LoadGr64Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl LoadGr64Indexed {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr64Indexed,
+        MirOP::LdrBGr64Indexed,
+        MirOP::LdrHGr64Indexed,
+        MirOP::LdrSBGr64Indexed,
+        MirOP::LdrSHGr64Indexed,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr64Indexed"
+        );
+        let ret = Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        };
+        {
+            super::utils::mark_operand_used(ret.rd());
+        }
+        ret
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr64Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr64Indexed | MirOP::LdrBGr64Indexed | MirOP::LdrHGr64Indexed | MirOP::LdrSBGr64Indexed | MirOP::LdrSHGr64Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr64Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr64Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr32Indexed`: 模板 `LoadRRIndexed[GPR32, ImmLSP32]` 的实例.
+///
+/// * 输出: `rd: GPR32`, `rn: GPR64`
+/// * 输入: `rm: ImmLSP32`
+/// * 操作码: `LdrGr32Indexed`, `LdrBGr32Indexed`, `LdrHGr32Indexed`, `LdrSBGr32Indexed`, `LdrSHGr32Indexed`
+#[derive(Clone)]
+pub struct LoadGr32Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl LoadGr32Indexed {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr32Indexed,
+        MirOP::LdrBGr32Indexed,
+        MirOP::LdrHGr32Indexed,
+        MirOP::LdrSBGr32Indexed,
+        MirOP::LdrSHGr32Indexed,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr32Indexed"
+        );
+        let ret = Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        };
+        {
+            super::utils::mark_operand_used(ret.rd());
+        }
+        ret
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr32Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr32Indexed | MirOP::LdrBGr32Indexed | MirOP::LdrHGr32Indexed | MirOP::LdrSBGr32Indexed | MirOP::LdrSHGr32Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr32Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr32Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF64Indexed`: 模板 `LoadRRIndexed[FPR64, ImmLSP64]` 的实例.
+///
+/// * 输出: `rd: FPR64`, `rn: GPR64`
+/// * 输入: `rm: ImmLSP64`
+/// * 操作码: `LdrF64Indexed`
+#[derive(Clone)]
+pub struct LoadF64Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl LoadF64Indexed {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF64Indexed,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF64Indexed"
+        );
+        let ret = Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        };
+        {
+            super::utils::mark_operand_used(ret.rd());
+        }
+        ret
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for LoadF64Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF64Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF64Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF64Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF32Indexed`: 模板 `LoadRRIndexed[FPR32, ImmLSP32]` 的实例.
+///
+/// * 输出: `rd: FPR32`, `rn: GPR64`
+/// * 输入: `rm: ImmLSP32`
+/// * 操作码: `LdrF32Indexed`
+#[derive(Clone)]
+pub struct LoadF32Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl LoadF32Indexed {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF32Indexed,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF32Indexed"
+        );
+        let ret = Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        };
+        {
+            super::utils::mark_operand_used(ret.rd());
+        }
+        ret
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for LoadF32Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF32Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF32Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF32Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr64Indexed`: 模板 `StoreRRIndexed[GPR64, ImmLSP64]` 的实例.
+///
+/// * 输出: `rn: GPR64`
+/// * 输入: `rd: GPR64`, `rm: ImmLSP64`
+/// * 操作码: `StrGr64Indexed`, `StrBGr64Indexed`, `StrHGr64Indexed`
+#[derive(Clone)]
+pub struct StoreGr64Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl StoreGr64Indexed {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr64Indexed,
+        MirOP::StrBGr64Indexed,
+        MirOP::StrHGr64Indexed,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rn: MirOperand, rd: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr64Indexed"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rn), Cell::new(rd), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        }
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr64Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr64Indexed | MirOP::StrBGr64Indexed | MirOP::StrHGr64Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr64Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr64Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreGr32Indexed`: 模板 `StoreRRIndexed[GPR32, ImmLSP32]` 的实例.
+///
+/// * 输出: `rn: GPR64`
+/// * 输入: `rd: GPR32`, `rm: ImmLSP32`
+/// * 操作码: `StrGr32Indexed`, `StrBGr32Indexed`, `StrHGr32Indexed`
+#[derive(Clone)]
+pub struct StoreGr32Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl StoreGr32Indexed {
+    pub const OPCODES: [MirOP; 3] = [
+        MirOP::StrGr32Indexed,
+        MirOP::StrBGr32Indexed,
+        MirOP::StrHGr32Indexed,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR32,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rn: MirOperand, rd: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreGr32Indexed"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rn), Cell::new(rd), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        }
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for StoreGr32Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrGr32Indexed | MirOP::StrBGr32Indexed | MirOP::StrHGr32Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreGr32Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreGr32Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF64Indexed`: 模板 `StoreRRIndexed[FPR64, ImmLSP64]` 的实例.
+///
+/// * 输出: `rn: GPR64`
+/// * 输入: `rd: FPR64`, `rm: ImmLSP64`
+/// * 操作码: `StrF64Indexed`
+#[derive(Clone)]
+pub struct StoreF64Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl StoreF64Indexed {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF64Indexed,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::FPR64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, rn: MirOperand, rd: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF64Indexed"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rn), Cell::new(rd), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        }
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for StoreF64Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF64Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF64Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF64Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `StoreF32Indexed`: 模板 `StoreRRIndexed[FPR32, ImmLSP32]` 的实例.
+///
+/// * 输出: `rn: GPR64`
+/// * 输入: `rd: FPR32`, `rm: ImmLSP32`
+/// * 操作码: `StrF32Indexed`
+#[derive(Clone)]
+pub struct StoreF32Indexed {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+    addr_mode: Cell<AddrMode>,
+}
+
+impl StoreF32Indexed {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::StrF32Indexed,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::FPR32,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, rn: MirOperand, rd: MirOperand, rm: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class StoreF32Indexed"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rn), Cell::new(rd), Cell::new(rm)],
+            addr_mode: Cell::new(AddrMode::PostIndex),
+        }
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn get_addr_mode(&self) -> AddrMode {
+        self.addr_mode.get()
+    }
+    pub fn set_addr_mode(&self, value: AddrMode) {
+        self.addr_mode.set(value)
+    }
+}
+
+impl IMirSubInst for StoreF32Indexed {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::StrF32Indexed)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "addr_mode: {:?}", self.get_addr_mode())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::StoreF32Indexed(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::StoreF32Indexed(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr64Literal`: 模板 `LoadLiteral[GPR64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `from: Symbol`
+/// * 操作码: `LdrGr64Literal`, `LdrBGr64Literal`, `LdrHGr64Literal`, `LdrSBGr64Literal`, `LdrSHGr64Literal`
+#[derive(Clone)]
+pub struct LoadGr64Literal {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl LoadGr64Literal {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr64Literal,
+        MirOP::LdrBGr64Literal,
+        MirOP::LdrHGr64Literal,
+        MirOP::LdrSBGr64Literal,
+        MirOP::LdrSHGr64Literal,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, from: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr64Literal"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(from)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn from(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_from(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_from(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr64Literal {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr64Literal | MirOP::LdrBGr64Literal | MirOP::LdrHGr64Literal | MirOP::LdrSBGr64Literal | MirOP::LdrSHGr64Literal)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr64Literal(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr64Literal(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadGr32Literal`: 模板 `LoadLiteral[GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `from: Symbol`
+/// * 操作码: `LdrGr32Literal`, `LdrBGr32Literal`, `LdrHGr32Literal`, `LdrSBGr32Literal`, `LdrSHGr32Literal`
+#[derive(Clone)]
+pub struct LoadGr32Literal {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl LoadGr32Literal {
+    pub const OPCODES: [MirOP; 5] = [
+        MirOP::LdrGr32Literal,
+        MirOP::LdrBGr32Literal,
+        MirOP::LdrHGr32Literal,
+        MirOP::LdrSBGr32Literal,
+        MirOP::LdrSHGr32Literal,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, from: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadGr32Literal"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(from)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn from(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_from(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_from(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for LoadGr32Literal {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrGr32Literal | MirOP::LdrBGr32Literal | MirOP::LdrHGr32Literal | MirOP::LdrSBGr32Literal | MirOP::LdrSHGr32Literal)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadGr32Literal(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadGr32Literal(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF64Literal`: 模板 `LoadLiteral[FPR64]` 的实例.
+///
+/// * 输出: `rd: FPR64`
+/// * 输入: `from: Symbol`
+/// * 操作码: `LdrF64Literal`
+#[derive(Clone)]
+pub struct LoadF64Literal {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl LoadF64Literal {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF64Literal,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, from: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF64Literal"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(from)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn from(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_from(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_from(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF64Literal {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF64Literal)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF64Literal(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF64Literal(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadF32Literal`: 模板 `LoadLiteral[FPR32]` 的实例.
+///
+/// * 输出: `rd: FPR32`
+/// * 输入: `from: Symbol`
+/// * 操作码: `LdrF32Literal`
+#[derive(Clone)]
+pub struct LoadF32Literal {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl LoadF32Literal {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LdrF32Literal,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, from: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadF32Literal"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(from)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn from(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_from(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_from(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for LoadF32Literal {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LdrF32Literal)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadF32Literal(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadF32Literal(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirLdrLitG64`: 模板 `MirLoadLiteral[GPR64]` 的实例.
+///
+/// * 输出: `dst: GPR64`, `tmp_addr: GPR64`
+/// * 输入: `src: Symbol`
+/// * 操作码: `MirLdrLitG64`
+#[derive(Clone)]
+pub struct MirLdrLitG64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirLdrLitG64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirLdrLitG64,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, tmp_addr: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirLdrLitG64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(tmp_addr), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirLdrLitG64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirLdrLitG64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirLdrLitG64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirLdrLitG64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirLdrLitG32`: 模板 `MirLoadLiteral[GPR32]` 的实例.
+///
+/// * 输出: `dst: GPR32`, `tmp_addr: GPR64`
+/// * 输入: `src: Symbol`
+/// * 操作码: `MirLdrLitG32`
+#[derive(Clone)]
+pub struct MirLdrLitG32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirLdrLitG32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirLdrLitG32,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, tmp_addr: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirLdrLitG32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(tmp_addr), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirLdrLitG32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirLdrLitG32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirLdrLitG32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirLdrLitG32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirLdrLitF64`: 模板 `MirLoadLiteral[FPR64]` 的实例.
+///
+/// * 输出: `dst: FPR64`, `tmp_addr: GPR64`
+/// * 输入: `src: Symbol`
+/// * 操作码: `MirLdrLitF64`
+#[derive(Clone)]
+pub struct MirLdrLitF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirLdrLitF64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirLdrLitF64,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, tmp_addr: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirLdrLitF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(tmp_addr), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirLdrLitF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirLdrLitF64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirLdrLitF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirLdrLitF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirLdrLitF32`: 模板 `MirLoadLiteral[FPR32]` 的实例.
+///
+/// * 输出: `dst: FPR32`, `tmp_addr: GPR64`
+/// * 输入: `src: Symbol`
+/// * 操作码: `MirLdrLitF32`
+#[derive(Clone)]
+pub struct MirLdrLitF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirLdrLitF32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirLdrLitF32,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, dst: MirOperand, tmp_addr: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirLdrLitF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(dst), Cell::new(tmp_addr), Cell::new(src)],
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_dst(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_dst(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirLdrLitF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirLdrLitF32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirLdrLitF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirLdrLitF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStrLitG64`: 模板 `MirStoreLiteral[GPR64]` 的实例.
+///
+/// * 输出: `tmp_addr: GPR64`
+/// * 输入: `rd: GPR64`, `to: Symbol`
+/// * 操作码: `MirStrLitG64`
+#[derive(Clone)]
+pub struct MirStrLitG64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirStrLitG64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStrLitG64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, tmp_addr: MirOperand, rd: MirOperand, to: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStrLitG64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(tmp_addr), Cell::new(rd), Cell::new(to)],
+        }
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn to(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_to(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_to(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirStrLitG64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStrLitG64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStrLitG64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStrLitG64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStrLitG32`: 模板 `MirStoreLiteral[GPR32]` 的实例.
+///
+/// * 输出: `tmp_addr: GPR64`
+/// * 输入: `rd: GPR32`, `to: Symbol`
+/// * 操作码: `MirStrLitG32`
+#[derive(Clone)]
+pub struct MirStrLitG32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirStrLitG32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStrLitG32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR32,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, tmp_addr: MirOperand, rd: MirOperand, to: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStrLitG32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(tmp_addr), Cell::new(rd), Cell::new(to)],
+        }
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn to(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_to(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_to(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirStrLitG32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStrLitG32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStrLitG32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStrLitG32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStrLitF64`: 模板 `MirStoreLiteral[FPR64]` 的实例.
+///
+/// * 输出: `tmp_addr: GPR64`
+/// * 输入: `rd: FPR64`, `to: Symbol`
+/// * 操作码: `MirStrLitF64`
+#[derive(Clone)]
+pub struct MirStrLitF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirStrLitF64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStrLitF64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::FPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, tmp_addr: MirOperand, rd: MirOperand, to: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStrLitF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(tmp_addr), Cell::new(rd), Cell::new(to)],
+        }
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn to(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_to(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_to(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirStrLitF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStrLitF64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStrLitF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStrLitF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStrLitF32`: 模板 `MirStoreLiteral[FPR32]` 的实例.
+///
+/// * 输出: `tmp_addr: GPR64`
+/// * 输入: `rd: FPR32`, `to: Symbol`
+/// * 操作码: `MirStrLitF32`
+#[derive(Clone)]
+pub struct MirStrLitF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirStrLitF32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStrLitF32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::FPR32,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, tmp_addr: MirOperand, rd: MirOperand, to: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStrLitF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(tmp_addr), Cell::new(rd), Cell::new(to)],
+        }
+    }
+
+    pub fn tmp_addr(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_tmp_addr(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_tmp_addr(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn to(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_to(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_to(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirStrLitF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStrLitF32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStrLitF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStrLitF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStImm64`: 模板 `MirStoreImm[Imm64, ImmLSP64]` 的实例.
+///
+/// * 输出: `tmpreg: GPR64`
+/// * 输入: `imm: Imm64`, `base: GPR64`, `offset: ImmLSP64`
+/// * 操作码: `MirStImm64`
+#[derive(Clone)]
+pub struct MirStImm64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl MirStImm64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStImm64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm64,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, tmpreg: MirOperand, imm: MirOperand, base: MirOperand, offset: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStImm64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(tmpreg), Cell::new(imm), Cell::new(base), Cell::new(offset)],
+        }
+    }
+
+    pub fn tmpreg(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_tmpreg(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_tmpreg(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn imm(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_imm(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_imm(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn base(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_base(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_base(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn offset(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_offset(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_offset(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for MirStImm64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStImm64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStImm64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStImm64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStSym64`: 模板 `MirStoreImm[Symbol, ImmLSP64]` 的实例.
+///
+/// * 输出: `tmpreg: GPR64`
+/// * 输入: `imm: Symbol`, `base: GPR64`, `offset: ImmLSP64`
+/// * 操作码: `MirStSym64`
+#[derive(Clone)]
+pub struct MirStSym64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl MirStSym64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStSym64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP64,
+    ];
+
+    pub fn new(opcode: MirOP, tmpreg: MirOperand, imm: MirOperand, base: MirOperand, offset: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStSym64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(tmpreg), Cell::new(imm), Cell::new(base), Cell::new(offset)],
+        }
+    }
+
+    pub fn tmpreg(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_tmpreg(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_tmpreg(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn imm(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_imm(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_imm(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn base(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_base(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_base(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn offset(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_offset(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_offset(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for MirStSym64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStSym64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStSym64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStSym64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStImm32`: 模板 `MirStoreImm[Imm32, ImmLSP32]` 的实例.
+///
+/// * 输出: `tmpreg: GPR64`
+/// * 输入: `imm: Imm32`, `base: GPR64`, `offset: ImmLSP32`
+/// * 操作码: `MirStImm32`
+#[derive(Clone)]
+pub struct MirStImm32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl MirStImm32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStImm32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm32,
+        MirOperandKind::GPR64,
+        MirOperandKind::ImmLSP32,
+    ];
+
+    pub fn new(opcode: MirOP, tmpreg: MirOperand, imm: MirOperand, base: MirOperand, offset: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStImm32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(tmpreg), Cell::new(imm), Cell::new(base), Cell::new(offset)],
+        }
+    }
+
+    pub fn tmpreg(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_tmpreg(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_tmpreg(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn imm(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_imm(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_imm(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn base(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_base(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_base(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn offset(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_offset(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_offset(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for MirStImm32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStImm32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStImm32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStImm32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStImm64Sym`: 模板 `MirStoreImmSym[Imm64]` 的实例.
+///
+/// * 输出: `immreg: GPR64`, `addr_reg: GPR64`
+/// * 输入: `imm: Imm64`, `target: Global`
+/// * 操作码: `MirStImm64Sym`
+#[derive(Clone)]
+pub struct MirStImm64Sym {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl MirStImm64Sym {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStImm64Sym,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm64,
+        MirOperandKind::Global,
+    ];
+
+    pub fn new(opcode: MirOP, immreg: MirOperand, addr_reg: MirOperand, imm: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStImm64Sym"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(immreg), Cell::new(addr_reg), Cell::new(imm), Cell::new(target)],
+        }
+    }
+
+    pub fn immreg(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_immreg(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_immreg(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn addr_reg(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_addr_reg(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_addr_reg(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn imm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_imm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_imm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for MirStImm64Sym {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStImm64Sym)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStImm64Sym(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStImm64Sym(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStImm32Sym`: 模板 `MirStoreImmSym[Imm32]` 的实例.
+///
+/// * 输出: `immreg: GPR64`, `addr_reg: GPR64`
+/// * 输入: `imm: Imm32`, `target: Global`
+/// * 操作码: `MirStImm32Sym`
+#[derive(Clone)]
+pub struct MirStImm32Sym {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl MirStImm32Sym {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStImm32Sym,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm32,
+        MirOperandKind::Global,
+    ];
+
+    pub fn new(opcode: MirOP, immreg: MirOperand, addr_reg: MirOperand, imm: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStImm32Sym"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(immreg), Cell::new(addr_reg), Cell::new(imm), Cell::new(target)],
+        }
+    }
+
+    pub fn immreg(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_immreg(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_immreg(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn addr_reg(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_addr_reg(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_addr_reg(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn imm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_imm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_imm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for MirStImm32Sym {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStImm32Sym)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStImm32Sym(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStImm32Sym(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirStSym64Sym`: 模板 `MirStoreImmSym[Symbol]` 的实例.
+///
+/// * 输出: `immreg: GPR64`, `addr_reg: GPR64`
+/// * 输入: `imm: Symbol`, `target: Global`
+/// * 操作码: `MirStSymSym`
+#[derive(Clone)]
+pub struct MirStSym64Sym {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+}
+
+impl MirStSym64Sym {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirStSymSym,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+        MirOperandKind::Global,
+    ];
+
+    pub fn new(opcode: MirOP, immreg: MirOperand, addr_reg: MirOperand, imm: MirOperand, target: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirStSym64Sym"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(immreg), Cell::new(addr_reg), Cell::new(imm), Cell::new(target)],
+        }
+    }
+
+    pub fn immreg(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_immreg(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_immreg(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn addr_reg(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_addr_reg(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_addr_reg(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn imm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_imm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_imm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn target(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_target(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_target(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+}
+
+impl IMirSubInst for MirStSym64Sym {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirStSymSym)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirStSym64Sym(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirStSym64Sym(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadConst64`: 模板 `MirLoadConst[GPR64, Imm64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `src: Imm64`
+/// * 操作码: `LoadConst64`
+#[derive(Clone)]
+pub struct LoadConst64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl LoadConst64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LoadConst64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadConst64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(src)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for LoadConst64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LoadConst64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadConst64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadConst64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `LoadConst64Symbol`: 模板 `MirLoadConst[GPR64, Symbol]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `src: Symbol`
+/// * 操作码: `LoadConst64Symbol`
+#[derive(Clone)]
+pub struct LoadConst64Symbol {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+}
+
+impl LoadConst64Symbol {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::LoadConst64Symbol,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::Symbol,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class LoadConst64Symbol"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(src)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+}
+
+impl IMirSubInst for LoadConst64Symbol {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::LoadConst64Symbol)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::LoadConst64Symbol(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::LoadConst64Symbol(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirLdImmF64`: 模板 `MirLoadFImm[FPR64, Imm64]` 的实例.
+///
+/// * 输出: `rd: FPR64`, `tmpreg: GPR64`
+/// * 输入: `src: Imm64`
+/// * 操作码: `MirLdImmF64`
+#[derive(Clone)]
+pub struct MirLdImmF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirLdImmF64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirLdImmF64,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, tmpreg: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirLdImmF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(tmpreg), Cell::new(src)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn tmpreg(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_tmpreg(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_tmpreg(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirLdImmF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirLdImmF64)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirLdImmF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirLdImmF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirLdImmF32`: 模板 `MirLoadFImm[FPR32, Imm32]` 的实例.
+///
+/// * 输出: `rd: FPR32`, `tmpreg: GPR64`
+/// * 输入: `src: Imm32`
+/// * 操作码: `MirLdImmF32`
+#[derive(Clone)]
+pub struct MirLdImmF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 3],
+}
+
+impl MirLdImmF32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirLdImmF32,
+    ];
+    pub const NUM_OUTS: usize = 2;
+    pub const OPERAND_KINDS: [MirOperandKind; 3] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::GPR64,
+        MirOperandKind::Imm32,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, tmpreg: MirOperand, src: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirLdImmF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(tmpreg), Cell::new(src)],
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn tmpreg(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_tmpreg(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_tmpreg(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn src(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_src(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_src(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+}
+
+impl IMirSubInst for MirLdImmF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirLdImmF32)
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirLdImmF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirLdImmF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `MirDynAlloca`.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `size: GPR64`
+/// * 操作码: `MirDynAlloca`
+#[derive(Clone)]
+pub struct MirDynAlloca {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+    align_log2: Cell<u8>,
+}
+
+impl MirDynAlloca {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::MirDynAlloca,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, size: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class MirDynAlloca"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(size)],
+            align_log2: Cell::new(4),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn size(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_size(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_size(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn get_align_log2(&self) -> u8 {
+        self.align_log2.get()
+    }
+    pub fn set_align_log2(&self, value: u8) {
+        self.align_log2.set(value)
+    }
+}
+
+impl IMirSubInst for MirDynAlloca {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::MirDynAlloca)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "align_log2: {:?}", self.get_align_log2())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirDynAlloca(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirDynAlloca(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `CSel64`: 模板 `CondSelects[GPR64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `rn: GPR64`, `rm: GPR64`, `csr: PState`
+/// * 操作码: `CSel64`, `CSInc64`, `CSInv64`, `CSNeg64`
+#[derive(Clone)]
+pub struct CSel64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+    cond: Cell<MirCondFlag>,
+}
+
+impl CSel64 {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::CSel64,
+        MirOP::CSInc64,
+        MirOP::CSInv64,
+        MirOP::CSNeg64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::GPR64,
+        MirOperandKind::PState,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, csr: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CSel64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(csr)],
+            cond: Cell::new(MirCondFlag::AL),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+}
+
+impl IMirSubInst for CSel64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::CSel64 | MirOP::CSInc64 | MirOP::CSInv64 | MirOP::CSNeg64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CSel64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CSel64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `CSel32`: 模板 `CondSelects[GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `rn: GPR32`, `rm: GPR32`, `csr: PState`
+/// * 操作码: `CSel32`, `CSInc32`, `CSInv32`, `CSNeg32`
+#[derive(Clone)]
+pub struct CSel32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+    cond: Cell<MirCondFlag>,
+}
+
+impl CSel32 {
+    pub const OPCODES: [MirOP; 4] = [
+        MirOP::CSel32,
+        MirOP::CSInc32,
+        MirOP::CSInv32,
+        MirOP::CSNeg32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::GPR32,
+        MirOperandKind::PState,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, csr: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CSel32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(csr)],
+            cond: Cell::new(MirCondFlag::AL),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+}
+
+impl IMirSubInst for CSel32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::CSel32 | MirOP::CSInc32 | MirOP::CSInv32 | MirOP::CSNeg32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CSel32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CSel32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `CSelF64`: 模板 `CondSelects[FPR64]` 的实例.
+///
+/// * 输出: `rd: FPR64`
+/// * 输入: `rn: FPR64`, `rm: FPR64`, `csr: PState`
+/// * 操作码: `CSelF64`
+#[derive(Clone)]
+pub struct CSelF64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+    cond: Cell<MirCondFlag>,
+}
+
+impl CSelF64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::CSelF64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+        MirOperandKind::FPR64,
+        MirOperandKind::PState,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, csr: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CSelF64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(csr)],
+            cond: Cell::new(MirCondFlag::AL),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+}
+
+impl IMirSubInst for CSelF64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::CSelF64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CSelF64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CSelF64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `CSelF32`: 模板 `CondSelects[FPR32]` 的实例.
+///
+/// * 输出: `rd: FPR32`
+/// * 输入: `rn: FPR32`, `rm: FPR32`, `csr: PState`
+/// * 操作码: `CSelF32`
+#[derive(Clone)]
+pub struct CSelF32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 4],
+    cond: Cell<MirCondFlag>,
+}
+
+impl CSelF32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::CSelF32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 4] = [
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+        MirOperandKind::FPR32,
+        MirOperandKind::PState,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, rn: MirOperand, rm: MirOperand, csr: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CSelF32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(rn), Cell::new(rm), Cell::new(csr)],
+            cond: Cell::new(MirCondFlag::AL),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn rn(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_rn(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_rn(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn rm(&self) -> &Cell<MirOperand> {
+        &self.operands[2]
+    }
+    pub fn get_rm(&self) -> MirOperand {
+        self.operands[2].get()
+    }
+    pub fn set_rm(&self, value: MirOperand) {
+        self.operands[2].set(value)
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[3]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[3].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[3].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+}
+
+impl IMirSubInst for CSelF32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::CSelF32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CSelF32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CSelF32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `CSet64`: 模板 `CondSets[GPR64]` 的实例.
+///
+/// * 输出: `rd: GPR64`
+/// * 输入: `csr: PState`
+/// * 操作码: `CSet64`
+#[derive(Clone)]
+pub struct CSet64 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+    cond: Cell<MirCondFlag>,
+}
+
+impl CSet64 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::CSet64,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR64,
+        MirOperandKind::PState,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, csr: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CSet64"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(csr)],
+            cond: Cell::new(MirCondFlag::AL),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+}
+
+impl IMirSubInst for CSet64 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::CSet64)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CSet64(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CSet64(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
+/// `CSet32`: 模板 `CondSets[GPR32]` 的实例.
+///
+/// * 输出: `rd: GPR32`
+/// * 输入: `csr: PState`
+/// * 操作码: `CSet32`
+#[derive(Clone)]
+pub struct CSet32 {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+    cond: Cell<MirCondFlag>,
+}
+
+impl CSet32 {
+    pub const OPCODES: [MirOP; 1] = [
+        MirOP::CSet32,
+    ];
+    pub const NUM_OUTS: usize = 1;
+    pub const OPERAND_KINDS: [MirOperandKind; 2] = [
+        MirOperandKind::GPR32,
+        MirOperandKind::PState,
+    ];
+
+    pub fn new(opcode: MirOP, rd: MirOperand, csr: MirOperand) -> Self {
+        assert!(
+            Self::accepts_opcode(opcode),
+            "opcode {opcode:?} does not belong to class CSet32"
+        );
+        Self {
+            common: MirInstCommon::new(opcode),
+            operands: [Cell::new(rd), Cell::new(csr)],
+            cond: Cell::new(MirCondFlag::AL),
+        }
+    }
+
+    pub fn rd(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_rd(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn set_rd(&self, value: MirOperand) {
+        self.operands[0].set(value)
+    }
+
+    pub fn csr(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    pub fn get_csr(&self) -> MirOperand {
+        self.operands[1].get()
+    }
+    pub fn set_csr(&self, value: MirOperand) {
+        self.operands[1].set(value)
+    }
+
+    pub fn get_cond(&self) -> MirCondFlag {
+        self.cond.get()
+    }
+    pub fn set_cond(&self, value: MirCondFlag) {
+        self.cond.set(value)
+    }
+}
+
+impl IMirSubInst for CSet32 {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        Self::NUM_OUTS
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &Self::OPERAND_KINDS
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        matches!(opcode, MirOP::CSet32)
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(out, "cond: {:?}", self.get_cond())
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::CSet32(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::CSet32(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
+
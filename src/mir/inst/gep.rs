@@ -0,0 +1,98 @@
+//! `MirGEP`: 地址计算伪指令, 对应 IR 中的 `getelementptr`.
+
+use crate::mir::{
+    IMirSubInst, MirInst, MirInstCommon, MirOP, MirOperand, MirOperandKind,
+    inst::call::operand_kind_of,
+};
+use std::cell::Cell;
+
+/// `MirGEP`: `dst = base + offset + Σ index[i] * scale[i]`.
+///
+/// * 输出: `dst: GPR64`
+/// * 输入: `base: GPR64 | Symbol`, 之后是各个下标寄存器
+///
+/// 常量下标在构造时折叠进 `offset`. 展开时按顺序生成乘加指令.
+#[derive(Clone)]
+pub struct MirGEP {
+    common: MirInstCommon,
+    operands: Box<[Cell<MirOperand>]>,
+    kinds: Box<[MirOperandKind]>,
+    offset: Cell<i64>,
+    scales: Box<[u64]>,
+}
+
+impl MirGEP {
+    pub fn new(
+        dst: MirOperand,
+        base: MirOperand,
+        offset: i64,
+        indices: &[(MirOperand, u64)],
+    ) -> Self {
+        let base_kind =
+            if base.is_symbol() { MirOperandKind::Symbol } else { MirOperandKind::GPR64 };
+        let index_ops = indices.iter().map(|&(op, _)| op);
+        let operands = [dst, base].into_iter().chain(index_ops.clone());
+        let kinds = [MirOperandKind::GPR64, base_kind]
+            .into_iter()
+            .chain(index_ops.map(operand_kind_of));
+        Self {
+            common: MirInstCommon::new(MirOP::MirGEP),
+            operands: operands.map(Cell::new).collect(),
+            kinds: kinds.collect(),
+            offset: Cell::new(offset),
+            scales: indices.iter().map(|&(_, scale)| scale).collect(),
+        }
+    }
+
+    pub fn dst(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn base(&self) -> &Cell<MirOperand> {
+        &self.operands[1]
+    }
+    /// 下标寄存器和对应的缩放系数.
+    pub fn indices(&self) -> impl Iterator<Item = (&Cell<MirOperand>, u64)> {
+        self.operands[2..].iter().zip(self.scales.iter().copied())
+    }
+    pub fn get_offset(&self) -> i64 {
+        self.offset.get()
+    }
+    pub fn set_offset(&self, offset: i64) {
+        self.offset.set(offset)
+    }
+}
+
+impl IMirSubInst for MirGEP {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        1
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &self.kinds
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        opcode == MirOP::MirGEP
+    }
+    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        write!(
+            out,
+            "offset: {}, scales: {:?}",
+            self.get_offset(),
+            self.scales
+        )
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirGEP(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirGEP(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
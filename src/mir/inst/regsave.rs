@@ -0,0 +1,74 @@
+//! 成组保存和恢复寄存器的伪指令. 栈帧布局确定之后展开为 `stp`/`ldp` 序列.
+
+use crate::mir::{
+    IMirSubInst, MirInst, MirInstCommon, MirOP, MirOperand, MirOperandKind, PReg,
+    inst::call::operand_kind_of,
+};
+use std::cell::Cell;
+
+macro_rules! reg_list_inst {
+    ($(#[$meta:meta])* $name:ident, saves: $saves:literal) => {
+        $(#[$meta])*
+        #[derive(Clone)]
+        pub struct $name {
+            common: MirInstCommon,
+            operands: Box<[Cell<MirOperand>]>,
+            kinds: Box<[MirOperandKind]>,
+        }
+
+        impl $name {
+            pub fn new(regs: &[PReg]) -> Self {
+                let regs = regs.iter().map(|&r| MirOperand::PReg(r));
+                Self {
+                    common: MirInstCommon::new(MirOP::$name),
+                    operands: regs.clone().map(Cell::new).collect(),
+                    kinds: regs.map(operand_kind_of).collect(),
+                }
+            }
+
+            pub fn regs(&self) -> impl Iterator<Item = PReg> + '_ {
+                self.operands.iter().filter_map(|r| r.get().as_preg())
+            }
+        }
+
+        impl IMirSubInst for $name {
+            fn get_common(&self) -> &MirInstCommon {
+                &self.common
+            }
+            fn operands(&self) -> &[Cell<MirOperand>] {
+                &self.operands
+            }
+            fn num_outs(&self) -> usize {
+                if $saves { 0 } else { self.operands.len() }
+            }
+            fn operand_kinds(&self) -> &[MirOperandKind] {
+                &self.kinds
+            }
+            fn accepts_opcode(opcode: MirOP) -> bool {
+                opcode == MirOP::$name
+            }
+            fn into_mir(self) -> MirInst {
+                MirInst::$name(self)
+            }
+            fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+                match inst {
+                    MirInst::$name(inst) => Some(inst),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+reg_list_inst! {
+    /// `MirSaveRegs`: 在函数序言里把被调用者保存寄存器存入栈帧. 所有操作数都是输入.
+    MirSaveRegs, saves: true
+}
+reg_list_inst! {
+    /// `MirRestoreRegs`: 在函数返回前恢复 `MirSaveRegs` 保存的寄存器. 所有操作数都是输出.
+    MirRestoreRegs, saves: false
+}
+reg_list_inst! {
+    /// `MirRestoreHostRegs`: 在调用返回之后恢复调用者保存寄存器. 所有操作数都是输出.
+    MirRestoreHostRegs, saves: false
+}
@@ -0,0 +1,72 @@
+//! `MirSwitch`: 按跳转表分派的多路跳转伪指令.
+
+use crate::mir::{
+    IMirSubInst, MirInst, MirInstCommon, MirOP, MirOperand, MirOperandKind, MirSwitchTabID,
+};
+use std::cell::Cell;
+
+/// `MirSwitch`: 以 `index` 的值查跳转表 `table`, 跳到对应的基本块.
+///
+/// * 输入: `index: GPR32 | GPR64`, `table: SwitchTab`
+///
+/// 跳转表存放在所属函数里, 见 `MirSwitchTab`.
+#[derive(Clone)]
+pub struct MirSwitch {
+    common: MirInstCommon,
+    operands: [Cell<MirOperand>; 2],
+    kinds: [MirOperandKind; 2],
+}
+
+impl MirSwitch {
+    pub fn new(index: MirOperand, table: MirSwitchTabID) -> Self {
+        let index_kind = match index.get_reg_class() {
+            Some(class) => MirOperandKind::from_reg_class(class),
+            None => MirOperandKind::GPR64,
+        };
+        Self {
+            common: MirInstCommon::new(MirOP::MirSwitch),
+            operands: [Cell::new(index), Cell::new(MirOperand::SwitchTab(table))],
+            kinds: [index_kind, MirOperandKind::SwitchTab],
+        }
+    }
+
+    pub fn index(&self) -> &Cell<MirOperand> {
+        &self.operands[0]
+    }
+    pub fn get_index(&self) -> MirOperand {
+        self.operands[0].get()
+    }
+    pub fn get_table(&self) -> MirSwitchTabID {
+        match self.operands[1].get() {
+            MirOperand::SwitchTab(table) => table,
+            op => panic!("MirSwitch table operand {op:?} is not a switch table"),
+        }
+    }
+}
+
+impl IMirSubInst for MirSwitch {
+    fn get_common(&self) -> &MirInstCommon {
+        &self.common
+    }
+    fn operands(&self) -> &[Cell<MirOperand>] {
+        &self.operands
+    }
+    fn num_outs(&self) -> usize {
+        0
+    }
+    fn operand_kinds(&self) -> &[MirOperandKind] {
+        &self.kinds
+    }
+    fn accepts_opcode(opcode: MirOP) -> bool {
+        opcode == MirOP::MirSwitch
+    }
+    fn into_mir(self) -> MirInst {
+        MirInst::MirSwitch(self)
+    }
+    fn try_from_mir(inst: &MirInst) -> Option<&Self> {
+        match inst {
+            MirInst::MirSwitch(inst) => Some(inst),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,1148 @@
+//! 指令选择: 把 Remusys-IR 模块翻译成面向 AArch64 的 Remusys-MIR.
+//!
+//! 按基本块逐条翻译指令, 翻译时自根向下匹配指令树:
+//!
+//! * 只被 `br`/`select` 当作条件使用的比较指令在使用处重新生成, 与跳转或条件选择合并成
+//!   `cmp` + `b.cond`/`csel`. 和 0 比较相等的分支选用 `cbz`/`cbnz`, 检查单个位的选用 `tbz`/`tbnz`.
+//! * 下标全是常量的 `getelementptr` 和 `alloca` 折叠进访存指令的 "基址 + 偏移" 寻址.
+//!   `dyn-alloca` 翻译成 `MirDynAlloca`, 由汇编输出在运行时移动 `sp`.
+//! * 常量在使用处按需物化, 能编码进指令立即数字段的直接作为立即数.
+//! * `switch` 先由 [`SwitchLowering`] 按分支密度拆成判定树和位测试, 剩下的密集 `switch` 翻译成查跳转表的
+//!   `MirSwitch`.
+//! * `unreachable` 翻译成陷入指令 `brk #0`, 而不是悄悄返回到调用者.
+//!
+//! 值和虚拟寄存器的约定:
+//!
+//! * 指针总放在 64 位通用寄存器里. `ArchInfo::ptr_nbits` 为 32 时, 高 32 位总是 0.
+//! * 不超过 32 位的整数放在 32 位通用寄存器里, 33 到 64 位的放在 64 位通用寄存器里.
+//!   比寄存器窄的整数只保证低位有效, 需要时再扩展.
+//! * 32 位通用虚拟寄存器只由写 32 位寄存器的指令定义, 所以它的 64 位视图就是它的零扩展.
+//!
+//! Phi 指令按 [`PhiCongruence`] 消除: 同一个等价类的值共用一个虚拟寄存器, 剩下的并行复制在前驱块的
+//! `jump` 之前排成顺序复制. 为此翻译前会在 IR 上拆开进入含 Phi 基本块的边.
+//! 参数和返回值的位置由 [`CallAbi`](crate::mir::CallAbi) 决定. 结构体和数组的值在选择过程中放在内存里,
+//! 传递时按调用约定拆进连续的寄存器、复制到栈上, 或者复制一份传地址; 按地址返回的值写到 `x8` 给出的地址.
+//! 栈上传入的参数以 `x29` 为基址读取, 传出的参数写进 `sp` 处的出参区. 128 位整数放在一对 64 位虚拟寄存器里,
+//! 按低半、高半分别传递; 16 字节的短向量放在一个 128 位向量寄存器里. 这两种值在选择过程中只能传递和返回,
+//! 不参与运算.
+
+use crate::{
+    SymbolStr,
+    ir::{inst::*, *},
+    mir::{
+        AbiPassKind, IMirSubInst, MirBlockID, MirCopy32, MirCopy64, MirDataUnit, MirFCopy32,
+        MirFCopy64, MirFCopy128, MirFunc, MirGlobal, MirGlobalID, MirGlobalVar, MirModule, MirOP,
+        MirOperand, MirRegClass, MirSection, MirStackSlot, MirSwitchTab, StackSlotID, Trap, VReg,
+    },
+    opt::{CfgErr, IFuncTransformPass, PhiCongruence, SwitchLowering, sequentialize_copies},
+    typing::{FPKind, IValType, ValTypeID},
+};
+use std::collections::HashMap;
+
+mod arith;
+mod call;
+mod cond;
+mod imm;
+mod mem;
+
+use mem::MemAddr;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MirISelErr {
+    #[error("pointer width {0} is not supported, expected 32 or 64")]
+    UnsupportedPtrWidth(u32),
+    #[error("type {0:?} cannot be held in a register")]
+    UnsupportedType(ValTypeID),
+    #[error("instruction {0:?} ({1:?}) is not supported")]
+    UnsupportedInst(InstID, Opcode),
+    #[error("value {0:?} is not supported as an operand")]
+    UnsupportedValue(ValueSSA),
+    #[error("signature of function `{0}` has an argument or return value that cannot be lowered")]
+    UnsupportedSignature(SymbolStr),
+    #[error("call instruction {0:?} has an argument or return value that cannot be lowered")]
+    UnsupportedCall(InstID),
+    #[error("initializer of global variable `{0}` is not supported")]
+    UnsupportedGlobalInit(SymbolStr),
+    #[error("thread-local variable `{0}` cannot be addressed directly")]
+    TLSAccess(SymbolStr),
+    #[error("symbol `{0}` is defined more than once")]
+    DuplicatedSymbol(SymbolStr),
+    #[error("control flow of function `{0}` cannot be analyzed: {1}")]
+    CfgAnalysis(SymbolStr, CfgErr),
+}
+pub type MirISelRes<T = ()> = Result<T, MirISelErr>;
+
+/// 对整个模块做指令选择. 全局变量按初始值排布成数据段, 有定义的函数逐个翻译.
+///
+/// 选择之前会在 `ir` 上做 `switch` 降级、拆开进入含 Phi 基本块的边, 所以要求独占 `ir`:
+/// 之后函数的控制流图和选择之前不一样, 但语义不变.
+pub fn select_module(ir: &mut Module) -> MirISelRes<MirModule> {
+    let ir: &Module = ir;
+    let ptr_nbits = ir.tctx.arch.ptr_nbits;
+    if ptr_nbits != 32 && ptr_nbits != 64 {
+        return Err(MirISelErr::UnsupportedPtrWidth(ptr_nbits));
+    }
+    ModuleISel {
+        ir,
+        mir: MirModule::new(ir.name.as_str()),
+        globals: HashMap::new(),
+    }
+    .run()
+}
+
+struct ModuleISel<'ir> {
+    ir: &'ir Module,
+    mir: MirModule,
+    globals: HashMap<GlobalID, MirGlobalID>,
+}
+
+impl<'ir> ModuleISel<'ir> {
+    fn run(mut self) -> MirISelRes<MirModule> {
+        let (vars, funcs) = {
+            let symbols = self.ir.symbols.borrow();
+            let mut vars: Vec<GlobalVarID> = symbols.var_pool().iter().copied().collect();
+            let mut funcs: Vec<FuncID> = symbols.func_pool().iter().copied().collect();
+            vars.sort_unstable();
+            funcs.sort_unstable();
+            (vars, funcs)
+        };
+        for &var in &vars {
+            self.declare_var(var)?;
+        }
+        for &func in &funcs {
+            self.declare_func(func)?;
+        }
+        if self.uses_frem(&funcs) {
+            self.declare_libcall("fmod");
+            self.declare_libcall("fmodf");
+        }
+        for &var in &vars {
+            self.lower_var_init(var)?;
+        }
+        for &func in &funcs {
+            if !func.is_extern(&self.ir.allocs) {
+                self.select_func(func)?;
+            }
+        }
+        Ok(self.mir)
+    }
+
+    fn add_global(&mut self, id: GlobalID, global: MirGlobal) -> MirISelRes {
+        match self.mir.add_global(global) {
+            Ok(mir_id) => {
+                self.globals.insert(id, mir_id);
+                Ok(())
+            }
+            Err((_, global)) => Err(MirISelErr::DuplicatedSymbol(global.get_name().clone())),
+        }
+    }
+
+    fn declare_var(&mut self, var: GlobalVarID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let mvar = MirGlobalVar {
+            name: var.clone_name(allocs),
+            exported: var.get_linkage(allocs) != Linkage::Private,
+            section: MirSection::Data,
+            align_log2: var.deref_ir(allocs).get_common().content_align_log,
+            init: None,
+        };
+        self.add_global(var.raw_into(), MirGlobal::Var(mvar))
+    }
+    fn declare_func(&mut self, func: FuncID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let name = func.clone_name(allocs);
+        let mfunc = if func.is_extern(allocs) {
+            MirFunc::new_extern(name)
+        } else {
+            let exported = func.get_linkage(allocs) != Linkage::Private;
+            MirFunc::new_defined(&self.mir.allocs, name, exported)
+        };
+        self.add_global(func.raw_into(), MirGlobal::Func(Box::new(mfunc)))
+    }
+    /// 声明翻译过程中会调用的库函数. 同名符号已经存在时沿用已有的.
+    fn declare_libcall(&mut self, name: &str) {
+        if self.mir.get_global_by_name(name).is_none() {
+            self.mir.add_func(MirFunc::new_extern(name));
+        }
+    }
+    fn uses_frem(&self, funcs: &[FuncID]) -> bool {
+        let allocs = &self.ir.allocs;
+        funcs.iter().filter(|f| !f.is_extern(allocs)).any(|&func| {
+            func.blocks_iter(allocs).any(|(_, bb)| {
+                bb.get_insts()
+                    .iter(&allocs.insts)
+                    .any(|(inst, _)| inst.get_opcode(allocs) == Opcode::Frem)
+            })
+        })
+    }
+
+    fn lower_var_init(&mut self, var: GlobalVarID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let init = var.get_init(allocs);
+        if init == ValueSSA::None {
+            return Ok(());
+        }
+        let name = var.clone_name(allocs);
+        let mut data = DataBuilder::default();
+        if !self.push_data(init, &mut data) {
+            return Err(MirISelErr::UnsupportedGlobalInit(name));
+        }
+        let units = data.units;
+        let all_zero = units.iter().all(|u| matches!(u, MirDataUnit::Zeros(_)));
+        let section = match (var.get_tls_model(allocs), var.is_readonly(allocs), all_zero) {
+            (Some(_), _, true) => MirSection::TBss,
+            (Some(_), _, false) => MirSection::TData,
+            (None, true, _) => MirSection::RoData,
+            (None, false, true) => MirSection::Bss,
+            (None, false, false) => MirSection::Data,
+        };
+        let mir_id = self.globals[&var.raw_into()];
+        let MirGlobal::Var(mvar) = &mut self.mir.globals[mir_id.get_index()] else {
+            unreachable!("global variable `{name}` was declared as a function");
+        };
+        mvar.section = section;
+        mvar.init = Some(units);
+        Ok(())
+    }
+    /// 把初始值按小端序排布到 `data` 末尾. 遇到不支持的初始值时返回 `false`.
+    fn push_data(&self, val: ValueSSA, data: &mut DataBuilder) -> bool {
+        let tctx = &self.ir.tctx;
+        let size = val.get_valtype(&self.ir.allocs).get_size(tctx);
+        let bytes = match val {
+            ValueSSA::ConstData(ConstData::Int(apint)) => apint.as_unsigned().to_le_bytes(),
+            ValueSSA::ConstData(ConstData::Float(FPKind::Ieee32, f)) => {
+                ((f as f32).to_bits() as u128).to_le_bytes()
+            }
+            ValueSSA::ConstData(ConstData::Float(FPKind::Ieee64, f)) => {
+                (f.to_bits() as u128).to_le_bytes()
+            }
+            ValueSSA::ConstData(_) | ValueSSA::AggrZero(_) => {
+                data.push_zeros(size as u64);
+                return true;
+            }
+            ValueSSA::ConstExpr(expr) => return self.push_aggr_data(expr, data),
+            ValueSSA::Global(g) if tctx.arch.ptr_nbits == 64 => {
+                data.push(MirDataUnit::Addr(self.globals[&g], 0));
+                return true;
+            }
+            _ => return false,
+        };
+        data.push_bytes(&bytes[..size]);
+        true
+    }
+    /// 聚合常量: 结构体字段放在 `StructTypeID::get_offsets` 算出的位置上,
+    /// 数组和向量元素按对齐之后的大小依次排列, 中间和末尾的空隙补 0.
+    fn push_aggr_data(&self, expr: ExprID, data: &mut DataBuilder) -> bool {
+        let (allocs, tctx) = (&self.ir.allocs, &self.ir.tctx);
+        let start = data.size;
+        let elems: Vec<(usize, ValueSSA)> = match expr.deref_ir(allocs) {
+            ExprObj::Struct(struc) => {
+                // `offsets[i]` 是第 i 个字段的结束位置
+                let offsets = struc.structty.get_offsets(tctx);
+                let fields = struc.fields.iter().zip(offsets.iter());
+                fields
+                    .map(|(&field, &end)| {
+                        let val = field.get_operand(allocs);
+                        (end - val.get_valtype(allocs).get_size(tctx), val)
+                    })
+                    .collect()
+            }
+            obj => {
+                let (elemty, vals): (ValTypeID, Vec<ValueSSA>) = match obj {
+                    ExprObj::Array(arr) => (arr.elemty, arr.value_iter(allocs).collect()),
+                    ExprObj::DataArray(arr) => {
+                        (arr.get_elem_type(), arr.value_iter(allocs).collect())
+                    }
+                    ExprObj::SplatArray(arr) => {
+                        (arr.get_elem_type(), arr.value_iter(allocs).collect())
+                    }
+                    ExprObj::KVArray(arr) => {
+                        (arr.get_elem_type(), arr.value_iter(allocs).collect())
+                    }
+                    ExprObj::FixVec(vec) => {
+                        let elems = vec.elems.iter().map(|&elem| elem.get_operand(allocs));
+                        (vec.vecty.get_elem().into_ir(), elems.collect())
+                    }
+                    ExprObj::Struct(_) => unreachable!(),
+                };
+                let stride = elemty.get_aligned_size(tctx);
+                (0..).step_by(stride).zip(vals).collect()
+            }
+        };
+        for (offset, val) in elems {
+            data.pad_to(start + offset as u64);
+            if !self.push_data(val, data) {
+                return false;
+            }
+        }
+        data.pad_to(start + expr.get_valtype(allocs).get_size(tctx) as u64);
+        true
+    }
+
+    fn select_func(&mut self, func: FuncID) -> MirISelRes {
+        SwitchLowering::new(self.ir).run_on_func(func);
+        let mir_id = self.globals[&func.raw_into()];
+        let mut fisel = FuncISel::new(self.ir, &self.mir, mir_id, &self.globals, func)?;
+        fisel.run()?;
+        let FuncISel { stack_slots, switch_tabs, outgoing_size, .. } = fisel;
+        let stack_align_log2 = func
+            .deref_ir(&self.ir.allocs)
+            .attrs()
+            .get_func_align_stack();
+        let mfunc = self.mir.get_func_mut(mir_id);
+        mfunc.stack_slots = stack_slots;
+        mfunc.switch_tabs = switch_tabs;
+        mfunc.stack_align_log2 = stack_align_log2;
+        mfunc.outgoing_size = outgoing_size;
+        Ok(())
+    }
+}
+
+/// 正在排布的全局变量初始值.
+#[derive(Default)]
+struct DataBuilder {
+    units: Vec<MirDataUnit>,
+    /// 已经排布的字节数.
+    size: u64,
+}
+
+impl DataBuilder {
+    fn push(&mut self, unit: MirDataUnit) {
+        self.size += unit.get_size();
+        self.units.push(unit);
+    }
+    /// 与前面的字节合并. 全 0 的值记作 `Zeros`, 这样全 0 的初始值可以放进 `.bss`.
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        if bytes.iter().all(|&b| b == 0) {
+            return self.push_zeros(bytes.len() as u64);
+        }
+        self.size += bytes.len() as u64;
+        match self.units.last_mut() {
+            Some(MirDataUnit::Bytes(last)) => last.extend_from_slice(bytes),
+            _ => self.units.push(MirDataUnit::Bytes(bytes.to_vec())),
+        }
+    }
+    /// 与前面的 0 合并.
+    fn push_zeros(&mut self, size: u64) {
+        if size == 0 {
+            return;
+        }
+        self.size += size;
+        match self.units.last_mut() {
+            Some(MirDataUnit::Zeros(last)) => *last += size,
+            _ => self.units.push(MirDataUnit::Zeros(size)),
+        }
+    }
+    /// 补 0 直到排布了 `size` 个字节.
+    fn pad_to(&mut self, size: u64) {
+        debug_assert!(size >= self.size, "initializer fields overlap");
+        self.push_zeros(size - self.size);
+    }
+}
+
+/// 结构体和数组. 这样的值在选择过程中放在内存里, 不占寄存器.
+fn is_aggr(ty: ValTypeID) -> bool {
+    matches!(
+        ty,
+        ValTypeID::Array(_) | ValTypeID::Struct(_) | ValTypeID::StructAlias(_)
+    )
+}
+
+/// 放在一对 64 位通用寄存器里的整数. 65 到 127 位的整数和 128 位整数一样传递.
+fn is_int_pair(ty: ValTypeID) -> bool {
+    matches!(ty, ValTypeID::Int(65..=128))
+}
+
+/// 单个函数的指令选择状态. 栈槽和跳转表先收集在这里, 翻译完再写回 `MirFunc`.
+struct FuncISel<'a> {
+    ir: &'a Module,
+    mir: &'a MirModule,
+    mfunc: &'a MirFunc,
+    globals: &'a HashMap<GlobalID, MirGlobalID>,
+    func: FuncID,
+    blocks: HashMap<BlockID, MirBlockID>,
+    vregs: HashMap<InstID, VReg>,
+    /// 各个参数所在的虚拟寄存器. 复合类型和 128 位整数的参数不在单个寄存器里, 为 `None`.
+    args: Vec<Option<VReg>>,
+    /// 复合类型的值 (指令结果和参数) 所在的内存.
+    aggrs: HashMap<ValueSSA, MemAddr>,
+    /// 128 位整数 (调用结果和参数) 所在的一对 64 位虚拟寄存器, 低半在前.
+    pairs: HashMap<ValueSSA, [VReg; 2]>,
+    /// 本函数返回值的位置.
+    ret_abi: AbiPassKind,
+    /// 返回值经由 `x8` 传来的地址时, 保存这个地址的寄存器.
+    sret: Option<VReg>,
+    phi_cc: PhiCongruence,
+    /// Phi 等价类中的参数预先分配的虚拟寄存器.
+    arg_vregs: HashMap<u32, VReg>,
+    allocas: HashMap<InstID, StackSlotID>,
+    stack_slots: Vec<MirStackSlot>,
+    switch_tabs: Vec<MirSwitchTab>,
+    /// 各个调用在栈上传参需要的最大字节数.
+    outgoing_size: u32,
+    curr: Option<MirBlockID>,
+}
+
+impl<'a> FuncISel<'a> {
+    fn new(
+        ir: &'a Module,
+        mir: &'a MirModule,
+        mir_id: MirGlobalID,
+        globals: &'a HashMap<GlobalID, MirGlobalID>,
+        func: FuncID,
+    ) -> MirISelRes<Self> {
+        let phi_cc = PhiCongruence::new(ir, func)
+            .map_err(|e| MirISelErr::CfgAnalysis(func.clone_name(&ir.allocs), e))?;
+        Ok(Self {
+            ir,
+            mir,
+            mfunc: mir.get_func(mir_id),
+            globals,
+            func,
+            blocks: HashMap::new(),
+            vregs: HashMap::new(),
+            args: Vec::new(),
+            aggrs: HashMap::new(),
+            pairs: HashMap::new(),
+            ret_abi: AbiPassKind::Ignore,
+            sret: None,
+            phi_cc,
+            arg_vregs: HashMap::new(),
+            allocas: HashMap::new(),
+            stack_slots: Vec::new(),
+            switch_tabs: Vec::new(),
+            outgoing_size: 0,
+            curr: None,
+        })
+    }
+
+    fn run(&mut self) -> MirISelRes {
+        let ir = self.ir;
+        let allocs = &ir.allocs;
+        for (bb, _) in self.func.blocks_iter(allocs) {
+            let mbb = MirBlockID::new(&self.mir.allocs, format!("bb{}", self.blocks.len()));
+            self.mfunc.push_block(&self.mir.allocs, mbb);
+            self.blocks.insert(bb, mbb);
+        }
+        self.curr = self.mfunc.get_entry(&self.mir.allocs);
+        // 先检查签名, 免得 Phi 等价类里的参数先报出不相干的类型错误
+        let abi = self.func_abi()?;
+        self.assign_phi_vregs()?;
+        self.lower_args(abi)?;
+        for (bb, bb_obj) in self.func.blocks_iter(allocs) {
+            self.curr = Some(self.blocks[&bb]);
+            for (inst, obj) in bb_obj.get_insts().iter(&allocs.insts) {
+                self.select_inst(inst, obj)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn select_inst(&mut self, inst: InstID, obj: &InstObj) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        match obj {
+            InstObj::GuideNode(_) | InstObj::PhiInstEnd(_) => Ok(()),
+            // Phi 的值由前驱块末尾的复制写进等价类的寄存器.
+            InstObj::Phi(_) => Ok(()),
+            InstObj::Unreachable(_) => {
+                self.emit(Trap::new(MirOP::Brk, MirOperand::Imm(0)));
+                Ok(())
+            }
+            InstObj::Ret(_) => self.select_ret(RetInstID::raw_from(inst)),
+            InstObj::Jump(_) => {
+                let target = JumpInstID::raw_from(inst).get_target(allocs);
+                let block = inst.get_parent(allocs).expect("jump should be in a block");
+                self.emit_phi_copies(block)?;
+                self.emit_jump(self.block_of(target));
+                Ok(())
+            }
+            InstObj::Br(_) => self.select_br(BrInstID::raw_from(inst)),
+            InstObj::Switch(_) => self.select_switch(SwitchInstID::raw_from(inst)),
+            InstObj::Alloca(_) if inst.get_opcode(allocs) == Opcode::Alloca => {
+                self.alloca_slot(AllocaInstID::raw_from(inst));
+                self.def_folded_addr(inst)
+            }
+            InstObj::Alloca(_) => self.select_dyn_alloca(AllocaInstID::raw_from(inst)),
+            InstObj::GEP(_) => self.select_gep(GEPInstID::raw_from(inst)),
+            InstObj::Load(_) => self.select_load(LoadInstID::raw_from(inst)),
+            InstObj::Store(_) => self.select_store(StoreInstID::raw_from(inst)),
+            InstObj::BinOP(_) => self.select_binop(BinOPInstID::raw_from(inst)),
+            InstObj::Cast(_) => self.select_cast(CastInstID::raw_from(inst)),
+            InstObj::Cmp(_) => self.select_cmp(CmpInstID::raw_from(inst)),
+            InstObj::Select(_) => self.select_select(SelectInstID::raw_from(inst)),
+            InstObj::Call(_) => self.select_call(CallInstID::raw_from(inst)),
+            _ => Err(MirISelErr::UnsupportedInst(inst, inst.get_opcode(allocs))),
+        }
+    }
+
+    fn emit(&self, inst: impl IMirSubInst) {
+        let curr = self.curr.expect("no block to emit instructions into");
+        curr.push_inst(&self.mir.allocs, inst.into_mir());
+    }
+    fn new_vreg(&self, class: MirRegClass) -> VReg {
+        self.mfunc.new_vreg(class)
+    }
+    fn block_of(&self, bb: Option<BlockID>) -> MirBlockID {
+        self.blocks[&bb.expect("jump target should be set")]
+    }
+
+    fn ptr_nbits(&self) -> u32 {
+        self.ir.tctx.arch.ptr_nbits
+    }
+    /// 整数和指针类型在寄存器里的有效位数.
+    fn value_bits(&self, ty: ValTypeID) -> u32 {
+        match ty {
+            ValTypeID::Int(bits) => bits as u32,
+            ValTypeID::Ptr => self.ptr_nbits(),
+            _ => panic!("type {ty:?} has no integer width"),
+        }
+    }
+    fn reg_class(&self, ty: ValTypeID) -> MirISelRes<MirRegClass> {
+        let class = match ty {
+            ValTypeID::Ptr => MirRegClass::GPR64,
+            ValTypeID::Int(1..=32) => MirRegClass::GPR32,
+            ValTypeID::Int(33..=64) => MirRegClass::GPR64,
+            ValTypeID::Float(FPKind::Ieee32) => MirRegClass::FPR32,
+            ValTypeID::Float(FPKind::Ieee64) => MirRegClass::FPR64,
+            _ => return Err(MirISelErr::UnsupportedType(ty)),
+        };
+        Ok(class)
+    }
+    /// 整个放在一个寄存器里传递的值的寄存器类. 16 字节的短向量只能传递, 不参与运算,
+    /// 所以它的寄存器类不在 `reg_class` 里.
+    fn abi_class(&self, ty: ValTypeID) -> MirISelRes<MirRegClass> {
+        match ty {
+            ValTypeID::FixVec(_) if ty.get_size(&self.ir.tctx) == 16 => Ok(MirRegClass::FPR128),
+            _ => self.reg_class(ty),
+        }
+    }
+    /// 整数或指针值参与整数运算时使用的寄存器视图和位数.
+    /// 32 位指针用 32 位视图, 这样截断和扩展都按 32 位整数处理.
+    fn int_view(&self, reg: VReg, ty: ValTypeID) -> (VReg, u32) {
+        if ty == ValTypeID::Ptr && self.ptr_nbits() == 32 {
+            (reg.with_class(MirRegClass::GPR32), 32)
+        } else {
+            (reg, self.value_bits(ty))
+        }
+    }
+
+    /// 指令结果所在的虚拟寄存器, 第一次访问时分配.
+    fn inst_vreg(&mut self, inst: InstID) -> MirISelRes<VReg> {
+        if let Some(&vreg) = self.vregs.get(&inst) {
+            return Ok(vreg);
+        }
+        let class = self.reg_class(inst.get_valtype(&self.ir.allocs))?;
+        let vreg = self.new_vreg(class);
+        self.vregs.insert(inst, vreg);
+        Ok(vreg)
+    }
+    /// 给每个 Phi 等价类分配一个虚拟寄存器, 等价类中的指令和参数直接定义这个寄存器.
+    fn assign_phi_vregs(&mut self) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let values: Vec<_> = self.phi_cc.values().collect();
+        let mut class_vregs: HashMap<ValueSSA, VReg> = HashMap::new();
+        for (val, repr) in values {
+            let vreg = match class_vregs.get(&repr) {
+                Some(&vreg) => vreg,
+                None => {
+                    let vreg = self.new_vreg(self.reg_class(val.get_valtype(allocs))?);
+                    class_vregs.insert(repr, vreg);
+                    vreg
+                }
+            };
+            match val {
+                ValueSSA::Inst(inst) => self.vregs.insert(inst, vreg),
+                ValueSSA::FuncArg(_, index) => self.arg_vregs.insert(index, vreg),
+                _ => unreachable!("only instructions and arguments are coalesced"),
+            };
+        }
+        Ok(())
+    }
+    /// 在 `block` 的 `jump` 之前执行 Phi 的并行复制. 源操作数先全部求出来, 再按顺序复制.
+    fn emit_phi_copies(&mut self, block: BlockID) -> MirISelRes {
+        let copies = self.phi_cc.copies_at(block).to_vec();
+        let mut pairs = Vec::with_capacity(copies.len());
+        for (phi, src) in copies {
+            let dst = self.inst_vreg(phi.raw_into())?;
+            pairs.push((dst, self.value_reg(src)?));
+        }
+        let seq = sequentialize_copies(&pairs, |reg| self.new_vreg(reg.get_class()));
+        for (dst, src) in seq {
+            self.emit_copy(dst.into(), src.into());
+        }
+        Ok(())
+    }
+
+    /// 把一个操作数放进寄存器. 常量和折叠掉的地址计算在这里物化.
+    fn value_reg(&mut self, val: ValueSSA) -> MirISelRes<VReg> {
+        match val {
+            ValueSSA::ConstData(data) => self.materialize_const(data),
+            ValueSSA::FuncArg(_, index) => {
+                self.args[index as usize].ok_or(MirISelErr::UnsupportedValue(val))
+            }
+            ValueSSA::Global(_) => {
+                let addr = self.value_addr(val)?;
+                Ok(self.addr_reg(addr))
+            }
+            ValueSSA::Inst(inst) if self.is_folded_addr(inst) => {
+                let addr = self.value_addr(val)?;
+                Ok(self.addr_reg(addr))
+            }
+            ValueSSA::Inst(inst) => self.inst_vreg(inst),
+            _ => Err(MirISelErr::UnsupportedValue(val)),
+        }
+    }
+
+    /// 128 位整数所在的一对 64 位寄存器, 低半在前. 常量在这里物化.
+    fn value_pair(&mut self, val: ValueSSA) -> MirISelRes<[VReg; 2]> {
+        if let Some(&pair) = self.pairs.get(&val) {
+            return Ok(pair);
+        }
+        let bits = match val {
+            ValueSSA::ConstData(ConstData::Int(apint)) => apint.as_unsigned(),
+            ValueSSA::ConstData(ConstData::Zero(_) | ConstData::Undef(_)) => 0,
+            _ => return Err(MirISelErr::UnsupportedValue(val)),
+        };
+        let halves = [bits as u64, (bits >> 64) as u64];
+        Ok(halves.map(|half| self.materialize_int(half, MirRegClass::GPR64)))
+    }
+
+    fn emit_copy(&self, dst: MirOperand, src: MirOperand) {
+        let class = dst
+            .get_reg_class()
+            .expect("copy destination should be a register");
+        match class {
+            MirRegClass::GPR64 => self.emit(MirCopy64::new(MirOP::MirCopy64, dst, src)),
+            MirRegClass::GPR32 => self.emit(MirCopy32::new(MirOP::MirCopy32, dst, src)),
+            MirRegClass::FPR128 => self.emit(MirFCopy128::new(MirOP::MirFCopy128, dst, src)),
+            MirRegClass::FPR64 => self.emit(MirFCopy64::new(MirOP::MirFCopy64, dst, src)),
+            MirRegClass::FPR32 => self.emit(MirFCopy32::new(MirOP::MirFCopy32, dst, src)),
+            MirRegClass::PState => panic!("cannot copy into NZCV"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::APInt,
+        mir::{MirWriter, verify_mir_module},
+        opt::{IFuncTransformPass, Mem2Reg},
+        testing::cases::*,
+        typing::{
+            AggrType, ArchInfo, ArrayTypeID, FixVecType, FuncTypeID, IntType, ScalarType,
+            StructTypeID,
+        },
+    };
+
+    fn select_and_verify(module: &mut Module) -> MirModule {
+        let mir = select_module(module).unwrap();
+        verify_mir_module(&mir).unwrap();
+        mir
+    }
+    fn func_text(mir: &MirModule, name: &str) -> String {
+        let id = mir.get_global_by_name(name).unwrap();
+        let mut text = String::new();
+        MirWriter::new(mir).write_global(id, &mut text).unwrap();
+        text
+    }
+
+    #[test]
+    fn test_select_cases() {
+        let cases = [
+            test_case_cfg_deep_while_br(),
+            test_case_minmax(),
+            test_case_array_sum(),
+            test_case_matrix_fill(),
+        ];
+        for mut builder in cases {
+            let mir = select_and_verify(&mut builder.module);
+            assert!(mir.funcs_iter().any(|(_, f)| !f.is_extern()));
+        }
+    }
+
+    #[test]
+    fn test_phi_copies() {
+        let mut module = test_case_loop_select().module;
+        let func = FuncID::raw_from(module.get_global_by_name("main").unwrap());
+        Mem2Reg::new(&module).run_on_func(func);
+        select_and_verify(&mut module);
+
+        // 交换两个 Phi 的复制构成环, 需要一个临时寄存器
+        let mut module = test_case_phi_swap().module;
+        let mir = select_and_verify(&mut module);
+        let text = func_text(&mir, "main");
+        let swap = [
+            "%v5:gpr32 = MirCopy32 %v0:gpr32",
+            "%v0:gpr32 = MirCopy32 %v1:gpr32",
+            "%v1:gpr32 = MirCopy32 %v5:gpr32",
+        ];
+        assert!(text.contains(&swap.join("\n    ")), "{text}");
+        // 参数和循环中的值与 Phi 合并, 不再需要复制
+        assert!(text.contains("%v2:gpr32 = Add32I %v2:gpr32, #1"), "{text}");
+    }
+
+    #[test]
+    fn test_cmp_br_fold() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "cmp_br");
+        let i32ty = ValTypeID::Int(32);
+        let func = new_func(&mut builder, "f", i32ty, &[i32ty, i32ty]);
+        let (a, b) = (ValueSSA::FuncArg(func, 0), ValueSSA::FuncArg(func, 1));
+        let slt = icmp(&mut builder, CmpCond::SLT, a, b);
+        let exit = builder.split_block().unwrap();
+        let less = builder.split_block().unwrap();
+        builder.focus_set_branch_to(slt, less, exit).unwrap();
+        builder.set_focus(IRFocus::Block(less));
+        ret(&mut builder, a);
+        builder.set_focus(IRFocus::Block(exit));
+        ret(&mut builder, b);
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("ICmp32R"), "{text}");
+        assert!(text.contains("BCond") && text.contains("LT"), "{text}");
+        assert!(!text.contains("CSet"), "{text}");
+    }
+
+    #[test]
+    fn test_zero_and_bit_test_br() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "cbz");
+        let i64ty = ValTypeID::Int(64);
+        let func = new_func(&mut builder, "f", i64ty, &[i64ty]);
+        let a = ValueSSA::FuncArg(func, 0);
+        let is_zero = icmp(&mut builder, CmpCond::EQ, a, APInt::new(0u64, 64).into());
+        let exit = builder.split_block().unwrap();
+        let test_bit = builder.split_block().unwrap();
+        builder
+            .focus_set_branch_to(is_zero, exit, test_bit)
+            .unwrap();
+
+        builder.set_focus(IRFocus::Block(test_bit));
+        let bit = binop(
+            &mut builder,
+            Opcode::BitAnd,
+            a,
+            APInt::new(1u64 << 40, 64).into(),
+        );
+        let bit_clear = icmp(&mut builder, CmpCond::EQ, bit, APInt::new(0u64, 64).into());
+        let set_bb = builder.split_block().unwrap();
+        builder
+            .focus_set_branch_to(bit_clear, exit, set_bb)
+            .unwrap();
+        builder.set_focus(IRFocus::Block(set_bb));
+        ret(&mut builder, a);
+        builder.set_focus(IRFocus::Block(exit));
+        ret(&mut builder, APInt::new(0u64, 64).into());
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("CBZ"), "{text}");
+        assert!(text.contains("TBZ64") && text.contains("40"), "{text}");
+        assert!(!text.contains("ICmp"), "{text}");
+    }
+
+    #[test]
+    fn test_const_gep_folded_into_load() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "gep");
+        let i32ty = ValTypeID::Int(32);
+        let arrty = ArrayTypeID::new(builder.tctx(), i32ty, 16);
+        let arr = GlobalVarID::builder("arr", ValTypeID::Array(arrty))
+            .initval(ValueSSA::AggrZero(AggrType::Array(arrty)))
+            .build_id(&builder.module)
+            .unwrap();
+        new_func(&mut builder, "f", i32ty, &[]);
+        let gep = GEPInstID::builder(builder.tctx(), builder.allocs(), ValTypeID::Array(arrty))
+            .base_ptr(ValueSSA::Global(arr.raw_into()))
+            .add_indices(&[APInt::new(0u64, 64).into(), APInt::new(3u64, 64).into()])
+            .build_id();
+        builder.insert_inst(gep).unwrap();
+        let load = LoadInstID::new_uninit(builder.allocs(), i32ty, 2);
+        load.set_source(builder.allocs(), ValueSSA::Inst(gep.raw_into()));
+        builder.insert_inst(load).unwrap();
+        ret(&mut builder, ValueSSA::Inst(load.raw_into()));
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(
+            text.contains("LdrGr32Base") && text.contains("12"),
+            "{text}"
+        );
+        assert!(!text.contains("MirGEP"), "{text}");
+    }
+
+    #[test]
+    fn test_unreachable_traps() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "trap");
+        let i32ty = ValTypeID::Int(32);
+        let func = new_func(&mut builder, "f", i32ty, &[i32ty]);
+        let a = ValueSSA::FuncArg(func, 0);
+        let is_zero = icmp(&mut builder, CmpCond::EQ, a, iconst(0, 32));
+        // `new_func` 建出的入口块以 `unreachable` 结尾, 拆分之后它留在最后一个块里
+        let trap_bb = builder.split_block().unwrap();
+        let ret_bb = builder.split_block().unwrap();
+        builder
+            .focus_set_branch_to(is_zero, trap_bb, ret_bb)
+            .unwrap();
+        builder.set_focus(IRFocus::Block(ret_bb));
+        ret(&mut builder, a);
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("Brk #0"), "{text}");
+        assert_eq!(text.matches("MirReturn").count(), 1, "{text}");
+    }
+
+    #[test]
+    fn test_i128_and_vector_args() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "wide_args");
+        let (i64ty, i128ty, ptrty) = (ValTypeID::Int(64), ValTypeID::Int(128), ValTypeID::Ptr);
+        let v4f32 = ValTypeID::FixVec(FixVecType(ScalarType::Float(FPKind::Ieee32), 2));
+        let hva = ValTypeID::Struct(StructTypeID::new(builder.tctx(), false, [v4f32; 2]));
+        let extern_func = |builder: &mut IRBuilder, name: &str, ret, args: &[ValTypeID]| {
+            let fty = FuncTypeID::new(builder.tctx(), ret, false, args.iter().copied());
+            FuncID::builder(builder.tctx(), name, fty)
+                .make_extern()
+                .build_id(&builder.module)
+                .unwrap()
+        };
+        let call = |builder: &mut IRBuilder, callee: FuncID, args: &[ValueSSA]| {
+            let fty = callee.get_functype(builder.allocs());
+            builder
+                .build_inst(|allocs, tctx| {
+                    let mut cb = CallInst::builder(tctx, fty);
+                    cb.callee(ValueSSA::Global(callee.raw_into()));
+                    cb.with_args(args);
+                    CallInstID::allocate(allocs, cb.build_obj(allocs)).raw_into()
+                })
+                .map(ValueSSA::Inst)
+                .unwrap()
+        };
+        let wide = extern_func(&mut builder, "wide", i128ty, &[i128ty, i128ty]);
+        let vec = extern_func(&mut builder, "vec", v4f32, &[v4f32]);
+
+        // `x0` 放了 i64, i128 参数从偶数号寄存器开始放进 `x2`/`x3`; 常量按低半、高半分别物化.
+        let f = new_func(&mut builder, "f", i128ty, &[i64ty, i128ty]);
+        let r = call(
+            &mut builder,
+            wide,
+            &[ValueSSA::FuncArg(f, 1), iconst((1 << 64) | 3, 128)],
+        );
+        ret(&mut builder, r);
+        // 前四个 i128 参数占满 `x0`..`x7`, 第五个的两半从栈上读取.
+        let g = new_func(&mut builder, "g", i128ty, &[i128ty; 5]);
+        ret(&mut builder, ValueSSA::FuncArg(g, 4));
+        let h = new_func(&mut builder, "h", v4f32, &[v4f32]);
+        let r = call(&mut builder, vec, &[ValueSSA::FuncArg(h, 0)]);
+        ret(&mut builder, r);
+        // 16 字节向量的同构聚合逐个成员放进 `q0`/`q1`, 再按 128 位写回栈上.
+        let k = new_func(&mut builder, "k", ValTypeID::Int(32), &[hva, ptrty]);
+        let (arg, ptr) = (ValueSSA::FuncArg(k, 0), ValueSSA::FuncArg(k, 1));
+        insert(
+            &mut builder,
+            StoreInstID::new(builder.allocs(), arg, ptr, 4),
+        );
+        ret(&mut builder, iconst(0, 32));
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(
+            text.contains("MirCopy64 $x2") && text.contains("MirCopy64 $x3"),
+            "{text}"
+        );
+        assert!(
+            text.contains("$x0, $x1 = MirCall @wide, $x0, $x1, $x2, $x3"),
+            "{text}"
+        );
+        assert!(text.contains("MirReturn $x0, $x1"), "{text}");
+        let text = func_text(&mir, "g");
+        assert!(
+            text.contains("LdrGr64Base $x29, #16") && text.contains("LdrGr64Base $x29, #24"),
+            "{text}"
+        );
+        let text = func_text(&mir, "h");
+        assert!(text.contains("%v0:fpr128 = MirFCopy128 $q0"), "{text}");
+        assert!(text.contains("$q0 = MirCall @vec, $q0"), "{text}");
+        assert!(text.contains("MirReturn $q0"), "{text}");
+        let text = func_text(&mir, "k");
+        assert!(text.contains("MirFCopy128 $q1"), "{text}");
+        assert_eq!(text.matches("StrF128Base ").count(), 2, "{text}");
+    }
+
+    #[test]
+    fn test_immediate_forms() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "imm");
+        let i32ty = ValTypeID::Int(32);
+        let func = new_func(&mut builder, "f", i32ty, &[i32ty]);
+        let a = ValueSSA::FuncArg(func, 0);
+        let x = binop(&mut builder, Opcode::Sub, a, iconst(-4, 32));
+        let x = binop(&mut builder, Opcode::BitAnd, x, iconst(0xff00, 32));
+        let x = binop(&mut builder, Opcode::BitXor, x, iconst(0x1234_5678, 32));
+        ret(&mut builder, x);
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("Add32I"), "{text}");
+        assert!(text.contains("And32I"), "{text}");
+        assert!(
+            text.contains("LoadConst64") && text.contains("EOR32R"),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn test_select_and_switch() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "sel");
+        let i32ty = ValTypeID::Int(32);
+        let func = new_func(&mut builder, "f", i32ty, &[i32ty, i32ty]);
+        let (a, b) = (ValueSSA::FuncArg(func, 0), ValueSSA::FuncArg(func, 1));
+        let ult = icmp(&mut builder, CmpCond::LT, a, b);
+        let sel = SelectInstID::new(builder.allocs(), ult, a, b);
+        builder.insert_inst(sel).unwrap();
+        let exit = builder.split_block().unwrap();
+        let other = builder.split_block().unwrap();
+        let sw = SwitchInstID::builder(IntType(32))
+            .discrim(ValueSSA::Inst(sel.raw_into()))
+            .default_bb(other)
+            .case(1, exit)
+            .case(-7, exit)
+            .case(-5, exit)
+            .case(-3, exit)
+            .build_id(builder.allocs());
+        builder.focus_set_terminator(sw).unwrap();
+        builder.set_focus(IRFocus::Block(other));
+        ret(&mut builder, a);
+        builder.set_focus(IRFocus::Block(exit));
+        ret(&mut builder, ValueSSA::Inst(sel.raw_into()));
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("CSel32") && text.contains("CC"), "{text}");
+        assert!(text.contains("MirSwitch"), "{text}");
+        let f = mir.get_func(mir.get_global_by_name("f").unwrap());
+        let mut cases: Vec<_> = f.switch_tabs[0].cases.iter().map(|&(v, _)| v).collect();
+        cases.sort_unstable();
+        assert_eq!(cases, [-7, -5, -3, 1]);
+    }
+
+    #[test]
+    fn test_call_and_struct_field() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "call");
+        let (i32ty, i64ty) = (ValTypeID::Int(32), ValTypeID::Int(64));
+        let f64ty = ValTypeID::Float(FPKind::Ieee64);
+        let sty = StructTypeID::new(builder.tctx(), false, [i32ty, i64ty]);
+        let callee_ty = FuncTypeID::new(builder.tctx(), f64ty, false, [i64ty, f64ty]);
+        let callee = FuncID::builder(builder.tctx(), "g", callee_ty)
+            .make_extern()
+            .build_id(&builder.module)
+            .unwrap();
+        let func = new_func(&mut builder, "f", f64ty, &[ValTypeID::Ptr, f64ty]);
+        let gep = GEPInstID::builder(builder.tctx(), builder.allocs(), ValTypeID::Struct(sty))
+            .base_ptr(ValueSSA::FuncArg(func, 0))
+            .add_indices(&[APInt::new(0u64, 64).into(), iconst(1, 32)])
+            .build_id();
+        builder.insert_inst(gep).unwrap();
+        let load = LoadInstID::new_uninit(builder.allocs(), i64ty, 3);
+        load.set_source(builder.allocs(), ValueSSA::Inst(gep.raw_into()));
+        builder.insert_inst(load).unwrap();
+        let call = builder
+            .build_inst(|allocs, tctx| {
+                let mut cb = CallInst::builder(tctx, callee_ty);
+                cb.callee(ValueSSA::Global(callee.raw_into()));
+                cb.with_args(&[ValueSSA::Inst(load.raw_into()), ValueSSA::FuncArg(func, 1)]);
+                CallInstID::allocate(allocs, cb.build_obj(allocs)).raw_into()
+            })
+            .unwrap();
+        ret(&mut builder, ValueSSA::Inst(call));
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("LdrGr64Base") && text.contains("8"), "{text}");
+        // 整数和浮点参数各自从 0 号寄存器开始分配.
+        assert!(text.contains("$d0 = MirCall @g, $x0, $d0"), "{text}");
+    }
+
+    #[test]
+    fn test_aggr_args_and_rets() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "aggr_call");
+        let (i32ty, i64ty, ptrty) = (ValTypeID::Int(32), ValTypeID::Int(64), ValTypeID::Ptr);
+        let f32ty = ValTypeID::Float(FPKind::Ieee32);
+        let small = ValTypeID::Struct(StructTypeID::new(builder.tctx(), false, [i32ty, i64ty]));
+        let hfa = ValTypeID::Struct(StructTypeID::new(builder.tctx(), false, [f32ty; 3]));
+        let big = ValTypeID::Array(ArrayTypeID::new(builder.tctx(), i64ty, 5));
+        let load = |builder: &mut IRBuilder, ty: ValTypeID, ptr: ValueSSA| {
+            let load = LoadInstID::new_uninit(builder.allocs(), ty, 3);
+            load.set_source(builder.allocs(), ptr);
+            insert(builder, load)
+        };
+        let store = |builder: &mut IRBuilder, val: ValueSSA, ptr: ValueSSA| {
+            insert(builder, StoreInstID::new(builder.allocs(), val, ptr, 3));
+        };
+        let call = |builder: &mut IRBuilder, callee: FuncID, args: &[ValueSSA]| {
+            let fty = callee.get_functype(builder.allocs());
+            builder
+                .build_inst(|allocs, tctx| {
+                    let mut cb = CallInst::builder(tctx, fty);
+                    cb.callee(ValueSSA::Global(callee.raw_into()));
+                    cb.with_args(args);
+                    CallInstID::allocate(allocs, cb.build_obj(allocs)).raw_into()
+                })
+                .map(ValueSSA::Inst)
+                .unwrap()
+        };
+        let extern_func = |builder: &mut IRBuilder, name: &str, ret, args: &[ValTypeID]| {
+            let fty = FuncTypeID::new(builder.tctx(), ret, false, args.iter().copied());
+            FuncID::builder(builder.tctx(), name, fty)
+                .make_extern()
+                .build_id(&builder.module)
+                .unwrap()
+        };
+        let g = extern_func(&mut builder, "g", small, &[small, hfa]);
+        let k = extern_func(&mut builder, "k", big, &[big]);
+
+        // 调用者: 小结构体拆进 x0/x1, HFA 拆进 s0..s2, 大数组复制一份传地址, 结果经 x8 写回.
+        let caller = new_func(&mut builder, "caller", i32ty, &[ptrty]);
+        let p = ValueSSA::FuncArg(caller, 0);
+        let (s, h) = (load(&mut builder, small, p), load(&mut builder, hfa, p));
+        let r = call(&mut builder, g, &[s, h]);
+        store(&mut builder, r, p);
+        let b = load(&mut builder, big, p);
+        let rb = call(&mut builder, k, &[b]);
+        store(&mut builder, rb, p);
+        ret(&mut builder, iconst(0, 32));
+
+        // 被调用者: 寄存器里的参数写回栈上, 按地址传来的参数直接读, 返回值写到 x8 给出的地址.
+        let callee = new_func(&mut builder, "callee", big, &[small, hfa, big, ptrty]);
+        let q = ValueSSA::FuncArg(callee, 3);
+        store(&mut builder, ValueSSA::FuncArg(callee, 0), q);
+        store(&mut builder, ValueSSA::FuncArg(callee, 1), q);
+        ret(&mut builder, ValueSSA::FuncArg(callee, 2));
+        let ret_hfa = new_func(&mut builder, "ret_hfa", hfa, &[ptrty]);
+        let h = load(&mut builder, hfa, ValueSSA::FuncArg(ret_hfa, 0));
+        ret(&mut builder, h);
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "caller");
+        assert!(
+            text.contains("$x0, $x1 = MirCall @g, $x0, $x1, $s0, $s1, $s2"),
+            "{text}"
+        );
+        assert!(text.contains("MirCall @k, $x0, $x8\n"), "{text}");
+        // 两个结构体、两个数组和一份传给 `k` 的副本.
+        let func = mir.get_func(mir.get_global_by_name("caller").unwrap());
+        let sizes: Vec<u64> = func.stack_slots.iter().map(|slot| slot.size).collect();
+        assert_eq!(sizes, [16, 16, 16, 40, 40, 40]);
+
+        let text = func_text(&mir, "callee");
+        assert!(text.contains("%v0:gpr64 = MirCopy64 $x8"), "{text}");
+        assert!(
+            text.contains("StrF32Base %v5:fpr32") && text.contains("MirCopy64 $x2"),
+            "{text}"
+        );
+        assert!(
+            text.contains("StrGr64Base %v21:gpr64, %v0:gpr64, #32"),
+            "{text}"
+        );
+        assert!(text.contains("MirReturn\n"), "{text}");
+        let text = func_text(&mir, "ret_hfa");
+        assert!(text.contains("MirReturn $s0, $s1, $s2"), "{text}");
+    }
+
+    #[test]
+    fn test_vararg_call_ext() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "vararg");
+        let (i8ty, i32ty) = (ValTypeID::Int(8), ValTypeID::Int(32));
+        let f64ty = ValTypeID::Float(FPKind::Ieee64);
+        let callee_ty = FuncTypeID::new(builder.tctx(), i32ty, true, [i8ty]);
+        let mut callee = FuncID::builder(builder.tctx(), "h", callee_ty);
+        callee.make_extern().arg_attrs[0].set_int_ext(IntExtAttr::SignExt);
+        let callee = callee.build_id(&builder.module).unwrap();
+        let func = new_func(&mut builder, "f", i32ty, &[i8ty, f64ty]);
+        let call = builder
+            .build_inst(|allocs, tctx| {
+                let mut cb = CallInst::builder(tctx, callee_ty);
+                cb.callee(ValueSSA::Global(callee.raw_into()));
+                cb.resize_nargs(2).unwrap();
+                cb.with_args(&[ValueSSA::FuncArg(func, 0), ValueSSA::FuncArg(func, 1)]);
+                CallInstID::allocate(allocs, cb.build_obj(allocs)).raw_into()
+            })
+            .unwrap();
+        ret(&mut builder, ValueSSA::Inst(call));
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        // `signext` 的 i8 参数由调用者扩展, 可变参数与固定参数一样放进寄存器.
+        assert!(text.contains("SXTB32"), "{text}");
+        assert!(text.contains("$w0 = MirCall @h, $w0, $d0"), "{text}");
+    }
+
+    #[test]
+    fn test_stack_args_dyn_alloca() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "stack_args");
+        let i64ty = ValTypeID::Int(64);
+        let arg_tys = [i64ty; 10];
+        let callee_ty = FuncTypeID::new(builder.tctx(), i64ty, false, arg_tys);
+        let callee = FuncID::builder(builder.tctx(), "g", callee_ty)
+            .make_extern()
+            .build_id(&builder.module)
+            .unwrap();
+        let func = new_func(&mut builder, "f", i64ty, &arg_tys);
+        let buf = AllocaInstID::new_dyn(builder.allocs(), i64ty, 4);
+        builder.insert_inst(buf).unwrap();
+        let buf = ValueSSA::Inst(buf.raw_into());
+        let store = StoreInstID::new(builder.allocs(), ValueSSA::FuncArg(func, 9), buf, 3);
+        builder.insert_inst(store).unwrap();
+        let call = builder
+            .build_inst(|allocs, tctx| {
+                let mut cb = CallInst::builder(tctx, callee_ty);
+                cb.callee(ValueSSA::Global(callee.raw_into()));
+                let args: Vec<_> = (0..10).map(|i| ValueSSA::FuncArg(func, i)).collect();
+                cb.with_args(&args);
+                CallInstID::allocate(allocs, cb.build_obj(allocs)).raw_into()
+            })
+            .unwrap();
+        ret(&mut builder, ValueSSA::Inst(call));
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        // 第 9、10 个参数从调用者的栈上读取, 传给 `g` 时写进出参区.
+        assert!(text.contains("%v8:gpr64 = LdrGr64Base $x29, #16"), "{text}");
+        assert!(text.contains("%v9:gpr64 = LdrGr64Base $x29, #24"), "{text}");
+        assert!(text.contains("StrGr64Base %v9:gpr64, $sp, #8"), "{text}");
+        assert!(
+            text.contains("MirDynAlloca") && text.contains("{align_log2: 4}"),
+            "{text}"
+        );
+        let func = mir.get_func(mir.get_global_by_name("f").unwrap());
+        assert_eq!(func.outgoing_size, 16);
+    }
+
+    #[test]
+    fn test_ptr32_load_store() {
+        let arch = ArchInfo { ptr_nbits: 32, reg_nbits: 64 };
+        let mut builder = IRBuilder::new_inlined(arch, "ptr32");
+        let func = new_func(&mut builder, "f", ValTypeID::Ptr, &[ValTypeID::Ptr]);
+        let p = ValueSSA::FuncArg(func, 0);
+        let load = LoadInstID::new_uninit(builder.allocs(), ValTypeID::Ptr, 2);
+        load.set_source(builder.allocs(), p);
+        builder.insert_inst(load).unwrap();
+        let store = StoreInstID::new(builder.allocs(), ValueSSA::Inst(load.raw_into()), p, 2);
+        builder.insert_inst(store).unwrap();
+        ret(&mut builder, ValueSSA::Inst(load.raw_into()));
+
+        let mir = select_and_verify(&mut builder.module);
+        let text = func_text(&mir, "f");
+        assert!(
+            text.contains("LdrGr32Base") && text.contains("StrGr32Base"),
+            "{text}"
+        );
+        assert!(!text.contains("LdrGr64"), "{text}");
+
+        let arch = ArchInfo { ptr_nbits: 16, reg_nbits: 16 };
+        let mut module = Module::new(arch, "ptr16");
+        assert!(matches!(
+            select_module(&mut module),
+            Err(MirISelErr::UnsupportedPtrWidth(16))
+        ));
+    }
+}
@@ -0,0 +1,387 @@
+//! 算术指令和类型转换指令的选择.
+
+use super::{
+    FuncISel, MirISelErr, MirISelRes,
+    imm::{const_uint, sext_imm, zext_imm},
+};
+use crate::{
+    ir::{inst::*, *},
+    mir::{
+        Bin32R, Bin32RC, Bin32RL, Bin32RShift, Bin64R, Bin64RC, Bin64RL, Bin64RShift, BinF32R,
+        BinF64R, ExtR, MirCall, MirOP, MirOperand, MirRegClass, PReg, TenaryG32, TenaryG64, Una32R,
+        UnaF32G64, UnaF64G32, UnaFG32, UnaFG64, UnaG64F32, UnaGF32, UnaGF64, UnaryF32F64,
+        UnaryF64F32, VReg, is_calc_imm, is_logic_imm,
+    },
+};
+
+impl FuncISel<'_> {
+    /// 把 `reg` 里 `bits` 位的整数扩展到整个寄存器. 已经占满寄存器的值原样返回.
+    pub(super) fn ext_reg(&self, reg: VReg, bits: u32, signed: bool) -> VReg {
+        let class = reg.get_class();
+        let reg_bits = class.get_bits() as u32;
+        if bits >= reg_bits {
+            return reg;
+        }
+        let dst = self.new_vreg(class);
+        let is64 = class == MirRegClass::GPR64;
+        let op = match (is64, bits, signed) {
+            (false, 8, false) => Some(MirOP::UXTB32),
+            (false, 8, true) => Some(MirOP::SXTB32),
+            (false, 16, false) => Some(MirOP::UXTH32),
+            (false, 16, true) => Some(MirOP::SXTH32),
+            _ => None,
+        };
+        if let Some(op) = op {
+            self.emit(Una32R::new(op, dst.into(), reg.into()));
+        } else if !signed {
+            let mask = MirOperand::Imm(zext_imm(u64::MAX, bits) as i64);
+            self.emit_bin_logic_imm(MirOP::And64I, MirOP::And32I, dst, reg, mask);
+        } else {
+            let shift = MirOperand::Imm((reg_bits - bits) as i64);
+            let tmp = self.new_vreg(class);
+            self.emit_bin_shift_imm(MirOP::Lsl64I, MirOP::Lsl32I, tmp, reg, shift);
+            self.emit_bin_shift_imm(MirOP::Asr64I, MirOP::Asr32I, dst, tmp, shift);
+        }
+        dst
+    }
+
+    /// 把 `bits` 位的整数有符号扩展成 64 位, 用作地址计算的下标.
+    pub(super) fn sext_to_64(&self, reg: VReg, bits: u32) -> VReg {
+        if reg.get_class() == MirRegClass::GPR64 {
+            return self.ext_reg(reg, bits, true);
+        }
+        let (src, op) = match bits {
+            8 => (reg, MirOP::SXTB64),
+            16 => (reg, MirOP::SXTH64),
+            _ => (self.ext_reg(reg, bits, true), MirOP::SXTW64),
+        };
+        let dst = self.new_vreg(MirRegClass::GPR64);
+        self.emit(ExtR::new(op, dst.into(), src.into()));
+        dst
+    }
+
+    pub(super) fn emit_bin_rr(&self, op64: MirOP, op32: MirOP, dst: VReg, lhs: VReg, rhs: VReg) {
+        let (dst, lhs, rhs) = (dst.into(), lhs.into(), rhs.into());
+        match dst {
+            MirOperand::VReg(r) if r.get_class() == MirRegClass::GPR64 => {
+                self.emit(Bin64R::new(op64, dst, lhs, rhs))
+            }
+            _ => self.emit(Bin32R::new(op32, dst, lhs, rhs)),
+        }
+    }
+    pub(super) fn emit_bin_calc_imm(
+        &self,
+        op64: MirOP,
+        op32: MirOP,
+        dst: VReg,
+        lhs: VReg,
+        imm: i64,
+    ) {
+        let imm = MirOperand::Imm(imm);
+        match dst.get_class() {
+            MirRegClass::GPR64 => self.emit(Bin64RC::new(op64, dst.into(), lhs.into(), imm)),
+            _ => self.emit(Bin32RC::new(op32, dst.into(), lhs.into(), imm)),
+        }
+    }
+    fn emit_bin_logic_imm(&self, op64: MirOP, op32: MirOP, dst: VReg, lhs: VReg, imm: MirOperand) {
+        match dst.get_class() {
+            MirRegClass::GPR64 => self.emit(Bin64RL::new(op64, dst.into(), lhs.into(), imm)),
+            _ => self.emit(Bin32RL::new(op32, dst.into(), lhs.into(), imm)),
+        }
+    }
+    fn emit_bin_shift_imm(&self, op64: MirOP, op32: MirOP, dst: VReg, lhs: VReg, imm: MirOperand) {
+        match dst.get_class() {
+            MirRegClass::GPR64 => self.emit(Bin64RShift::new(op64, dst.into(), lhs.into(), imm)),
+            _ => self.emit(Bin32RShift::new(op32, dst.into(), lhs.into(), imm)),
+        }
+    }
+    /// `dst = reg + imm`. 立即数放不进 `add`/`sub` 时先物化.
+    pub(super) fn emit_add_imm(&self, dst: VReg, reg: VReg, imm: i64) {
+        if is_calc_imm(imm) {
+            self.emit_bin_calc_imm(MirOP::Add64I, MirOP::Add32I, dst, reg, imm);
+        } else if imm != i64::MIN && is_calc_imm(-imm) {
+            self.emit_bin_calc_imm(MirOP::Sub64I, MirOP::Sub32I, dst, reg, -imm);
+        } else {
+            let rhs = self.materialize_int(imm as u64, dst.get_class());
+            self.emit_bin_rr(MirOP::Add64R, MirOP::Add32R, dst, reg, rhs);
+        }
+    }
+
+    pub(super) fn select_binop(&mut self, inst: BinOPInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let opcode = inst.get_opcode(allocs);
+        let (mut lhs, mut rhs) = (inst.get_lhs(allocs), inst.get_rhs(allocs));
+        let dst = self.inst_vreg(inst.raw_into())?;
+        if opcode.is_float_op() {
+            return self.select_float_binop(opcode, dst, lhs, rhs);
+        }
+        let commutative = matches!(
+            opcode,
+            Opcode::Add | Opcode::Mul | Opcode::BitAnd | Opcode::BitOr | Opcode::BitXor
+        );
+        if commutative && const_uint(lhs).is_some() && const_uint(rhs).is_none() {
+            std::mem::swap(&mut lhs, &mut rhs);
+        }
+        let bits = self.value_bits(inst.get_rettype(allocs));
+        let reg_bits = dst.get_class().get_bits() as u32;
+
+        use Opcode::*;
+        if let Some(imm) = const_uint(rhs) {
+            let simm = sext_imm(imm, bits);
+            match opcode {
+                Add | Sub => {
+                    let simm = if opcode == Sub { simm.wrapping_neg() } else { simm };
+                    let lreg = self.value_reg(lhs)?;
+                    self.emit_add_imm(dst, lreg, simm);
+                    return Ok(());
+                }
+                BitAnd | BitOr | BitXor => {
+                    // 只要求低 `bits` 位正确, 高位可以按符号扩展的方式填, 这样 `-8` 这样的掩码也能编码.
+                    let imm = zext_imm(simm as u64, reg_bits);
+                    if is_logic_imm(imm, reg_bits) {
+                        let lreg = self.value_reg(lhs)?;
+                        let (op64, op32) = match opcode {
+                            BitAnd => (MirOP::And64I, MirOP::And32I),
+                            BitOr => (MirOP::ORR64I, MirOP::ORR32I),
+                            _ => (MirOP::EOR64I, MirOP::EOR32I),
+                        };
+                        let imm = MirOperand::Imm(imm as i64);
+                        self.emit_bin_logic_imm(op64, op32, dst, lreg, imm);
+                        return Ok(());
+                    }
+                }
+                Shl | Lshr | Ashr => {
+                    let lreg = self.value_reg(lhs)?;
+                    // 移位量不小于位宽时结果是 poison, 随便取一个合法的移位量.
+                    let amount = MirOperand::Imm((imm % bits as u64) as i64);
+                    let (lreg, op64, op32) = match opcode {
+                        Shl => (lreg, MirOP::Lsl64I, MirOP::Lsl32I),
+                        Lshr => (
+                            self.ext_reg(lreg, bits, false),
+                            MirOP::Lsr64I,
+                            MirOP::Lsr32I,
+                        ),
+                        _ => (self.ext_reg(lreg, bits, true), MirOP::Asr64I, MirOP::Asr32I),
+                    };
+                    self.emit_bin_shift_imm(op64, op32, dst, lreg, amount);
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        let lreg = self.value_reg(lhs)?;
+        let rreg = self.value_reg(rhs)?;
+        match opcode {
+            Add => self.emit_bin_rr(MirOP::Add64R, MirOP::Add32R, dst, lreg, rreg),
+            Sub => self.emit_bin_rr(MirOP::Sub64R, MirOP::Sub32R, dst, lreg, rreg),
+            Mul => self.emit_bin_rr(MirOP::Mul64, MirOP::Mul32, dst, lreg, rreg),
+            BitAnd => self.emit_bin_rr(MirOP::And64R, MirOP::And32R, dst, lreg, rreg),
+            BitOr => self.emit_bin_rr(MirOP::ORR64R, MirOP::ORR32R, dst, lreg, rreg),
+            BitXor => self.emit_bin_rr(MirOP::EOR64R, MirOP::EOR32R, dst, lreg, rreg),
+            Shl | Lshr | Ashr => {
+                let signed = opcode == Ashr;
+                let lreg = if opcode == Shl { lreg } else { self.ext_reg(lreg, bits, signed) };
+                let rreg = self.ext_reg(rreg, bits, false);
+                let (op64, op32) = match opcode {
+                    Shl => (MirOP::Lsl64R, MirOP::Lsl32R),
+                    Lshr => (MirOP::Lsr64R, MirOP::Lsr32R),
+                    _ => (MirOP::Asr64R, MirOP::Asr32R),
+                };
+                self.emit_bin_rr(op64, op32, dst, lreg, rreg);
+            }
+            Sdiv | Udiv | Srem | Urem => {
+                let signed = matches!(opcode, Sdiv | Srem);
+                let lreg = self.ext_reg(lreg, bits, signed);
+                let rreg = self.ext_reg(rreg, bits, signed);
+                let (op64, op32) = if signed {
+                    (MirOP::SDiv64, MirOP::SDiv32)
+                } else {
+                    (MirOP::UDiv64, MirOP::UDiv32)
+                };
+                if matches!(opcode, Sdiv | Udiv) {
+                    self.emit_bin_rr(op64, op32, dst, lreg, rreg);
+                    return Ok(());
+                }
+                // lhs % rhs = lhs - (lhs / rhs) * rhs
+                let quot = self.new_vreg(dst.get_class());
+                self.emit_bin_rr(op64, op32, quot, lreg, rreg);
+                let ops = [dst, quot, rreg, lreg].map(MirOperand::from);
+                match dst.get_class() {
+                    MirRegClass::GPR64 => self.emit(TenaryG64::new(
+                        MirOP::MSub64,
+                        ops[0],
+                        ops[1],
+                        ops[2],
+                        ops[3],
+                    )),
+                    _ => self.emit(TenaryG32::new(
+                        MirOP::MSub32,
+                        ops[0],
+                        ops[1],
+                        ops[2],
+                        ops[3],
+                    )),
+                }
+            }
+            _ => return Err(MirISelErr::UnsupportedInst(inst.raw_into(), opcode)),
+        }
+        Ok(())
+    }
+
+    fn select_float_binop(
+        &mut self,
+        opcode: Opcode,
+        dst: VReg,
+        lhs: ValueSSA,
+        rhs: ValueSSA,
+    ) -> MirISelRes {
+        let lreg = self.value_reg(lhs)?;
+        let rreg = self.value_reg(rhs)?;
+        let is64 = dst.get_class() == MirRegClass::FPR64;
+        let op = match (opcode, is64) {
+            (Opcode::Fadd, true) => MirOP::FAdd64,
+            (Opcode::Fsub, true) => MirOP::FSub64,
+            (Opcode::Fmul, true) => MirOP::FMul64,
+            (Opcode::Fdiv, true) => MirOP::FDiv64,
+            (Opcode::Fadd, false) => MirOP::FAdd32,
+            (Opcode::Fsub, false) => MirOP::FSub32,
+            (Opcode::Fmul, false) => MirOP::FMul32,
+            (Opcode::Fdiv, false) => MirOP::FDiv32,
+            _ => {
+                // AArch64 没有浮点取余指令, 调用 C 库的 `fmod`/`fmodf`.
+                let name = if is64 { "fmod" } else { "fmodf" };
+                let (a0, a1) =
+                    if is64 { (PReg::d(0), PReg::d(1)) } else { (PReg::s(0), PReg::s(1)) };
+                self.emit_copy(a0.into(), lreg.into());
+                self.emit_copy(a1.into(), rreg.into());
+                let callee = self
+                    .mir
+                    .get_global_by_name(name)
+                    .expect("libcall should be declared");
+                let call = MirCall::new(
+                    MirOperand::Global(callee),
+                    &[a0.into()],
+                    &[a0.into(), a1.into()],
+                );
+                self.emit(call);
+                self.emit_copy(dst.into(), a0.into());
+                return Ok(());
+            }
+        };
+        let (dst, lreg, rreg) = (dst.into(), lreg.into(), rreg.into());
+        if is64 {
+            self.emit(BinF64R::new(op, dst, lreg, rreg));
+        } else {
+            self.emit(BinF32R::new(op, dst, lreg, rreg));
+        }
+        Ok(())
+    }
+
+    pub(super) fn select_cast(&mut self, cast: CastInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let opcode = cast.get_opcode(allocs);
+        let from_ty = cast.from_ty(allocs);
+        let to_ty = cast.get_rettype(allocs);
+        let src = self.value_reg(cast.get_from(allocs))?;
+        let dst = self.inst_vreg(cast.raw_into())?;
+        use Opcode::*;
+        match opcode {
+            Zext | Sext | Trunc | PtrToInt | IntToPtr => {
+                let (src, from_bits) = self.int_view(src, from_ty);
+                let (dst, to_bits) = self.int_view(dst, to_ty);
+                self.emit_int_resize(dst, to_bits, src, from_bits, opcode == Sext);
+            }
+            Bitcast => match (src.get_class(), dst.get_class()) {
+                (MirRegClass::GPR64, MirRegClass::FPR64) => {
+                    self.emit(UnaFG64::new(MirOP::FMovFG64, dst.into(), src.into()))
+                }
+                (MirRegClass::GPR32, MirRegClass::FPR32) => {
+                    self.emit(UnaFG32::new(MirOP::FMovFG32, dst.into(), src.into()))
+                }
+                (MirRegClass::FPR64, MirRegClass::GPR64) => {
+                    self.emit(UnaGF64::new(MirOP::FMovGF64, dst.into(), src.into()))
+                }
+                (MirRegClass::FPR32, MirRegClass::GPR32) => {
+                    self.emit(UnaGF32::new(MirOP::FMovGF32, dst.into(), src.into()))
+                }
+                (s, d) if s == d => self.emit_copy(dst.into(), src.into()),
+                _ => return Err(MirISelErr::UnsupportedInst(cast.raw_into(), opcode)),
+            },
+            Sitofp | Uitofp => {
+                let signed = opcode == Sitofp;
+                let (src, bits) = self.int_view(src, from_ty);
+                let src = self.ext_reg(src, bits, signed);
+                let (d, s) = (dst.into(), src.into());
+                match (src.get_class(), dst.get_class()) {
+                    (MirRegClass::GPR32, MirRegClass::FPR32) => {
+                        let op = if signed { MirOP::SCvtF32 } else { MirOP::UCvtF32 };
+                        self.emit(UnaFG32::new(op, d, s))
+                    }
+                    (MirRegClass::GPR32, _) => {
+                        let op = if signed { MirOP::SCvtF64G32 } else { MirOP::UCvtF64G32 };
+                        self.emit(UnaF64G32::new(op, d, s))
+                    }
+                    (_, MirRegClass::FPR32) => {
+                        let op = if signed { MirOP::SCvtF32G64 } else { MirOP::UCvtF32G64 };
+                        self.emit(UnaF32G64::new(op, d, s))
+                    }
+                    _ => {
+                        let op = if signed { MirOP::SCvtF64 } else { MirOP::UCvtF64 };
+                        self.emit(UnaFG64::new(op, d, s))
+                    }
+                }
+            }
+            Fptosi | Fptoui => {
+                let signed = opcode == Fptosi;
+                let (d, s) = (dst.into(), src.into());
+                match (src.get_class(), dst.get_class()) {
+                    (MirRegClass::FPR32, MirRegClass::GPR32) => {
+                        let op = if signed { MirOP::FCvtZS32 } else { MirOP::FCvtZU32 };
+                        self.emit(UnaGF32::new(op, d, s))
+                    }
+                    (MirRegClass::FPR32, _) => {
+                        let op = if signed { MirOP::FCvtZS64F32 } else { MirOP::FCvtZU64F32 };
+                        self.emit(UnaG64F32::new(op, d, s))
+                    }
+                    (_, MirRegClass::GPR64) => {
+                        let op = if signed { MirOP::FCvtZS64 } else { MirOP::FCvtZU64 };
+                        self.emit(UnaGF64::new(op, d, s))
+                    }
+                    _ => {
+                        // 没有 "双精度到 32 位整数" 的截断转换, 先转成 64 位再取低 32 位.
+                        let op = if signed { MirOP::FCvtZS64 } else { MirOP::FCvtZU64 };
+                        let tmp = self.new_vreg(MirRegClass::GPR64);
+                        self.emit(UnaGF64::new(op, tmp.into(), s));
+                        self.emit_copy(d, tmp.with_class(MirRegClass::GPR32).into());
+                    }
+                }
+            }
+            Fpext => self.emit(UnaryF64F32::new(MirOP::FCvt64F32, dst.into(), src.into())),
+            Fptrunc => self.emit(UnaryF32F64::new(MirOP::FCvt32F64, dst.into(), src.into())),
+            _ => return Err(MirISelErr::UnsupportedInst(cast.raw_into(), opcode)),
+        }
+        Ok(())
+    }
+
+    /// 在两个整数寄存器视图之间截断或扩展.
+    fn emit_int_resize(&self, dst: VReg, to_bits: u32, src: VReg, from_bits: u32, signed: bool) {
+        let (src_class, dst_class) = (src.get_class(), dst.get_class());
+        if to_bits <= from_bits {
+            // 截断: 高位本来就可以是任意值, 直接取低位视图.
+            self.emit_copy(dst.into(), src.with_class(dst_class).into());
+            return;
+        }
+        if src_class == dst_class {
+            let clean = self.ext_reg(src, from_bits, signed);
+            self.emit_copy(dst.into(), clean.into());
+        } else if signed {
+            let ext = self.sext_to_64(src, from_bits);
+            self.emit_copy(dst.into(), ext.into());
+        } else {
+            // 写 32 位视图会清零高 32 位, 正好是零扩展.
+            let clean = self.ext_reg(src, from_bits, false);
+            self.emit_copy(dst.with_class(src_class).into(), clean.into());
+        }
+    }
+}
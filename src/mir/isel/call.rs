@@ -0,0 +1,400 @@
+//! 参数、调用和返回的选择. 位置由 [`CallAbi`] 决定.
+//!
+//! 标量整个放在一个寄存器或者一个栈位置里, 16 字节的短向量放在一个 `q` 寄存器里.
+//! 128 位整数放在一对相邻的通用寄存器里, 低半在编号小的寄存器; 放在栈上时高半紧跟在低半后面.
+//! 结构体和数组在选择过程中放在内存里, 按 [`AbiPart`] 逐段读进寄存器或者复制到栈上;
+//! 按地址传递的先复制一份, 再传副本的地址. 返回按地址传递的值时, 调用者把存放结果的地址放在 `x8` 里,
+//! 被调用者把结果复制过去.
+
+use super::{
+    FuncISel, MirISelErr, MirISelRes, is_aggr, is_int_pair,
+    mem::{AddrBase, MemAddr},
+};
+use crate::{
+    ir::{inst::*, *},
+    mir::{
+        AbiLoc, AbiPart, AbiPassKind, CallAbi, MirCall, MirOperand, MirRegClass, MirReturn, PReg,
+        SRET_GPR, VReg,
+    },
+    typing::{IValType, ValTypeID},
+};
+
+/// 单个标量参数的位置: 一个寄存器, 或者栈上参数区中的偏移量.
+#[derive(Debug, Clone, Copy)]
+enum ArgPlace {
+    Reg(PReg),
+    Stack(u32),
+}
+
+/// 参数寄存器都复制出来以后再做的事. 先复制寄存器, 免得中间的临时值占用还没读出来的参数寄存器.
+enum ArgFixup {
+    /// 读取栈上传入的参数.
+    LoadStack(VReg, u32),
+    /// 把寄存器里传入的一段写回复合类型参数所在的内存.
+    Store(VReg, MemAddr, u32),
+}
+
+/// 放复合类型的一段用的寄存器类. 通用寄存器按段的大小选 32 位或 64 位视图,
+/// 16 字节的短向量段整个放在一个 `q` 寄存器里.
+fn part_class(part: &AbiPart) -> Option<MirRegClass> {
+    match (part.loc, part.size) {
+        (AbiLoc::GPR(_), 0..=4) => Some(MirRegClass::GPR32),
+        (AbiLoc::GPR(_), _) => Some(MirRegClass::GPR64),
+        (AbiLoc::FPR(_), 4) => Some(MirRegClass::FPR32),
+        (AbiLoc::FPR(_), 8) => Some(MirRegClass::FPR64),
+        (AbiLoc::FPR(_), 16) => Some(MirRegClass::FPR128),
+        _ => None,
+    }
+}
+/// 放在寄存器里的一段所在的物理寄存器.
+fn part_preg(part: &AbiPart) -> PReg {
+    let class = part_class(part).expect("part should fit in a register");
+    match part.loc {
+        AbiLoc::GPR(n) | AbiLoc::FPR(n) => PReg::new(n, class),
+        AbiLoc::Stack(_) => unreachable!("stack part has no register"),
+    }
+}
+
+/// 128 位整数的低半和高半各自的位置. 寄存器里的两段放在一对通用寄存器里,
+/// 栈上的整个值占 16 字节.
+fn pair_places(kind: &AbiPassKind) -> Option<[ArgPlace; 2]> {
+    let AbiPassKind::Direct(parts) = kind else {
+        return None;
+    };
+    match parts.as_slice() {
+        [AbiPart { loc: AbiLoc::GPR(lo), .. }, AbiPart { loc: AbiLoc::GPR(hi), .. }] => {
+            Some([ArgPlace::Reg(PReg::x(*lo)), ArgPlace::Reg(PReg::x(*hi))])
+        }
+        [AbiPart { loc: AbiLoc::Stack(offset), .. }] => {
+            Some([ArgPlace::Stack(*offset), ArgPlace::Stack(offset + 8)])
+        }
+        _ => None,
+    }
+}
+/// 放在寄存器里的 128 位整数返回值的一对寄存器.
+fn pair_regs(kind: &AbiPassKind) -> [PReg; 2] {
+    let places = pair_places(kind).expect("128-bit value should be passed in two halves");
+    places.map(|place| match place {
+        ArgPlace::Reg(preg) => preg,
+        ArgPlace::Stack(_) => unreachable!("128-bit return value should be in registers"),
+    })
+}
+
+impl FuncISel<'_> {
+    /// 标量放在一个寄存器或者一个栈位置里时, 它的位置.
+    fn abi_place(&self, ty: ValTypeID, kind: &AbiPassKind) -> Option<ArgPlace> {
+        let class = self.abi_class(ty).ok()?;
+        match kind.as_single_loc()? {
+            AbiLoc::GPR(n) | AbiLoc::FPR(n) => Some(ArgPlace::Reg(PReg::new(n, class))),
+            AbiLoc::Stack(offset) => Some(ArgPlace::Stack(offset)),
+        }
+    }
+    /// 能否按 `kind` 搬运 `ty` 类型的值. 标量要整个放在一个位置里, 128 位整数要拆成两半,
+    /// 复合类型的每一段都要放得进寄存器类.
+    fn is_passable(&self, ty: ValTypeID, kind: &AbiPassKind) -> bool {
+        if is_int_pair(ty) {
+            return pair_places(kind).is_some();
+        }
+        if !is_aggr(ty) {
+            return self.abi_place(ty, kind).is_some();
+        }
+        match kind {
+            AbiPassKind::Direct(parts) => parts
+                .iter()
+                .all(|part| !part.loc.is_reg() || part_class(part).is_some()),
+            AbiPassKind::Ignore | AbiPassKind::Indirect(_) => true,
+        }
+    }
+    fn is_lowerable(&self, abi: &CallAbi) -> bool {
+        let ret_ok = abi.ret_ty == ValTypeID::Void || self.is_passable(abi.ret_ty, &abi.ret);
+        ret_ok
+            && abi
+                .args
+                .iter()
+                .all(|arg| self.is_passable(arg.ty, &arg.kind))
+    }
+
+    /// 本函数参数和返回值的位置. 有搬运不了的参数或返回值 (比如 8 字节的短向量)
+    /// 时报告 `UnsupportedSignature`.
+    pub(super) fn func_abi(&self) -> MirISelRes<CallAbi> {
+        match CallAbi::of_func(self.ir, self.func) {
+            Ok(abi) if self.is_lowerable(&abi) => Ok(abi),
+            _ => {
+                let name = self.func.clone_name(&self.ir.allocs);
+                Err(MirISelErr::UnsupportedSignature(name))
+            }
+        }
+    }
+
+    /// 在入口块开头把参数复制或者读取到虚拟寄存器里. 复合类型的参数放进内存.
+    pub(super) fn lower_args(&mut self, abi: CallAbi) -> MirISelRes {
+        if abi.has_sret() {
+            let ptr = self.new_vreg(MirRegClass::GPR64);
+            self.emit_copy(ptr.into(), PReg::x(SRET_GPR).into());
+            self.sret = Some(ptr);
+        }
+        let mut fixups = Vec::new();
+        for (index, arg) in abi.args.iter().enumerate() {
+            if is_aggr(arg.ty) {
+                let home = self.receive_aggr(arg.ty, &arg.kind, &mut fixups);
+                self.aggrs
+                    .insert(ValueSSA::FuncArg(self.func, index as u32), home);
+                self.args.push(None);
+                continue;
+            }
+            if is_int_pair(arg.ty) {
+                let pair = [self.new_vreg(MirRegClass::GPR64), self.new_vreg(MirRegClass::GPR64)];
+                for (vreg, place) in pair.into_iter().zip(pair_places(&arg.kind).unwrap()) {
+                    self.receive_place(vreg, place, &mut fixups);
+                }
+                self.pairs
+                    .insert(ValueSSA::FuncArg(self.func, index as u32), pair);
+                self.args.push(None);
+                continue;
+            }
+            let vreg = match self.arg_vregs.get(&(index as u32)) {
+                Some(&vreg) => vreg,
+                None => self.new_vreg(self.abi_class(arg.ty)?),
+            };
+            let place = self.abi_place(arg.ty, &arg.kind).unwrap();
+            self.receive_place(vreg, place, &mut fixups);
+            self.args.push(Some(vreg));
+        }
+        for fixup in fixups {
+            match fixup {
+                ArgFixup::LoadStack(vreg, offset) => self.load_stack_arg(vreg, offset),
+                ArgFixup::Store(vreg, addr, size) => self.store_part(vreg, addr, size),
+            }
+        }
+        self.ret_abi = abi.ret;
+        Ok(())
+    }
+    /// 把 `place` 处传入的值放进 `vreg`. 栈上的值等参数寄存器都复制出来以后再读.
+    fn receive_place(&self, vreg: VReg, place: ArgPlace, fixups: &mut Vec<ArgFixup>) {
+        match place {
+            ArgPlace::Reg(preg) => self.emit_copy(vreg.into(), preg.into()),
+            ArgPlace::Stack(offset) => fixups.push(ArgFixup::LoadStack(vreg, offset)),
+        }
+    }
+    /// 复合类型参数所在的内存. 寄存器里传入的段复制出来, 写回内存的操作记在 `fixups` 里;
+    /// 栈上传入的直接使用调用者的出参区, 按地址传入的直接使用调用者准备的副本.
+    fn receive_aggr(
+        &mut self,
+        ty: ValTypeID,
+        kind: &AbiPassKind,
+        fixups: &mut Vec<ArgFixup>,
+    ) -> MemAddr {
+        match kind {
+            AbiPassKind::Direct(parts) => {
+                if let [AbiPart { loc: AbiLoc::Stack(offset), .. }] = parts.as_slice() {
+                    return self.frame_addr(PReg::FP, 16 + *offset as i64);
+                }
+                let home = self.new_aggr_slot(ty);
+                for part in parts {
+                    let preg = part_preg(part);
+                    let vreg = self.new_vreg(preg.get_class());
+                    self.emit_copy(vreg.into(), preg.into());
+                    let addr = home.add(part.offset as i64);
+                    fixups.push(ArgFixup::Store(vreg, addr, part.size));
+                }
+                home
+            }
+            AbiPassKind::Indirect(loc) => {
+                let ptr = self.new_vreg(MirRegClass::GPR64);
+                match *loc {
+                    AbiLoc::GPR(n) => self.emit_copy(ptr.into(), PReg::x(n).into()),
+                    AbiLoc::Stack(offset) => fixups.push(ArgFixup::LoadStack(ptr, offset)),
+                    AbiLoc::FPR(_) => unreachable!("address is never passed in a float register"),
+                }
+                MemAddr { base: AddrBase::Reg(ptr), offset: 0 }
+            }
+            AbiPassKind::Ignore => self.new_aggr_slot(ty),
+        }
+    }
+
+    pub(super) fn select_call(&mut self, call: CallInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let inst = call.raw_into();
+        let abi = match CallAbi::of_call(self.ir, call) {
+            Ok(abi) if self.is_lowerable(&abi) => abi,
+            _ => return Err(MirISelErr::UnsupportedCall(inst)),
+        };
+        self.outgoing_size = self.outgoing_size.max(abi.stack_size);
+
+        let callee = match call.get_callee(allocs) {
+            ValueSSA::Global(g) if FuncID::try_from_global(allocs, g).is_some() => {
+                MirOperand::Global(self.globals[&g])
+            }
+            callee => self.value_reg(callee)?.into(),
+        };
+        // 先把所有实参求出来、把栈上的部分写进出参区, 最后再写参数寄存器,
+        // 免得求值过程中插入的指令夹在参数复制之间.
+        let mut regs: Vec<(PReg, VReg)> = Vec::with_capacity(abi.args.len());
+        for (i, arg) in abi.args.iter().enumerate() {
+            let val = call.get_arg(allocs, i);
+            if is_aggr(arg.ty) {
+                self.pass_aggr(val, arg.ty, &arg.kind, &mut regs)?;
+                continue;
+            }
+            if is_int_pair(arg.ty) {
+                let pair = self.value_pair(val)?;
+                for (reg, place) in pair.into_iter().zip(pair_places(&arg.kind).unwrap()) {
+                    match place {
+                        ArgPlace::Reg(preg) => regs.push((preg, reg)),
+                        ArgPlace::Stack(offset) => self.store_stack_arg(reg, offset),
+                    }
+                }
+                continue;
+            }
+            let reg = self.value_reg(val)?;
+            let reg = match (arg.ext, arg.ty) {
+                (IntExtAttr::NoExt, _) => reg,
+                (ext, ty) => self.ext_reg(reg, self.value_bits(ty), ext == IntExtAttr::SignExt),
+            };
+            match self.abi_place(arg.ty, &arg.kind).unwrap() {
+                ArgPlace::Reg(preg) => regs.push((preg, reg)),
+                ArgPlace::Stack(offset) => self.store_stack_arg(reg, offset),
+            }
+        }
+        if abi.has_sret() {
+            let home = self.aggr_addr(ValueSSA::Inst(inst))?;
+            regs.push((PReg::x(SRET_GPR), self.addr_reg(home)));
+        }
+        for &(preg, reg) in &regs {
+            self.emit_copy(preg.into(), reg.into());
+        }
+        let arg_regs: Vec<MirOperand> = regs.iter().map(|&(preg, _)| preg.into()).collect();
+
+        match &abi.ret {
+            _ if abi.ret_ty == ValTypeID::Void => {
+                self.emit(MirCall::new(callee, &[], &arg_regs));
+            }
+            AbiPassKind::Direct(parts) if is_aggr(abi.ret_ty) => {
+                let rets: Vec<MirOperand> = parts.iter().map(|p| part_preg(p).into()).collect();
+                self.emit(MirCall::new(callee, &rets, &arg_regs));
+                let home = self.aggr_addr(ValueSSA::Inst(inst))?;
+                let mut vregs = Vec::with_capacity(parts.len());
+                for part in parts {
+                    let preg = part_preg(part);
+                    let vreg = self.new_vreg(preg.get_class());
+                    self.emit_copy(vreg.into(), preg.into());
+                    vregs.push(vreg);
+                }
+                for (part, vreg) in parts.iter().zip(vregs) {
+                    self.store_part(vreg, home.add(part.offset as i64), part.size);
+                }
+            }
+            AbiPassKind::Ignore | AbiPassKind::Indirect(_) => {
+                self.emit(MirCall::new(callee, &[], &arg_regs));
+            }
+            kind if is_int_pair(abi.ret_ty) => {
+                let rets = pair_regs(kind);
+                self.emit(MirCall::new(callee, &rets.map(MirOperand::from), &arg_regs));
+                let pair = rets.map(|preg| {
+                    let vreg = self.new_vreg(MirRegClass::GPR64);
+                    self.emit_copy(vreg.into(), preg.into());
+                    vreg
+                });
+                self.pairs.insert(ValueSSA::Inst(inst), pair);
+            }
+            kind => {
+                let Some(ArgPlace::Reg(preg)) = self.abi_place(abi.ret_ty, kind) else {
+                    unreachable!("scalar return value should be in a register");
+                };
+                self.emit(MirCall::new(callee, &[preg.into()], &arg_regs));
+                // 短向量不参与运算, `inst_vreg` 不给它分配寄存器, 这里按返回值的寄存器类分配.
+                let dst = match self.vregs.get(&inst) {
+                    Some(&vreg) => vreg,
+                    None => {
+                        let vreg = self.new_vreg(preg.get_class());
+                        self.vregs.insert(inst, vreg);
+                        vreg
+                    }
+                };
+                self.emit_copy(dst.into(), preg.into());
+            }
+        }
+        Ok(())
+    }
+    /// 准备一个复合类型的实参: 放进寄存器的段读进虚拟寄存器, 记在 `regs` 里;
+    /// 放在栈上的直接复制到出参区; 按地址传递的复制一份, 因为被调用者可以改写传来的内存.
+    fn pass_aggr(
+        &mut self,
+        val: ValueSSA,
+        ty: ValTypeID,
+        kind: &AbiPassKind,
+        regs: &mut Vec<(PReg, VReg)>,
+    ) -> MirISelRes {
+        let home = self.aggr_addr(val)?;
+        match kind {
+            AbiPassKind::Ignore => {}
+            AbiPassKind::Direct(parts) => {
+                for part in parts {
+                    let src = home.add(part.offset as i64);
+                    if let AbiLoc::Stack(offset) = part.loc {
+                        let dst = self.frame_addr(PReg::SP, offset as i64);
+                        self.copy_mem(dst, src, part.size as u64);
+                        continue;
+                    }
+                    let preg = part_preg(part);
+                    regs.push((preg, self.load_part(preg.get_class(), src, part.size)));
+                }
+            }
+            AbiPassKind::Indirect(loc) => {
+                let copy = self.new_aggr_slot(ty);
+                self.copy_mem(copy, home, ty.get_size(&self.ir.tctx) as u64);
+                let ptr = self.addr_reg(copy);
+                match *loc {
+                    AbiLoc::GPR(n) => regs.push((PReg::x(n), ptr)),
+                    AbiLoc::Stack(offset) => self.store_stack_arg(ptr, offset),
+                    AbiLoc::FPR(_) => unreachable!("address is never passed in a float register"),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn select_ret(&mut self, ret: RetInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        if !ret.has_retval(allocs) {
+            self.emit(MirReturn::new(&[]));
+            return Ok(());
+        }
+        let val = ret.get_retval(allocs);
+        let ty = val.get_valtype(allocs);
+        let mut regs: Vec<(PReg, VReg)> = Vec::new();
+        if is_aggr(ty) {
+            let home = self.aggr_addr(val)?;
+            match self.ret_abi.clone() {
+                AbiPassKind::Direct(parts) => {
+                    for part in &parts {
+                        let preg = part_preg(part);
+                        let src = home.add(part.offset as i64);
+                        regs.push((preg, self.load_part(preg.get_class(), src, part.size)));
+                    }
+                }
+                AbiPassKind::Indirect(_) => {
+                    let sret = self.sret.expect("sret address should be saved at entry");
+                    let dst = MemAddr { base: AddrBase::Reg(sret), offset: 0 };
+                    self.copy_mem(dst, home, ty.get_size(&self.ir.tctx) as u64);
+                }
+                AbiPassKind::Ignore => {}
+            }
+        } else if is_int_pair(ty) {
+            let pair = self.value_pair(val)?;
+            regs.extend(pair_regs(&self.ret_abi).into_iter().zip(pair));
+        } else {
+            let reg = self.value_reg(val)?;
+            let Some(ArgPlace::Reg(preg)) = self.abi_place(ty, &self.ret_abi) else {
+                unreachable!("scalar return value should be in a register");
+            };
+            regs.push((preg, reg));
+        }
+        for &(preg, reg) in &regs {
+            self.emit_copy(preg.into(), reg.into());
+        }
+        let rets: Vec<MirOperand> = regs.iter().map(|&(preg, _)| preg.into()).collect();
+        self.emit(MirReturn::new(&rets));
+        Ok(())
+    }
+}
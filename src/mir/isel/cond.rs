@@ -0,0 +1,415 @@
+//! 比较、条件跳转、条件选择和多路跳转的选择.
+
+use super::{
+    FuncISel, MirISelRes,
+    imm::{const_uint, sext_imm, zext_imm},
+};
+use crate::{
+    ir::{inst::*, *},
+    mir::{
+        Bin32R, Bin32RL, CBZs, CSel32, CSel64, CSelF32, CSelF64, CSet32, CondBr, FCmp32, FCmp64,
+        ICmp32I, ICmp32R, ICmp64I, ICmp64R, MirBlockID, MirCondFlag, MirOP, MirOperand,
+        MirRegClass, MirSwitch, MirSwitchTab, MirSwitchTabID, Mov32I, PReg, TBZ32, TBZ64, UncondBr,
+        VReg, is_calc_imm,
+    },
+    typing::ValTypeID,
+};
+
+/// 比较之后需要检查的条件标志.
+///
+/// 浮点比较的结果在 NZCV 上有四种情况 (小于、等于、大于、无序), 有的 IR 条件需要同时检查两个标志.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FlagTest {
+    Never,
+    Always,
+    One(MirCondFlag),
+    Either(MirCondFlag, MirCondFlag),
+}
+
+impl FlagTest {
+    fn from_int_cond(cond: CmpCond) -> Self {
+        use MirCondFlag::*;
+        let signed = cond.is_signed_ordered();
+        let basic = cond.get_basic_cond();
+        let flag = match basic {
+            CmpCond::NEVER => return FlagTest::Never,
+            CmpCond::ALWAYS => return FlagTest::Always,
+            CmpCond::EQ => EQ,
+            CmpCond::NE => NE,
+            CmpCond::LT => {
+                if signed {
+                    LT
+                } else {
+                    CC
+                }
+            }
+            CmpCond::LE => {
+                if signed {
+                    LE
+                } else {
+                    LS
+                }
+            }
+            CmpCond::GT => {
+                if signed {
+                    GT
+                } else {
+                    HI
+                }
+            }
+            CmpCond::GE => {
+                if signed {
+                    GE
+                } else {
+                    CS
+                }
+            }
+            _ => unreachable!("invalid compare condition {cond:?}"),
+        };
+        FlagTest::One(flag)
+    }
+
+    /// `fcmp` 之后各个标志的含义: 小于时 `N`, 等于时 `ZC`, 大于时 `C`, 无序时 `CV`.
+    fn from_float_cond(cond: CmpCond) -> Self {
+        use MirCondFlag::*;
+        let ordered = cond.is_signed_ordered();
+        let basic = cond.get_basic_cond();
+        match (basic, ordered) {
+            (CmpCond::NEVER, true) => FlagTest::Never,
+            (CmpCond::ALWAYS, false) => FlagTest::Always,
+            (CmpCond::NEVER, false) => FlagTest::One(VS),
+            (CmpCond::ALWAYS, true) => FlagTest::One(VC),
+            (CmpCond::LT, true) => FlagTest::One(MI),
+            (CmpCond::EQ, true) => FlagTest::One(EQ),
+            (CmpCond::GT, true) => FlagTest::One(GT),
+            (CmpCond::LE, true) => FlagTest::One(LS),
+            (CmpCond::GE, true) => FlagTest::One(GE),
+            (CmpCond::NE, true) => FlagTest::Either(MI, GT),
+            (CmpCond::LT, false) => FlagTest::One(LT),
+            (CmpCond::EQ, false) => FlagTest::Either(EQ, VS),
+            (CmpCond::GT, false) => FlagTest::One(HI),
+            (CmpCond::LE, false) => FlagTest::One(LE),
+            (CmpCond::GE, false) => FlagTest::One(PL),
+            (CmpCond::NE, false) => FlagTest::One(NE),
+            _ => unreachable!("invalid compare condition {cond:?}"),
+        }
+    }
+}
+
+impl FuncISel<'_> {
+    /// 条件值是比较指令时返回它, 这样比较可以在使用处和跳转或选择合并.
+    fn foldable_cmp(&self, cond: ValueSSA) -> Option<CmpInstID> {
+        let ValueSSA::Inst(inst) = cond else {
+            return None;
+        };
+        match inst.deref_ir(&self.ir.allocs) {
+            InstObj::Cmp(_) => Some(CmpInstID::raw_from(inst)),
+            _ => None,
+        }
+    }
+
+    /// 生成比较指令, 返回比较结果为真时成立的条件标志.
+    fn emit_compare(&mut self, cmp: CmpInstID) -> MirISelRes<FlagTest> {
+        let allocs = &self.ir.allocs;
+        let cond = cmp.get_cond(allocs);
+        let ty = cmp.operand_ty(allocs);
+        let (lhs, rhs) = (cmp.get_lhs(allocs), cmp.get_rhs(allocs));
+        if cond.is_float() {
+            let test = FlagTest::from_float_cond(cond);
+            if matches!(test, FlagTest::Never | FlagTest::Always) {
+                return Ok(test);
+            }
+            let lreg = self.value_reg(lhs)?.into();
+            let rreg = self.value_reg(rhs)?.into();
+            let nzcv = PReg::NZCV.into();
+            match ty {
+                ValTypeID::Float(crate::typing::FPKind::Ieee64) => {
+                    self.emit(FCmp64::new(MirOP::FCmp64, nzcv, lreg, rreg))
+                }
+                _ => self.emit(FCmp32::new(MirOP::FCmp32, nzcv, lreg, rreg)),
+            }
+            return Ok(test);
+        }
+        self.emit_int_compare(lhs, rhs, cond, ty)
+    }
+
+    fn emit_int_compare(
+        &mut self,
+        mut lhs: ValueSSA,
+        mut rhs: ValueSSA,
+        mut cond: CmpCond,
+        ty: ValTypeID,
+    ) -> MirISelRes<FlagTest> {
+        if matches!(
+            FlagTest::from_int_cond(cond),
+            FlagTest::Never | FlagTest::Always
+        ) {
+            return Ok(FlagTest::from_int_cond(cond));
+        }
+        if const_uint(lhs).is_some() && const_uint(rhs).is_none() {
+            std::mem::swap(&mut lhs, &mut rhs);
+            cond = cond.swap_operands();
+        }
+        let signed = cond.is_signed_ordered();
+        let lreg = self.value_reg(lhs)?;
+        let (lreg, bits) = self.int_view(lreg, ty);
+        let lreg = self.ext_reg(lreg, bits, signed);
+        let is64 = lreg.get_class() == MirRegClass::GPR64;
+        let nzcv = PReg::NZCV.into();
+
+        if let Some(imm) = const_uint(rhs) {
+            let imm = if signed { sext_imm(imm, bits) } else { imm as i64 };
+            let (cmp, cmn) = match is64 {
+                true => (MirOP::ICmp64I, MirOP::ICmn64I),
+                false => (MirOP::ICmp32I, MirOP::ICmn32I),
+            };
+            let form = if is_calc_imm(imm) {
+                Some((cmp, imm))
+            } else if imm != i64::MIN && is_calc_imm(-imm) {
+                Some((cmn, -imm))
+            } else {
+                None
+            };
+            if let Some((op, imm)) = form {
+                let (l, r) = (lreg.into(), MirOperand::Imm(imm));
+                match is64 {
+                    true => self.emit(ICmp64I::new(op, nzcv, l, r)),
+                    false => self.emit(ICmp32I::new(op, nzcv, l, r)),
+                }
+                return Ok(FlagTest::from_int_cond(cond));
+            }
+        }
+        let rreg = self.value_reg(rhs)?;
+        let (rreg, _) = self.int_view(rreg, ty);
+        let rreg = self.ext_reg(rreg, bits, signed);
+        match is64 {
+            true => self.emit(ICmp64R::new(MirOP::ICmp64R, nzcv, lreg.into(), rreg.into())),
+            false => self.emit(ICmp32R::new(MirOP::ICmp32R, nzcv, lreg.into(), rreg.into())),
+        }
+        Ok(FlagTest::from_int_cond(cond))
+    }
+
+    /// 比较结果作为普通值使用时才需要写进寄存器. 只被跳转和选择使用的比较在使用处重新生成.
+    pub(super) fn select_cmp(&mut self, cmp: CmpInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let has_value_user = cmp
+            .deref_ir(allocs)
+            .user_iter(allocs)
+            .any(|(_, u)| !matches!(u.get_kind(), UseKind::BranchCond | UseKind::SelectCond));
+        if !has_value_user {
+            return Ok(());
+        }
+        let dst = self.inst_vreg(cmp.raw_into())?;
+        let test = self.emit_compare(cmp)?;
+        let cset = |flag: MirCondFlag, rd: VReg| {
+            let inst = CSet32::new(MirOP::CSet32, rd.into(), PReg::NZCV.into());
+            inst.set_cond(flag);
+            self.emit(inst);
+        };
+        match test {
+            FlagTest::Never | FlagTest::Always => {
+                let imm = MirOperand::Imm((test == FlagTest::Always) as i64);
+                self.emit(Mov32I::new(MirOP::Mov32I, dst.into(), imm));
+            }
+            FlagTest::One(flag) => cset(flag, dst),
+            FlagTest::Either(a, b) => {
+                let (ta, tb) = (
+                    self.new_vreg(MirRegClass::GPR32),
+                    self.new_vreg(MirRegClass::GPR32),
+                );
+                cset(a, ta);
+                cset(b, tb);
+                let inst = Bin32R::new(MirOP::ORR32R, dst.into(), ta.into(), tb.into());
+                self.emit(inst);
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn emit_jump(&self, target: MirBlockID) {
+        self.emit(UncondBr::new(MirOP::B, MirOperand::Label(target)));
+    }
+    fn emit_bcond(&self, flag: MirCondFlag, target: MirBlockID) {
+        let br = CondBr::new(MirOP::BCond, MirOperand::Label(target), PReg::NZCV.into());
+        br.set_cond(flag);
+        self.emit(br);
+    }
+
+    pub(super) fn select_br(&mut self, br: BrInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let then_bb = self.block_of(br.get_then(allocs));
+        let else_bb = self.block_of(br.get_else(allocs));
+        let cond = br.get_cond(allocs);
+        if let Some(c) = const_uint(cond) {
+            self.emit_jump(if c & 1 != 0 { then_bb } else { else_bb });
+            return Ok(());
+        }
+        let Some(cmp) = self.foldable_cmp(cond) else {
+            // 布尔值只有最低位有效.
+            let reg = self.value_reg(cond)?;
+            let (target, bit) = (MirOperand::Label(then_bb), MirOperand::Imm(0));
+            self.emit(TBZ32::new(MirOP::TBNZ32, reg.into(), bit, target));
+            self.emit_jump(else_bb);
+            return Ok(());
+        };
+        if self.try_select_zero_test_br(cmp, then_bb, else_bb)? {
+            return Ok(());
+        }
+        match self.emit_compare(cmp)? {
+            FlagTest::Never => self.emit_jump(else_bb),
+            FlagTest::Always => self.emit_jump(then_bb),
+            FlagTest::One(flag) => {
+                self.emit_bcond(flag, then_bb);
+                self.emit_jump(else_bb);
+            }
+            FlagTest::Either(a, b) => {
+                self.emit_bcond(a, then_bb);
+                self.emit_bcond(b, then_bb);
+                self.emit_jump(else_bb);
+            }
+        }
+        Ok(())
+    }
+
+    /// `x == 0` 和 `x != 0` 选用 `cbz`/`cbnz`; 其中 `x` 是 `y & (1 << k)` 时选用 `tbz`/`tbnz`.
+    fn try_select_zero_test_br(
+        &mut self,
+        cmp: CmpInstID,
+        then_bb: MirBlockID,
+        else_bb: MirBlockID,
+    ) -> MirISelRes<bool> {
+        let allocs = &self.ir.allocs;
+        let cond = cmp.get_cond(allocs);
+        let basic = cond.get_basic_cond();
+        if cond.is_float() || (basic != CmpCond::EQ && basic != CmpCond::NE) {
+            return Ok(false);
+        }
+        let (lhs, rhs) = (cmp.get_lhs(allocs), cmp.get_rhs(allocs));
+        let val = match (const_uint(lhs), const_uint(rhs)) {
+            (_, Some(0)) => lhs,
+            (Some(0), _) => rhs,
+            _ => return Ok(false),
+        };
+        let (zero_bb, nonzero_bb) =
+            if basic == CmpCond::EQ { (then_bb, else_bb) } else { (else_bb, then_bb) };
+        let ty = cmp.operand_ty(allocs);
+
+        if let Some((src, bit)) = self.single_bit_test(val, ty) {
+            let reg = self.value_reg(src)?;
+            let (bit, target) = (MirOperand::Imm(bit as i64), MirOperand::Label(zero_bb));
+            match reg.get_class() {
+                MirRegClass::GPR64 => self.emit(TBZ64::new(MirOP::TBZ64, reg.into(), bit, target)),
+                _ => self.emit(TBZ32::new(MirOP::TBZ32, reg.into(), bit, target)),
+            }
+        } else {
+            let reg = self.value_reg(val)?;
+            let (reg, bits) = self.int_view(reg, ty);
+            // 高位清零之后, 64 位视图的值和原来的整数相等.
+            let reg = self
+                .ext_reg(reg, bits, false)
+                .with_class(MirRegClass::GPR64);
+            let target = MirOperand::Label(zero_bb);
+            self.emit(CBZs::new(MirOP::CBZ, reg.into(), target));
+        }
+        self.emit_jump(nonzero_bb);
+        Ok(true)
+    }
+    /// `val` 是 `x & (1 << k)` 时返回 `(x, k)`.
+    fn single_bit_test(&self, val: ValueSSA, ty: ValTypeID) -> Option<(ValueSSA, u32)> {
+        let allocs = &self.ir.allocs;
+        let ValueSSA::Inst(inst) = val else {
+            return None;
+        };
+        if inst.get_opcode(allocs) != Opcode::BitAnd || !matches!(ty, ValTypeID::Int(_)) {
+            return None;
+        }
+        let and = BinOPInstID::raw_from(inst);
+        let (lhs, rhs) = (and.get_lhs(allocs), and.get_rhs(allocs));
+        let bits = self.value_bits(ty);
+        [(lhs, rhs), (rhs, lhs)].into_iter().find_map(|(x, mask)| {
+            let mask = zext_imm(const_uint(mask)?, bits);
+            (mask.is_power_of_two() && const_uint(x).is_none()).then(|| (x, mask.trailing_zeros()))
+        })
+    }
+
+    pub(super) fn select_select(&mut self, sel: SelectInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let dst = self.inst_vreg(sel.raw_into())?;
+        // 操作数要在比较之前物化, 以免夹在比较和 `csel` 之间.
+        let then_reg = self.value_reg(sel.get_then(allocs))?;
+        let else_reg = self.value_reg(sel.get_else(allocs))?;
+        let cond = sel.get_cond(allocs);
+        let test = if let Some(c) = const_uint(cond) {
+            if c & 1 != 0 { FlagTest::Always } else { FlagTest::Never }
+        } else if let Some(cmp) = self.foldable_cmp(cond) {
+            self.emit_compare(cmp)?
+        } else {
+            let reg = self.value_reg(cond)?;
+            let bit = self.new_vreg(MirRegClass::GPR32);
+            let one = MirOperand::Imm(1);
+            self.emit(Bin32RL::new(MirOP::And32I, bit.into(), reg.into(), one));
+            let zero = MirOperand::Imm(0);
+            self.emit(ICmp32I::new(
+                MirOP::ICmp32I,
+                PReg::NZCV.into(),
+                bit.into(),
+                zero,
+            ));
+            FlagTest::One(MirCondFlag::NE)
+        };
+        match test {
+            FlagTest::Always => self.emit_copy(dst.into(), then_reg.into()),
+            FlagTest::Never => self.emit_copy(dst.into(), else_reg.into()),
+            FlagTest::One(flag) => self.emit_csel(dst, then_reg, else_reg, flag),
+            FlagTest::Either(a, b) => {
+                let tmp = self.new_vreg(dst.get_class());
+                self.emit_csel(tmp, then_reg, else_reg, a);
+                self.emit_csel(dst, then_reg, tmp, b);
+            }
+        }
+        Ok(())
+    }
+    /// `dst = flag ? rn : rm`
+    fn emit_csel(&self, dst: VReg, rn: VReg, rm: VReg, flag: MirCondFlag) {
+        let (rd, rn, rm, csr) = (dst.into(), rn.into(), rm.into(), PReg::NZCV.into());
+        match dst.get_class() {
+            MirRegClass::GPR64 => {
+                let inst = CSel64::new(MirOP::CSel64, rd, rn, rm, csr);
+                inst.set_cond(flag);
+                self.emit(inst)
+            }
+            MirRegClass::GPR32 => {
+                let inst = CSel32::new(MirOP::CSel32, rd, rn, rm, csr);
+                inst.set_cond(flag);
+                self.emit(inst)
+            }
+            MirRegClass::FPR64 => {
+                let inst = CSelF64::new(MirOP::CSelF64, rd, rn, rm, csr);
+                inst.set_cond(flag);
+                self.emit(inst)
+            }
+            _ => {
+                let inst = CSelF32::new(MirOP::CSelF32, rd, rn, rm, csr);
+                inst.set_cond(flag);
+                self.emit(inst)
+            }
+        }
+    }
+
+    pub(super) fn select_switch(&mut self, sw: SwitchInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let bits = sw.discrim_ty(allocs).0 as u32;
+        let discrim = self.value_reg(sw.get_discrim(allocs))?;
+        // 跳转表按有符号数比较下标, 所以下标和各个分支的值都按有符号数扩展.
+        let index = self.ext_reg(discrim, bits, true);
+        let default = self.block_of(sw.get_default_bb(allocs));
+        let cases = sw
+            .cases_iter(allocs)
+            .map(|(_, case, bb)| (sext_imm(case as u64, bits), self.block_of(bb)))
+            .collect();
+        self.switch_tabs.push(MirSwitchTab { default, cases });
+        let tab = MirSwitchTabID(self.switch_tabs.len() as u32 - 1);
+        self.emit(MirSwitch::new(index.into(), tab));
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+//! 常量物化: 把 IR 常量写进虚拟寄存器, 尽量使用单条 `mov`/`fmov`.
+
+use super::{FuncISel, MirISelRes};
+use crate::{
+    ir::{ConstData, ValueSSA},
+    mir::{
+        FMov32I, FMov64I, LoadConst64, MirLdImmF32, MirLdImmF64, MirOP, MirOperand, MirRegClass,
+        Mov32I, Mov64I, PReg, UnaFG32, UnaFG64, VReg, is_fmov_imm32, is_fmov_imm64, is_mov_imm,
+    },
+    typing::{FPKind, IValType, ScalarType, ValTypeID},
+};
+
+/// 整数常量 (包括空指针) 的值, 按类型位宽零扩展.
+pub(super) fn const_uint(val: ValueSSA) -> Option<u64> {
+    match val {
+        ValueSSA::ConstData(ConstData::Int(apint)) => Some(apint.as_unsigned() as u64),
+        ValueSSA::ConstData(ConstData::PtrNull)
+        | ValueSSA::ConstData(ConstData::Zero(ScalarType::Int(_) | ScalarType::Ptr)) => Some(0),
+        _ => None,
+    }
+}
+
+/// 把 `bits` 位的整数按有符号数扩展到 64 位.
+pub(super) fn sext_imm(imm: u64, bits: u32) -> i64 {
+    if bits >= 64 {
+        return imm as i64;
+    }
+    let shift = 64 - bits;
+    ((imm << shift) as i64) >> shift
+}
+
+/// 只保留 `bits` 位整数的低位.
+pub(super) fn zext_imm(imm: u64, bits: u32) -> u64 {
+    if bits >= 64 { imm } else { imm & ((1u64 << bits) - 1) }
+}
+
+impl FuncISel<'_> {
+    pub(super) fn materialize_const(&mut self, data: ConstData) -> MirISelRes<VReg> {
+        let ty = match data {
+            ConstData::Undef(ty) => ty,
+            ConstData::Zero(scalar) => scalar.into_ir(),
+            ConstData::PtrNull => ValTypeID::Ptr,
+            ConstData::Int(apint) => ValTypeID::Int(apint.bits()),
+            ConstData::Float(kind, _) => ValTypeID::Float(kind),
+        };
+        let class = self.reg_class(ty)?;
+        let reg = match data {
+            ConstData::Int(apint) => self.materialize_int(apint.as_unsigned() as u64, class),
+            ConstData::Float(FPKind::Ieee32, f) => {
+                self.materialize_float((f as f32).to_bits() as u64, class)
+            }
+            ConstData::Float(FPKind::Ieee64, f) => self.materialize_float(f.to_bits(), class),
+            // 未定义值随便取一个, 取 0 最便宜.
+            _ if class.is_fpr() => self.materialize_float(0, class),
+            _ => self.materialize_int(0, class),
+        };
+        Ok(reg)
+    }
+
+    /// 把整数写进一个新的 `class` 类虚拟寄存器. `imm` 按寄存器位宽截断.
+    pub(super) fn materialize_int(&self, imm: u64, class: MirRegClass) -> VReg {
+        let dst = self.new_vreg(class);
+        let bits = class.get_bits() as u32;
+        let imm = zext_imm(imm, bits);
+        if is_mov_imm(imm, bits) {
+            let src = MirOperand::Imm(imm as i64);
+            match class {
+                MirRegClass::GPR64 => self.emit(Mov64I::new(MirOP::Mov64I, dst.into(), src)),
+                _ => self.emit(Mov32I::new(MirOP::Mov32I, dst.into(), src)),
+            }
+        } else {
+            // 32 位常量零扩展之后写进 64 位视图, 高 32 位仍然是 0.
+            let dst64 = dst.with_class(MirRegClass::GPR64);
+            let src = MirOperand::Imm(imm as i64);
+            self.emit(LoadConst64::new(MirOP::LoadConst64, dst64.into(), src));
+        }
+        dst
+    }
+
+    /// 把浮点数的位模式写进一个新的 `class` 类虚拟寄存器.
+    pub(super) fn materialize_float(&self, bits: u64, class: MirRegClass) -> VReg {
+        let dst = self.new_vreg(class);
+        let src = MirOperand::Imm(bits as i64);
+        match class {
+            MirRegClass::FPR64 if bits == 0 => {
+                self.emit(UnaFG64::new(MirOP::FMovFG64, dst.into(), PReg::XZR.into()))
+            }
+            MirRegClass::FPR32 if bits == 0 => {
+                self.emit(UnaFG32::new(MirOP::FMovFG32, dst.into(), PReg::WZR.into()))
+            }
+            MirRegClass::FPR64 if is_fmov_imm64(bits) => {
+                self.emit(FMov64I::new(MirOP::FMov64I, dst.into(), src))
+            }
+            MirRegClass::FPR32 if is_fmov_imm32(bits as u32) => {
+                self.emit(FMov32I::new(MirOP::FMov32I, dst.into(), src))
+            }
+            MirRegClass::FPR64 => {
+                let tmp = self.new_vreg(MirRegClass::GPR64);
+                let inst = MirLdImmF64::new(MirOP::MirLdImmF64, dst.into(), tmp.into(), src);
+                self.emit(inst)
+            }
+            MirRegClass::FPR32 => {
+                let tmp = self.new_vreg(MirRegClass::GPR64);
+                let inst = MirLdImmF32::new(MirOP::MirLdImmF32, dst.into(), tmp.into(), src);
+                self.emit(inst)
+            }
+            _ => panic!("{class} is not a floating-point register class"),
+        }
+        dst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_imm_extend() {
+        assert_eq!(sext_imm(0xff, 8), -1);
+        assert_eq!(sext_imm(0x7f, 8), 127);
+        assert_eq!(sext_imm(0x8000_0000, 32), i32::MIN as i64);
+        assert_eq!(sext_imm(u64::MAX, 64), -1);
+        assert_eq!(zext_imm(u64::MAX, 1), 1);
+        assert_eq!(zext_imm(0x1_2345_6789, 32), 0x2345_6789);
+    }
+}
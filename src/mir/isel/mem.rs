@@ -0,0 +1,516 @@
+//! 栈槽分配、地址计算和访存指令的选择.
+
+use super::{
+    FuncISel, MirISelErr, MirISelRes,
+    imm::{const_uint, sext_imm},
+    is_aggr,
+};
+use crate::{
+    ir::{inst::*, *},
+    mir::{
+        Adr, Bin64RL, Bin64RSym, LoadF32Base, LoadF32BaseS, LoadF64Base, LoadF64BaseS,
+        LoadF128Base, LoadF128BaseS, LoadGr32Base, LoadGr32BaseS, LoadGr64Base, LoadGr64BaseS,
+        MirDynAlloca, MirGEP, MirGlobalID, MirOP, MirOperand, MirOperandKind, MirRegClass,
+        MirStackSlot, MirStackSlotKind, PReg, StackSlotID, StoreF32Base, StoreF32BaseS,
+        StoreF64Base, StoreF64BaseS, StoreF128Base, StoreF128BaseS, StoreGr32Base, StoreGr32BaseS,
+        StoreGr64Base, StoreGr64BaseS, VReg,
+    },
+    typing::{IValType, ValTypeID},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AddrBase {
+    Reg(VReg),
+    Stack(StackSlotID),
+    Global(MirGlobalID),
+}
+
+/// 选择过程中的地址: `base + offset`. 栈槽和全局量的地址在用到时才物化.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct MemAddr {
+    pub base: AddrBase,
+    pub offset: i64,
+}
+
+impl MemAddr {
+    pub fn add(self, offset: i64) -> Self {
+        Self { base: self.base, offset: self.offset + offset }
+    }
+}
+
+/// 访存宽度. 整数按字节数选择, 寄存器类由目的/源寄存器决定.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemWidth {
+    B,
+    H,
+    W,
+    X,
+    S,
+    D,
+    Q,
+}
+
+impl MemWidth {
+    fn new(class: MirRegClass, size: usize) -> Self {
+        match (class, size) {
+            (MirRegClass::FPR32, _) => MemWidth::S,
+            (MirRegClass::FPR64, _) => MemWidth::D,
+            (MirRegClass::FPR128, _) => MemWidth::Q,
+            (_, 1) => MemWidth::B,
+            (_, 2) => MemWidth::H,
+            (MirRegClass::GPR32, _) => MemWidth::W,
+            _ => MemWidth::X,
+        }
+    }
+    /// 非符号偏移量所用的立即数类型. 按字访问的规则对更窄的访问也成立.
+    fn offset_kind(self) -> MirOperandKind {
+        match self {
+            MemWidth::Q => MirOperandKind::ImmLSP128,
+            MemWidth::X | MemWidth::D => MirOperandKind::ImmLSP64,
+            _ => MirOperandKind::ImmLSP32,
+        }
+    }
+    fn load_op(self, symbolic: bool) -> MirOP {
+        use MirOP::*;
+        match (self, symbolic) {
+            (MemWidth::B, false) => LdrBGr32Base,
+            (MemWidth::H, false) => LdrHGr32Base,
+            (MemWidth::W, false) => LdrGr32Base,
+            (MemWidth::X, false) => LdrGr64Base,
+            (MemWidth::S, false) => LdrF32Base,
+            (MemWidth::D, false) => LdrF64Base,
+            (MemWidth::Q, false) => LdrF128Base,
+            (MemWidth::B, true) => LdrBGr32BaseS,
+            (MemWidth::H, true) => LdrHGr32BaseS,
+            (MemWidth::W, true) => LdrGr32BaseS,
+            (MemWidth::X, true) => LdrGr64BaseS,
+            (MemWidth::S, true) => LdrF32BaseS,
+            (MemWidth::D, true) => LdrF64BaseS,
+            (MemWidth::Q, true) => LdrF128BaseS,
+        }
+    }
+    fn store_op(self, symbolic: bool) -> MirOP {
+        use MirOP::*;
+        match (self, symbolic) {
+            (MemWidth::B, false) => StrBGr32Base,
+            (MemWidth::H, false) => StrHGr32Base,
+            (MemWidth::W, false) => StrGr32Base,
+            (MemWidth::X, false) => StrGr64Base,
+            (MemWidth::S, false) => StrF32Base,
+            (MemWidth::D, false) => StrF64Base,
+            (MemWidth::Q, false) => StrF128Base,
+            (MemWidth::B, true) => StrBGr32BaseS,
+            (MemWidth::H, true) => StrHGr32BaseS,
+            (MemWidth::W, true) => StrGr32BaseS,
+            (MemWidth::X, true) => StrGr64BaseS,
+            (MemWidth::S, true) => StrF32BaseS,
+            (MemWidth::D, true) => StrF64BaseS,
+            (MemWidth::Q, true) => StrF128BaseS,
+        }
+    }
+}
+
+impl FuncISel<'_> {
+    /// 静态 `alloca` 对应的栈槽, 第一次访问时分配.
+    pub(super) fn alloca_slot(&mut self, alloca: AllocaInstID) -> StackSlotID {
+        if let Some(&slot) = self.allocas.get(&alloca.raw_into()) {
+            return slot;
+        }
+        let (allocs, tctx) = (&self.ir.allocs, &self.ir.tctx);
+        let ty = alloca.get_pointee_ty(allocs);
+        let slot = MirStackSlot {
+            kind: MirStackSlotKind::Local,
+            size: ty.get_aligned_size(tctx) as u64,
+            align_log2: ty.get_align_log2(tctx).max(alloca.get_align_log2(allocs)),
+        };
+        self.stack_slots.push(slot);
+        let id = StackSlotID(self.stack_slots.len() as u32 - 1);
+        self.allocas.insert(alloca.raw_into(), id);
+        id
+    }
+
+    /// 给一个复合类型的值分配栈槽. 大小向上取整到 8 字节, 这样按寄存器整段读写也不会越界.
+    pub(super) fn new_aggr_slot(&mut self, ty: ValTypeID) -> MemAddr {
+        let tctx = &self.ir.tctx;
+        let slot = MirStackSlot {
+            kind: MirStackSlotKind::Local,
+            size: (ty.get_aligned_size(tctx) as u64).next_multiple_of(8),
+            align_log2: ty.get_align_log2(tctx).max(3),
+        };
+        self.stack_slots.push(slot);
+        let id = StackSlotID(self.stack_slots.len() as u32 - 1);
+        MemAddr { base: AddrBase::Stack(id), offset: 0 }
+    }
+    /// 复合类型的值所在的内存. 指令的结果第一次访问时分配栈槽, 由定义它的指令写入.
+    pub(super) fn aggr_addr(&mut self, val: ValueSSA) -> MirISelRes<MemAddr> {
+        if let Some(&addr) = self.aggrs.get(&val) {
+            return Ok(addr);
+        }
+        let ValueSSA::Inst(inst) = val else {
+            return Err(MirISelErr::UnsupportedValue(val));
+        };
+        let addr = self.new_aggr_slot(inst.get_valtype(&self.ir.allocs));
+        self.aggrs.insert(val, addr);
+        Ok(addr)
+    }
+    /// 以物理寄存器 `base` (`sp` 或 `x29`) 为基址的地址. 基址先复制到虚拟寄存器里.
+    pub(super) fn frame_addr(&self, base: PReg, offset: i64) -> MemAddr {
+        let base_reg = self.new_vreg(MirRegClass::GPR64);
+        self.emit_copy(base_reg.into(), base.into());
+        MemAddr { base: AddrBase::Reg(base_reg), offset }
+    }
+
+    /// 从 `addr` 读取 `size` 字节到一个 `class` 类的寄存器. 通用寄存器按不小于 `size` 的宽度读取.
+    pub(super) fn load_part(&self, class: MirRegClass, addr: MemAddr, size: u32) -> VReg {
+        let rd = self.new_vreg(class);
+        let width = MemWidth::new(class, (size as usize).next_power_of_two());
+        let (rn, rm) = self.mem_operands(addr, width);
+        self.emit_load(width, rd.into(), rn, rm);
+        rd
+    }
+    /// 把 `src` 的低 `size` 字节写到 `addr`. 通用寄存器按不小于 `size` 的宽度写入.
+    pub(super) fn store_part(&self, src: VReg, addr: MemAddr, size: u32) {
+        let width = MemWidth::new(src.get_class(), (size as usize).next_power_of_two());
+        let (rn, rm) = self.mem_operands(addr, width);
+        self.emit_store(width, src.into(), rn, rm);
+    }
+    /// 把 `size` 字节从 `src` 复制到 `dst`, 每次搬运 8、4、2 或 1 个字节.
+    pub(super) fn copy_mem(&self, dst: MemAddr, src: MemAddr, size: u64) {
+        let mut offset = 0;
+        while offset < size {
+            let chunk = [8, 4, 2, 1]
+                .into_iter()
+                .find(|&n| n <= size - offset)
+                .unwrap();
+            let class = if chunk == 8 { MirRegClass::GPR64 } else { MirRegClass::GPR32 };
+            let reg = self.load_part(class, src.add(offset as i64), chunk as u32);
+            self.store_part(reg, dst.add(offset as i64), chunk as u32);
+            offset += chunk;
+        }
+    }
+
+    /// 动态 `alloca`: 每次执行都在 `sp` 之下分配新的内存.
+    pub(super) fn select_dyn_alloca(&mut self, alloca: AllocaInstID) -> MirISelRes {
+        let (allocs, tctx) = (&self.ir.allocs, &self.ir.tctx);
+        let ty = alloca.get_pointee_ty(allocs);
+        let align_log2 = ty.get_align_log2(tctx).max(alloca.get_align_log2(allocs));
+        let size = self.materialize_int(ty.get_aligned_size(tctx) as u64, MirRegClass::GPR64);
+        let dst = self.inst_vreg(alloca.raw_into())?;
+        let inst = MirDynAlloca::new(MirOP::MirDynAlloca, dst.into(), size.into());
+        inst.set_align_log2(align_log2);
+        self.emit(inst);
+        Ok(())
+    }
+
+    /// 把 GEP 拆成 `常量偏移 + Σ 下标 * 缩放系数`.
+    fn gep_terms(&self, gep: GEPInstID) -> (i64, Vec<(ValueSSA, u64)>) {
+        let (allocs, tctx) = (&self.ir.allocs, &self.ir.tctx);
+        let mut offset = 0i64;
+        let mut terms = Vec::new();
+        let mut parent: Option<ValTypeID> = None;
+        for (index, ty) in GEPTypeIter::new(tctx, allocs, gep) {
+            let struc = match parent {
+                Some(ValTypeID::Struct(s)) => Some(s),
+                Some(ValTypeID::StructAlias(sa)) => Some(sa.get_aliasee(tctx)),
+                _ => None,
+            };
+            if let Some(struc) = struc {
+                let field = index.as_apint().expect("struct index should be constant");
+                offset += struc.get_offset(tctx, field.as_unsigned() as usize) as i64;
+            } else {
+                let scale = ty.get_aligned_size(tctx) as u64;
+                let bits = index.get_valtype(allocs).get_size(tctx) as u32 * 8;
+                match const_uint(index) {
+                    Some(c) => {
+                        let delta = sext_imm(c, bits).wrapping_mul(scale as i64);
+                        offset = offset.wrapping_add(delta);
+                    }
+                    None => terms.push((index, scale)),
+                }
+            }
+            parent = Some(ty);
+        }
+        (offset, terms)
+    }
+    /// 下标全是常量的 GEP 相对于基址的偏移量. 这样的 GEP 折叠进使用它的指令.
+    pub(super) fn const_gep_offset(&self, gep: GEPInstID) -> Option<i64> {
+        let (offset, terms) = self.gep_terms(gep);
+        terms.is_empty().then_some(offset)
+    }
+
+    /// 结果不占寄存器, 而是折叠进使用处的地址计算: 静态 `alloca` 和下标全是常量的 GEP.
+    pub(super) fn is_folded_addr(&self, inst: InstID) -> bool {
+        match inst.get_opcode(&self.ir.allocs) {
+            Opcode::Alloca => true,
+            Opcode::IndexPtr => self.const_gep_offset(GEPInstID::raw_from(inst)).is_some(),
+            _ => false,
+        }
+    }
+
+    /// 折叠掉的地址属于某个 Phi 等价类时, 仍然要在定义处把地址写进等价类的寄存器.
+    pub(super) fn def_folded_addr(&mut self, inst: InstID) -> MirISelRes {
+        let Some(&dst) = self.vregs.get(&inst) else {
+            return Ok(());
+        };
+        let addr = self.value_addr(ValueSSA::Inst(inst))?;
+        let src = self.addr_reg(addr);
+        self.emit_copy(dst.into(), src.into());
+        Ok(())
+    }
+
+    /// 指针值对应的地址. 全局量、栈槽和常量偏移不立即物化.
+    pub(super) fn value_addr(&mut self, val: ValueSSA) -> MirISelRes<MemAddr> {
+        let allocs = &self.ir.allocs;
+        let base = match val {
+            ValueSSA::Global(g) => {
+                if let Some(var) = GlobalVarID::try_from_global(allocs, g)
+                    && var.get_tls_model(allocs).is_some()
+                {
+                    return Err(MirISelErr::TLSAccess(var.clone_name(allocs)));
+                }
+                AddrBase::Global(self.globals[&g])
+            }
+            ValueSSA::Inst(inst) if inst.get_opcode(allocs) == Opcode::Alloca => {
+                AddrBase::Stack(self.alloca_slot(AllocaInstID::raw_from(inst)))
+            }
+            ValueSSA::Inst(inst) if inst.get_opcode(allocs) == Opcode::IndexPtr => {
+                let gep = GEPInstID::raw_from(inst);
+                match self.const_gep_offset(gep) {
+                    Some(offset) => {
+                        let base = self.value_addr(gep.get_base(allocs))?;
+                        return Ok(MemAddr { base: base.base, offset: base.offset + offset });
+                    }
+                    None => AddrBase::Reg(self.inst_vreg(inst)?),
+                }
+            }
+            _ => AddrBase::Reg(self.value_reg(val)?),
+        };
+        Ok(MemAddr { base, offset: 0 })
+    }
+
+    /// 把地址物化到一个 64 位通用寄存器里.
+    pub(super) fn addr_reg(&self, addr: MemAddr) -> VReg {
+        let base = match addr.base {
+            AddrBase::Reg(reg) => reg,
+            AddrBase::Stack(slot) => {
+                let dst = self.new_vreg(MirRegClass::GPR64);
+                let (sp, sym) = (PReg::SP.into(), MirOperand::StackSlot(slot));
+                self.emit(Bin64RSym::new(MirOP::Add64Sym, dst.into(), sp, sym));
+                dst
+            }
+            AddrBase::Global(global) => {
+                let page = self.global_page(global);
+                let dst = self.new_vreg(MirRegClass::GPR64);
+                let sym = MirOperand::Global(global);
+                self.emit(Bin64RSym::new(
+                    MirOP::Add64Sym,
+                    dst.into(),
+                    page.into(),
+                    sym,
+                ));
+                dst
+            }
+        };
+        if addr.offset == 0 {
+            return base;
+        }
+        let dst = self.new_vreg(MirRegClass::GPR64);
+        self.emit_add_imm(dst, base, addr.offset);
+        dst
+    }
+    /// `adrp`: 全局量所在的 4KB 页的地址.
+    fn global_page(&self, global: MirGlobalID) -> VReg {
+        let page = self.new_vreg(MirRegClass::GPR64);
+        self.emit(Adr::new(
+            MirOP::AdrP,
+            page.into(),
+            MirOperand::Global(global),
+        ));
+        page
+    }
+    /// 访存指令的基址和偏移量操作数.
+    /// 偏移为 0 的栈槽和全局量使用符号偏移量, 其余情况尽量把偏移量编码进立即数字段.
+    fn mem_operands(&self, addr: MemAddr, width: MemWidth) -> (MirOperand, MirOperand) {
+        match addr.base {
+            AddrBase::Stack(slot) if addr.offset == 0 => {
+                return (PReg::SP.into(), MirOperand::StackSlot(slot));
+            }
+            AddrBase::Global(global) if addr.offset == 0 => {
+                return (self.global_page(global).into(), MirOperand::Global(global));
+            }
+            _ => {}
+        }
+        let offset = MirOperand::Imm(addr.offset);
+        if width.offset_kind().accepts(offset) {
+            let base = self.addr_reg(MemAddr { base: addr.base, offset: 0 });
+            (base.into(), offset)
+        } else {
+            (self.addr_reg(addr).into(), MirOperand::Imm(0))
+        }
+    }
+
+    /// 值在访存指令中使用的寄存器视图: 32 位指针按 32 位整数读写.
+    fn mem_view(&self, reg: VReg, ty: ValTypeID) -> VReg {
+        match ty {
+            ValTypeID::Ptr | ValTypeID::Int(_) => self.int_view(reg, ty).0,
+            _ => reg,
+        }
+    }
+
+    pub(super) fn select_gep(&mut self, gep: GEPInstID) -> MirISelRes {
+        let (offset, terms) = self.gep_terms(gep);
+        if terms.is_empty() {
+            // 在使用处折叠.
+            return self.def_folded_addr(gep.raw_into());
+        }
+        let allocs = &self.ir.allocs;
+        let dst = self.inst_vreg(gep.raw_into())?;
+        let base = self.value_addr(gep.get_base(allocs))?;
+        let base_reg = self.addr_reg(MemAddr { base: base.base, offset: 0 });
+        let mut indices = Vec::with_capacity(terms.len());
+        for (index, scale) in terms {
+            let reg = self.value_reg(index)?;
+            let bits = self.value_bits(index.get_valtype(&self.ir.allocs));
+            indices.push((self.sext_to_64(reg, bits).into(), scale));
+        }
+        let offset = offset.wrapping_add(base.offset);
+        if self.ptr_nbits() == 64 {
+            self.emit(MirGEP::new(dst.into(), base_reg.into(), offset, &indices));
+        } else {
+            // 32 位指针的地址计算按 2^32 取模.
+            let tmp = self.new_vreg(MirRegClass::GPR64);
+            self.emit(MirGEP::new(tmp.into(), base_reg.into(), offset, &indices));
+            let mask = MirOperand::Imm(u32::MAX as i64);
+            self.emit(Bin64RL::new(MirOP::And64I, dst.into(), tmp.into(), mask));
+        }
+        Ok(())
+    }
+
+    pub(super) fn select_load(&mut self, load: LoadInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let ty = load.get_rettype(allocs);
+        if is_aggr(ty) {
+            let dst = self.aggr_addr(ValueSSA::Inst(load.raw_into()))?;
+            let src = self.value_addr(load.get_source(allocs))?;
+            self.copy_mem(dst, src, ty.get_size(&self.ir.tctx) as u64);
+            return Ok(());
+        }
+        let dst = self.inst_vreg(load.raw_into())?;
+        let addr = self.value_addr(load.get_source(allocs))?;
+        let rd = self.mem_view(dst, ty);
+        let width = MemWidth::new(rd.get_class(), ty.get_size(&self.ir.tctx));
+        let (rn, rm) = self.mem_operands(addr, width);
+        self.emit_load(width, rd.into(), rn, rm);
+        Ok(())
+    }
+
+    pub(super) fn select_store(&mut self, store: StoreInstID) -> MirISelRes {
+        let allocs = &self.ir.allocs;
+        let ty = store.source_ty(allocs);
+        let val = store.get_source(allocs);
+        if is_aggr(ty) {
+            let src = self.aggr_addr(val)?;
+            let dst = self.value_addr(store.get_target(allocs))?;
+            self.copy_mem(dst, src, ty.get_size(&self.ir.tctx) as u64);
+            return Ok(());
+        }
+        let class = self.reg_class(ty)?;
+        let size = ty.get_size(&self.ir.tctx);
+        let src = if class.is_gpr() && const_uint(val) == Some(0) {
+            let zr = PReg::new(PReg::ZR_NUM, class);
+            self.mem_view_preg(zr, ty)
+        } else {
+            let reg = self.value_reg(val)?;
+            let reg = self.mem_view(reg, ty);
+            // 位宽不是整字节的整数 (比如 `i1`) 要先清掉多余的高位.
+            let reg = match ty {
+                ValTypeID::Int(bits) if (bits as usize) < size * 8 => {
+                    self.ext_reg(reg, bits as u32, false)
+                }
+                _ => reg,
+            };
+            reg.into()
+        };
+        let addr = self.value_addr(store.get_target(allocs))?;
+        let width = MemWidth::new(src.get_reg_class().unwrap(), size);
+        let (rn, rm) = self.mem_operands(addr, width);
+        self.emit_store(width, src, rn, rm);
+        Ok(())
+    }
+    fn mem_view_preg(&self, reg: PReg, ty: ValTypeID) -> MirOperand {
+        if ty == ValTypeID::Ptr && self.ptr_nbits() == 32 {
+            reg.with_class(MirRegClass::GPR32).into()
+        } else {
+            reg.into()
+        }
+    }
+
+    /// 按 `rd` 的寄存器类读取 `[rn, rm]`. `rm` 是符号时选用符号偏移量的形式.
+    fn emit_load(&self, width: MemWidth, rd: MirOperand, rn: MirOperand, rm: MirOperand) {
+        let symbolic = rm.is_symbol();
+        let op = width.load_op(symbolic);
+        match (rd.get_reg_class().unwrap(), symbolic) {
+            (MirRegClass::GPR64, false) => self.emit(LoadGr64Base::new(op, rd, rn, rm)),
+            (MirRegClass::GPR64, true) => self.emit(LoadGr64BaseS::new(op, rd, rn, rm)),
+            (MirRegClass::GPR32, false) => self.emit(LoadGr32Base::new(op, rd, rn, rm)),
+            (MirRegClass::GPR32, true) => self.emit(LoadGr32BaseS::new(op, rd, rn, rm)),
+            (MirRegClass::FPR128, false) => self.emit(LoadF128Base::new(op, rd, rn, rm)),
+            (MirRegClass::FPR128, true) => self.emit(LoadF128BaseS::new(op, rd, rn, rm)),
+            (MirRegClass::FPR64, false) => self.emit(LoadF64Base::new(op, rd, rn, rm)),
+            (MirRegClass::FPR64, true) => self.emit(LoadF64BaseS::new(op, rd, rn, rm)),
+            (MirRegClass::FPR32, false) => self.emit(LoadF32Base::new(op, rd, rn, rm)),
+            (MirRegClass::FPR32, true) => self.emit(LoadF32BaseS::new(op, rd, rn, rm)),
+            (MirRegClass::PState, _) => unreachable!("cannot load into NZCV"),
+        }
+    }
+    /// 按 `src` 的寄存器类把它写到 `[rn, rm]`.
+    fn emit_store(&self, width: MemWidth, src: MirOperand, rn: MirOperand, rm: MirOperand) {
+        let symbolic = rm.is_symbol();
+        let op = width.store_op(symbolic);
+        match (src.get_reg_class().unwrap(), symbolic) {
+            (MirRegClass::GPR64, false) => self.emit(StoreGr64Base::new(op, src, rn, rm)),
+            (MirRegClass::GPR64, true) => self.emit(StoreGr64BaseS::new(op, src, rn, rm)),
+            (MirRegClass::GPR32, false) => self.emit(StoreGr32Base::new(op, src, rn, rm)),
+            (MirRegClass::GPR32, true) => self.emit(StoreGr32BaseS::new(op, src, rn, rm)),
+            (MirRegClass::FPR128, false) => self.emit(StoreF128Base::new(op, src, rn, rm)),
+            (MirRegClass::FPR128, true) => self.emit(StoreF128BaseS::new(op, src, rn, rm)),
+            (MirRegClass::FPR64, false) => self.emit(StoreF64Base::new(op, src, rn, rm)),
+            (MirRegClass::FPR64, true) => self.emit(StoreF64BaseS::new(op, src, rn, rm)),
+            (MirRegClass::FPR32, false) => self.emit(StoreF32Base::new(op, src, rn, rm)),
+            (MirRegClass::FPR32, true) => self.emit(StoreF32BaseS::new(op, src, rn, rm)),
+            (MirRegClass::PState, _) => unreachable!("cannot store NZCV"),
+        }
+    }
+
+    /// 以物理寄存器 `base` 为基址、偏移量为 `offset` 的整字访存操作数. 偏移量放不进指令时先算出地址.
+    fn frame_mem_operands(
+        &self,
+        base: PReg,
+        offset: i64,
+        width: MemWidth,
+    ) -> (MirOperand, MirOperand) {
+        let imm = MirOperand::Imm(offset);
+        if width.offset_kind().accepts(imm) {
+            return (base.into(), imm);
+        }
+        let base_reg = self.new_vreg(MirRegClass::GPR64);
+        self.emit_copy(base_reg.into(), base.into());
+        let addr = self.new_vreg(MirRegClass::GPR64);
+        self.emit_add_imm(addr, base_reg, offset);
+        (addr.into(), MirOperand::Imm(0))
+    }
+    /// 读取栈上传入的参数. `offset` 相对于调用者的 `sp`, 也就是本函数的 `x29 + 16`.
+    pub(super) fn load_stack_arg(&self, rd: VReg, offset: u32) {
+        let class = rd.get_class();
+        let width = MemWidth::new(class, class.get_bits() as usize / 8);
+        let (rn, rm) = self.frame_mem_operands(PReg::FP, 16 + offset as i64, width);
+        self.emit_load(width, rd.into(), rn, rm);
+    }
+    /// 把实参写到出参区中偏移量为 `offset` 的位置.
+    pub(super) fn store_stack_arg(&self, src: VReg, offset: u32) {
+        let class = src.get_class();
+        let width = MemWidth::new(class, class.get_bits() as usize / 8);
+        let (rn, rm) = self.frame_mem_operands(PReg::SP, offset as i64, width);
+        self.emit_store(width, src.into(), rn, rm);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    SymbolStr,
+    mir::{MirBlockAlloc, MirFunc, MirGlobal, MirGlobalID, MirInstAlloc},
+};
+use mtb_entity_slab::EntityAlloc;
+use std::collections::HashMap;
+
+pub struct MirAllocs {
+    pub insts: MirInstAlloc,
+    pub blocks: MirBlockAlloc,
+}
+
+impl Default for MirAllocs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MirAllocs {
+    pub fn new() -> Self {
+        Self { insts: EntityAlloc::new(), blocks: EntityAlloc::new() }
+    }
+}
+
+pub struct MirModule {
+    pub name: SymbolStr,
+    pub allocs: MirAllocs,
+    pub globals: Vec<MirGlobal>,
+    symtab: HashMap<SymbolStr, MirGlobalID>,
+}
+
+impl MirModule {
+    pub fn new(name: impl Into<SymbolStr>) -> Self {
+        Self {
+            name: name.into(),
+            allocs: MirAllocs::new(),
+            globals: Vec::new(),
+            symtab: HashMap::new(),
+        }
+    }
+
+    /// 加入一个全局符号. 同名符号已经存在时返回已有符号的编号和被拒绝的符号.
+    pub fn add_global(
+        &mut self,
+        global: MirGlobal,
+    ) -> Result<MirGlobalID, (MirGlobalID, MirGlobal)> {
+        if let Some(&id) = self.symtab.get(global.get_name()) {
+            return Err((id, global));
+        }
+        let id = MirGlobalID(self.globals.len() as u32);
+        self.symtab.insert(global.get_name().clone(), id);
+        if let MirGlobal::Func(func) = &global
+            && let Some(blocks) = &func.blocks
+        {
+            blocks
+                .forall_with_sentinel(&self.allocs.blocks, |_, bb| {
+                    bb.parent_func.set(Some(id));
+                    Ok(())
+                })
+                .expect("Failed to initialize MirFunc block list");
+        }
+        self.globals.push(global);
+        Ok(id)
+    }
+    pub fn add_func(&mut self, func: MirFunc) -> MirGlobalID {
+        match self.add_global(MirGlobal::Func(Box::new(func))) {
+            Ok(id) => id,
+            Err((_, func)) => panic!("Duplicated MIR symbol `{}`", func.get_name()),
+        }
+    }
+
+    pub fn get_global(&self, id: MirGlobalID) -> &MirGlobal {
+        &self.globals[id.get_index()]
+    }
+    pub fn try_get_global(&self, id: MirGlobalID) -> Option<&MirGlobal> {
+        self.globals.get(id.get_index())
+    }
+    pub fn get_global_by_name(&self, name: &str) -> Option<MirGlobalID> {
+        self.symtab.get(name).copied()
+    }
+    pub fn get_func(&self, id: MirGlobalID) -> &MirFunc {
+        self.get_global(id)
+            .as_func()
+            .unwrap_or_else(|| panic!("MIR global {id:?} is not a function"))
+    }
+    pub fn get_func_mut(&mut self, id: MirGlobalID) -> &mut MirFunc {
+        self.globals[id.get_index()]
+            .as_func_mut()
+            .unwrap_or_else(|| panic!("MIR global {id:?} is not a function"))
+    }
+    pub fn globals_iter(&self) -> impl Iterator<Item = (MirGlobalID, &MirGlobal)> {
+        let ids = (0..self.globals.len() as u32).map(MirGlobalID);
+        ids.zip(self.globals.iter())
+    }
+    pub fn funcs_iter(&self) -> impl Iterator<Item = (MirGlobalID, &MirFunc)> {
+        self.globals_iter()
+            .filter_map(|(id, g)| g.as_func().map(|f| (id, f)))
+    }
+}
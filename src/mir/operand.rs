@@ -0,0 +1,438 @@
+//! MIR 操作数: 寄存器、立即数和各种符号引用.
+
+use crate::mir::{MirBlockID, MirGlobalID, imm};
+use std::fmt::{Display, Formatter};
+
+/// 寄存器类. 与 RIG 语法里的寄存器操作数类型一一对应.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MirRegClass {
+    GPR32,
+    GPR64,
+    FPR32,
+    FPR64,
+    FPR128,
+    PState,
+}
+
+/// 寄存器堆. `w0` 和 `x0` 是同一个物理寄存器的两种视图, 属于同一个寄存器堆.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MirRegBank {
+    GPR,
+    FPR,
+    PState,
+}
+
+impl MirRegClass {
+    pub fn get_name(self) -> &'static str {
+        match self {
+            MirRegClass::GPR32 => "gpr32",
+            MirRegClass::GPR64 => "gpr64",
+            MirRegClass::FPR32 => "fpr32",
+            MirRegClass::FPR64 => "fpr64",
+            MirRegClass::FPR128 => "fpr128",
+            MirRegClass::PState => "pstate",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        let class = match name {
+            "gpr32" => MirRegClass::GPR32,
+            "gpr64" => MirRegClass::GPR64,
+            "fpr32" => MirRegClass::FPR32,
+            "fpr64" => MirRegClass::FPR64,
+            "fpr128" => MirRegClass::FPR128,
+            "pstate" => MirRegClass::PState,
+            _ => return None,
+        };
+        Some(class)
+    }
+
+    pub fn get_bank(self) -> MirRegBank {
+        match self {
+            MirRegClass::GPR32 | MirRegClass::GPR64 => MirRegBank::GPR,
+            MirRegClass::FPR32 | MirRegClass::FPR64 | MirRegClass::FPR128 => MirRegBank::FPR,
+            MirRegClass::PState => MirRegBank::PState,
+        }
+    }
+    /// 寄存器位宽. `PState` 只有 NZCV 四个标志位.
+    pub fn get_bits(self) -> u8 {
+        match self {
+            MirRegClass::GPR32 | MirRegClass::FPR32 => 32,
+            MirRegClass::GPR64 | MirRegClass::FPR64 => 64,
+            MirRegClass::FPR128 => 128,
+            MirRegClass::PState => 4,
+        }
+    }
+    pub fn is_gpr(self) -> bool {
+        self.get_bank() == MirRegBank::GPR
+    }
+    pub fn is_fpr(self) -> bool {
+        self.get_bank() == MirRegBank::FPR
+    }
+}
+
+impl Display for MirRegClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.get_name())
+    }
+}
+
+/// 虚拟寄存器. 编号在函数内唯一, 由 `MirFunc::new_vreg` 分配.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VReg {
+    id: u32,
+    class: MirRegClass,
+}
+
+impl VReg {
+    pub fn new(id: u32, class: MirRegClass) -> Self {
+        Self { id, class }
+    }
+    pub fn get_id(self) -> u32 {
+        self.id
+    }
+    pub fn get_class(self) -> MirRegClass {
+        self.class
+    }
+    /// 同一个虚拟寄存器换一个位宽视图, 例如把 64 位寄存器当作 32 位使用.
+    pub fn with_class(self, class: MirRegClass) -> Self {
+        assert_eq!(
+            self.class.get_bank(),
+            class.get_bank(),
+            "cannot view {self} as a register of another bank"
+        );
+        Self { id: self.id, class }
+    }
+}
+
+impl Display for VReg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "%v{}:{}", self.id, self.class)
+    }
+}
+
+/// AArch64 物理寄存器.
+///
+/// 通用寄存器编号 0..=30 对应 `x0`..`x30`, 31 是零寄存器 `xzr`, 32 是栈指针 `sp`.
+/// 硬件编码里零寄存器和栈指针都是 31, 这里把它们分开以便区分 `GPR` 和 `GSP` 操作数.
+/// 浮点寄存器编号 0..=31, `PState` 只有一个寄存器 `nzcv`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PReg {
+    num: u8,
+    class: MirRegClass,
+}
+
+impl PReg {
+    pub const ZR_NUM: u8 = 31;
+    pub const SP_NUM: u8 = 32;
+
+    pub const FP: PReg = PReg::x(29);
+    pub const LR: PReg = PReg::x(30);
+    pub const XZR: PReg = PReg::x(Self::ZR_NUM);
+    pub const WZR: PReg = PReg::w(Self::ZR_NUM);
+    pub const SP: PReg = PReg::x(Self::SP_NUM);
+    pub const WSP: PReg = PReg::w(Self::SP_NUM);
+    pub const NZCV: PReg = PReg { num: 0, class: MirRegClass::PState };
+
+    pub const fn x(num: u8) -> Self {
+        assert!(num <= Self::SP_NUM);
+        Self { num, class: MirRegClass::GPR64 }
+    }
+    pub const fn w(num: u8) -> Self {
+        assert!(num <= Self::SP_NUM);
+        Self { num, class: MirRegClass::GPR32 }
+    }
+    pub const fn d(num: u8) -> Self {
+        assert!(num < 32);
+        Self { num, class: MirRegClass::FPR64 }
+    }
+    pub const fn q(num: u8) -> Self {
+        assert!(num < 32);
+        Self { num, class: MirRegClass::FPR128 }
+    }
+    pub const fn s(num: u8) -> Self {
+        assert!(num < 32);
+        Self { num, class: MirRegClass::FPR32 }
+    }
+    pub fn new(num: u8, class: MirRegClass) -> Self {
+        match class {
+            MirRegClass::GPR32 => Self::w(num),
+            MirRegClass::GPR64 => Self::x(num),
+            MirRegClass::FPR32 => Self::s(num),
+            MirRegClass::FPR64 => Self::d(num),
+            MirRegClass::FPR128 => Self::q(num),
+            MirRegClass::PState => {
+                assert_eq!(num, 0, "PState has only one register");
+                Self::NZCV
+            }
+        }
+    }
+
+    pub fn get_num(self) -> u8 {
+        self.num
+    }
+    pub fn get_class(self) -> MirRegClass {
+        self.class
+    }
+    pub fn with_class(self, class: MirRegClass) -> Self {
+        assert_eq!(
+            self.class.get_bank(),
+            class.get_bank(),
+            "cannot view {self} as a register of another bank"
+        );
+        Self { num: self.num, class }
+    }
+
+    pub fn is_sp(self) -> bool {
+        self.class.is_gpr() && self.num == Self::SP_NUM
+    }
+    pub fn is_zr(self) -> bool {
+        self.class.is_gpr() && self.num == Self::ZR_NUM
+    }
+
+    pub fn get_name(self) -> String {
+        match (self.class, self.num) {
+            (MirRegClass::GPR64, Self::SP_NUM) => "sp".into(),
+            (MirRegClass::GPR32, Self::SP_NUM) => "wsp".into(),
+            (MirRegClass::GPR64, Self::ZR_NUM) => "xzr".into(),
+            (MirRegClass::GPR32, Self::ZR_NUM) => "wzr".into(),
+            (MirRegClass::GPR64, n) => format!("x{n}"),
+            (MirRegClass::GPR32, n) => format!("w{n}"),
+            (MirRegClass::FPR64, n) => format!("d{n}"),
+            (MirRegClass::FPR128, n) => format!("q{n}"),
+            (MirRegClass::FPR32, n) => format!("s{n}"),
+            (MirRegClass::PState, _) => "nzcv".into(),
+        }
+    }
+}
+
+impl Display for PReg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "${}", self.get_name())
+    }
+}
+
+/// 函数内跳转表的编号, 下标即 `MirFunc::switch_tabs` 中的位置.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MirSwitchTabID(pub u32);
+
+/// 函数内栈槽的编号, 下标即 `MirFunc::stack_slots` 中的位置.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StackSlotID(pub u32);
+
+impl MirSwitchTabID {
+    pub fn get_index(self) -> usize {
+        self.0 as usize
+    }
+}
+impl StackSlotID {
+    pub fn get_index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MirOperand {
+    /// 空操作数. 只在构造指令时临时使用, 校验器不接受它.
+    #[default]
+    None,
+    VReg(VReg),
+    PReg(PReg),
+    /// 整数立即数. 浮点立即数按位模式存放.
+    Imm(i64),
+    Label(MirBlockID),
+    Global(MirGlobalID),
+    SwitchTab(MirSwitchTabID),
+    /// 栈槽地址相对于栈指针的偏移量. 栈帧布局确定之前只能以符号形式出现.
+    StackSlot(StackSlotID),
+}
+
+impl From<VReg> for MirOperand {
+    fn from(vreg: VReg) -> Self {
+        MirOperand::VReg(vreg)
+    }
+}
+impl From<PReg> for MirOperand {
+    fn from(preg: PReg) -> Self {
+        MirOperand::PReg(preg)
+    }
+}
+
+impl MirOperand {
+    pub fn is_none(self) -> bool {
+        matches!(self, MirOperand::None)
+    }
+    pub fn is_reg(self) -> bool {
+        matches!(self, MirOperand::VReg(_) | MirOperand::PReg(_))
+    }
+    pub fn is_symbol(self) -> bool {
+        matches!(
+            self,
+            MirOperand::Label(_)
+                | MirOperand::Global(_)
+                | MirOperand::SwitchTab(_)
+                | MirOperand::StackSlot(_)
+        )
+    }
+    pub fn get_reg_class(self) -> Option<MirRegClass> {
+        match self {
+            MirOperand::VReg(v) => Some(v.get_class()),
+            MirOperand::PReg(p) => Some(p.get_class()),
+            _ => None,
+        }
+    }
+    pub fn as_vreg(self) -> Option<VReg> {
+        match self {
+            MirOperand::VReg(v) => Some(v),
+            _ => None,
+        }
+    }
+    pub fn as_preg(self) -> Option<PReg> {
+        match self {
+            MirOperand::PReg(p) => Some(p),
+            _ => None,
+        }
+    }
+    pub fn as_imm(self) -> Option<i64> {
+        match self {
+            MirOperand::Imm(imm) => Some(imm),
+            _ => None,
+        }
+    }
+    pub fn as_label(self) -> Option<MirBlockID> {
+        match self {
+            MirOperand::Label(bb) => Some(bb),
+            _ => None,
+        }
+    }
+}
+
+/// 指令类对操作数的约束, 变体与 RIG 操作数类型同名.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirOperandKind {
+    GPR32,
+    GPR64,
+    GSP32,
+    GSP64,
+    XSP,
+    WSP,
+    GPR { bits: u8, flags: &'static [&'static str] },
+
+    PState,
+    PC,
+
+    FPR32,
+    FPR64,
+    FPR128,
+    FPR { bits: u8, flags: &'static [&'static str] },
+
+    Imm32,
+    Imm64,
+    ImmCalc,
+    ImmLogic,
+    ImmSMax,
+    ImmUMax,
+    ImmShift,
+    ImmLSP32,
+    ImmLSP64,
+    ImmLSP128,
+    ImmCCmp,
+    ImmMov,
+    ImmMovZNK,
+    ImmFMov32,
+    ImmFMov64,
+
+    Label,
+    Global,
+    Symbol,
+    SwitchTab,
+    Any,
+}
+
+impl MirOperandKind {
+    /// 只接受某个寄存器类的操作数类型. 用于操作数个数不固定的指令.
+    pub fn from_reg_class(class: MirRegClass) -> Self {
+        match class {
+            MirRegClass::GPR32 => MirOperandKind::GPR32,
+            MirRegClass::GPR64 => MirOperandKind::GPR64,
+            MirRegClass::FPR32 => MirOperandKind::FPR32,
+            MirRegClass::FPR64 => MirOperandKind::FPR64,
+            MirRegClass::FPR128 => MirOperandKind::FPR128,
+            MirRegClass::PState => MirOperandKind::PState,
+        }
+    }
+
+    /// 寄存器操作数对应的寄存器类. 立即数和符号操作数返回 `None`.
+    pub fn get_reg_class(self) -> Option<MirRegClass> {
+        use MirOperandKind::*;
+        let class = match self {
+            GPR32 | GSP32 | WSP | GPR { bits: 32, .. } => MirRegClass::GPR32,
+            GPR64 | GSP64 | XSP | GPR { .. } => MirRegClass::GPR64,
+            FPR32 | FPR { bits: 32, .. } => MirRegClass::FPR32,
+            FPR128 | FPR { bits: 128, .. } => MirRegClass::FPR128,
+            FPR64 | FPR { .. } => MirRegClass::FPR64,
+            PState => MirRegClass::PState,
+            _ => return None,
+        };
+        Some(class)
+    }
+
+    /// 操作数 `op` 能否放进这个位置. 立即数的范围检查按 AArch64 的编码规则进行.
+    pub fn accepts(self, op: MirOperand) -> bool {
+        use MirOperandKind::*;
+        match (self, op) {
+            (_, MirOperand::None) => false,
+            (PC, _) => false,
+            (Any, _) => true,
+
+            (GPR32 | GPR64 | GPR { .. } | FPR32 | FPR64 | FPR128 | FPR { .. } | PState, _) => {
+                Self::accepts_reg(self, op, |p| !p.is_sp())
+            }
+            (GSP32 | GSP64, _) => Self::accepts_reg(self, op, |p| !p.is_zr()),
+            (XSP | WSP, MirOperand::PReg(p)) => {
+                p.is_sp() && Some(p.get_class()) == self.get_reg_class()
+            }
+
+            (Imm32, MirOperand::Imm(imm)) => imm >= i32::MIN as i64 && imm <= u32::MAX as i64,
+            (Imm64, MirOperand::Imm(_)) => true,
+            (ImmCalc, MirOperand::Imm(imm)) => imm::is_calc_imm(imm),
+            (ImmLogic, MirOperand::Imm(imm)) => {
+                imm::is_logic_imm(imm as u64, 64)
+                    || (imm as u64 <= u32::MAX as u64 && imm::is_logic_imm(imm as u64, 32))
+            }
+            (ImmSMax, MirOperand::Imm(imm)) => (-128..=127).contains(&imm),
+            (ImmUMax, MirOperand::Imm(imm)) => (0..=255).contains(&imm),
+            (ImmShift, MirOperand::Imm(imm)) => (0..64).contains(&imm),
+            (ImmLSP32, MirOperand::Imm(imm)) => imm::is_lsp_imm(imm, 2),
+            (ImmLSP64, MirOperand::Imm(imm)) => imm::is_lsp_imm(imm, 3),
+            (ImmLSP128, MirOperand::Imm(imm)) => imm::is_lsp_imm(imm, 4),
+            (ImmCCmp, MirOperand::Imm(imm)) => (0..32).contains(&imm),
+            (ImmMov, MirOperand::Imm(imm)) => {
+                imm::is_mov_imm(imm as u64, 64) || imm::is_mov_imm(imm as u64, 32)
+            }
+            (ImmMovZNK, MirOperand::Imm(imm)) => imm::is_movznk_imm(imm as u64),
+            (ImmFMov32, MirOperand::Imm(imm)) => {
+                imm as u64 <= u32::MAX as u64 && imm::is_fmov_imm32(imm as u32)
+            }
+            (ImmFMov64, MirOperand::Imm(imm)) => imm::is_fmov_imm64(imm as u64),
+
+            (Label, MirOperand::Label(_)) => true,
+            (Global, MirOperand::Global(_)) => true,
+            (SwitchTab, MirOperand::SwitchTab(_)) => true,
+            (Symbol, op) => op.is_symbol(),
+            _ => false,
+        }
+    }
+
+    fn accepts_reg(self, op: MirOperand, preg_ok: impl FnOnce(PReg) -> bool) -> bool {
+        let Some(class) = op.get_reg_class() else {
+            return false;
+        };
+        if Some(class) != self.get_reg_class() {
+            return false;
+        }
+        match op {
+            MirOperand::PReg(p) => preg_ok(p),
+            _ => true,
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! MIR 文本格式.
+//!
+//! ```text
+//! func @main exported {
+//!     ss0: local, size 16, align 8
+//!     jt0: default .exit, [1 => .bb1, 2 => .bb2]
+//! .entry:
+//!     %v0:gpr64 = Mov64I #42
+//!     %v1:gpr64 = Add64R %v0:gpr64, $x1 {rm_op: None}
+//!     ICmp64R %v1:gpr64, $xzr -> $nzcv {rm_op: None}
+//!     BCond .bb1, $nzcv {cond: EQ}
+//!     B .exit
+//! ...
+//! }
+//! ```
+//!
+//! 输出操作数写在 `=` 左边; 没有输出时 (例如存储和跳转) 直接写操作码.
+//! `PState` 输出操作数写在 `->` 右边, 这样比较指令读起来和汇编一样.
+
+use crate::mir::{
+    MirDataUnit, MirFunc, MirGlobal, MirGlobalID, MirGlobalVar, MirInst, MirModule, MirOperand,
+    MirRegClass, MirStackSlotKind,
+};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+pub struct MirWriter<'mir> {
+    pub module: &'mir MirModule,
+}
+
+impl<'mir> MirWriter<'mir> {
+    pub fn new(module: &'mir MirModule) -> Self {
+        Self { module }
+    }
+
+    pub fn write_module(&self, out: &mut dyn Write) -> FmtResult {
+        writeln!(out, "; MIR module `{}`", self.module.name)?;
+        for (id, _) in self.module.globals_iter() {
+            writeln!(out)?;
+            self.write_global(id, out)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_global(&self, id: MirGlobalID, out: &mut dyn Write) -> FmtResult {
+        match self.module.get_global(id) {
+            MirGlobal::Func(func) => self.write_func(func, out),
+            MirGlobal::Var(var) => self.write_var(var, out),
+        }
+    }
+
+    fn write_var(&self, var: &MirGlobalVar, out: &mut dyn Write) -> FmtResult {
+        let Some(init) = &var.init else {
+            return writeln!(out, "extern var @{}", var.name);
+        };
+        let exported = if var.exported { " exported" } else { "" };
+        write!(
+            out,
+            "var @{}{exported}: {}, align {} [",
+            var.name,
+            var.section.get_name(),
+            1u64 << var.align_log2
+        )?;
+        for (i, unit) in init.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            match unit {
+                MirDataUnit::Bytes(bytes) => {
+                    write!(out, "{sep}bytes")?;
+                    for b in bytes {
+                        write!(out, " {b:02x}")?;
+                    }
+                }
+                MirDataUnit::Zeros(size) => write!(out, "{sep}zeros {size}")?,
+                MirDataUnit::Addr(global, offset) => {
+                    write!(out, "{sep}addr ")?;
+                    self.write_global_ref(*global, out)?;
+                    if *offset != 0 {
+                        write!(out, "{offset:+}")?;
+                    }
+                }
+            }
+        }
+        writeln!(out, "]")
+    }
+
+    pub fn write_func(&self, func: &MirFunc, out: &mut dyn Write) -> FmtResult {
+        if func.is_extern() {
+            return writeln!(out, "extern func @{}", func.name);
+        }
+        let exported = if func.exported { " exported" } else { "" };
+        writeln!(out, "func @{}{exported} {{", func.name)?;
+        for (i, slot) in func.stack_slots.iter().enumerate() {
+            let kind = match slot.kind {
+                MirStackSlotKind::Local => "local",
+                MirStackSlotKind::Spill => "spill",
+            };
+            let align = 1u64 << slot.align_log2;
+            writeln!(out, "    ss{i}: {kind}, size {}, align {align}", slot.size)?;
+        }
+        let allocs = &self.module.allocs;
+        for (i, tab) in func.switch_tabs.iter().enumerate() {
+            write!(
+                out,
+                "    jt{i}: default .{}, [",
+                tab.default.get_name(allocs)
+            )?;
+            for (j, (case, bb)) in tab.cases.iter().enumerate() {
+                let sep = if j == 0 { "" } else { ", " };
+                write!(out, "{sep}{case} => .{}", bb.get_name(allocs))?;
+            }
+            writeln!(out, "]")?;
+        }
+        for (bb, _) in func.blocks_iter(allocs) {
+            writeln!(out, ".{}:", bb.get_name(allocs))?;
+            for (_, inst) in bb.insts_iter(allocs) {
+                out.write_str("    ")?;
+                self.write_inst(inst.get_inst(), out)?;
+                writeln!(out)?;
+            }
+        }
+        writeln!(out, "}}")
+    }
+
+    /// 写出一条指令, 不带缩进和换行.
+    pub fn write_inst(&self, inst: &MirInst, out: &mut dyn Write) -> FmtResult {
+        let (inst, comment) = match inst {
+            MirInst::MirComment(comment) => return write!(out, "// {}", comment.get_text()),
+            MirInst::MirCommentedInst(commented) => {
+                (commented.get_inner(), Some(commented.get_comment()))
+            }
+            inst => (inst, None),
+        };
+        let is_flag = |op: MirOperand| op.get_reg_class() == Some(MirRegClass::PState);
+        let outs: Vec<_> = inst.outs().iter().map(|o| o.get()).collect();
+        let (flag_outs, value_outs): (Vec<_>, Vec<_>) = outs.into_iter().partition(|&o| is_flag(o));
+        if !value_outs.is_empty() {
+            self.write_operand_list(&value_outs, out)?;
+            out.write_str(" = ")?;
+        }
+        write!(out, "{}", inst.get_opcode())?;
+        let ins: Vec<_> = inst.ins().iter().map(|o| o.get()).collect();
+        if !ins.is_empty() {
+            out.write_str(" ")?;
+            self.write_operand_list(&ins, out)?;
+        }
+        if !flag_outs.is_empty() {
+            out.write_str(" -> ")?;
+            self.write_operand_list(&flag_outs, out)?;
+        }
+        let mut props = String::new();
+        inst.fmt_props(&mut props)?;
+        if !props.is_empty() {
+            write!(out, " {{{props}}}")?;
+        }
+        if let Some(comment) = comment {
+            write!(out, " // {comment}")?;
+        }
+        Ok(())
+    }
+
+    fn write_operand_list(&self, operands: &[MirOperand], out: &mut dyn Write) -> FmtResult {
+        for (i, &op) in operands.iter().enumerate() {
+            if i > 0 {
+                out.write_str(", ")?;
+            }
+            self.write_operand(op, out)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_operand(&self, operand: MirOperand, out: &mut dyn Write) -> FmtResult {
+        match operand {
+            MirOperand::None => out.write_str("_"),
+            MirOperand::VReg(vreg) => write!(out, "{vreg}"),
+            MirOperand::PReg(preg) => write!(out, "{preg}"),
+            MirOperand::Imm(imm) => write!(out, "#{imm}"),
+            MirOperand::Label(bb) => match bb.try_deref_mir(&self.module.allocs) {
+                Some(obj) if obj.body.is_some() => write!(out, ".{}", obj.get_body().name),
+                _ => write!(out, ".<dead {bb:?}>"),
+            },
+            MirOperand::Global(global) => self.write_global_ref(global, out),
+            MirOperand::SwitchTab(tab) => write!(out, "jt{}", tab.0),
+            MirOperand::StackSlot(slot) => write!(out, "ss{}", slot.0),
+        }
+    }
+
+    fn write_global_ref(&self, global: MirGlobalID, out: &mut dyn Write) -> FmtResult {
+        match self.module.try_get_global(global) {
+            Some(g) => write!(out, "@{}", g.get_name()),
+            None => write!(out, "@<invalid {}>", global.0),
+        }
+    }
+}
+
+impl Display for MirModule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        MirWriter::new(self).write_module(f)
+    }
+}
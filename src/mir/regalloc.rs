@@ -0,0 +1,419 @@
+//! 线性扫描寄存器分配.
+//!
+//! 按 Wimmer 和 Franz 的 "Linear Scan Register Allocation on SSA Form" 的思路实现, 但不要求 SSA:
+//!
+//! * 指令按基本块布局顺序编号, 第 `k` 条指令读操作数的位置是 `2k`, 写结果的位置是 `2k + 1`.
+//!   活跃区间由基本块级的活跃变量分析得到, 每个虚拟寄存器一个区间, 区间中间可以有空洞.
+//! * 物理寄存器的占用 (参数和返回值寄存器、调用破坏的调用者保存寄存器) 作为固定区间参与分配.
+//! * 寄存器不够时, 溢出代价较小的一方: 代价是区间内各个使用点 `10^循环深度` 之和除以区间长度.
+//!   被溢出的区间从溢出点拆开, 下一个使用点之前放在栈槽里, 之后的部分重新参与分配.
+//! * 分配结束后把虚拟寄存器改写成物理寄存器. 区间被拆开的地方插入复制、存储或加载,
+//!   基本块边界两侧位置不同的值在边上补齐, 必要时拆开关键边.
+//! * 用到的被调用者保存寄存器在入口处用 `MirSaveRegs` 保存, 在每个 `MirReturn` 之前用
+//!   `MirRestoreRegs` 恢复.
+//! * 128 位的向量值不放进被调用者保存的浮点寄存器, 因为 AAPCS64 只保存它们的低 64 位;
+//!   它们的溢出槽和复制都按 128 位进行.
+//!
+//! `x16`/`x17` 和 `d31` 保留给并行复制和伪指令展开, `x18`、`x29`、`x30` 不参与分配.
+//! 栈帧既要重新对齐又有动态栈分配时 `x19` 是栈槽的基址 (见 `mir::frame`),
+//! 它在整个函数里作为固定区间, 不参与分配.
+
+use crate::{
+    SymbolStr,
+    mir::{BASE_REG, MirFrameLayout, MirGlobalID, MirModule, MirRegBank, PReg},
+};
+
+mod interval;
+mod linear_scan;
+mod liveness;
+mod rewrite;
+
+use self::{
+    interval::LiveRange, linear_scan::LinearScan, liveness::FuncLiveness, rewrite::Rewriter,
+};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RegAllocErr {
+    #[error("function `{0}` runs out of {1:?} registers at position {2}")]
+    OutOfRegisters(SymbolStr, MirRegBank, u32),
+    #[error("virtual register %v{1} of function `{0}` is not in a general or float register bank")]
+    UnsupportedVReg(SymbolStr, u32),
+    #[error("function `{0}`: {1} is shared by overlapping intervals at position {2}")]
+    OverlappingIntervals(SymbolStr, PReg, u32),
+}
+pub type RegAllocRes<T = ()> = Result<T, RegAllocErr>;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RegAllocConfig {
+    /// 分配结束后检查同一个物理寄存器上的区间是否重叠. 用于调试分配器本身.
+    pub verify: bool,
+}
+
+/// 对模块中所有有定义的函数做寄存器分配.
+pub fn allocate_regs(module: &mut MirModule, config: RegAllocConfig) -> RegAllocRes {
+    let funcs: Vec<MirGlobalID> = module
+        .funcs_iter()
+        .filter(|(_, f)| !f.is_extern())
+        .map(|(id, _)| id)
+        .collect();
+    for func in funcs {
+        allocate_func_regs(module, func, config)?;
+    }
+    Ok(())
+}
+
+/// 对单个函数做寄存器分配. 结束后函数里不再有虚拟寄存器.
+pub fn allocate_func_regs(
+    module: &mut MirModule,
+    func_id: MirGlobalID,
+    config: RegAllocConfig,
+) -> RegAllocRes {
+    let func = module.get_func(func_id);
+    let live = FuncLiveness::new(&module.allocs, func)?;
+    let mut intervals = live.build_intervals(&module.allocs);
+    if MirFrameLayout::needs_base_reg(func, &module.allocs) {
+        let whole = LiveRange { start: 0, end: live.insts.len() as u32 * 2 };
+        *intervals.fixed_mut(MirRegBank::GPR, BASE_REG.get_num()) = vec![whole];
+    }
+    for bank in [MirRegBank::GPR, MirRegBank::FPR] {
+        LinearScan::new(&live, &mut intervals, bank).run()?;
+    }
+    if config.verify {
+        intervals.verify(&func.name)?;
+    }
+    let func = module.globals[func_id.get_index()]
+        .as_func_mut()
+        .expect("regalloc target should be a function");
+    Rewriter::new(&module.allocs, func, &live, &intervals).run();
+    Ok(())
+}
+
+/// 参与分配的寄存器, 按分配的优先顺序排列: 先用调用者保存寄存器, 最后才用需要保存的寄存器.
+fn alloc_order(bank: MirRegBank) -> &'static [u8] {
+    const GPR_ORDER: [u8; 26] = [
+        9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7, 8, //
+        19, 20, 21, 22, 23, 24, 25, 26, 27, 28,
+    ];
+    const FPR_ORDER: [u8; 31] = [
+        16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 0, 1, 2, 3, 4, 5, 6, 7, //
+        8, 9, 10, 11, 12, 13, 14, 15,
+    ];
+    match bank {
+        MirRegBank::GPR => &GPR_ORDER,
+        MirRegBank::FPR => &FPR_ORDER,
+        MirRegBank::PState => &[],
+    }
+}
+
+fn is_allocatable(bank: MirRegBank, num: u8) -> bool {
+    alloc_order(bank).contains(&num)
+}
+
+/// AAPCS64 的被调用者保存寄存器: `x19`..`x28` 和 `d8`..`d15` (只保存低 64 位).
+fn is_callee_saved(bank: MirRegBank, num: u8) -> bool {
+    match bank {
+        MirRegBank::GPR => (19..=28).contains(&num),
+        MirRegBank::FPR => (8..=15).contains(&num),
+        MirRegBank::PState => false,
+    }
+}
+
+/// 并行复制成环时暂存旧值的寄存器.
+fn scratch_reg(bank: MirRegBank) -> PReg {
+    match bank {
+        MirRegBank::GPR => PReg::x(16),
+        MirRegBank::FPR => PReg::d(31),
+        MirRegBank::PState => panic!("PState has no scratch register"),
+    }
+}
+
+/// 寄存器堆中编号为 `num` 的寄存器的 64 位视图.
+fn full_reg(bank: MirRegBank, num: u8) -> PReg {
+    match bank {
+        MirRegBank::GPR => PReg::x(num),
+        MirRegBank::FPR => PReg::d(num),
+        MirRegBank::PState => PReg::NZCV,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        interval::{IntervalSet, LiveInterval, LiveRange, Location},
+        *,
+    };
+    use crate::{
+        ir::{FuncID, ISubGlobalID},
+        mir::{
+            Bin64R, CondBr, IMirSubInst, MirBlockID, MirCall, MirCondFlag, MirCopy64, MirFCopy128,
+            MirFunc, MirOP, MirOperand, MirRegClass, MirReturn, MirStackSlotKind, MirWriter,
+            Mov64I, UncondBr, select_module, verify_mir_module,
+        },
+        opt::{IFuncTransformPass, Mem2Reg},
+        testing::cases::*,
+    };
+
+    fn allocate_and_verify(mir: &mut MirModule) {
+        allocate_regs(mir, RegAllocConfig { verify: true }).unwrap();
+        verify_mir_module(mir).unwrap();
+        for (_, func) in mir.funcs_iter().filter(|(_, f)| !f.is_extern()) {
+            for (bb, _) in func.blocks_iter(&mir.allocs) {
+                for (_, inst) in bb.insts_iter(&mir.allocs) {
+                    let inst = inst.get_inst();
+                    let vreg = inst
+                        .operands()
+                        .iter()
+                        .find(|op| op.get().as_vreg().is_some());
+                    assert!(vreg.is_none(), "vreg left in `{}`", func.name);
+                }
+            }
+        }
+    }
+    fn func_text(mir: &MirModule, name: &str) -> String {
+        let id = mir.get_global_by_name(name).unwrap();
+        let mut text = String::new();
+        MirWriter::new(mir).write_global(id, &mut text).unwrap();
+        text
+    }
+
+    #[test]
+    fn test_allocate_cases() {
+        let cases = [
+            test_case_cfg_deep_while_br(),
+            test_case_minmax(),
+            test_case_array_sum(),
+            test_case_matrix_fill(),
+            test_case_phi_swap(),
+        ];
+        for mut builder in cases {
+            let mut mir = select_module(&mut builder.module).unwrap();
+            allocate_and_verify(&mut mir);
+        }
+
+        let mut module = test_case_loop_select().module;
+        let func = FuncID::raw_from(module.get_global_by_name("main").unwrap());
+        Mem2Reg::new(&module).run_on_func(func);
+        let mut mir = select_module(&mut module).unwrap();
+        allocate_and_verify(&mut mir);
+    }
+
+    /// `n` 个同时活跃的常量最后加在一起返回.
+    fn build_pressure_func(module: &mut MirModule, n: i64) -> MirGlobalID {
+        let func = MirFunc::new_defined(&module.allocs, "pressure", true);
+        let func_id = module.add_func(func);
+        let allocs = &module.allocs;
+        let func = module.get_func(func_id);
+        let entry = MirBlockID::new(allocs, "entry");
+        func.push_block(allocs, entry);
+
+        let vals: Vec<_> = (0..n).map(|_| func.new_vreg(MirRegClass::GPR64)).collect();
+        for (i, &val) in vals.iter().enumerate() {
+            let mov = Mov64I::new(MirOP::Mov64I, val.into(), MirOperand::Imm(i as i64));
+            entry.push_inst(allocs, mov.into_mir());
+        }
+        let sum = vals[0];
+        for &val in &vals[1..] {
+            let add = Bin64R::new(MirOP::Add64R, sum.into(), sum.into(), val.into());
+            entry.push_inst(allocs, add.into_mir());
+        }
+        let x0 = MirOperand::PReg(PReg::x(0));
+        let copy = MirCopy64::new(MirOP::MirCopy64, x0, sum.into());
+        entry.push_inst(allocs, copy.into_mir());
+        entry.push_inst(allocs, MirReturn::new(&[x0]).into_mir());
+        func_id
+    }
+
+    #[test]
+    fn test_spill_under_pressure() {
+        let mut mir = MirModule::new("test");
+        let func_id = build_pressure_func(&mut mir, 40);
+        verify_mir_module(&mir).unwrap();
+        allocate_and_verify(&mut mir);
+
+        let func = mir.get_func(func_id);
+        let spills = func.stack_slots.iter();
+        assert!(spills.filter(|s| s.kind == MirStackSlotKind::Spill).count() > 0);
+        let text = func_text(&mir, "pressure");
+        assert!(text.contains("StrGr64BaseS"), "{text}");
+        assert!(text.contains("LdrGr64BaseS"), "{text}");
+        // 寄存器够用时不溢出
+        let mut mir = MirModule::new("test");
+        let func_id = build_pressure_func(&mut mir, 8);
+        allocate_and_verify(&mut mir);
+        assert!(mir.get_func(func_id).stack_slots.is_empty());
+    }
+
+    /// 循环里反复使用的值留在寄存器里, 溢出的是只在循环外使用的值.
+    #[test]
+    fn test_spill_outside_loop() {
+        let mut mir = MirModule::new("test");
+        let func = MirFunc::new_defined(&mir.allocs, "f", true);
+        let func_id = mir.add_func(func);
+        let allocs = &mir.allocs;
+        let func = mir.get_func(func_id);
+        let [entry, body, exit] =
+            ["entry", "body", "exit"].map(|name| MirBlockID::new(allocs, name));
+        for bb in [entry, body, exit] {
+            func.push_block(allocs, bb);
+        }
+
+        let cold: Vec<_> = (0..30).map(|_| func.new_vreg(MirRegClass::GPR64)).collect();
+        let hot = func.new_vreg(MirRegClass::GPR64);
+        for (i, &val) in cold.iter().chain([&hot]).enumerate() {
+            let mov = Mov64I::new(MirOP::Mov64I, val.into(), MirOperand::Imm(i as i64));
+            entry.push_inst(allocs, mov.into_mir());
+        }
+        entry.push_inst(
+            allocs,
+            UncondBr::new(MirOP::B, MirOperand::Label(body)).into_mir(),
+        );
+
+        let add = Bin64R::new(MirOP::Add64R, hot.into(), hot.into(), hot.into());
+        body.push_inst(allocs, add.into_mir());
+        let br = CondBr::new(MirOP::BCond, MirOperand::Label(body), PReg::NZCV.into());
+        br.set_cond(MirCondFlag::NE);
+        body.push_inst(allocs, br.into_mir());
+        body.push_inst(
+            allocs,
+            UncondBr::new(MirOP::B, MirOperand::Label(exit)).into_mir(),
+        );
+
+        for &val in &cold {
+            let add = Bin64R::new(MirOP::Add64R, hot.into(), hot.into(), val.into());
+            exit.push_inst(allocs, add.into_mir());
+        }
+        let x0 = MirOperand::PReg(PReg::x(0));
+        let copy = MirCopy64::new(MirOP::MirCopy64, x0, hot.into());
+        exit.push_inst(allocs, copy.into_mir());
+        exit.push_inst(allocs, MirReturn::new(&[x0]).into_mir());
+
+        allocate_and_verify(&mut mir);
+        let text = func_text(&mir, "f");
+        let body_text = text
+            .split(".body:")
+            .nth(1)
+            .unwrap()
+            .split(".exit:")
+            .next()
+            .unwrap();
+        assert!(!body_text.contains("BaseS"), "{text}");
+        assert!(text.contains("LdrGr64BaseS"), "{text}");
+    }
+
+    #[test]
+    fn test_callee_saved_across_call() {
+        let mut mir = MirModule::new("test");
+        let callee = mir.add_func(MirFunc::new_extern("g"));
+        let func = MirFunc::new_defined(&mir.allocs, "f", true);
+        let func_id = mir.add_func(func);
+        let allocs = &mir.allocs;
+        let func = mir.get_func(func_id);
+        let entry = MirBlockID::new(allocs, "entry");
+        func.push_block(allocs, entry);
+
+        // 跨过调用的值只能放在被调用者保存寄存器里
+        let val = func.new_vreg(MirRegClass::GPR64);
+        let x0 = MirOperand::PReg(PReg::x(0));
+        let mov = Mov64I::new(MirOP::Mov64I, val.into(), MirOperand::Imm(7));
+        entry.push_inst(allocs, mov.into_mir());
+        let call = MirCall::new(MirOperand::Global(callee), &[x0], &[]);
+        entry.push_inst(allocs, call.into_mir());
+        let add = Bin64R::new(MirOP::Add64R, x0, x0, val.into());
+        entry.push_inst(allocs, add.into_mir());
+        entry.push_inst(allocs, MirReturn::new(&[x0]).into_mir());
+
+        allocate_and_verify(&mut mir);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("$x19 = Mov64I #7"), "{text}");
+        assert!(text.contains("MirSaveRegs $x19"), "{text}");
+        assert!(text.contains("$x19 = MirRestoreRegs"), "{text}");
+    }
+
+    /// 被调用者保存的浮点寄存器只保存低 64 位, 跨过调用的 128 位向量值只能溢出.
+    #[test]
+    fn test_vector_across_call() {
+        let mut mir = MirModule::new("test");
+        let callee = mir.add_func(MirFunc::new_extern("g"));
+        let func = MirFunc::new_defined(&mir.allocs, "f", true);
+        let func_id = mir.add_func(func);
+        let allocs = &mir.allocs;
+        let func = mir.get_func(func_id);
+        let entry = MirBlockID::new(allocs, "entry");
+        func.push_block(allocs, entry);
+
+        let val = func.new_vreg(MirRegClass::FPR128);
+        let q0 = MirOperand::PReg(PReg::q(0));
+        let copy = MirFCopy128::new(MirOP::MirFCopy128, val.into(), q0);
+        entry.push_inst(allocs, copy.into_mir());
+        let call = MirCall::new(MirOperand::Global(callee), &[], &[]);
+        entry.push_inst(allocs, call.into_mir());
+        let copy = MirFCopy128::new(MirOP::MirFCopy128, q0, val.into());
+        entry.push_inst(allocs, copy.into_mir());
+        entry.push_inst(allocs, MirReturn::new(&[q0]).into_mir());
+
+        allocate_and_verify(&mut mir);
+        let text = func_text(&mir, "f");
+        assert!(text.contains("StrF128BaseS $q"), "{text}");
+        assert!(text.contains("LdrF128BaseS"), "{text}");
+        assert!(!text.contains("MirSaveRegs"), "{text}");
+        let slots = &mir.get_func(func_id).stack_slots;
+        assert_eq!(slots.len(), 1);
+        assert_eq!((slots[0].size, slots[0].align_log2), (16, 4));
+    }
+
+    #[test]
+    fn test_loop_depth() {
+        let mut mir = MirModule::new("test");
+        let func = MirFunc::new_defined(&mir.allocs, "f", true);
+        let func_id = mir.add_func(func);
+        let allocs = &mir.allocs;
+        let func = mir.get_func(func_id);
+        let names = ["entry", "outer", "inner", "latch", "exit"];
+        let [entry, outer, inner, latch, exit] = names.map(|name| MirBlockID::new(allocs, name));
+        for bb in [entry, outer, inner, latch, exit] {
+            func.push_block(allocs, bb);
+        }
+        let nzcv = MirOperand::PReg(PReg::NZCV);
+        let jump = |from: MirBlockID, to| {
+            from.push_inst(
+                allocs,
+                UncondBr::new(MirOP::B, MirOperand::Label(to)).into_mir(),
+            );
+        };
+        let cond_jump = |from: MirBlockID, to| {
+            let br = CondBr::new(MirOP::BCond, MirOperand::Label(to), nzcv);
+            br.set_cond(MirCondFlag::EQ);
+            from.push_inst(allocs, br.into_mir());
+        };
+        jump(entry, outer);
+        cond_jump(outer, exit);
+        jump(outer, inner);
+        cond_jump(inner, inner);
+        jump(inner, latch);
+        jump(latch, outer);
+        exit.push_inst(allocs, MirReturn::new(&[]).into_mir());
+
+        let live = FuncLiveness::new(allocs, func).unwrap();
+        assert_eq!(live.loop_depth, [0, 1, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_verify_overlap() {
+        let range = |start, end| LiveRange { start, end };
+        let mut intervals = IntervalSet::new(2);
+        for (vreg, ranges) in [(0, vec![range(1, 6)]), (1, vec![range(5, 8)])] {
+            let mut it = LiveInterval::new(vreg, MirRegBank::GPR);
+            it.ranges = ranges;
+            it.loc = Some(Location::Reg(9));
+            intervals.pieces.push(it);
+        }
+        let err = intervals.verify(&"f".into()).unwrap_err();
+        assert!(matches!(err, RegAllocErr::OverlappingIntervals(_, r, 5) if r == PReg::x(9)));
+
+        intervals.pieces[1].loc = Some(Location::Reg(10));
+        intervals.verify(&"f".into()).unwrap();
+        // 与固定区间重叠
+        intervals.fixed_mut(MirRegBank::GPR, 10).push(range(7, 9));
+        assert!(intervals.verify(&"f".into()).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! 活跃区间和分配结果.
+
+use crate::{
+    SymbolStr,
+    mir::{
+        MirRegBank,
+        regalloc::{RegAllocErr, RegAllocRes, full_reg},
+    },
+};
+
+/// 左闭右开的位置区间 `[start, end)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct LiveRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl LiveRange {
+    pub fn contains(self, pos: u32) -> bool {
+        self.start <= pos && pos < self.end
+    }
+}
+
+/// 区间所在的位置. 同一个虚拟寄存器的所有栈上区间共用一个溢出槽.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Location {
+    Reg(u8),
+    Stack,
+}
+
+/// 必须放在寄存器里的位置, 以及按循环深度估计的执行频率.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct UsePos {
+    pub pos: u32,
+    pub weight: f64,
+}
+
+/// 一个虚拟寄存器的活跃区间, 或者它被拆开之后的一段.
+#[derive(Debug, Clone)]
+pub(super) struct LiveInterval {
+    pub vreg: u32,
+    pub bank: MirRegBank,
+    /// 按位置排列, 互不相交也不相邻.
+    pub ranges: Vec<LiveRange>,
+    /// 按位置排列.
+    pub uses: Vec<UsePos>,
+    pub loc: Option<Location>,
+}
+
+impl LiveInterval {
+    pub fn new(vreg: u32, bank: MirRegBank) -> Self {
+        Self { vreg, bank, ranges: Vec::new(), uses: Vec::new(), loc: None }
+    }
+
+    pub fn start(&self) -> u32 {
+        self.ranges.first().map_or(0, |r| r.start)
+    }
+    pub fn end(&self) -> u32 {
+        self.ranges.last().map_or(0, |r| r.end)
+    }
+    pub fn covers(&self, pos: u32) -> bool {
+        ranges_cover(&self.ranges, pos)
+    }
+    /// 与 `ranges` 第一个共同的位置.
+    pub fn next_intersection(&self, ranges: &[LiveRange]) -> Option<u32> {
+        next_intersection(&self.ranges, ranges)
+    }
+
+    /// 不早于 `pos` 的第一个使用点.
+    pub fn next_use(&self, pos: u32) -> Option<u32> {
+        let i = self.uses.partition_point(|u| u.pos < pos);
+        self.uses.get(i).map(|u| u.pos)
+    }
+    /// `[lo, hi]` 内是否有使用点.
+    pub fn has_use_in(&self, lo: u32, hi: u32) -> bool {
+        self.next_use(lo).is_some_and(|pos| pos <= hi)
+    }
+
+    /// 溢出代价: 使用点的频率之和除以区间覆盖的指令数.
+    pub fn spill_weight(&self) -> f64 {
+        let len: u32 = self.ranges.iter().map(|r| r.end - r.start).sum();
+        let freq: f64 = self.uses.iter().map(|u| u.weight).sum();
+        freq / (len / 2 + 1) as f64
+    }
+
+    /// 从 `pos` 处拆开, 返回不早于 `pos` 的部分. `pos` 之后不再活跃时返回 `None`.
+    ///
+    /// 调用者保证 `pos` 之前还有活跃的部分.
+    pub fn split_at(&mut self, pos: u32) -> Option<LiveInterval> {
+        debug_assert!(self.start() < pos, "splitting %v{} at its start", self.vreg);
+        let i = self.ranges.partition_point(|r| r.end <= pos);
+        if i == self.ranges.len() {
+            return None;
+        }
+        let mut tail_ranges = self.ranges.split_off(i);
+        if tail_ranges[0].start < pos {
+            self.ranges
+                .push(LiveRange { start: tail_ranges[0].start, end: pos });
+            tail_ranges[0].start = pos;
+        }
+        let j = self.uses.partition_point(|u| u.pos < pos);
+        Some(LiveInterval {
+            vreg: self.vreg,
+            bank: self.bank,
+            ranges: tail_ranges,
+            uses: self.uses.split_off(j),
+            loc: None,
+        })
+    }
+}
+
+pub(super) fn ranges_cover(ranges: &[LiveRange], pos: u32) -> bool {
+    let i = ranges.partition_point(|r| r.end <= pos);
+    ranges.get(i).is_some_and(|r| r.contains(pos))
+}
+
+pub(super) fn next_intersection(lhs: &[LiveRange], rhs: &[LiveRange]) -> Option<u32> {
+    let (mut i, mut j) = (0, 0);
+    while i < lhs.len() && j < rhs.len() {
+        let (l, r) = (lhs[i], rhs[j]);
+        let start = l.start.max(r.start);
+        if start < l.end.min(r.end) {
+            return Some(start);
+        }
+        if l.end <= r.end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    None
+}
+
+/// 一个函数所有的活跃区间.
+pub(super) struct IntervalSet {
+    /// 虚拟寄存器的区间. 拆开之后的各段都在这里, 由 `vreg` 字段区分.
+    pub pieces: Vec<LiveInterval>,
+    /// 参与分配的物理寄存器被指令直接占用的位置, 下标见 `fixed_index`.
+    fixed: Vec<Vec<LiveRange>>,
+    pub num_vregs: u32,
+}
+
+impl IntervalSet {
+    pub fn new(num_vregs: u32) -> Self {
+        Self { pieces: Vec::new(), fixed: vec![Vec::new(); 64], num_vregs }
+    }
+
+    fn fixed_index(bank: MirRegBank, num: u8) -> usize {
+        match bank {
+            MirRegBank::GPR => num as usize,
+            MirRegBank::FPR => 32 + num as usize,
+            MirRegBank::PState => panic!("NZCV is not allocated"),
+        }
+    }
+    pub fn fixed(&self, bank: MirRegBank, num: u8) -> &[LiveRange] {
+        &self.fixed[Self::fixed_index(bank, num)]
+    }
+    pub fn fixed_mut(&mut self, bank: MirRegBank, num: u8) -> &mut Vec<LiveRange> {
+        &mut self.fixed[Self::fixed_index(bank, num)]
+    }
+
+    /// 每个虚拟寄存器的各段区间, 按起点排列.
+    pub fn pieces_by_vreg(&self) -> Vec<Vec<usize>> {
+        let mut by_vreg = vec![Vec::new(); self.num_vregs as usize];
+        for (id, piece) in self.pieces.iter().enumerate() {
+            by_vreg[piece.vreg as usize].push(id);
+        }
+        for ids in &mut by_vreg {
+            ids.sort_by_key(|&id| self.pieces[id].start());
+        }
+        by_vreg
+    }
+
+    /// 检查分到同一个物理寄存器的区间互不重叠, 固定区间也算在内.
+    pub fn verify(&self, func_name: &SymbolStr) -> RegAllocRes {
+        // (起点, 终点, 所属虚拟寄存器; 固定区间为 `None`)
+        let mut by_reg: Vec<Vec<(u32, u32, Option<u32>)>> = vec![Vec::new(); self.fixed.len()];
+        for piece in &self.pieces {
+            let Some(Location::Reg(num)) = piece.loc else {
+                continue;
+            };
+            let regs = &mut by_reg[Self::fixed_index(piece.bank, num)];
+            regs.extend(
+                piece
+                    .ranges
+                    .iter()
+                    .map(|r| (r.start, r.end, Some(piece.vreg))),
+            );
+        }
+        for (index, fixed) in self.fixed.iter().enumerate() {
+            by_reg[index].extend(fixed.iter().map(|r| (r.start, r.end, None)));
+        }
+        for (index, ranges) in by_reg.iter_mut().enumerate() {
+            ranges.sort_unstable();
+            // 同一个主人的区间互不相交, 所以只要和终点最靠后的那个比较
+            let mut last: Option<(u32, Option<u32>)> = None;
+            for &(start, end, owner) in ranges.iter() {
+                match last {
+                    Some((last_end, last_owner)) if start < last_end && last_owner != owner => {
+                        let bank = if index < 32 { MirRegBank::GPR } else { MirRegBank::FPR };
+                        let preg = full_reg(bank, (index % 32) as u8);
+                        return Err(RegAllocErr::OverlappingIntervals(
+                            func_name.clone(),
+                            preg,
+                            start,
+                        ));
+                    }
+                    Some((last_end, _)) if last_end >= end => {}
+                    _ => last = Some((end, owner)),
+                }
+            }
+        }
+        Ok(())
+    }
+}
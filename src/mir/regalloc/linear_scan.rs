@@ -0,0 +1,278 @@
+//! 线性扫描的主循环: 按起点依次给区间分配寄存器, 寄存器不够时拆分和溢出.
+
+use crate::mir::{
+    MirRegBank,
+    regalloc::{
+        RegAllocErr, RegAllocRes, alloc_order,
+        interval::{IntervalSet, LiveInterval, Location},
+        is_callee_saved,
+        liveness::FuncLiveness,
+    },
+};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+pub(super) struct LinearScan<'a> {
+    live: &'a FuncLiveness,
+    intervals: &'a mut IntervalSet,
+    bank: MirRegBank,
+    /// 还没处理的区间, 按起点排列.
+    unhandled: BinaryHeap<Reverse<(u32, usize)>>,
+    /// 覆盖当前位置并且占着寄存器的区间.
+    active: Vec<usize>,
+    /// 占着寄存器, 但当前位置落在空洞里的区间.
+    inactive: Vec<usize>,
+}
+
+impl<'a> LinearScan<'a> {
+    pub fn new(live: &'a FuncLiveness, intervals: &'a mut IntervalSet, bank: MirRegBank) -> Self {
+        let unhandled = intervals
+            .pieces
+            .iter()
+            .enumerate()
+            .filter(|(_, it)| it.bank == bank)
+            .map(|(id, it)| Reverse((it.start(), id)))
+            .collect();
+        Self {
+            live,
+            intervals,
+            bank,
+            unhandled,
+            active: Vec::new(),
+            inactive: Vec::new(),
+        }
+    }
+
+    pub fn run(mut self) -> RegAllocRes {
+        while let Some(Reverse((pos, current))) = self.unhandled.pop() {
+            self.advance(pos);
+            if !self.try_alloc_free(current) {
+                self.alloc_blocked(current)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn piece(&self, id: usize) -> &LiveInterval {
+        &self.intervals.pieces[id]
+    }
+    fn reg_of(&self, id: usize) -> u8 {
+        match self.piece(id).loc {
+            Some(Location::Reg(num)) => num,
+            loc => panic!("interval {id} in the active set is at {loc:?}"),
+        }
+    }
+    /// `id` 可以使用的寄存器, 按分配的优先顺序排列. 被调用者保存的浮点寄存器只保存低 64 位,
+    /// 放不下 128 位的向量值.
+    fn candidates(&self, id: usize) -> Vec<u8> {
+        let wide = self.live.vreg_wide[self.piece(id).vreg as usize];
+        alloc_order(self.bank)
+            .iter()
+            .copied()
+            .filter(|&num| !(wide && is_callee_saved(self.bank, num)))
+            .collect()
+    }
+    fn enqueue(&mut self, piece: LiveInterval) {
+        let id = self.intervals.pieces.len();
+        self.unhandled.push(Reverse((piece.start(), id)));
+        self.intervals.pieces.push(piece);
+    }
+    /// 从 `pos` 处拆开 `id`, 后半段重新参与分配.
+    fn split_and_enqueue(&mut self, id: usize, pos: u32) {
+        if let Some(tail) = self.intervals.pieces[id].split_at(pos) {
+            self.enqueue(tail);
+        }
+    }
+
+    fn advance(&mut self, pos: u32) {
+        let pieces = &self.intervals.pieces;
+        let mut to_inactive = Vec::new();
+        self.active.retain(|&id| {
+            let it = &pieces[id];
+            if it.end() <= pos {
+                false
+            } else if !it.covers(pos) {
+                to_inactive.push(id);
+                false
+            } else {
+                true
+            }
+        });
+        let mut to_active = Vec::new();
+        self.inactive.retain(|&id| {
+            let it = &pieces[id];
+            if it.end() <= pos {
+                false
+            } else if it.covers(pos) {
+                to_active.push(id);
+                false
+            } else {
+                true
+            }
+        });
+        self.active.extend(to_active);
+        self.inactive.extend(to_inactive);
+    }
+
+    /// 固定区间第一次与 `current` 相交的位置.
+    fn fixed_intersection(&self, current: usize, num: u8) -> Option<u32> {
+        let fixed = self.intervals.fixed(self.bank, num);
+        self.piece(current).next_intersection(fixed)
+    }
+
+    /// 找一个在 `current` 开始时空闲的寄存器. 只空闲一段时间的话, 把 `current` 从那里拆开.
+    fn try_alloc_free(&mut self, current: usize) -> bool {
+        let order = self.candidates(current);
+        let mut free_until = [u32::MAX; 32];
+        for &id in &self.active {
+            free_until[self.reg_of(id) as usize] = 0;
+        }
+        for &id in &self.inactive {
+            let num = self.reg_of(id) as usize;
+            if let Some(pos) = self
+                .piece(id)
+                .next_intersection(&self.piece(current).ranges)
+            {
+                free_until[num] = free_until[num].min(pos);
+            }
+        }
+        for &num in &order {
+            if let Some(pos) = self.fixed_intersection(current, num) {
+                free_until[num as usize] = free_until[num as usize].min(pos);
+            }
+        }
+
+        let (start, end) = (self.piece(current).start(), self.piece(current).end());
+        // 能放下整个区间的寄存器里取顺序最靠前的, 否则取空闲最久的
+        let whole = order.iter().find(|&&num| free_until[num as usize] >= end);
+        let num = match whole {
+            Some(&num) => num,
+            None => {
+                let best = order
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|&(i, &num)| (free_until[num as usize], Reverse(i)));
+                let Some((_, &num)) = best else {
+                    return false;
+                };
+                let split = self.live.split_pos(free_until[num as usize]);
+                if split <= start {
+                    return false;
+                }
+                self.split_and_enqueue(current, split);
+                num
+            }
+        };
+        self.intervals.pieces[current].loc = Some(Location::Reg(num));
+        self.active.push(current);
+        true
+    }
+
+    /// 所有寄存器都被占用. 比较溢出 `current` 和腾出某个寄存器的代价, 溢出较小的一方.
+    fn alloc_blocked(&mut self, current: usize) -> RegAllocRes {
+        let order = self.candidates(current);
+        let start = self.piece(current).start();
+        // 占用者在这里让出寄存器, 需要的复制插在这个位置之前
+        let evict_pos = self.live.split_pos(start);
+        let mut costs = [0f64; 32];
+        let mut blocked = [false; 32];
+        for &num in &order {
+            let fixed = self.fixed_intersection(current, num);
+            if fixed.is_some_and(|pos| self.live.split_pos(pos) <= start) {
+                blocked[num as usize] = true;
+            }
+        }
+        for &id in &self.active {
+            let num = self.reg_of(id) as usize;
+            let it = self.piece(id);
+            if it.has_use_in(evict_pos, start) || it.start() > evict_pos {
+                blocked[num] = true;
+            }
+            costs[num] += it.spill_weight();
+        }
+        for &id in &self.inactive {
+            let it = self.piece(id);
+            if it.next_intersection(&self.piece(current).ranges).is_some() {
+                costs[self.reg_of(id) as usize] += it.spill_weight();
+            }
+        }
+        let best = order
+            .iter()
+            .copied()
+            .filter(|&num| !blocked[num as usize])
+            .min_by(|&a, &b| costs[a as usize].total_cmp(&costs[b as usize]));
+
+        // 溢出 `current` 在第一个使用点之前的部分
+        let cur = self.piece(current);
+        let first_use = cur.next_use(start);
+        let spill_until = first_use.map(|pos| self.live.split_pos(pos));
+        let spillable = spill_until.is_none_or(|pos| pos > start);
+        let cheaper = best.is_none_or(|num| cur.spill_weight() <= costs[num as usize]);
+        if spillable && cheaper {
+            if let Some(pos) = spill_until {
+                self.split_and_enqueue(current, pos);
+            }
+            self.intervals.pieces[current].loc = Some(Location::Stack);
+            return Ok(());
+        }
+        let Some(num) = best else {
+            let name = self.live.func_name.clone();
+            return Err(RegAllocErr::OutOfRegisters(name, self.bank, start));
+        };
+
+        // 腾出寄存器 `num`
+        let evicted: Vec<usize> = self
+            .active
+            .iter()
+            .copied()
+            .filter(|&id| self.reg_of(id) == num)
+            .collect();
+        self.active.retain(|id| !evicted.contains(id));
+        for id in evicted {
+            self.evict_active(id, evict_pos);
+        }
+        let current_ranges = self.piece(current).ranges.clone();
+        let mut evicted = Vec::new();
+        for &id in &self.inactive {
+            if self.reg_of(id) != num {
+                continue;
+            }
+            if let Some(pos) = self.piece(id).next_intersection(&current_ranges) {
+                evicted.push((id, pos));
+            }
+        }
+        for (id, pos) in evicted {
+            self.split_and_enqueue(id, self.live.split_pos(pos));
+        }
+
+        if let Some(pos) = self.fixed_intersection(current, num) {
+            self.split_and_enqueue(current, self.live.split_pos(pos));
+        }
+        self.intervals.pieces[current].loc = Some(Location::Reg(num));
+        self.active.push(current);
+        Ok(())
+    }
+
+    /// 让正在占用寄存器的区间从 `pos` 开始让出寄存器: 放进栈槽, 到下一个使用点之前再重新分配.
+    fn evict_active(&mut self, id: usize, pos: u32) {
+        let tail_id = if self.piece(id).start() < pos {
+            match self.intervals.pieces[id].split_at(pos) {
+                Some(tail) => {
+                    self.intervals.pieces.push(tail);
+                    self.intervals.pieces.len() - 1
+                }
+                None => return,
+            }
+        } else {
+            id
+        };
+        let tail = self.piece(tail_id);
+        let reload = tail
+            .next_use(tail.start())
+            .map(|pos| self.live.split_pos(pos));
+        if let Some(reload) = reload {
+            debug_assert!(reload > tail.start(), "evicted interval is used right away");
+            self.split_and_enqueue(tail_id, reload);
+        }
+        self.intervals.pieces[tail_id].loc = Some(Location::Stack);
+    }
+}
@@ -0,0 +1,425 @@
+//! 指令编号、循环深度、活跃变量分析和活跃区间的构造.
+
+use crate::{
+    SymbolStr,
+    base::FixBitSet,
+    mir::{
+        MirAllocs, MirBlockID, MirFunc, MirInst, MirInstID, MirOP, MirOperand, MirRegBank,
+        MirRegClass,
+        regalloc::{
+            RegAllocErr, RegAllocRes, alloc_order,
+            interval::{IntervalSet, LiveInterval, LiveRange, UsePos},
+            is_allocatable, is_callee_saved,
+        },
+    },
+};
+use std::collections::HashMap;
+
+/// 寄存器操作数在指令里的作用.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum OperandRole {
+    Use,
+    Def,
+    /// 先读后写, 例如 `movk` 的目标和带写回访存的基址.
+    UseDef,
+    /// 读到指令结束为止, 不能和输出共用寄存器. 展开成多条指令的伪指令的输入.
+    LateUse,
+}
+
+pub(super) fn operand_role(inst: &MirInst, index: usize) -> OperandRole {
+    let inst = inst.strip_comment();
+    if index >= inst.num_outs() {
+        return match inst.get_opcode() {
+            MirOP::MirGEP => OperandRole::LateUse,
+            _ => OperandRole::Use,
+        };
+    }
+    let in_out = match inst {
+        MirInst::MovZNK64(_) | MirInst::MovZNK32(_) => {
+            matches!(inst.get_opcode(), MirOP::MovK64 | MirOP::MovK32)
+        }
+        MirInst::LoadGr64Indexed(_)
+        | MirInst::LoadGr32Indexed(_)
+        | MirInst::LoadF64Indexed(_)
+        | MirInst::LoadF32Indexed(_) => index == 1,
+        MirInst::StoreGr64Indexed(_)
+        | MirInst::StoreGr32Indexed(_)
+        | MirInst::StoreF64Indexed(_)
+        | MirInst::StoreF32Indexed(_) => true,
+        _ => false,
+    };
+    if in_out { OperandRole::UseDef } else { OperandRole::Def }
+}
+
+/// 活跃变量分析中的一个寄存器: 虚拟寄存器, 或者参与分配的物理寄存器.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegKey {
+    VReg(u32),
+    PReg(MirRegBank, u8),
+}
+
+/// 函数的指令编号、控制流图和基本块边界上的活跃寄存器.
+pub(super) struct FuncLiveness {
+    pub func_name: SymbolStr,
+    /// 基本块的布局顺序.
+    pub blocks: Vec<MirBlockID>,
+    pub block_index: HashMap<MirBlockID, usize>,
+    /// 每个基本块的位置区间 `[from, to)`.
+    pub block_ranges: Vec<LiveRange>,
+    /// 每个基本块第一条跳转指令的位置.
+    pub term_pos: Vec<u32>,
+    /// 第 `k` 条指令.
+    pub insts: Vec<MirInstID>,
+    pub succs: Vec<Vec<usize>>,
+    pub preds: Vec<Vec<usize>>,
+    pub loop_depth: Vec<u32>,
+    pub live_in: Vec<FixBitSet>,
+    pub live_out: Vec<FixBitSet>,
+    pub vreg_banks: Vec<Option<MirRegBank>>,
+    /// 整个 128 位都有效的向量寄存器. 它们不能放进只保存低 64 位的被调用者保存寄存器,
+    /// 溢出和复制也要按 128 位进行.
+    pub vreg_wide: Vec<bool>,
+}
+
+impl FuncLiveness {
+    pub fn new(allocs: &MirAllocs, func: &MirFunc) -> RegAllocRes<Self> {
+        let mut live = Self {
+            func_name: func.name.clone(),
+            blocks: Vec::new(),
+            block_index: HashMap::new(),
+            block_ranges: Vec::new(),
+            term_pos: Vec::new(),
+            insts: Vec::new(),
+            succs: Vec::new(),
+            preds: Vec::new(),
+            loop_depth: Vec::new(),
+            live_in: Vec::new(),
+            live_out: Vec::new(),
+            vreg_banks: vec![None; func.num_vregs() as usize],
+            vreg_wide: vec![false; func.num_vregs() as usize],
+        };
+        live.number_insts(allocs, func)?;
+        live.build_cfg(allocs, func);
+        live.compute_loop_depth();
+        live.solve(allocs);
+        Ok(live)
+    }
+
+    fn number_insts(&mut self, allocs: &MirAllocs, func: &MirFunc) -> RegAllocRes {
+        for (bb, _) in func.blocks_iter(allocs) {
+            self.block_index.insert(bb, self.blocks.len());
+            self.blocks.push(bb);
+            let from = self.insts.len() as u32 * 2;
+            let mut term_pos = None;
+            for (inst_id, obj) in bb.insts_iter(allocs) {
+                let inst = obj.get_inst();
+                let pos = self.insts.len() as u32 * 2;
+                if inst.is_terminator() {
+                    term_pos.get_or_insert(pos);
+                }
+                for op in inst.operands() {
+                    let Some(vreg) = op.get().as_vreg() else {
+                        continue;
+                    };
+                    let bank = vreg.get_class().get_bank();
+                    if bank == MirRegBank::PState {
+                        return Err(RegAllocErr::UnsupportedVReg(
+                            func.name.clone(),
+                            vreg.get_id(),
+                        ));
+                    }
+                    self.vreg_banks[vreg.get_id() as usize] = Some(bank);
+                    if vreg.get_class() == MirRegClass::FPR128 {
+                        self.vreg_wide[vreg.get_id() as usize] = true;
+                    }
+                }
+                self.insts.push(inst_id);
+            }
+            let to = self.insts.len() as u32 * 2;
+            self.block_ranges.push(LiveRange { start: from, end: to });
+            self.term_pos.push(term_pos.unwrap_or(to));
+        }
+        Ok(())
+    }
+
+    fn build_cfg(&mut self, allocs: &MirAllocs, func: &MirFunc) {
+        let nblocks = self.blocks.len();
+        self.succs = vec![Vec::new(); nblocks];
+        self.preds = vec![Vec::new(); nblocks];
+        for (index, &bb) in self.blocks.iter().enumerate() {
+            for (_, obj) in bb.insts_iter(allocs) {
+                for target in obj.get_inst().branch_targets(func) {
+                    let succ = self.block_index[&target];
+                    if !self.succs[index].contains(&succ) {
+                        self.succs[index].push(succ);
+                        self.preds[succ].push(index);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 从入口深度优先搜索找出回边, 每个回边的目标是一个循环头.
+    /// 基本块的循环深度是包含它的自然循环个数, 同一个循环头的多条回边算一个循环.
+    fn compute_loop_depth(&mut self) {
+        let nblocks = self.blocks.len();
+        self.loop_depth = vec![0; nblocks];
+        if nblocks == 0 {
+            return;
+        }
+        let mut visited = vec![false; nblocks];
+        let mut on_stack = vec![false; nblocks];
+        let mut back_edges: Vec<(usize, usize)> = Vec::new();
+        let mut stack = vec![(0usize, 0usize)];
+        visited[0] = true;
+        on_stack[0] = true;
+        while let Some(&mut (node, ref mut next)) = stack.last_mut() {
+            let Some(&succ) = self.succs[node].get(*next) else {
+                on_stack[node] = false;
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            if on_stack[succ] {
+                back_edges.push((node, succ));
+            } else if !visited[succ] {
+                visited[succ] = true;
+                on_stack[succ] = true;
+                stack.push((succ, 0));
+            }
+        }
+
+        let mut bodies: HashMap<usize, Vec<bool>> = HashMap::new();
+        for (tail, header) in back_edges {
+            let body = bodies.entry(header).or_insert_with(|| {
+                let mut body = vec![false; nblocks];
+                body[header] = true;
+                body
+            });
+            let mut worklist = vec![tail];
+            while let Some(node) = worklist.pop() {
+                if body[node] {
+                    continue;
+                }
+                body[node] = true;
+                worklist.extend(self.preds[node].iter().copied());
+            }
+        }
+        for body in bodies.values() {
+            for (depth, _) in self
+                .loop_depth
+                .iter_mut()
+                .zip(body)
+                .filter(|(_, in_body)| **in_body)
+            {
+                *depth += 1;
+            }
+        }
+    }
+
+    fn num_keys(&self) -> usize {
+        self.vreg_banks.len() + 64
+    }
+    fn key_index(&self, key: RegKey) -> usize {
+        match key {
+            RegKey::VReg(id) => id as usize,
+            RegKey::PReg(MirRegBank::GPR, num) => self.vreg_banks.len() + num as usize,
+            RegKey::PReg(_, num) => self.vreg_banks.len() + 32 + num as usize,
+        }
+    }
+    fn key_of(&self, index: usize) -> RegKey {
+        let nvregs = self.vreg_banks.len();
+        match index.checked_sub(nvregs) {
+            None => RegKey::VReg(index as u32),
+            Some(i) if i < 32 => RegKey::PReg(MirRegBank::GPR, i as u8),
+            Some(i) => RegKey::PReg(MirRegBank::FPR, (i - 32) as u8),
+        }
+    }
+    fn operand_key(op: MirOperand) -> Option<RegKey> {
+        match op {
+            MirOperand::VReg(vreg) => Some(RegKey::VReg(vreg.get_id())),
+            MirOperand::PReg(preg) => {
+                let bank = preg.get_class().get_bank();
+                let num = preg.get_num();
+                is_allocatable(bank, num).then_some(RegKey::PReg(bank, num))
+            }
+            _ => None,
+        }
+    }
+
+    /// 对指令的每个寄存器操作数调用 `f(作用, 寄存器)`. 调用指令还会 "定义" 所有调用者保存寄存器.
+    fn for_each_reg(&self, inst: &MirInst, mut f: impl FnMut(OperandRole, RegKey)) {
+        for (index, op) in inst.operands().iter().enumerate() {
+            if let Some(key) = Self::operand_key(op.get()) {
+                f(operand_role(inst, index), key);
+            }
+        }
+        if inst.strip_comment().get_opcode().is_call() {
+            for bank in [MirRegBank::GPR, MirRegBank::FPR] {
+                for &num in alloc_order(bank) {
+                    if !is_callee_saved(bank, num) {
+                        f(OperandRole::Def, RegKey::PReg(bank, num));
+                    }
+                }
+            }
+        }
+    }
+
+    fn solve(&mut self, allocs: &MirAllocs) {
+        let nblocks = self.blocks.len();
+        let nkeys = self.num_keys();
+        let mut gens = Vec::with_capacity(nblocks);
+        let mut kills = Vec::with_capacity(nblocks);
+        for index in 0..nblocks {
+            let mut gen_set: FixBitSet = FixBitSet::with_len(nkeys);
+            let mut kill_set: FixBitSet = FixBitSet::with_len(nkeys);
+            for k in self.block_insts(index).rev() {
+                let inst = self.insts[k].get_inst(allocs);
+                self.for_each_reg(inst, |role, key| {
+                    if role != OperandRole::Use && role != OperandRole::LateUse {
+                        let key = self.key_index(key);
+                        kill_set.enable(key);
+                        gen_set.disable(key);
+                    }
+                });
+                self.for_each_reg(inst, |role, key| {
+                    if role != OperandRole::Def {
+                        gen_set.enable(self.key_index(key));
+                    }
+                });
+            }
+            gens.push(gen_set);
+            kills.push(kill_set);
+        }
+
+        self.live_in = vec![FixBitSet::with_len(nkeys); nblocks];
+        self.live_out = vec![FixBitSet::with_len(nkeys); nblocks];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for index in (0..nblocks).rev() {
+                let mut out = FixBitSet::with_len(nkeys);
+                for &succ in &self.succs[index] {
+                    for key in &self.live_in[succ] {
+                        out.enable(key);
+                    }
+                }
+                let mut live_in = gens[index].clone();
+                for key in out.iter().filter(|&key| !kills[index].get(key)) {
+                    live_in.enable(key);
+                }
+                if !live_in.iter().eq(self.live_in[index].iter()) {
+                    self.live_in[index] = live_in;
+                    changed = true;
+                }
+                self.live_out[index] = out;
+            }
+        }
+    }
+
+    fn block_insts(&self, index: usize) -> std::ops::Range<usize> {
+        let range = self.block_ranges[index];
+        range.start as usize / 2..range.end as usize / 2
+    }
+
+    /// 位置 `pos` 所在的基本块. `pos` 等于函数末尾时返回最后一个基本块.
+    pub fn block_at(&self, pos: u32) -> usize {
+        let index = self.block_ranges.partition_point(|r| r.end <= pos);
+        index.min(self.blocks.len() - 1)
+    }
+    pub fn is_block_start(&self, pos: u32) -> bool {
+        self.block_ranges.iter().any(|r| r.start == pos)
+    }
+    /// 在 `pos` 或更早的地方找一个可以插入复制的位置: 某条指令之前, 且不在跳转指令之间.
+    pub fn split_pos(&self, pos: u32) -> u32 {
+        let pos = pos & !1;
+        let index = self.block_at(pos);
+        let range = self.block_ranges[index];
+        let term_pos = self.term_pos[index];
+        if range.contains(pos) && pos > term_pos { term_pos } else { pos }
+    }
+
+    fn use_weight(&self, block: usize) -> f64 {
+        10f64.powi(self.loop_depth[block].min(8) as i32)
+    }
+
+    /// 按 Wimmer 的方法逆序扫描每个基本块, 构造所有寄存器的活跃区间.
+    pub fn build_intervals(&self, allocs: &MirAllocs) -> IntervalSet {
+        let nkeys = self.num_keys();
+        // 逆序构造时区间和使用点都倒着存放, 最后再翻转.
+        let mut ranges: Vec<Vec<LiveRange>> = vec![Vec::new(); nkeys];
+        let mut uses: Vec<Vec<UsePos>> = vec![Vec::new(); nkeys];
+        let add_range = |ranges: &mut Vec<LiveRange>, start: u32, end: u32| match ranges.last_mut()
+        {
+            Some(last) if end >= last.start => {
+                last.start = last.start.min(start);
+                last.end = last.end.max(end);
+            }
+            _ => ranges.push(LiveRange { start, end }),
+        };
+
+        for index in (0..self.blocks.len()).rev() {
+            let block_range = self.block_ranges[index];
+            let weight = self.use_weight(index);
+            let mut live = self.live_out[index].clone();
+            for key in &live {
+                add_range(&mut ranges[key], block_range.start, block_range.end);
+            }
+            for k in self.block_insts(index).rev() {
+                let inst = self.insts[k].get_inst(allocs);
+                let (use_pos, def_pos) = (k as u32 * 2, k as u32 * 2 + 1);
+                let mut defined = Vec::new();
+                self.for_each_reg(inst, |role, key| {
+                    let key = self.key_index(key);
+                    if matches!(role, OperandRole::Use | OperandRole::LateUse)
+                        || defined.contains(&key)
+                    {
+                        return;
+                    }
+                    defined.push(key);
+                    match (live.get(key), ranges[key].last_mut()) {
+                        (true, Some(last)) => last.start = def_pos,
+                        _ => add_range(&mut ranges[key], def_pos, def_pos + 1),
+                    }
+                    uses[key].push(UsePos { pos: def_pos, weight });
+                    live.disable(key);
+                });
+                let mut used = Vec::new();
+                self.for_each_reg(inst, |role, key| {
+                    let key = self.key_index(key);
+                    let end = match role {
+                        OperandRole::Def => return,
+                        OperandRole::LateUse => def_pos + 1,
+                        _ => use_pos + 1,
+                    };
+                    add_range(&mut ranges[key], block_range.start, end);
+                    if !used.contains(&key) {
+                        used.push(key);
+                        uses[key].push(UsePos { pos: use_pos, weight });
+                    }
+                    live.enable(key);
+                });
+            }
+        }
+
+        let mut intervals = IntervalSet::new(self.vreg_banks.len() as u32);
+        for (index, (mut key_ranges, mut key_uses)) in ranges.into_iter().zip(uses).enumerate() {
+            if key_ranges.is_empty() {
+                continue;
+            }
+            key_ranges.reverse();
+            key_uses.reverse();
+            match self.key_of(index) {
+                RegKey::VReg(id) => {
+                    let bank = self.vreg_banks[id as usize].expect("live vreg should be used");
+                    let mut interval = LiveInterval::new(id, bank);
+                    interval.ranges = key_ranges;
+                    interval.uses = key_uses;
+                    intervals.pieces.push(interval);
+                }
+                RegKey::PReg(bank, num) => *intervals.fixed_mut(bank, num) = key_ranges,
+            }
+        }
+        intervals
+    }
+}
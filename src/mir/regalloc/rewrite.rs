@@ -0,0 +1,342 @@
+//! 把分配结果写回 MIR: 改写操作数, 在区间拆开的地方和基本块边上插入复制, 保存被调用者保存寄存器.
+
+use crate::{
+    mir::{
+        IMirSubInst, LoadF64BaseS, LoadF128BaseS, LoadGr64BaseS, MirAllocs, MirBlockID, MirCopy64,
+        MirFCopy64, MirFCopy128, MirFunc, MirInst, MirInstID, MirOP, MirOperand, MirRegBank,
+        MirRegClass, MirRestoreRegs, MirSaveRegs, MirStackSlot, MirStackSlotKind, PReg,
+        StackSlotID, StoreF64BaseS, StoreF128BaseS, StoreGr64BaseS, UncondBr,
+        regalloc::{
+            full_reg,
+            interval::{IntervalSet, Location},
+            is_callee_saved,
+            liveness::{FuncLiveness, OperandRole, operand_role},
+            scratch_reg,
+        },
+    },
+    opt::sequentialize_copies,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// 同一个虚拟寄存器从一个位置搬到另一个位置.
+#[derive(Debug, Clone, Copy)]
+struct Move {
+    vreg: u32,
+    bank: MirRegBank,
+    from: Location,
+    to: Location,
+}
+
+pub(super) struct Rewriter<'a> {
+    allocs: &'a MirAllocs,
+    func: &'a mut MirFunc,
+    live: &'a FuncLiveness,
+    intervals: &'a IntervalSet,
+    by_vreg: Vec<Vec<usize>>,
+    spill_slots: HashMap<u32, StackSlotID>,
+}
+
+impl<'a> Rewriter<'a> {
+    pub fn new(
+        allocs: &'a MirAllocs,
+        func: &'a mut MirFunc,
+        live: &'a FuncLiveness,
+        intervals: &'a IntervalSet,
+    ) -> Self {
+        Self {
+            allocs,
+            func,
+            live,
+            intervals,
+            by_vreg: intervals.pieces_by_vreg(),
+            spill_slots: HashMap::new(),
+        }
+    }
+
+    pub fn run(mut self) {
+        // 先按原来的指令编号算出所有复制, 再改动指令序列
+        let split_moves = self.collect_split_moves();
+        let edge_moves = self.collect_edge_moves();
+        self.rewrite_operands();
+        for (pos, moves) in split_moves {
+            let anchor = self.live.insts[pos as usize / 2];
+            self.emit_parallel_move(&moves, anchor);
+        }
+        for (pred, succ, moves) in edge_moves {
+            let anchor = self.edge_anchor(pred, succ);
+            self.emit_parallel_move(&moves, anchor);
+        }
+        self.save_callee_saved();
+    }
+
+    fn location_at(&self, vreg: u32, pos: u32) -> Location {
+        let pieces = &self.intervals.pieces;
+        self.by_vreg[vreg as usize]
+            .iter()
+            .map(|&id| &pieces[id])
+            .find(|piece| piece.covers(pos))
+            .and_then(|piece| piece.loc)
+            .unwrap_or_else(|| panic!("%v{vreg} is not allocated at position {pos}"))
+    }
+
+    /// 基本块内部区间被拆开的地方. 键是插入复制的位置, 复制插在这个位置的指令之前.
+    fn collect_split_moves(&self) -> BTreeMap<u32, Vec<Move>> {
+        let pieces = &self.intervals.pieces;
+        let mut moves: BTreeMap<u32, Vec<Move>> = BTreeMap::new();
+        for ids in &self.by_vreg {
+            for &id in ids {
+                let piece = &pieces[id];
+                let pos = piece.start();
+                // 奇数位置是定义, 值直接写到新位置
+                if pos % 2 == 1 || self.live.is_block_start(pos) {
+                    continue;
+                }
+                let prev = ids
+                    .iter()
+                    .map(|&id| &pieces[id])
+                    .find(|p| p.covers(pos - 1));
+                let (Some(prev), Some(to)) = (prev, piece.loc) else {
+                    continue;
+                };
+                let from = prev.loc.expect("every piece should be allocated");
+                if from != to {
+                    let mv = Move { vreg: piece.vreg, bank: piece.bank, from, to };
+                    moves.entry(pos).or_default().push(mv);
+                }
+            }
+        }
+        moves
+    }
+
+    /// 基本块边界两侧位置不同的值. 每条边一组: `(前驱, 后继, 复制)`.
+    fn collect_edge_moves(&self) -> Vec<(usize, usize, Vec<Move>)> {
+        let live = self.live;
+        let mut edges = Vec::new();
+        for (pred, succs) in live.succs.iter().enumerate() {
+            let pred_end = live.block_ranges[pred].end - 1;
+            for &succ in succs {
+                let succ_start = live.block_ranges[succ].start;
+                let mut moves = Vec::new();
+                for key in &live.live_in[succ] {
+                    let Some(bank) = live.vreg_banks.get(key).copied().flatten() else {
+                        continue;
+                    };
+                    let vreg = key as u32;
+                    let from = self.location_at(vreg, pred_end);
+                    let to = self.location_at(vreg, succ_start);
+                    if from != to {
+                        moves.push(Move { vreg, bank, from, to });
+                    }
+                }
+                if !moves.is_empty() {
+                    edges.push((pred, succ, moves));
+                }
+            }
+        }
+        edges
+    }
+
+    fn rewrite_operands(&self) {
+        for (k, &inst_id) in self.live.insts.iter().enumerate() {
+            let inst = inst_id.get_inst(self.allocs);
+            for (index, op) in inst.operands().iter().enumerate() {
+                let MirOperand::VReg(vreg) = op.get() else {
+                    continue;
+                };
+                let pos = match operand_role(inst, index) {
+                    OperandRole::Def => k as u32 * 2 + 1,
+                    _ => k as u32 * 2,
+                };
+                let Location::Reg(num) = self.location_at(vreg.get_id(), pos) else {
+                    panic!("%v{} is on the stack where it is used", vreg.get_id());
+                };
+                op.set(PReg::new(num, vreg.get_class()).into());
+            }
+        }
+    }
+
+    /// 边上的复制插在哪条指令前面: 后继只有这一个前驱时放在后继开头, 前驱只有这一个后继时
+    /// 放在前驱的跳转之前, 否则拆开这条边.
+    fn edge_anchor(&mut self, pred: usize, succ: usize) -> MirInstID {
+        let allocs = self.allocs;
+        let (pred_bb, succ_bb) = (self.live.blocks[pred], self.live.blocks[succ]);
+        if self.live.preds[succ].len() == 1 {
+            return succ_bb
+                .get_insts(allocs)
+                .get_front_id(&allocs.insts)
+                .expect("MIR block should not be empty");
+        }
+        let first_term = pred_bb
+            .get_first_terminator(allocs)
+            .expect("MIR block should end with a terminator");
+        let reads_reg = pred_bb
+            .insts_iter(allocs)
+            .filter(|(_, obj)| obj.get_inst().is_terminator())
+            .any(|(_, obj)| obj.get_inst().operands().iter().any(|op| op.get().is_reg()));
+        if self.live.succs[pred].len() == 1 && !reads_reg {
+            return first_term;
+        }
+        self.split_edge(pred_bb, succ_bb)
+    }
+
+    /// 在 `pred` 和 `succ` 之间插入一个只有跳转的基本块, 返回这条跳转指令.
+    fn split_edge(&mut self, pred: MirBlockID, succ: MirBlockID) -> MirInstID {
+        let allocs = self.allocs;
+        let name = format!("{}.{}", pred.get_name(allocs), succ.get_name(allocs));
+        let mid = MirBlockID::new(allocs, name.as_str());
+        let jump = UncondBr::new(MirOP::B, MirOperand::Label(succ));
+        let jump = mid.push_inst(allocs, jump.into_mir());
+        self.func.push_block(allocs, mid);
+
+        for (_, obj) in pred.insts_iter(allocs) {
+            let inst = obj.get_inst();
+            if !inst.is_terminator() {
+                continue;
+            }
+            for op in inst.operands() {
+                match op.get() {
+                    MirOperand::Label(bb) if bb == succ => op.set(MirOperand::Label(mid)),
+                    MirOperand::SwitchTab(tab) => {
+                        let tab = &mut self.func.switch_tabs[tab.get_index()];
+                        if tab.default == succ {
+                            tab.default = mid;
+                        }
+                        for (_, bb) in tab.cases.iter_mut().filter(|(_, bb)| *bb == succ) {
+                            *bb = mid;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        jump
+    }
+
+    fn spill_slot(&mut self, vreg: u32) -> StackSlotID {
+        let func = &mut *self.func;
+        let wide = self.live.vreg_wide[vreg as usize];
+        *self.spill_slots.entry(vreg).or_insert_with(|| {
+            // 总是按 64 位存取, 32 位视图不受影响. 128 位的向量值按 128 位存取.
+            let (size, align_log2) = if wide { (16, 4) } else { (8, 3) };
+            func.add_stack_slot(MirStackSlot { kind: MirStackSlotKind::Spill, size, align_log2 })
+        })
+    }
+    /// 搬运 `vreg` 时所用的物理寄存器视图: 128 位的向量值用 `q` 视图, 其余用 64 位视图.
+    fn move_reg(&self, mv: &Move, num: u8) -> PReg {
+        if self.live.vreg_wide[mv.vreg as usize] { PReg::q(num) } else { full_reg(mv.bank, num) }
+    }
+
+    /// 在 `anchor` 之前执行一组并行复制: 先把要溢出的寄存器存进栈槽, 再做寄存器之间的复制,
+    /// 最后从栈槽加载.
+    fn emit_parallel_move(&mut self, moves: &[Move], anchor: MirInstID) {
+        let mut insts = Vec::new();
+        for mv in moves {
+            if let (Location::Reg(num), Location::Stack) = (mv.from, mv.to) {
+                let slot = MirOperand::StackSlot(self.spill_slot(mv.vreg));
+                let src = self.move_reg(mv, num);
+                let (class, src, sp) = (src.get_class(), src.into(), PReg::SP.into());
+                insts.push(match class {
+                    MirRegClass::GPR64 => {
+                        StoreGr64BaseS::new(MirOP::StrGr64BaseS, src, sp, slot).into_mir()
+                    }
+                    MirRegClass::FPR128 => {
+                        StoreF128BaseS::new(MirOP::StrF128BaseS, src, sp, slot).into_mir()
+                    }
+                    _ => StoreF64BaseS::new(MirOP::StrF64BaseS, src, sp, slot).into_mir(),
+                });
+            }
+        }
+        for bank in [MirRegBank::GPR, MirRegBank::FPR] {
+            // 有一个 128 位的向量值时整组都按 128 位复制, 免得成环时暂存寄存器只保住低 64 位.
+            let wide = moves
+                .iter()
+                .any(|mv| mv.bank == bank && self.live.vreg_wide[mv.vreg as usize]);
+            let view = |num| if wide { PReg::q(num) } else { full_reg(bank, num) };
+            let copies: Vec<(PReg, PReg)> = moves
+                .iter()
+                .filter(|mv| mv.bank == bank)
+                .filter_map(|mv| match (mv.from, mv.to) {
+                    (Location::Reg(src), Location::Reg(dst)) => Some((view(dst), view(src))),
+                    _ => None,
+                })
+                .collect();
+            let scratch = view(scratch_reg(bank).get_num());
+            for (dst, src) in sequentialize_copies(&copies, |_| scratch) {
+                let (class, dst, src) = (dst.get_class(), dst.into(), src.into());
+                insts.push(match class {
+                    MirRegClass::GPR64 => MirCopy64::new(MirOP::MirCopy64, dst, src).into_mir(),
+                    MirRegClass::FPR128 => {
+                        MirFCopy128::new(MirOP::MirFCopy128, dst, src).into_mir()
+                    }
+                    _ => MirFCopy64::new(MirOP::MirFCopy64, dst, src).into_mir(),
+                });
+            }
+        }
+        for mv in moves {
+            if let (Location::Stack, Location::Reg(num)) = (mv.from, mv.to) {
+                let slot = MirOperand::StackSlot(self.spill_slot(mv.vreg));
+                let dst = self.move_reg(mv, num);
+                let (class, dst, sp) = (dst.get_class(), dst.into(), PReg::SP.into());
+                insts.push(match class {
+                    MirRegClass::GPR64 => {
+                        LoadGr64BaseS::new(MirOP::LdrGr64BaseS, dst, sp, slot).into_mir()
+                    }
+                    MirRegClass::FPR128 => {
+                        LoadF128BaseS::new(MirOP::LdrF128BaseS, dst, sp, slot).into_mir()
+                    }
+                    _ => LoadF64BaseS::new(MirOP::LdrF64BaseS, dst, sp, slot).into_mir(),
+                });
+            }
+        }
+        self.insert_before(anchor, insts);
+    }
+
+    fn insert_before(&self, anchor: MirInstID, insts: Vec<MirInst>) {
+        for inst in insts {
+            anchor.insert_before(self.allocs, MirInstID::from_mir(self.allocs, inst));
+        }
+    }
+
+    /// 在入口保存用到的被调用者保存寄存器, 在每个 `MirReturn` 之前恢复.
+    fn save_callee_saved(&self) {
+        let used: BTreeSet<(MirRegBank, u8)> = self
+            .intervals
+            .pieces
+            .iter()
+            .filter_map(|piece| match piece.loc {
+                Some(Location::Reg(num)) if is_callee_saved(piece.bank, num) => {
+                    Some((piece.bank, num))
+                }
+                _ => None,
+            })
+            .collect();
+        if used.is_empty() {
+            return;
+        }
+        let regs: Vec<PReg> = used
+            .into_iter()
+            .map(|(bank, num)| full_reg(bank, num))
+            .collect();
+        let allocs = self.allocs;
+        let mut returns = Vec::new();
+        for (bb, _) in self.func.blocks_iter(allocs) {
+            for (inst_id, obj) in bb.insts_iter(allocs) {
+                if obj.get_inst().strip_comment().get_opcode() == MirOP::MirReturn {
+                    returns.push(inst_id);
+                }
+            }
+        }
+        for ret in returns {
+            self.insert_before(ret, vec![MirRestoreRegs::new(&regs).into_mir()]);
+        }
+        let entry = self
+            .func
+            .get_entry(allocs)
+            .expect("function should have blocks");
+        let first = entry
+            .get_insts(allocs)
+            .get_front_id(&allocs.insts)
+            .expect("MIR block should not be empty");
+        self.insert_before(first, vec![MirSaveRegs::new(&regs).into_mir()]);
+    }
+}
@@ -0,0 +1,14 @@
+use crate::mir::MirOperand;
+use std::cell::Cell;
+
+/// 检查 `operand` 已经是一个寄存器.
+///
+/// 带写回的加载指令 (`LoadRRIndexed` 系列) 在构造函数里用它保证数据寄存器已经确定,
+/// 不能先留空再填写.
+pub(crate) fn mark_operand_used(operand: &Cell<MirOperand>) {
+    let operand = operand.get();
+    assert!(
+        operand.is_reg(),
+        "operand {operand:?} of a write-back load must be a register"
+    );
+}
@@ -0,0 +1,201 @@
+//! MIR 校验. 检查指令的操作数是否符合指令类的约束、基本块是否正确结束、引用是否有效.
+
+use crate::{
+    SymbolStr,
+    mir::{
+        MirBlockID, MirFunc, MirGlobalID, MirInstID, MirModule, MirOP, MirOperand, MirOperandKind,
+        MirRegClass,
+    },
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MirVerifyErr {
+    #[error("function `{0}` has no basic blocks")]
+    EmptyFunc(SymbolStr),
+    #[error("block {1:?} of function `{0}` is unexpectedly dead")]
+    DeadBlock(SymbolStr, MirBlockID),
+    #[error("block {1:?} in function `{0}` believes it belongs to {2:?}")]
+    BlockParentMismatch(SymbolStr, MirBlockID, Option<MirGlobalID>),
+    #[error("instruction {1:?} in block {0:?} believes it belongs to {2:?}")]
+    InstParentMismatch(MirBlockID, MirInstID, Option<MirBlockID>),
+    #[error("block {0:?} does not end with an unconditional terminator")]
+    BlockNotTerminated(MirBlockID),
+    #[error("terminator {1:?} in block {0:?} is followed by a non-terminator")]
+    TerminatorInMiddle(MirBlockID, MirInstID),
+
+    #[error("instruction {0:?} ({1:?}) has {2} operands but {3} operand kinds")]
+    OperandCountMismatch(MirInstID, MirOP, usize, usize),
+    #[error(
+        "operand #{index} of instruction {inst:?} ({opcode:?}) is {found:?}, expected {kind:?}"
+    )]
+    OperandKindMismatch {
+        inst: MirInstID,
+        opcode: MirOP,
+        index: usize,
+        kind: MirOperandKind,
+        found: MirOperand,
+    },
+    #[error("virtual register %v{0} is used as both {1} and {2}")]
+    VRegClassConflict(u32, MirRegClass, MirRegClass),
+    #[error("virtual register %v{0} is out of range: function `{2}` allocated {1} vregs")]
+    VRegOutOfRange(u32, u32, SymbolStr),
+    #[error("instruction {0:?} jumps to block {1:?} outside of function `{2}`")]
+    LabelOutOfFunc(MirInstID, MirBlockID, SymbolStr),
+    #[error("instruction {0:?} refers to a nonexistent global #{1}")]
+    GlobalOutOfRange(MirInstID, u32),
+    #[error("instruction {0:?} refers to a nonexistent switch table jt{1}")]
+    SwitchTabOutOfRange(MirInstID, u32),
+    #[error("switch table jt{0} of function `{1}` jumps to block {2:?} outside of the function")]
+    SwitchTabOutOfFunc(u32, SymbolStr, MirBlockID),
+    #[error("instruction {0:?} refers to a nonexistent stack slot ss{1}")]
+    StackSlotOutOfRange(MirInstID, u32),
+}
+pub type MirVerifyRes<T = ()> = Result<T, MirVerifyErr>;
+
+pub fn verify_mir_module(module: &MirModule) -> MirVerifyRes {
+    for (id, func) in module.funcs_iter() {
+        if !func.is_extern() {
+            verify_mir_func(module, id)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn verify_mir_func(module: &MirModule, func_id: MirGlobalID) -> MirVerifyRes {
+    let func = module.get_func(func_id);
+    let allocs = &module.allocs;
+    let mut verifier = MirFuncVerifier {
+        module,
+        func,
+        blocks: Vec::new(),
+        vreg_classes: HashMap::new(),
+    };
+    for (bb, obj) in func.blocks_iter(allocs) {
+        if obj.get_parent_func() != Some(func_id) {
+            let parent = obj.get_parent_func();
+            return Err(MirVerifyErr::BlockParentMismatch(
+                func.name.clone(),
+                bb,
+                parent,
+            ));
+        }
+        verifier.blocks.push(bb);
+    }
+    if verifier.blocks.is_empty() {
+        return Err(MirVerifyErr::EmptyFunc(func.name.clone()));
+    }
+    for (i, tab) in func.switch_tabs.iter().enumerate() {
+        if let Some(bb) = tab.iter_targets().find(|bb| !verifier.blocks.contains(bb)) {
+            return Err(MirVerifyErr::SwitchTabOutOfFunc(
+                i as u32,
+                func.name.clone(),
+                bb,
+            ));
+        }
+    }
+    for bb in verifier.blocks.clone() {
+        verifier.verify_block(bb)?;
+    }
+    Ok(())
+}
+
+struct MirFuncVerifier<'mir> {
+    module: &'mir MirModule,
+    func: &'mir MirFunc,
+    blocks: Vec<MirBlockID>,
+    vreg_classes: HashMap<u32, MirRegClass>,
+}
+
+impl<'mir> MirFuncVerifier<'mir> {
+    fn verify_block(&mut self, bb: MirBlockID) -> MirVerifyRes {
+        let allocs = &self.module.allocs;
+        if !bb.is_alive(allocs) {
+            return Err(MirVerifyErr::DeadBlock(self.func.name.clone(), bb));
+        }
+        let mut seen_terminator = None;
+        let mut last = None;
+        for (inst_id, obj) in bb.insts_iter(allocs) {
+            if obj.get_parent() != Some(bb) {
+                return Err(MirVerifyErr::InstParentMismatch(
+                    bb,
+                    inst_id,
+                    obj.get_parent(),
+                ));
+            }
+            let inst = obj.get_inst();
+            if matches!(inst.get_opcode(), MirOP::MirComment) {
+                continue;
+            }
+            if inst.is_terminator() {
+                seen_terminator.get_or_insert(inst_id);
+            } else if let Some(term) = seen_terminator {
+                return Err(MirVerifyErr::TerminatorInMiddle(bb, term));
+            }
+            self.verify_inst(inst_id)?;
+            last = Some(inst);
+        }
+        match last {
+            Some(inst) if inst.is_uncond_terminator() => Ok(()),
+            _ => Err(MirVerifyErr::BlockNotTerminated(bb)),
+        }
+    }
+
+    fn verify_inst(&mut self, inst_id: MirInstID) -> MirVerifyRes {
+        let inst = inst_id.get_inst(&self.module.allocs);
+        let opcode = inst.get_opcode();
+        let operands = inst.operands();
+        let kinds = inst.operand_kinds();
+        if operands.len() != kinds.len() {
+            let (nops, nkinds) = (operands.len(), kinds.len());
+            return Err(MirVerifyErr::OperandCountMismatch(
+                inst_id, opcode, nops, nkinds,
+            ));
+        }
+        for (index, (operand, &kind)) in operands.iter().zip(kinds).enumerate() {
+            let operand = operand.get();
+            if !kind.accepts(operand) {
+                return Err(MirVerifyErr::OperandKindMismatch {
+                    inst: inst_id,
+                    opcode,
+                    index,
+                    kind,
+                    found: operand,
+                });
+            }
+            self.verify_operand_ref(inst_id, operand)?;
+        }
+        Ok(())
+    }
+
+    fn verify_operand_ref(&mut self, inst_id: MirInstID, operand: MirOperand) -> MirVerifyRes {
+        let func = self.func;
+        match operand {
+            MirOperand::VReg(vreg) => {
+                let id = vreg.get_id();
+                if id >= func.num_vregs() {
+                    let name = func.name.clone();
+                    return Err(MirVerifyErr::VRegOutOfRange(id, func.num_vregs(), name));
+                }
+                let class = *self.vreg_classes.entry(id).or_insert(vreg.get_class());
+                if class.get_bank() != vreg.get_class().get_bank() {
+                    return Err(MirVerifyErr::VRegClassConflict(id, class, vreg.get_class()));
+                }
+            }
+            MirOperand::Label(bb) if !self.blocks.contains(&bb) => {
+                return Err(MirVerifyErr::LabelOutOfFunc(inst_id, bb, func.name.clone()));
+            }
+            MirOperand::Global(global) if self.module.try_get_global(global).is_none() => {
+                return Err(MirVerifyErr::GlobalOutOfRange(inst_id, global.0));
+            }
+            MirOperand::SwitchTab(tab) if tab.get_index() >= func.switch_tabs.len() => {
+                return Err(MirVerifyErr::SwitchTabOutOfRange(inst_id, tab.0));
+            }
+            MirOperand::StackSlot(slot) if slot.get_index() >= func.stack_slots.len() => {
+                return Err(MirVerifyErr::StackSlotOutOfRange(inst_id, slot.0));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,20 @@ mod analysis;
 mod transforms;
 
 pub use self::{
-    analysis::{cfg::*, dfs::*, dominance::*},
-    transforms::{IFuncTransformPass, basic_dce::*, mem2reg::*},
+    analysis::{
+        cfg::*,
+        dataflow::{avail_expr::*, liveness::*, reaching_defs::*, *},
+        dfs::*,
+        dominance::*,
+        known_bits::*,
+        loops::*,
+        scev::*,
+        value_range::*,
+    },
+    transforms::{
+        IFuncTransformPass, IModuleTransformPass, basic_dce::*, critical_edge::*, dead_arg_elim::*,
+        int_legalize::*, ipcp::*, jump_threading::*, loop_deletion::*, loop_rotate::*,
+        loop_simplify::*, loop_strength_reduce::*, loop_unroll::*, loop_unswitch::*, mem2reg::*,
+        phi_elim::*, pre::*, sccp::*, slp_vectorize::*, switch_lower::*,
+    },
 };
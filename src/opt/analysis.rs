@@ -1,4 +1,9 @@
 pub mod cfg;
+pub mod dataflow;
 pub mod dfs;
 pub mod dominance;
+pub mod known_bits;
 pub mod live_interval;
+pub mod loops;
+pub mod scev;
+pub mod value_range;
@@ -60,7 +60,7 @@ impl CfgBlockStat {
     }
 }
 
-#[derive(thiserror::Error)]
+#[derive(Clone, thiserror::Error)]
 pub enum CfgErr {
     #[error("function {0:?} is extern")]
     FuncIsExtern(FuncID),
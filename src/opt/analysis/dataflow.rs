@@ -0,0 +1,559 @@
+//! Generic monotone dataflow framework.
+//!
+//! 提供单调数据流分析的通用求解器:
+//!
+//! * [`IDataflowLattice`]: 数据流事实所在的格 (join / bottom / top / 相等判断);
+//! * [`IDataflowProblem`]: 数据流问题, 包括方向、边界条件、基本块级 / 指令级传递函数,
+//!   以及对 `BrInst` / `SwitchInst` 出边敏感的边传递函数;
+//! * [`DataflowResults`]: 求解器, 按 `CfgDfsSeq` 的 (逆) 后序迭代到不动点,
+//!   并提供按 `BlockID` 和 `InstID` 查询的结果.
+//!
+//! 活跃变量、到达定值与可用表达式分析作为示例客户端放在子模块中.
+
+use crate::{
+    ir::{BlockID, FuncID, IRAllocs, ISubInstID, InstID, InstObj, TerminatorID, ValueSSA},
+    opt::{CfgBlockStat, CfgDfsSeq, CfgRes, CfgSnapshot, DfsOrder},
+};
+use smallvec::SmallVec;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    hash::Hash,
+};
+
+pub mod avail_expr;
+pub mod liveness;
+pub mod reaching_defs;
+
+/// 数据流事实所在的半格.
+///
+/// `bottom` 是 `join` 的单位元, 求解器用它初始化所有尚未求值的程序点;
+/// `top` 是最保守的事实.
+pub trait IDataflowLattice: Clone + Eq {
+    fn bottom() -> Self;
+    fn top() -> Self;
+    fn join(&self, other: &Self) -> Self;
+
+    /// 把 `other` 合并到 `self` 中, 返回 `self` 是否发生了变化.
+    fn join_assign(&mut self, other: &Self) -> bool {
+        let joined = self.join(other);
+        if joined == *self {
+            return false;
+        }
+        *self = joined;
+        true
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataflowDirection {
+    Forward,
+    Backward,
+}
+
+/// 一条控制流边及其上的分支条件, 供边敏感的传递函数使用.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataflowEdgeKind {
+    /// 无条件跳转, 或者所有分支都指向同一个基本块
+    Jump,
+    /// `br` 条件为真时的出边
+    BrThen(ValueSSA),
+    /// `br` 条件为假时的出边
+    BrElse(ValueSSA),
+    /// `switch` 的出边. `cases` 为跳转到目标块的所有 case 值,
+    /// `is_default` 表示目标块同时也是 default 块.
+    Switch { discrim: ValueSSA, cases: SmallVec<[i64; 2]>, is_default: bool },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataflowEdge {
+    pub from: BlockID,
+    pub to: BlockID,
+    pub kind: DataflowEdgeKind,
+}
+
+impl DataflowEdge {
+    pub fn new(allocs: &IRAllocs, from: BlockID, to: BlockID) -> Self {
+        let kind = match from.get_terminator(allocs) {
+            TerminatorID::Br(br) => {
+                let cond = br.get_cond(allocs);
+                match (br.get_then(allocs), br.get_else(allocs)) {
+                    (then_bb, else_bb) if then_bb == else_bb => DataflowEdgeKind::Jump,
+                    (Some(then_bb), _) if then_bb == to => DataflowEdgeKind::BrThen(cond),
+                    _ => DataflowEdgeKind::BrElse(cond),
+                }
+            }
+            TerminatorID::Switch(switch) => {
+                let cases = switch
+                    .cases_iter(allocs)
+                    .filter(|&(_, _, bb)| bb == Some(to))
+                    .map(|(_, val, _)| val)
+                    .collect();
+                DataflowEdgeKind::Switch {
+                    discrim: switch.get_discrim(allocs),
+                    cases,
+                    is_default: switch.get_default_bb(allocs) == Some(to),
+                }
+            }
+            _ => DataflowEdgeKind::Jump,
+        };
+        Self { from, to, kind }
+    }
+}
+
+/// 一个单调数据流问题.
+///
+/// 传递函数的方向与 `DIRECTION` 一致: 前向分析中 `fact` 从基本块入口流向出口,
+/// 后向分析中则从出口流向入口.
+pub trait IDataflowProblem {
+    type Fact: IDataflowLattice;
+    const DIRECTION: DataflowDirection;
+
+    /// 边界条件: 前向分析为入口块的 IN, 后向分析为出口块的 OUT.
+    fn boundary_fact(&self) -> Self::Fact {
+        Self::Fact::bottom()
+    }
+
+    /// 基本块级的传递函数. 默认按分析方向依次对每条指令调用 `transfer_inst`.
+    fn transfer_block(&self, allocs: &IRAllocs, block: BlockID, fact: &mut Self::Fact) {
+        for inst in block_insts(allocs, block, Self::DIRECTION) {
+            self.transfer_inst(allocs, inst, fact);
+        }
+    }
+
+    /// 指令级的传递函数. 只有在没有重写 `transfer_block` 时才会被调用.
+    fn transfer_inst(&self, allocs: &IRAllocs, inst: InstID, fact: &mut Self::Fact) {
+        let _ = (allocs, inst, fact);
+    }
+
+    /// 边传递函数: 事实沿着 `edge` 流动时的变换. 默认不做变换.
+    fn transfer_edge(&self, allocs: &IRAllocs, edge: &DataflowEdge, fact: &mut Self::Fact) {
+        let _ = (allocs, edge, fact);
+    }
+}
+
+/// 按分析方向排列的基本块指令, 不包含指令链表的引导结点.
+pub fn block_insts(
+    allocs: &IRAllocs,
+    block: BlockID,
+    direction: DataflowDirection,
+) -> SmallVec<[InstID; 16]> {
+    let mut insts: SmallVec<[InstID; 16]> = block
+        .insts_iter(allocs)
+        .filter(|(_, inst)| !matches!(inst, InstObj::GuideNode(_) | InstObj::PhiInstEnd(_)))
+        .map(|(id, _)| id)
+        .collect();
+    if direction == DataflowDirection::Backward {
+        insts.reverse();
+    }
+    insts
+}
+
+/// 数据流分析的求解结果.
+///
+/// `block_in` / `block_out` 总是按程序顺序理解: `block_in` 是基本块入口处的事实,
+/// `block_out` 是出口处的事实, 与分析方向无关. 入口不可达的基本块没有结果.
+pub struct DataflowResults<F> {
+    pub direction: DataflowDirection,
+    pub block_in: HashMap<BlockID, F>,
+    pub block_out: HashMap<BlockID, F>,
+}
+
+/// 某条指令前后的数据流事实 (按程序顺序).
+#[derive(Debug, Clone)]
+pub struct InstFacts<F> {
+    pub inst: InstID,
+    pub before: F,
+    pub after: F,
+}
+
+impl<F: IDataflowLattice> DataflowResults<F> {
+    /// 对函数 `func` 求解数据流问题 `problem`.
+    pub fn solve<P>(problem: &P, allocs: &IRAllocs, func: FuncID) -> CfgRes<Self>
+    where
+        P: IDataflowProblem<Fact = F>,
+    {
+        let cfg = CfgSnapshot::new(allocs, func)?;
+        let direction = P::DIRECTION;
+        // 前向分析按逆后序, 后向分析按后序处理基本块
+        let order = match direction {
+            DataflowDirection::Forward => DfsOrder::RevPost,
+            DataflowDirection::Backward => DfsOrder::Post,
+        };
+        let dfs = CfgDfsSeq::new(allocs, func, order)?;
+        let blocks: Vec<BlockID> = dfs
+            .nodes
+            .iter()
+            .filter_map(|node| match node.block {
+                CfgBlockStat::Block(b) => Some(b),
+                CfgBlockStat::Virtual => None,
+            })
+            .collect();
+        let index: HashMap<BlockID, usize> =
+            blocks.iter().enumerate().map(|(i, &b)| (b, i)).collect();
+
+        // `input` 是传递函数的输入端, `output` 是输出端
+        let mut input: HashMap<BlockID, F> = HashMap::new();
+        let mut output: HashMap<BlockID, F> = blocks.iter().map(|&b| (b, F::bottom())).collect();
+        let mut worklist: BTreeSet<usize> = (0..blocks.len()).collect();
+        let mut edge_cache: HashMap<(BlockID, BlockID), DataflowEdge> = HashMap::new();
+        let mut edge = |from: BlockID, to: BlockID| {
+            edge_cache
+                .entry((from, to))
+                .or_insert_with(|| DataflowEdge::new(allocs, from, to))
+                .clone()
+        };
+
+        while let Some(i) = worklist.pop_first() {
+            let block = blocks[i];
+            let (sources, is_boundary) = match direction {
+                DataflowDirection::Forward => {
+                    (cfg.pred_of(block).unwrap_or(&[]), block == cfg.entry)
+                }
+                DataflowDirection::Backward => {
+                    let succs = cfg.succ_of(block).unwrap_or(&[]);
+                    (succs, succs.is_empty())
+                }
+            };
+            let mut fact = if is_boundary { problem.boundary_fact() } else { F::bottom() };
+            for &src in sources {
+                let Some(src_fact) = output.get(&src) else {
+                    continue;
+                };
+                let mut src_fact = src_fact.clone();
+                let e = match direction {
+                    DataflowDirection::Forward => edge(src, block),
+                    DataflowDirection::Backward => edge(block, src),
+                };
+                problem.transfer_edge(allocs, &e, &mut src_fact);
+                fact.join_assign(&src_fact);
+            }
+            input.insert(block, fact.clone());
+            problem.transfer_block(allocs, block, &mut fact);
+            if output.get(&block) == Some(&fact) {
+                continue;
+            }
+            output.insert(block, fact);
+            let targets = match direction {
+                DataflowDirection::Forward => cfg.succ_of(block),
+                DataflowDirection::Backward => cfg.pred_of(block),
+            };
+            for target in targets.unwrap_or(&[]) {
+                if let Some(&j) = index.get(target) {
+                    worklist.insert(j);
+                }
+            }
+        }
+
+        let (block_in, block_out) = match direction {
+            DataflowDirection::Forward => (input, output),
+            DataflowDirection::Backward => (output, input),
+        };
+        Ok(Self { direction, block_in, block_out })
+    }
+
+    pub fn block_in(&self, block: BlockID) -> Option<&F> {
+        self.block_in.get(&block)
+    }
+    pub fn block_out(&self, block: BlockID) -> Option<&F> {
+        self.block_out.get(&block)
+    }
+
+    /// 在基本块内按指令重放传递函数, 得到每条指令前后的事实 (按程序顺序排列).
+    ///
+    /// 只有在 `problem` 使用默认的 `transfer_block` 时, 结果才与基本块级结果一致.
+    pub fn block_inst_facts<P>(
+        &self,
+        problem: &P,
+        allocs: &IRAllocs,
+        block: BlockID,
+    ) -> Option<Vec<InstFacts<F>>>
+    where
+        P: IDataflowProblem<Fact = F>,
+    {
+        let mut fact = match self.direction {
+            DataflowDirection::Forward => self.block_in(block)?.clone(),
+            DataflowDirection::Backward => self.block_out(block)?.clone(),
+        };
+        let mut facts = Vec::new();
+        for inst in block_insts(allocs, block, self.direction) {
+            let start = fact.clone();
+            problem.transfer_inst(allocs, inst, &mut fact);
+            let (before, after) = match self.direction {
+                DataflowDirection::Forward => (start, fact.clone()),
+                DataflowDirection::Backward => (fact.clone(), start),
+            };
+            facts.push(InstFacts { inst, before, after });
+        }
+        if self.direction == DataflowDirection::Backward {
+            facts.reverse();
+        }
+        Some(facts)
+    }
+
+    /// 指令 `inst` 执行之前 (按程序顺序) 的事实.
+    pub fn fact_before_inst<P>(&self, problem: &P, allocs: &IRAllocs, inst: InstID) -> Option<F>
+    where
+        P: IDataflowProblem<Fact = F>,
+    {
+        self.find_inst_facts(problem, allocs, inst)
+            .map(|f| f.before)
+    }
+    /// 指令 `inst` 执行之后 (按程序顺序) 的事实.
+    pub fn fact_after_inst<P>(&self, problem: &P, allocs: &IRAllocs, inst: InstID) -> Option<F>
+    where
+        P: IDataflowProblem<Fact = F>,
+    {
+        self.find_inst_facts(problem, allocs, inst).map(|f| f.after)
+    }
+    fn find_inst_facts<P>(
+        &self,
+        problem: &P,
+        allocs: &IRAllocs,
+        inst: InstID,
+    ) -> Option<InstFacts<F>>
+    where
+        P: IDataflowProblem<Fact = F>,
+    {
+        let block = inst.get_parent(allocs)?;
+        self.block_inst_facts(problem, allocs, block)?
+            .into_iter()
+            .find(|f| f.inst == inst)
+    }
+}
+
+/// 可能为全集的集合. 数据流分析中的 `top` / `bottom` 往往需要表示 "所有元素".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataflowSet<T: Hash + Eq> {
+    All,
+    Set(HashSet<T>),
+}
+
+impl<T: Hash + Eq> Default for DataflowSet<T> {
+    fn default() -> Self {
+        Self::Set(HashSet::new())
+    }
+}
+
+impl<T: Hash + Eq + Clone> DataflowSet<T> {
+    pub fn contains(&self, value: &T) -> bool {
+        match self {
+            Self::All => true,
+            Self::Set(set) => set.contains(value),
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Set(set) if set.is_empty())
+    }
+    /// 有限集合的元素. 全集返回 `None`.
+    pub fn as_set(&self) -> Option<&HashSet<T>> {
+        match self {
+            Self::All => None,
+            Self::Set(set) => Some(set),
+        }
+    }
+    pub fn insert(&mut self, value: T) {
+        if let Self::Set(set) = self {
+            set.insert(value);
+        }
+    }
+    /// 从集合中删除元素. 全集不能直接删除元素, 会保持为全集.
+    pub fn remove(&mut self, value: &T) {
+        if let Self::Set(set) = self {
+            set.remove(value);
+        }
+    }
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        if let Self::Set(set) = self {
+            set.retain(f);
+        }
+    }
+    pub fn union(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::All, _) | (_, Self::All) => Self::All,
+            (Self::Set(a), Self::Set(b)) => Self::Set(a.union(b).cloned().collect()),
+        }
+    }
+    pub fn intersection(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::All, x) | (x, Self::All) => x.clone(),
+            (Self::Set(a), Self::Set(b)) => Self::Set(a.intersection(b).cloned().collect()),
+        }
+    }
+}
+
+/// "may" 分析的集合格: join 为并集, bottom 为空集.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MaySet<T: Hash + Eq>(pub DataflowSet<T>);
+
+/// "must" 分析的集合格: join 为交集, bottom 为全集.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MustSet<T: Hash + Eq>(pub DataflowSet<T>);
+
+impl<T: Hash + Eq + Clone> IDataflowLattice for MaySet<T> {
+    fn bottom() -> Self {
+        Self(DataflowSet::default())
+    }
+    fn top() -> Self {
+        Self(DataflowSet::All)
+    }
+    fn join(&self, other: &Self) -> Self {
+        Self(self.0.union(&other.0))
+    }
+}
+
+impl<T: Hash + Eq + Clone> IDataflowLattice for MustSet<T> {
+    fn bottom() -> Self {
+        Self(DataflowSet::All)
+    }
+    fn top() -> Self {
+        Self(DataflowSet::default())
+    }
+    fn join(&self, other: &Self) -> Self {
+        Self(self.0.intersection(&other.0))
+    }
+}
+
+impl<T: Hash + Eq> std::ops::Deref for MaySet<T> {
+    type Target = DataflowSet<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T: Hash + Eq> std::ops::DerefMut for MaySet<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T: Hash + Eq> std::ops::Deref for MustSet<T> {
+    type Target = DataflowSet<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T: Hash + Eq> std::ops::DerefMut for MustSet<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{avail_expr::*, liveness::*, reaching_defs::*, *};
+    use crate::{
+        ir::{ISubGlobalID, Module},
+        testing::cases::test_case_cfg_deep_while_br,
+    };
+
+    fn main_func(module: &Module) -> FuncID {
+        module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function")
+    }
+    fn find_inst(allocs: &IRAllocs, block: BlockID, f: impl Fn(&InstObj) -> bool) -> InstID {
+        block
+            .insts_iter(allocs)
+            .find(|(_, inst)| f(inst))
+            .map(|(id, _)| id)
+            .expect("instruction not found")
+    }
+    /// 返回 `(header, body, exit)`: 循环头、循环头的 then 分支和 else 分支.
+    fn loop_blocks(allocs: &IRAllocs, cfg: &CfgSnapshot) -> (BlockID, BlockID, BlockID) {
+        let header = cfg.succ_of(cfg.entry).unwrap()[0];
+        let TerminatorID::Br(br) = header.get_terminator(allocs) else {
+            panic!("loop header should end with br");
+        };
+        (
+            header,
+            br.get_then(allocs).unwrap(),
+            br.get_else(allocs).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_liveness() {
+        let module = test_case_cfg_deep_while_br().module;
+        let allocs = &module.allocs;
+        let func = main_func(&module);
+        let cfg = CfgSnapshot::new(allocs, func).unwrap();
+        let alloca = find_inst(allocs, cfg.entry, |i| matches!(i, InstObj::Alloca(_)));
+
+        let live = Liveness::solve(allocs, func).unwrap();
+        let out = live.block_out(cfg.entry).unwrap();
+        assert_eq!(out.as_set().unwrap().len(), 1);
+        assert!(out.contains(&ValueSSA::Inst(alloca)));
+        assert!(live.block_in(cfg.entry).unwrap().is_empty());
+
+        // alloca 之后 %1 才活跃
+        let before = live.fact_before_inst(&Liveness, allocs, alloca).unwrap();
+        let after = live.fact_after_inst(&Liveness, allocs, alloca).unwrap();
+        assert!(!before.contains(&ValueSSA::Inst(alloca)));
+        assert!(after.contains(&ValueSSA::Inst(alloca)));
+    }
+
+    #[test]
+    fn test_reaching_defs() {
+        let module = test_case_cfg_deep_while_br().module;
+        let allocs = &module.allocs;
+        let func = main_func(&module);
+        let cfg = CfgSnapshot::new(allocs, func).unwrap();
+        let (_, _, exit) = loop_blocks(allocs, &cfg);
+
+        let defs = ReachingDefs::solve(allocs, func).unwrap();
+        let stores_in = |fact: &ReachingSet| {
+            let set = fact.as_set().unwrap();
+            set.iter()
+                .filter(|&&i| ReachingDefs::store_target(allocs, i).is_some())
+                .count()
+        };
+        assert_eq!(stores_in(defs.block_in(exit).unwrap()), 3);
+
+        // 每个 store 都会杀死之前对同一地址的 store
+        for (block, _) in func.blocks_iter(allocs) {
+            for facts in defs.block_inst_facts(&ReachingDefs, allocs, block).unwrap() {
+                if let InstObj::Store(_) = facts.inst.deref_ir(allocs) {
+                    assert_eq!(stores_in(&facts.after), 1);
+                    assert!(facts.after.contains(&facts.inst));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_avail_exprs() {
+        let module = test_case_cfg_deep_while_br().module;
+        let allocs = &module.allocs;
+        let func = main_func(&module);
+        let cfg = CfgSnapshot::new(allocs, func).unwrap();
+        let (header, body, _) = loop_blocks(allocs, &cfg);
+        let load = find_inst(allocs, header, |i| matches!(i, InstObj::Load(_)));
+        let load = ExprKey::from_inst(allocs, load).unwrap();
+
+        let avail = AvailExprs::solve(allocs, func).unwrap();
+        assert!(avail.block_in(cfg.entry).unwrap().is_empty());
+        assert!(!avail.block_in(header).unwrap().contains(&load));
+        assert!(avail.block_in(body).unwrap().contains(&load));
+        assert!(avail.block_out(header).unwrap().contains(&load));
+    }
+
+    #[test]
+    fn test_edge_kind() {
+        let module = test_case_cfg_deep_while_br().module;
+        let allocs = &module.allocs;
+        let cfg = CfgSnapshot::new(allocs, main_func(&module)).unwrap();
+        let (header, body, exit) = loop_blocks(allocs, &cfg);
+
+        let edge = DataflowEdge::new(allocs, cfg.entry, header);
+        assert_eq!(edge.kind, DataflowEdgeKind::Jump);
+        let then_edge = DataflowEdge::new(allocs, header, body);
+        let else_edge = DataflowEdge::new(allocs, header, exit);
+        assert!(matches!(
+            then_edge.kind,
+            DataflowEdgeKind::BrThen(ValueSSA::Inst(_))
+        ));
+        assert!(matches!(
+            else_edge.kind,
+            DataflowEdgeKind::BrElse(ValueSSA::Inst(_))
+        ));
+    }
+}
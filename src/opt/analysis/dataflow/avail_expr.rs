@@ -0,0 +1,82 @@
+//! 可用表达式分析: 前向 must 分析, 事实为在所有路径上都已经计算过的表达式集合.
+
+use crate::{
+    ir::{
+        CmpCond, FuncID, IRAllocs, ISubInst, ISubInstID, ITraceableValue, IUser, InstID, InstObj,
+        Opcode, ValueSSA,
+    },
+    opt::{
+        CfgRes,
+        analysis::dataflow::{
+            DataflowDirection, DataflowResults, DataflowSet, IDataflowProblem, MustSet,
+        },
+    },
+    typing::ValTypeID,
+};
+use smallvec::SmallVec;
+
+/// 表达式的结构化键. 两条指令的键相同, 就说明它们计算的是同一个表达式.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExprKey {
+    pub opcode: Opcode,
+    pub cond: Option<CmpCond>,
+    pub ty: ValTypeID,
+    pub operands: SmallVec<[ValueSSA; 3]>,
+}
+
+impl ExprKey {
+    /// 构造指令对应的表达式键. 只有无副作用的纯计算指令和 `load` 才有表达式键.
+    pub fn from_inst(allocs: &IRAllocs, inst: InstID) -> Option<Self> {
+        let obj = inst.deref_ir(allocs);
+        let cond = match obj {
+            InstObj::Cmp(cmp) => Some(cmp.cond),
+            InstObj::BinOP(_) | InstObj::Cast(_) | InstObj::Select(_) | InstObj::Load(_) => None,
+            _ => return None,
+        };
+        Some(Self {
+            opcode: obj.get_opcode(),
+            cond,
+            ty: obj.get_valtype(),
+            operands: obj.operands_iter().map(|u| u.get_operand(allocs)).collect(),
+        })
+    }
+
+    pub fn is_load(&self) -> bool {
+        self.opcode == Opcode::Load
+    }
+}
+
+/// 可用表达式分析.
+///
+/// SSA 形式下操作数不会被重新定义, 所以纯计算表达式一旦计算就一直可用;
+/// `load` 表达式会被 `store`、`call` 和原子读改写指令杀死 (不做别名分析).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AvailExprs;
+
+pub type AvailSet = MustSet<ExprKey>;
+
+impl AvailExprs {
+    pub fn solve(allocs: &IRAllocs, func: FuncID) -> CfgRes<DataflowResults<AvailSet>> {
+        DataflowResults::solve(&Self, allocs, func)
+    }
+}
+
+impl IDataflowProblem for AvailExprs {
+    type Fact = AvailSet;
+    const DIRECTION: DataflowDirection = DataflowDirection::Forward;
+
+    fn boundary_fact(&self) -> AvailSet {
+        MustSet(DataflowSet::default())
+    }
+
+    fn transfer_inst(&self, allocs: &IRAllocs, inst: InstID, fact: &mut AvailSet) {
+        if matches!(
+            inst.deref_ir(allocs),
+            InstObj::Store(_) | InstObj::Call(_) | InstObj::AmoRmw(_)
+        ) {
+            fact.retain(|expr| !expr.is_load());
+        } else if let Some(expr) = ExprKey::from_inst(allocs, inst) {
+            fact.insert(expr);
+        }
+    }
+}
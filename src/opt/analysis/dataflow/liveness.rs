@@ -0,0 +1,71 @@
+//! 活跃变量分析: 后向 may 分析, 事实为活跃的 SSA 值集合.
+
+use crate::{
+    ir::{BlockID, FuncID, IRAllocs, ISubInstID, IUser, InstID, InstObj, ValueSSA},
+    opt::{
+        CfgRes,
+        analysis::dataflow::{
+            DataflowDirection, DataflowEdge, DataflowResults, IDataflowProblem, MaySet,
+        },
+    },
+};
+
+/// 活跃变量分析. 只跟踪指令结果和函数参数.
+///
+/// Phi 指令的操作数不在 Phi 所在的基本块中使用, 而是在对应前驱块的出口处使用,
+/// 因此由边传递函数处理.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Liveness;
+
+pub type LiveSet = MaySet<ValueSSA>;
+
+impl Liveness {
+    pub fn solve(allocs: &IRAllocs, func: FuncID) -> CfgRes<DataflowResults<LiveSet>> {
+        DataflowResults::solve(&Self, allocs, func)
+    }
+
+    fn is_tracked(value: ValueSSA) -> bool {
+        matches!(value, ValueSSA::Inst(_) | ValueSSA::FuncArg(..))
+    }
+}
+
+impl IDataflowProblem for Liveness {
+    type Fact = LiveSet;
+    const DIRECTION: DataflowDirection = DataflowDirection::Backward;
+
+    fn transfer_inst(&self, allocs: &IRAllocs, inst: InstID, fact: &mut LiveSet) {
+        fact.remove(&ValueSSA::Inst(inst));
+        let obj = inst.deref_ir(allocs);
+        if matches!(obj, InstObj::Phi(_)) {
+            return;
+        }
+        for use_id in obj.operands_iter() {
+            let operand = use_id.get_operand(allocs);
+            if Self::is_tracked(operand) {
+                fact.insert(operand);
+            }
+        }
+    }
+
+    fn transfer_edge(&self, allocs: &IRAllocs, edge: &DataflowEdge, fact: &mut LiveSet) {
+        add_phi_uses(allocs, edge.from, edge.to, fact);
+    }
+}
+
+/// 把 `to` 中 Phi 指令来自 `from` 的操作数加入活跃集合.
+fn add_phi_uses(allocs: &IRAllocs, from: BlockID, to: BlockID, fact: &mut LiveSet) {
+    for (_, inst) in to.insts_iter(allocs) {
+        let InstObj::Phi(phi) = inst else {
+            continue;
+        };
+        for &[val_use, blk_use] in phi.incoming_uses().iter() {
+            if blk_use.get_operand(allocs) != ValueSSA::Block(from) {
+                continue;
+            }
+            let value = val_use.get_operand(allocs);
+            if Liveness::is_tracked(value) {
+                fact.insert(value);
+            }
+        }
+    }
+}
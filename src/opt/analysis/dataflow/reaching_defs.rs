@@ -0,0 +1,50 @@
+//! 到达定值分析: 前向 may 分析, 事实为可能到达当前程序点的定值指令集合.
+
+use crate::{
+    ir::{FuncID, IRAllocs, ISubInstID, ITraceableValue, InstID, InstObj, ValueSSA},
+    opt::{
+        CfgRes,
+        analysis::dataflow::{DataflowDirection, DataflowResults, IDataflowProblem, MaySet},
+    },
+    typing::ValTypeID,
+};
+
+/// 到达定值分析.
+///
+/// SSA 值只定义一次, 所以产生值的指令只生成定值, 从不被杀死.
+/// 内存定值以 `store` 表示: 对同一个地址操作数的 `store` 会杀死之前的 `store`.
+/// 这里不做别名分析, 只有地址操作数完全相同时才会杀死.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReachingDefs;
+
+pub type ReachingSet = MaySet<InstID>;
+
+impl ReachingDefs {
+    pub fn solve(allocs: &IRAllocs, func: FuncID) -> CfgRes<DataflowResults<ReachingSet>> {
+        DataflowResults::solve(&Self, allocs, func)
+    }
+
+    /// `store` 指令的目标地址. 其他指令返回 `None`.
+    pub fn store_target(allocs: &IRAllocs, inst: InstID) -> Option<ValueSSA> {
+        match inst.deref_ir(allocs) {
+            InstObj::Store(store) => Some(store.get_target(allocs)),
+            _ => None,
+        }
+    }
+}
+
+impl IDataflowProblem for ReachingDefs {
+    type Fact = ReachingSet;
+    const DIRECTION: DataflowDirection = DataflowDirection::Forward;
+
+    fn transfer_inst(&self, allocs: &IRAllocs, inst: InstID, fact: &mut ReachingSet) {
+        let obj = inst.deref_ir(allocs);
+        if let InstObj::Store(store) = obj {
+            let target = store.get_target(allocs);
+            fact.retain(|&def| Self::store_target(allocs, def) != Some(target));
+            fact.insert(inst);
+        } else if obj.get_valtype() != ValTypeID::Void {
+            fact.insert(inst);
+        }
+    }
+}
@@ -0,0 +1,637 @@
+//! Known-bits & demanded-bits analysis.
+//!
+//! * [`KnownBitsAnalysis`] 前向分析, 对函数内每个整数值求出一定为 0 / 一定为 1 的位.
+//! * [`DemandedBits`] 后向分析, 求出每条整数指令的结果中有哪些位会被使用者观察到.
+//!
+//! 两者配合可以做 instcombine 风格的化简 (例如消去多余的 `and` 掩码) 以及
+//! `BinOPInst` 的位宽收窄.
+
+use crate::{
+    base::APInt,
+    ir::{
+        ConstData, FuncID, IRAllocs, ISubInst, ISubInstID, ISubValueSSA, IUser, InstID, InstObj,
+        Opcode, UseKind, ValueSSA,
+    },
+    typing::ValTypeID,
+};
+use std::collections::HashMap;
+
+const fn low_mask(bits: u32) -> u128 {
+    if bits >= 128 { u128::MAX } else { (1u128 << bits) - 1 }
+}
+
+/// 从第 0 位到 `mask` 最高的置位 (含) 全部置 1 的掩码.
+const fn fill_below_msb(mask: u128) -> u128 {
+    low_mask(128 - mask.leading_zeros())
+}
+
+/// 一个整数值的已知位. `zero` 中置位的位一定为 0, `one` 中置位的位一定为 1.
+///
+/// `zero` 与 `one` 的位宽总是相同的, 并且两者不会有公共的置位.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KnownBits {
+    pub zero: APInt,
+    pub one: APInt,
+}
+
+impl KnownBits {
+    pub fn new_unknown(bits: u8) -> Self {
+        Self { zero: APInt::new(0u8, bits), one: APInt::new(0u8, bits) }
+    }
+    pub fn from_const(value: APInt) -> Self {
+        Self { zero: !value, one: value }
+    }
+    fn from_masks(zero: u128, one: u128, bits: u8) -> Self {
+        Self { zero: APInt::new(zero, bits), one: APInt::new(one, bits) }
+    }
+
+    pub fn bits(&self) -> u8 {
+        self.one.bits()
+    }
+    fn mask(&self) -> u128 {
+        low_mask(self.bits() as u32)
+    }
+    fn zero_raw(&self) -> u128 {
+        self.zero.as_unsigned()
+    }
+    fn one_raw(&self) -> u128 {
+        self.one.as_unsigned()
+    }
+
+    /// 已知 (为 0 或为 1) 的位
+    pub fn known_mask(&self) -> APInt {
+        self.zero | self.one
+    }
+    pub fn is_unknown(&self) -> bool {
+        self.known_mask().is_zero()
+    }
+    pub fn is_const(&self) -> bool {
+        (self.zero_raw() | self.one_raw()) == self.mask()
+    }
+    pub fn as_const(&self) -> Option<APInt> {
+        self.is_const().then_some(self.one)
+    }
+    /// 该值可能取到的最小无符号值
+    pub fn min_unsigned(&self) -> APInt {
+        self.one
+    }
+    /// 该值可能取到的最大无符号值
+    pub fn max_unsigned(&self) -> APInt {
+        !self.zero
+    }
+    pub fn is_known_negative(&self) -> bool {
+        self.one.is_negative()
+    }
+    pub fn is_known_non_negative(&self) -> bool {
+        self.zero.is_negative()
+    }
+    pub fn count_min_trailing_zeros(&self) -> u32 {
+        self.zero.trailing_ones()
+    }
+    pub fn count_min_leading_zeros(&self) -> u32 {
+        self.zero.leading_ones()
+    }
+
+    /// 格上的交汇: 只保留两边都已知且一致的位.
+    pub fn meet(self, other: Self) -> Self {
+        Self { zero: self.zero & other.zero, one: self.one & other.one }
+    }
+
+    pub fn and_with(self, rhs: Self) -> Self {
+        Self { zero: self.zero | rhs.zero, one: self.one & rhs.one }
+    }
+    pub fn or_with(self, rhs: Self) -> Self {
+        Self { zero: self.zero & rhs.zero, one: self.one | rhs.one }
+    }
+    pub fn xor_with(self, rhs: Self) -> Self {
+        Self {
+            zero: (self.zero & rhs.zero) | (self.one & rhs.one),
+            one: (self.zero & rhs.one) | (self.one & rhs.zero),
+        }
+    }
+    pub fn flip(self) -> Self {
+        Self { zero: self.one, one: self.zero }
+    }
+
+    /// 带进位的加法. `carry` 为 `None` 表示进位未知.
+    fn add_with_carry(self, rhs: Self, carry: Option<bool>) -> Self {
+        let bits = self.bits();
+        let mask = self.mask();
+        let (carry_zero, carry_one) = match carry {
+            Some(c) => (!c, c),
+            None => (false, false),
+        };
+        let (l0, l1) = (self.zero_raw(), self.one_raw());
+        let (r0, r1) = (rhs.zero_raw(), rhs.one_raw());
+        let sum_max = (!l0 & mask)
+            .wrapping_add(!r0 & mask)
+            .wrapping_add(!carry_zero as u128)
+            & mask;
+        let sum_min = l1.wrapping_add(r1).wrapping_add(carry_one as u128) & mask;
+        // 每一位的进位输入是否已知
+        let carry_known_zero = !(sum_max ^ l0 ^ r0) & mask;
+        let carry_known_one = (sum_min ^ l1 ^ r1) & mask;
+        let known = (l0 | l1) & (r0 | r1) & (carry_known_zero | carry_known_one);
+        Self::from_masks(!sum_max & known, sum_min & known, bits)
+    }
+    pub fn add_with(self, rhs: Self) -> Self {
+        self.add_with_carry(rhs, Some(false))
+    }
+    pub fn sub_with(self, rhs: Self) -> Self {
+        self.add_with_carry(rhs.flip(), Some(true))
+    }
+    pub fn mul_with(self, rhs: Self) -> Self {
+        if let (Some(l), Some(r)) = (self.as_const(), rhs.as_const()) {
+            return Self::from_const(l * r);
+        }
+        let bits = self.bits() as u32;
+        let tz = (self.count_min_trailing_zeros() + rhs.count_min_trailing_zeros()).min(bits);
+        Self::from_masks(low_mask(tz), 0, self.bits())
+    }
+    pub fn udiv_with(self, rhs: Self) -> Self {
+        if let (Some(l), Some(r)) = (self.as_const(), rhs.as_const())
+            && r.is_nonzero()
+        {
+            return Self::from_const(l.udiv(r));
+        }
+        // 商不会大于被除数
+        let lz = self.count_min_leading_zeros();
+        Self::from_masks(!low_mask(self.bits() as u32 - lz), 0, self.bits())
+    }
+    pub fn urem_with(self, rhs: Self) -> Self {
+        let bits = self.bits();
+        if let Some(r) = rhs.as_const()
+            && let Some(log2) = r.as_power_of_two()
+        {
+            return self.and_with(Self::from_const(APInt::new(low_mask(log2), bits)));
+        }
+        // 余数既不大于被除数也不大于除数
+        let lz = self
+            .count_min_leading_zeros()
+            .max(rhs.count_min_leading_zeros());
+        Self::from_masks(!low_mask(bits as u32 - lz), 0, bits)
+    }
+
+    pub fn shl_with(self, rhs: Self) -> Self {
+        let bits = self.bits();
+        match rhs.as_const().map(|s| s.as_unsigned()) {
+            Some(s) if s < bits as u128 => {
+                let s = s as u32;
+                Self::from_masks(
+                    (self.zero_raw() << s) | low_mask(s),
+                    self.one_raw() << s,
+                    bits,
+                )
+            }
+            // 移位量超出位宽时结果为 poison, 不做任何假设
+            _ => Self::new_unknown(bits),
+        }
+    }
+    pub fn lshr_with(self, rhs: Self) -> Self {
+        let bits = self.bits();
+        match rhs.as_const().map(|s| s.as_unsigned()) {
+            Some(s) if s < bits as u128 => {
+                let s = s as u32;
+                let high = self.mask() & !(self.mask() >> s);
+                Self::from_masks((self.zero_raw() >> s) | high, self.one_raw() >> s, bits)
+            }
+            _ => {
+                let lz = self.count_min_leading_zeros();
+                Self::from_masks(!low_mask(bits as u32 - lz), 0, bits)
+            }
+        }
+    }
+    pub fn ashr_with(self, rhs: Self) -> Self {
+        let bits = self.bits();
+        match rhs.as_const().map(|s| s.as_unsigned()) {
+            Some(s) if s < bits as u128 => Self { zero: self.zero.ashr(s), one: self.one.ashr(s) },
+            _ => Self::new_unknown(bits),
+        }
+    }
+
+    pub fn zext(self, bits: u8) -> Self {
+        let high = low_mask(bits as u32) & !self.mask();
+        Self::from_masks(self.zero_raw() | high, self.one_raw(), bits)
+    }
+    pub fn sext(self, bits: u8) -> Self {
+        Self { zero: self.zero.sext_to(bits), one: self.one.sext_to(bits) }
+    }
+    pub fn trunc(self, bits: u8) -> Self {
+        Self::from_masks(self.zero_raw(), self.one_raw(), bits)
+    }
+
+    /// 按二元运算的操作码计算结果的已知位.
+    pub fn binop(opcode: Opcode, lhs: Self, rhs: Self) -> Self {
+        match opcode {
+            Opcode::BitAnd => lhs.and_with(rhs),
+            Opcode::BitOr => lhs.or_with(rhs),
+            Opcode::BitXor => lhs.xor_with(rhs),
+            Opcode::Add => lhs.add_with(rhs),
+            Opcode::Sub => lhs.sub_with(rhs),
+            Opcode::Mul => lhs.mul_with(rhs),
+            Opcode::Udiv => lhs.udiv_with(rhs),
+            Opcode::Urem => lhs.urem_with(rhs),
+            Opcode::Shl => lhs.shl_with(rhs),
+            Opcode::Lshr => lhs.lshr_with(rhs),
+            Opcode::Ashr => lhs.ashr_with(rhs),
+            _ => Self::new_unknown(lhs.bits()),
+        }
+    }
+}
+
+/// 函数级的 known-bits 分析.
+///
+/// 采用乐观的不动点迭代: 所有指令一开始处于 "尚未求值" 的状态, 每轮迭代只会让已知位
+/// 变少, 因此循环中的 phi 也能得到尽可能精确的结果.
+pub struct KnownBitsAnalysis {
+    known: HashMap<InstID, KnownBits>,
+}
+
+impl KnownBitsAnalysis {
+    pub fn new(allocs: &IRAllocs, func: FuncID) -> Self {
+        let mut ret = Self { known: HashMap::new() };
+        let insts = int_insts(allocs, func);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &inst in &insts {
+                let Some(new) = ret.visit_inst(allocs, inst) else {
+                    continue;
+                };
+                let merged = match ret.known.get(&inst) {
+                    Some(old) => old.meet(new),
+                    None => new,
+                };
+                if ret.known.insert(inst, merged) != Some(merged) {
+                    changed = true;
+                }
+            }
+        }
+        ret
+    }
+
+    /// 获取某个值的已知位. 非整数值返回 `None`.
+    pub fn get(&self, allocs: &IRAllocs, value: ValueSSA) -> Option<KnownBits> {
+        let ValTypeID::Int(bits) = value.get_valtype(allocs) else {
+            return None;
+        };
+        Some(self.try_get(value).unwrap_or(KnownBits::new_unknown(bits)))
+    }
+
+    /// 与 `get` 相同, 但对尚未求值的指令返回 `None`.
+    fn try_get(&self, value: ValueSSA) -> Option<KnownBits> {
+        match value {
+            ValueSSA::ConstData(ConstData::Int(c)) => Some(KnownBits::from_const(c)),
+            ValueSSA::ConstData(ConstData::Zero(_)) => value.as_apint().map(KnownBits::from_const),
+            ValueSSA::Inst(inst) => self.known.get(&inst).copied(),
+            _ => None,
+        }
+    }
+
+    fn operand(&self, allocs: &IRAllocs, value: ValueSSA) -> Option<KnownBits> {
+        match value {
+            ValueSSA::Inst(inst) => self.known.get(&inst).copied(),
+            _ => self.get(allocs, value),
+        }
+    }
+
+    fn visit_inst(&self, allocs: &IRAllocs, inst_id: InstID) -> Option<KnownBits> {
+        let ValTypeID::Int(bits) = inst_id.get_valtype(allocs) else {
+            return None;
+        };
+        let unknown = KnownBits::new_unknown(bits);
+        let known = match inst_id.deref_ir(allocs) {
+            InstObj::BinOP(binop) => {
+                let lhs = self.operand(allocs, binop.get_lhs(allocs))?;
+                let rhs = self.operand(allocs, binop.get_rhs(allocs))?;
+                KnownBits::binop(binop.get_opcode(), lhs, rhs)
+            }
+            InstObj::Cast(cast) => {
+                let from = cast.get_from(allocs);
+                match cast.get_opcode() {
+                    Opcode::Zext => self.operand(allocs, from)?.zext(bits),
+                    Opcode::Sext => self.operand(allocs, from)?.sext(bits),
+                    Opcode::Trunc => self.operand(allocs, from)?.trunc(bits),
+                    _ => unknown,
+                }
+            }
+            InstObj::Select(select) => {
+                let then_val = self.operand(allocs, select.get_then(allocs));
+                let else_val = self.operand(allocs, select.get_else(allocs));
+                match self
+                    .operand(allocs, select.get_cond(allocs))
+                    .and_then(|c| c.as_const())
+                {
+                    Some(c) if c.is_nonzero() => then_val?,
+                    Some(_) => else_val?,
+                    None => then_val?.meet(else_val?),
+                }
+            }
+            InstObj::Phi(phi) => {
+                let mut known: Option<KnownBits> = None;
+                for &[val_use, _] in phi.incoming_uses().iter() {
+                    let Some(incoming) = self.operand(allocs, val_use.get_operand(allocs)) else {
+                        continue;
+                    };
+                    known = Some(match known {
+                        Some(k) => k.meet(incoming),
+                        None => incoming,
+                    });
+                }
+                known?
+            }
+            _ => unknown,
+        };
+        Some(known)
+    }
+}
+
+/// 函数级的 demanded-bits 分析.
+///
+/// 从不可收窄的使用者 (store, call, ret, cmp 等) 出发反向传播, 求出每条整数指令的结果中
+/// 真正被观察到的位. 结果为 0 的指令其值完全没有被使用.
+pub struct DemandedBits {
+    demanded: HashMap<InstID, APInt>,
+}
+
+impl DemandedBits {
+    pub fn new(allocs: &IRAllocs, func: FuncID, known: &KnownBitsAnalysis) -> Self {
+        let mut ret = Self { demanded: HashMap::new() };
+        let insts = all_insts(allocs, func);
+        for &inst in &insts {
+            if let ValTypeID::Int(bits) = inst.get_valtype(allocs) {
+                ret.demanded.insert(inst, APInt::new(0u8, bits));
+            }
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &user in insts.iter().rev() {
+                changed |= ret.visit_user(allocs, user, known);
+            }
+        }
+        ret
+    }
+
+    /// 指令结果中被使用的位. 非整数指令返回 `None`.
+    pub fn get(&self, inst: InstID) -> Option<APInt> {
+        self.demanded.get(&inst).copied()
+    }
+    /// 指令结果的所有位都没有被使用
+    pub fn is_dead(&self, inst: InstID) -> bool {
+        self.get(inst).is_some_and(|d| d.is_zero())
+    }
+    /// 保留所有被使用的位所需的最小位宽, 用于 `BinOPInst` 的位宽收窄.
+    pub fn demanded_width(&self, inst: InstID) -> Option<u8> {
+        self.get(inst).map(|d| d.bits() - d.leading_zeros() as u8)
+    }
+
+    fn visit_user(&mut self, allocs: &IRAllocs, user: InstID, known: &KnownBitsAnalysis) -> bool {
+        let inst = user.deref_ir(allocs);
+        let user_demanded = self.get(user);
+        if user_demanded.is_some_and(|d| d.is_zero()) && !has_side_effect(inst) {
+            return false;
+        }
+        let mut changed = false;
+        for use_id in inst.operands_iter() {
+            let ValueSSA::Inst(operand) = use_id.get_operand(allocs) else {
+                continue;
+            };
+            let Some(old) = self.get(operand) else {
+                continue;
+            };
+            let bits = old.bits();
+            let all = low_mask(bits as u32);
+            let demanded = match user_demanded {
+                Some(d) => operand_demanded(allocs, inst, use_id.get_kind(allocs), d, bits, known),
+                None => all,
+            };
+            let new = old | APInt::new(demanded & all, bits);
+            if new != old {
+                self.demanded.insert(operand, new);
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// 计算使用者 `inst` 需要其 `kind` 号操作数提供哪些位. `user_demanded` 是
+/// `inst` 自身结果被使用的位, `bits` 是操作数的位宽.
+fn operand_demanded(
+    allocs: &IRAllocs,
+    inst: &InstObj,
+    kind: UseKind,
+    user_demanded: APInt,
+    bits: u8,
+    known: &KnownBitsAnalysis,
+) -> u128 {
+    let d = user_demanded.as_unsigned();
+    let all = low_mask(bits as u32);
+    match inst {
+        InstObj::BinOP(binop) => {
+            let other = match kind {
+                UseKind::BinOpLhs => binop.get_rhs(allocs),
+                UseKind::BinOpRhs => binop.get_lhs(allocs),
+                _ => return all,
+            };
+            let other_known = known.get(allocs, other);
+            let shift = other_known
+                .and_then(|k| k.as_const())
+                .map(|s| s.as_unsigned())
+                .filter(|&s| s < bits as u128)
+                .map(|s| s as u32);
+            match (binop.get_opcode(), kind) {
+                (Opcode::BitAnd, _) => d & !other_known.map_or(0, |k| k.zero.as_unsigned()),
+                (Opcode::BitOr, _) => d & !other_known.map_or(0, |k| k.one.as_unsigned()),
+                (Opcode::BitXor, _) => d,
+                // 低位的结果不依赖于高位的操作数
+                (Opcode::Add | Opcode::Sub | Opcode::Mul, _) => fill_below_msb(d),
+                (Opcode::Shl, UseKind::BinOpLhs) => match shift {
+                    Some(s) => d >> s,
+                    None => fill_below_msb(d),
+                },
+                (Opcode::Lshr, UseKind::BinOpLhs) => match shift {
+                    Some(s) => (d << s) & all,
+                    None => all,
+                },
+                (Opcode::Ashr, UseKind::BinOpLhs) => match shift {
+                    Some(s) => {
+                        let shifted = (d << s) & all;
+                        // 结果的高 s 位都是操作数符号位的拷贝
+                        let high = all & !(all >> s);
+                        if d & high != 0 { shifted | (1 << (bits - 1)) } else { shifted }
+                    }
+                    None => all,
+                },
+                _ => all,
+            }
+        }
+        InstObj::Cast(cast) => {
+            match cast.get_opcode() {
+                Opcode::Trunc | Opcode::Zext => d,
+                // 扩展出来的高位都是操作数符号位的拷贝
+                Opcode::Sext if d & !all != 0 => (d & all) | (1 << (bits - 1)),
+                Opcode::Sext => d,
+                _ => all,
+            }
+        }
+        InstObj::Select(_) => match kind {
+            UseKind::SelectThen | UseKind::SelectElse => d,
+            _ => all,
+        },
+        InstObj::Phi(_) => match kind {
+            UseKind::PhiIncomingValue(_) => d,
+            _ => all,
+        },
+        _ => all,
+    }
+}
+
+/// 值被丢弃后仍然不能删除的指令. 它们的整数操作数总是全部位都被使用.
+fn has_side_effect(inst: &InstObj) -> bool {
+    !matches!(
+        inst,
+        InstObj::BinOP(_) | InstObj::Cast(_) | InstObj::Select(_) | InstObj::Phi(_)
+    )
+}
+
+fn all_insts(allocs: &IRAllocs, func: FuncID) -> Vec<InstID> {
+    let mut insts = Vec::new();
+    for (block, _) in func.blocks_iter(allocs) {
+        insts.extend(block.insts_iter(allocs).map(|(inst, _)| inst));
+    }
+    insts
+}
+
+fn int_insts(allocs: &IRAllocs, func: FuncID) -> Vec<InstID> {
+    let mut insts = all_insts(allocs, func);
+    insts.retain(|inst| matches!(inst.get_valtype(allocs), ValTypeID::Int(_)));
+    insts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{inst::*, *},
+        typing::{ArchInfo, FuncTypeID},
+    };
+
+    fn kb(zero: u32, one: u32) -> KnownBits {
+        KnownBits { zero: APInt::new(zero, 8), one: APInt::new(one, 8) }
+    }
+
+    #[test]
+    fn test_known_bits_transfer() {
+        let c = |x: u32| KnownBits::from_const(APInt::new(x, 8));
+        let unknown = KnownBits::new_unknown(8);
+        assert_eq!(c(3).add_with(c(5)).as_const(), Some(APInt::new(8u32, 8)));
+        assert_eq!(c(3).sub_with(c(5)).as_const(), Some(APInt::new(254u32, 8)));
+        // x & 0xf0 的低 4 位一定是 0
+        assert_eq!(unknown.and_with(c(0xf0)), kb(0x0f, 0));
+        // (x & 0xf0) + 1 的最低位一定是 1
+        assert_eq!(unknown.and_with(c(0xf0)).add_with(c(1)), kb(0x0e, 0x01));
+        assert_eq!(c(0x81).shl_with(c(1)), c(0x02));
+        assert_eq!(kb(0, 0x80).ashr_with(c(2)), kb(0, 0xe0));
+        assert_eq!(kb(0x80, 0).lshr_with(c(4)), kb(0xf8, 0));
+        assert_eq!(unknown.mul_with(kb(0x03, 0)), kb(0x03, 0));
+        assert_eq!(kb(0x80, 0).sext(16).zero, APInt::new(0xff80u32, 16));
+        assert_eq!(
+            kb(0, 0x80).zext(16),
+            KnownBits::from_masks(0xff00, 0x80, 16)
+        );
+        assert_eq!(
+            c(0x1ff & 0xff).trunc(4),
+            KnownBits::from_const(APInt::new(0xfu32, 4))
+        );
+        assert_eq!(c(1).meet(c(3)), kb(0xfc, 0x01));
+    }
+
+    /// ```remusys-ir
+    /// define dso_local i8 @f(i32 %0, i1 %1) {
+    ///     %3 = and i32 %0, 255
+    ///     %4 = shl i32 %3, 4
+    ///     %5 = or i32 %4, 1
+    ///     %6 = select i1 %1, i32 %5, i32 17
+    ///     %7 = trunc i32 %6 to i8
+    ///     ret i8 %7
+    /// }
+    /// ```
+    #[test]
+    fn test_known_and_demanded_bits() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "known_bits");
+        let i32ty = ValTypeID::Int(32);
+        let i8ty = ValTypeID::Int(8);
+        let fty = FuncTypeID::new(builder.tctx(), i8ty, false, [i32ty, ValTypeID::Int(1)]);
+        let func = FuncID::builder(builder.tctx(), "f", fty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+        let entry = func.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(entry));
+
+        let c32 = |x: u32| ValueSSA::from(APInt::new(x, 32));
+        let mut binop = |op, lhs, rhs| {
+            let inst = builder
+                .build_inst(|allocs, _| BinOPInstID::new(allocs, op, lhs, rhs))
+                .unwrap();
+            ValueSSA::Inst(inst.raw_into())
+        };
+        let and = binop(Opcode::BitAnd, ValueSSA::FuncArg(func, 0), c32(0xff));
+        let shl = binop(Opcode::Shl, and, c32(4));
+        let or = binop(Opcode::BitOr, shl, c32(1));
+        let select = builder
+            .build_inst(|allocs, _| {
+                SelectInstID::new(allocs, ValueSSA::FuncArg(func, 1), or, c32(17))
+            })
+            .unwrap();
+        let trunc = builder
+            .build_inst(|allocs, _| {
+                CastInstID::new(
+                    allocs,
+                    Opcode::Trunc,
+                    ValueSSA::Inst(select.raw_into()),
+                    i8ty,
+                )
+            })
+            .unwrap();
+        let TerminatorID::Ret(ret) = entry.get_terminator(builder.allocs()) else {
+            panic!("entry should end with ret");
+        };
+        ret.set_retval(builder.allocs(), ValueSSA::Inst(trunc.raw_into()));
+        let module = builder.take();
+        let allocs = &module.allocs;
+
+        let known = KnownBitsAnalysis::new(allocs, func);
+        let known_of = |v: ValueSSA| known.get(allocs, v).unwrap();
+        let high = 0xffff_f000u128;
+        assert_eq!(known_of(shl).zero.as_unsigned(), high | 0xf);
+        assert_eq!(known_of(or).one.as_unsigned(), 1);
+        // select 的两个分支都是奇数, 并且 bit 1 ~ 3 都是 0
+        let sel = known_of(ValueSSA::Inst(select.raw_into()));
+        assert_eq!(sel.one.as_unsigned(), 1);
+        assert_eq!(sel.zero.as_unsigned(), high | 0xe);
+        assert!(
+            known
+                .get(allocs, ValueSSA::FuncArg(func, 0))
+                .unwrap()
+                .is_unknown()
+        );
+
+        let demanded = DemandedBits::new(allocs, func, &known);
+        let demanded_of = |v: ValueSSA| {
+            let ValueSSA::Inst(inst) = v else { unreachable!() };
+            demanded.get(inst).unwrap().as_unsigned()
+        };
+        assert_eq!(demanded_of(ValueSSA::Inst(select.raw_into())), 0xff);
+        // or 已知最低位为 1, 因此 shl 的最低位不被需要
+        assert_eq!(demanded_of(or), 0xff);
+        assert_eq!(demanded_of(shl), 0xfe);
+        assert_eq!(demanded_of(and), 0x0f);
+        assert_eq!(demanded.demanded_width(trunc.raw_into()), Some(8));
+        let ValueSSA::Inst(and_inst) = and else { unreachable!() };
+        assert_eq!(demanded.demanded_width(and_inst), Some(4));
+    }
+}
@@ -0,0 +1,205 @@
+//! Natural loop analysis.
+//!
+//! 根据支配树找出函数中的所有自然循环 (回边 `latch -> header` 满足 `header` 支配 `latch`),
+//! 并建立循环嵌套关系. 共享同一个循环头的回边被合并为同一个循环.
+
+use crate::{
+    ir::{BlockID, FuncID, IRAllocs},
+    opt::{CfgBlockStat, CfgRes, CfgSnapshot, DominatorTree},
+};
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+
+/// 单个自然循环.
+#[derive(Debug, Clone)]
+pub struct Loop {
+    pub header: BlockID,
+    /// 循环体中的所有基本块, 包括循环头和嵌套的子循环.
+    pub blocks: HashSet<BlockID>,
+    /// 与 `blocks` 相同的基本块, 按支配树 DFS 先序排列. 循环头总是第一个.
+    pub block_list: Vec<BlockID>,
+    /// 回边的起点, 按 CFG 前驱顺序排列.
+    pub latches: SmallVec<[BlockID; 2]>,
+    /// 直接外层循环在 `LoopInfo::loops` 中的下标.
+    pub parent: Option<usize>,
+    /// 直接内层循环在 `LoopInfo::loops` 中的下标.
+    pub children: SmallVec<[usize; 2]>,
+    /// 循环嵌套深度. 最外层循环的深度为 1.
+    pub depth: u32,
+}
+
+impl Loop {
+    pub fn contains(&self, block: BlockID) -> bool {
+        self.blocks.contains(&block)
+    }
+
+    pub fn single_latch(&self) -> Option<BlockID> {
+        match self.latches.as_slice() {
+            [latch] => Some(*latch),
+            _ => None,
+        }
+    }
+
+    /// 循环头在循环外的前驱.
+    pub fn outside_preds(&self, cfg: &CfgSnapshot) -> SmallVec<[BlockID; 2]> {
+        let preds = cfg.pred_of(self.header).unwrap_or(&[]);
+        preds
+            .iter()
+            .copied()
+            .filter(|b| !self.contains(*b))
+            .collect()
+    }
+
+    /// 循环的前置块: 循环头唯一的循环外前驱, 并且它唯一的后继就是循环头.
+    pub fn preheader(&self, cfg: &CfgSnapshot) -> Option<BlockID> {
+        let [pred] = self.outside_preds(cfg)[..] else {
+            return None;
+        };
+        match cfg.succ_of(pred) {
+            Some([succ]) if *succ == self.header => Some(pred),
+            _ => None,
+        }
+    }
+
+    /// 循环的出口边 `(exiting, exit)`: `exiting` 在循环内, `exit` 在循环外.
+    pub fn exit_edges(&self, cfg: &CfgSnapshot) -> Vec<(BlockID, BlockID)> {
+        let mut edges = Vec::new();
+        for &block in &self.block_list {
+            for &succ in cfg.succ_of(block).unwrap_or(&[]) {
+                if !self.contains(succ) && !edges.contains(&(block, succ)) {
+                    edges.push((block, succ));
+                }
+            }
+        }
+        edges
+    }
+    /// 有后继在循环外的循环块.
+    pub fn exiting_blocks(&self, cfg: &CfgSnapshot) -> SmallVec<[BlockID; 4]> {
+        let mut blocks: SmallVec<[BlockID; 4]> = SmallVec::new();
+        for (from, _) in self.exit_edges(cfg) {
+            if !blocks.contains(&from) {
+                blocks.push(from);
+            }
+        }
+        blocks
+    }
+    /// 循环外的出口块.
+    pub fn exit_blocks(&self, cfg: &CfgSnapshot) -> SmallVec<[BlockID; 4]> {
+        let mut blocks: SmallVec<[BlockID; 4]> = SmallVec::new();
+        for (_, to) in self.exit_edges(cfg) {
+            if !blocks.contains(&to) {
+                blocks.push(to);
+            }
+        }
+        blocks
+    }
+    /// 所有出口块的前驱都在循环内.
+    pub fn has_dedicated_exits(&self, cfg: &CfgSnapshot) -> bool {
+        self.exit_blocks(cfg).into_iter().all(|exit| {
+            let preds = cfg.pred_of(exit).unwrap_or(&[]);
+            preds.iter().all(|p| self.contains(*p))
+        })
+    }
+
+    /// 循环是否处于 LoopSimplify 规范形式: 有前置块、唯一的 latch 和专用出口块.
+    pub fn is_simplified(&self, cfg: &CfgSnapshot) -> bool {
+        self.preheader(cfg).is_some()
+            && self.single_latch().is_some()
+            && self.has_dedicated_exits(cfg)
+    }
+}
+
+/// 函数的循环森林.
+pub struct LoopInfo {
+    pub func: FuncID,
+    /// 所有循环, 按循环头在支配树先序中的顺序排列. 外层循环总是排在内层循环之前.
+    pub loops: Vec<Loop>,
+    /// 每个基本块所在的最内层循环.
+    pub block_loop: HashMap<BlockID, usize>,
+}
+
+impl LoopInfo {
+    pub fn new(allocs: &IRAllocs, func: FuncID) -> CfgRes<Self> {
+        let cfg = CfgSnapshot::new(allocs, func)?;
+        let dom = DominatorTree::builder(allocs, func)?.build();
+        Ok(Self::from_dom(&cfg, &dom))
+    }
+
+    pub fn from_dom(cfg: &CfgSnapshot, dom: &DominatorTree) -> Self {
+        let mut loops: Vec<Loop> = Vec::new();
+        for node in &dom.nodes {
+            let CfgBlockStat::Block(header) = node.block else {
+                continue;
+            };
+            let latches: SmallVec<[BlockID; 2]> = cfg
+                .pred_of(header)
+                .unwrap_or(&[])
+                .iter()
+                .copied()
+                .filter(|&p| dom.block_dominates_block(header, p))
+                .collect();
+            if latches.is_empty() {
+                continue;
+            }
+            let mut blocks = HashSet::from([header]);
+            let mut worklist: Vec<BlockID> = latches.to_vec();
+            while let Some(block) = worklist.pop() {
+                // 不可达的前驱不属于任何循环
+                if !dom.dfs.block_reachable(block) || !blocks.insert(block) {
+                    continue;
+                }
+                worklist.extend_from_slice(cfg.pred_of(block).unwrap_or(&[]));
+            }
+            let mut block_list: Vec<BlockID> = blocks.iter().copied().collect();
+            block_list.sort_by_key(|&b| dom.dfs.block_dfn(b));
+            loops.push(Loop {
+                header,
+                blocks,
+                block_list,
+                latches,
+                parent: None,
+                children: SmallVec::new(),
+                depth: 1,
+            });
+        }
+
+        // 外层循环的循环头在支配树先序中总是先出现, 所以从后往前找第一个包含自己的循环即可.
+        for i in 0..loops.len() {
+            let header = loops[i].header;
+            let parent = (0..i).rev().find(|&j| loops[j].contains(header));
+            if let Some(p) = parent {
+                loops[i].parent = Some(p);
+                loops[i].depth = loops[p].depth + 1;
+                loops[p].children.push(i);
+            }
+        }
+        let mut block_loop = HashMap::new();
+        for (i, lp) in loops.iter().enumerate() {
+            for &block in &lp.blocks {
+                block_loop.insert(block, i);
+            }
+        }
+        Self { func: cfg.func, loops, block_loop }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.loops.is_empty()
+    }
+    pub fn loop_of(&self, block: BlockID) -> Option<usize> {
+        self.block_loop.get(&block).copied()
+    }
+    pub fn depth_of(&self, block: BlockID) -> u32 {
+        self.loop_of(block).map_or(0, |l| self.loops[l].depth)
+    }
+    pub fn is_header(&self, block: BlockID) -> bool {
+        self.loop_of(block)
+            .is_some_and(|l| self.loops[l].header == block)
+    }
+    pub fn top_level(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.loops.len()).filter(|&i| self.loops[i].parent.is_none())
+    }
+    /// 内层循环在前的遍历顺序, 适合自底向上变换循环.
+    pub fn innermost_first(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.loops.len()).rev()
+    }
+}
@@ -0,0 +1,815 @@
+//! Scalar evolution analysis.
+//!
+//! 把循环中的整数值描述成关于循环迭代次数的表达式 [`Scev`]. 其中最重要的是加法递推式
+//! (add-recurrence) `{start,+,step}<header>`: 第 `i` 次迭代时它的值为 `start + i * step`.
+//!
+//! 分析只识别步长在循环内不变的一阶递推式. 在此基础上还能:
+//!
+//! * 根据唯一出口块上的 `CmpInst` 条件计算循环的回边执行次数 (trip count);
+//! * 把 `GEPInst` 的下标折叠成相对基址的字节偏移表达式, 从而得到指针每次迭代的步长.
+
+use crate::{
+    base::APInt,
+    ir::{
+        BlockID, CmpCond, FuncID, IRAllocs, ISubInst, ISubInstID, ISubValueSSA, InstID, InstObj,
+        Opcode, TerminatorID, ValueSSA, inst::GEPInstID,
+    },
+    opt::{CfgBlockStat, CfgRes, CfgSnapshot, DominatorTree, Loop, LoopInfo},
+    typing::{IValType, TypeContext, ValTypeID},
+};
+use std::{collections::HashMap, fmt, rc::Rc};
+
+pub type ScevRef = Rc<Scev>;
+
+/// 标量演化表达式. 同一个表达式树中所有结点的位宽都相同 (扩展与截断结点除外).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Scev {
+    Const(APInt),
+    /// 无法继续分析的值, 例如函数参数、load 的结果或非循环头的 Phi.
+    Unknown(ValueSSA, u8),
+    Add(ScevRef, ScevRef),
+    Mul(ScevRef, ScevRef),
+    SExt(ScevRef, u8),
+    ZExt(ScevRef, u8),
+    Trunc(ScevRef, u8),
+    AddRec(ScevAddRec),
+}
+
+/// 加法递推式 `{start,+,step}<header>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScevAddRec {
+    pub start: ScevRef,
+    pub step: ScevRef,
+    pub header: BlockID,
+    /// 递推过程不会发生有符号回绕.
+    pub nsw: bool,
+    /// 递推过程不会发生无符号回绕.
+    pub nuw: bool,
+}
+
+impl ScevAddRec {
+    /// 第 `iter` 次迭代时的值. 只有起始值和步长都是常量时才能求值.
+    pub fn evaluate_at(&self, iter: u128) -> Option<APInt> {
+        let start = self.start.as_const()?;
+        let step = self.step.as_const()?;
+        Some(start + step * APInt::new(iter, start.bits()))
+    }
+}
+
+impl fmt::Display for Scev {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Scev::Const(c) => write!(f, "{}", c.as_signed()),
+            Scev::Unknown(value, _) => write!(f, "%{value:?}"),
+            Scev::Add(lhs, rhs) => write!(f, "({lhs} + {rhs})"),
+            Scev::Mul(lhs, rhs) => write!(f, "({lhs} * {rhs})"),
+            Scev::SExt(expr, bits) => write!(f, "(sext {expr} to i{bits})"),
+            Scev::ZExt(expr, bits) => write!(f, "(zext {expr} to i{bits})"),
+            Scev::Trunc(expr, bits) => write!(f, "(trunc {expr} to i{bits})"),
+            Scev::AddRec(rec) => {
+                write!(f, "{{{},+,{}}}<{:?}>", rec.start, rec.step, rec.header)?;
+                if rec.nsw {
+                    write!(f, "<nsw>")?;
+                }
+                if rec.nuw {
+                    write!(f, "<nuw>")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Scev {
+    pub fn bits(&self) -> u8 {
+        match self {
+            Scev::Const(c) => c.bits(),
+            Scev::Unknown(_, bits)
+            | Scev::SExt(_, bits)
+            | Scev::ZExt(_, bits)
+            | Scev::Trunc(_, bits) => *bits,
+            Scev::Add(lhs, _) | Scev::Mul(lhs, _) => lhs.bits(),
+            Scev::AddRec(rec) => rec.start.bits(),
+        }
+    }
+    pub fn as_const(&self) -> Option<APInt> {
+        match self {
+            Scev::Const(c) => Some(*c),
+            _ => None,
+        }
+    }
+    pub fn as_addrec(&self) -> Option<&ScevAddRec> {
+        match self {
+            Scev::AddRec(rec) => Some(rec),
+            _ => None,
+        }
+    }
+    pub fn is_zero(&self) -> bool {
+        self.as_const().is_some_and(|c| c.is_zero())
+    }
+    /// 表达式中是否包含加法递推式.
+    pub fn has_addrec(&self) -> bool {
+        self.any_node(&mut |e| matches!(e, Scev::AddRec(_)))
+    }
+
+    fn any_node(&self, pred: &mut impl FnMut(&Scev) -> bool) -> bool {
+        if pred(self) {
+            return true;
+        }
+        match self {
+            Scev::Const(_) | Scev::Unknown(..) => false,
+            Scev::Add(lhs, rhs) | Scev::Mul(lhs, rhs) => lhs.any_node(pred) || rhs.any_node(pred),
+            Scev::SExt(expr, _) | Scev::ZExt(expr, _) | Scev::Trunc(expr, _) => expr.any_node(pred),
+            Scev::AddRec(rec) => rec.start.any_node(pred) || rec.step.any_node(pred),
+        }
+    }
+
+    pub fn new_const(value: APInt) -> ScevRef {
+        Rc::new(Scev::Const(value))
+    }
+    pub fn new_unknown(value: ValueSSA, bits: u8) -> ScevRef {
+        Rc::new(Scev::Unknown(value, bits))
+    }
+    pub fn new_addrec(start: ScevRef, step: ScevRef, header: BlockID) -> ScevRef {
+        Rc::new(Scev::AddRec(ScevAddRec {
+            start,
+            step,
+            header,
+            nsw: false,
+            nuw: false,
+        }))
+    }
+
+    /// 构造 `lhs + rhs`. 同一个循环的递推式会被合并, 循环无关的值会被并入递推式的起始值,
+    /// 其余情况下常量总是被放在左边.
+    pub fn new_add(lhs: ScevRef, rhs: ScevRef) -> ScevRef {
+        assert_eq!(lhs.bits(), rhs.bits(), "Scev add width mismatch");
+        match (&*lhs, &*rhs) {
+            (Scev::Const(a), Scev::Const(b)) => Scev::new_const(*a + *b),
+            (Scev::Const(a), _) if a.is_zero() => rhs,
+            (_, Scev::Const(b)) if b.is_zero() => lhs,
+            (Scev::AddRec(a), Scev::AddRec(b)) if a.header == b.header => {
+                let start = Scev::new_add(a.start.clone(), b.start.clone());
+                let step = Scev::new_add(a.step.clone(), b.step.clone());
+                Scev::new_addrec(start, step, a.header)
+            }
+            (Scev::AddRec(rec), _) if !rhs.has_addrec() => {
+                let start = Scev::new_add(rec.start.clone(), rhs.clone());
+                Scev::new_addrec(start, rec.step.clone(), rec.header)
+            }
+            (_, Scev::AddRec(rec)) if !lhs.has_addrec() => {
+                let start = Scev::new_add(lhs.clone(), rec.start.clone());
+                Scev::new_addrec(start, rec.step.clone(), rec.header)
+            }
+            (_, Scev::Const(_)) => Scev::new_add(rhs, lhs),
+            (Scev::Const(_), Scev::Add(b, rest)) if b.as_const().is_some() => {
+                let sum = Scev::new_add(lhs.clone(), b.clone());
+                Scev::new_add(sum, rest.clone())
+            }
+            _ => Rc::new(Scev::Add(lhs, rhs)),
+        }
+    }
+    /// 构造 `lhs * rhs`. 递推式乘以循环无关的值仍然是递推式.
+    pub fn new_mul(lhs: ScevRef, rhs: ScevRef) -> ScevRef {
+        assert_eq!(lhs.bits(), rhs.bits(), "Scev mul width mismatch");
+        match (&*lhs, &*rhs) {
+            (Scev::Const(a), Scev::Const(b)) => Scev::new_const(*a * *b),
+            (Scev::Const(a), _) if a.is_zero() => lhs,
+            (_, Scev::Const(b)) if b.is_zero() => rhs,
+            (Scev::Const(a), _) if a.as_unsigned() == 1 => rhs,
+            (_, Scev::Const(b)) if b.as_unsigned() == 1 => lhs,
+            (_, Scev::AddRec(rec)) if !lhs.has_addrec() => {
+                let start = Scev::new_mul(lhs.clone(), rec.start.clone());
+                let step = Scev::new_mul(lhs.clone(), rec.step.clone());
+                Scev::new_addrec(start, step, rec.header)
+            }
+            (Scev::AddRec(rec), _) if !rhs.has_addrec() => {
+                let start = Scev::new_mul(rec.start.clone(), rhs.clone());
+                let step = Scev::new_mul(rec.step.clone(), rhs.clone());
+                Scev::new_addrec(start, step, rec.header)
+            }
+            (_, Scev::Const(_)) => Scev::new_mul(rhs, lhs),
+            (Scev::Const(_), Scev::Mul(b, rest)) if b.as_const().is_some() => {
+                let prod = Scev::new_mul(lhs.clone(), b.clone());
+                Scev::new_mul(prod, rest.clone())
+            }
+            _ => Rc::new(Scev::Mul(lhs, rhs)),
+        }
+    }
+    pub fn new_neg(expr: ScevRef) -> ScevRef {
+        let minus_one = APInt::new(u128::MAX, expr.bits());
+        Scev::new_mul(Scev::new_const(minus_one), expr)
+    }
+    pub fn new_sub(lhs: ScevRef, rhs: ScevRef) -> ScevRef {
+        Scev::new_add(lhs, Scev::new_neg(rhs))
+    }
+    /// 符号扩展. 只有不发生有符号回绕的递推式才能把扩展分配到起始值和步长上.
+    pub fn new_sext(expr: ScevRef, bits: u8) -> ScevRef {
+        if expr.bits() == bits {
+            return expr;
+        }
+        match &*expr {
+            Scev::Const(c) => Scev::new_const(c.sext_to(bits)),
+            Scev::AddRec(rec) if rec.nsw => {
+                let start = Scev::new_sext(rec.start.clone(), bits);
+                let step = Scev::new_sext(rec.step.clone(), bits);
+                let ext = ScevAddRec { start, step, header: rec.header, nsw: true, nuw: false };
+                Rc::new(Scev::AddRec(ext))
+            }
+            _ => Rc::new(Scev::SExt(expr, bits)),
+        }
+    }
+    /// 零扩展. 只有不发生无符号回绕的递推式才能把扩展分配到起始值和步长上.
+    pub fn new_zext(expr: ScevRef, bits: u8) -> ScevRef {
+        if expr.bits() == bits {
+            return expr;
+        }
+        match &*expr {
+            Scev::Const(c) => Scev::new_const(c.zext_to(bits)),
+            Scev::AddRec(rec) if rec.nuw => {
+                let start = Scev::new_zext(rec.start.clone(), bits);
+                let step = Scev::new_zext(rec.step.clone(), bits);
+                let ext = ScevAddRec { start, step, header: rec.header, nsw: false, nuw: true };
+                Rc::new(Scev::AddRec(ext))
+            }
+            _ => Rc::new(Scev::ZExt(expr, bits)),
+        }
+    }
+    /// 截断. 模 `2^bits` 下加法和乘法都与截断交换, 所以截断总能分配到子表达式上.
+    pub fn new_trunc(expr: ScevRef, bits: u8) -> ScevRef {
+        if expr.bits() == bits {
+            return expr;
+        }
+        match &*expr {
+            Scev::Const(c) => Scev::new_const(APInt::new(c.as_unsigned(), bits)),
+            Scev::Add(lhs, rhs) => Scev::new_add(
+                Scev::new_trunc(lhs.clone(), bits),
+                Scev::new_trunc(rhs.clone(), bits),
+            ),
+            Scev::Mul(lhs, rhs) => Scev::new_mul(
+                Scev::new_trunc(lhs.clone(), bits),
+                Scev::new_trunc(rhs.clone(), bits),
+            ),
+            Scev::AddRec(rec) => {
+                let start = Scev::new_trunc(rec.start.clone(), bits);
+                let step = Scev::new_trunc(rec.step.clone(), bits);
+                Scev::new_addrec(start, step, rec.header)
+            }
+            _ => Rc::new(Scev::Trunc(expr, bits)),
+        }
+    }
+    /// 把表达式符号扩展或截断到 `bits` 位.
+    pub fn new_sext_or_trunc(expr: ScevRef, bits: u8) -> ScevRef {
+        if expr.bits() < bits { Scev::new_sext(expr, bits) } else { Scev::new_trunc(expr, bits) }
+    }
+
+    /// 把表达式中所有的 `from` 替换为 `to`, 并重新折叠.
+    pub fn substitute(expr: &ScevRef, from: &Scev, to: &ScevRef) -> ScevRef {
+        if **expr == *from {
+            return to.clone();
+        }
+        if !expr.any_node(&mut |e| e == from) {
+            return expr.clone();
+        }
+        let subst = |e: &ScevRef| Scev::substitute(e, from, to);
+        match &**expr {
+            Scev::Const(_) | Scev::Unknown(..) => expr.clone(),
+            Scev::Add(lhs, rhs) => Scev::new_add(subst(lhs), subst(rhs)),
+            Scev::Mul(lhs, rhs) => Scev::new_mul(subst(lhs), subst(rhs)),
+            Scev::SExt(e, bits) => Scev::new_sext(subst(e), *bits),
+            Scev::ZExt(e, bits) => Scev::new_zext(subst(e), *bits),
+            Scev::Trunc(e, bits) => Scev::new_trunc(subst(e), *bits),
+            Scev::AddRec(rec) => {
+                let start = subst(&rec.start);
+                let step = subst(&rec.step);
+                Rc::new(Scev::AddRec(ScevAddRec { start, step, ..rec.clone() }))
+            }
+        }
+    }
+
+    /// 从加法树中去掉一个 `term` 项, 返回剩余部分. `term` 不是加法项时返回 `None`.
+    fn strip_add_term(expr: &ScevRef, term: &Scev) -> Option<ScevRef> {
+        if **expr == *term {
+            return Some(Scev::new_const(APInt::new(0u8, expr.bits())));
+        }
+        let Scev::Add(lhs, rhs) = &**expr else {
+            return None;
+        };
+        if let Some(rest) = Scev::strip_add_term(lhs, term) {
+            Some(Scev::new_add(rest, rhs.clone()))
+        } else {
+            Scev::strip_add_term(rhs, term).map(|rest| Scev::new_add(lhs.clone(), rest))
+        }
+    }
+}
+
+/// `GEPInst` 计算出的地址: `base + offset`, 其中 `offset` 是指针宽度的字节偏移.
+#[derive(Debug, Clone)]
+pub struct GEPOffset {
+    /// 不是 GEP 的最内层基址.
+    pub base: ValueSSA,
+    pub offset: ScevRef,
+}
+
+/// 循环的退出条件: 在 `exiting` 块中比较 `iv` 和 `bound`, `stay_cond` 成立时留在循环内.
+#[derive(Debug, Clone)]
+pub struct ExitCompare {
+    pub exiting: BlockID,
+    /// 被比较的归纳变量, 它的表达式为 `rec`.
+    pub iv: ValueSSA,
+    pub rec: ScevAddRec,
+    pub bound: ScevRef,
+    /// `iv stay_cond bound` 成立时留在循环内.
+    pub stay_cond: CmpCond,
+}
+
+/// 函数内所有整数指令的标量演化表达式.
+pub struct ScalarEvolution {
+    pub loops: LoopInfo,
+    exprs: HashMap<InstID, ScevRef>,
+    cfg: CfgSnapshot,
+    dom: DominatorTree,
+}
+
+impl ScalarEvolution {
+    pub fn new(allocs: &IRAllocs, func: FuncID) -> CfgRes<Self> {
+        let cfg = CfgSnapshot::new(allocs, func)?;
+        let dom = DominatorTree::builder(allocs, func)?.build();
+        let loops = LoopInfo::from_dom(&cfg, &dom);
+        let mut ret = Self { loops, exprs: HashMap::new(), cfg, dom };
+        ret.build_exprs(allocs);
+        ret.build_addrecs(allocs);
+        Ok(ret)
+    }
+
+    /// 按支配树先序计算每条整数指令的表达式. 这时所有 Phi 都还是未知值.
+    fn build_exprs(&mut self, allocs: &IRAllocs) {
+        let blocks: Vec<BlockID> = self
+            .dom
+            .nodes
+            .iter()
+            .filter_map(|node| match node.block {
+                CfgBlockStat::Block(b) => Some(b),
+                _ => None,
+            })
+            .collect();
+        for block in blocks {
+            for (inst_id, _) in block.insts_iter(allocs) {
+                if let Some(expr) = self.eval_inst(allocs, inst_id) {
+                    self.exprs.insert(inst_id, expr);
+                }
+            }
+        }
+    }
+
+    fn eval_inst(&self, allocs: &IRAllocs, inst_id: InstID) -> Option<ScevRef> {
+        let ValTypeID::Int(bits) = inst_id.get_valtype(allocs) else {
+            return None;
+        };
+        let unknown = || Scev::new_unknown(ValueSSA::Inst(inst_id), bits);
+        let expr = match inst_id.deref_ir(allocs) {
+            InstObj::BinOP(binop) => {
+                let lhs = self.get_scev(allocs, binop.get_lhs(allocs))?;
+                let rhs = self.get_scev(allocs, binop.get_rhs(allocs))?;
+                match binop.get_opcode() {
+                    Opcode::Add => Scev::new_add(lhs, rhs),
+                    Opcode::Sub => Scev::new_sub(lhs, rhs),
+                    Opcode::Mul => Scev::new_mul(lhs, rhs),
+                    Opcode::Shl => match rhs.as_const() {
+                        Some(sh) if sh.as_unsigned() < bits as u128 => {
+                            let factor = APInt::new(1u128 << sh.as_unsigned(), bits);
+                            Scev::new_mul(Scev::new_const(factor), lhs)
+                        }
+                        _ => unknown(),
+                    },
+                    _ => unknown(),
+                }
+            }
+            InstObj::Cast(cast) => {
+                let from = cast.get_from(allocs);
+                match cast.get_opcode() {
+                    Opcode::Sext => Scev::new_sext(self.get_scev(allocs, from)?, bits),
+                    Opcode::Zext => Scev::new_zext(self.get_scev(allocs, from)?, bits),
+                    Opcode::Trunc => Scev::new_trunc(self.get_scev(allocs, from)?, bits),
+                    _ => unknown(),
+                }
+            }
+            _ => unknown(),
+        };
+        Some(expr)
+    }
+
+    /// 识别循环头 Phi 形成的递推式. 外层循环先处理, 这样内层递推式的起始值可以引用外层递推式.
+    fn build_addrecs(&mut self, allocs: &IRAllocs) {
+        for l in 0..self.loops.loops.len() {
+            let header = self.loops.loops[l].header;
+            for (phi_id, inst) in header.insts_iter(allocs) {
+                let InstObj::Phi(_) = inst else {
+                    continue;
+                };
+                let Some(rec) = self.recognize_addrec(allocs, l, phi_id) else {
+                    continue;
+                };
+                let placeholder = self.exprs[&phi_id].clone();
+                for expr in self.exprs.values_mut() {
+                    *expr = Scev::substitute(expr, &placeholder, &rec);
+                }
+                self.exprs.insert(phi_id, rec);
+            }
+        }
+    }
+
+    fn recognize_addrec(&self, allocs: &IRAllocs, l: usize, phi_id: InstID) -> Option<ScevRef> {
+        let lp = &self.loops.loops[l];
+        let placeholder = self.exprs.get(&phi_id)?;
+        let InstObj::Phi(phi) = phi_id.deref_ir(allocs) else {
+            return None;
+        };
+        let (mut init, mut next) = (None, None);
+        for &[uval, ublk] in phi.incoming_uses().iter() {
+            let ValueSSA::Block(pred) = ublk.get_operand(allocs) else {
+                return None;
+            };
+            let value = uval.get_operand(allocs);
+            let slot = if lp.contains(pred) { &mut next } else { &mut init };
+            match slot {
+                Some(v) if *v != value => return None,
+                _ => *slot = Some(value),
+            }
+        }
+        let (init, next) = (init?, next?);
+        let start = self.get_scev(allocs, init)?;
+        let step = Scev::strip_add_term(&self.get_scev(allocs, next)?, placeholder)?;
+        if !self.is_loop_invariant(allocs, &start, lp) || !self.is_loop_invariant(allocs, &step, lp)
+        {
+            return None;
+        }
+        // 只有直接由 `phi + step` 算出的下一次迭代值才能继承回绕标志
+        let (nsw, nuw) = match next {
+            ValueSSA::Inst(inst) => match inst.deref_ir(allocs) {
+                InstObj::BinOP(binop)
+                    if binop.get_opcode() == Opcode::Add
+                        && (binop.get_lhs(allocs) == ValueSSA::Inst(phi_id)
+                            || binop.get_rhs(allocs) == ValueSSA::Inst(phi_id)) =>
+                {
+                    let flags = binop.get_flags();
+                    (flags.has_nsw(), flags.has_nuw())
+                }
+                _ => (false, false),
+            },
+            _ => (false, false),
+        };
+        let header = lp.header;
+        Some(Rc::new(Scev::AddRec(ScevAddRec {
+            start,
+            step,
+            header,
+            nsw,
+            nuw,
+        })))
+    }
+
+    /// 值的标量演化表达式. 非整数值返回 `None`.
+    pub fn get_scev(&self, allocs: &IRAllocs, value: ValueSSA) -> Option<ScevRef> {
+        let ValTypeID::Int(bits) = value.get_valtype(allocs) else {
+            return None;
+        };
+        if let Some(c) = value.as_apint() {
+            return Some(Scev::new_const(c));
+        }
+        let expr = match value {
+            ValueSSA::Inst(inst) => self.exprs.get(&inst).cloned(),
+            _ => None,
+        };
+        Some(expr.unwrap_or_else(|| Scev::new_unknown(value, bits)))
+    }
+
+    /// 表达式在循环 `lp` 内是否不变.
+    pub fn is_loop_invariant(&self, allocs: &IRAllocs, expr: &Scev, lp: &Loop) -> bool {
+        !expr.any_node(&mut |e| match e {
+            Scev::Unknown(ValueSSA::Inst(inst), _) => {
+                inst.get_parent(allocs).is_some_and(|b| lp.contains(b))
+            }
+            Scev::AddRec(rec) => lp.contains(rec.header),
+            _ => false,
+        })
+    }
+
+    /// 循环的退出条件. 要求循环只有一个出口块, 它支配唯一的 latch,
+    /// 并且以 `icmp` 比较本循环的一个递推式和一个循环不变量.
+    pub fn exit_compare(&self, allocs: &IRAllocs, l: usize) -> Option<ExitCompare> {
+        let lp = &self.loops.loops[l];
+        let latch = lp.single_latch()?;
+        let [exiting] = lp.exiting_blocks(&self.cfg)[..] else {
+            return None;
+        };
+        if !self.dom.block_dominates_block(exiting, latch) {
+            return None;
+        }
+        let TerminatorID::Br(br) = exiting.get_terminator(allocs) else {
+            return None;
+        };
+        let ValueSSA::Inst(cond) = br.get_cond(allocs) else {
+            return None;
+        };
+        let InstObj::Cmp(cmp) = cond.deref_ir(allocs) else {
+            return None;
+        };
+        if !cmp.cond.is_int() {
+            return None;
+        }
+        // 统一成 "条件成立时留在循环内"
+        let stay_cond = match (br.get_then(allocs), br.get_else(allocs)) {
+            (Some(then_bb), Some(else_bb)) if lp.contains(then_bb) && !lp.contains(else_bb) => {
+                cmp.cond
+            }
+            (Some(then_bb), Some(else_bb)) if !lp.contains(then_bb) && lp.contains(else_bb) => {
+                cmp.cond.invert()
+            }
+            _ => return None,
+        };
+        let (lhs, rhs) = (cmp.get_lhs(allocs), cmp.get_rhs(allocs));
+        let (lhs_expr, rhs_expr) = (self.get_scev(allocs, lhs)?, self.get_scev(allocs, rhs)?);
+        let is_own_rec = |e: &Scev| e.as_addrec().is_some_and(|r| r.header == lp.header);
+        let (iv, rec, bound, stay_cond) = if is_own_rec(&lhs_expr) {
+            (lhs, lhs_expr, rhs_expr, stay_cond)
+        } else if is_own_rec(&rhs_expr) {
+            (rhs, rhs_expr, lhs_expr, stay_cond.swap_operands())
+        } else {
+            return None;
+        };
+        if !self.is_loop_invariant(allocs, &bound, lp) {
+            return None;
+        }
+        let rec = rec.as_addrec()?.clone();
+        Some(ExitCompare { exiting, iv, rec, bound, stay_cond })
+    }
+
+    /// 循环回边被执行的次数, 也就是循环体在第几次迭代时离开循环 (从 0 开始计数).
+    /// 只有递推式和边界都是常量时才能计算.
+    pub fn backedge_taken_count(&self, allocs: &IRAllocs, l: usize) -> Option<u128> {
+        let ExitCompare { rec, bound, stay_cond, .. } = self.exit_compare(allocs, l)?;
+        let (start, step, bound) = (
+            rec.start.as_const()?,
+            rec.step.as_const()?,
+            bound.as_const()?,
+        );
+        exit_iteration(stay_cond, start, step, bound)
+    }
+
+    /// 循环头被执行的次数.
+    pub fn trip_count(&self, allocs: &IRAllocs, l: usize) -> Option<u128> {
+        self.backedge_taken_count(allocs, l)?.checked_add(1)
+    }
+
+    /// 把 GEP 的下标折叠成相对最内层基址的字节偏移. 基址本身是 GEP 时会递归展开.
+    pub fn gep_offset(
+        &self,
+        allocs: &IRAllocs,
+        tctx: &TypeContext,
+        gep: GEPInstID,
+    ) -> Option<GEPOffset> {
+        let ptr_bits = tctx.arch.ptr_nbits as u8;
+        let gep_obj = gep.deref_ir(allocs);
+        let mut offset = Scev::new_const(APInt::new(0u8, ptr_bits));
+        let mut curr_ty = gep_obj.initial_ty;
+        for (i, &index_use) in gep_obj.index_uses().iter().enumerate() {
+            let index = index_use.get_operand(allocs);
+            let delta = match curr_ty {
+                // 第一个下标把基址看作元素类型为 `initial_ty` 的无限长数组
+                _ if i == 0 => {
+                    let stride = curr_ty.try_get_aligned_size(tctx)?;
+                    self.scaled_index(allocs, index, stride, ptr_bits)?
+                }
+                ValTypeID::Array(arr) => {
+                    curr_ty = arr.get_element_type(tctx);
+                    let stride = arr.get_unit_size(tctx);
+                    self.scaled_index(allocs, index, stride, ptr_bits)?
+                }
+                ValTypeID::FixVec(vec) => {
+                    curr_ty = vec.get_elem().into_ir();
+                    let stride = curr_ty.try_get_size(tctx)?;
+                    self.scaled_index(allocs, index, stride, ptr_bits)?
+                }
+                ValTypeID::Struct(_) | ValTypeID::StructAlias(_) => {
+                    let s = match curr_ty {
+                        ValTypeID::StructAlias(sa) => sa.get_aliasee(tctx),
+                        ValTypeID::Struct(s) => s,
+                        _ => unreachable!(),
+                    };
+                    let field = index.as_apint()?.as_unsigned() as usize;
+                    curr_ty = *s.get_fields(tctx).get(field)?;
+                    let field_offset = s.get_offset(tctx, field);
+                    Scev::new_const(APInt::new(field_offset, ptr_bits))
+                }
+                _ => return None,
+            };
+            offset = Scev::new_add(offset, delta);
+        }
+        let base = gep_obj.get_base(allocs);
+        match base {
+            ValueSSA::Inst(inst) if matches!(inst.deref_ir(allocs), InstObj::GEP(_)) => {
+                let inner = self.gep_offset(allocs, tctx, GEPInstID::raw_from(inst))?;
+                let offset = Scev::new_add(inner.offset, offset);
+                Some(GEPOffset { base: inner.base, offset })
+            }
+            _ => Some(GEPOffset { base, offset }),
+        }
+    }
+
+    fn scaled_index(
+        &self,
+        allocs: &IRAllocs,
+        index: ValueSSA,
+        stride: usize,
+        ptr_bits: u8,
+    ) -> Option<ScevRef> {
+        let index = Scev::new_sext_or_trunc(self.get_scev(allocs, index)?, ptr_bits);
+        let stride = Scev::new_const(APInt::new(stride, ptr_bits));
+        Some(Scev::new_mul(stride, index))
+    }
+
+    /// GEP 算出的地址在循环 `l` 中每次迭代增加的字节数.
+    pub fn gep_stride(
+        &self,
+        allocs: &IRAllocs,
+        tctx: &TypeContext,
+        gep: GEPInstID,
+        l: usize,
+    ) -> Option<APInt> {
+        let GEPOffset { base, offset } = self.gep_offset(allocs, tctx, gep)?;
+        let lp = &self.loops.loops[l];
+        let base_expr = Scev::new_unknown(base, tctx.arch.ptr_nbits as u8);
+        if !self.is_loop_invariant(allocs, &base_expr, lp) {
+            return None;
+        }
+        if self.is_loop_invariant(allocs, &offset, lp) {
+            return Some(APInt::new(0u8, offset.bits()));
+        }
+        let rec = offset.as_addrec()?;
+        if rec.header != lp.header {
+            return None;
+        }
+        rec.step.as_const()
+    }
+}
+
+/// 递推式 `start + i * step` 第一次使 `stay_cond` 不成立时的迭代次数 `i`.
+/// 在此之前的迭代中递推式不能发生回绕, 否则返回 `None`.
+fn exit_iteration(stay_cond: CmpCond, start: APInt, step: APInt, bound: APInt) -> Option<u128> {
+    let bits = start.bits();
+    if bits > 64 {
+        return None;
+    }
+    let signed = stay_cond.is_signed()?;
+    let as_value = |x: APInt| if signed { x.as_signed() } else { x.as_unsigned() as i128 };
+    let (min, max) = if signed {
+        (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+    } else {
+        (0, (1i128 << bits) - 1)
+    };
+    let (s, b, t) = (as_value(start), as_value(bound), step.as_signed());
+    let basic = stay_cond.get_basic_cond();
+    let holds = |x: i128| match basic {
+        CmpCond::LT => x < b,
+        CmpCond::LE => x <= b,
+        CmpCond::GT => x > b,
+        CmpCond::GE => x >= b,
+        CmpCond::EQ => x == b,
+        CmpCond::NE => x != b,
+        CmpCond::ALWAYS => true,
+        _ => false,
+    };
+    if !holds(s) {
+        return Some(0);
+    }
+    let n = match basic {
+        CmpCond::LT if t > 0 => (b - s + t - 1) / t,
+        CmpCond::LE if t > 0 => (b - s) / t + 1,
+        CmpCond::GT if t < 0 => (s - b - t - 1) / -t,
+        CmpCond::GE if t < 0 => (s - b) / -t + 1,
+        CmpCond::NE if t != 0 && (b - s) % t == 0 && (b - s) / t > 0 => (b - s) / t,
+        // 步长非零时下一次迭代的值一定不等于边界
+        CmpCond::EQ if t != 0 => return Some(1),
+        _ => return None,
+    };
+    let last = n.checked_mul(t)?.checked_add(s)?;
+    if last < min || last > max {
+        return None;
+    }
+    Some(n as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{FuncID, ISubGlobalID},
+        opt::{IFuncTransformPass, LoopRotate, Mem2Reg},
+        testing::cases::test_case_array_sum,
+    };
+
+    fn find_inst(allocs: &IRAllocs, func: FuncID, pred: impl Fn(&InstObj) -> bool) -> InstID {
+        func.blocks_iter(allocs)
+            .flat_map(|(block, _)| block.insts_iter(allocs).collect::<Vec<_>>())
+            .find(|(_, inst)| pred(inst))
+            .map(|(id, _)| id)
+            .expect("instruction not found")
+    }
+
+    #[test]
+    fn test_exit_iteration() {
+        let i32c = |v: i32| APInt::new(v as u32, 32);
+        // for (i = 0; i < 100; i++)
+        assert_eq!(
+            exit_iteration(CmpCond::SLT, i32c(0), i32c(1), i32c(100)),
+            Some(100)
+        );
+        // for (i = 0; i <= 100; i += 3)
+        assert_eq!(
+            exit_iteration(CmpCond::SLE, i32c(0), i32c(3), i32c(100)),
+            Some(34)
+        );
+        // for (i = 10; i > 0; i -= 2)
+        assert_eq!(
+            exit_iteration(CmpCond::SGT, i32c(10), i32c(-2), i32c(0)),
+            Some(5)
+        );
+        // for (i = 0; i != 10; i += 2)
+        assert_eq!(
+            exit_iteration(CmpCond::NE, i32c(0), i32c(2), i32c(10)),
+            Some(5)
+        );
+        // for (i = 0; i != 9; i += 2) 会回绕
+        assert_eq!(exit_iteration(CmpCond::NE, i32c(0), i32c(2), i32c(9)), None);
+        // 一次都不进入循环
+        assert_eq!(
+            exit_iteration(CmpCond::SLT, i32c(5), i32c(1), i32c(0)),
+            Some(0)
+        );
+        // 无符号比较中 0 - 1 会回绕
+        assert_eq!(
+            exit_iteration(CmpCond::GE, i32c(3), i32c(-1), i32c(0)),
+            None
+        );
+        // i8: for (i = 100; i < 127; i += 10) 会越过有符号上界
+        let i8c = |v: i8| APInt::new(v as u8, 8);
+        assert_eq!(
+            exit_iteration(CmpCond::SLT, i8c(100), i8c(10), i8c(127)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_scev_loop() {
+        let module = test_case_array_sum().module;
+        let allocs = &module.allocs;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(&module).run_on_func(func);
+
+        let scev = ScalarEvolution::new(allocs, func).unwrap();
+        assert_eq!(scev.loops.loops.len(), 1);
+        let header = scev.loops.loops[0].header;
+        assert_eq!(scev.trip_count(allocs, 0), Some(101));
+        assert_eq!(scev.backedge_taken_count(allocs, 0), Some(100));
+
+        // i = {0,+,1}<header>
+        let cmp = find_inst(allocs, func, |inst| matches!(inst, InstObj::Cmp(_)));
+        let InstObj::Cmp(cmp) = cmp.deref_ir(allocs) else { unreachable!() };
+        let iv = scev.get_scev(allocs, cmp.get_lhs(allocs)).unwrap();
+        let rec = iv
+            .as_addrec()
+            .expect("induction variable should be an add-recurrence");
+        assert_eq!(rec.header, header);
+        assert_eq!(rec.start.as_const(), Some(APInt::new(0u32, 32)));
+        assert_eq!(rec.step.as_const(), Some(APInt::new(1u32, 32)));
+        assert!(rec.nsw);
+        assert_eq!(rec.evaluate_at(7), Some(APInt::new(7u32, 32)));
+
+        // &a[i] = a + {0,+,4}<header>
+        let gep = find_inst(allocs, func, |inst| matches!(inst, InstObj::GEP(_)));
+        let gep = GEPInstID::raw_from(gep);
+        let offset = scev.gep_offset(allocs, &module.tctx, gep).unwrap();
+        let ptr_bits = module.tctx.arch.ptr_nbits as u8;
+        let rec = offset
+            .offset
+            .as_addrec()
+            .expect("GEP offset should be an add-recurrence");
+        assert!(rec.start.is_zero());
+        assert_eq!(rec.step.as_const(), Some(APInt::new(4u8, ptr_bits)));
+        assert_eq!(
+            scev.gep_stride(allocs, &module.tctx, gep, 0),
+            Some(APInt::new(4u8, ptr_bits))
+        );
+
+        // 旋转后出口条件移到 latch, 循环体执行 100 次
+        LoopRotate::new(&module).run_on_func(func);
+        let scev = ScalarEvolution::new(allocs, func).unwrap();
+        assert_eq!(scev.loops.loops.len(), 1);
+        assert_eq!(scev.backedge_taken_count(allocs, 0), Some(99));
+        assert_eq!(scev.trip_count(allocs, 0), Some(100));
+    }
+}
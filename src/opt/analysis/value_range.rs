@@ -0,0 +1,1033 @@
+//! Integer value range analysis.
+//!
+//! 基于区间抽象解释的整数值域分析. 区间使用 [`ConstRange`] 表示, 它是模 `2^bits`
+//! 意义下的回绕区间, 因此同时可以表示有符号和无符号的值域.
+//!
+//! 分析会在 `BrInst` 的出边上根据 `CmpInst` 的条件细化操作数的值域, 并在循环 phi
+//! 上使用 widening 保证收敛, 收敛后再做若干轮 narrowing 找回精度.
+
+use crate::{
+    base::APInt,
+    ir::{
+        BlockID, CmpCond, ConstData, FuncID, IRAllocs, ISubInst, ISubInstID, ISubValueSSA,
+        ITraceableValue, InstID, InstObj, Opcode, TerminatorID, ValueSSA,
+    },
+    opt::{CfgRes, CfgSnapshot, DominatorTree},
+    typing::ValTypeID,
+};
+use smallvec::{SmallVec, smallvec};
+use std::collections::HashMap;
+
+const fn width_mask(bits: u8) -> u128 {
+    if bits >= 128 { u128::MAX } else { (1u128 << bits) - 1 }
+}
+const fn sign_bit(bits: u8) -> u128 {
+    1u128 << (bits - 1)
+}
+/// 把 `bits` 位宽下的无符号值解释为有符号值
+const fn to_signed(value: u128, bits: u8) -> i128 {
+    let shift = 128 - bits as u32;
+    ((value << shift) as i128) >> shift
+}
+
+/// 模 `2^bits` 意义下的整数区间 `[lower, upper)`.
+///
+/// 当 `lower > upper` 时区间会回绕, 例如 8 位的 `[250, 3)` 表示 `{250, ..., 255, 0, 1, 2}`.
+/// `lower == upper` 只用来表示全集 (两者都为全 1) 与空集 (两者都为 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstRange {
+    lower: APInt,
+    upper: APInt,
+}
+
+impl std::fmt::Display for ConstRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bits = self.bits();
+        if self.is_full() {
+            write!(f, "full:i{bits}")
+        } else if self.is_empty() {
+            write!(f, "empty:i{bits}")
+        } else {
+            write!(f, "[{}, {}):i{bits}", self.lower, self.upper)
+        }
+    }
+}
+
+impl ConstRange {
+    pub fn new_full(bits: u8) -> Self {
+        let max = APInt::new(u128::MAX, bits);
+        Self { lower: max, upper: max }
+    }
+    pub fn new_empty(bits: u8) -> Self {
+        let zero = APInt::new(0u8, bits);
+        Self { lower: zero, upper: zero }
+    }
+    pub fn new_single(value: APInt) -> Self {
+        Self { lower: value, upper: value + 1u8 }
+    }
+    /// 区间 `[lower, upper)`. `lower == upper` 时得到全集.
+    pub fn new(lower: APInt, upper: APInt) -> Self {
+        assert_eq!(
+            lower.bits(),
+            upper.bits(),
+            "ConstRange bound width mismatch"
+        );
+        if lower == upper { Self::new_full(lower.bits()) } else { Self { lower, upper } }
+    }
+    /// 无符号闭区间 `[min, max]`. `min > max` 时得到空集.
+    pub fn new_urange(min: u128, max: u128, bits: u8) -> Self {
+        let mask = width_mask(bits);
+        let (min, max) = (min & mask, max & mask);
+        if min > max {
+            return Self::new_empty(bits);
+        }
+        Self::new(APInt::new(min, bits), APInt::new(max.wrapping_add(1), bits))
+    }
+    /// 有符号闭区间 `[min, max]`. `min > max` 时得到空集.
+    pub fn new_srange(min: i128, max: i128, bits: u8) -> Self {
+        if min > max {
+            return Self::new_empty(bits);
+        }
+        let sb = sign_bit(bits);
+        Self::new_urange(min as u128 ^ sb, max as u128 ^ sb, bits).biased()
+    }
+
+    fn raw_new(lower: u128, upper: u128, bits: u8) -> Self {
+        Self::new(APInt::new(lower, bits), APInt::new(upper, bits))
+    }
+    fn lower_raw(&self) -> u128 {
+        self.lower.as_unsigned()
+    }
+    fn upper_raw(&self) -> u128 {
+        self.upper.as_unsigned()
+    }
+    fn mask(&self) -> u128 {
+        width_mask(self.bits())
+    }
+
+    pub fn bits(&self) -> u8 {
+        self.lower.bits()
+    }
+    pub fn lower(&self) -> APInt {
+        self.lower
+    }
+    pub fn upper(&self) -> APInt {
+        self.upper
+    }
+    pub fn is_full(&self) -> bool {
+        self.lower == self.upper && self.lower_raw() == self.mask()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.lower == self.upper && self.lower.is_zero()
+    }
+    /// 区间在无符号意义下回绕, 即同时包含最大值和 0.
+    pub fn is_wrapped(&self) -> bool {
+        self.lower_raw() > self.upper_raw() && self.upper.is_nonzero()
+    }
+    pub fn as_single(&self) -> Option<APInt> {
+        (self.upper_raw().wrapping_sub(self.lower_raw()) & self.mask() == 1).then_some(self.lower)
+    }
+    pub fn contains(&self, value: APInt) -> bool {
+        let v = value.as_unsigned();
+        let (l, u) = (self.lower_raw(), self.upper_raw());
+        if self.is_full() {
+            true
+        } else if l <= u {
+            l <= v && v < u
+        } else {
+            l <= v || v < u
+        }
+    }
+    /// 区间内的元素个数减一. 对空集返回 `None`.
+    fn span(&self) -> Option<u128> {
+        if self.is_empty() {
+            None
+        } else if self.is_full() {
+            Some(self.mask())
+        } else {
+            Some(
+                self.upper_raw()
+                    .wrapping_sub(self.lower_raw())
+                    .wrapping_sub(1)
+                    & self.mask(),
+            )
+        }
+    }
+
+    /// 把区间拆成不回绕的无符号闭区间, 按起点升序排列.
+    fn pieces(&self) -> SmallVec<[(u128, u128); 2]> {
+        let (l, u, max) = (self.lower_raw(), self.upper_raw(), self.mask());
+        if self.is_empty() {
+            smallvec![]
+        } else if self.is_full() {
+            smallvec![(0, max)]
+        } else if u == 0 {
+            smallvec![(l, max)]
+        } else if l < u {
+            smallvec![(l, u - 1)]
+        } else {
+            smallvec![(0, u - 1), (l, max)]
+        }
+    }
+    /// 覆盖若干不相交的无符号闭区间的最小回绕区间: 去掉其中最大的空隙.
+    fn from_pieces(mut pieces: SmallVec<[(u128, u128); 4]>, bits: u8) -> Self {
+        let max = width_mask(bits);
+        pieces.sort_unstable();
+        let Some(&(first, _)) = pieces.first() else {
+            return Self::new_empty(bits);
+        };
+        let last = pieces.iter().map(|p| p.1).max().unwrap();
+        // 从最大值回绕到 0 的空隙. 优先选择它, 以得到不回绕的结果
+        let mut best_gap = max - last + first;
+        let (mut lower, mut upper) = (first, last.wrapping_add(1));
+        let mut reach = first;
+        for &(start, end) in &pieces {
+            if start > reach.saturating_add(1) && start - reach - 1 > best_gap {
+                best_gap = start - reach - 1;
+                (lower, upper) = (start, reach + 1);
+            }
+            reach = reach.max(end);
+        }
+        if best_gap == 0 {
+            return Self::new_full(bits);
+        }
+        Self::raw_new(lower, upper, bits)
+    }
+
+    /// 把区间整体平移 `2^(bits-1)`. 这样有符号的大小关系就变成了无符号的大小关系.
+    fn biased(&self) -> Self {
+        if self.is_full() || self.is_empty() {
+            return *self;
+        }
+        let sb = sign_bit(self.bits());
+        Self::raw_new(self.lower_raw() ^ sb, self.upper_raw() ^ sb, self.bits())
+    }
+
+    pub fn unsigned_min(&self) -> APInt {
+        let pieces = self.pieces();
+        APInt::new(pieces.first().map_or(0, |p| p.0), self.bits())
+    }
+    pub fn unsigned_max(&self) -> APInt {
+        let pieces = self.pieces();
+        APInt::new(pieces.last().map_or(0, |p| p.1), self.bits())
+    }
+    pub fn signed_min(&self) -> APInt {
+        APInt::new(self.biased().umin() ^ sign_bit(self.bits()), self.bits())
+    }
+    pub fn signed_max(&self) -> APInt {
+        APInt::new(self.biased().umax() ^ sign_bit(self.bits()), self.bits())
+    }
+    fn umin(&self) -> u128 {
+        self.unsigned_min().as_unsigned()
+    }
+    fn umax(&self) -> u128 {
+        self.unsigned_max().as_unsigned()
+    }
+    fn smin(&self) -> i128 {
+        self.signed_min().as_signed()
+    }
+    fn smax(&self) -> i128 {
+        self.signed_max().as_signed()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let pieces = self.pieces().into_iter().chain(other.pieces()).collect();
+        Self::from_pieces(pieces, self.bits())
+    }
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut pieces = SmallVec::new();
+        for (l0, r0) in self.pieces() {
+            for (l1, r1) in other.pieces() {
+                let (l, r) = (l0.max(l1), r0.min(r1));
+                if l <= r {
+                    pieces.push((l, r));
+                }
+            }
+        }
+        Self::from_pieces(pieces, self.bits())
+    }
+    /// 取两个区间中元素较少的那一个.
+    fn smaller(self, other: Self) -> Self {
+        match (self.span(), other.span()) {
+            (Some(a), Some(b)) if b < a => other,
+            _ => self,
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        let (Some(sa), Some(sb)) = (self.span(), other.span()) else {
+            return Self::new_empty(bits);
+        };
+        match sa.checked_add(sb) {
+            Some(s) if s < self.mask() => Self::raw_new(
+                self.lower_raw().wrapping_add(other.lower_raw()),
+                self.lower_raw()
+                    .wrapping_add(other.lower_raw())
+                    .wrapping_add(s + 1),
+                bits,
+            ),
+            _ => Self::new_full(bits),
+        }
+    }
+    pub fn sub(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        let (Some(sa), Some(sb)) = (self.span(), other.span()) else {
+            return Self::new_empty(bits);
+        };
+        match sa.checked_add(sb) {
+            Some(s) if s < self.mask() => {
+                let lower = self
+                    .lower_raw()
+                    .wrapping_sub(other.lower_raw())
+                    .wrapping_sub(sb);
+                Self::raw_new(lower, lower.wrapping_add(s + 1), bits)
+            }
+            _ => Self::new_full(bits),
+        }
+    }
+    pub fn mul(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() {
+            return Self::new_empty(bits);
+        }
+        let mask = self.mask();
+        let unsigned = match self.umax().checked_mul(other.umax()) {
+            Some(max) if max <= mask => Self::new_urange(self.umin() * other.umin(), max, bits),
+            _ => Self::new_full(bits),
+        };
+        let (smin, smax) = (to_signed(sign_bit(bits), bits), to_signed(mask >> 1, bits));
+        let corners = [
+            self.smin().checked_mul(other.smin()),
+            self.smin().checked_mul(other.smax()),
+            self.smax().checked_mul(other.smin()),
+            self.smax().checked_mul(other.smax()),
+        ];
+        let signed = if corners
+            .iter()
+            .all(|c| c.is_some_and(|c| smin <= c && c <= smax))
+        {
+            let corners = corners.map(Option::unwrap);
+            let min = corners.iter().copied().min().unwrap();
+            let max = corners.iter().copied().max().unwrap();
+            Self::new_srange(min, max, bits)
+        } else {
+            Self::new_full(bits)
+        };
+        unsigned.smaller(signed)
+    }
+    pub fn udiv(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() || other.umax() == 0 {
+            return Self::new_empty(bits);
+        }
+        // 除以 0 是未定义行为, 因此除数至少为 1
+        let rhs_min = other.umin().max(1);
+        Self::new_urange(self.umin() / other.umax(), self.umax() / rhs_min, bits)
+    }
+    pub fn urem(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() || other.umax() == 0 {
+            return Self::new_empty(bits);
+        }
+        if self.umax() < other.umin() {
+            return *self;
+        }
+        Self::new_urange(0, self.umax().min(other.umax() - 1), bits)
+    }
+    pub fn srem(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() {
+            return Self::new_empty(bits);
+        }
+        // 余数的绝对值小于除数的绝对值, 符号与被除数相同
+        let limit = other.smin().unsigned_abs().max(other.smax().unsigned_abs()) - 1;
+        let limit = limit.min(i128::MAX as u128) as i128;
+        let min = if self.smin() >= 0 { 0 } else { self.smin().max(-limit) };
+        let max = if self.smax() <= 0 { 0 } else { self.smax().min(limit) };
+        Self::new_srange(min, max, bits)
+    }
+
+    pub fn and(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() {
+            return Self::new_empty(bits);
+        }
+        Self::new_urange(0, self.umax().min(other.umax()), bits)
+    }
+    pub fn or(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() {
+            return Self::new_empty(bits);
+        }
+        let high = self.umax() | other.umax();
+        let max = width_mask(128 - high.leading_zeros() as u8);
+        Self::new_urange(self.umin().max(other.umin()), max, bits)
+    }
+    pub fn xor(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() {
+            return Self::new_empty(bits);
+        }
+        let high = self.umax() | other.umax();
+        Self::new_urange(0, width_mask(128 - high.leading_zeros() as u8), bits)
+    }
+    pub fn shl(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() {
+            return Self::new_empty(bits);
+        }
+        let (min_sh, max_sh) = (other.umin(), other.umax());
+        if max_sh >= bits as u128 {
+            return Self::new_full(bits);
+        }
+        let umax = self.umax();
+        // 最高的置位被移出时结果会回绕
+        if (umax << (128 - bits as u32)).leading_zeros() < max_sh as u32 {
+            return Self::new_full(bits);
+        }
+        Self::new_urange(self.umin() << min_sh, umax << max_sh, bits)
+    }
+    pub fn lshr(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() || other.umin() >= bits as u128 {
+            return Self::new_empty(bits);
+        }
+        let max_sh = other.umax().min(bits as u128 - 1);
+        Self::new_urange(self.umin() >> max_sh, self.umax() >> other.umin(), bits)
+    }
+    pub fn ashr(&self, other: &Self) -> Self {
+        let bits = self.bits();
+        if self.is_empty() || other.is_empty() || other.umin() >= bits as u128 {
+            return Self::new_empty(bits);
+        }
+        let (min_sh, max_sh) = (other.umin(), other.umax().min(bits as u128 - 1));
+        let min = (self.smin() >> min_sh).min(self.smin() >> max_sh);
+        let max = (self.smax() >> min_sh).max(self.smax() >> max_sh);
+        Self::new_srange(min, max, bits)
+    }
+
+    pub fn zext(&self, bits: u8) -> Self {
+        if self.is_empty() {
+            return Self::new_empty(bits);
+        }
+        if self.is_wrapped() || self.is_full() {
+            return Self::new_urange(0, self.mask(), bits);
+        }
+        Self::new_urange(self.umin(), self.umax(), bits)
+    }
+    pub fn sext(&self, bits: u8) -> Self {
+        if self.is_empty() {
+            return Self::new_empty(bits);
+        }
+        Self::new_srange(self.smin(), self.smax(), bits)
+    }
+    pub fn trunc(&self, bits: u8) -> Self {
+        match self.span() {
+            None => Self::new_empty(bits),
+            Some(span) if span < width_mask(bits) => {
+                let lower = self.lower_raw() & width_mask(bits);
+                Self::raw_new(lower, lower.wrapping_add(span + 1), bits)
+            }
+            Some(_) => Self::new_full(bits),
+        }
+    }
+
+    /// 按二元运算的操作码计算结果的值域.
+    pub fn binop(opcode: Opcode, lhs: &Self, rhs: &Self) -> Self {
+        match opcode {
+            Opcode::Add => lhs.add(rhs),
+            Opcode::Sub => lhs.sub(rhs),
+            Opcode::Mul => lhs.mul(rhs),
+            Opcode::Udiv => lhs.udiv(rhs),
+            Opcode::Urem => lhs.urem(rhs),
+            Opcode::Srem => lhs.srem(rhs),
+            Opcode::BitAnd => lhs.and(rhs),
+            Opcode::BitOr => lhs.or(rhs),
+            Opcode::BitXor => lhs.xor(rhs),
+            Opcode::Shl => lhs.shl(rhs),
+            Opcode::Lshr => lhs.lshr(rhs),
+            Opcode::Ashr => lhs.ashr(rhs),
+            _ if lhs.is_empty() || rhs.is_empty() => Self::new_empty(lhs.bits()),
+            _ => Self::new_full(lhs.bits()),
+        }
+    }
+
+    /// 求出所有满足 "存在 `y ∈ other` 使得 `x cond y`" 的 `x` 构成的区间.
+    /// 用于在条件跳转的出边上细化比较操作数的值域.
+    pub fn make_allowed_cmp_region(cond: CmpCond, other: &Self) -> Self {
+        let bits = other.bits();
+        if other.is_empty() {
+            return Self::new_empty(bits);
+        }
+        if cond.is_signed_ordered() {
+            return Self::make_allowed_cmp_region(cond - CmpCond::SIGNED_ORDERED, &other.biased())
+                .biased();
+        }
+        let max = other.mask();
+        match cond.get_basic_cond() {
+            CmpCond::ALWAYS => Self::new_full(bits),
+            CmpCond::NEVER => Self::new_empty(bits),
+            CmpCond::EQ => *other,
+            CmpCond::NE => match other.as_single() {
+                Some(v) => Self::new(v + 1u8, v),
+                None => Self::new_full(bits),
+            },
+            CmpCond::LT => match other.umax() {
+                0 => Self::new_empty(bits),
+                umax => Self::new_urange(0, umax - 1, bits),
+            },
+            CmpCond::LE => Self::new_urange(0, other.umax(), bits),
+            CmpCond::GT => match other.umin() {
+                umin if umin == max => Self::new_empty(bits),
+                umin => Self::new_urange(umin + 1, max, bits),
+            },
+            CmpCond::GE => Self::new_urange(other.umin(), max, bits),
+            _ => Self::new_full(bits),
+        }
+    }
+
+    /// 判断 `lhs cond rhs` 是否对区间内的所有取值恒成立或恒不成立.
+    pub fn icmp(cond: CmpCond, lhs: &Self, rhs: &Self) -> Option<bool> {
+        if lhs.is_empty() || rhs.is_empty() {
+            return None;
+        }
+        if cond.is_signed_ordered() {
+            return Self::icmp(cond - CmpCond::SIGNED_ORDERED, &lhs.biased(), &rhs.biased());
+        }
+        let disjoint = lhs.intersect(rhs).is_empty();
+        let same_single = lhs.as_single().is_some() && lhs.as_single() == rhs.as_single();
+        match cond.get_basic_cond() {
+            CmpCond::ALWAYS => Some(true),
+            CmpCond::NEVER => Some(false),
+            CmpCond::EQ if same_single => Some(true),
+            CmpCond::EQ if disjoint => Some(false),
+            CmpCond::NE if same_single => Some(false),
+            CmpCond::NE if disjoint => Some(true),
+            CmpCond::LT if lhs.umax() < rhs.umin() => Some(true),
+            CmpCond::LT if lhs.umin() >= rhs.umax() => Some(false),
+            CmpCond::LE if lhs.umax() <= rhs.umin() => Some(true),
+            CmpCond::LE if lhs.umin() > rhs.umax() => Some(false),
+            CmpCond::GT if lhs.umin() > rhs.umax() => Some(true),
+            CmpCond::GT if lhs.umax() <= rhs.umin() => Some(false),
+            CmpCond::GE if lhs.umin() >= rhs.umax() => Some(true),
+            CmpCond::GE if lhs.umax() < rhs.umin() => Some(false),
+            _ => None,
+        }
+    }
+
+    /// widening: 在有符号和无符号两种意义下, 把正在增长的边界直接推到极值.
+    fn widen(&self, new: &Self) -> Self {
+        let joined = self.union(new);
+        if self.is_empty() || joined == *self {
+            return joined;
+        }
+        let bits = self.bits();
+        let (smin, smax) = (
+            to_signed(sign_bit(bits), bits),
+            to_signed(self.mask() >> 1, bits),
+        );
+        let signed = Self::new_srange(
+            if joined.smin() < self.smin() { smin } else { joined.smin() },
+            if joined.smax() > self.smax() { smax } else { joined.smax() },
+            bits,
+        );
+        let unsigned = Self::new_urange(
+            if joined.umin() < self.umin() { 0 } else { joined.umin() },
+            if joined.umax() > self.umax() { self.mask() } else { joined.umax() },
+            bits,
+        );
+        signed.smaller(unsigned)
+    }
+}
+
+/// 一组值域约束: 值 -> 该值在某个程序点上的值域.
+type RangeFacts = HashMap<ValueSSA, ConstRange>;
+
+/// 函数级的整数值域分析.
+///
+/// 分析结果有两种查询方式:
+///
+/// * [`get_range`](Self::get_range): 值在整个函数中的值域;
+/// * [`get_range_at`](Self::get_range_at): 值在某个基本块中的值域, 包含了支配该块的
+///   所有条件跳转带来的约束.
+pub struct ValueRangeAnalysis {
+    ranges: HashMap<InstID, ConstRange>,
+    block_facts: HashMap<BlockID, RangeFacts>,
+    /// 支配树先序排列的基本块及其直接支配者
+    blocks: Vec<(BlockID, Option<BlockID>)>,
+    cfg: CfgSnapshot,
+}
+
+impl ValueRangeAnalysis {
+    /// phi 的值域增长超过这么多次之后开始 widening
+    const WIDEN_THRESHOLD: usize = 3;
+    /// 不动点之后 narrowing 的轮数
+    const NARROW_ROUNDS: usize = 2;
+
+    pub fn new(allocs: &IRAllocs, func: FuncID) -> CfgRes<Self> {
+        let dom_tree = DominatorTree::builder(allocs, func)?.build();
+        let blocks = dom_tree
+            .nodes
+            .iter()
+            .filter_map(|node| node.block.map(|b| (b, node.idom.map(|d| d))))
+            .collect();
+        let mut ret = Self {
+            ranges: HashMap::new(),
+            block_facts: HashMap::new(),
+            blocks,
+            cfg: CfgSnapshot::new(allocs, func)?,
+        };
+        let mut phi_updates: HashMap<InstID, usize> = HashMap::new();
+        while ret.run_round(allocs, |inst, old, new| {
+            let Some(old) = old else {
+                return new;
+            };
+            if !matches!(inst.deref_ir(allocs), InstObj::Phi(_)) {
+                return old.union(&new);
+            }
+            let count = phi_updates.entry(inst).or_default();
+            let joined = old.union(&new);
+            if joined != old {
+                *count += 1;
+            }
+            if *count > Self::WIDEN_THRESHOLD { old.widen(&new) } else { joined }
+        }) {}
+        for _ in 0..Self::NARROW_ROUNDS {
+            ret.run_round(allocs, |_, old, new| match old {
+                Some(old) => old.intersect(&new),
+                None => new,
+            });
+        }
+        Ok(ret)
+    }
+
+    /// 按支配树先序遍历一遍所有指令. 返回值域是否有变化.
+    fn run_round(
+        &mut self,
+        allocs: &IRAllocs,
+        mut merge: impl FnMut(InstID, Option<ConstRange>, ConstRange) -> ConstRange,
+    ) -> bool {
+        let mut changed = false;
+        for i in 0..self.blocks.len() {
+            let (block, idom) = self.blocks[i];
+            let mut facts = idom
+                .and_then(|d| self.block_facts.get(&d))
+                .cloned()
+                .unwrap_or_default();
+            if let Some(&[pred]) = self.cfg.pred_of(block) {
+                for (value, range) in self.edge_facts(allocs, pred, block) {
+                    Self::add_fact(&mut facts, value, range);
+                }
+            }
+            self.block_facts.insert(block, facts);
+
+            for (inst, _) in block.insts_iter(allocs) {
+                let Some(new) = self.eval_inst(allocs, block, inst) else {
+                    continue;
+                };
+                let old = self.ranges.get(&inst).copied();
+                let merged = merge(inst, old, new);
+                if old != Some(merged) {
+                    self.ranges.insert(inst, merged);
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    fn add_fact(facts: &mut RangeFacts, value: ValueSSA, range: ConstRange) {
+        let range = match facts.get(&value) {
+            Some(old) => old.intersect(&range),
+            None => range,
+        };
+        facts.insert(value, range);
+    }
+
+    /// 控制流沿着 `from -> to` 这条边流动时得到的约束.
+    fn edge_facts(
+        &self,
+        allocs: &IRAllocs,
+        from: BlockID,
+        to: BlockID,
+    ) -> SmallVec<[(ValueSSA, ConstRange); 2]> {
+        let mut facts = SmallVec::new();
+        let TerminatorID::Br(br) = from.get_terminator(allocs) else {
+            return facts;
+        };
+        let (then_bb, else_bb) = (br.get_then(allocs), br.get_else(allocs));
+        if then_bb == else_bb {
+            return facts;
+        }
+        let ValueSSA::Inst(cond) = br.get_cond(allocs) else {
+            return facts;
+        };
+        let InstObj::Cmp(cmp) = cond.deref_ir(allocs) else {
+            return facts;
+        };
+        if !cmp.cond.is_int() {
+            return facts;
+        }
+        let cond = if then_bb == Some(to) { cmp.cond } else { cmp.cond.invert() };
+        let (lhs, rhs) = (cmp.get_lhs(allocs), cmp.get_rhs(allocs));
+        let (Some(lhs_range), Some(rhs_range)) = (
+            self.range_at(allocs, from, lhs),
+            self.range_at(allocs, from, rhs),
+        ) else {
+            return facts;
+        };
+        if !matches!(lhs, ValueSSA::ConstData(_)) {
+            let allowed = ConstRange::make_allowed_cmp_region(cond, &rhs_range);
+            facts.push((lhs, lhs_range.intersect(&allowed)));
+        }
+        if !matches!(rhs, ValueSSA::ConstData(_)) {
+            let allowed = ConstRange::make_allowed_cmp_region(cond.swap_operands(), &lhs_range);
+            facts.push((rhs, rhs_range.intersect(&allowed)));
+        }
+        facts
+    }
+
+    fn eval_inst(&self, allocs: &IRAllocs, block: BlockID, inst_id: InstID) -> Option<ConstRange> {
+        let ValTypeID::Int(bits) = inst_id.get_valtype(allocs) else {
+            return None;
+        };
+        let operand = |value| self.range_at(allocs, block, value);
+        let range = match inst_id.deref_ir(allocs) {
+            InstObj::BinOP(binop) => {
+                let lhs = operand(binop.get_lhs(allocs))?;
+                let rhs = operand(binop.get_rhs(allocs))?;
+                ConstRange::binop(binop.get_opcode(), &lhs, &rhs)
+            }
+            InstObj::Cast(cast) => {
+                let from = cast.get_from(allocs);
+                match cast.get_opcode() {
+                    Opcode::Zext => operand(from)?.zext(bits),
+                    Opcode::Sext => operand(from)?.sext(bits),
+                    Opcode::Trunc => operand(from)?.trunc(bits),
+                    _ => ConstRange::new_full(bits),
+                }
+            }
+            InstObj::Cmp(cmp) if cmp.cond.is_int() => {
+                let lhs = operand(cmp.get_lhs(allocs))?;
+                let rhs = operand(cmp.get_rhs(allocs))?;
+                match ConstRange::icmp(cmp.cond, &lhs, &rhs) {
+                    Some(b) => ConstRange::new_single(APInt::from(b)),
+                    None if lhs.is_empty() || rhs.is_empty() => ConstRange::new_empty(1),
+                    None => ConstRange::new_full(1),
+                }
+            }
+            InstObj::Select(select) => {
+                let then_range = operand(select.get_then(allocs))?;
+                let else_range = operand(select.get_else(allocs))?;
+                match operand(select.get_cond(allocs)).and_then(|c| c.as_single()) {
+                    Some(c) if c.is_nonzero() => then_range,
+                    Some(_) => else_range,
+                    None => then_range.union(&else_range),
+                }
+            }
+            InstObj::Phi(phi) => {
+                let mut range = ConstRange::new_empty(bits);
+                for &[val_use, blk_use] in phi.incoming_uses().iter() {
+                    let ValueSSA::Block(pred) = blk_use.get_operand(allocs) else {
+                        continue;
+                    };
+                    let value = val_use.get_operand(allocs);
+                    if let Some(incoming) = self.range_on_edge(allocs, pred, block, value) {
+                        range = range.union(&incoming);
+                    }
+                }
+                range
+            }
+            _ => ConstRange::new_full(bits),
+        };
+        Some(range)
+    }
+
+    fn range_at(&self, allocs: &IRAllocs, block: BlockID, value: ValueSSA) -> Option<ConstRange> {
+        let range = match value {
+            // 分析过程中尚未求值的指令按空集处理
+            ValueSSA::Inst(inst) => match inst.get_valtype(allocs) {
+                ValTypeID::Int(bits) => self
+                    .ranges
+                    .get(&inst)
+                    .copied()
+                    .unwrap_or(ConstRange::new_empty(bits)),
+                _ => return None,
+            },
+            _ => self.get_range(allocs, value)?,
+        };
+        match self.block_facts.get(&block).and_then(|f| f.get(&value)) {
+            Some(fact) => Some(range.intersect(fact)),
+            None => Some(range),
+        }
+    }
+
+    /// 值在整个函数中的值域. 非整数值返回 `None`.
+    pub fn get_range(&self, allocs: &IRAllocs, value: ValueSSA) -> Option<ConstRange> {
+        let ValTypeID::Int(bits) = value.get_valtype(allocs) else {
+            return None;
+        };
+        let range = match value {
+            ValueSSA::ConstData(ConstData::Undef(_)) => ConstRange::new_full(bits),
+            ValueSSA::ConstData(_) => match value.as_apint() {
+                Some(c) => ConstRange::new_single(c),
+                None => ConstRange::new_full(bits),
+            },
+            ValueSSA::Inst(inst) => self
+                .ranges
+                .get(&inst)
+                .copied()
+                .unwrap_or(ConstRange::new_full(bits)),
+            _ => ConstRange::new_full(bits),
+        };
+        Some(range)
+    }
+    /// 值在基本块 `block` 中的值域, 包含了支配该块的条件跳转带来的约束.
+    pub fn get_range_at(
+        &self,
+        allocs: &IRAllocs,
+        block: BlockID,
+        value: ValueSSA,
+    ) -> Option<ConstRange> {
+        let range = self.get_range(allocs, value)?;
+        match self.block_facts.get(&block).and_then(|f| f.get(&value)) {
+            Some(fact) => Some(range.intersect(fact)),
+            None => Some(range),
+        }
+    }
+    /// 值在控制流边 `from -> to` 上的值域.
+    pub fn range_on_edge(
+        &self,
+        allocs: &IRAllocs,
+        from: BlockID,
+        to: BlockID,
+        value: ValueSSA,
+    ) -> Option<ConstRange> {
+        let mut range = self.range_at(allocs, from, value)?;
+        for (fact_value, fact) in self.edge_facts(allocs, from, to) {
+            if fact_value == value {
+                range = range.intersect(&fact);
+            }
+        }
+        Some(range)
+    }
+
+    /// 检查整数二元运算在其所在基本块中是否一定不会发生有符号溢出.
+    /// 可以用来为 `add`/`sub`/`mul` 添加 `BinOPFlags::NSW`.
+    pub fn proves_no_signed_wrap(&self, allocs: &IRAllocs, inst: InstID) -> bool {
+        self.check_no_wrap(allocs, inst, true)
+    }
+    /// 检查整数二元运算在其所在基本块中是否一定不会发生无符号溢出.
+    pub fn proves_no_unsigned_wrap(&self, allocs: &IRAllocs, inst: InstID) -> bool {
+        self.check_no_wrap(allocs, inst, false)
+    }
+    fn check_no_wrap(&self, allocs: &IRAllocs, inst: InstID, signed: bool) -> bool {
+        let InstObj::BinOP(binop) = inst.deref_ir(allocs) else {
+            return false;
+        };
+        let ValTypeID::Int(bits) = binop.get_valtype() else {
+            return false;
+        };
+        let Some(block) = binop.get_parent() else {
+            return false;
+        };
+        let (Some(lhs), Some(rhs)) = (
+            self.get_range_at(allocs, block, binop.get_lhs(allocs)),
+            self.get_range_at(allocs, block, binop.get_rhs(allocs)),
+        ) else {
+            return false;
+        };
+        if lhs.is_empty() || rhs.is_empty() {
+            return true;
+        }
+        let op = match binop.get_opcode() {
+            Opcode::Add => i128::checked_add,
+            Opcode::Sub => i128::checked_sub,
+            Opcode::Mul => i128::checked_mul,
+            _ => return false,
+        };
+        let (min, max, lhs_bounds, rhs_bounds) = if signed {
+            let mask = width_mask(bits);
+            (
+                to_signed(sign_bit(bits), bits),
+                to_signed(mask >> 1, bits),
+                [lhs.smin(), lhs.smax()],
+                [rhs.smin(), rhs.smax()],
+            )
+        } else if bits < 127 {
+            (
+                0,
+                width_mask(bits) as i128,
+                [lhs.umin() as i128, lhs.umax() as i128],
+                [rhs.umin() as i128, rhs.umax() as i128],
+            )
+        } else {
+            return false;
+        };
+        // add / sub / mul 在每个操作数上都是单调的 (mul 需要检查四个角), 只需检查边界
+        lhs_bounds.iter().all(|&l| {
+            rhs_bounds
+                .iter()
+                .all(|&r| op(l, r).is_some_and(|v| min <= v && v <= max))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{inst::*, *},
+        typing::{ArchInfo, FuncTypeID},
+    };
+
+    fn urange(min: u128, max: u128) -> ConstRange {
+        ConstRange::new_urange(min, max, 8)
+    }
+
+    #[test]
+    fn test_const_range() {
+        let full = ConstRange::new_full(8);
+        assert!(full.is_full() && !full.is_empty());
+        assert!(ConstRange::new_empty(8).is_empty());
+        assert_eq!(urange(3, 2), ConstRange::new_empty(8));
+
+        let wrapped = ConstRange::new_srange(-2, 3, 8);
+        assert!(wrapped.is_wrapped());
+        assert_eq!(wrapped.signed_min().as_signed(), -2);
+        assert_eq!(wrapped.signed_max().as_signed(), 3);
+        assert_eq!(wrapped.unsigned_min().as_unsigned(), 0);
+        assert_eq!(wrapped.unsigned_max().as_unsigned(), 255);
+        assert!(wrapped.contains(APInt::new(254u32, 8)));
+        assert!(!wrapped.contains(APInt::new(4u32, 8)));
+
+        assert_eq!(urange(0, 9).add(&urange(1, 1)), urange(1, 10));
+        assert_eq!(urange(250, 255).add(&urange(10, 10)).as_single(), None);
+        assert_eq!(urange(250, 255).add(&urange(10, 10)), urange(4, 9));
+        assert_eq!(
+            urange(0, 9).sub(&urange(1, 1)),
+            ConstRange::new_srange(-1, 8, 8)
+        );
+        assert!(urange(0, 200).add(&urange(0, 100)).is_full());
+        assert_eq!(urange(2, 3).mul(&urange(4, 5)), urange(8, 15));
+        assert_eq!(urange(0, 100).and(&urange(0, 15)), urange(0, 15));
+        assert_eq!(urange(0, 15).shl(&urange(2, 2)), urange(0, 60));
+        assert_eq!(urange(16, 64).lshr(&urange(2, 4)), urange(1, 16));
+        assert_eq!(urange(10, 20).urem(&urange(8, 8)), urange(0, 7));
+        assert_eq!(urange(1, 2).union(&urange(5, 6)), urange(1, 6));
+        assert_eq!(
+            urange(250, 255).union(&urange(0, 1)),
+            ConstRange::new_srange(-6, 1, 8)
+        );
+        assert_eq!(urange(0, 10).intersect(&urange(5, 20)), urange(5, 10));
+        assert_eq!(
+            ConstRange::new_srange(-1, 1, 8).sext(16),
+            ConstRange::new_srange(-1, 1, 16)
+        );
+        assert_eq!(
+            ConstRange::new_srange(-1, 1, 8).zext(16),
+            ConstRange::new_urange(0, 255, 16)
+        );
+        assert_eq!(ConstRange::new_urange(256, 260, 16).trunc(8), urange(0, 4));
+
+        let ten = ConstRange::new_single(APInt::new(10u32, 8));
+        let slt_ten = ConstRange::make_allowed_cmp_region(CmpCond::SLT, &ten);
+        assert_eq!(slt_ten, ConstRange::new_srange(-128, 9, 8));
+        let uge_ten = ConstRange::make_allowed_cmp_region(CmpCond::GE, &ten);
+        assert_eq!(uge_ten, urange(10, 255));
+        assert_eq!(
+            ConstRange::icmp(CmpCond::SLT, &urange(0, 9), &ten),
+            Some(true)
+        );
+        assert_eq!(
+            ConstRange::icmp(CmpCond::LT, &urange(10, 20), &ten),
+            Some(false)
+        );
+        assert_eq!(ConstRange::icmp(CmpCond::LT, &urange(5, 20), &ten), None);
+        assert_eq!(CmpCond::SLT.invert(), CmpCond::SGE);
+        assert_eq!(CmpCond::SLE.swap_operands(), CmpCond::SGE);
+    }
+
+    /// ```remusys-ir
+    /// define dso_local i32 @count() {
+    /// 0:
+    ///     br label %1
+    /// 1:
+    ///     %2 = phi i32 [0, %0], [%5, %4]
+    ///     %3 = icmp slt i32 %2, 10
+    ///     br i1 %3, label %4, label %6
+    /// 4:
+    ///     %5 = add i32 %2, 1
+    ///     br label %1
+    /// 6:
+    ///     ret i32 %2
+    /// }
+    /// ```
+    #[test]
+    fn test_value_range_loop() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "value_range");
+        let i32ty = ValTypeID::Int(32);
+        let fty = FuncTypeID::new(builder.tctx(), i32ty, false, []);
+        let func = FuncID::builder(builder.tctx(), "count", fty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+        let entry = func.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(entry));
+        let header = builder.split_block().unwrap();
+        builder.set_focus(IRFocus::Block(header));
+        let exit = builder.split_block().unwrap();
+        let body = builder.split_block().unwrap();
+
+        let phi = PhiInstID::from_incomings(
+            builder.allocs(),
+            i32ty,
+            [(entry, APInt::new(0u32, 32).into())],
+        );
+        builder.insert_inst(phi).unwrap();
+        let i = ValueSSA::Inst(phi.raw_into());
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, CmpCond::SLT, i32ty);
+        cmp.set_lhs(builder.allocs(), i);
+        cmp.set_rhs(builder.allocs(), APInt::new(10u32, 32).into());
+        builder.insert_inst(cmp).unwrap();
+        builder
+            .focus_set_branch_to(ValueSSA::Inst(cmp.raw_into()), body, exit)
+            .unwrap();
+
+        builder.set_focus(IRFocus::Block(body));
+        let next = builder
+            .build_inst(|allocs, _| {
+                BinOPInstID::new(allocs, Opcode::Add, i, APInt::new(1u32, 32).into())
+            })
+            .unwrap();
+        builder.focus_set_jump_to(header).unwrap();
+        phi.set_incoming(builder.allocs(), body, ValueSSA::Inst(next.raw_into()));
+
+        let TerminatorID::Ret(ret) = exit.get_terminator(builder.allocs()) else {
+            panic!("exit block should end with ret");
+        };
+        ret.set_retval(builder.allocs(), i);
+        let module = builder.take();
+        let allocs = &module.allocs;
+        crate::ir::checking::assert_module_sane(&module);
+
+        let ranges = ValueRangeAnalysis::new(allocs, func).unwrap();
+        let srange = |min, max| ConstRange::new_srange(min, max, 32);
+        let next = ValueSSA::Inst(next.raw_into());
+        assert_eq!(ranges.get_range(allocs, i), Some(srange(0, 10)));
+        assert_eq!(ranges.get_range_at(allocs, body, i), Some(srange(0, 9)));
+        assert_eq!(ranges.get_range_at(allocs, exit, i), Some(srange(10, 10)));
+        assert_eq!(ranges.get_range(allocs, next), Some(srange(1, 10)));
+        assert_eq!(
+            ranges.range_on_edge(allocs, header, body, i),
+            Some(srange(0, 9))
+        );
+        let cond = ValueSSA::Inst(cmp.raw_into());
+        assert!(ranges.get_range(allocs, cond).unwrap().is_full());
+        let ValueSSA::Inst(next) = next else { unreachable!() };
+        assert!(ranges.proves_no_signed_wrap(allocs, next));
+        assert!(ranges.proves_no_unsigned_wrap(allocs, next));
+    }
+}
@@ -1,9 +1,209 @@
-use crate::{SymbolStr, ir::FuncID};
+use crate::{
+    SymbolStr,
+    ir::{
+        BlockID, FuncID, IRAllocs, IRBuilder, IRFocus, ISubGlobalID, ISubInstID, ITraceableValue,
+        InstObj, JumpTargetID, Module, UseID, UseKind, UserID, ValueSSA,
+        inst::{CallInstID, JumpInstID, PhiInstID},
+    },
+    opt::{CfgDfsSeq, Loop},
+};
+use smallvec::SmallVec;
 
 pub mod basic_dce;
+pub mod critical_edge;
+pub mod dead_arg_elim;
+pub mod int_legalize;
+pub mod ipcp;
+pub mod jump_threading;
+pub mod loop_deletion;
+pub mod loop_rotate;
+pub mod loop_simplify;
+pub mod loop_strength_reduce;
+pub mod loop_unroll;
+pub mod loop_unswitch;
 pub mod mem2reg;
+pub mod phi_elim;
+pub mod pre;
+pub mod sccp;
+pub mod slp_vectorize;
+pub mod switch_lower;
 
 pub trait IFuncTransformPass {
     fn get_name(&self) -> SymbolStr;
     fn run_on_func(&mut self, func: FuncID);
 }
+
+pub trait IModuleTransformPass {
+    fn get_name(&self) -> SymbolStr;
+    fn run_on_module(&mut self);
+}
+
+/// 收集函数的所有直接调用点. 如果函数的地址被获取了 (存在非 callee 的使用者),
+/// 或者某个调用点的函数类型与函数本身不一致, 则返回 `None`.
+fn collect_direct_calls(allocs: &IRAllocs, func: FuncID) -> Option<SmallVec<[CallInstID; 4]>> {
+    let functy = func.get_functype(allocs);
+    let mut calls = SmallVec::new();
+    for (_, u) in func.deref_ir(allocs).user_iter(allocs) {
+        if u.get_kind() != UseKind::CallOpCallee {
+            return None;
+        }
+        let Some(UserID::Inst(inst)) = u.user.get() else {
+            return None;
+        };
+        let call = CallInstID::try_from_instid(inst, allocs)?;
+        if call.callee_ty(allocs) != functy {
+            return None;
+        }
+        calls.push(call);
+    }
+    Some(calls)
+}
+
+/// 基本块开头的所有 Phi 指令.
+fn block_phis(allocs: &IRAllocs, block: BlockID) -> SmallVec<[PhiInstID; 4]> {
+    let mut phis = SmallVec::new();
+    for (inst_id, inst) in block.insts_iter(allocs) {
+        match inst {
+            InstObj::Phi(_) => phis.push(PhiInstID::raw_from(inst_id)),
+            InstObj::PhiInstEnd(_) => break,
+            _ => continue,
+        }
+    }
+    phis
+}
+
+/// 使用 `use_id` 的位置所在的基本块. Phi 的传入值被视为在对应的前驱块末尾使用.
+fn use_block(allocs: &IRAllocs, use_id: UseID) -> Option<BlockID> {
+    let Some(UserID::Inst(user)) = use_id.get_user(allocs) else {
+        return None;
+    };
+    match (use_id.get_kind(allocs), user.deref_ir(allocs)) {
+        (UseKind::PhiIncomingValue(idx), InstObj::Phi(phi)) => {
+            let [_, ublk] = phi.incoming_uses()[idx as usize];
+            match ublk.get_operand(allocs) {
+                ValueSSA::Block(block) => Some(block),
+                _ => None,
+            }
+        }
+        _ => user.get_parent(allocs),
+    }
+}
+
+/// 把 `from` 的终结指令中所有指向 `old` 的跳转目标改为指向 `new`. 不会修改 Phi 指令.
+fn redirect_jumps(allocs: &IRAllocs, from: BlockID, old: BlockID, new: BlockID) {
+    let jts: SmallVec<[JumpTargetID; 4]> = from
+        .get_terminator(allocs)
+        .get_jts(allocs)
+        .iter()
+        .copied()
+        .filter(|jt| jt.get_block(allocs) == Some(old))
+        .collect();
+    for jt in jts {
+        jt.set_block(allocs, new);
+    }
+}
+
+/// 在 `block` 之前插入一个新基本块, 让 `preds` 中的前驱都改为跳转到新块, 新块再无条件跳转到 `block`.
+///
+/// `block` 中 Phi 指令来自 `preds` 的传入值会被合并到新块中: 如果这些值都相同, 直接作为新块的传入值;
+/// 否则在新块中插入一条新的 Phi 指令.
+fn split_block_preds(module: &Module, block: BlockID, preds: &[BlockID]) -> BlockID {
+    let allocs = &module.allocs;
+    let func = block
+        .get_parent_func(allocs)
+        .expect("Internal error: block to split is not attached to a function");
+    let new_bb = BlockID::new_with_terminator(allocs, JumpInstID::with_target(allocs, block));
+    func.blocks_unwrap(allocs)
+        .node_add_prev(block, new_bb, &allocs.blocks)
+        .expect("Internal error: failed to insert the new block");
+
+    let mut uniq_preds: SmallVec<[BlockID; 4]> = SmallVec::new();
+    for &pred in preds {
+        if !uniq_preds.contains(&pred) {
+            uniq_preds.push(pred);
+        }
+    }
+    for &pred in &uniq_preds {
+        redirect_jumps(allocs, pred, block, new_bb);
+    }
+
+    let mut builder = IRBuilder::new(module);
+    builder.set_focus(IRFocus::Block(new_bb));
+    for phi in block_phis(allocs, block) {
+        let phi_obj = phi.deref_ir(allocs);
+        let incomings: SmallVec<[(BlockID, ValueSSA); 4]> = uniq_preds
+            .iter()
+            .filter_map(|&pred| Some((pred, phi_obj.remove_incoming(allocs, pred)?)))
+            .collect();
+        let Some(&(_, first)) = incomings.first() else {
+            continue;
+        };
+        let value = if incomings.iter().all(|&(_, v)| v == first) {
+            first
+        } else {
+            let new_phi = PhiInstID::from_incomings(allocs, phi_obj.get_valtype(), incomings);
+            builder
+                .insert_inst(new_phi)
+                .expect("Internal error: failed to insert phi into split block");
+            ValueSSA::Inst(new_phi.raw_into())
+        };
+        phi_obj.set_incoming(allocs, new_bb, value);
+    }
+    new_bb
+}
+
+/// 循环中除 Phi 以外的指令条数, 包括终结指令.
+fn loop_size(allocs: &IRAllocs, lp: &Loop) -> usize {
+    lp.block_list
+        .iter()
+        .map(|&block| {
+            block
+                .insts_iter(allocs)
+                .filter(|(_, inst)| {
+                    !matches!(
+                        inst,
+                        InstObj::GuideNode(_) | InstObj::Phi(_) | InstObj::PhiInstEnd(_)
+                    )
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// 删除函数中从入口不可达的基本块, 同时移除其他块的 Phi 中来自这些块的传入值. 返回删除的块数.
+fn remove_unreachable_blocks(module: &Module, func: FuncID) -> usize {
+    let allocs = &module.allocs;
+    let Ok(dfs) = CfgDfsSeq::new_pre(allocs, func) else {
+        return 0;
+    };
+    let dead: Vec<BlockID> = func
+        .blocks_iter(allocs)
+        .map(|(block, _)| block)
+        .filter(|&block| !dfs.block_reachable(block))
+        .collect();
+    for &block in &dead {
+        let succs: SmallVec<[BlockID; 4]> = block
+            .get_terminator(allocs)
+            .get_jts(allocs)
+            .iter()
+            .filter_map(|jt| jt.get_block(allocs))
+            .collect();
+        for succ in succs {
+            for phi in block_phis(allocs, succ) {
+                phi.deref_ir(allocs).remove_incoming(allocs, block);
+            }
+        }
+    }
+    let blocks = func.blocks_unwrap(allocs);
+    for &block in &dead {
+        blocks
+            .node_unplug(block, &allocs.blocks)
+            .expect("Internal error: failed to unplug unreachable block");
+    }
+    for &block in &dead {
+        block
+            .dispose(allocs)
+            .expect("Internal error: failed to dispose unreachable block");
+    }
+    dead.len()
+}
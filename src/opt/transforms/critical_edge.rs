@@ -0,0 +1,147 @@
+//! CriticalEdgeSplit: 拆分所有关键边.
+//!
+//! 关键边是起点有多个后继、终点有多个前驱的边. 在这样的边上无法插入只在这条边上执行的代码:
+//! 放在起点末尾会影响其他后继, 放在终点开头会影响其他前驱. Phi 消除和 PRE 都需要先拆分关键边.
+//!
+//! 每条关键边上插入一个只包含 `jump` 的新块, 见 [`IRBuilder::split_edge`].
+
+use crate::{
+    SymbolStr,
+    ir::{BlockID, FuncID, IRAllocs, IRBuilder, JumpTargetID, Module},
+    opt::transforms::IFuncTransformPass,
+};
+use smallvec::SmallVec;
+
+pub struct CriticalEdgeSplit<'ir> {
+    pub module: &'ir Module,
+    pub num_split: usize,
+}
+
+impl<'ir> IFuncTransformPass for CriticalEdgeSplit<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("CriticalEdgeSplit")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        // 拆分一条边不会改变其他边的起点后继数和终点前驱数, 所以可以先收集再拆分
+        let edges: Vec<JumpTargetID> = func
+            .blocks_iter(allocs)
+            .flat_map(|(block, _)| critical_edges_of(allocs, block))
+            .collect();
+        let mut builder = IRBuilder::new(self.module);
+        for jt in edges {
+            builder
+                .split_edge(jt)
+                .expect("Internal error: failed to split critical edge");
+            self.num_split += 1;
+        }
+    }
+}
+
+impl<'ir> CriticalEdgeSplit<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, num_split: 0 }
+    }
+}
+
+/// `block` 出发的关键边.
+pub fn critical_edges_of(allocs: &IRAllocs, block: BlockID) -> SmallVec<[JumpTargetID; 4]> {
+    let succs = block.get_succs(allocs);
+    if succs.len() < 2 {
+        return SmallVec::new();
+    }
+    succs
+        .iter()
+        .copied()
+        .filter(|jt| {
+            jt.get_block(allocs)
+                .is_some_and(|to| to.get_preds(allocs).iter(&allocs.jts).nth(1).is_some())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::inst::SwitchInstID,
+        ir::{
+            IRWriteOption, ISubGlobalID, ISubInstID, ValueSSA,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        opt::{Mem2Reg, transforms::block_phis},
+        testing::cases::{test_case_cfg_deep_while_br, test_case_jump_thread},
+    };
+
+    #[test]
+    fn test_critical_edge_split() {
+        let module = test_case_cfg_deep_while_br().module;
+        let allocs = &module.allocs;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(&module).run_on_func(func);
+        let count_critical = || {
+            func.blocks_iter(allocs)
+                .map(|(block, _)| critical_edges_of(allocs, block).len())
+                .sum::<usize>()
+        };
+        let num_critical = count_critical();
+        assert!(num_critical > 0);
+
+        let mut pass = CriticalEdgeSplit::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-critical-edge.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_split, num_critical);
+        assert_eq!(count_critical(), 0);
+    }
+
+    #[test]
+    fn test_split_switch_edge() {
+        let module = test_case_jump_thread().module;
+        let allocs = &module.allocs;
+        let func = module
+            .get_global_by_name("dom_cond")
+            .map(FuncID::raw_from)
+            .expect("test case has no dom_cond function");
+        // dom_cond 的块依次是 entry, L, R, M, X, Y. 把 L 的 `jump M` 换成两条边都指向 M 的 switch
+        let blocks: Vec<BlockID> = func.blocks_iter(allocs).map(|(b, _)| b).collect();
+        let (left, merge) = (blocks[1], blocks[3]);
+        let switch =
+            SwitchInstID::from_cases(allocs, ValueSSA::FuncArg(func, 0), [(1, merge)], merge);
+        drop(left.set_terminator_inst(allocs, switch.raw_into()));
+        let phi = block_phis(allocs, merge)[0].deref_ir(allocs);
+        let left_value = phi.find_incoming_value(allocs, left).unwrap();
+
+        // 拆分 case 1 之后 L 仍然通过 default 跳到 M, 两个传入值都要保留
+        let mut builder = IRBuilder::new(&module);
+        let case_jt = switch.deref_ir(allocs).find_case_jt(allocs, 1).unwrap();
+        let case_bb = builder.split_edge(case_jt).unwrap();
+        assert_eq!(case_jt.get_block(allocs), Some(case_bb));
+        assert_eq!(phi.find_incoming_value(allocs, case_bb), Some(left_value));
+        assert_eq!(phi.find_incoming_value(allocs, left), Some(left_value));
+
+        // 拆分 default 之后 L 不再跳到 M
+        let default_jt = switch.deref_ir(allocs).default_jt();
+        let default_bb = builder.split_edge(default_jt).unwrap();
+        assert_eq!(
+            phi.find_incoming_value(allocs, default_bb),
+            Some(left_value)
+        );
+        assert_eq!(phi.find_incoming_value(allocs, left), None);
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    SymbolStr,
+    ir::{
+        AttrClass, BlockID, FuncArg, FuncID, IRBuilder, IRFocus, ISubGlobal, ISubGlobalID,
+        ISubInstID, ITraceableValue, Linkage, Module, TerminatorID, ValueSSA,
+        inst::{CallInst, CallInstID, RetInstID},
+    },
+    opt::transforms::{IModuleTransformPass, collect_direct_calls},
+    typing::{FuncTypeID, IValType, ValTypeID},
+};
+use smallvec::SmallVec;
+
+/// 死参数 / 死返回值消除.
+///
+/// 只处理满足以下条件的函数:
+///
+/// * 有函数体, 链接属性是 `Private` 或 `DSOLocal`;
+/// * 没有在 `SymbolPool` 中导出;
+/// * 不是变参函数;
+/// * 地址没有被获取 -- 所有使用者都是以该函数为 callee 的 `call` 指令.
+///
+/// 对于这样的函数, 没有使用者的参数会被删除; 如果所有调用点都不使用返回值,
+/// 返回类型会被改写为 `void`. 函数本身保留原来的 `FuncID`, 只是参数列表和
+/// 函数类型被重建; 所有调用点会被重新构建为新签名的 `call` 指令.
+pub struct DeadArgElim<'ir> {
+    pub module: &'ir mut Module,
+    pub num_dead_args: usize,
+    pub num_dead_rets: usize,
+}
+
+/// 函数属性集中描述返回值 (而不是函数本身) 的属性类别.
+const RET_ATTR_CLASSES: [AttrClass; 6] = [
+    AttrClass::NoUndef,
+    AttrClass::IntExt,
+    AttrClass::PtrReadOnly,
+    AttrClass::PtrNoCapture,
+    AttrClass::ArgPtrTarget,
+    AttrClass::ArgPtrDerefBytes,
+];
+
+struct DeadArgInfo {
+    func: FuncID,
+    calls: SmallVec<[CallInstID; 4]>,
+    arg_dead: SmallVec<[bool; 8]>,
+    ret_dead: bool,
+}
+
+impl<'ir> IModuleTransformPass for DeadArgElim<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("DeadArgElim")
+    }
+
+    fn run_on_module(&mut self) {
+        let mut funcs: Vec<FuncID> = self
+            .module
+            .symbols
+            .borrow()
+            .func_pool()
+            .iter()
+            .copied()
+            .collect();
+        funcs.sort_unstable();
+        for func in funcs {
+            let Some(info) = self.analyze_func(func) else {
+                continue;
+            };
+            self.rewrite_func(&info);
+        }
+    }
+}
+
+impl<'ir> DeadArgElim<'ir> {
+    pub fn new(module: &'ir mut Module) -> Self {
+        Self { module, num_dead_args: 0, num_dead_rets: 0 }
+    }
+
+    fn func_is_candidate(&self, func: FuncID) -> bool {
+        let module = &*self.module;
+        let allocs = &module.allocs;
+        if !func.is_alive(allocs) || func.get_body(allocs).is_none() {
+            return false;
+        }
+        if !matches!(
+            func.get_linkage(allocs),
+            Linkage::Private | Linkage::DSOLocal
+        ) {
+            return false;
+        }
+        if module.symbol_is_exported(func.raw_into()) {
+            return false;
+        }
+        !func.get_functype(allocs).is_vararg(&module.tctx)
+    }
+
+    fn analyze_func(&self, func: FuncID) -> Option<DeadArgInfo> {
+        if !self.func_is_candidate(func) {
+            return None;
+        }
+        let allocs = &self.module.allocs;
+        let calls = collect_direct_calls(allocs, func)?;
+        let arg_dead: SmallVec<[bool; 8]> = func
+            .args(allocs)
+            .iter()
+            .map(|arg| !arg.has_users(allocs))
+            .collect();
+        let ret_dead = func.deref_ir(allocs).ret_type != ValTypeID::Void
+            && calls
+                .iter()
+                .all(|call| !call.deref_ir(allocs).has_users(allocs));
+        if !ret_dead && !arg_dead.contains(&true) {
+            return None;
+        }
+        Some(DeadArgInfo { func, calls, arg_dead, ret_dead })
+    }
+
+    fn rewrite_func(&mut self, info: &DeadArgInfo) {
+        let &DeadArgInfo { func, ref calls, ref arg_dead, ret_dead } = info;
+        let new_functy = {
+            let module = &*self.module;
+            let allocs = &module.allocs;
+            let old_ret = func.deref_ir(allocs).ret_type;
+            let new_ret = if ret_dead { ValTypeID::Void } else { old_ret };
+            let new_args = func
+                .args(allocs)
+                .iter()
+                .zip(arg_dead.iter())
+                .filter_map(|(arg, &dead)| if dead { None } else { Some(arg.ty) });
+            FuncTypeID::new(&module.tctx, new_ret, false, new_args)
+        };
+
+        if ret_dead {
+            self.rewrite_returns(func);
+            self.num_dead_rets += 1;
+        }
+        for &call in calls {
+            self.rewrite_call(call, func, new_functy, arg_dead, ret_dead);
+        }
+        self.rebuild_signature(func, new_functy, arg_dead, ret_dead);
+    }
+
+    /// 把函数体中所有的 `ret <ty> <val>` 替换成 `ret void`.
+    fn rewrite_returns(&self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        let blocks: SmallVec<[BlockID; 16]> = func.blocks_iter(allocs).map(|(b, _)| b).collect();
+        for block in blocks {
+            let TerminatorID::Ret(_) = block.get_terminator(allocs) else {
+                continue;
+            };
+            let ret_void = RetInstID::with_retval(allocs, ValueSSA::None);
+            // 旧的 ret 指令由 ManagedInst 负责 dispose
+            let old = block.set_terminator_inst(allocs, ret_void.raw_into());
+            assert!(
+                old.is_some(),
+                "Internal error: block with ret terminator lost its terminator"
+            );
+        }
+    }
+
+    fn rewrite_call(
+        &self,
+        old_call: CallInstID,
+        func: FuncID,
+        new_functy: FuncTypeID,
+        arg_dead: &[bool],
+        ret_dead: bool,
+    ) {
+        let module = &*self.module;
+        let allocs = &module.allocs;
+        let old_inst = old_call.deref_ir(allocs);
+        let mut call_builder = CallInst::builder(&module.tctx, new_functy);
+        call_builder
+            .callee(ValueSSA::Global(func.raw_into()))
+            .is_tail_call(old_inst.is_tail_call.get());
+        if !ret_dead {
+            call_builder.ret_attrs(old_inst.ret_attrs().clone());
+        }
+        let alive_args = old_inst
+            .arg_uses()
+            .iter()
+            .enumerate()
+            .zip(arg_dead.iter())
+            .filter_map(|(arg, &dead)| if dead { None } else { Some(arg) });
+        for (new_index, (old_index, &u)) in alive_args.enumerate() {
+            call_builder
+                .set_arg(new_index, u.get_operand(allocs))
+                .set_arg_attrs(new_index, old_inst.arg_attrs(old_index).clone());
+        }
+        let new_call = call_builder.build_id(allocs);
+
+        let mut builder = IRBuilder::new(module);
+        builder.set_focus(IRFocus::Inst(old_call.raw_into()));
+        builder
+            .insert_inst(new_call)
+            .expect("Internal error: failed to insert rewritten call");
+        if !ret_dead {
+            old_call
+                .deref_ir(allocs)
+                .replace_self_with(allocs, ValueSSA::Inst(new_call.raw_into()))
+                .expect("Internal error: failed to replace old call users");
+        }
+        builder
+            .remove_inst(old_call)
+            .expect("Internal error: failed to remove old call");
+        old_call.dispose(allocs).unwrap();
+    }
+
+    /// 重建参数列表和函数类型. 保留下来的参数会被重新编号, 它们的使用者
+    /// 所引用的 `ValueSSA::FuncArg` 也会一并更新. 返回值被删除时,
+    /// 函数上描述返回值的属性 (`noundef`, `zeroext` 等) 也会被清除.
+    fn rebuild_signature(
+        &mut self,
+        func: FuncID,
+        new_functy: FuncTypeID,
+        arg_dead: &[bool],
+        ret_dead: bool,
+    ) {
+        let Module { allocs, tctx, .. } = &mut *self.module;
+        let old_args = std::mem::take(&mut func.deref_ir_mut(allocs).args);
+
+        let mut new_args: Vec<FuncArg> = Vec::with_capacity(old_args.len());
+        for (mut arg, &dead) in old_args.into_iter().zip(arg_dead.iter()) {
+            if dead {
+                arg.users
+                    .sentinel
+                    .dispose(allocs)
+                    .expect("Internal error: failed to dispose dead argument");
+                self.num_dead_args += 1;
+                continue;
+            }
+            let new_index = new_args.len() as u32;
+            if arg.index != new_index {
+                let new_value = ValueSSA::FuncArg(func, new_index);
+                for (_, u) in arg.user_iter(allocs) {
+                    u.operand.set(new_value);
+                }
+            }
+            arg.index = new_index;
+            new_args.push(arg);
+        }
+
+        let new_ret = new_functy.get_ret_type(tctx);
+        let f = func.deref_ir_mut(allocs);
+        f.args = new_args.into_boxed_slice();
+        f.ret_type = new_ret;
+        f.common_mut().content_ty = new_functy.into_ir();
+        if ret_dead {
+            let mut attrs = f.attrs.borrow_mut();
+            for class in RET_ATTR_CLASSES {
+                attrs.clean_attr(class);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::APInt,
+        ir::{checking::assert_module_sane, inst::*, *},
+        typing::ArchInfo,
+    };
+
+    /// ```remusys-ir
+    /// define internal noundef i32 @helper(i32 %0, i32 %1, i32 %2) {
+    ///     %4 = add i32 %0, %2
+    ///     ret i32 %4
+    /// }
+    ///
+    /// define dso_local i32 @main() {
+    ///     call noundef i32 @helper(i32 1, i32 noundef 2, i32 noundef 3)
+    ///     ret i32 0
+    /// }
+    /// ```
+    fn build_module() -> (Module, FuncID, FuncID) {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "dead_arg_elim");
+        let i32ty = ValTypeID::Int(32);
+        let tctx = builder.tctx();
+        let helper_ty = FuncTypeID::new(tctx, i32ty, false, [i32ty; 3]);
+        let main_ty = FuncTypeID::new(tctx, i32ty, false, []);
+
+        let helper = FuncID::builder(tctx, "helper", helper_ty)
+            .make_private()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_pinned(&builder.module);
+        let main = FuncID::builder(tctx, "main", main_ty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+
+        helper
+            .deref_ir(builder.allocs())
+            .attrs_mut()
+            .set_noundef(true);
+        let helper_entry = helper.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(helper_entry));
+        let add = builder
+            .build_inst(|allocs, _| {
+                BinOPInstID::new(
+                    allocs,
+                    Opcode::Add,
+                    ValueSSA::FuncArg(helper, 0),
+                    ValueSSA::FuncArg(helper, 2),
+                )
+            })
+            .unwrap();
+        let TerminatorID::Ret(ret) = helper_entry.get_terminator(builder.allocs()) else {
+            panic!("helper entry should end with ret");
+        };
+        ret.set_retval(builder.allocs(), ValueSSA::Inst(add.raw_into()));
+
+        let main_entry = main.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(main_entry));
+        let mut noundef = AttrSet::new(AttributePos::CALLARG);
+        noundef.set_noundef(true);
+        builder
+            .build_inst(|allocs, tctx| {
+                CallInst::builder(tctx, helper_ty)
+                    .callee(ValueSSA::Global(helper.raw_into()))
+                    .with_args(&[
+                        ValueSSA::from(APInt::new(1u32, 32)),
+                        ValueSSA::from(APInt::new(2u32, 32)),
+                        ValueSSA::from(APInt::new(3u32, 32)),
+                    ])
+                    .ret_attrs(noundef.clone())
+                    .set_arg_attrs(1, noundef.clone())
+                    .set_arg_attrs(2, noundef.clone())
+                    .build_id(allocs)
+            })
+            .unwrap();
+        (builder.take(), helper, main)
+    }
+
+    #[test]
+    fn test_dead_arg_elim() {
+        let (mut module, helper, main) = build_module();
+        let old_main_ty = main.get_functype(&module.allocs);
+        let mut pass = DeadArgElim::new(&mut module);
+        pass.run_on_module();
+        assert_eq!(pass.num_dead_args, 1);
+        assert_eq!(pass.num_dead_rets, 1);
+
+        let allocs = &module.allocs;
+        let tctx = &module.tctx;
+        let helper_ty = helper.get_functype(allocs);
+        assert_eq!(helper_ty.get_ret_type(tctx), ValTypeID::Void);
+        assert!(!helper.deref_ir(allocs).attrs().is_noundef());
+        assert_eq!(helper_ty.get_nargs(tctx), 2);
+        assert_eq!(helper.args(allocs).len(), 2);
+        for (i, arg) in helper.args(allocs).iter().enumerate() {
+            assert_eq!(arg.index as usize, i);
+            for (_, u) in arg.user_iter(allocs) {
+                assert_eq!(u.operand.get(), ValueSSA::FuncArg(helper, i as u32));
+            }
+        }
+        // main 被导出, 不应被改写
+        assert_eq!(main.get_functype(allocs), old_main_ty);
+
+        let (_, call) = main
+            .get_entry(allocs)
+            .unwrap()
+            .insts_iter(allocs)
+            .find(|(_, inst)| matches!(inst, InstObj::Call(_)))
+            .expect("main should still call helper");
+        let InstObj::Call(call) = call else { unreachable!() };
+        assert_eq!(call.callee_ty, helper_ty);
+        assert_eq!(call.arg_uses().len(), 2);
+        assert_eq!(
+            call.get_arg(allocs, 1),
+            ValueSSA::from(APInt::new(3u32, 32))
+        );
+        // 实参 `i32 noundef 2` 被删除, 保留下来的实参属性应跟着移动
+        assert!(!call.arg_attrs(0).is_noundef());
+        assert!(call.arg_attrs(1).is_noundef());
+        assert_module_sane(&module);
+        write_ir_to_file(
+            "../target/test-dead-arg-elim.ll",
+            &module,
+            IRWriteOption::quiet(),
+        );
+    }
+}
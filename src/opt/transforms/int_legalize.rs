@@ -0,0 +1,1442 @@
+//! IntLegalize: 把硬件不能直接运算的整数类型改写成合法位宽上的运算.
+//!
+//! 合法位宽由 [`ArchInfo::reg_nbits`] 决定, 见 [`legal_int_widths`]. `i1` 只用作比较结果和分支条件, 不参与改写.
+//!
+//! * 提升: 窄于寄存器位宽的非法整数 (如 `i7`、`i16`、`i33`) 放到下一个合法位宽上运算; 介于寄存器位宽和
+//!   两倍寄存器位宽之间的整数 (如 `i100`) 提升到两倍寄存器位宽, 再交给下面的展开.
+//!   二元运算、比较、类型转换、Phi 和 `select` 都换成宽类型上的指令, 提升后的值只保证低位和原值相同.
+//!   操作数按运算的需要扩展: 加减乘、按位运算和左移的被移位数只用到低位, 不必扩展;
+//!   逻辑右移、无符号除法和无符号比较需要零扩展; 算术右移、有符号除法和有符号比较需要符号扩展.
+//!   已经提升过的值零扩展写成按掩码相与, 符号扩展写成先左移再算术右移.
+//!   读写内存的宽度由类型的字节数决定: 字节数正好是提升后位宽的 (如 `i25`) 直接按提升后的类型读写,
+//!   写之前清掉高位; 其余的保持原来的宽度, 读出来的值在使用处扩展, 写入的值从宽值截断.
+//!   函数参数、返回值、调用等提升不了的地方也一样在边界上扩展或截断.
+//! * 展开: 两倍寄存器位宽的整数 (AArch64 上的 `i128`) 拆成低半和高半两个寄存器位宽的值. 加减用进位和借位串接,
+//!   乘法用半宽乘积拼出高半部分, 移位用 `select` 挑出跨越两半的结果, 比较先比高半再比低半.
+//!   读写内存、Phi、`select` 和整数扩展、截断也各自拆成两半.
+//!
+//! 展开以值的连通分量为单位: 通过运算连在一起的一组宽整数里只要有一个拆不开 (函数参数、调用、除法等),
+//! 整组都保持原样, 留给指令选择报错.
+
+use crate::{
+    SymbolStr,
+    base::APInt,
+    ir::{
+        BlockID, CmpCond, ConstData, FuncID, IRAllocs, IRBuilder, IRFocus, ISubInst, ISubInstID,
+        ISubValueSSA, ITraceableValue, IUser, InstID, InstObj, Module, Opcode, ValueSSA,
+        inst::{
+            BinOPInstID, CastInstID, CmpInstID, GEPInstID, LoadInstID, PhiInstID, SelectInstID,
+            StoreInstID,
+        },
+    },
+    opt::transforms::IFuncTransformPass,
+    typing::{ArchInfo, ValTypeID},
+};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+
+/// 目标能直接运算的整数位宽, 从小到大: 从 32 (寄存器更窄时就是寄存器位宽) 到 `reg_nbits` 的 2 的幂.
+pub fn legal_int_widths(arch: &ArchInfo) -> SmallVec<[u8; 2]> {
+    let reg_nbits = arch.reg_nbits.min(64);
+    let mut widths = SmallVec::new();
+    let mut bits = reg_nbits.min(32);
+    while bits <= reg_nbits {
+        widths.push(bits as u8);
+        bits *= 2;
+    }
+    widths
+}
+
+/// `bits` 位的整数需要提升时, 返回提升后的位宽: 比它宽的最小合法位宽. 比寄存器宽、但不到两倍寄存器位宽的
+/// 整数提升到两倍寄存器位宽, 之后再拆成两半.
+fn promoted_width(legal_widths: &[u8], bits: u8) -> Option<u8> {
+    if bits <= 1 || legal_widths.contains(&bits) {
+        return None;
+    }
+    let reg_nbits = *legal_widths.last()?;
+    match legal_widths.iter().copied().find(|&w| w > bits) {
+        Some(width) => Some(width),
+        None if bits < reg_nbits * 2 => Some(reg_nbits * 2),
+        None => None,
+    }
+}
+
+pub struct IntLegalize<'ir> {
+    pub module: &'ir Module,
+    /// 合法位宽, 从小到大. 最后一个是寄存器位宽.
+    pub legal_widths: SmallVec<[u8; 2]>,
+    pub num_promoted: usize,
+    pub num_expanded: usize,
+    /// 因为所在的连通分量拆不开而保持原样的宽整数指令数.
+    pub num_unexpanded: usize,
+}
+
+impl<'ir> IFuncTransformPass for IntLegalize<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("IntLegalize")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        if func.get_entry(&self.module.allocs).is_none() {
+            return;
+        }
+        self.promote_func(func);
+        if self.reg_nbits() * 2 <= 128 {
+            self.expand_func(func);
+        }
+    }
+}
+
+impl<'ir> IntLegalize<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self {
+            module,
+            legal_widths: legal_int_widths(&module.tctx.arch),
+            num_promoted: 0,
+            num_expanded: 0,
+            num_unexpanded: 0,
+        }
+    }
+
+    fn reg_nbits(&self) -> u8 {
+        *self.legal_widths.last().expect("no legal integer width")
+    }
+
+    /// `bits` 位的整数需要提升时, 返回提升后的位宽.
+    pub fn promoted_width(&self, bits: u8) -> Option<u8> {
+        promoted_width(&self.legal_widths, bits)
+    }
+
+    fn promote_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        let mut promoter = Promoter {
+            module: self.module,
+            legal_widths: self.legal_widths.clone(),
+            wides: HashMap::new(),
+        };
+        // 1. 结果是非法整数的指令提升成宽值; 结果合法、只有操作数非法的指令直接改写
+        let mut defs = Vec::new();
+        let mut users = Vec::new();
+        let is_narrow = |ty: ValTypeID| promoter.narrow(ty).is_some();
+        for (block, _) in func.blocks_iter(allocs) {
+            for (inst, obj) in block.insts_iter(allocs) {
+                if !promoter.can_promote(obj) {
+                    continue;
+                }
+                if is_narrow(obj.get_valtype()) {
+                    defs.push(inst);
+                } else if obj
+                    .operands_iter()
+                    .any(|u| is_narrow(u.get_operand(allocs).get_valtype(allocs)))
+                {
+                    users.push(inst);
+                }
+            }
+        }
+
+        // 2. Phi 先建好, 传入值最后再填, 这样循环里的值也能按需提升
+        let phis: Vec<InstID> = defs
+            .iter()
+            .copied()
+            .filter(|inst| matches!(inst.deref_ir(allocs), InstObj::Phi(_)))
+            .collect();
+        for &phi in &phis {
+            promoter.create_phi(phi);
+        }
+        for &inst in &defs {
+            promoter.wide_inst(inst);
+        }
+        for &inst in &users {
+            match promoter.promote_user(inst) {
+                Some(new_value) => self.replace_inst(inst, new_value),
+                None => self.remove_insts(&[inst]),
+            }
+        }
+        for &phi in &phis {
+            promoter.fill_phi(phi);
+        }
+
+        // 3. 提升不了的使用者 (返回值、调用参数等) 改用宽值的截断, 然后删掉原来的窄整数指令
+        let mut truncs = Vec::with_capacity(defs.len());
+        for &inst in &defs {
+            let obj = inst.deref_ir(allocs);
+            let pos = match obj {
+                InstObj::Phi(_) => first_non_phi(allocs, inst.get_parent(allocs).unwrap()),
+                _ => inst,
+            };
+            let emit = Emitter { module: self.module, pos };
+            let trunc = emit.cast(Opcode::Trunc, promoter.wides[&inst], obj.get_valtype());
+            obj.replace_self_with(allocs, trunc)
+                .expect("Internal error: failed to replace promoted instruction");
+            truncs.push(trunc);
+        }
+        self.remove_insts(&defs);
+        self.num_promoted += defs.len() + users.len();
+        // 截断的结果大多没有使用者
+        for trunc in truncs {
+            let ValueSSA::Inst(trunc) = trunc else {
+                continue;
+            };
+            if trunc.deref_ir(allocs).user_iter(allocs).next().is_none() {
+                self.remove_insts(&[trunc]);
+            }
+        }
+    }
+
+    fn expand_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        let half = self.reg_nbits();
+        let wide_ty = ValTypeID::Int(half * 2);
+
+        // 1. 按数据流把宽整数指令合并成连通分量, 标记拆不开的分量
+        let mut comps = WideComponents::default();
+        // 结果不是宽整数的使用者, 以及它所连接的一条宽整数指令
+        let mut users: Vec<(InstID, Option<InstID>)> = Vec::new();
+        for (block, _) in func.blocks_iter(allocs) {
+            for (inst, obj) in block.insts_iter(allocs) {
+                let is_wide = obj.get_valtype() == wide_ty;
+                let wide_ops: SmallVec<[ValueSSA; 4]> = obj
+                    .operands_iter()
+                    .map(|u| u.get_operand(allocs))
+                    .filter(|v| v.get_valtype(allocs) == wide_ty)
+                    .collect();
+                if !is_wide && wide_ops.is_empty() {
+                    continue;
+                }
+                let members: SmallVec<[InstID; 4]> = wide_ops
+                    .iter()
+                    .filter_map(|v| match v {
+                        ValueSSA::Inst(inst) => Some(*inst),
+                        _ => None,
+                    })
+                    .chain(is_wide.then_some(inst))
+                    .collect();
+                let splittable = wide_ops.iter().all(|v| {
+                    matches!(
+                        v,
+                        ValueSSA::Inst(_)
+                            | ValueSSA::ConstData(
+                                ConstData::Int(_) | ConstData::Zero(_) | ConstData::Undef(_)
+                            )
+                    )
+                });
+                let ok = splittable && can_expand(obj, is_wide, half);
+                comps.union_all(&members, ok);
+                if !is_wide && ok {
+                    users.push((inst, members.first().copied()));
+                }
+            }
+        }
+
+        // 2. 展开能拆开的分量. Phi 先建好, 传入值最后再填, 这样循环里的值也能按需展开
+        let mut expander = Expander { module: self.module, half, pairs: HashMap::new() };
+        let mut wide_insts = Vec::new();
+        let mut phis = Vec::new();
+        for inst in comps.members() {
+            if !comps.is_ok(inst) {
+                self.num_unexpanded += 1;
+                continue;
+            }
+            wide_insts.push(inst);
+            if let InstObj::Phi(_) = inst.deref_ir(allocs) {
+                phis.push(inst);
+                expander.create_phis(inst);
+            }
+        }
+        for &inst in &wide_insts {
+            expander.pair_of(ValueSSA::Inst(inst));
+        }
+        for &(inst, member) in &users {
+            if member.is_some_and(|member| !comps.is_ok(member)) {
+                continue;
+            }
+            match expander.expand_user(inst) {
+                Some(new_value) => self.replace_inst(inst, new_value),
+                None => self.remove_insts(&[inst]),
+            }
+        }
+        for &phi in &phis {
+            expander.fill_phis(phi);
+        }
+        // 3. 原来的宽整数指令之间可能互相引用, 全部卸下以后再释放
+        self.remove_insts(&wide_insts);
+        self.num_expanded += wide_insts.len();
+    }
+
+    /// 把 `inst` 的使用者都换成 `new_value`, 然后删除 `inst`.
+    fn replace_inst(&self, inst: InstID, new_value: ValueSSA) {
+        let allocs = &self.module.allocs;
+        inst.deref_ir(allocs)
+            .replace_self_with(allocs, new_value)
+            .expect("Internal error: failed to replace legalized instruction");
+        self.remove_insts(&[inst]);
+    }
+    fn remove_insts(&self, insts: &[InstID]) {
+        let allocs = &self.module.allocs;
+        let mut builder = IRBuilder::new(self.module);
+        for &inst in insts {
+            builder
+                .remove_inst(inst)
+                .expect("Internal error: failed to remove legalized instruction");
+        }
+        for &inst in insts {
+            inst.dispose(allocs).unwrap();
+        }
+    }
+}
+
+/// 操作数提升到宽类型的方式.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Widen {
+    /// 运算只用到低位, 高位是什么都可以.
+    Low,
+    Zext,
+    Sext,
+}
+
+impl Widen {
+    /// 类型转换的源操作数的提升方式.
+    fn of_cast(opcode: Opcode) -> Self {
+        match opcode {
+            Opcode::Zext | Opcode::Uitofp | Opcode::IntToPtr => Widen::Zext,
+            Opcode::Sext | Opcode::Sitofp => Widen::Sext,
+            _ => Widen::Low,
+        }
+    }
+}
+
+/// 二元整数运算左右操作数各自的提升方式.
+fn widen_kinds(opcode: Opcode) -> Option<(Widen, Widen)> {
+    use Widen::*;
+    let kinds = match opcode {
+        Opcode::Add | Opcode::Sub | Opcode::Mul => (Low, Low),
+        Opcode::BitAnd | Opcode::BitOr | Opcode::BitXor => (Low, Low),
+        Opcode::Shl => (Low, Zext),
+        Opcode::Lshr | Opcode::Udiv | Opcode::Urem => (Zext, Zext),
+        Opcode::Ashr => (Sext, Zext),
+        Opcode::Sdiv | Opcode::Srem => (Sext, Sext),
+        _ => return None,
+    };
+    Some(kinds)
+}
+
+/// 涉及宽整数的指令 `obj` 能否拆成两半. `is_wide` 表示 `obj` 本身的结果是宽整数.
+fn can_expand(obj: &InstObj, is_wide: bool, half: u8) -> bool {
+    let int_bits = |ty: ValTypeID| match ty {
+        ValTypeID::Int(bits) => bits,
+        _ => u8::MAX,
+    };
+    match obj {
+        InstObj::BinOP(bin) => {
+            is_wide
+                && matches!(
+                    bin.get_opcode(),
+                    Opcode::Add
+                        | Opcode::Sub
+                        | Opcode::Mul
+                        | Opcode::BitAnd
+                        | Opcode::BitOr
+                        | Opcode::BitXor
+                        | Opcode::Shl
+                        | Opcode::Lshr
+                        | Opcode::Ashr
+                )
+        }
+        InstObj::Cast(cast) => match cast.get_opcode() {
+            Opcode::Zext | Opcode::Sext => is_wide && int_bits(cast.from_ty) <= half,
+            Opcode::Trunc => !is_wide && int_bits(cast.get_valtype()) <= half,
+            _ => false,
+        },
+        InstObj::Cmp(cmp) => cmp.get_opcode() == Opcode::Icmp,
+        InstObj::Load(_) | InstObj::Phi(_) | InstObj::Select(_) => is_wide,
+        InstObj::Store(_) => true,
+        _ => false,
+    }
+}
+
+/// 宽整数指令的并查集. 一个分量只要有一条指令拆不开就整体不展开.
+#[derive(Default)]
+struct WideComponents {
+    index: HashMap<InstID, usize>,
+    order: Vec<InstID>,
+    parent: Vec<usize>,
+    ok: Vec<bool>,
+}
+
+impl WideComponents {
+    fn find(&mut self, inst: InstID) -> usize {
+        let mut x = match self.index.get(&inst) {
+            Some(&x) => x,
+            None => {
+                let x = self.order.len();
+                self.index.insert(inst, x);
+                self.order.push(inst);
+                self.parent.push(x);
+                self.ok.push(true);
+                x
+            }
+        };
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+    fn union_all(&mut self, insts: &[InstID], ok: bool) {
+        let Some((&first, rest)) = insts.split_first() else {
+            return;
+        };
+        let root = self.find(first);
+        for &inst in rest {
+            let other = self.find(inst);
+            if other != root {
+                self.parent[other] = root;
+                self.ok[root] &= self.ok[other];
+            }
+        }
+        self.ok[root] &= ok;
+    }
+    fn is_ok(&mut self, inst: InstID) -> bool {
+        let root = self.find(inst);
+        self.ok[root]
+    }
+    fn members(&self) -> Vec<InstID> {
+        self.order.clone()
+    }
+}
+
+/// 块中第一条不是 Phi 的指令.
+fn first_non_phi(allocs: &IRAllocs, block: BlockID) -> InstID {
+    block
+        .insts_iter(allocs)
+        .find(|(_, obj)| !matches!(obj, InstObj::Phi(_) | InstObj::PhiInstEnd(_)))
+        .map(|(inst, _)| inst)
+        .expect("Internal error: block has no terminator")
+}
+
+/// 把非法整数值提升到宽类型. 提升后的值只有低位有意义, 高位由使用者按需要清掉或者填上符号位.
+/// 提升的指令插在原指令之前.
+struct Promoter<'ir> {
+    module: &'ir Module,
+    legal_widths: SmallVec<[u8; 2]>,
+    wides: HashMap<InstID, ValueSSA>,
+}
+
+impl<'ir> Promoter<'ir> {
+    /// `ty` 是需要提升的整数时, 返回原位宽和提升后的位宽.
+    fn narrow(&self, ty: ValTypeID) -> Option<(u8, u8)> {
+        let ValTypeID::Int(bits) = ty else {
+            return None;
+        };
+        promoted_width(&self.legal_widths, bits).map(|width| (bits, width))
+    }
+    /// 按提升后的类型读写 `ty` 时访问的字节数不变.
+    fn fills_memory(&self, ty: ValTypeID) -> bool {
+        self.narrow(ty)
+            .is_some_and(|(bits, width)| bits.div_ceil(8) * 8 == width)
+    }
+    /// 涉及非法整数的指令 `obj` 能否换成宽类型上的指令.
+    fn can_promote(&self, obj: &InstObj) -> bool {
+        match obj {
+            InstObj::BinOP(bin) => widen_kinds(bin.get_opcode()).is_some(),
+            InstObj::Cmp(cmp) => cmp.get_opcode() == Opcode::Icmp,
+            InstObj::Cast(cast) => cast.get_opcode() != Opcode::Bitcast,
+            InstObj::Phi(_) | InstObj::Select(_) => true,
+            InstObj::Load(load) => self.fills_memory(load.get_valtype()),
+            InstObj::Store(store) => self.fills_memory(store.source_ty),
+            _ => false,
+        }
+    }
+
+    fn create_phi(&mut self, phi: InstID) {
+        let allocs = &self.module.allocs;
+        let (_, width) = self.narrow(phi.deref_ir(allocs).get_valtype()).unwrap();
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(IRFocus::Block(phi.get_parent(allocs).unwrap()));
+        let new_phi = PhiInstID::from_incomings(allocs, ValTypeID::Int(width), []);
+        builder
+            .insert_inst(new_phi)
+            .expect("Internal error: failed to insert promoted phi");
+        self.wides.insert(phi, ValueSSA::Inst(new_phi.raw_into()));
+    }
+    fn fill_phi(&mut self, phi: InstID) {
+        let allocs = &self.module.allocs;
+        let InstObj::Phi(obj) = phi.deref_ir(allocs) else {
+            unreachable!();
+        };
+        let incomings: Vec<(BlockID, ValueSSA)> = obj
+            .incoming_uses()
+            .iter()
+            .map(|[value, block]| {
+                let ValueSSA::Block(block) = block.get_operand(allocs) else {
+                    unreachable!("phi incoming block is not a block");
+                };
+                (block, value.get_operand(allocs))
+            })
+            .collect();
+        let ValueSSA::Inst(new_phi) = self.wides[&phi] else {
+            unreachable!();
+        };
+        for (block, value) in incomings {
+            // 需要扩展的传入值在前驱块末尾扩展
+            let emit = Emitter { module: self.module, pos: block.get_terminator_inst(allocs) };
+            let wide = self.widen(&emit, value, Widen::Low);
+            PhiInstID::raw_from(new_phi)
+                .deref_ir(allocs)
+                .set_incoming(allocs, block, wide);
+        }
+    }
+
+    /// 提升后的 `inst`. 提升不了的指令 (调用、按原宽度读内存等) 返回 `None`.
+    fn wide_inst(&mut self, inst: InstID) -> Option<ValueSSA> {
+        if let Some(&wide) = self.wides.get(&inst) {
+            return Some(wide);
+        }
+        if !self.can_promote(inst.deref_ir(&self.module.allocs)) {
+            return None;
+        }
+        let wide = self.promote_def(inst);
+        self.wides.insert(inst, wide);
+        Some(wide)
+    }
+
+    /// 把非法整数 `value` 按 `kind` 提升, 需要的指令插在 `emit` 的位置.
+    fn widen(&mut self, emit: &Emitter, value: ValueSSA, kind: Widen) -> ValueSSA {
+        let allocs = &self.module.allocs;
+        let (bits, width) = self.narrow(value.get_valtype(allocs)).unwrap();
+        let ty = ValTypeID::Int(width);
+        let wide = match value {
+            ValueSSA::ConstData(ConstData::Int(c)) => {
+                let c = if kind == Widen::Sext { c.sext_to(width) } else { c.zext_to(width) };
+                return c.into();
+            }
+            ValueSSA::ConstData(ConstData::Zero(_)) => return emit.konst(0, width),
+            ValueSSA::ConstData(ConstData::Undef(_)) => {
+                return ValueSSA::ConstData(ConstData::Undef(ty));
+            }
+            ValueSSA::Inst(inst) => self.wide_inst(inst),
+            _ => None,
+        };
+        // 函数参数、调用结果这样提升不了的值直接扩展
+        let Some(wide) = wide else {
+            let opcode = if kind == Widen::Sext { Opcode::Sext } else { Opcode::Zext };
+            return emit.cast(opcode, value, ty);
+        };
+        match kind {
+            Widen::Low => wide,
+            Widen::Zext => {
+                let mask = APInt::new(u128::MAX >> (128 - bits as u32), width);
+                emit.binop(Opcode::BitAnd, wide, mask.into())
+            }
+            Widen::Sext => {
+                let shift = emit.konst((width - bits) as u64, width);
+                let shl = emit.binop(Opcode::Shl, wide, shift);
+                emit.binop(Opcode::Ashr, shl, shift)
+            }
+        }
+    }
+
+    /// 结果是非法整数的指令换成宽类型上的指令, 返回宽值.
+    fn promote_def(&mut self, inst: InstID) -> ValueSSA {
+        let allocs = &self.module.allocs;
+        let emit = Emitter { module: self.module, pos: inst };
+        let obj = inst.deref_ir(allocs);
+        let (_, width) = self.narrow(obj.get_valtype()).unwrap();
+        let ty = ValTypeID::Int(width);
+        match obj {
+            InstObj::BinOP(bin) => {
+                let opcode = bin.get_opcode();
+                let (lk, rk) = widen_kinds(opcode).unwrap();
+                let lhs = self.widen(&emit, bin.get_lhs(allocs), lk);
+                let rhs = self.widen(&emit, bin.get_rhs(allocs), rk);
+                emit.binop(opcode, lhs, rhs)
+            }
+            InstObj::Cast(cast) => {
+                let opcode = cast.get_opcode();
+                let from = cast.get_from(allocs);
+                let from = match opcode {
+                    Opcode::Zext | Opcode::Sext | Opcode::Trunc
+                        if self.narrow(cast.from_ty).is_some() =>
+                    {
+                        self.widen(&emit, from, Widen::of_cast(opcode))
+                    }
+                    _ => from,
+                };
+                emit.resize(opcode, from, ty)
+            }
+            InstObj::Select(select) => {
+                let cond = select.get_cond(allocs);
+                let then_val = self.widen(&emit, select.get_then(allocs), Widen::Low);
+                let else_val = self.widen(&emit, select.get_else(allocs), Widen::Low);
+                emit.select(cond, then_val, else_val)
+            }
+            InstObj::Load(load) => {
+                let new_load = LoadInstID::new_uninit(allocs, ty, load.align_log2);
+                new_load.set_source(allocs, load.get_source(allocs));
+                emit.insert(new_load)
+            }
+            obj => unreachable!("cannot promote {:?}", obj.get_opcode()),
+        }
+    }
+
+    /// 改写结果合法、操作数里有非法整数的指令. `store` 没有结果, 返回 `None`.
+    fn promote_user(&mut self, inst: InstID) -> Option<ValueSSA> {
+        let allocs = &self.module.allocs;
+        let emit = Emitter { module: self.module, pos: inst };
+        match inst.deref_ir(allocs) {
+            InstObj::Cmp(cmp) => {
+                let kind = if cmp.cond.is_signed_ordered() { Widen::Sext } else { Widen::Zext };
+                let lhs = self.widen(&emit, cmp.get_lhs(allocs), kind);
+                let rhs = self.widen(&emit, cmp.get_rhs(allocs), kind);
+                Some(emit.icmp(cmp.cond, lhs, rhs))
+            }
+            InstObj::Cast(cast) => {
+                let opcode = cast.get_opcode();
+                let from = self.widen(&emit, cast.get_from(allocs), Widen::of_cast(opcode));
+                Some(emit.resize(opcode, from, cast.get_valtype()))
+            }
+            InstObj::Store(store) => {
+                let value = self.widen(&emit, store.get_source(allocs), Widen::Zext);
+                let ptr = store.get_target(allocs);
+                emit.insert(StoreInstID::new(allocs, value, ptr, store.align_log2));
+                None
+            }
+            obj => unreachable!("cannot promote {:?}", obj.get_opcode()),
+        }
+    }
+}
+
+/// 把宽整数值拆成 `(低半, 高半)`. 展开的指令插在原指令之前.
+struct Expander<'ir> {
+    module: &'ir Module,
+    half: u8,
+    pairs: HashMap<InstID, (ValueSSA, ValueSSA)>,
+}
+
+impl<'ir> Expander<'ir> {
+    fn half_ty(&self) -> ValTypeID {
+        ValTypeID::Int(self.half)
+    }
+
+    fn create_phis(&mut self, phi: InstID) {
+        let allocs = &self.module.allocs;
+        let block = phi.get_parent(allocs).unwrap();
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(IRFocus::Block(block));
+        let [lo, hi] = [(); 2].map(|_| {
+            let new_phi = PhiInstID::from_incomings(allocs, self.half_ty(), []);
+            builder
+                .insert_inst(new_phi)
+                .expect("Internal error: failed to insert expanded phi");
+            ValueSSA::Inst(new_phi.raw_into())
+        });
+        self.pairs.insert(phi, (lo, hi));
+    }
+    fn fill_phis(&mut self, phi: InstID) {
+        let allocs = &self.module.allocs;
+        let InstObj::Phi(obj) = phi.deref_ir(allocs) else {
+            unreachable!();
+        };
+        let incomings: Vec<(BlockID, ValueSSA)> = obj
+            .incoming_uses()
+            .iter()
+            .map(|[value, block]| {
+                let ValueSSA::Block(block) = block.get_operand(allocs) else {
+                    unreachable!("phi incoming block is not a block");
+                };
+                (block, value.get_operand(allocs))
+            })
+            .collect();
+        let (ValueSSA::Inst(lo), ValueSSA::Inst(hi)) = self.pairs[&phi] else {
+            unreachable!();
+        };
+        for (block, value) in incomings {
+            let (vlo, vhi) = self.pair_of(value);
+            PhiInstID::raw_from(lo)
+                .deref_ir(allocs)
+                .set_incoming(allocs, block, vlo);
+            PhiInstID::raw_from(hi)
+                .deref_ir(allocs)
+                .set_incoming(allocs, block, vhi);
+        }
+    }
+
+    fn pair_of(&mut self, value: ValueSSA) -> (ValueSSA, ValueSSA) {
+        let half = self.half;
+        match value {
+            ValueSSA::ConstData(ConstData::Int(c)) => {
+                let bits = c.as_unsigned();
+                (
+                    APInt::new(bits, half).into(),
+                    APInt::new(bits >> half, half).into(),
+                )
+            }
+            ValueSSA::ConstData(ConstData::Undef(_)) => {
+                let undef = ValueSSA::ConstData(ConstData::Undef(self.half_ty()));
+                (undef, undef)
+            }
+            ValueSSA::ConstData(ConstData::Zero(_)) => {
+                let zero: ValueSSA = APInt::new(0u64, half).into();
+                (zero, zero)
+            }
+            ValueSSA::Inst(inst) => {
+                if let Some(&pair) = self.pairs.get(&inst) {
+                    return pair;
+                }
+                let pair = self.expand_def(inst);
+                self.pairs.insert(inst, pair);
+                pair
+            }
+            _ => unreachable!("unsplittable wide value {value:?}"),
+        }
+    }
+
+    fn expand_def(&mut self, inst: InstID) -> (ValueSSA, ValueSSA) {
+        let allocs = &self.module.allocs;
+        let half = self.half;
+        let emit = Emitter { module: self.module, pos: inst };
+        match inst.deref_ir(allocs) {
+            InstObj::BinOP(bin) => {
+                let (al, ah) = self.pair_of(bin.get_lhs(allocs));
+                let (bl, bh) = self.pair_of(bin.get_rhs(allocs));
+                match bin.get_opcode() {
+                    Opcode::Add => {
+                        let lo = emit.binop(Opcode::Add, al, bl);
+                        let carry = emit.icmp(CmpCond::LT, lo, al);
+                        let carry = emit.cast(Opcode::Zext, carry, self.half_ty());
+                        let hi = emit.binop(Opcode::Add, ah, bh);
+                        (lo, emit.binop(Opcode::Add, hi, carry))
+                    }
+                    Opcode::Sub => {
+                        let lo = emit.binop(Opcode::Sub, al, bl);
+                        let borrow = emit.icmp(CmpCond::LT, al, bl);
+                        let borrow = emit.cast(Opcode::Zext, borrow, self.half_ty());
+                        let hi = emit.binop(Opcode::Sub, ah, bh);
+                        (lo, emit.binop(Opcode::Sub, hi, borrow))
+                    }
+                    op @ (Opcode::BitAnd | Opcode::BitOr | Opcode::BitXor) => {
+                        (emit.binop(op, al, bl), emit.binop(op, ah, bh))
+                    }
+                    Opcode::Mul => {
+                        let lo = emit.binop(Opcode::Mul, al, bl);
+                        let hi = emit.umulh(al, bl);
+                        let cross = emit.binop(Opcode::Mul, al, bh);
+                        let hi = emit.binop(Opcode::Add, hi, cross);
+                        let cross = emit.binop(Opcode::Mul, ah, bl);
+                        (lo, emit.binop(Opcode::Add, hi, cross))
+                    }
+                    op => emit.shift(op, (al, ah), bl),
+                }
+            }
+            InstObj::Cast(cast) => {
+                let from = cast.get_from(allocs);
+                let lo = match cast.from_ty {
+                    ValTypeID::Int(bits) if bits == half => from,
+                    _ => emit.cast(cast.get_opcode(), from, self.half_ty()),
+                };
+                let hi = match cast.get_opcode() {
+                    Opcode::Sext => emit.binop(Opcode::Ashr, lo, emit.konst(half as u64 - 1, half)),
+                    _ => emit.konst(0, half),
+                };
+                (lo, hi)
+            }
+            InstObj::Load(load) => {
+                let ptr = load.get_source(allocs);
+                let align = load.align_log2.min(half.trailing_zeros() as u8 - 3);
+                let hi_ptr = emit.byte_offset(ptr, half as u64 / 8);
+                let [lo, hi] = [ptr, hi_ptr].map(|ptr| {
+                    let load = LoadInstID::new_uninit(allocs, self.half_ty(), align);
+                    load.set_source(allocs, ptr);
+                    emit.insert(load)
+                });
+                (lo, hi)
+            }
+            InstObj::Select(select) => {
+                let cond = select.get_cond(allocs);
+                let (tl, th) = self.pair_of(select.get_then(allocs));
+                let (el, eh) = self.pair_of(select.get_else(allocs));
+                (emit.select(cond, tl, el), emit.select(cond, th, eh))
+            }
+            obj => unreachable!("cannot expand {:?}", obj.get_opcode()),
+        }
+    }
+
+    /// 展开使用宽整数但结果不是宽整数的指令. `store` 没有结果, 返回 `None`.
+    fn expand_user(&mut self, inst: InstID) -> Option<ValueSSA> {
+        let allocs = &self.module.allocs;
+        let half = self.half;
+        let emit = Emitter { module: self.module, pos: inst };
+        match inst.deref_ir(allocs) {
+            InstObj::Cmp(cmp) => {
+                let (al, ah) = self.pair_of(cmp.get_lhs(allocs));
+                let (bl, bh) = self.pair_of(cmp.get_rhs(allocs));
+                let cond = cmp.cond;
+                let basic = cond.get_basic_cond();
+                if basic == CmpCond::EQ || basic == CmpCond::NE {
+                    let lo = emit.binop(Opcode::BitXor, al, bl);
+                    let hi = emit.binop(Opcode::BitXor, ah, bh);
+                    let diff = emit.binop(Opcode::BitOr, lo, hi);
+                    return Some(emit.icmp(basic, diff, emit.konst(0, half)));
+                }
+                // 高半相等时低半按无符号数比较
+                let hi_eq = emit.icmp(CmpCond::EQ, ah, bh);
+                let lo_cmp = emit.icmp(basic, al, bl);
+                let hi_cmp = emit.icmp(cond, ah, bh);
+                Some(emit.select(hi_eq, lo_cmp, hi_cmp))
+            }
+            InstObj::Cast(cast) => {
+                let (lo, _) = self.pair_of(cast.get_from(allocs));
+                Some(match cast.get_valtype() {
+                    ValTypeID::Int(bits) if bits == half => lo,
+                    ty => emit.cast(Opcode::Trunc, lo, ty),
+                })
+            }
+            InstObj::Store(store) => {
+                let ptr = store.get_target(allocs);
+                let (lo, hi) = self.pair_of(store.get_source(allocs));
+                let align = store.align_log2.min(half.trailing_zeros() as u8 - 3);
+                let hi_ptr = emit.byte_offset(ptr, half as u64 / 8);
+                for (value, ptr) in [(lo, ptr), (hi, hi_ptr)] {
+                    emit.insert(StoreInstID::new(allocs, value, ptr, align));
+                }
+                None
+            }
+            obj => unreachable!("cannot expand {:?}", obj.get_opcode()),
+        }
+    }
+}
+
+/// 在 `pos` 之前插入新指令.
+struct Emitter<'ir> {
+    module: &'ir Module,
+    pos: InstID,
+}
+
+impl<'ir> Emitter<'ir> {
+    fn insert(&self, inst: impl ISubInstID) -> ValueSSA {
+        let allocs = &self.module.allocs;
+        let inst = inst.raw_into();
+        let block = self.pos.get_parent(allocs).unwrap();
+        block
+            .get_insts(allocs)
+            .node_add_prev(self.pos, inst, &allocs.insts)
+            .expect("Internal error: failed to insert legalized instruction");
+        ValueSSA::Inst(inst)
+    }
+
+    fn konst(&self, value: u64, bits: u8) -> ValueSSA {
+        APInt::new(value, bits).into()
+    }
+
+    fn binop(&self, opcode: Opcode, lhs: ValueSSA, rhs: ValueSSA) -> ValueSSA {
+        self.insert(BinOPInstID::new(&self.module.allocs, opcode, lhs, rhs))
+    }
+    fn icmp(&self, cond: CmpCond, lhs: ValueSSA, rhs: ValueSSA) -> ValueSSA {
+        let allocs = &self.module.allocs;
+        let cmp = CmpInstID::new_uninit(allocs, Opcode::Icmp, cond, lhs.get_valtype(allocs));
+        cmp.set_lhs(allocs, lhs);
+        cmp.set_rhs(allocs, rhs);
+        self.insert(cmp)
+    }
+    fn cast(&self, opcode: Opcode, from: ValueSSA, ty: ValTypeID) -> ValueSSA {
+        self.insert(CastInstID::new(&self.module.allocs, opcode, from, ty))
+    }
+    /// `value` 的类型已经是 `ty` 时不做转换.
+    fn resize(&self, opcode: Opcode, value: ValueSSA, ty: ValTypeID) -> ValueSSA {
+        if value.get_valtype(&self.module.allocs) == ty {
+            value
+        } else {
+            self.cast(opcode, value, ty)
+        }
+    }
+    fn select(&self, cond: ValueSSA, then_val: ValueSSA, else_val: ValueSSA) -> ValueSSA {
+        self.insert(SelectInstID::new(
+            &self.module.allocs,
+            cond,
+            then_val,
+            else_val,
+        ))
+    }
+
+    /// `ptr` 之后 `offset` 字节处的指针.
+    fn byte_offset(&self, ptr: ValueSSA, offset: u64) -> ValueSSA {
+        let gep = GEPInstID::builder_from_module(self.module, ValTypeID::Int(8))
+            .base_ptr(ptr)
+            .add_indices(&[APInt::new(offset, 64).into()])
+            .build_id();
+        self.insert(gep)
+    }
+
+    /// 两个无符号数乘积的高半部分, 用四个半宽乘积拼出来.
+    fn umulh(&self, a: ValueSSA, b: ValueSSA) -> ValueSSA {
+        use Opcode::*;
+        let ValTypeID::Int(bits) = a.get_valtype(&self.module.allocs) else {
+            unreachable!();
+        };
+        let h = bits / 2;
+        let mask = self.konst(u64::MAX >> (64 - h as u32), bits);
+        let shift = self.konst(h as u64, bits);
+        let (a0, a1) = (self.binop(BitAnd, a, mask), self.binop(Lshr, a, shift));
+        let (b0, b1) = (self.binop(BitAnd, b, mask), self.binop(Lshr, b, shift));
+        let p00 = self.binop(Mul, a0, b0);
+        let p01 = self.binop(Mul, a0, b1);
+        let p10 = self.binop(Mul, a1, b0);
+        let p11 = self.binop(Mul, a1, b1);
+        // 中间一列的和, 它的进位进入高半部分
+        let mid = self.binop(Lshr, p00, shift);
+        let mid = self.binop(Add, mid, self.binop(BitAnd, p01, mask));
+        let mid = self.binop(Add, mid, self.binop(BitAnd, p10, mask));
+        let hi = self.binop(Add, p11, self.binop(Lshr, p01, shift));
+        let hi = self.binop(Add, hi, self.binop(Lshr, p10, shift));
+        self.binop(Add, hi, self.binop(Lshr, mid, shift))
+    }
+
+    /// 宽整数 `(lo, hi)` 移位 `amount` 位. `amount` 只取低半, 大于等于宽整数位宽时结果无意义.
+    fn shift(
+        &self,
+        opcode: Opcode,
+        (lo, hi): (ValueSSA, ValueSSA),
+        amount: ValueSSA,
+    ) -> (ValueSSA, ValueSSA) {
+        use Opcode::*;
+        let ValTypeID::Int(bits) = lo.get_valtype(&self.module.allocs) else {
+            unreachable!();
+        };
+        let max = self.konst(bits as u64 - 1, bits);
+        let one = self.konst(1, bits);
+        let zero = self.konst(0, bits);
+        // 半内的移位量 `s`, 以及是否移过了整个低半
+        let s = self.binop(BitAnd, amount, max);
+        let big = self.binop(BitAnd, amount, self.konst(bits as u64, bits));
+        let big = self.icmp(CmpCond::NE, big, zero);
+        // `bits - s` 可能等于 `bits`, 所以先移 1 位再移 `bits - 1 - s` 位
+        let rest = self.binop(BitXor, s, max);
+        match opcode {
+            Shl => {
+                let lo_s = self.binop(Shl, lo, s);
+                let carry = self.binop(Lshr, self.binop(Lshr, lo, one), rest);
+                let hi_s = self.binop(BitOr, self.binop(Shl, hi, s), carry);
+                (self.select(big, zero, lo_s), self.select(big, lo_s, hi_s))
+            }
+            Lshr | Ashr => {
+                let hi_s = self.binop(opcode, hi, s);
+                let carry = self.binop(Shl, self.binop(Shl, hi, one), rest);
+                let lo_s = self.binop(BitOr, self.binop(Lshr, lo, s), carry);
+                let fill = match opcode {
+                    Lshr => zero,
+                    _ => self.binop(Ashr, hi, max),
+                };
+                (self.select(big, hi_s, lo_s), self.select(big, fill, hi_s))
+            }
+            _ => unreachable!("{opcode:?} is not a shift"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            TerminatorID,
+            checking::{assert_func_dominance, assert_module_sane},
+            inst::{AllocaInstID, BrInstID, JumpInstID, RetInstID},
+        },
+        opt::{sccp_fold_binop, sccp_fold_cast, sccp_fold_cmp},
+        testing::cases::{binop, cast, icmp, iconst, insert, new_func, ret, select},
+    };
+
+    fn add_block(builder: &IRBuilder, func: FuncID, after: BlockID) -> BlockID {
+        let allocs = builder.allocs();
+        let block = BlockID::new_uninit(allocs);
+        func.blocks_unwrap(allocs)
+            .node_add_next(after, block, &allocs.blocks)
+            .unwrap();
+        block
+    }
+    /// 按 `args` 执行 `func`, 返回它的返回值. 只支持整数运算、`select`、Phi 和跳转.
+    fn eval(allocs: &crate::ir::IRAllocs, func: FuncID, args: &[APInt]) -> APInt {
+        let mut env: HashMap<InstID, ConstData> = HashMap::new();
+        let value = |env: &HashMap<InstID, ConstData>, v: ValueSSA| match v {
+            ValueSSA::FuncArg(_, index) => ConstData::Int(args[index as usize]),
+            ValueSSA::ConstData(c) => c,
+            ValueSSA::Inst(inst) => env[&inst],
+            _ => panic!("unexpected operand {v:?}"),
+        };
+        let as_int = |c: ConstData| match c {
+            ConstData::Int(c) => c,
+            _ => panic!("not an integer: {c:?}"),
+        };
+        let (mut prev, mut block) = (None, func.get_entry(allocs).unwrap());
+        loop {
+            let mut phis = Vec::new();
+            for (inst, obj) in block.insts_iter(allocs) {
+                if let InstObj::Phi(phi) = obj {
+                    let incoming = phi.find_incoming_value(allocs, prev.unwrap()).unwrap();
+                    phis.push((inst, value(&env, incoming)));
+                }
+            }
+            env.extend(phis);
+            for (inst, obj) in block.insts_iter(allocs) {
+                let res = match obj {
+                    InstObj::BinOP(op) => {
+                        let (l, r) = (op.get_lhs(allocs), op.get_rhs(allocs));
+                        sccp_fold_binop(op.get_opcode(), &value(&env, l), &value(&env, r))
+                    }
+                    InstObj::Cast(c) => {
+                        let from = value(&env, c.get_from(allocs));
+                        sccp_fold_cast(c.get_opcode(), &from, c.get_valtype())
+                    }
+                    InstObj::Cmp(cmp) => {
+                        let (l, r) = (cmp.get_lhs(allocs), cmp.get_rhs(allocs));
+                        let res = sccp_fold_cmp(cmp.cond, &value(&env, l), &value(&env, r));
+                        res.map(|b| ConstData::Int(APInt::new(b as u8, 1)))
+                    }
+                    InstObj::Select(sel) => {
+                        let cond = as_int(value(&env, sel.get_cond(allocs)));
+                        let arm = if cond.is_nonzero() {
+                            sel.get_then(allocs)
+                        } else {
+                            sel.get_else(allocs)
+                        };
+                        Some(value(&env, arm))
+                    }
+                    _ => continue,
+                };
+                env.insert(inst, res.unwrap());
+            }
+            let next = match block.get_terminator(allocs) {
+                TerminatorID::Ret(r) => return as_int(value(&env, r.get_retval(allocs))),
+                TerminatorID::Jump(jump) => jump.get_target(allocs),
+                TerminatorID::Br(br) => {
+                    let cond = as_int(value(&env, br.get_cond(allocs)));
+                    if cond.is_nonzero() { br.get_then(allocs) } else { br.get_else(allocs) }
+                }
+                t => panic!("unexpected terminator {t:?}"),
+            };
+            (prev, block) = (Some(block), next.unwrap());
+        }
+    }
+
+    /// 函数中结果或操作数是 `bits` 位整数的运算指令个数.
+    fn count_int_ops(allocs: &crate::ir::IRAllocs, func: FuncID, bits: u8) -> usize {
+        let ty = ValTypeID::Int(bits);
+        func.blocks_iter(allocs)
+            .flat_map(|(bb, _)| bb.insts_iter(allocs))
+            .filter(|(_, obj)| match obj {
+                InstObj::BinOP(_) | InstObj::Load(_) | InstObj::Phi(_) | InstObj::Select(_) => {
+                    obj.get_valtype() == ty
+                }
+                InstObj::Cmp(cmp) => cmp.operand_ty == ty,
+                InstObj::Store(store) => store.source_ty == ty,
+                _ => false,
+            })
+            .count()
+    }
+
+    /// 执行 `func` 的所有参数组合, 返回结果.
+    fn eval_all(allocs: &crate::ir::IRAllocs, func: FuncID, inputs: &[Vec<APInt>]) -> Vec<APInt> {
+        inputs.iter().map(|args| eval(allocs, func, args)).collect()
+    }
+
+    #[test]
+    fn test_legal_widths() {
+        let arch64 = ArchInfo { ptr_nbits: 64, reg_nbits: 64 };
+        assert_eq!(legal_int_widths(&arch64).as_slice(), [32, 64]);
+        let arch32 = ArchInfo { ptr_nbits: 32, reg_nbits: 32 };
+        assert_eq!(legal_int_widths(&arch32).as_slice(), [32]);
+        let arch16 = ArchInfo { ptr_nbits: 16, reg_nbits: 16 };
+        assert_eq!(legal_int_widths(&arch16).as_slice(), [16]);
+
+        let builder = IRBuilder::new_inlined(arch64, "widths");
+        let pass = IntLegalize::new(&builder.module);
+        assert_eq!(pass.promoted_width(1), None);
+        assert_eq!(pass.promoted_width(7), Some(32));
+        assert_eq!(pass.promoted_width(32), None);
+        assert_eq!(pass.promoted_width(33), Some(64));
+        assert_eq!(pass.promoted_width(65), Some(128));
+        assert_eq!(pass.promoted_width(100), Some(128));
+        assert_eq!(pass.promoted_width(128), None);
+    }
+
+    #[test]
+    fn test_promote_narrow() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "promote");
+        let (i8ty, i16ty, i32ty) = (ValTypeID::Int(8), ValTypeID::Int(16), ValTypeID::Int(32));
+        let func = new_func(
+            &mut builder,
+            "f",
+            i32ty,
+            &[i32ty, i32ty, ValTypeID::Int(64)],
+        );
+        let [a, b, c] = [0, 1, 2].map(|i| ValueSSA::FuncArg(func, i));
+        let entry = func.get_entry(builder.allocs()).unwrap();
+        let left = add_block(&builder, func, entry);
+        let join = add_block(&builder, func, left);
+        let a = cast(&mut builder, Opcode::Trunc, a, i8ty);
+        let b = cast(&mut builder, Opcode::Trunc, b, i8ty);
+        let c = cast(&mut builder, Opcode::Trunc, c, ValTypeID::Int(33));
+        let t = binop(&mut builder, Opcode::Add, a, b);
+        let u = binop(&mut builder, Opcode::Lshr, t, iconst(1, 8));
+        let v = binop(&mut builder, Opcode::Ashr, t, iconst(2, 8));
+        let d = binop(&mut builder, Opcode::Udiv, t, iconst(3, 8));
+        let s = icmp(&mut builder, CmpCond::SLT, u, v);
+        let e = icmp(&mut builder, CmpCond::LT, d, b);
+        let m = binop(&mut builder, Opcode::Mul, c, c);
+        let n = binop(&mut builder, Opcode::Ashr, m, iconst(5, 33));
+        let q = icmp(&mut builder, CmpCond::SGT, n, c);
+        let r0 = select(&mut builder, s, u, v);
+        let r1 = select(&mut builder, e, r0, d);
+        let w = cast(&mut builder, Opcode::Zext, r1, i16ty);
+        let y = cast(&mut builder, Opcode::Trunc, n, i16ty);
+        let r3 = cast(&mut builder, Opcode::Trunc, n, i32ty);
+        let br = BrInstID::new(builder.allocs(), q, left, join);
+        builder.focus_set_terminator(br).unwrap();
+
+        builder.set_focus(IRFocus::Block(left));
+        let jump = JumpInstID::with_target(builder.allocs(), join);
+        drop(left.set_terminator_inst(builder.allocs(), jump.raw_into()));
+        let x = binop(&mut builder, Opcode::Sub, w, iconst(7, 16));
+
+        builder.set_focus(IRFocus::Block(join));
+        let ret_tmp = RetInstID::with_retval(builder.allocs(), iconst(0, 32));
+        drop(join.set_terminator_inst(builder.allocs(), ret_tmp.raw_into()));
+        let p = PhiInstID::from_incomings(builder.allocs(), i16ty, [(left, x), (entry, y)]);
+        let p = insert(&mut builder, p);
+        let r2 = cast(&mut builder, Opcode::Sext, p, i32ty);
+        let r4 = select(&mut builder, q, r2, r3);
+        ret(&mut builder, r4);
+
+        let allocs = builder.allocs();
+        let mut inputs = Vec::new();
+        for x in [0i128, 1, 5, 100, 127, -1, -2, -100, -128, 0x1234_5680] {
+            for y in [0i128, 3, -7, 127, -128] {
+                for z in [0i128, 7, -9, 1 << 20, (1 << 32) - 1, -(1 << 32), 1 << 40] {
+                    let args = [(x, 32), (y, 32), (z, 64)];
+                    inputs.push(args.map(|(v, bits)| APInt::new(v as u128, bits)).to_vec());
+                }
+            }
+        }
+        let expected = eval_all(allocs, func, &inputs);
+
+        let mut pass = IntLegalize::new(&builder.module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_promoted, 20);
+        assert_module_sane(&builder.module);
+        assert_func_dominance(allocs, func);
+        let illegal = illegal_ops(&pass, func);
+        assert!(illegal.is_empty(), "{illegal:?}");
+        assert_eq!(eval_all(allocs, func, &inputs), expected);
+        // `t` 的三个使用者直接用 32 位的和: 逻辑右移前按掩码清掉高位, 算术右移前先左移
+        let ops: Vec<Opcode> = func
+            .blocks_iter(allocs)
+            .flat_map(|(bb, _)| bb.insts_iter(allocs).map(|(_, obj)| obj.get_opcode()))
+            .collect();
+        assert_eq!(ops.iter().filter(|&&op| op == Opcode::Add).count(), 1);
+        assert!(
+            ops.contains(&Opcode::BitAnd) && ops.contains(&Opcode::Shl),
+            "{ops:?}"
+        );
+    }
+
+    /// 函数中结果或者操作数的类型需要提升的指令.
+    fn illegal_ops(pass: &IntLegalize, func: FuncID) -> Vec<Opcode> {
+        let allocs = &pass.module.allocs;
+        let illegal = |ty: ValTypeID| match ty {
+            ValTypeID::Int(bits) => pass.promoted_width(bits).is_some(),
+            _ => false,
+        };
+        func.blocks_iter(allocs)
+            .flat_map(|(bb, _)| bb.insts_iter(allocs))
+            .filter(|(_, obj)| {
+                illegal(obj.get_valtype())
+                    || obj
+                        .operands_iter()
+                        .any(|u| illegal(u.get_operand(allocs).get_valtype(allocs)))
+            })
+            .map(|(_, obj)| obj.get_opcode())
+            .collect()
+    }
+
+    #[test]
+    fn test_promote_memory() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "promote_mem");
+        let (i25ty, i32ty, ptrty) = (ValTypeID::Int(25), ValTypeID::Int(32), ValTypeID::Ptr);
+        let func = new_func(&mut builder, "mem", i32ty, &[ptrty, ptrty, i32ty]);
+        let [p, q, v] = [0, 1, 2].map(|i| ValueSSA::FuncArg(func, i));
+        // `i25` 占 4 个字节, 可以按 `i32` 读写; `i8` 只能按原来的宽度读写
+        let x = cast(&mut builder, Opcode::Trunc, v, i25ty);
+        let store = StoreInstID::new(builder.allocs(), x, p, 2);
+        insert(&mut builder, store);
+        let load = LoadInstID::new_uninit(builder.allocs(), i25ty, 2);
+        load.set_source(builder.allocs(), p);
+        let l = insert(&mut builder, load);
+        let s = binop(&mut builder, Opcode::Add, l, iconst(1, 25));
+        let b = cast(&mut builder, Opcode::Trunc, v, ValTypeID::Int(8));
+        let store = StoreInstID::new(builder.allocs(), b, q, 0);
+        insert(&mut builder, store);
+        let load = LoadInstID::new_uninit(builder.allocs(), ValTypeID::Int(8), 0);
+        load.set_source(builder.allocs(), q);
+        let l8 = insert(&mut builder, load);
+        let s8 = binop(&mut builder, Opcode::Add, l8, iconst(1, 8));
+        let t = cast(&mut builder, Opcode::Zext, s, i32ty);
+        let t8 = cast(&mut builder, Opcode::Zext, s8, i32ty);
+        let r = binop(&mut builder, Opcode::Add, t, t8);
+        ret(&mut builder, r);
+
+        let allocs = builder.allocs();
+        let mut pass = IntLegalize::new(&builder.module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_promoted, 8);
+        assert_module_sane(&builder.module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(count_int_ops(allocs, func, 25), 0);
+        // 只剩 `i8` 的读写和紧挨着它们的截断、扩展
+        assert_eq!(
+            illegal_ops(&pass, func),
+            [Opcode::Trunc, Opcode::Store, Opcode::Load, Opcode::Zext]
+        );
+        let wide_mem = func
+            .blocks_iter(allocs)
+            .flat_map(|(bb, _)| bb.insts_iter(allocs))
+            .filter(|(_, obj)| match obj {
+                InstObj::Load(_) => obj.get_valtype() == i32ty,
+                InstObj::Store(store) => store.source_ty == i32ty,
+                _ => false,
+            })
+            .count();
+        assert_eq!(wide_mem, 2);
+    }
+
+    #[test]
+    fn test_promote_then_expand() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "promote_wide");
+        let (i64ty, i100ty) = (ValTypeID::Int(64), ValTypeID::Int(100));
+        let func = new_func(&mut builder, "w", i64ty, &[i64ty, i64ty]);
+        let [a, b] = [0, 1].map(|i| ValueSSA::FuncArg(func, i));
+        let bd = &mut builder;
+        let x = cast(bd, Opcode::Zext, a, i100ty);
+        let y = cast(bd, Opcode::Sext, b, i100ty);
+        let m = binop(bd, Opcode::Mul, x, y);
+        let hi = binop(bd, Opcode::Lshr, m, iconst(40, 100));
+        let sh = binop(bd, Opcode::Ashr, m, iconst(90, 100));
+        let sum = binop(bd, Opcode::Add, hi, sh);
+        let lt = icmp(bd, CmpCond::SLT, m, y);
+        let sum = cast(bd, Opcode::Trunc, sum, i64ty);
+        let lt = cast(bd, Opcode::Zext, lt, i64ty);
+        let res = binop(bd, Opcode::BitXor, sum, lt);
+        ret(bd, res);
+
+        let allocs = builder.allocs();
+        let mut inputs = Vec::new();
+        for x in [0i64, 1, -1, i64::MAX, i64::MIN, 0x1234_5678_9abc] {
+            for y in [0i64, 3, -7, i64::MAX, i64::MIN] {
+                inputs.push([x, y].map(|v| APInt::new(v as u64, 64)).to_vec());
+            }
+        }
+        let expected = eval_all(allocs, func, &inputs);
+
+        let mut pass = IntLegalize::new(&builder.module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_unexpanded, 0);
+        assert!(pass.num_expanded > 0);
+        assert_module_sane(&builder.module);
+        assert_func_dominance(allocs, func);
+        let illegal = illegal_ops(&pass, func);
+        assert!(illegal.is_empty(), "{illegal:?}");
+        assert_eq!(count_int_ops(allocs, func, 128), 0);
+        assert_eq!(eval_all(allocs, func, &inputs), expected);
+    }
+
+    #[test]
+    fn test_expand_i128_arith() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "expand");
+        let i64ty = ValTypeID::Int(64);
+        let func = new_func(&mut builder, "g", i64ty, &[i64ty, i64ty, i64ty]);
+        let [a, b, s] = [0, 1, 2].map(|i| ValueSSA::FuncArg(func, i));
+        let bd = &mut builder;
+        let x = cast(bd, Opcode::Zext, a, ValTypeID::Int(128));
+        let y = cast(bd, Opcode::Sext, b, ValTypeID::Int(128));
+        let sh = cast(bd, Opcode::Zext, s, ValTypeID::Int(128));
+        let m = binop(bd, Opcode::Mul, x, y);
+        let p = binop(bd, Opcode::Add, m, y);
+        let q = binop(
+            bd,
+            Opcode::Sub,
+            p,
+            iconst(0x1234_5678_9abc_def0_1122_3344_5566_7788, 128),
+        );
+        let l = binop(bd, Opcode::Shl, q, sh);
+        let r = binop(bd, Opcode::Lshr, l, iconst(3, 128));
+        let ar = binop(bd, Opcode::Ashr, q, sh);
+        let lr = binop(bd, Opcode::Lshr, q, sh);
+        let c = icmp(bd, CmpCond::SLT, ar, r);
+        let cu = icmp(bd, CmpCond::GE, lr, y);
+        let e = icmp(bd, CmpCond::NE, l, q);
+        let sel = select(bd, c, l, r);
+        let xo = binop(bd, Opcode::BitXor, sel, ar);
+        let an = binop(bd, Opcode::BitAnd, xo, lr);
+        let or = binop(bd, Opcode::BitOr, an, m);
+        let hi = binop(bd, Opcode::Lshr, or, iconst(64, 128));
+        let hi = cast(bd, Opcode::Trunc, hi, ValTypeID::Int(64));
+        let lo = cast(bd, Opcode::Trunc, or, ValTypeID::Int(64));
+        let sum = binop(bd, Opcode::Add, hi, lo);
+        let (cu, e) = (
+            cast(bd, Opcode::Zext, cu, ValTypeID::Int(64)),
+            cast(bd, Opcode::Zext, e, ValTypeID::Int(64)),
+        );
+        let flags = binop(bd, Opcode::BitXor, cu, e);
+        let flags = binop(bd, Opcode::Shl, flags, iconst(1, 64));
+        let res = binop(bd, Opcode::BitXor, sum, flags);
+        let t32 = cast(bd, Opcode::Trunc, xo, ValTypeID::Int(32));
+        let t32 = cast(bd, Opcode::Zext, t32, ValTypeID::Int(64));
+        let res = binop(bd, Opcode::Add, res, t32);
+        ret(bd, res);
+
+        let allocs = builder.allocs();
+        let mut inputs = Vec::new();
+        for x in [0i64, 1, -1, 0x7fff_ffff_ffff_ffff, i64::MIN, 0x1234_5678, -0x5555_aaaa_1234] {
+            for y in [0i64, 3, -7, i64::MAX, i64::MIN + 1] {
+                for s in [0i64, 1, 13, 63, 64, 65, 100, 127] {
+                    inputs.push([x, y, s].map(|v| APInt::new(v as u64, 64)).to_vec());
+                }
+            }
+        }
+        let expected = eval_all(allocs, func, &inputs);
+
+        let mut pass = IntLegalize::new(&builder.module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_unexpanded, 0);
+        assert!(pass.num_expanded > 0);
+        assert_module_sane(&builder.module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(count_int_ops(allocs, func, 128), 0);
+        let casts_128 = func
+            .blocks_iter(allocs)
+            .flat_map(|(bb, _)| bb.insts_iter(allocs))
+            .filter(|(_, obj)| obj.get_valtype() == ValTypeID::Int(128))
+            .count();
+        assert_eq!(casts_128, 0);
+        assert_eq!(eval_all(allocs, func, &inputs), expected);
+    }
+
+    #[test]
+    fn test_expand_i128_loop_and_memory() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "loop");
+        let (i64ty, i128ty) = (ValTypeID::Int(64), ValTypeID::Int(128));
+        // 循环 n 次 acc = acc * x + 12345, 返回 acc 的高半与低半之和
+        let func = new_func(&mut builder, "pow", i64ty, &[i64ty, i64ty]);
+        let [a, n] = [0, 1].map(|i| ValueSSA::FuncArg(func, i));
+        let entry = func.get_entry(builder.allocs()).unwrap();
+        let body = add_block(&builder, func, entry);
+        let exit = add_block(&builder, func, body);
+        let x = cast(&mut builder, Opcode::Zext, a, ValTypeID::Int(128));
+        let jump = JumpInstID::with_target(builder.allocs(), body);
+        builder.focus_set_terminator(jump).unwrap();
+
+        builder.set_focus(IRFocus::Block(body));
+        let ret_tmp = RetInstID::with_retval(builder.allocs(), iconst(0, 64));
+        drop(body.set_terminator_inst(builder.allocs(), ret_tmp.raw_into()));
+        let acc = PhiInstID::from_incomings(builder.allocs(), i128ty, [(entry, iconst(1, 128))]);
+        let i = PhiInstID::from_incomings(builder.allocs(), i64ty, [(entry, iconst(0, 64))]);
+        let acc_v = insert(&mut builder, acc);
+        let i_v = insert(&mut builder, i);
+        let acc2 = binop(&mut builder, Opcode::Mul, acc_v, x);
+        let acc2 = binop(&mut builder, Opcode::Add, acc2, iconst(12345, 128));
+        let i2 = binop(&mut builder, Opcode::Add, i_v, iconst(1, 64));
+        let cond = icmp(&mut builder, CmpCond::LT, i2, n);
+        acc.deref_ir(builder.allocs())
+            .set_incoming(builder.allocs(), body, acc2);
+        i.deref_ir(builder.allocs())
+            .set_incoming(builder.allocs(), body, i2);
+        let br = BrInstID::new(builder.allocs(), cond, body, exit);
+        builder.focus_set_terminator(br).unwrap();
+
+        builder.set_focus(IRFocus::Block(exit));
+        drop(exit.set_terminator_inst(
+            builder.allocs(),
+            RetInstID::with_retval(builder.allocs(), iconst(0, 64)).raw_into(),
+        ));
+        let hi = binop(&mut builder, Opcode::Lshr, acc2, iconst(64, 128));
+        let hi = cast(&mut builder, Opcode::Trunc, hi, ValTypeID::Int(64));
+        let lo = cast(&mut builder, Opcode::Trunc, acc2, ValTypeID::Int(64));
+        let sum = binop(&mut builder, Opcode::Add, hi, lo);
+        ret(&mut builder, sum);
+
+        // 把 i128 存进栈上再读出来
+        let mem = new_func(&mut builder, "mem", i64ty, &[i64ty]);
+        let arg = ValueSSA::FuncArg(mem, 0);
+        let slot = AllocaInstID::new(builder.allocs(), i128ty, 4);
+        let slot = insert(&mut builder, slot);
+        let wide = cast(&mut builder, Opcode::Sext, arg, ValTypeID::Int(128));
+        let store = StoreInstID::new(builder.allocs(), wide, slot, 4);
+        insert(&mut builder, store);
+        let load = LoadInstID::new_uninit(builder.allocs(), i128ty, 4);
+        load.set_source(builder.allocs(), slot);
+        let loaded = insert(&mut builder, load);
+        let hi = binop(&mut builder, Opcode::Ashr, loaded, iconst(64, 128));
+        let hi = cast(&mut builder, Opcode::Trunc, hi, ValTypeID::Int(64));
+        ret(&mut builder, hi);
+
+        let allocs = builder.allocs();
+        let inputs: Vec<Vec<APInt>> = [(3i64, 1i64), (3, 30), (-1, 5), (0x1_0000_0001, 7)]
+            .iter()
+            .map(|&(a, n)| vec![APInt::new(a as u64, 64), APInt::new(n as u64, 64)])
+            .collect();
+        let expected = eval_all(allocs, func, &inputs);
+        let mut pass = IntLegalize::new(&builder.module);
+        pass.run_on_func(func);
+        pass.run_on_func(mem);
+        assert_eq!(pass.num_unexpanded, 0);
+        assert_module_sane(&builder.module);
+        assert_func_dominance(allocs, func);
+        assert_func_dominance(allocs, mem);
+        assert_eq!(count_int_ops(allocs, func, 128), 0);
+        assert_eq!(count_int_ops(allocs, mem, 128), 0);
+        assert!(count_int_ops(allocs, func, 64) > 0);
+        assert_eq!(eval_all(allocs, func, &inputs), expected);
+        let mem_ops: Vec<Opcode> = mem
+            .blocks_iter(allocs)
+            .flat_map(|(bb, _)| bb.insts_iter(allocs).map(|(_, obj)| obj.get_opcode()))
+            .collect();
+        assert_eq!(mem_ops.iter().filter(|&&op| op == Opcode::Store).count(), 2);
+        assert_eq!(mem_ops.iter().filter(|&&op| op == Opcode::Load).count(), 2);
+    }
+
+    #[test]
+    fn test_unexpandable_component() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "opaque");
+        let i128ty = ValTypeID::Int(128);
+        let func = new_func(
+            &mut builder,
+            "h",
+            ValTypeID::Int(64),
+            &[i128ty, ValTypeID::Int(64)],
+        );
+        // `x + 1` 依赖参数, 拆不开; `zext y` 自成一组, 可以展开
+        let x = binop(
+            &mut builder,
+            Opcode::Add,
+            ValueSSA::FuncArg(func, 0),
+            iconst(1, 128),
+        );
+        let x = cast(&mut builder, Opcode::Trunc, x, ValTypeID::Int(64));
+        let y = cast(
+            &mut builder,
+            Opcode::Zext,
+            ValueSSA::FuncArg(func, 1),
+            ValTypeID::Int(128),
+        );
+        let y = binop(&mut builder, Opcode::Shl, y, iconst(3, 128));
+        let y = cast(&mut builder, Opcode::Trunc, y, ValTypeID::Int(64));
+        let sum = binop(&mut builder, Opcode::Add, x, y);
+        ret(&mut builder, sum);
+
+        let mut pass = IntLegalize::new(&builder.module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_unexpanded, 1);
+        assert_eq!(pass.num_expanded, 2);
+        assert_module_sane(&builder.module);
+        assert_eq!(count_int_ops(builder.allocs(), func, 128), 1);
+    }
+}
@@ -0,0 +1,461 @@
+use crate::{
+    SymbolStr,
+    ir::{
+        ConstData, FuncArgID, FuncClone, FuncID, ISubGlobalID, ISubInstID, ITraceableValue,
+        Linkage, Module, ValueSSA, inst::CallInstID,
+    },
+    opt::transforms::{IFuncTransformPass, IModuleTransformPass, collect_direct_calls, sccp::*},
+};
+use smallvec::SmallVec;
+
+/// 按常量实参分组的调用点.
+type CallGroups = Vec<(ConstData, SmallVec<[CallInstID; 4]>)>;
+
+/// 过程间常量传播 (IPCP) 与函数特化.
+///
+/// 候选函数的条件与 `DeadArgElim` 相同: 有函数体, 链接属性为 `Private` 或
+/// `DSOLocal`, 没有导出, 不是变参函数, 并且只被直接调用.
+///
+/// 对每个候选函数的每个参数, 在所有调用点上对实参做 `SccpValue` 交汇:
+///
+/// * 交汇结果是常量: 直接把该常量代入函数体;
+/// * 所有调用点都传入常量, 但常量有 2 ~ `max_specializations` 种: 为每种常量
+///   (除最后一种外) 用 `FuncClone` 克隆出一个特化版本, 代入常量并把对应调用点的
+///   callee 改写到特化版本上; 最后一种常量直接代入原函数.
+///
+/// 代入常量之后会在函数上运行 `SCCP`, 让常量继续在函数体内传播.
+/// 特化新增的指令数受 `size_budget` 限制. 自递归函数不做特化.
+pub struct IPConstProp<'ir> {
+    pub module: &'ir mut Module,
+    /// 函数特化允许新增的指令总数.
+    pub size_budget: usize,
+    /// 每个函数最多保留的特化版本数 (包括原函数).
+    pub max_specializations: usize,
+    pub num_const_args: usize,
+    pub num_specialized: usize,
+}
+
+impl<'ir> IModuleTransformPass for IPConstProp<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("IPConstProp")
+    }
+
+    fn run_on_module(&mut self) {
+        let mut funcs: Vec<FuncID> = self
+            .module
+            .symbols
+            .borrow()
+            .func_pool()
+            .iter()
+            .copied()
+            .collect();
+        funcs.sort_unstable();
+
+        // 先在所有函数内部做一遍 SCCP, 让调用点上的实参尽可能折叠成常量.
+        let mut sccp = SCCP::new(self.module);
+        for &func in &funcs {
+            sccp.run_on_func(func);
+        }
+
+        for func in funcs {
+            if !self.func_is_candidate(func) {
+                continue;
+            }
+            let Some(calls) = collect_direct_calls(&self.module.allocs, func) else {
+                continue;
+            };
+            if calls.is_empty() {
+                continue;
+            }
+            self.propagate_uniform_args(func, &calls);
+            self.try_specialize(func, &calls);
+        }
+    }
+}
+
+impl<'ir> IPConstProp<'ir> {
+    pub fn new(module: &'ir mut Module) -> Self {
+        Self {
+            module,
+            size_budget: 1024,
+            max_specializations: 4,
+            num_const_args: 0,
+            num_specialized: 0,
+        }
+    }
+
+    fn func_is_candidate(&self, func: FuncID) -> bool {
+        let module = &*self.module;
+        let allocs = &module.allocs;
+        if !func.is_alive(allocs) || func.get_body(allocs).is_none() {
+            return false;
+        }
+        if !matches!(
+            func.get_linkage(allocs),
+            Linkage::Private | Linkage::DSOLocal
+        ) {
+            return false;
+        }
+        if module.symbol_is_exported(func.raw_into()) {
+            return false;
+        }
+        !func.get_functype(allocs).is_vararg(&module.tctx)
+    }
+
+    fn call_arg_value(&self, call: CallInstID, index: usize) -> SccpValue {
+        match call.get_arg(&self.module.allocs, index) {
+            ValueSSA::ConstData(c) => SccpValue::from_const(c),
+            _ => SccpValue::Overdefined,
+        }
+    }
+
+    /// 把所有调用点都一致的常量实参代入函数体.
+    fn propagate_uniform_args(&mut self, func: FuncID, calls: &[CallInstID]) {
+        let allocs = &self.module.allocs;
+        let nargs = func.args(allocs).len();
+        let mut changed = false;
+        for index in 0..nargs {
+            let arg = FuncArgID(func, index as u32).deref_ir(allocs);
+            if !arg.has_users(allocs) {
+                continue;
+            }
+            let value = calls.iter().fold(SccpValue::Undefined, |v, &call| {
+                v.meet(self.call_arg_value(call, index))
+            });
+            let SccpValue::Const(c) = value else {
+                continue;
+            };
+            arg.replace_self_with(allocs, ValueSSA::ConstData(c))
+                .expect("Internal error: failed to replace constant argument");
+            self.num_const_args += 1;
+            changed = true;
+        }
+        if changed {
+            SCCP::new(self.module).run_on_func(func);
+        }
+    }
+
+    /// 选出一个适合特化的参数: 仍然被使用, 所有调用点都传入常量,
+    /// 并且常量的种类数在 `2..=max_specializations` 之间.
+    /// 返回参数下标和按常量分组的调用点.
+    fn select_specialize_arg(
+        &self,
+        func: FuncID,
+        calls: &[CallInstID],
+    ) -> Option<(usize, CallGroups)> {
+        let allocs = &self.module.allocs;
+        let nargs = func.args(allocs).len();
+        'args: for index in 0..nargs {
+            if !FuncArgID(func, index as u32)
+                .deref_ir(allocs)
+                .has_users(allocs)
+            {
+                continue;
+            }
+            let mut groups = CallGroups::new();
+            for &call in calls {
+                let SccpValue::Const(c) = self.call_arg_value(call, index) else {
+                    continue 'args;
+                };
+                match groups.iter_mut().find(|(gc, _)| *gc == c) {
+                    Some((_, group)) => group.push(call),
+                    None => groups.push((c, SmallVec::from_slice(&[call]))),
+                }
+                if groups.len() > self.max_specializations {
+                    continue 'args;
+                }
+            }
+            if groups.len() >= 2 {
+                return Some((index, groups));
+            }
+        }
+        None
+    }
+
+    fn func_size(&self, func: FuncID) -> usize {
+        let allocs = &self.module.allocs;
+        func.blocks_iter(allocs)
+            .map(|(block, _)| block.get_insts(allocs).len())
+            .sum()
+    }
+
+    fn try_specialize(&mut self, func: FuncID, calls: &[CallInstID]) {
+        // 特化版本中的递归调用仍然指向原函数, 而原函数的参数会被代入
+        // `last_const`, 递归调用点传入的常量未必与之相同.
+        let allocs = &self.module.allocs;
+        if calls
+            .iter()
+            .any(|call| call.get_parent_func(allocs) == Some(func))
+        {
+            return;
+        }
+        let Some((index, mut groups)) = self.select_specialize_arg(func, calls) else {
+            return;
+        };
+        // 最后一组留给原函数, 只需要克隆其余的组
+        let cost = self.func_size(func) * (groups.len() - 1);
+        if cost > self.size_budget {
+            return;
+        }
+        let (last_const, _) = groups.pop().unwrap();
+
+        for (c, group) in groups {
+            let name = format!(
+                "{}.specialized.{}",
+                func.get_name(&self.module.allocs),
+                self.num_specialized
+            );
+            let clone = FuncClone::new(self.module, func).and_then(|mut clone| {
+                clone.change_name(name).linkage(Linkage::Private)?;
+                clone.finish()
+            });
+            let new_func = match clone {
+                Ok(mapping) => mapping.new_func,
+                Err(e) => {
+                    log::debug!("IPConstProp: failed to specialize function: {e}");
+                    return;
+                }
+            };
+            self.size_budget -= self.func_size(func);
+            self.num_specialized += 1;
+
+            let allocs = &self.module.allocs;
+            FuncArgID(new_func, index as u32)
+                .deref_ir(allocs)
+                .replace_self_with(allocs, ValueSSA::ConstData(c))
+                .expect("Internal error: failed to replace constant argument");
+            for call in group {
+                call.set_callee(allocs, ValueSSA::Global(new_func.raw_into()));
+            }
+            SCCP::new(self.module).run_on_func(new_func);
+        }
+
+        let allocs = &self.module.allocs;
+        FuncArgID(func, index as u32)
+            .deref_ir(allocs)
+            .replace_self_with(allocs, ValueSSA::ConstData(last_const))
+            .expect("Internal error: failed to replace constant argument");
+        self.num_const_args += 1;
+        SCCP::new(self.module).run_on_func(func);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::APInt,
+        ir::{checking::assert_module_sane, inst::*, *},
+        typing::{ArchInfo, FuncTypeID, ValTypeID},
+    };
+
+    fn i32_const(x: u32) -> ValueSSA {
+        APInt::new(x, 32).into()
+    }
+
+    /// ```remusys-ir
+    /// define internal i32 @scale(i32 %0, i32 %1) {
+    ///     %3 = mul i32 %0, %1
+    ///     ret i32 %3
+    /// }
+    ///
+    /// define dso_local i32 @main() {
+    ///     %1 = call i32 @scale(i32 2, i32 10)
+    ///     %2 = call i32 @scale(i32 3, i32 10)
+    ///     %3 = add i32 %1, %2
+    ///     ret i32 %3
+    /// }
+    /// ```
+    fn build_module() -> (Module, FuncID, FuncID) {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "ipcp");
+        let i32ty = ValTypeID::Int(32);
+        let tctx = builder.tctx();
+        let scale_ty = FuncTypeID::new(tctx, i32ty, false, [i32ty; 2]);
+        let main_ty = FuncTypeID::new(tctx, i32ty, false, []);
+
+        let scale = FuncID::builder(tctx, "scale", scale_ty)
+            .make_private()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_pinned(&builder.module);
+        let main = FuncID::builder(tctx, "main", main_ty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+
+        let scale_entry = scale.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(scale_entry));
+        let mul = BinOPInstID::new(
+            builder.allocs(),
+            Opcode::Mul,
+            ValueSSA::FuncArg(scale, 0),
+            ValueSSA::FuncArg(scale, 1),
+        );
+        builder.insert_inst(mul).unwrap();
+        let TerminatorID::Ret(ret) = scale_entry.get_terminator(builder.allocs()) else {
+            panic!("scale entry should end with ret");
+        };
+        ret.set_retval(builder.allocs(), ValueSSA::Inst(mul.raw_into()));
+
+        let main_entry = main.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(main_entry));
+        let mut calls = [ValueSSA::None; 2];
+        for (i, x) in [2, 3].into_iter().enumerate() {
+            let call = CallInst::builder(builder.tctx(), scale_ty)
+                .callee(ValueSSA::Global(scale.raw_into()))
+                .with_args(&[i32_const(x), i32_const(10)])
+                .build_id(builder.allocs());
+            builder.insert_inst(call).unwrap();
+            calls[i] = ValueSSA::Inst(call.raw_into());
+        }
+        let add = BinOPInstID::new(builder.allocs(), Opcode::Add, calls[0], calls[1]);
+        builder.insert_inst(add).unwrap();
+        let TerminatorID::Ret(ret) = main_entry.get_terminator(builder.allocs()) else {
+            panic!("main entry should end with ret");
+        };
+        ret.set_retval(builder.allocs(), ValueSSA::Inst(add.raw_into()));
+
+        (builder.take(), scale, main)
+    }
+
+    fn returned_value(allocs: &IRAllocs, func: FuncID) -> ValueSSA {
+        let TerminatorID::Ret(ret) = func.get_entry(allocs).unwrap().get_terminator(allocs) else {
+            panic!("function entry should end with ret");
+        };
+        ret.get_retval(allocs)
+    }
+
+    #[test]
+    fn test_ipcp_specialize() {
+        let (mut module, scale, main) = build_module();
+        let mut pass = IPConstProp::new(&mut module);
+        pass.run_on_module();
+        // 第二个参数在所有调用点都是 10, 第一个参数有两种取值
+        assert_eq!(pass.num_const_args, 2);
+        assert_eq!(pass.num_specialized, 1);
+
+        let allocs = &module.allocs;
+        let callees: Vec<FuncID> = main
+            .get_entry(allocs)
+            .unwrap()
+            .insts_iter(allocs)
+            .filter_map(|(_, inst)| match inst {
+                InstObj::Call(call) => match call.get_callee(allocs) {
+                    ValueSSA::Global(g) => Some(FuncID::raw_from(g)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        assert_eq!(callees.len(), 2);
+        assert_ne!(callees[0], callees[1]);
+        assert_eq!(callees[1], scale);
+        assert_eq!(returned_value(allocs, callees[0]), i32_const(20));
+        assert_eq!(returned_value(allocs, scale), i32_const(30));
+
+        assert_module_sane(&module);
+        write_ir_to_file("../target/test-ipcp.ll", &module, IRWriteOption::quiet());
+    }
+
+    /// ```remusys-ir
+    /// define internal i32 @count(i32 %0) {
+    ///     %2 = icmp eq i32 %0, 1
+    ///     br i1 %2, label %6, label %3
+    /// 3:
+    ///     %4 = call i32 @count(i32 1)
+    ///     %5 = add i32 %4, %0
+    ///     ret i32 %5
+    /// 6:
+    ///     ret i32 0
+    /// }
+    ///
+    /// define dso_local i32 @main() {
+    ///     %1 = call i32 @count(i32 2)
+    ///     %2 = call i32 @count(i32 3)
+    ///     %3 = add i32 %1, %2
+    ///     ret i32 %3
+    /// }
+    /// ```
+    #[test]
+    fn test_ipcp_recursive() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "ipcp_recursive");
+        let i32ty = ValTypeID::Int(32);
+        let tctx = builder.tctx();
+        let count_ty = FuncTypeID::new(tctx, i32ty, false, [i32ty]);
+        let main_ty = FuncTypeID::new(tctx, i32ty, false, []);
+        let count = FuncID::builder(tctx, "count", count_ty)
+            .make_private()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_pinned(&builder.module);
+        let main = FuncID::builder(tctx, "main", main_ty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+        let call_count = |builder: &mut IRBuilder, x: u32| {
+            let call = CallInst::builder(builder.tctx(), count_ty)
+                .callee(ValueSSA::Global(count.raw_into()))
+                .with_args(&[i32_const(x)])
+                .build_id(builder.allocs());
+            builder.insert_inst(call).unwrap();
+            ValueSSA::Inst(call.raw_into())
+        };
+
+        let entry = count.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(entry));
+        // 每次拆分都在入口块之后插入新块, 所以按逆序创建
+        let base = builder.split_block().unwrap();
+        let recur = builder.split_block().unwrap();
+        let arg = ValueSSA::FuncArg(count, 0);
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, CmpCond::EQ, i32ty);
+        cmp.set_lhs(builder.allocs(), arg);
+        cmp.set_rhs(builder.allocs(), i32_const(1));
+        builder.insert_inst(cmp).unwrap();
+        builder
+            .focus_set_branch_to(ValueSSA::Inst(cmp.raw_into()), base, recur)
+            .unwrap();
+
+        builder.set_focus(IRFocus::Block(recur));
+        let inner = call_count(&mut builder, 1);
+        let add = BinOPInstID::new(builder.allocs(), Opcode::Add, inner, arg);
+        builder.insert_inst(add).unwrap();
+        let ret = RetInstID::with_retval(builder.allocs(), ValueSSA::Inst(add.raw_into()));
+        builder.focus_set_terminator(ret).unwrap();
+
+        let main_entry = main.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(main_entry));
+        let lhs = call_count(&mut builder, 2);
+        let rhs = call_count(&mut builder, 3);
+        let add = BinOPInstID::new(builder.allocs(), Opcode::Add, lhs, rhs);
+        builder.insert_inst(add).unwrap();
+        let ret = RetInstID::with_retval(builder.allocs(), ValueSSA::Inst(add.raw_into()));
+        builder.focus_set_terminator(ret).unwrap();
+
+        let mut module = builder.take();
+        let mut pass = IPConstProp::new(&mut module);
+        pass.run_on_module();
+        assert_eq!(pass.num_const_args, 0);
+        assert_eq!(pass.num_specialized, 0);
+
+        // 参数没有被任何常量替换, 所有调用点仍然指向原函数
+        let allocs = &module.allocs;
+        assert!(FuncArgID(count, 0).deref_ir(allocs).has_users(allocs));
+        let calls = collect_direct_calls(allocs, count).unwrap();
+        assert_eq!(calls.len(), 3);
+        assert_module_sane(&module);
+    }
+
+    #[test]
+    fn test_ipcp_no_budget() {
+        let (mut module, scale, _) = build_module();
+        let mut pass = IPConstProp::new(&mut module);
+        pass.size_budget = 0;
+        pass.run_on_module();
+        assert_eq!(pass.num_const_args, 1);
+        assert_eq!(pass.num_specialized, 0);
+        assert!(matches!(
+            returned_value(&module.allocs, scale),
+            ValueSSA::Inst(_)
+        ));
+    }
+}
@@ -0,0 +1,344 @@
+//! JumpThreading: 条件在某条入边上已知时, 让前驱直接跳到已知的后继.
+//!
+//! 对以 `br cond` 结尾的块 `B` 和它的前驱 `P`, 如果沿着 `P -> B` 这条边进入时 `cond` 的值已知,
+//! 就复制一份 `B` 给 `P` 专用, 副本的结尾改为直接跳到已知的后继:
+//!
+//! ```text
+//! P:  jump B                P:  jump B'
+//! B:  phi...; br c, T, F => B': ...; jump T
+//!                           B:  phi...; br c, T, F   ; 其余前驱仍然进入 B
+//! ```
+//!
+//! 条件已知的情况有两种:
+//!
+//! * `cond` 是 `B` 中的 Phi, 或者是操作数为常量 / `B` 中 Phi 的比较指令, 并且这些 Phi 从 `P` 传入的是常量;
+//! * `cond` 在 `B` 之外定义, 而某条支配 `P` 的边已经按 `cond` 分过支了.
+//!
+//! `B` 中定义、在 `B` 外使用的值在复制之后有两个定义, 由 [`SSAUpdater`] 插入 Phi 修复.
+//! 如果 `B` 只有一个前驱, 就不需要复制, 直接把分支改为跳转.
+
+use crate::{
+    SymbolStr,
+    ir::{
+        BlockID, ConstData, FuncID, IRBuilder, ISubInstID, ITraceableValue, InstID, InstObj,
+        Module, SSAUpdater, TerminatorID, UseID, ValueSSA, clone_blocks, inst::JumpInstID,
+    },
+    opt::{
+        CfgBlockStat, CfgSnapshot, DominanceFrontier, DominatorTree, sccp_fold_cmp,
+        transforms::{
+            IFuncTransformPass, block_phis, redirect_jumps, remove_unreachable_blocks, use_block,
+        },
+    },
+};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+
+pub struct JumpThreading<'ir> {
+    pub module: &'ir Module,
+    /// 只复制非 Phi 指令条数不超过这个值的块.
+    pub dup_threshold: usize,
+    pub num_threaded: usize,
+}
+
+impl<'ir> IFuncTransformPass for JumpThreading<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("JumpThreading")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        // 每次线程化都会让一条入边不再经过条件分支, 所以这个循环一定会结束
+        loop {
+            let (Ok(cfg), Ok(dom)) = (
+                CfgSnapshot::new(allocs, func),
+                DominatorTree::builder(allocs, func).map(|b| b.build()),
+            ) else {
+                return;
+            };
+            let blocks: SmallVec<[BlockID; 16]> = func
+                .blocks_iter(allocs)
+                .map(|(block, _)| block)
+                .filter(|&block| dom.dfs.try_block_dfn(block).is_some())
+                .collect();
+            let threaded = blocks
+                .iter()
+                .any(|&block| self.try_thread_block(func, &cfg, &dom, block));
+            if !threaded {
+                break;
+            }
+        }
+    }
+}
+
+impl<'ir> JumpThreading<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, dup_threshold: 8, num_threaded: 0 }
+    }
+
+    fn try_thread_block(
+        &mut self,
+        func: FuncID,
+        cfg: &CfgSnapshot,
+        dom: &DominatorTree,
+        block: BlockID,
+    ) -> bool {
+        let allocs = &self.module.allocs;
+        let TerminatorID::Br(br) = block.get_terminator(allocs) else {
+            return false;
+        };
+        let (Some(then_bb), Some(else_bb)) = (br.get_then(allocs), br.get_else(allocs)) else {
+            return false;
+        };
+        let Some(preds) = cfg.pred_of(block) else {
+            return false;
+        };
+        // 循环头的条件每次迭代都会重新计算, 不处理
+        if then_bb == else_bb || preds.iter().any(|&p| dom.block_dominates_block(block, p)) {
+            return false;
+        }
+        let cond = br.get_cond(allocs);
+        for &pred in preds {
+            // 一个前驱有多条边进入 block 时无法只重定向其中一条
+            if preds.iter().filter(|&&p| p == pred).count() != 1 {
+                continue;
+            }
+            let Some(taken) = self.known_cond(cfg, dom, block, cond, pred) else {
+                continue;
+            };
+            let (taken, dropped) = if taken { (then_bb, else_bb) } else { (else_bb, then_bb) };
+            if preds.len() == 1 {
+                for phi in block_phis(allocs, dropped) {
+                    phi.deref_ir(allocs).remove_incoming(allocs, block);
+                }
+                let jump = JumpInstID::with_target(allocs, taken);
+                drop(block.set_terminator_inst(allocs, jump.raw_into()));
+            } else if self.dup_size(block) <= self.dup_threshold {
+                self.thread(func, pred, block, taken);
+            } else {
+                continue;
+            }
+            remove_unreachable_blocks(self.module, func);
+            self.num_threaded += 1;
+            return true;
+        }
+        false
+    }
+
+    /// 沿 `pred -> block` 进入时条件 `cond` 的值.
+    fn known_cond(
+        &self,
+        cfg: &CfgSnapshot,
+        dom: &DominatorTree,
+        block: BlockID,
+        cond: ValueSSA,
+        pred: BlockID,
+    ) -> Option<bool> {
+        let allocs = &self.module.allocs;
+        let ValueSSA::Inst(inst) = cond else {
+            return self.dominating_cond(cfg, dom, cond, pred, block);
+        };
+        if inst.get_parent(allocs) != Some(block) {
+            return self.dominating_cond(cfg, dom, cond, pred, block);
+        }
+        // 条件在 block 中定义: 只看 Phi 从 pred 传入的常量
+        let incoming_const = |value: ValueSSA| match value {
+            ValueSSA::ConstData(c) => Some(c),
+            ValueSSA::Inst(inst) if inst.get_parent(allocs) == Some(block) => {
+                match inst.deref_ir(allocs) {
+                    InstObj::Phi(phi) => match phi.find_incoming_value(allocs, pred)? {
+                        ValueSSA::ConstData(c) => Some(c),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        match inst.deref_ir(allocs) {
+            InstObj::Phi(_) => match incoming_const(cond)? {
+                ConstData::Int(c) => Some(c.is_nonzero()),
+                _ => None,
+            },
+            InstObj::Cmp(cmp) => {
+                let lhs = incoming_const(cmp.get_lhs(allocs))?;
+                let rhs = incoming_const(cmp.get_rhs(allocs))?;
+                sccp_fold_cmp(cmp.cond, &lhs, &rhs)
+            }
+            _ => None,
+        }
+    }
+
+    /// 在支配 `pred` 的边上查找按同一个条件的分支.
+    fn dominating_cond(
+        &self,
+        cfg: &CfgSnapshot,
+        dom: &DominatorTree,
+        cond: ValueSSA,
+        pred: BlockID,
+        block: BlockID,
+    ) -> Option<bool> {
+        let allocs = &self.module.allocs;
+        let edge_cond = |from: BlockID, to: BlockID| match from.get_terminator(allocs) {
+            TerminatorID::Br(br) if br.get_cond(allocs) == cond => {
+                let (then_bb, else_bb) = (br.get_then(allocs)?, br.get_else(allocs)?);
+                (then_bb != else_bb).then_some(then_bb == to)
+            }
+            _ => None,
+        };
+        if let Some(known) = edge_cond(pred, block) {
+            return Some(known);
+        }
+        // 沿支配树向上走. 只有一个前驱的块, 它的入边支配它支配的所有块
+        let mut cur = pred;
+        while let Some(dfn) = dom.dfs.try_block_dfn(cur)
+            && dfn != 0
+        {
+            if let Some(&[single]) = cfg.pred_of(cur)
+                && let Some(known) = edge_cond(single, cur)
+            {
+                return Some(known);
+            }
+            match dom.nodes[dfn].idom {
+                CfgBlockStat::Block(idom) => cur = idom,
+                CfgBlockStat::Virtual => return None,
+            }
+        }
+        None
+    }
+
+    fn dup_size(&self, block: BlockID) -> usize {
+        let allocs = &self.module.allocs;
+        block
+            .insts_iter(allocs)
+            .filter(|(_, inst)| !matches!(inst, InstObj::Phi(_) | InstObj::PhiInstEnd(_)))
+            .count()
+    }
+
+    /// 复制 `block` 给 `pred` 专用, 副本直接跳到 `taken`.
+    fn thread(&self, func: FuncID, pred: BlockID, block: BlockID, taken: BlockID) {
+        let allocs = &self.module.allocs;
+
+        // 1. 记录在 block 外被使用的值
+        let mut live_out: Vec<(InstID, SmallVec<[UseID; 4]>)> = Vec::new();
+        for (inst, obj) in block.insts_iter(allocs) {
+            let uses: SmallVec<[UseID; 4]> = obj
+                .user_iter(allocs)
+                .map(|(use_id, _)| use_id)
+                .filter(|&use_id| use_block(allocs, use_id) != Some(block))
+                .collect();
+            if !uses.is_empty() {
+                live_out.push((inst, uses));
+            }
+        }
+
+        // 2. 复制 block. 副本只从 pred 进入, 只跳到 taken
+        let mapping = clone_blocks(self.module, &[block], block);
+        let new_block = mapping.map_block(block);
+        for phi in block_phis(allocs, taken) {
+            let phi = phi.deref_ir(allocs);
+            if let Some(value) = phi.find_incoming_value(allocs, block) {
+                phi.set_incoming(allocs, new_block, mapping.map_or_keep(value));
+            }
+        }
+        let jump = JumpInstID::with_target(allocs, taken);
+        drop(new_block.set_terminator_inst(allocs, jump.raw_into()));
+
+        // 3. 副本中的 Phi 只剩 pred 一个传入值, 直接替换掉
+        let mut clone_defs: HashMap<InstID, ValueSSA> = HashMap::new();
+        let mut builder = IRBuilder::new(self.module);
+        for phi in block_phis(allocs, block) {
+            let value = phi
+                .deref_ir(allocs)
+                .find_incoming_value(allocs, pred)
+                .expect("Internal error: phi has no incoming value from the threaded pred");
+            let ValueSSA::Inst(new_phi) = mapping.map_or_keep(ValueSSA::Inst(phi.raw_into()))
+            else {
+                unreachable!("Internal error: phi was not cloned");
+            };
+            new_phi
+                .deref_ir(allocs)
+                .replace_self_with(allocs, value)
+                .expect("Internal error: failed to replace cloned phi");
+            builder
+                .remove_inst(new_phi)
+                .expect("Internal error: failed to remove cloned phi");
+            new_phi.dispose(allocs).unwrap();
+            clone_defs.insert(phi.raw_into(), value);
+        }
+
+        // 4. pred 改为进入副本
+        redirect_jumps(allocs, pred, block, new_block);
+        for phi in block_phis(allocs, block) {
+            phi.deref_ir(allocs).remove_incoming(allocs, pred);
+        }
+
+        // 5. 修复 block 外的使用
+        if !live_out.is_empty() {
+            let dom = DominatorTree::builder(allocs, func)
+                .expect("Internal error: CFG is broken after jump threading")
+                .build();
+            let df = DominanceFrontier::new(&dom, allocs)
+                .expect("Internal error: CFG is broken after jump threading");
+            for (inst, uses) in live_out {
+                let new_def = clone_defs
+                    .get(&inst)
+                    .copied()
+                    .unwrap_or_else(|| mapping.map_or_keep(ValueSSA::Inst(inst)));
+                let mut updater = SSAUpdater::new(allocs, &df, inst.deref_ir(allocs).get_valtype());
+                updater.add_def(block, ValueSSA::Inst(inst));
+                updater.add_def(new_block, new_def);
+                for use_id in uses {
+                    updater.rewrite_use(use_id);
+                }
+                updater.finish();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            IRAllocs, IRWriteOption, ISubGlobalID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        testing::cases::test_case_jump_thread,
+    };
+
+    fn count_br(allocs: &IRAllocs, func: FuncID) -> usize {
+        func.blocks_iter(allocs)
+            .filter(|(block, _)| matches!(block.get_terminator(allocs), TerminatorID::Br(_)))
+            .count()
+    }
+
+    #[test]
+    fn test_jump_threading() {
+        let module = test_case_jump_thread().module;
+        let allocs = &module.allocs;
+        let mut pass = JumpThreading::new(&module);
+        for name in ["main", "dom_cond"] {
+            let func = module
+                .get_global_by_name(name)
+                .map(FuncID::raw_from)
+                .expect("test case function not found");
+            pass.num_threaded = 0;
+            pass.run_on_func(func);
+            assert_module_sane(&module);
+            assert_func_dominance(allocs, func);
+            assert_eq!(pass.num_threaded, 2, "{name}");
+            // 只剩入口块的条件分支
+            assert_eq!(count_br(allocs, func), 1, "{name}");
+        }
+        write_ir_to_file(
+            "../target/test-jump-threading.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! LoopDeletion: 删除没有任何可观察效果的循环.
+//!
+//! 满足下面所有条件的循环可以直接删掉, preheader 改为跳转到唯一的出口块:
+//!
+//! * 循环中没有 `store`、`amormw` 以及非 pure 函数的调用;
+//! * 循环中定义的值都不在循环外使用. 出口块的 Phi 从循环内各前驱得到的必须是同一个循环外定义的值;
+//! * 循环的迭代次数有限. 这里要求 [`ScalarEvolution`] 能算出常量的 trip count.
+//!
+//! 先删除内层循环, 外层循环在内层循环被删除之后才有机会满足条件.
+
+use crate::{
+    SymbolStr,
+    ir::{
+        AttrClass, BlockID, FuncID, GlobalObj, ISubGlobalID, ISubInstID, ITraceableValue, InstObj,
+        Module, ValueSSA, inst::PhiInstID,
+    },
+    opt::{
+        CfgSnapshot, Loop, LoopSimplify, ScalarEvolution,
+        transforms::{
+            IFuncTransformPass, block_phis, redirect_jumps, remove_unreachable_blocks, use_block,
+        },
+    },
+};
+use smallvec::SmallVec;
+
+pub struct LoopDeletion<'ir> {
+    pub module: &'ir Module,
+    pub num_deleted: usize,
+}
+
+impl<'ir> IFuncTransformPass for LoopDeletion<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("LoopDeletion")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        LoopSimplify::new(self.module).run_on_func(func);
+        loop {
+            let (Ok(scev), Ok(cfg)) = (
+                ScalarEvolution::new(allocs, func),
+                CfgSnapshot::new(allocs, func),
+            ) else {
+                return;
+            };
+            let deleted = scev
+                .loops
+                .innermost_first()
+                .any(|l| self.try_delete(func, &scev, &cfg, l));
+            if !deleted {
+                break;
+            }
+        }
+    }
+}
+
+impl<'ir> LoopDeletion<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, num_deleted: 0 }
+    }
+
+    fn try_delete(
+        &mut self,
+        func: FuncID,
+        scev: &ScalarEvolution,
+        cfg: &CfgSnapshot,
+        l: usize,
+    ) -> bool {
+        let allocs = &self.module.allocs;
+        let lp = &scev.loops.loops[l];
+        let Some(preheader) = lp.preheader(cfg) else {
+            return false;
+        };
+        let [exit] = lp.exit_blocks(cfg)[..] else {
+            return false;
+        };
+        if !lp.children.is_empty() || self.has_side_effects(lp) || self.used_outside(lp) {
+            return false;
+        }
+        let Some(exit_values) = self.exit_values(cfg, lp, exit) else {
+            return false;
+        };
+        if scev.trip_count(allocs, l).is_none() {
+            return false;
+        }
+
+        // 出口块的 Phi 改为从 preheader 传入, preheader 直接跳转到出口块
+        let preds: SmallVec<[BlockID; 4]> = cfg
+            .pred_of(exit)
+            .unwrap_or(&[])
+            .iter()
+            .copied()
+            .filter(|&pred| lp.contains(pred))
+            .collect();
+        for (phi, value) in exit_values {
+            let phi = phi.deref_ir(allocs);
+            for &pred in &preds {
+                phi.remove_incoming(allocs, pred);
+            }
+            phi.set_incoming(allocs, preheader, value);
+        }
+        redirect_jumps(allocs, preheader, lp.header, exit);
+        remove_unreachable_blocks(self.module, func);
+        self.num_deleted += 1;
+        true
+    }
+
+    fn has_side_effects(&self, lp: &Loop) -> bool {
+        let allocs = &self.module.allocs;
+        lp.block_list.iter().any(|&block| {
+            block.insts_iter(allocs).any(|(_, inst)| match inst {
+                InstObj::Store(_) | InstObj::AmoRmw(_) => true,
+                InstObj::Call(call) => match call.get_callee(allocs) {
+                    ValueSSA::Global(global) => match global.deref_ir(allocs) {
+                        GlobalObj::Func(func) => !func.has_attr_class(AttrClass::FuncPure),
+                        _ => true,
+                    },
+                    _ => true,
+                },
+                _ => false,
+            })
+        })
+    }
+
+    /// 循环中定义的值是否在循环外被使用. 出口块 Phi 的传入值视为在循环内使用, 由 [`Self::exit_values`] 单独检查.
+    fn used_outside(&self, lp: &Loop) -> bool {
+        let allocs = &self.module.allocs;
+        lp.block_list.iter().any(|&block| {
+            block.insts_iter(allocs).any(|(_, inst)| {
+                inst.user_iter(allocs)
+                    .any(|(use_id, _)| use_block(allocs, use_id).is_none_or(|b| !lp.contains(b)))
+            })
+        })
+    }
+
+    /// 出口块中每个 Phi 从循环内得到的值. 这些值必须相同并且在循环外定义.
+    fn exit_values(
+        &self,
+        cfg: &CfgSnapshot,
+        lp: &Loop,
+        exit: BlockID,
+    ) -> Option<Vec<(PhiInstID, ValueSSA)>> {
+        let allocs = &self.module.allocs;
+        let preds = cfg.pred_of(exit)?;
+        let mut values = Vec::new();
+        for phi in block_phis(allocs, exit) {
+            let phi_obj = phi.deref_ir(allocs);
+            let mut common = None;
+            for &pred in preds.iter().filter(|&&pred| lp.contains(pred)) {
+                let value = phi_obj.find_incoming_value(allocs, pred)?;
+                if common.is_some_and(|c| c != value) {
+                    return None;
+                }
+                common = Some(value);
+            }
+            let value = common?;
+            if let ValueSSA::Inst(inst) = value
+                && inst.get_parent(allocs).is_some_and(|b| lp.contains(b))
+            {
+                return None;
+            }
+            values.push((phi, value));
+        }
+        Some(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::APInt,
+        ir::{
+            IRWriteOption, TerminatorID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        opt::{LoopInfo, Mem2Reg},
+        testing::cases::{test_case_array_sum, test_case_loop_select},
+    };
+
+    fn main_after_mem2reg(module: &Module) -> FuncID {
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(module).run_on_func(func);
+        func
+    }
+
+    #[test]
+    fn test_loop_deletion() {
+        let module = test_case_loop_select().module;
+        let allocs = &module.allocs;
+        let func = main_after_mem2reg(&module);
+
+        // 循环的结果 s 仍然被返回, 不能删除
+        let mut pass = LoopDeletion::new(&module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_deleted, 0);
+
+        // 改为返回常量之后循环就没有可观察的效果了
+        for (block, _) in func.blocks_iter(allocs) {
+            if let TerminatorID::Ret(ret) = block.get_terminator(allocs) {
+                ret.retval_use(allocs)
+                    .set_operand(allocs, APInt::new(0u32, 32).into());
+            }
+        }
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-loop-deletion.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_deleted, 1);
+        assert!(LoopInfo::new(allocs, func).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_loop_deletion_keeps_stores() {
+        let module = test_case_array_sum().module;
+        let func = main_after_mem2reg(&module);
+        let mut pass = LoopDeletion::new(&module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_deleted, 0);
+        assert_eq!(LoopInfo::new(&module.allocs, func).unwrap().loops.len(), 1);
+    }
+}
@@ -0,0 +1,330 @@
+//! LoopRotate: 把 `while` 形式的循环旋转成带守卫的 `do-while` 形式.
+//!
+//! 旋转前循环头负责判断是否退出循环:
+//!
+//! ```text
+//! preheader: jump header
+//! header:    phi...; cond = ...; br cond, body, exit
+//! body:      ...; jump header          ; latch
+//! ```
+//!
+//! 旋转后循环头的非 Phi 指令被复制到前置块中作为守卫, 原来的循环头变成循环底部的判断块,
+//! `body` 成为新的循环头:
+//!
+//! ```text
+//! preheader: cond0 = ...; br cond0, body, exit
+//! body:      phi [preheader, header]...; ...; jump header
+//! header:    cond = ...; br cond, body, exit
+//! ```
+//!
+//! 在原循环头中定义、在其他地方使用的值会在 `body` 或 `exit` 中插入 Phi 合并.
+//! 这个 Pass 要求循环处于 LoopSimplify 规范形式, 并且只有循环头一个出口块.
+
+use crate::{
+    SymbolStr,
+    ir::{
+        BlockID, FuncID, IRBuilder, IRFocus, ISubInstID, ITraceableValue, InstID, InstObj, Module,
+        TerminatorID, UseID, ValueSSA, clone_inst,
+        inst::{BrInstID, PhiInstID},
+        remap_inst_operands,
+    },
+    opt::{
+        CfgSnapshot, Loop, LoopInfo, LoopSimplify,
+        transforms::{IFuncTransformPass, block_phis, split_block_preds, use_block},
+    },
+};
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+
+pub struct LoopRotate<'ir> {
+    pub module: &'ir Module,
+    /// 能被复制到前置块的循环头最多包含多少条非 Phi 指令.
+    pub max_header_size: usize,
+    pub num_rotated: usize,
+}
+
+impl<'ir> IFuncTransformPass for LoopRotate<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("LoopRotate")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        LoopSimplify::new(self.module).run_on_func(func);
+        let mut visited: HashSet<BlockID> = HashSet::new();
+        loop {
+            let (Ok(info), Ok(cfg)) = (LoopInfo::new(allocs, func), CfgSnapshot::new(allocs, func))
+            else {
+                return;
+            };
+            let rotated = info.innermost_first().any(|l| {
+                let lp = &info.loops[l];
+                visited.insert(lp.header) && self.rotate_loop(&cfg, lp)
+            });
+            if !rotated {
+                break;
+            }
+        }
+        // 旋转后前置块同时跳转到新循环头和出口块, 需要重新整理出前置块和专用出口块
+        if self.num_rotated > 0 {
+            LoopSimplify::new(self.module).run_on_func(func);
+        }
+    }
+}
+
+/// 旋转一个循环所需的基本块.
+struct RotateShape {
+    preheader: BlockID,
+    header: BlockID,
+    body: BlockID,
+    exit: BlockID,
+    br: BrInstID,
+}
+
+impl<'ir> LoopRotate<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, max_header_size: 16, num_rotated: 0 }
+    }
+
+    fn rotate_loop(&mut self, cfg: &CfgSnapshot, lp: &Loop) -> bool {
+        let Some(mut shape) = self.check_shape(cfg, lp) else {
+            return false;
+        };
+        // 新循环头只能有原循环头一个前驱, 否则先拆分这条边
+        if cfg.pred_of(shape.body).is_some_and(|p| p.len() > 1) {
+            shape.body = split_block_preds(self.module, shape.body, &[shape.header]);
+        }
+        self.rotate(lp, &shape);
+        self.num_rotated += 1;
+        true
+    }
+
+    fn check_shape(&self, cfg: &CfgSnapshot, lp: &Loop) -> Option<RotateShape> {
+        let allocs = &self.module.allocs;
+        let header = lp.header;
+        let preheader = lp.preheader(cfg)?;
+        let latch = lp.single_latch()?;
+        // 单块循环本身就是 do-while 形式
+        if latch == header || !lp.has_dedicated_exits(cfg) {
+            return None;
+        }
+        if lp.exiting_blocks(cfg)[..] != [header] {
+            return None;
+        }
+        let TerminatorID::Br(br) = header.get_terminator(allocs) else {
+            return None;
+        };
+        let (then_bb, else_bb) = (br.get_then(allocs)?, br.get_else(allocs)?);
+        let (body, exit) = match (lp.contains(then_bb), lp.contains(else_bb)) {
+            (true, false) => (then_bb, else_bb),
+            (false, true) => (else_bb, then_bb),
+            _ => return None,
+        };
+        if body == header {
+            return None;
+        }
+        let header_size = header
+            .insts_iter(allocs)
+            .filter(|(_, inst)| {
+                !matches!(
+                    inst,
+                    InstObj::GuideNode(_) | InstObj::Phi(_) | InstObj::PhiInstEnd(_)
+                )
+            })
+            .count();
+        // 终结指令不需要复制
+        if header_size - 1 > self.max_header_size {
+            return None;
+        }
+        Some(RotateShape { preheader, header, body, exit, br })
+    }
+
+    fn rotate(&mut self, lp: &Loop, shape: &RotateShape) {
+        let allocs = &self.module.allocs;
+        let RotateShape { preheader, header, body, exit, br } = *shape;
+
+        // 1. 把循环头复制到前置块. `cloned` 记录循环头中的值在前置块中的对应值.
+        let mut cloned: HashMap<InstID, ValueSSA> = HashMap::new();
+        let header_phis = block_phis(allocs, header);
+        for &phi in &header_phis {
+            let init = phi
+                .deref_ir(allocs)
+                .find_incoming_value(allocs, preheader)
+                .expect("Internal error: header phi has no incoming from preheader");
+            cloned.insert(phi.raw_into(), init);
+        }
+        let header_insts: SmallVec<[InstID; 16]> = header
+            .insts_iter(allocs)
+            .filter(|(_, inst)| {
+                !matches!(
+                    inst,
+                    InstObj::GuideNode(_) | InstObj::Phi(_) | InstObj::PhiInstEnd(_)
+                )
+            })
+            .map(|(id, _)| id)
+            .filter(|&id| id != br.raw_into())
+            .collect();
+        let map_value = |cloned: &HashMap<InstID, ValueSSA>, v: ValueSSA| match v {
+            ValueSSA::Inst(inst) => cloned.get(&inst).copied(),
+            _ => None,
+        };
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(IRFocus::Block(preheader));
+        for &inst in &header_insts {
+            let new_inst = clone_inst(allocs, &self.module.tctx, inst);
+            remap_inst_operands(allocs, new_inst, |v| map_value(&cloned, v));
+            builder
+                .insert_inst(new_inst)
+                .expect("Internal error: failed to insert cloned header instruction");
+            cloned.insert(inst, ValueSSA::Inst(new_inst));
+        }
+
+        // 2. 修复在循环头之外使用的循环头定义值: 循环内的使用改为 body 中的新 Phi,
+        //    循环外的使用改为 exit 中的新 Phi. 这一步要在前置块改变跳转之前收集使用点.
+        let defs: SmallVec<[InstID; 16]> = header_phis
+            .iter()
+            .map(|phi| phi.raw_into())
+            .chain(header_insts.iter().copied())
+            .collect();
+        let mut body_fixes: Vec<(InstID, SmallVec<[UseID; 4]>)> = Vec::new();
+        let mut exit_fixes: Vec<(InstID, SmallVec<[UseID; 4]>)> = Vec::new();
+        for &def in &defs {
+            let mut in_body = SmallVec::new();
+            let mut in_exit = SmallVec::new();
+            for (use_id, _) in def.deref_ir(allocs).user_iter(allocs) {
+                match use_block(allocs, use_id) {
+                    Some(b) if b == header || b == preheader => {}
+                    Some(b) if lp.contains(b) || b == body => in_body.push(use_id),
+                    Some(_) => in_exit.push(use_id),
+                    None => {}
+                }
+            }
+            if !in_body.is_empty() {
+                body_fixes.push((def, in_body));
+            }
+            if !in_exit.is_empty() {
+                exit_fixes.push((def, in_exit));
+            }
+        }
+
+        // 3. 出口块多了一个来自前置块的前驱
+        for phi in block_phis(allocs, exit) {
+            let phi = phi.deref_ir(allocs);
+            let Some(v) = phi.find_incoming_value(allocs, header) else {
+                continue;
+            };
+            let v = map_value(&cloned, v).unwrap_or(v);
+            phi.set_incoming(allocs, preheader, v);
+        }
+
+        // 4. 前置块改为守卫分支, 原循环头不再是前置块的后继
+        for &phi in &header_phis {
+            phi.deref_ir(allocs).remove_incoming(allocs, preheader);
+        }
+        let cond = br.get_cond(allocs);
+        let guard_cond = map_value(&cloned, cond).unwrap_or(cond);
+        let (then_bb, else_bb) =
+            if br.get_then(allocs) == Some(body) { (body, exit) } else { (exit, body) };
+        let guard = BrInstID::new(allocs, guard_cond, then_bb, else_bb);
+        // 旧的 jump 指令由 ManagedInst 负责 dispose
+        preheader
+            .set_terminator_inst(allocs, guard.raw_into())
+            .expect("Internal error: preheader has no terminator");
+
+        // 5. 插入合并用的 Phi
+        for (block, fixes) in [(body, body_fixes), (exit, exit_fixes)] {
+            builder.set_focus(IRFocus::Block(block));
+            for (def, uses) in fixes {
+                let ty = def.deref_ir(allocs).get_valtype();
+                let init = cloned[&def];
+                let incomings = [(preheader, init), (header, ValueSSA::Inst(def))];
+                let phi = PhiInstID::from_incomings(allocs, ty, incomings);
+                builder
+                    .insert_inst(phi)
+                    .expect("Internal error: failed to insert rotate phi");
+                for use_id in uses {
+                    use_id.set_operand(allocs, ValueSSA::Inst(phi.raw_into()));
+                }
+            }
+        }
+
+        // 6. 原循环头只剩 latch 一个前驱, 它的 Phi 可以直接替换为传入值
+        for phi in header_phis {
+            let phi_obj = phi.deref_ir(allocs);
+            let incomings = phi_obj.incoming_uses();
+            let [[uval, _]] = incomings[..] else {
+                continue;
+            };
+            let value = uval.get_operand(allocs);
+            drop(incomings);
+            let defined_in_header = match value {
+                ValueSSA::Inst(inst) => inst.get_parent(allocs) == Some(header),
+                _ => false,
+            };
+            if defined_in_header {
+                continue;
+            }
+            phi_obj
+                .replace_self_with(allocs, value)
+                .expect("Internal error: failed to replace header phi");
+            builder
+                .remove_inst(phi)
+                .expect("Internal error: failed to remove header phi");
+            phi.raw_into().dispose(allocs).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            IRWriteOption, ISubGlobalID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        opt::Mem2Reg,
+        testing::cases::test_case_cfg_deep_while_br,
+    };
+
+    #[test]
+    fn test_loop_rotate() {
+        let module = test_case_cfg_deep_while_br().module;
+        let allocs = &module.allocs;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(&module).run_on_func(func);
+
+        let mut pass = LoopRotate::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-loop-rotate.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_rotated, 1);
+
+        // 旋转后入口块就是守卫, 直接分支到循环体或出口
+        let cfg = CfgSnapshot::new(allocs, func).unwrap();
+        let TerminatorID::Br(_) = cfg.entry.get_terminator(allocs) else {
+            panic!("rotated loop should be guarded by a branch in the preheader");
+        };
+        let info = LoopInfo::new(allocs, func).unwrap();
+        assert_eq!(info.loops.len(), 1);
+        let lp = &info.loops[0];
+        let latch = lp
+            .single_latch()
+            .expect("rotated loop should keep a single latch");
+        // 新的 latch 同时也是唯一的出口块
+        assert_eq!(lp.exiting_blocks(&cfg)[..], [latch]);
+        assert!(lp.is_simplified(&cfg));
+    }
+}
@@ -0,0 +1,155 @@
+//! LoopSimplify: 把自然循环整理成规范形式.
+//!
+//! 规范形式的循环满足:
+//!
+//! * 有专用的前置块 (preheader): 循环头唯一的循环外前驱, 并且它只跳转到循环头;
+//! * 只有一个 latch, 即只有一条回边;
+//! * 所有出口块都是专用的: 出口块的前驱都在循环内.
+//!
+//! 每一步整理都通过在 CFG 上插入新的中转块完成, Phi 指令的传入列表会同步更新.
+
+use crate::{
+    SymbolStr,
+    ir::{BlockID, FuncID, Module},
+    opt::{
+        CfgSnapshot, Loop, LoopInfo,
+        transforms::{IFuncTransformPass, split_block_preds},
+    },
+};
+use smallvec::SmallVec;
+use std::collections::HashSet;
+
+pub struct LoopSimplify<'ir> {
+    pub module: &'ir Module,
+    pub num_preheaders: usize,
+    pub num_latches: usize,
+    pub num_exits: usize,
+}
+
+impl<'ir> IFuncTransformPass for LoopSimplify<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("LoopSimplify")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        // 无法整理的循环 (循环头是入口块) 按循环头记录下来, 避免反复尝试
+        let mut skipped: HashSet<BlockID> = HashSet::new();
+        // 每次只做一步修改, 然后重新计算循环信息, 直到所有循环都是规范形式
+        loop {
+            let (Ok(info), Ok(cfg)) = (LoopInfo::new(allocs, func), CfgSnapshot::new(allocs, func))
+            else {
+                return;
+            };
+            let changed = info.innermost_first().any(|l| {
+                let lp = &info.loops[l];
+                !skipped.contains(&lp.header) && self.simplify_step(&cfg, lp, &mut skipped)
+            });
+            if !changed {
+                break;
+            }
+        }
+    }
+}
+
+impl<'ir> LoopSimplify<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, num_preheaders: 0, num_latches: 0, num_exits: 0 }
+    }
+
+    pub fn num_changes(&self) -> usize {
+        self.num_preheaders + self.num_latches + self.num_exits
+    }
+
+    /// 对循环做一步整理. 返回是否修改了 IR.
+    fn simplify_step(
+        &mut self,
+        cfg: &CfgSnapshot,
+        lp: &Loop,
+        skipped: &mut HashSet<BlockID>,
+    ) -> bool {
+        if lp.preheader(cfg).is_none() {
+            let outside = lp.outside_preds(cfg);
+            if outside.is_empty() {
+                skipped.insert(lp.header);
+                return false;
+            }
+            split_block_preds(self.module, lp.header, &outside);
+            self.num_preheaders += 1;
+            return true;
+        }
+        if lp.latches.len() > 1 {
+            split_block_preds(self.module, lp.header, &lp.latches);
+            self.num_latches += 1;
+            return true;
+        }
+        for exit in lp.exit_blocks(cfg) {
+            let preds = cfg.pred_of(exit).unwrap_or(&[]);
+            if preds.iter().all(|p| lp.contains(*p)) {
+                continue;
+            }
+            let inside: SmallVec<[BlockID; 4]> =
+                preds.iter().copied().filter(|p| lp.contains(*p)).collect();
+            split_block_preds(self.module, exit, &inside);
+            self.num_exits += 1;
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            IRWriteOption, ISubGlobalID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        opt::Mem2Reg,
+        testing::cases::test_case_cfg_deep_while_br,
+    };
+
+    #[test]
+    fn test_loop_simplify() {
+        let module = test_case_cfg_deep_while_br().module;
+        let allocs = &module.allocs;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(&module).run_on_func(func);
+
+        let info = LoopInfo::new(allocs, func).unwrap();
+        assert_eq!(info.loops.len(), 1);
+        assert_eq!(info.loops[0].latches.len(), 4);
+
+        let mut pass = LoopSimplify::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-loop-simplify.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_latches, 1);
+        // 入口块只跳转到循环头, 本身就是前置块
+        assert_eq!(pass.num_preheaders, 0);
+
+        let info = LoopInfo::new(allocs, func).unwrap();
+        let cfg = CfgSnapshot::new(allocs, func).unwrap();
+        assert_eq!(info.loops.len(), 1);
+        assert!(info.loops[0].is_simplified(&cfg));
+        assert_eq!(info.loops[0].preheader(&cfg), Some(cfg.entry));
+
+        // 已经是规范形式的循环不会再被修改
+        let mut pass = LoopSimplify::new(&module);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_changes(), 0);
+    }
+}
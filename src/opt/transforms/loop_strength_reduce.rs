@@ -0,0 +1,542 @@
+//! LoopStrengthReduce: 循环强度削减与归纳变量化简.
+//!
+//! 从最内层循环开始, 对每个循环依次做三件事:
+//!
+//! * 归纳变量规范化: 选出一个主归纳变量 (优先选退出条件比较的那个), 把步长相同的其他循环头 Phi
+//!   改写成 `主归纳变量 + 起始值之差`;
+//! * 强度削减: 地址是本循环递推式的 `GEPInst`, 以及值是本循环递推式的 `mul`/`shl`, 都替换成新的
+//!   循环头 Phi. 新 Phi 在 latch 中每次加上固定步长, 不再需要每次迭代重新做乘法和地址计算;
+//! * 删除变为死代码的指令, 以及只被自身递增指令使用的归纳变量.
+//!
+//! 递推式来自 [`ScalarEvolution`]. 新归纳变量的起始值和步长在 preheader 中展开成 IR,
+//! 其中外层循环的递推式借助外层循环的 `{0,+,1}` 归纳变量展开.
+
+use crate::{
+    SymbolStr,
+    ir::{
+        BlockID, FuncID, IRAllocs, IRBuilder, IRFocus, ISubInst, ISubInstID, ITraceableValue,
+        InstID, InstObj, Module, Opcode, UserID, ValueSSA,
+        inst::{BinOPInstID, CastInstID, GEPInstID, PhiInstID},
+    },
+    opt::{
+        CfgSnapshot, GEPOffset, Loop, LoopInfo, LoopSimplify, ScalarEvolution, Scev, ScevAddRec,
+        ScevRef,
+        transforms::{IFuncTransformPass, block_phis},
+    },
+    typing::ValTypeID,
+};
+use std::collections::{HashMap, HashSet};
+
+pub struct LoopStrengthReduce<'ir> {
+    pub module: &'ir Module,
+    pub num_merged_ivs: usize,
+    pub num_reduced: usize,
+    pub num_dead_ivs: usize,
+}
+
+impl<'ir> IFuncTransformPass for LoopStrengthReduce<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("LoopStrengthReduce")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        LoopSimplify::new(self.module).run_on_func(func);
+        let Ok(loops) = LoopInfo::new(allocs, func) else {
+            return;
+        };
+        // 这个 Pass 不修改 CFG, 所以循环可以用循环头来标识
+        let headers: Vec<BlockID> = loops
+            .innermost_first()
+            .map(|l| loops.loops[l].header)
+            .collect();
+        for header in headers {
+            self.run_on_loop(func, header);
+        }
+    }
+}
+
+/// 正在处理的循环的结构.
+#[derive(Clone, Copy)]
+struct LoopShape {
+    header: BlockID,
+    preheader: BlockID,
+    latch: BlockID,
+}
+
+impl<'ir> LoopStrengthReduce<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, num_merged_ivs: 0, num_reduced: 0, num_dead_ivs: 0 }
+    }
+
+    fn run_on_loop(&mut self, func: FuncID, header: BlockID) {
+        let allocs = &self.module.allocs;
+        let Ok(cfg) = CfgSnapshot::new(allocs, func) else {
+            return;
+        };
+        let Some((scev, l)) = Self::analyze(allocs, func, header) else {
+            return;
+        };
+        let lp = &scev.loops.loops[l];
+        let (Some(preheader), Some(latch)) = (lp.preheader(&cfg), lp.single_latch()) else {
+            return;
+        };
+        let shape = LoopShape { header, preheader, latch };
+        self.merge_ivs(&scev, l, shape);
+
+        let Some((scev, l)) = Self::analyze(allocs, func, header) else {
+            return;
+        };
+        let lp = &scev.loops.loops[l];
+        self.reduce(&scev, l, shape);
+        self.remove_dead(lp, shape);
+    }
+
+    fn analyze(
+        allocs: &IRAllocs,
+        func: FuncID,
+        header: BlockID,
+    ) -> Option<(ScalarEvolution, usize)> {
+        let scev = ScalarEvolution::new(allocs, func).ok()?;
+        let l = scev.loops.loop_of(header)?;
+        (scev.loops.loops[l].header == header).then_some((scev, l))
+    }
+
+    /// 本循环的归纳变量: 循环头中表达式为本循环递推式的 Phi.
+    fn loop_ivs(&self, scev: &ScalarEvolution, header: BlockID) -> Vec<(PhiInstID, ScevAddRec)> {
+        let allocs = &self.module.allocs;
+        block_phis(allocs, header)
+            .into_iter()
+            .filter_map(|phi| {
+                let expr = scev.get_scev(allocs, ValueSSA::Inst(phi.raw_into()))?;
+                let rec = expr.as_addrec()?;
+                (rec.header == header).then(|| (phi, rec.clone()))
+            })
+            .collect()
+    }
+
+    /// 把步长与主归纳变量相同的归纳变量改写成 `主归纳变量 + 起始值之差`.
+    fn merge_ivs(&mut self, scev: &ScalarEvolution, l: usize, shape: LoopShape) {
+        let allocs = &self.module.allocs;
+        let ivs = self.loop_ivs(scev, shape.header);
+        if ivs.len() < 2 {
+            return;
+        }
+        let exit_iv = scev.exit_compare(allocs, l).map(|ec| ec.iv);
+        let primary_idx = ivs
+            .iter()
+            .position(|(phi, _)| {
+                let phi_val = ValueSSA::Inst(phi.raw_into());
+                let next = phi
+                    .deref_ir(allocs)
+                    .find_incoming_value(allocs, shape.latch);
+                exit_iv.is_some_and(|iv| iv == phi_val || Some(iv) == next)
+            })
+            .or_else(|| {
+                ivs.iter().position(|(_, rec)| {
+                    rec.start.is_zero() && rec.step.as_const().is_some_and(|s| s.as_unsigned() == 1)
+                })
+            })
+            .unwrap_or(0);
+        let (primary, primary_rec) = ivs[primary_idx].clone();
+        let primary = ValueSSA::Inst(primary.raw_into());
+
+        let mut expander = ScevExpander::new(self.module, scev, shape);
+        for (i, (phi, rec)) in ivs.into_iter().enumerate() {
+            if i == primary_idx
+                || rec.start.bits() != primary_rec.start.bits()
+                || rec.step != primary_rec.step
+            {
+                continue;
+            }
+            let diff = Scev::new_sub(rec.start.clone(), primary_rec.start.clone());
+            let merged = if diff.is_zero() {
+                primary
+            } else {
+                let Some(diff) = expander.expand(&diff) else {
+                    continue;
+                };
+                let add = BinOPInstID::new(allocs, Opcode::Add, primary, diff);
+                shape
+                    .header
+                    .get_insts(allocs)
+                    .node_add_next(
+                        shape.header.get_phi_end(allocs),
+                        add.raw_into(),
+                        &allocs.insts,
+                    )
+                    .expect("Internal error: failed to insert merged induction variable");
+                ValueSSA::Inst(add.raw_into())
+            };
+            phi.deref_ir(allocs)
+                .replace_self_with(allocs, merged)
+                .expect("Internal error: failed to replace merged induction variable");
+            self.num_merged_ivs += 1;
+        }
+    }
+
+    /// 把地址或值是本循环递推式的 GEP 与乘法替换成新的归纳变量.
+    fn reduce(&mut self, scev: &ScalarEvolution, l: usize, shape: LoopShape) {
+        let Module { allocs, tctx, .. } = self.module;
+        let lp = &scev.loops.loops[l];
+        let ptr_bits = tctx.arch.ptr_nbits as u8;
+        let is_own_rec = |rec: &ScevAddRec| rec.header == shape.header && !rec.step.is_zero();
+
+        let mut geps: Vec<(InstID, ValueSSA, ScevAddRec)> = Vec::new();
+        let mut muls: Vec<(InstID, ScevAddRec)> = Vec::new();
+        for &block in &lp.block_list {
+            for (inst_id, inst) in block.insts_iter(allocs) {
+                match inst {
+                    InstObj::GEP(_) => {
+                        let gep = GEPInstID::raw_from(inst_id);
+                        let Some(GEPOffset { base, offset }) = scev.gep_offset(allocs, tctx, gep)
+                        else {
+                            continue;
+                        };
+                        let base_expr = Scev::new_unknown(base, ptr_bits);
+                        if let Some(rec) = offset.as_addrec()
+                            && is_own_rec(rec)
+                            && scev.is_loop_invariant(allocs, &base_expr, lp)
+                        {
+                            geps.push((inst_id, base, rec.clone()));
+                        }
+                    }
+                    InstObj::BinOP(binop)
+                        if matches!(binop.get_opcode(), Opcode::Mul | Opcode::Shl) =>
+                    {
+                        if let Some(expr) = scev.get_scev(allocs, ValueSSA::Inst(inst_id))
+                            && let Some(rec) = expr.as_addrec()
+                            && is_own_rec(rec)
+                        {
+                            muls.push((inst_id, rec.clone()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // 先处理 GEP: 地址被替换之后, 下标计算中的乘法往往也随之变成死代码
+        let mut expander = ScevExpander::new(self.module, scev, shape);
+        for (gep, base, rec) in geps {
+            let (Some(start), Some(step)) =
+                (expander.expand(&rec.start), expander.expand(&rec.step))
+            else {
+                continue;
+            };
+            let init =
+                if rec.start.is_zero() { base } else { expander.emit_byte_offset(base, start) };
+            self.replace_with_iv(gep, ValTypeID::Ptr, init, shape, |phi| {
+                GEPInstID::builder(tctx, allocs, ValTypeID::Int(8))
+                    .base_ptr(phi)
+                    .add_indices(&[step])
+                    .build_id()
+                    .raw_into()
+            });
+        }
+        let removed = self.remove_dead_insts(lp);
+        for (mul, rec) in muls {
+            if removed.contains(&mul) {
+                continue;
+            }
+            let (Some(init), Some(step)) =
+                (expander.expand(&rec.start), expander.expand(&rec.step))
+            else {
+                continue;
+            };
+            let ty = mul.deref_ir(allocs).get_valtype();
+            self.replace_with_iv(mul, ty, init, shape, |phi| {
+                BinOPInstID::new(allocs, Opcode::Add, phi, step).raw_into()
+            });
+        }
+    }
+
+    /// 用新的循环头 Phi 替换 `inst`. 新 Phi 从 `init` 开始, 在 latch 中由 `make_next` 算出下一次迭代的值.
+    fn replace_with_iv(
+        &mut self,
+        inst: InstID,
+        ty: ValTypeID,
+        init: ValueSSA,
+        shape: LoopShape,
+        make_next: impl FnOnce(ValueSSA) -> InstID,
+    ) {
+        let allocs = &self.module.allocs;
+        let phi = PhiInstID::from_incomings(allocs, ty, [(shape.preheader, init)]);
+        let phi_val = ValueSSA::Inst(phi.raw_into());
+        let next = make_next(phi_val);
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(IRFocus::Block(shape.header));
+        builder
+            .insert_inst(phi)
+            .expect("Internal error: failed to insert induction variable");
+        builder.set_focus(IRFocus::Block(shape.latch));
+        builder
+            .insert_inst(next)
+            .expect("Internal error: failed to insert induction variable increment");
+        phi.deref_ir(allocs)
+            .set_incoming(allocs, shape.latch, ValueSSA::Inst(next));
+        inst.deref_ir(allocs)
+            .replace_self_with(allocs, phi_val)
+            .expect("Internal error: failed to replace strength-reduced instruction");
+        self.num_reduced += 1;
+    }
+
+    /// 删除死代码和只被自身递增指令使用的归纳变量.
+    fn remove_dead(&mut self, lp: &Loop, shape: LoopShape) {
+        let allocs = &self.module.allocs;
+        self.remove_dead_insts(lp);
+        for phi in block_phis(allocs, shape.header) {
+            let phi_id = phi.raw_into();
+            let phi_obj = phi.deref_ir(allocs);
+            let Some(ValueSSA::Inst(next)) = phi_obj.find_incoming_value(allocs, shape.latch)
+            else {
+                continue;
+            };
+            if next == phi_id || !is_pure(next.deref_ir(allocs)) {
+                continue;
+            }
+            let only_used_by = |inst: InstID, user: InstID| {
+                inst.deref_ir(allocs)
+                    .user_iter(allocs)
+                    .all(|(_, u)| u.user.get() == Some(UserID::Inst(user)))
+            };
+            if !only_used_by(phi_id, next) || !only_used_by(next, phi_id) {
+                continue;
+            }
+            // 先断开 Phi 对递增指令的使用, 两条指令就都没有使用者了
+            phi_obj.remove_incoming(allocs, shape.latch);
+            self.erase_inst(next);
+            self.erase_inst(phi_id);
+            self.num_dead_ivs += 1;
+        }
+        self.remove_dead_insts(lp);
+    }
+
+    /// 反复删除循环中没有使用者的纯计算指令, 返回被删除的指令.
+    fn remove_dead_insts(&self, lp: &Loop) -> HashSet<InstID> {
+        let allocs = &self.module.allocs;
+        let mut removed = HashSet::new();
+        loop {
+            let dead: Vec<InstID> = lp
+                .block_list
+                .iter()
+                .flat_map(|&block| {
+                    block
+                        .insts_iter(allocs)
+                        .filter(|(_, inst)| {
+                            is_pure(inst) && inst.user_iter(allocs).next().is_none()
+                        })
+                        .map(|(id, _)| id)
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            if dead.is_empty() {
+                return removed;
+            }
+            for inst in dead {
+                self.erase_inst(inst);
+                removed.insert(inst);
+            }
+        }
+    }
+
+    fn erase_inst(&self, inst: InstID) {
+        IRBuilder::new(self.module)
+            .remove_inst(inst)
+            .expect("Internal error: failed to remove dead instruction");
+        inst.dispose(&self.module.allocs).unwrap();
+    }
+}
+
+/// 没有副作用, 可以在没有使用者时直接删除的指令.
+fn is_pure(inst: &InstObj) -> bool {
+    matches!(
+        inst,
+        InstObj::BinOP(_)
+            | InstObj::Cast(_)
+            | InstObj::Cmp(_)
+            | InstObj::GEP(_)
+            | InstObj::Phi(_)
+            | InstObj::Select(_)
+    )
+}
+
+/// 在 preheader 中把循环不变的表达式展开成 IR. 相同的子表达式只展开一次.
+struct ScevExpander<'a> {
+    module: &'a Module,
+    scev: &'a ScalarEvolution,
+    shape: LoopShape,
+    cache: HashMap<ScevRef, ValueSSA>,
+}
+
+impl<'a> ScevExpander<'a> {
+    fn new(module: &'a Module, scev: &'a ScalarEvolution, shape: LoopShape) -> Self {
+        Self { module, scev, shape, cache: HashMap::new() }
+    }
+
+    fn expand(&mut self, expr: &ScevRef) -> Option<ValueSSA> {
+        let allocs = &self.module.allocs;
+        if let Some(&value) = self.cache.get(expr) {
+            return Some(value);
+        }
+        let cast = |this: &mut Self, opcode: Opcode, from: &ScevRef, bits: u8| {
+            let from = this.expand(from)?;
+            Some(this.emit(CastInstID::new(allocs, opcode, from, ValTypeID::Int(bits)).raw_into()))
+        };
+        let value = match &**expr {
+            Scev::Const(c) => ValueSSA::from(*c),
+            Scev::Unknown(value, _) => *value,
+            Scev::Add(lhs, rhs) => {
+                let (lhs, rhs) = (self.expand(lhs)?, self.expand(rhs)?);
+                self.emit(BinOPInstID::new(allocs, Opcode::Add, lhs, rhs).raw_into())
+            }
+            Scev::Mul(lhs, rhs) => {
+                let (lhs, rhs) = (self.expand(lhs)?, self.expand(rhs)?);
+                self.emit(BinOPInstID::new(allocs, Opcode::Mul, lhs, rhs).raw_into())
+            }
+            Scev::SExt(from, bits) => cast(self, Opcode::Sext, from, *bits)?,
+            Scev::ZExt(from, bits) => cast(self, Opcode::Zext, from, *bits)?,
+            Scev::Trunc(from, bits) => cast(self, Opcode::Trunc, from, *bits)?,
+            Scev::AddRec(rec) => {
+                // start + step * iv, 其中 iv 是外层循环的 {0,+,1}
+                let iv = self.canonical_iv(rec)?;
+                let (start, step) = (self.expand(&rec.start)?, self.expand(&rec.step)?);
+                let scaled = self.emit(BinOPInstID::new(allocs, Opcode::Mul, step, iv).raw_into());
+                self.emit(BinOPInstID::new(allocs, Opcode::Add, start, scaled).raw_into())
+            }
+        };
+        self.cache.insert(expr.clone(), value);
+        Some(value)
+    }
+
+    /// 包含当前循环的外层循环 `rec.header` 中从 0 开始每次加 1 的归纳变量, 必要时符号扩展到 `rec` 的位宽.
+    fn canonical_iv(&mut self, rec: &ScevAddRec) -> Option<ValueSSA> {
+        let allocs = &self.module.allocs;
+        let loops = &self.scev.loops;
+        let outer = loops.loop_of(rec.header)?;
+        if loops.loops[outer].header != rec.header
+            || !loops.loops[outer].contains(self.shape.header)
+        {
+            return None;
+        }
+        let bits = rec.start.bits();
+        let (iv, iv_bits) = block_phis(allocs, rec.header).into_iter().find_map(|phi| {
+            let value = ValueSSA::Inst(phi.raw_into());
+            let expr = self.scev.get_scev(allocs, value)?;
+            let iv_rec = expr.as_addrec()?;
+            let canonical = iv_rec.header == rec.header
+                && iv_rec.start.is_zero()
+                && iv_rec.step.as_const().is_some_and(|s| s.as_unsigned() == 1);
+            let fits = expr.bits() == bits || (expr.bits() < bits && iv_rec.nsw);
+            (canonical && fits).then_some((value, expr.bits()))
+        })?;
+        if iv_bits == bits {
+            return Some(iv);
+        }
+        Some(self.emit(CastInstID::new(allocs, Opcode::Sext, iv, ValTypeID::Int(bits)).raw_into()))
+    }
+
+    /// 在 preheader 中计算 `base + offset` 字节处的地址.
+    fn emit_byte_offset(&mut self, base: ValueSSA, offset: ValueSSA) -> ValueSSA {
+        let Module { allocs, tctx, .. } = self.module;
+        let gep = GEPInstID::builder(tctx, allocs, ValTypeID::Int(8))
+            .base_ptr(base)
+            .add_indices(&[offset])
+            .build_id();
+        self.emit(gep.raw_into())
+    }
+
+    fn emit(&mut self, inst: InstID) -> ValueSSA {
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(IRFocus::Block(self.shape.preheader));
+        builder
+            .insert_inst(inst)
+            .expect("Internal error: failed to insert expanded expression");
+        ValueSSA::Inst(inst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            IRWriteOption, ISubGlobalID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        opt::Mem2Reg,
+        testing::cases::{test_case_array_sum, test_case_matrix_fill},
+    };
+
+    fn main_after_mem2reg(module: &Module) -> FuncID {
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(module).run_on_func(func);
+        func
+    }
+
+    fn count_insts(module: &Module, func: FuncID, pred: impl Fn(&InstObj) -> bool) -> usize {
+        let allocs = &module.allocs;
+        func.blocks_unwrap(allocs)
+            .iter(&allocs.blocks)
+            .map(|(block, _)| {
+                block
+                    .insts_iter(allocs)
+                    .filter(|(_, inst)| pred(inst))
+                    .count()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_lsr_array_sum() {
+        let module = test_case_array_sum().module;
+        let func = main_after_mem2reg(&module);
+        let mut pass = LoopStrengthReduce::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-lsr-array-sum.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(&module.allocs, func);
+
+        // a[i] 的地址变成每次加 4 字节的指针归纳变量, 下标的符号扩展随之被删除
+        assert_eq!(pass.num_reduced, 1);
+        let is_sext =
+            |inst: &InstObj| matches!(inst, InstObj::Cast(c) if c.get_opcode() == Opcode::Sext);
+        assert_eq!(count_insts(&module, func, is_sext), 0);
+        let is_ptr_phi = |inst: &InstObj| {
+            matches!(inst, InstObj::Phi(_)) && inst.get_valtype() == ValTypeID::Ptr
+        };
+        assert_eq!(count_insts(&module, func, is_ptr_phi), 1);
+    }
+
+    #[test]
+    fn test_lsr_matrix_fill() {
+        let module = test_case_matrix_fill().module;
+        let func = main_after_mem2reg(&module);
+        let mut pass = LoopStrengthReduce::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-lsr-matrix-fill.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(&module.allocs, func);
+
+        // 内层循环中的 i * 4 变成外层循环中每次加 4 的归纳变量
+        assert!(pass.num_reduced >= 1);
+        let is_mul =
+            |inst: &InstObj| matches!(inst, InstObj::BinOP(b) if b.get_opcode() == Opcode::Mul);
+        assert_eq!(count_insts(&module, func, is_mul), 0);
+    }
+}
@@ -0,0 +1,477 @@
+//! LoopUnroll: 展开已知迭代次数的循环.
+//!
+//! 这个 Pass 只处理 LoopRotate 之后的 `do-while` 形式的最内层循环: latch 是唯一的出口块,
+//! 它以 `icmp` 比较一个常量递推式和常量边界. 设循环体执行 `n` 次:
+//!
+//! * 完全展开: `n` 份循环体的大小不超过 `full_unroll_threshold` 时, 把循环体复制 `n` 份顺序连接, 删除回边;
+//! * 部分展开: 否则按 `unroll_factor` 把循环体复制 `factor` 份. 展开后的主循环执行 `n / factor` 次,
+//!   其中只有最后一份循环体保留退出判断; 剩下的 `n % factor` 次迭代由主循环之后的余数循环 (原循环的副本) 完成.
+//!
+//! 循环体按基本块复制, 每一份副本都有自己的 [`FuncCloneMapping`]. 副本循环头中的 Phi 被替换为上一份副本在
+//! latch 处的传入值, 出口块中的 Phi 改为接收最后一份副本的值.
+
+use crate::{
+    SymbolStr,
+    ir::{
+        BlockID, CmpCond, FuncCloneMapping, FuncID, IRBuilder, IRFocus, ISubInstID, ISubValueSSA,
+        ITraceableValue, InstID, InstObj, Module, Opcode, TerminatorID, UseID, ValueSSA,
+        clone_blocks,
+        inst::{BrInstID, CmpInstID, JumpInstID, PhiInstID},
+    },
+    opt::{
+        CfgSnapshot, ExitCompare, Loop, LoopRotate, ScalarEvolution,
+        transforms::{IFuncTransformPass, block_phis, loop_size, use_block},
+    },
+};
+use smallvec::SmallVec;
+use std::collections::HashSet;
+
+pub struct LoopUnroll<'ir> {
+    pub module: &'ir Module,
+    /// 完全展开后循环体最多包含多少条指令.
+    pub full_unroll_threshold: usize,
+    /// 部分展开的展开因子. 小于 2 时不做部分展开.
+    pub unroll_factor: usize,
+    /// 部分展开后循环体最多包含多少条指令.
+    pub partial_unroll_threshold: usize,
+    pub num_fully_unrolled: usize,
+    pub num_partially_unrolled: usize,
+}
+
+impl<'ir> IFuncTransformPass for LoopUnroll<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("LoopUnroll")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        LoopRotate::new(self.module).run_on_func(func);
+        let mut visited: HashSet<BlockID> = HashSet::new();
+        loop {
+            let (Ok(scev), Ok(cfg)) = (
+                ScalarEvolution::new(allocs, func),
+                CfgSnapshot::new(allocs, func),
+            ) else {
+                return;
+            };
+            let unrolled = scev.loops.innermost_first().any(|l| {
+                visited.insert(scev.loops.loops[l].header) && self.unroll_loop(&scev, &cfg, l)
+            });
+            if !unrolled {
+                break;
+            }
+        }
+    }
+}
+
+/// 展开一个循环所需的信息.
+struct UnrollShape {
+    preheader: BlockID,
+    header: BlockID,
+    latch: BlockID,
+    exit: BlockID,
+    exit_cmp: ExitCompare,
+    trip_count: u128,
+    factor: u128,
+}
+
+impl UnrollShape {
+    fn main_iters(&self) -> u128 {
+        self.trip_count / self.factor
+    }
+    fn remainder(&self) -> u128 {
+        self.trip_count % self.factor
+    }
+}
+
+impl<'ir> LoopUnroll<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self {
+            module,
+            full_unroll_threshold: 128,
+            unroll_factor: 4,
+            partial_unroll_threshold: 128,
+            num_fully_unrolled: 0,
+            num_partially_unrolled: 0,
+        }
+    }
+
+    fn unroll_loop(&mut self, scev: &ScalarEvolution, cfg: &CfgSnapshot, l: usize) -> bool {
+        let Some(shape) = self.check_shape(scev, cfg, l) else {
+            return false;
+        };
+        let lp = &scev.loops.loops[l];
+        self.unroll(lp, &shape);
+        if shape.main_iters() == 1 && shape.remainder() == 0 {
+            self.num_fully_unrolled += 1;
+        } else {
+            self.num_partially_unrolled += 1;
+        }
+        true
+    }
+
+    fn check_shape(
+        &self,
+        scev: &ScalarEvolution,
+        cfg: &CfgSnapshot,
+        l: usize,
+    ) -> Option<UnrollShape> {
+        let allocs = &self.module.allocs;
+        let lp = &scev.loops.loops[l];
+        if !lp.children.is_empty() || !lp.has_dedicated_exits(cfg) {
+            return None;
+        }
+        let preheader = lp.preheader(cfg)?;
+        let latch = lp.single_latch()?;
+        let exit_cmp = scev.exit_compare(allocs, l)?;
+        if exit_cmp.exiting != latch {
+            return None;
+        }
+        let [exit] = lp.exit_blocks(cfg)[..] else {
+            return None;
+        };
+        let trip_count = scev.trip_count(allocs, l)?;
+        let size = loop_size(allocs, lp) as u128;
+        let factor = if trip_count.checked_mul(size)? <= self.full_unroll_threshold as u128 {
+            trip_count
+        } else {
+            let factor = self.unroll_factor as u128;
+            if factor < 2
+                || trip_count < factor
+                || size * factor > self.partial_unroll_threshold as u128
+            {
+                return None;
+            }
+            factor
+        };
+        let shape = UnrollShape {
+            preheader,
+            header: lp.header,
+            latch,
+            exit,
+            exit_cmp,
+            trip_count,
+            factor,
+        };
+        // 展开后的退出判断需要知道归纳变量在最后一次迭代时的值
+        if shape.main_iters() > 1 {
+            shape
+                .exit_cmp
+                .rec
+                .evaluate_at(shape.main_iters() * factor - 1)?;
+        }
+        Some(shape)
+    }
+
+    fn unroll(&mut self, lp: &Loop, shape: &UnrollShape) {
+        let allocs = &self.module.allocs;
+        let UnrollShape { preheader, header, latch, exit, factor, .. } = *shape;
+        let func = header
+            .get_parent_func(allocs)
+            .expect("Internal error: loop header is not attached to a function");
+        self.form_exit_phis(lp, latch, exit);
+
+        // 循环头 Phi 的初始值和下一次迭代的值
+        let header_phis: SmallVec<[(PhiInstID, ValueSSA); 4]> = block_phis(allocs, header)
+            .into_iter()
+            .map(|phi| {
+                let next = phi
+                    .deref_ir(allocs)
+                    .find_incoming_value(allocs, latch)
+                    .expect("Internal error: header phi has no incoming from latch");
+                (phi, next)
+            })
+            .collect();
+        let exit_phis = block_phis(allocs, exit);
+        let phi_in = |mapping: &FuncCloneMapping, phi: PhiInstID| {
+            PhiInstID::raw_from(mapping.insts[&phi.raw_into()])
+        };
+
+        // 1. 余数循环: 原循环的一份完整副本, 从主循环结束时的值开始迭代
+        let remainder = if shape.remainder() > 0 {
+            let rem_pre = BlockID::new_uninit(allocs);
+            func.blocks_unwrap(allocs)
+                .node_add_prev(exit, rem_pre, &allocs.blocks)
+                .expect("Internal error: failed to insert remainder preheader");
+            let rem = clone_blocks(self.module, &lp.block_list, exit);
+            let jump = JumpInstID::with_target(allocs, rem.map_block(header));
+            rem_pre.set_terminator_inst(allocs, jump.raw_into());
+            for &(phi, _) in &header_phis {
+                phi_in(&rem, phi)
+                    .deref_ir(allocs)
+                    .remove_incoming(allocs, preheader);
+            }
+            for &phi in &exit_phis {
+                let phi = phi.deref_ir(allocs);
+                if let Some(v) = phi.find_incoming_value(allocs, latch) {
+                    phi.set_incoming(allocs, rem.map_block(latch), rem.map_or_keep(v));
+                }
+            }
+            Some((rem_pre, rem))
+        } else {
+            None
+        };
+        let out_target = remainder.as_ref().map_or(exit, |(rem_pre, _)| *rem_pre);
+
+        // 2. 复制循环体. 第 0 份就是原循环本身
+        let copies: Vec<FuncCloneMapping> = (1..factor)
+            .map(|_| clone_blocks(self.module, &lp.block_list, out_target))
+            .collect();
+        let map_val = |k: u128, v: ValueSSA| match k {
+            0 => v,
+            k => copies[k as usize - 1].map_or_keep(v),
+        };
+        let map_bb = |k: u128, bb: BlockID| match k {
+            0 => bb,
+            k => copies[k as usize - 1].map_block(bb),
+        };
+
+        // 3. 第 k - 1 份的 latch 直接跳转到第 k 份的循环头, 第 k 份循环头的 Phi 只剩这一个传入值
+        for k in 1..factor {
+            let prev_latch = map_bb(k - 1, latch);
+            self.replace_terminator(
+                prev_latch,
+                JumpInstID::with_target(allocs, map_bb(k, header)),
+            );
+            for &(phi, next) in &header_phis {
+                let copy_phi = phi_in(&copies[k as usize - 1], phi).deref_ir(allocs);
+                copy_phi.remove_incoming(allocs, preheader);
+                copy_phi.remove_incoming(allocs, map_bb(k, latch));
+                copy_phi.set_incoming(allocs, prev_latch, map_val(k - 1, next));
+            }
+        }
+
+        // 4. 最后一份的 latch 负责退出判断
+        let last = factor - 1;
+        let last_latch = map_bb(last, latch);
+        for &(phi, _) in &header_phis {
+            phi.deref_ir(allocs).remove_incoming(allocs, latch);
+        }
+        if shape.main_iters() > 1 {
+            let iv = map_val(last, shape.exit_cmp.iv);
+            let final_iv = shape
+                .exit_cmp
+                .rec
+                .evaluate_at(shape.main_iters() * factor - 1)
+                .expect("Internal error: checked in check_shape");
+            let cmp =
+                CmpInstID::new_uninit(allocs, Opcode::Icmp, CmpCond::NE, iv.get_valtype(allocs));
+            cmp.set_lhs(allocs, iv);
+            cmp.set_rhs(allocs, final_iv.into());
+            let mut builder = IRBuilder::new(self.module);
+            builder.set_focus(IRFocus::Block(last_latch));
+            builder
+                .insert_inst(cmp)
+                .expect("Internal error: failed to insert unrolled exit compare");
+            let cond = ValueSSA::Inst(cmp.raw_into());
+            self.replace_terminator(last_latch, BrInstID::new(allocs, cond, header, out_target));
+            for &(phi, next) in &header_phis {
+                phi.deref_ir(allocs)
+                    .set_incoming(allocs, last_latch, map_val(last, next));
+            }
+        } else {
+            self.replace_terminator(last_latch, JumpInstID::with_target(allocs, out_target));
+        }
+        match &remainder {
+            Some((rem_pre, rem)) => {
+                for &phi in &exit_phis {
+                    phi.deref_ir(allocs).remove_incoming(allocs, latch);
+                }
+                for &(phi, next) in &header_phis {
+                    phi_in(rem, phi).deref_ir(allocs).set_incoming(
+                        allocs,
+                        *rem_pre,
+                        map_val(last, next),
+                    );
+                }
+            }
+            None => {
+                for &phi in &exit_phis {
+                    let phi = phi.deref_ir(allocs);
+                    if let Some(v) = phi.remove_incoming(allocs, latch) {
+                        phi.set_incoming(allocs, last_latch, map_val(last, v));
+                    }
+                }
+            }
+        }
+
+        // 5. 只剩一个传入值的 Phi 可以直接替换掉
+        let mut single_phis: Vec<PhiInstID> = copies
+            .iter()
+            .flat_map(|copy| header_phis.iter().map(|&(phi, _)| phi_in(copy, phi)))
+            .collect();
+        if shape.main_iters() == 1 {
+            single_phis.extend(header_phis.iter().map(|&(phi, _)| phi));
+        }
+        for phi in single_phis {
+            self.fold_single_phi(phi);
+        }
+    }
+
+    /// 让循环中定义的值只通过出口块中的 Phi 在循环外使用, 这样展开后只需要修复出口块的 Phi.
+    fn form_exit_phis(&self, lp: &Loop, latch: BlockID, exit: BlockID) {
+        let allocs = &self.module.allocs;
+        let defs: Vec<InstID> = lp
+            .block_list
+            .iter()
+            .flat_map(|&block| {
+                block
+                    .insts_iter(allocs)
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(IRFocus::Block(exit));
+        for def in defs {
+            let outside: SmallVec<[UseID; 4]> = def
+                .deref_ir(allocs)
+                .user_iter(allocs)
+                .map(|(use_id, _)| use_id)
+                .filter(|&use_id| use_block(allocs, use_id).is_some_and(|b| !lp.contains(b)))
+                .collect();
+            if outside.is_empty() {
+                continue;
+            }
+            let ty = def.deref_ir(allocs).get_valtype();
+            let phi = PhiInstID::from_incomings(allocs, ty, [(latch, ValueSSA::Inst(def))]);
+            builder
+                .insert_inst(phi)
+                .expect("Internal error: failed to insert exit phi");
+            for use_id in outside {
+                use_id.set_operand(allocs, ValueSSA::Inst(phi.raw_into()));
+            }
+        }
+    }
+
+    /// 替换基本块的终结指令. 被替换掉的分支条件如果不再被使用, 也一并删除.
+    fn replace_terminator(&self, block: BlockID, terminator: impl ISubInstID) {
+        let allocs = &self.module.allocs;
+        let old_cond = match block.get_terminator(allocs) {
+            TerminatorID::Br(br) => br.get_cond(allocs),
+            _ => ValueSSA::None,
+        };
+        // 旧的终结指令由 ManagedInst 负责 dispose
+        drop(block.set_terminator_inst(allocs, terminator.raw_into()));
+        let ValueSSA::Inst(cond) = old_cond else {
+            return;
+        };
+        let obj = cond.deref_ir(allocs);
+        if !matches!(obj, InstObj::Cmp(_)) || obj.user_iter(allocs).next().is_some() {
+            return;
+        }
+        IRBuilder::new(self.module)
+            .remove_inst(cond)
+            .expect("Internal error: failed to remove dead branch condition");
+        cond.dispose(allocs).unwrap();
+    }
+
+    fn fold_single_phi(&self, phi: PhiInstID) {
+        let allocs = &self.module.allocs;
+        let phi_obj = phi.deref_ir(allocs);
+        let incomings = phi_obj.incoming_uses();
+        let [[uval, _]] = incomings[..] else {
+            return;
+        };
+        let value = uval.get_operand(allocs);
+        drop(incomings);
+        phi_obj
+            .replace_self_with(allocs, value)
+            .expect("Internal error: failed to replace single-incoming phi");
+        IRBuilder::new(self.module)
+            .remove_inst(phi)
+            .expect("Internal error: failed to remove single-incoming phi");
+        phi.raw_into().dispose(allocs).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            IRWriteOption, ISubGlobalID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        opt::{LoopInfo, Mem2Reg},
+        testing::cases::test_case_array_sum,
+    };
+
+    fn array_sum_main() -> (Module, FuncID) {
+        let module = test_case_array_sum().module;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(&module).run_on_func(func);
+        (module, func)
+    }
+
+    #[test]
+    fn test_loop_unroll_partial() {
+        let (module, func) = array_sum_main();
+        let allocs = &module.allocs;
+        let mut pass = LoopUnroll::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-loop-unroll-partial.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_partially_unrolled, 1);
+        assert_eq!(pass.num_fully_unrolled, 0);
+
+        // 100 次迭代按 4 展开, 没有余数循环
+        let scev = ScalarEvolution::new(allocs, func).unwrap();
+        assert_eq!(scev.loops.loops.len(), 1);
+        assert_eq!(scev.trip_count(allocs, 0), Some(25));
+        let iv = scev.exit_compare(allocs, 0).unwrap().rec;
+        assert_eq!(iv.step.as_const().map(|s| s.as_unsigned()), Some(4));
+    }
+
+    #[test]
+    fn test_loop_unroll_remainder() {
+        let (module, func) = array_sum_main();
+        let allocs = &module.allocs;
+        let mut pass = LoopUnroll::new(&module);
+        pass.unroll_factor = 3;
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-loop-unroll-remainder.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_partially_unrolled, 1);
+
+        // 主循环执行 33 次, 余数循环执行 1 次
+        let scev = ScalarEvolution::new(allocs, func).unwrap();
+        assert_eq!(scev.loops.loops.len(), 2);
+        let main_loop = scev
+            .loops
+            .top_level()
+            .find(|&l| scev.trip_count(allocs, l).is_some())
+            .expect("main loop should have a constant trip count");
+        assert_eq!(scev.trip_count(allocs, main_loop), Some(33));
+    }
+
+    #[test]
+    fn test_loop_unroll_full() {
+        let (module, func) = array_sum_main();
+        let allocs = &module.allocs;
+        let mut pass = LoopUnroll::new(&module);
+        pass.full_unroll_threshold = 1024;
+        pass.run_on_func(func);
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_fully_unrolled, 1);
+        assert!(LoopInfo::new(allocs, func).unwrap().is_empty());
+    }
+}
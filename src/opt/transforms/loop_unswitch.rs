@@ -0,0 +1,306 @@
+//! LoopUnswitch: 把循环不变的条件分支提到循环外.
+//!
+//! 如果循环中某条 `br` 的条件在循环内不变, 就把整个循环复制一份, 在 preheader 中按这个条件选择进入哪一份:
+//! 原循环中这条分支固定走 `then` 分支, 副本中固定走 `else` 分支. 两份循环中走不到的块随后被删除.
+//!
+//! 为了让循环外的使用者能同时看到两份循环算出的值, 复制之前先在出口块中为这些值插入 Phi (LCSSA 形式).
+//! 条件本身如果是循环内的 `icmp`/`fcmp`, 且操作数都在循环外定义, 会先被移动到 preheader 中.
+
+use crate::{
+    SymbolStr,
+    ir::{
+        BlockID, FuncID, IRAllocs, IRBuilder, IRFocus, ISubInstID, ITraceableValue, IUser, InstID,
+        InstObj, Module, TerminatorID, UseID, ValueSSA, clone_blocks,
+        inst::{BrInstID, JumpInstID, PhiInstID},
+    },
+    opt::{
+        CfgSnapshot, DominatorTree, Loop, LoopInfo, LoopSimplify,
+        transforms::{
+            IFuncTransformPass, block_phis, loop_size, remove_unreachable_blocks, use_block,
+        },
+    },
+};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+
+pub struct LoopUnswitch<'ir> {
+    pub module: &'ir Module,
+    /// 只复制指令条数不超过这个值的循环.
+    pub size_threshold: usize,
+    pub num_unswitched: usize,
+}
+
+impl<'ir> IFuncTransformPass for LoopUnswitch<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("LoopUnswitch")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        // 每次外提都会让两份循环各少一条条件分支, 所以这个循环一定会结束
+        loop {
+            LoopSimplify::new(self.module).run_on_func(func);
+            let (Ok(cfg), Ok(dom)) = (
+                CfgSnapshot::new(allocs, func),
+                DominatorTree::builder(allocs, func).map(|b| b.build()),
+            ) else {
+                return;
+            };
+            let loops = LoopInfo::from_dom(&cfg, &dom);
+            let unswitched = loops
+                .innermost_first()
+                .any(|l| self.try_unswitch(func, &cfg, &dom, &loops.loops[l]));
+            if !unswitched {
+                break;
+            }
+        }
+    }
+}
+
+/// 一次外提需要的信息.
+struct UnswitchPlan {
+    preheader: BlockID,
+    /// 条件分支所在的块.
+    block: BlockID,
+    cond: ValueSSA,
+    then_bb: BlockID,
+    else_bb: BlockID,
+    exits: SmallVec<[BlockID; 4]>,
+    /// 循环外的使用者, 以及支配这些使用者的出口块.
+    outside_uses: Vec<(InstID, UseID, BlockID)>,
+}
+
+impl<'ir> LoopUnswitch<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, size_threshold: 64, num_unswitched: 0 }
+    }
+
+    fn try_unswitch(
+        &mut self,
+        func: FuncID,
+        cfg: &CfgSnapshot,
+        dom: &DominatorTree,
+        lp: &Loop,
+    ) -> bool {
+        let Some(plan) = self.plan(cfg, dom, lp) else {
+            return false;
+        };
+        self.unswitch(func, cfg, lp, plan);
+        self.num_unswitched += 1;
+        true
+    }
+
+    fn plan(&self, cfg: &CfgSnapshot, dom: &DominatorTree, lp: &Loop) -> Option<UnswitchPlan> {
+        let allocs = &self.module.allocs;
+        let preheader = lp.preheader(cfg)?;
+        if !lp.has_dedicated_exits(cfg) || loop_size(allocs, lp) > self.size_threshold {
+            return None;
+        }
+        let (block, cond, then_bb, else_bb) = lp.block_list.iter().find_map(|&block| {
+            let TerminatorID::Br(br) = block.get_terminator(allocs) else {
+                return None;
+            };
+            let cond = br.get_cond(allocs);
+            let (then_bb, else_bb) = (br.get_then(allocs)?, br.get_else(allocs)?);
+            (then_bb != else_bb && self.cond_is_invariant(lp, cond))
+                .then_some((block, cond, then_bb, else_bb))
+        })?;
+
+        let exits = lp.exit_blocks(cfg);
+        let mut outside_uses = Vec::new();
+        for &def_bb in &lp.block_list {
+            for (def, _) in def_bb.insts_iter(allocs) {
+                for (use_id, _) in def.deref_ir(allocs).user_iter(allocs) {
+                    let use_bb = use_block(allocs, use_id)?;
+                    if lp.contains(use_bb) {
+                        continue;
+                    }
+                    // 使用者必须被某个出口块支配, 并且定义支配这个出口块的所有前驱
+                    let exit = exits.iter().copied().find(|&exit| {
+                        dom.block_dominates_block(exit, use_bb)
+                            && cfg
+                                .pred_of(exit)
+                                .unwrap_or(&[])
+                                .iter()
+                                .all(|&pred| dom.block_dominates_block(def_bb, pred))
+                    })?;
+                    outside_uses.push((def, use_id, exit));
+                }
+            }
+        }
+        Some(UnswitchPlan {
+            preheader,
+            block,
+            cond,
+            then_bb,
+            else_bb,
+            exits,
+            outside_uses,
+        })
+    }
+
+    /// 条件在循环内不变: 在循环外定义, 或者是操作数都在循环外定义 (或是常量) 的比较指令.
+    fn cond_is_invariant(&self, lp: &Loop, cond: ValueSSA) -> bool {
+        let allocs = &self.module.allocs;
+        let defined_outside = |value: ValueSSA| match value {
+            ValueSSA::Inst(inst) => inst.get_parent(allocs).is_some_and(|b| !lp.contains(b)),
+            ValueSSA::None | ValueSSA::Block(_) => false,
+            _ => true,
+        };
+        match cond {
+            // 常量条件留给 SCCP 处理
+            ValueSSA::ConstData(_) => false,
+            ValueSSA::Inst(inst) if !defined_outside(cond) => match inst.deref_ir(allocs) {
+                InstObj::Cmp(cmp) => cmp
+                    .operands_iter()
+                    .all(|u| defined_outside(u.get_operand(allocs))),
+                _ => false,
+            },
+            _ => defined_outside(cond),
+        }
+    }
+
+    fn unswitch(&self, func: FuncID, cfg: &CfgSnapshot, lp: &Loop, plan: UnswitchPlan) {
+        let allocs = &self.module.allocs;
+        let UnswitchPlan {
+            preheader,
+            block,
+            cond,
+            then_bb,
+            else_bb,
+            exits,
+            outside_uses,
+        } = plan;
+        let mut builder = IRBuilder::new(self.module);
+
+        // 1. 把循环内的比较指令移动到 preheader
+        if let ValueSSA::Inst(cond_inst) = cond
+            && cond_inst.get_parent(allocs).is_some_and(|b| lp.contains(b))
+        {
+            builder
+                .remove_inst(cond_inst)
+                .expect("Internal error: failed to unplug unswitched condition");
+            builder.set_focus(IRFocus::Block(preheader));
+            builder
+                .insert_inst(cond_inst)
+                .expect("Internal error: failed to hoist unswitched condition");
+        }
+
+        // 2. LCSSA: 循环外的使用者改为使用出口块中的 Phi
+        let mut exit_phis: HashMap<(InstID, BlockID), ValueSSA> = HashMap::new();
+        for (def, use_id, exit) in outside_uses {
+            let phi = *exit_phis.entry((def, exit)).or_insert_with(|| {
+                let preds = loop_preds(cfg, lp, exit);
+                let ty = def.deref_ir(allocs).get_valtype();
+                let incomings = preds.iter().map(|&pred| (pred, ValueSSA::Inst(def)));
+                let phi = PhiInstID::from_incomings(allocs, ty, incomings);
+                builder.set_focus(IRFocus::Block(exit));
+                builder
+                    .insert_inst(phi)
+                    .expect("Internal error: failed to insert exit phi");
+                ValueSSA::Inst(phi.raw_into())
+            });
+            use_id.set_operand(allocs, phi);
+        }
+
+        // 3. 复制循环. 出口块中的 Phi 补上来自副本的传入值
+        let mapping = clone_blocks(self.module, &lp.block_list, lp.header);
+        for &exit in &exits {
+            let preds = loop_preds(cfg, lp, exit);
+            for phi in block_phis(allocs, exit) {
+                let phi = phi.deref_ir(allocs);
+                for &pred in &preds {
+                    if let Some(value) = phi.find_incoming_value(allocs, pred) {
+                        phi.set_incoming(
+                            allocs,
+                            mapping.map_block(pred),
+                            mapping.map_or_keep(value),
+                        );
+                    }
+                }
+            }
+        }
+
+        // 4. preheader 按条件选择循环, 两份循环中的条件分支各自固定为一个方向
+        let select = BrInstID::new(allocs, cond, lp.header, mapping.map_block(lp.header));
+        drop(preheader.set_terminator_inst(allocs, select.raw_into()));
+        fold_branch(allocs, block, then_bb, else_bb);
+        fold_branch(
+            allocs,
+            mapping.map_block(block),
+            mapping.map_block(else_bb),
+            mapping.map_block(then_bb),
+        );
+        remove_unreachable_blocks(self.module, func);
+    }
+}
+
+/// 出口块在循环内的前驱.
+fn loop_preds(cfg: &CfgSnapshot, lp: &Loop, exit: BlockID) -> SmallVec<[BlockID; 4]> {
+    let mut preds: SmallVec<[BlockID; 4]> = SmallVec::new();
+    for &pred in cfg.pred_of(exit).unwrap_or(&[]) {
+        if lp.contains(pred) && !preds.contains(&pred) {
+            preds.push(pred);
+        }
+    }
+    preds
+}
+
+/// 把 `block` 的条件分支改为无条件跳转到 `taken`.
+fn fold_branch(allocs: &IRAllocs, block: BlockID, taken: BlockID, dropped: BlockID) {
+    for phi in block_phis(allocs, dropped) {
+        phi.deref_ir(allocs).remove_incoming(allocs, block);
+    }
+    let jump = JumpInstID::with_target(allocs, taken);
+    // 旧的 br 指令由 ManagedInst 负责 dispose
+    drop(block.set_terminator_inst(allocs, jump.raw_into()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            IRWriteOption, ISubGlobalID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        opt::Mem2Reg,
+        testing::cases::test_case_loop_select,
+    };
+
+    #[test]
+    fn test_loop_unswitch() {
+        let module = test_case_loop_select().module;
+        let allocs = &module.allocs;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        Mem2Reg::new(&module).run_on_func(func);
+        let mut pass = LoopUnswitch::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-loop-unswitch.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        assert_eq!(pass.num_unswitched, 1);
+
+        // 两份循环中都不再有依赖 flag 的分支
+        let loops = LoopInfo::new(allocs, func).unwrap();
+        assert_eq!(loops.loops.len(), 2);
+        for lp in &loops.loops {
+            for &block in &lp.block_list {
+                if let TerminatorID::Br(br) = block.get_terminator(allocs) {
+                    assert!(!pass.cond_is_invariant(lp, br.get_cond(allocs)));
+                }
+            }
+        }
+    }
+}
@@ -1,18 +1,16 @@
 use crate::{
     SymbolStr,
-    base::FixBitSet,
     ir::{
-        BlockID, ConstData, FuncID, IRBuilder, IRFocus, ISubInstID, ITraceableValue, IValueConvert,
-        InstID, InstObj, Module, UserID, ValueSSA,
-        inst::{AllocaInst, AllocaInstID, LoadInstID, PhiInstID, StoreInstID},
+        BlockID, ConstData, FuncID, IRBuilder, ISubInstID, ITraceableValue, InstID, InstObj,
+        Module, SSAUpdater, UserID, ValueSSA,
+        inst::{AllocaInst, AllocaInstID, LoadInstID, StoreInstID},
     },
-    opt::{CfgBlockStat, DominanceFrontier, DominatorTree, IFuncTransformPass},
+    opt::{DominanceFrontier, DominatorTree, IFuncTransformPass},
     typing::{IValType, ScalarType, ValTypeID},
 };
 use smallvec::SmallVec;
 use std::collections::{HashMap, HashSet};
 
-type DT = DominatorTree;
 type DF<'a> = DominanceFrontier<'a>;
 
 pub struct Mem2Reg<'ir> {
@@ -114,8 +112,7 @@ impl<'ir> Mem2Reg<'ir> {
             return self.promote_local(info, local_bb);
         }
 
-        let cfg_dfn_phi = self.insert_phis(df, info);
-        self.rename(df, info, cfg_dfn_phi);
+        self.promote_general(df, info);
     }
 
     fn promote_nostore(&self, info: &PromoteInfo) {
@@ -231,198 +228,88 @@ impl<'ir> Mem2Reg<'ir> {
         HashSet::from_iter(insts.iter().copied().map(ISubInstID::raw_into))
     }
 
-    #[inline(never)]
-    fn insert_phis(&self, df: &DF, info: &PromoteInfo) -> HashMap<usize, PhiInstID> {
-        let def_dfns: FixBitSet = self.dump_def_cfgdfns(df, info);
-        let mut phi_blocks = HashSet::new();
-        let mut dfn_worklist: SmallVec<[usize; 16]> = def_dfns.iter().collect();
-
-        while let Some(cfg_dfn) = dfn_worklist.pop() {
-            for &df_dfn in &df.df[cfg_dfn] {
-                if phi_blocks.insert(df_dfn) {
-                    dfn_worklist.push(df_dfn);
-                }
-            }
-        }
-        let mut builder = IRBuilder::new(self.module);
-        let mut ret = HashMap::new();
-        let allocs = &self.module.allocs;
-        for &dfn in &phi_blocks {
-            let CfgBlockStat::Block(block) = df.dom_tree.dfs.nodes[dfn].block else {
-                continue;
-            };
-            builder.set_focus(IRFocus::Block(block));
-            let phi = PhiInstID::new_empty(allocs, info.valty);
-            builder.insert_inst(phi).expect("Failed to insert phi");
-            ret.insert(dfn, phi);
-        }
-        ret
-    }
-    fn dump_def_cfgdfns(&self, df: &DF<'_>, info: &PromoteInfo) -> FixBitSet {
+    /// 一般情况: 由 [`SSAUpdater`] 按需在迭代支配边界上插入 Phi.
+    fn promote_general(&self, df: &DF, info: &PromoteInfo) {
         let allocs = &self.module.allocs;
-        let dfs = &df.dom_tree.dfs;
-        let mut set = FixBitSet::with_len(dfs.nodes.len());
-        for &store in &info.stores {
-            let parent = store
+        let stores = Self::dump_insts(&info.stores);
+        let loads = Self::dump_insts(&info.loads);
+        let mut blocks: SmallVec<[BlockID; 8]> = SmallVec::new();
+        for &inst in stores.iter().chain(loads.iter()) {
+            let parent = inst
                 .get_parent(allocs)
-                .expect("IR invariant violated: store has no parent block");
-            if let Some(&dfn) = dfs.unseq.get(&parent) {
-                set.enable(dfn);
+                .expect("IR invariant violated: load/store has no parent block");
+            if !blocks.contains(&parent) {
+                blocks.push(parent);
             }
         }
-        set
-    }
-
-    #[inline(never)]
-    fn rename(&self, df: &DF<'_>, info: &PromoteInfo, phis: HashMap<usize, PhiInstID>) {
-        let mut renamer = Rename::new(self, phis, df, info);
-        renamer.run();
-        renamer.cleanup();
-    }
-}
 
-struct Rename<'t> {
-    builder: IRBuilder<&'t Module>,
-    dfn_phi: HashMap<usize, PhiInstID>,
-    defuse: HashSet<InstID>,
-    stack: SmallVec<[ValueSSA; 16]>,
-    df: &'t DF<'t>,
-    dt: &'t DT,
-    info: &'t PromoteInfo,
-}
-
-impl<'t> Rename<'t> {
-    fn new(
-        mem2reg: &Mem2Reg<'t>,
-        dfn_phi: HashMap<usize, PhiInstID>,
-        df: &'t DF<'t>,
-        info: &'t PromoteInfo,
-    ) -> Self {
-        let mut defuse = HashSet::new();
-        if info.loads.len() >= 8 {
-            defuse.extend(info.loads.iter().copied().map(ISubInstID::raw_into));
-        }
-        if info.stores.len() >= 8 {
-            defuse.extend(info.stores.iter().copied().map(ISubInstID::raw_into));
-        }
-        Self {
-            builder: IRBuilder::new(mem2reg.module),
-            dfn_phi,
-            defuse,
-            stack: SmallVec::new(),
-            df,
-            dt: df.dom_tree,
-            info,
-        }
-    }
-    fn push_value(&mut self, val: impl IValueConvert) {
-        self.stack.push(val.into_value());
-    }
-
-    fn run(&mut self) {
-        let undef = ValueSSA::ConstData(ConstData::Undef(self.info.valty));
-        self.stack.push(undef);
-        self.rename_one(DT::ROOT_INDEX);
-    }
-
-    fn rename_one(&mut self, cfg_dfn: usize) {
-        let CfgBlockStat::Block(block) = self.dt.dfs.nodes[cfg_dfn].block else {
-            return;
-        };
-        let stack_len = self.stack.len();
-        if let Some(&phi) = self.dfn_phi.get(&cfg_dfn) {
-            self.push_value(phi);
-        }
-        let allocs = {
-            let module = self.builder.module;
-            &module.allocs
-        };
-
-        for (inst_id, inst) in block.insts_iter(allocs) {
-            let removed = match inst {
-                InstObj::Store(store) if self.has_store(inst_id) => {
-                    self.push_value(store.get_source(allocs));
-                    true
-                }
-                InstObj::Load(load) if self.has_load(inst_id) => {
-                    let Some(&val) = self.stack.last() else {
-                        panic!("Internal error: stack underflow when renaming load");
-                    };
-                    load.replace_self_with(allocs, val)
-                        .expect("Internal error: failed to replace load with renamed value");
-                    true
-                }
-                _ => false,
-            };
-            if removed {
-                self.builder
-                    .remove_inst(inst_id)
-                    .expect("Internal error: failed to remove load/store instruction");
+        // 每个块末尾的定义是块中最后一条 store 写入的值
+        let mut updater = SSAUpdater::new(allocs, df, info.valty);
+        for &block in &blocks {
+            let last_store = block
+                .insts_iter(allocs)
+                .filter_map(|(inst_id, inst)| match inst {
+                    InstObj::Store(store) if stores.contains(&inst_id) => {
+                        Some(store.get_source(allocs))
+                    }
+                    _ => None,
+                });
+            if let Some(value) = last_store.last() {
+                updater.add_def(block, value);
             }
         }
 
-        let succs = self.df.cfg.succ_of(block).unwrap_or(&[]);
-        for &succ in succs {
-            if let Some(phi) = self.block_get_phi(succ) {
-                let Some(&val) = self.stack.last() else {
-                    panic!("Internal error: stack underflow when renaming phi operand");
-                };
-                phi.set_incoming(allocs, block, val);
+        // load 读到同一个块中前面的 store, 或者块入口处的值
+        let mut replace: HashMap<InstID, ValueSSA> = HashMap::new();
+        for &block in &blocks {
+            let mut current = None;
+            for (inst_id, inst) in block.insts_iter(allocs) {
+                match inst {
+                    InstObj::Store(store) if stores.contains(&inst_id) => {
+                        current = Some(store.get_source(allocs));
+                    }
+                    InstObj::Load(_) if loads.contains(&inst_id) => {
+                        let value = current.unwrap_or_else(|| updater.value_at_entry(block));
+                        replace.insert(inst_id, value);
+                    }
+                    _ => {}
+                }
             }
         }
 
-        for &child_dfn in &self.dt.nodes[cfg_dfn].children_dfn {
-            self.rename_one(child_dfn);
-        }
-        self.stack.truncate(stack_len);
-    }
-
-    fn has_load(&mut self, load: InstID) -> bool {
-        let loads = &self.info.loads;
-        if loads.len() < 8 {
-            loads.contains(&LoadInstID::raw_from(load))
-        } else {
-            self.defuse.contains(&load)
-        }
-    }
-    fn has_store(&mut self, store: InstID) -> bool {
-        let stores = &self.info.stores;
-        if stores.len() < 8 {
-            stores.contains(&StoreInstID::raw_from(store))
-        } else {
-            self.defuse.contains(&store)
-        }
-    }
-    fn block_get_phi(&self, block: BlockID) -> Option<PhiInstID> {
-        let dfn = self.dt.dfs.unseq.get(&block)?;
-        self.dfn_phi.get(dfn).copied()
-    }
-
-    fn cleanup(&mut self) {
-        let allocs = &self.builder.module.allocs;
-        // dedup phi operands where possible
-        for phi in self.dfn_phi.values() {
-            let mut dedup = phi.begin_dedup(allocs);
-            if !dedup.initial_nodup() {
-                let ok = dedup.dedup_same_operand();
-                if !ok {
-                    dedup.keep_first();
+        // store 的值可能是被提升的 load, 它在 `replace` 中记录的是替换前的值
+        let resolve = |load: InstID| {
+            let mut value = replace[&load];
+            for _ in 0..replace.len() {
+                match value {
+                    ValueSSA::Inst(inst) if replace.contains_key(&inst) => value = replace[&inst],
+                    _ => return value,
                 }
-                dedup.apply().expect("Phi dedup failed");
             }
-        }
-
-        // finally remove the original alloca
-        self.builder
-            .remove_inst(self.info.alloca)
-            .expect("Internal error: failed to remove alloca instruction");
-        self.info.alloca.dispose(allocs).unwrap();
-        for &def in &self.info.stores {
-            def.dispose(allocs).unwrap();
-        }
-        for &load in &self.info.loads {
+            ValueSSA::ConstData(ConstData::Undef(info.valty))
+        };
+        let mut builder = IRBuilder::new(self.module);
+        for &load in &info.loads {
+            let value = resolve(load.raw_into());
+            load.deref_ir(allocs)
+                .replace_self_with(allocs, value)
+                .expect("Internal error: failed to replace load with promoted value");
+            builder
+                .remove_inst(load)
+                .expect("Internal error: failed to remove load instruction");
             load.dispose(allocs).unwrap();
         }
+        for &store in &info.stores {
+            builder
+                .remove_inst(store)
+                .expect("Internal error: failed to remove store instruction");
+            store.dispose(allocs).unwrap();
+        }
+        builder
+            .remove_inst(info.alloca)
+            .expect("Internal error: failed to remove alloca instruction");
+        info.alloca.dispose(allocs).unwrap();
+        updater.finish();
     }
 }
 
@@ -451,5 +338,12 @@ mod tests {
             &module,
             IRWriteOption::quiet(),
         );
+        checking::assert_module_sane(&module);
+        checking::assert_func_dominance(&module.allocs, main_func);
+        let has_alloca = main_func
+            .blocks_iter(&module.allocs)
+            .flat_map(|(block, _)| block.insts_iter(&module.allocs))
+            .any(|(_, inst)| matches!(inst, InstObj::Alloca(_)));
+        assert!(!has_alloca);
     }
 }
@@ -0,0 +1,522 @@
+//! Phi 消除 (SSA 解构): 把 Phi 指令换成传入边上的并行复制.
+//!
+//! 1. 拆边: 终点含 Phi、起点不以 `jump` 结尾的边都拆开, 见 [`split_phi_edges`].
+//!    之后每条进入含 Phi 基本块的边上的复制都可以放在起点的 `jump` 之前.
+//! 2. 合并: 按活跃变量分析计算 Phi 和它们的传入值之间的干涉, 把互不干涉的值并进同一个等价类.
+//!    同一个等价类的值共用一个存储位置, 它们之间的复制不必生成. 见 [`PhiCongruence`].
+//! 3. 序列化: 同一条边上剩下的复制是并行执行的, [`sequentialize_copies`] 把它们排成顺序复制,
+//!    遇到复制环时借助一个临时位置.
+//!
+//! MIR 指令选择直接使用 [`PhiCongruence`], 每个等价类对应一个虚拟寄存器. [`OutOfSSA`] 是 IR 上的版本,
+//! 每个等价类对应一个 `alloca`, 便于调试 Phi 消除本身.
+
+use crate::{
+    SymbolStr,
+    base::DSU,
+    ir::{
+        BlockID, ConstData, FuncID, IRAllocs, IRBuilder, IRFocus, ISubInstID, ISubValueSSA,
+        ITraceableValue, JumpTargetID, Module, Opcode, TerminatorID, ValueSSA,
+        inst::{AllocaInstID, LoadInstID, PhiInstID, StoreInstID},
+    },
+    opt::{
+        CfgRes, Liveness,
+        transforms::{IFuncTransformPass, block_phis},
+    },
+    typing::IValType,
+};
+use std::{
+    collections::{HashMap, HashSet, hash_map::Entry},
+    hash::Hash,
+};
+
+/// 拆开所有终点含 Phi、起点的终结指令不是 `jump` 的边, 返回拆开的边数.
+///
+/// 拆分之后每条进入含 Phi 基本块的边都是起点唯一的出边, 关键边也都被拆开了.
+pub fn split_phi_edges(module: &Module, func: FuncID) -> usize {
+    let allocs = &module.allocs;
+    let mut edges: Vec<JumpTargetID> = Vec::new();
+    for (block, _) in func.blocks_iter(allocs) {
+        if matches!(block.get_terminator(allocs), TerminatorID::Jump(_)) {
+            continue;
+        }
+        edges.extend(block.get_succs(allocs).iter().copied().filter(|jt| {
+            jt.get_block(allocs)
+                .is_some_and(|to| !block_phis(allocs, to).is_empty())
+        }));
+    }
+    let mut builder = IRBuilder::new(module);
+    for &jt in &edges {
+        builder
+            .split_edge(jt)
+            .expect("Internal error: failed to split phi edge");
+    }
+    edges.len()
+}
+
+/// Phi 相关值的等价类和每条边上剩下的复制.
+///
+/// 参与合并的值是 Phi 指令和它们来自指令或函数参数的传入值. 两个值干涉, 当且仅当一个值定义处之后另一个值仍然活跃;
+/// 同一基本块的 Phi 都在块入口同时定义, 总是互相干涉, 函数参数也一样.
+/// 一个等价类里的值两两不干涉, 所以可以共用一个存储位置.
+pub struct PhiCongruence {
+    values: Vec<ValueSSA>,
+    index: HashMap<ValueSSA, usize>,
+    classes: DSU,
+    /// 以 `jump` 结尾的基本块在 `jump` 之前要执行的并行复制 `(目标 Phi, 源值)`.
+    copies: HashMap<BlockID, Vec<(PhiInstID, ValueSSA)>>,
+}
+
+impl PhiCongruence {
+    /// 先用 [`split_phi_edges`] 拆边, 再计算函数 `func` 的 Phi 等价类.
+    pub fn new(module: &Module, func: FuncID) -> CfgRes<Self> {
+        split_phi_edges(module, func);
+        let allocs = &module.allocs;
+        let mut phis = Vec::new();
+        let mut this = Self {
+            values: Vec::new(),
+            index: HashMap::new(),
+            classes: DSU::new(0),
+            copies: HashMap::new(),
+        };
+        for (block, _) in func.blocks_iter(allocs) {
+            for phi in block_phis(allocs, block) {
+                this.add_value(ValueSSA::Inst(phi.raw_into()));
+                for &[val_use, _] in phi.incoming_uses(allocs).iter() {
+                    this.add_value(val_use.get_operand(allocs));
+                }
+                phis.push(phi);
+            }
+        }
+        if phis.is_empty() {
+            return Ok(this);
+        }
+        this.classes = DSU::new(this.values.len());
+
+        let interf = this.interference(allocs, func)?;
+        let mut members: Vec<Vec<usize>> = (0..this.values.len()).map(|i| vec![i]).collect();
+        for &phi in &phis {
+            let dst = this.index[&ValueSSA::Inst(phi.raw_into())];
+            let srcs: Vec<usize> = phi
+                .incoming_uses(allocs)
+                .iter()
+                .filter_map(|&[val_use, _]| this.index.get(&val_use.get_operand(allocs)).copied())
+                .collect();
+            for src in srcs {
+                this.try_coalesce(&mut members, &interf, dst, src);
+            }
+        }
+        this.collect_copies(allocs, func);
+        Ok(this)
+    }
+
+    fn add_value(&mut self, val: ValueSSA) {
+        if !matches!(val, ValueSSA::Inst(_) | ValueSSA::FuncArg(..)) {
+            return;
+        }
+        if let Entry::Vacant(e) = self.index.entry(val) {
+            e.insert(self.values.len());
+            self.values.push(val);
+        }
+    }
+
+    /// 参与合并的值之间的干涉关系, 每对值按 `(较小下标, 较大下标)` 存放.
+    fn interference(&self, allocs: &IRAllocs, func: FuncID) -> CfgRes<HashSet<(usize, usize)>> {
+        let live = Liveness::solve(allocs, func)?;
+        let mut interf = HashSet::new();
+        let mut add_defs = |defs: &[usize], live: &mut dyn Iterator<Item = &ValueSSA>| {
+            let live: Vec<usize> = live.filter_map(|v| self.index.get(v).copied()).collect();
+            for &def in defs {
+                for &other in defs.iter().chain(&live) {
+                    if other != def {
+                        interf.insert((def.min(other), def.max(other)));
+                    }
+                }
+            }
+        };
+
+        let entry = func.entry_unwrap(allocs);
+        let args: Vec<usize> = (0..func.args(allocs).len())
+            .filter_map(|i| self.index.get(&ValueSSA::FuncArg(func, i as u32)).copied())
+            .collect();
+        if let Some(live_in) = live.block_in(entry) {
+            add_defs(&args, &mut live_in.as_set().into_iter().flatten());
+        }
+        for (block, _) in func.blocks_iter(allocs) {
+            let Some(live_in) = live.block_in(block) else {
+                continue;
+            };
+            let phis: Vec<usize> = block_phis(allocs, block)
+                .iter()
+                .filter_map(|phi| self.index.get(&ValueSSA::Inst(phi.raw_into())).copied())
+                .collect();
+            add_defs(&phis, &mut live_in.as_set().into_iter().flatten());
+
+            let facts = live
+                .block_inst_facts(&Liveness, allocs, block)
+                .unwrap_or_default();
+            for fact in facts {
+                let Some(&def) = self.index.get(&ValueSSA::Inst(fact.inst)) else {
+                    continue;
+                };
+                if phis.contains(&def) {
+                    continue;
+                }
+                add_defs(&[def], &mut fact.after.as_set().into_iter().flatten());
+            }
+        }
+        Ok(interf)
+    }
+
+    fn try_coalesce(
+        &mut self,
+        members: &mut [Vec<usize>],
+        interf: &HashSet<(usize, usize)>,
+        a: usize,
+        b: usize,
+    ) {
+        let (ra, rb) = (self.classes.find(a), self.classes.find(b));
+        if ra == rb {
+            return;
+        }
+        let conflict = members[ra].iter().any(|&x| {
+            members[rb]
+                .iter()
+                .any(|&y| interf.contains(&(x.min(y), x.max(y))))
+        });
+        if conflict {
+            return;
+        }
+        self.classes.union(ra, rb);
+        let root = self.classes.find(ra);
+        let other = if root == ra { rb } else { ra };
+        let moved = std::mem::take(&mut members[other]);
+        members[root].extend(moved);
+    }
+
+    fn collect_copies(&mut self, allocs: &IRAllocs, func: FuncID) {
+        for (block, _) in func.blocks_iter(allocs) {
+            let TerminatorID::Jump(jump) = block.get_terminator(allocs) else {
+                continue;
+            };
+            let Some(to) = jump.get_target(allocs) else {
+                continue;
+            };
+            let mut copies = Vec::new();
+            for phi in block_phis(allocs, to) {
+                let Some(src) = phi.find_incoming_value(allocs, block) else {
+                    continue;
+                };
+                let dst = ValueSSA::Inst(phi.raw_into());
+                if matches!(src, ValueSSA::ConstData(ConstData::Undef(_)))
+                    || self.class_of(src) == self.class_of(dst)
+                {
+                    continue;
+                }
+                copies.push((phi, src));
+            }
+            if !copies.is_empty() {
+                self.copies.insert(block, copies);
+            }
+        }
+    }
+
+    /// `val` 所在等价类的代表元. 不参与合并的值返回 `None`.
+    pub fn class_of(&self, val: ValueSSA) -> Option<ValueSSA> {
+        let &i = self.index.get(&val)?;
+        Some(self.values[self.classes.readonly_find(i)])
+    }
+    /// 所有参与合并的值和它们所在等价类的代表元.
+    pub fn values(&self) -> impl Iterator<Item = (ValueSSA, ValueSSA)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(i, &val)| (val, self.values[self.classes.readonly_find(i)]))
+    }
+    /// 基本块 `block` 的 `jump` 之前要执行的并行复制 `(目标 Phi, 源值)`.
+    pub fn copies_at(&self, block: BlockID) -> &[(PhiInstID, ValueSSA)] {
+        self.copies.get(&block).map_or(&[], Vec::as_slice)
+    }
+    pub fn num_classes(&self) -> usize {
+        (0..self.values.len())
+            .filter(|&i| self.classes.readonly_find(i) == i)
+            .count()
+    }
+    pub fn num_copies(&self) -> usize {
+        self.copies.values().map(Vec::len).sum()
+    }
+}
+
+/// 把一组并行复制 `(目标, 源)` 排成等价的顺序复制.
+///
+/// 每个目标最多出现一次, 一个源可以复制到多个目标. 目标和源相同的复制被丢弃.
+/// 剩下的复制构成环时, 用 `new_tmp(loc)` 创建一个临时位置保存 `loc` 的旧值.
+pub fn sequentialize_copies<L: Copy + Eq + Hash>(
+    copies: &[(L, L)],
+    mut new_tmp: impl FnMut(L) -> L,
+) -> Vec<(L, L)> {
+    let mut pending: Vec<(L, L)> = copies.iter().copied().filter(|(d, s)| d != s).collect();
+    let mut seq = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        // 目标不再被其他复制读取的复制可以直接执行
+        let ready = pending
+            .iter()
+            .position(|&(dst, _)| pending.iter().all(|&(_, src)| src != dst));
+        if let Some(i) = ready {
+            seq.push(pending.swap_remove(i));
+            continue;
+        }
+        // 剩下的只有环. 先把环上一个目标的旧值存进临时位置, 读它的复制改读临时位置
+        let (dst, _) = pending[0];
+        let tmp = new_tmp(dst);
+        seq.push((tmp, dst));
+        for (_, src) in pending.iter_mut().filter(|(_, src)| *src == dst) {
+            *src = tmp;
+        }
+    }
+    seq
+}
+
+/// IR 上的 Phi 消除: 每个等价类放进入口块的一个 `alloca` 里.
+///
+/// 等价类中的非 Phi 值在定义之后立即存进栈槽, Phi 换成块开头的 `load`,
+/// 边上的复制换成起点 `jump` 之前的 `store`. 结果可以再用 `Mem2Reg` 提升回 SSA 形式.
+pub struct OutOfSSA<'ir> {
+    pub module: &'ir Module,
+    /// 创建的栈槽数, 即等价类数.
+    pub num_slots: usize,
+    /// 边上生成的复制数.
+    pub num_copies: usize,
+}
+
+impl<'ir> IFuncTransformPass for OutOfSSA<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("OutOfSSA")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        let phi_cc = PhiCongruence::new(self.module, func)
+            .expect("Internal error: failed to build phi congruence classes");
+        if phi_cc.values.is_empty() {
+            return;
+        }
+        let entry = func.entry_unwrap(allocs);
+        let entry_insts = entry.get_insts(allocs);
+        let entry_begin = entry.get_phi_end(allocs);
+
+        // 参数的 store 先插入, 这样 alloca 插在它们前面
+        let mut slots: HashMap<ValueSSA, (ValueSSA, u8)> = HashMap::new();
+        let mut members = Vec::new();
+        for (val, repr) in phi_cc.values() {
+            let ty = val.get_valtype(allocs);
+            let align = ty.get_align_log2(&self.module.tctx);
+            let slot = *slots.entry(repr).or_insert_with(|| {
+                let alloca = AllocaInstID::new(allocs, ty, align);
+                (ValueSSA::Inst(alloca.raw_into()), align)
+            });
+            members.push((val, slot));
+        }
+        let mut phis = Vec::new();
+        for (val, (slot, align)) in members {
+            let (block, after) = match val {
+                ValueSSA::FuncArg(..) => (entry, entry_begin),
+                ValueSSA::Inst(inst) if inst.get_opcode(allocs) == Opcode::Phi => {
+                    phis.push((PhiInstID::raw_from(inst), slot, align));
+                    continue;
+                }
+                ValueSSA::Inst(inst) => (inst.get_parent(allocs).unwrap(), inst),
+                _ => unreachable!("only instructions and arguments are coalesced"),
+            };
+            let store = StoreInstID::new(allocs, val, slot, align);
+            block
+                .get_insts(allocs)
+                .node_add_next(after, store.raw_into(), &allocs.insts)
+                .expect("Internal error: failed to insert store after definition");
+        }
+        for &(slot, _) in slots.values() {
+            let ValueSSA::Inst(alloca) = slot else { unreachable!() };
+            entry_insts
+                .node_add_next(entry_begin, alloca, &allocs.insts)
+                .expect("Internal error: failed to insert alloca");
+        }
+        self.num_slots += slots.len();
+
+        // 边上的复制读的是 SSA 值, 各条 store 之间不会互相影响, 不需要序列化
+        let mut builder = IRBuilder::new(self.module);
+        for (block, copies) in &phi_cc.copies {
+            builder.set_focus(IRFocus::Block(*block));
+            for &(phi, src) in copies {
+                let (slot, align) =
+                    slots[&phi_cc.class_of(ValueSSA::Inst(phi.raw_into())).unwrap()];
+                let store = StoreInstID::new(allocs, src, slot, align);
+                builder
+                    .insert_inst(store)
+                    .expect("Internal error: failed to insert edge copy");
+                self.num_copies += 1;
+            }
+        }
+        for (phi, slot, align) in phis {
+            let block = phi.get_parent(allocs).unwrap();
+            let ty = phi.raw_into().get_valtype(allocs);
+            let load = LoadInstID::new_uninit(allocs, ty, align);
+            load.set_source(allocs, slot);
+            block
+                .get_insts(allocs)
+                .node_add_next(block.get_phi_end(allocs), load.raw_into(), &allocs.insts)
+                .expect("Internal error: failed to insert phi load");
+            phi.deref_ir(allocs)
+                .replace_self_with(allocs, ValueSSA::Inst(load.raw_into()))
+                .expect("Internal error: failed to replace phi with load");
+            builder
+                .remove_inst(phi)
+                .expect("Internal error: failed to remove phi");
+            phi.raw_into().dispose(allocs).unwrap();
+        }
+    }
+}
+
+impl<'ir> OutOfSSA<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, num_slots: 0, num_copies: 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            ISubGlobalID, InstObj,
+            checking::{assert_func_dominance, assert_module_sane},
+        },
+        opt::Mem2Reg,
+        testing::cases::{test_case_loop_select, test_case_phi_swap, test_case_pre},
+    };
+
+    fn main_func(module: &Module) -> FuncID {
+        module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function")
+    }
+    fn count_phis(allocs: &IRAllocs, func: FuncID) -> usize {
+        func.blocks_iter(allocs)
+            .map(|(block, _)| block_phis(allocs, block).len())
+            .sum()
+    }
+
+    /// 按顺序执行复制, 检查结果与并行执行一致.
+    fn check_sequentialize(copies: &[(u32, u32)]) -> Vec<(u32, u32)> {
+        let mut next_tmp = 100;
+        let seq = sequentialize_copies(copies, |_| {
+            next_tmp += 1;
+            next_tmp
+        });
+        let mut regs: HashMap<u32, u32> = (0..100).map(|r| (r, r)).collect();
+        for &(dst, src) in &seq {
+            let value = regs[&src];
+            regs.insert(dst, value);
+        }
+        for &(dst, src) in copies {
+            assert_eq!(regs[&dst], src, "{copies:?} => {seq:?}");
+        }
+        seq
+    }
+
+    #[test]
+    fn test_sequentialize_copies() {
+        // 没有环: 按依赖顺序排列, 不需要临时位置
+        let seq = check_sequentialize(&[(1, 0), (2, 1), (3, 2)]);
+        assert_eq!(seq, [(3, 2), (2, 1), (1, 0)]);
+        // 交换和三元轮换各需要一个临时位置
+        assert_eq!(check_sequentialize(&[(0, 1), (1, 0)]).len(), 3);
+        assert_eq!(check_sequentialize(&[(0, 1), (1, 2), (2, 0)]).len(), 4);
+        // 一个源复制到多个目标, 其中一个目标又在环上
+        assert_eq!(
+            check_sequentialize(&[(0, 1), (1, 0), (2, 0), (3, 3)]).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_congruence_loop() {
+        let module = test_case_loop_select().module;
+        let allocs = &module.allocs;
+        let func = main_func(&module);
+        Mem2Reg::new(&module).run_on_func(func);
+        assert!(count_phis(allocs, func) > 0);
+
+        let phi_cc = PhiCongruence::new(&module, func).unwrap();
+        assert_module_sane(&module);
+        // 循环中的值都能和 Phi 合并, 只剩入口处的常量初值需要复制
+        for (block, _) in func.blocks_iter(allocs) {
+            for &(phi, src) in phi_cc.copies_at(block) {
+                assert!(matches!(src, ValueSSA::ConstData(_)), "{phi:?} <- {src:?}");
+            }
+        }
+        assert_eq!(phi_cc.num_copies(), 2);
+    }
+
+    #[test]
+    fn test_congruence_swap() {
+        let module = test_case_phi_swap().module;
+        let allocs = &module.allocs;
+        let func = main_func(&module);
+        let phi_cc = PhiCongruence::new(&module, func).unwrap();
+        assert_module_sane(&module);
+
+        // 自环被拆开, 拆出的块上是 x <- y, y <- x 两条复制
+        let body = func.blocks_iter(allocs).nth(1).unwrap().0;
+        let phis = block_phis(allocs, body);
+        let [x, y] = [phis[0], phis[1]].map(|phi| ValueSSA::Inst(phi.raw_into()));
+        assert_ne!(phi_cc.class_of(x), phi_cc.class_of(y));
+        let latch = func
+            .blocks_iter(allocs)
+            .map(|(block, _)| block)
+            .find(|&block| phi_cc.copies_at(block).len() == 2)
+            .unwrap();
+        assert_eq!(
+            latch.get_terminator(allocs).get_jts(allocs)[0].get_block(allocs),
+            Some(body)
+        );
+        // 参数直接放进 Phi 的存储位置, 入口只复制计数器的初值
+        assert_eq!(
+            phi_cc.class_of(ValueSSA::FuncArg(func, 0)),
+            phi_cc.class_of(x)
+        );
+        assert_eq!(
+            phi_cc.class_of(ValueSSA::FuncArg(func, 1)),
+            phi_cc.class_of(y)
+        );
+        assert_eq!(phi_cc.num_copies(), 3);
+    }
+
+    #[test]
+    fn test_out_of_ssa() {
+        let cases = [test_case_loop_select(), test_case_pre(), test_case_phi_swap()];
+        for builder in cases {
+            let module = builder.module;
+            let allocs = &module.allocs;
+            let func = main_func(&module);
+            Mem2Reg::new(&module).run_on_func(func);
+            let mut pass = OutOfSSA::new(&module);
+            pass.run_on_func(func);
+            assert_module_sane(&module);
+            assert_eq!(count_phis(allocs, func), 0);
+            let num_allocas = func
+                .entry_unwrap(allocs)
+                .insts_iter(allocs)
+                .filter(|(_, inst)| matches!(inst, InstObj::Alloca(_)))
+                .count();
+            assert_eq!(num_allocas, pass.num_slots);
+
+            // 栈槽可以被重新提升回 SSA 形式
+            Mem2Reg::new(&module).run_on_func(func);
+            assert_module_sane(&module);
+            assert_func_dominance(allocs, func);
+        }
+    }
+}
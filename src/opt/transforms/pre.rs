@@ -0,0 +1,522 @@
+//! PRE: 基于 lazy code motion 的部分冗余消除.
+//!
+//! 一个表达式如果在到达某处的一部分路径上已经计算过, 就是部分冗余的. LCM 在缺少这个表达式的边上补充计算,
+//! 让后面的出现变成完全冗余, 再用 [`SSAUpdater`] 把这些出现替换为补充计算的结果.
+//! 计算位置在保证安全 (只在一定会计算该表达式的路径上计算) 的前提下尽量靠后, 以缩短临时值的生存期.
+//!
+//! 求解过程与龙书 9.5 节相同, 在 [`CfgSnapshot`] 上依次求解四个数据流问题:
+//!
+//! 1. anticipated (后向, must): 从块入口出发的每条路径都会在操作数被重新定义之前计算该表达式;
+//! 2. will-be-available (前向, must): 在块入口处, 假设所有 anticipated 的表达式都尽早计算时可用的表达式;
+//!    `earliest = anticipated.in - available.in`;
+//! 3. postponable (前向, must): 可以从 `earliest` 推迟到这里再计算的表达式, 由此得到 `latest`;
+//! 4. used (后向, may): `latest` 之后还会被使用的表达式. 只在 `latest ∩ used.out` 处插入计算.
+//!
+//! SSA 形式下操作数不会被重新赋值, 块 "杀死" 一个表达式是指块中定义了它的某个操作数.
+//! 插入位置在块的开头, 所以求解之前先拆分关键边, 没有用到的拆分块在结束时合并回去.
+//! 为了简单, 这里只处理纯计算指令, 不移动 `load`.
+
+use crate::{
+    SymbolStr,
+    ir::{
+        BlockID, FuncID, IRAllocs, IRBuilder, IRFocus, ISubInst, ISubInstID, ITraceableValue,
+        IUser, InstID, InstObj, Module, SSAUpdater, ValueSSA, clone_inst,
+    },
+    opt::{
+        CfgSnapshot, DataflowDirection, DataflowResults, DataflowSet, DominanceFrontier,
+        DominatorTree, ExprKey, IDataflowProblem, MaySet, MustSet, critical_edges_of,
+        transforms::{IFuncTransformPass, block_phis, redirect_jumps, remove_unreachable_blocks},
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+pub struct PRE<'ir> {
+    pub module: &'ir Module,
+    /// 补充计算的次数.
+    pub num_inserted: usize,
+    /// 被替换掉的冗余计算的条数.
+    pub num_replaced: usize,
+}
+
+impl<'ir> IFuncTransformPass for PRE<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("PRE")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        if func.get_entry(&self.module.allocs).is_none() {
+            return;
+        }
+        let split_blocks = self.split_critical_edges(func);
+        // 替换之后表达式的操作数可能改变, 依赖被替换值的表达式留到下一轮处理
+        while self.run_round(func) {}
+        self.merge_split_blocks(func, &split_blocks);
+    }
+}
+
+/// 一轮 LCM 的局部信息. 表达式用 `exprs` 中的下标表示.
+#[derive(Default)]
+struct LocalInfo {
+    exprs: Vec<ExprKey>,
+    /// 块中上行暴露的出现: 操作数都不在这个块中定义. 每个块中每个表达式只保留第一个出现.
+    occurs: HashMap<BlockID, HashMap<usize, InstID>>,
+    /// 每个块中定义了操作数的表达式.
+    kills: HashMap<BlockID, HashSet<usize>>,
+}
+
+impl LocalInfo {
+    fn uses(&self, block: BlockID) -> impl Iterator<Item = usize> + '_ {
+        self.occurs
+            .get(&block)
+            .into_iter()
+            .flat_map(|m| m.keys().copied())
+    }
+    fn is_used(&self, block: BlockID, expr: usize) -> bool {
+        self.occurs
+            .get(&block)
+            .is_some_and(|m| m.contains_key(&expr))
+    }
+    fn is_killed(&self, block: BlockID, expr: usize) -> bool {
+        self.kills.get(&block).is_some_and(|s| s.contains(&expr))
+    }
+}
+
+type BlockSets = HashMap<BlockID, HashSet<usize>>;
+
+struct Anticipated<'a>(&'a LocalInfo);
+struct WillBeAvail<'a>(&'a LocalInfo, &'a DataflowResults<MustSet<usize>>);
+struct Postponable<'a>(&'a LocalInfo, &'a BlockSets);
+struct Used<'a>(&'a LocalInfo, &'a BlockSets);
+
+impl IDataflowProblem for Anticipated<'_> {
+    type Fact = MustSet<usize>;
+    const DIRECTION: DataflowDirection = DataflowDirection::Backward;
+
+    fn boundary_fact(&self) -> Self::Fact {
+        MustSet(DataflowSet::default())
+    }
+    fn transfer_block(&self, _: &IRAllocs, block: BlockID, fact: &mut Self::Fact) {
+        fact.retain(|&e| !self.0.is_killed(block, e));
+        for e in self.0.uses(block) {
+            fact.insert(e);
+        }
+    }
+}
+
+impl IDataflowProblem for WillBeAvail<'_> {
+    type Fact = MustSet<usize>;
+    const DIRECTION: DataflowDirection = DataflowDirection::Forward;
+
+    fn boundary_fact(&self) -> Self::Fact {
+        MustSet(DataflowSet::default())
+    }
+    fn transfer_block(&self, _: &IRAllocs, block: BlockID, fact: &mut Self::Fact) {
+        if let Some(ant_in) = self.1.block_in(block) {
+            fact.0 = fact.union(ant_in);
+        }
+        fact.retain(|&e| !self.0.is_killed(block, e));
+    }
+}
+
+impl IDataflowProblem for Postponable<'_> {
+    type Fact = MustSet<usize>;
+    const DIRECTION: DataflowDirection = DataflowDirection::Forward;
+
+    fn boundary_fact(&self) -> Self::Fact {
+        MustSet(DataflowSet::default())
+    }
+    fn transfer_block(&self, _: &IRAllocs, block: BlockID, fact: &mut Self::Fact) {
+        for &e in self.1.get(&block).into_iter().flatten() {
+            fact.insert(e);
+        }
+        fact.retain(|&e| !self.0.is_used(block, e));
+    }
+}
+
+impl IDataflowProblem for Used<'_> {
+    type Fact = MaySet<usize>;
+    const DIRECTION: DataflowDirection = DataflowDirection::Backward;
+
+    fn transfer_block(&self, _: &IRAllocs, block: BlockID, fact: &mut Self::Fact) {
+        for e in self.0.uses(block) {
+            fact.insert(e);
+        }
+        let latest = self.1.get(&block);
+        fact.retain(|e| !latest.is_some_and(|l| l.contains(e)));
+    }
+}
+
+impl<'ir> PRE<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, num_inserted: 0, num_replaced: 0 }
+    }
+
+    fn split_critical_edges(&self, func: FuncID) -> Vec<BlockID> {
+        let allocs = &self.module.allocs;
+        let edges: Vec<_> = func
+            .blocks_iter(allocs)
+            .flat_map(|(block, _)| critical_edges_of(allocs, block))
+            .collect();
+        let mut builder = IRBuilder::new(self.module);
+        edges
+            .into_iter()
+            .map(|jt| {
+                builder
+                    .split_edge(jt)
+                    .expect("Internal error: failed to split critical edge")
+            })
+            .collect()
+    }
+
+    /// 执行一轮 LCM, 返回是否修改了函数.
+    fn run_round(&mut self, func: FuncID) -> bool {
+        let allocs = &self.module.allocs;
+        let (Ok(cfg), Ok(dom)) = (
+            CfgSnapshot::new(allocs, func),
+            DominatorTree::builder(allocs, func).map(|b| b.build()),
+        ) else {
+            return false;
+        };
+        // 局部冗余被替换之后其他表达式的键可能失效, 先重新收集一次
+        let Some(local) = self.collect_local(func) else {
+            return true;
+        };
+        if local.exprs.is_empty() {
+            return false;
+        }
+        let Ok(plan) = LcmPlan::solve(allocs, func, &cfg, &local) else {
+            return false;
+        };
+        let mut changed = false;
+        let df = DominanceFrontier::new(&dom, allocs)
+            .expect("Internal error: failed to build dominance frontier");
+
+        let blocks: Vec<BlockID> = func.blocks_iter(allocs).map(|(b, _)| b).collect();
+        let mut replaced: HashSet<InstID> = HashSet::new();
+        for expr in 0..local.exprs.len() {
+            let insert: Vec<BlockID> = blocks
+                .iter()
+                .copied()
+                .filter(|&b| plan.is_latest(b, expr) && plan.is_used_out(b, expr))
+                .collect();
+            let replace: Vec<BlockID> = blocks
+                .iter()
+                .copied()
+                .filter(|&b| {
+                    local.is_used(b, expr)
+                        && !insert.contains(&b)
+                        && (!plan.is_latest(b, expr) || plan.is_used_out(b, expr))
+                })
+                .collect();
+            if replace.is_empty() {
+                continue;
+            }
+            let key = &local.exprs[expr];
+            let operands_replaced = key.operands.iter().any(|operand| match operand {
+                ValueSSA::Inst(inst) => replaced.contains(inst),
+                _ => false,
+            });
+            if operands_replaced || !self.can_insert_all(&dom, &local, expr, &insert) {
+                continue;
+            }
+
+            let sample = local
+                .occurs
+                .values()
+                .find_map(|m| m.get(&expr).copied())
+                .expect("Internal error: expression has no occurrence");
+            let valty = sample.deref_ir(allocs).get_valtype();
+            let mut updater = SSAUpdater::new(allocs, &df, valty);
+            for &block in &insert {
+                let def = match local.occurs.get(&block).and_then(|m| m.get(&expr)) {
+                    Some(&occur) => occur,
+                    None => self.insert_at_block_start(block, sample),
+                };
+                updater.add_def(block, ValueSSA::Inst(def));
+            }
+            let mut builder = IRBuilder::new(self.module);
+            for &block in &replace {
+                let occur = local.occurs[&block][&expr];
+                let value = updater.value_at_entry(block);
+                occur
+                    .deref_ir(allocs)
+                    .replace_self_with(allocs, value)
+                    .expect("Internal error: failed to replace redundant expression");
+                builder
+                    .remove_inst(occur)
+                    .expect("Internal error: failed to remove redundant expression");
+                occur.dispose(allocs).unwrap();
+                replaced.insert(occur);
+                self.num_replaced += 1;
+            }
+            updater.finish();
+            changed = true;
+        }
+        changed
+    }
+
+    /// 收集局部信息. 同一个块中重复的上行暴露出现直接替换为第一个出现, 这时返回 `None`.
+    fn collect_local(&mut self, func: FuncID) -> Option<LocalInfo> {
+        let allocs = &self.module.allocs;
+        let mut local = LocalInfo::default();
+        let mut expr_index: HashMap<ExprKey, usize> = HashMap::new();
+        let mut duplicates: Vec<(InstID, InstID)> = Vec::new();
+        for (block, _) in func.blocks_iter(allocs) {
+            for (inst, obj) in block.insts_iter(allocs) {
+                let Some(key) = ExprKey::from_inst(allocs, inst) else {
+                    continue;
+                };
+                if key.is_load() || Self::operands_defined_in(allocs, obj, block) {
+                    continue;
+                }
+                let next = local.exprs.len();
+                let expr = *expr_index.entry(key.clone()).or_insert(next);
+                if expr == next {
+                    local.exprs.push(key);
+                }
+                let occurs = local.occurs.entry(block).or_default();
+                match occurs.get(&expr) {
+                    Some(&first) => duplicates.push((inst, first)),
+                    None => {
+                        occurs.insert(expr, inst);
+                    }
+                }
+            }
+        }
+
+        if !duplicates.is_empty() {
+            let mut builder = IRBuilder::new(self.module);
+            for (inst, first) in duplicates {
+                inst.deref_ir(allocs)
+                    .replace_self_with(allocs, ValueSSA::Inst(first))
+                    .expect("Internal error: failed to replace local redundant expression");
+                builder
+                    .remove_inst(inst)
+                    .expect("Internal error: failed to remove local redundant expression");
+                inst.dispose(allocs).unwrap();
+                self.num_replaced += 1;
+            }
+            return None;
+        }
+
+        for (expr, key) in local.exprs.iter().enumerate() {
+            for operand in &key.operands {
+                let ValueSSA::Inst(def) = operand else {
+                    continue;
+                };
+                if let Some(block) = def.get_parent(allocs) {
+                    local.kills.entry(block).or_default().insert(expr);
+                }
+            }
+        }
+        Some(local)
+    }
+
+    fn operands_defined_in(allocs: &IRAllocs, obj: &InstObj, block: BlockID) -> bool {
+        obj.operands_iter().any(|u| match u.get_operand(allocs) {
+            ValueSSA::Inst(def) => def.get_parent(allocs) == Some(block),
+            _ => false,
+        })
+    }
+
+    /// 插入位置必须被所有操作数的定义支配. 求解结果在不可达或无法退出的区域中可能过于乐观, 这里再检查一遍.
+    fn can_insert_all(
+        &self,
+        dom: &DominatorTree,
+        local: &LocalInfo,
+        expr: usize,
+        insert: &[BlockID],
+    ) -> bool {
+        let allocs = &self.module.allocs;
+        insert.iter().all(|&block| {
+            local.is_used(block, expr)
+                || local.exprs[expr]
+                    .operands
+                    .iter()
+                    .all(|operand| match operand {
+                        ValueSSA::Inst(def) => def.get_parent(allocs).is_some_and(|def_bb| {
+                            def_bb != block && dom.block_dominates_block(def_bb, block)
+                        }),
+                        _ => true,
+                    })
+        })
+    }
+
+    /// 在 `block` 的 Phi 之后插入 `sample` 的副本.
+    fn insert_at_block_start(&mut self, block: BlockID, sample: InstID) -> InstID {
+        let (allocs, tctx) = (&self.module.allocs, &self.module.tctx);
+        let first = block
+            .insts_iter(allocs)
+            .find(|(_, inst)| {
+                !matches!(
+                    inst,
+                    InstObj::Phi(_) | InstObj::PhiInstEnd(_) | InstObj::GuideNode(_)
+                ) && !inst.is_terminator()
+            })
+            .map(|(inst, _)| inst);
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(match first {
+            Some(inst) => IRFocus::Inst(inst),
+            None => IRFocus::Block(block),
+        });
+        let inst = clone_inst(allocs, tctx, sample);
+        builder
+            .insert_inst(inst)
+            .expect("Internal error: failed to insert PRE computation");
+        self.num_inserted += 1;
+        inst
+    }
+
+    /// 把没有插入任何计算的拆分块合并回去.
+    fn merge_split_blocks(&self, func: FuncID, split_blocks: &[BlockID]) {
+        let allocs = &self.module.allocs;
+        let Ok(cfg) = CfgSnapshot::new(allocs, func) else {
+            return;
+        };
+        for &block in split_blocks {
+            let (Some(&[pred]), Some(&[succ])) = (cfg.pred_of(block), cfg.succ_of(block)) else {
+                continue;
+            };
+            let only_jump = block.insts_iter(allocs).all(|(_, inst)| {
+                matches!(
+                    inst,
+                    InstObj::PhiInstEnd(_) | InstObj::GuideNode(_) | InstObj::Jump(_)
+                )
+            });
+            if !only_jump {
+                continue;
+            }
+            // pred 的另一条边已经到达 succ 时, 两条边在 succ 的 Phi 中必须传入相同的值
+            let phis = block_phis(allocs, succ);
+            let conflict = phis.iter().any(|phi| {
+                let phi = phi.deref_ir(allocs);
+                let from_pred = phi.find_incoming_value(allocs, pred);
+                from_pred.is_some() && from_pred != phi.find_incoming_value(allocs, block)
+            });
+            if conflict {
+                continue;
+            }
+            for phi in phis {
+                let phi = phi.deref_ir(allocs);
+                if let Some(value) = phi.remove_incoming(allocs, block) {
+                    phi.set_incoming(allocs, pred, value);
+                }
+            }
+            redirect_jumps(allocs, pred, block, succ);
+        }
+        remove_unreachable_blocks(self.module, func);
+    }
+}
+
+/// LCM 的求解结果.
+struct LcmPlan {
+    latest: BlockSets,
+    used_out: HashMap<BlockID, MaySet<usize>>,
+}
+
+impl LcmPlan {
+    fn solve(
+        allocs: &IRAllocs,
+        func: FuncID,
+        cfg: &CfgSnapshot,
+        local: &LocalInfo,
+    ) -> Result<Self, crate::opt::CfgErr> {
+        let num_exprs = local.exprs.len();
+        let anticipated = DataflowResults::solve(&Anticipated(local), allocs, func)?;
+        let available = DataflowResults::solve(&WillBeAvail(local, &anticipated), allocs, func)?;
+
+        let blocks: Vec<BlockID> = anticipated.block_in.keys().copied().collect();
+        let mut earliest: BlockSets = HashMap::new();
+        for &block in &blocks {
+            let (Some(ant_in), Some(av_in)) =
+                (anticipated.block_in(block), available.block_in(block))
+            else {
+                continue;
+            };
+            let set = (0..num_exprs)
+                .filter(|e| ant_in.contains(e) && !av_in.contains(e))
+                .collect();
+            earliest.insert(block, set);
+        }
+
+        let postponable = DataflowResults::solve(&Postponable(local, &earliest), allocs, func)?;
+        // earliest ∪ postponable.in
+        let movable = |block: BlockID, e: usize| {
+            earliest.get(&block).is_some_and(|s| s.contains(&e))
+                || postponable.block_in(block).is_some_and(|s| s.contains(&e))
+        };
+        let mut latest: BlockSets = HashMap::new();
+        for &block in &blocks {
+            let succs = cfg.succ_of(block).unwrap_or(&[]);
+            let set = (0..num_exprs)
+                .filter(|&e| {
+                    movable(block, e)
+                        && (local.is_used(block, e) || !succs.iter().all(|&s| movable(s, e)))
+                })
+                .collect();
+            latest.insert(block, set);
+        }
+
+        let used = DataflowResults::solve(&Used(local, &latest), allocs, func)?;
+        Ok(Self { latest, used_out: used.block_out })
+    }
+
+    fn is_latest(&self, block: BlockID, expr: usize) -> bool {
+        self.latest.get(&block).is_some_and(|s| s.contains(&expr))
+    }
+    fn is_used_out(&self, block: BlockID, expr: usize) -> bool {
+        self.used_out.get(&block).is_some_and(|s| s.contains(&expr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            IRWriteOption, ISubGlobalID, Opcode, TerminatorID,
+            checking::{assert_func_dominance, assert_module_sane},
+            write_ir_to_file,
+        },
+        testing::cases::test_case_pre,
+    };
+
+    #[test]
+    fn test_pre() {
+        let module = test_case_pre().module;
+        let allocs = &module.allocs;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        let num_blocks = func.blocks_iter(allocs).count();
+
+        let mut pass = PRE::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file("../target/test-pre.ll", &module, IRWriteOption::loud());
+        assert_module_sane(&module);
+        assert_func_dominance(allocs, func);
+        // add 补在 else 分支中, mul 提到循环前新建的块中
+        assert_eq!(pass.num_inserted, 2);
+        assert_eq!(pass.num_replaced, 2);
+        assert_eq!(func.blocks_iter(allocs).count(), num_blocks + 1);
+
+        // 循环体 (以自己为后继的块) 中不再有 mul
+        let (body, _) = func
+            .blocks_iter(allocs)
+            .find(|(block, _)| match block.get_terminator(allocs) {
+                TerminatorID::Br(br) => br.get_then(allocs) == Some(*block),
+                _ => false,
+            })
+            .expect("loop body not found");
+        let has_mul = body
+            .insts_iter(allocs)
+            .any(|(_, inst)| inst.get_opcode() == Opcode::Mul);
+        assert!(!has_mul);
+
+        // 再运行一次不会有任何变化
+        let mut again = PRE::new(&module);
+        again.run_on_func(func);
+        assert_eq!((again.num_inserted, again.num_replaced), (0, 0));
+    }
+}
@@ -0,0 +1,673 @@
+use crate::{
+    SymbolStr,
+    base::APInt,
+    ir::{
+        BlockID, CmpCond, ConstData, FuncID, IRAllocs, IRBuilder, ISubInst, ISubInstID,
+        ITraceableValue, InstID, InstObj, Module, Opcode, UserID, ValueSSA, inst::JumpInstID,
+    },
+    opt::transforms::{IFuncTransformPass, block_phis},
+    typing::{ScalarType, ValTypeID},
+};
+use smallvec::SmallVec;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet, VecDeque},
+};
+
+/// SCCP 使用的常量格.
+///
+/// 格的高度为 3: `Undefined` (尚未求值) < `Const` < `Overdefined` (不是常量).
+/// `Const` 中保存的 `ConstData` 总是经过规范化的, 因此 `Zero(i32)` 和
+/// `Int(0: i32)` 会被视为同一个常量.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SccpValue {
+    #[default]
+    Undefined,
+    Const(ConstData),
+    Overdefined,
+}
+
+impl SccpValue {
+    /// 从常量构造格元素. `undef` 常量不参与常量传播, 视为 `Overdefined`.
+    pub fn from_const(c: ConstData) -> Self {
+        match c {
+            ConstData::Undef(_) => SccpValue::Overdefined,
+            ConstData::Zero(ScalarType::Ptr) => SccpValue::Const(ConstData::PtrNull),
+            ConstData::Zero(ScalarType::Int(bits)) => {
+                SccpValue::Const(ConstData::Int(APInt::new(0u8, bits)))
+            }
+            ConstData::Zero(ScalarType::Float(kind)) => {
+                SccpValue::Const(ConstData::Float(kind, 0.0))
+            }
+            c => SccpValue::Const(c),
+        }
+    }
+
+    pub fn is_const(&self) -> bool {
+        matches!(self, SccpValue::Const(_))
+    }
+    pub fn is_overdefined(&self) -> bool {
+        matches!(self, SccpValue::Overdefined)
+    }
+    pub fn as_const(&self) -> Option<ConstData> {
+        match self {
+            SccpValue::Const(c) => Some(*c),
+            _ => None,
+        }
+    }
+    pub fn as_apint(&self) -> Option<APInt> {
+        self.as_const().and_then(|c| c.as_apint())
+    }
+
+    /// 格上的交汇运算.
+    pub fn meet(self, other: Self) -> Self {
+        use SccpValue::*;
+        match (self, other) {
+            (Undefined, x) | (x, Undefined) => x,
+            (Overdefined, _) | (_, Overdefined) => Overdefined,
+            (Const(a), Const(b)) => {
+                if a == b {
+                    Const(a)
+                } else {
+                    Overdefined
+                }
+            }
+        }
+    }
+}
+
+/// 折叠二元运算. 不能折叠 (类型不支持 / 除以零等) 时返回 `None`.
+pub fn sccp_fold_binop(opcode: Opcode, lhs: &ConstData, rhs: &ConstData) -> Option<ConstData> {
+    use Opcode::*;
+    let res = match opcode {
+        Add | Fadd => lhs.add(rhs),
+        Sub | Fsub => lhs.sub(rhs),
+        Mul | Fmul => lhs.mul(rhs),
+        Sdiv => lhs.sdiv(rhs),
+        Udiv => lhs.udiv(rhs),
+        Srem => lhs.srem(rhs),
+        Urem => lhs.urem(rhs),
+        Shl => lhs.shl(rhs),
+        Lshr => lhs.lshr(rhs),
+        Ashr => lhs.ashr(rhs),
+        BitAnd | BitOr | BitXor => {
+            let (l, r) = (lhs.as_apint()?, rhs.as_apint()?);
+            if l.bits() != r.bits() {
+                return Option::None;
+            }
+            return Some(ConstData::Int(match opcode {
+                BitAnd => l & r,
+                BitOr => l | r,
+                _ => l ^ r,
+            }));
+        }
+        _ => return Option::None,
+    };
+    res.ok()
+}
+
+/// 折叠比较运算, 返回比较结果.
+pub fn sccp_fold_cmp(cond: CmpCond, lhs: &ConstData, rhs: &ConstData) -> Option<bool> {
+    let ord = match (lhs, rhs) {
+        (ConstData::Int(l), ConstData::Int(r)) if l.bits() == r.bits() => {
+            if cond.is_signed_ordered() {
+                l.as_signed().cmp(&r.as_signed())
+            } else {
+                l.as_unsigned().cmp(&r.as_unsigned())
+            }
+        }
+        (ConstData::PtrNull, ConstData::PtrNull) => Ordering::Equal,
+        (ConstData::Float(lk, l), ConstData::Float(rk, r)) if lk == rk => {
+            match l.partial_cmp(r) {
+                Some(ord) => ord,
+                // 有 NaN 参与: 有序比较恒假, 无序比较恒真
+                None => return Some(!cond.is_signed_ordered()),
+            }
+        }
+        _ => return None,
+    };
+    let basic = cond.get_basic_cond();
+    let res = match ord {
+        Ordering::Less => basic.contains(CmpCond::LT),
+        Ordering::Equal => basic.contains(CmpCond::EQ),
+        Ordering::Greater => basic.contains(CmpCond::GT),
+    };
+    Some(res)
+}
+
+/// 折叠整数之间的类型转换.
+pub fn sccp_fold_cast(opcode: Opcode, from: &ConstData, to_ty: ValTypeID) -> Option<ConstData> {
+    let ValTypeID::Int(bits) = to_ty else {
+        return None;
+    };
+    let value = from.as_apint()?;
+    let res = match opcode {
+        Opcode::Zext => value.zext_to(bits),
+        Opcode::Sext => value.sext_to(bits),
+        Opcode::Trunc => APInt::new(value.as_unsigned(), bits),
+        _ => return None,
+    };
+    Some(ConstData::Int(res))
+}
+
+/// 稀疏条件常量传播 (Sparse Conditional Constant Propagation).
+///
+/// 只在函数内部传播; 函数参数、全局量和内存读取都视为 `Overdefined`.
+/// 求解结束后, 值为常量的 `binop` / `cmp` / `cast` / `select` / `phi` 指令会被
+/// 替换成常量并删除, 条件已知的 `br` 会被改写成 `jump`. 由此变得不可达的基本块
+/// 不在这里删除, 留给 `BasicFuncDCE` 处理.
+pub struct SCCP<'ir> {
+    pub module: &'ir Module,
+    pub num_folded_insts: usize,
+    pub num_folded_branches: usize,
+}
+
+impl<'ir> IFuncTransformPass for SCCP<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("SCCP")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        let Some(entry) = func.get_entry(allocs) else {
+            return;
+        };
+        let mut solver = SccpSolver::new(allocs);
+        solver.solve(entry);
+        self.rewrite_values(func, &solver);
+        self.rewrite_branches(func, &solver);
+    }
+}
+
+impl<'ir> SCCP<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self { module, num_folded_insts: 0, num_folded_branches: 0 }
+    }
+
+    fn rewrite_values(&mut self, func: FuncID, solver: &SccpSolver) {
+        let allocs = &self.module.allocs;
+        let mut folded: Vec<(InstID, ConstData)> = Vec::new();
+        for (block, _) in func.blocks_iter(allocs) {
+            if !solver.exec_blocks.contains(&block) {
+                continue;
+            }
+            for (inst_id, inst) in block.insts_iter(allocs) {
+                use InstObj::*;
+                if !matches!(inst, BinOP(_) | Cmp(_) | Cast(_) | Select(_) | Phi(_)) {
+                    continue;
+                }
+                if let Some(c) = solver.get_inst_value(inst_id).as_const() {
+                    folded.push((inst_id, c));
+                }
+            }
+        }
+
+        let mut builder = IRBuilder::new(self.module);
+        for &(inst_id, c) in &folded {
+            inst_id
+                .deref_ir(allocs)
+                .replace_self_with(allocs, ValueSSA::ConstData(c))
+                .expect("Internal error: failed to replace folded instruction");
+            builder
+                .remove_inst(inst_id)
+                .expect("Internal error: failed to remove folded instruction");
+            inst_id.dispose(allocs).unwrap();
+        }
+        self.num_folded_insts += folded.len();
+    }
+
+    fn rewrite_branches(&mut self, func: FuncID, solver: &SccpSolver) {
+        let allocs = &self.module.allocs;
+        let mut folded: Vec<(BlockID, BlockID, SmallVec<[BlockID; 4]>)> = Vec::new();
+        for (block, _) in func.blocks_iter(allocs) {
+            if !solver.exec_blocks.contains(&block) {
+                continue;
+            }
+            let (taken, succs) = match block.get_terminator_inst(allocs).deref_ir(allocs) {
+                InstObj::Br(br) => {
+                    let Some(cond) = solver.get_value(br.get_cond(allocs)).as_apint() else {
+                        continue;
+                    };
+                    let (Some(then_bb), Some(else_bb)) = (br.get_then(allocs), br.get_else(allocs))
+                    else {
+                        continue;
+                    };
+                    let taken = if cond.is_nonzero() { then_bb } else { else_bb };
+                    (taken, SmallVec::from_slice(&[then_bb, else_bb]))
+                }
+                InstObj::Switch(switch) => {
+                    let Some(c) = solver.get_value(switch.get_discrim(allocs)).as_apint() else {
+                        continue;
+                    };
+                    let Some(taken) = switch.find_const_case_or_default(allocs, c) else {
+                        continue;
+                    };
+                    let mut succs: SmallVec<[BlockID; 4]> = switch
+                        .cases_iter(allocs)
+                        .filter_map(|(_, _, bb)| bb)
+                        .collect();
+                    succs.extend(switch.get_default_bb(allocs));
+                    (taken, succs)
+                }
+                _ => continue,
+            };
+            folded.push((block, taken, succs));
+        }
+
+        for (block, taken, succs) in &folded {
+            // 同一个后继可能出现在多条边上, 只删一次 phi 的对应项
+            for (i, &dropped) in succs.iter().enumerate() {
+                if dropped == *taken || succs[..i].contains(&dropped) {
+                    continue;
+                }
+                for phi in block_phis(allocs, dropped) {
+                    phi.deref_ir(allocs).remove_incoming(allocs, *block);
+                }
+            }
+            let jump = JumpInstID::with_target(allocs, *taken);
+            // 旧的 br/switch 指令由 ManagedInst 负责 dispose
+            let old = block.set_terminator_inst(allocs, jump.raw_into());
+            assert!(
+                old.is_some(),
+                "Internal error: folded branch block lost its terminator"
+            );
+        }
+        self.num_folded_branches += folded.len();
+    }
+}
+
+/// SCCP 求解器: 同时维护可执行边和 SSA 值的格.
+pub struct SccpSolver<'ir> {
+    allocs: &'ir IRAllocs,
+    pub values: HashMap<InstID, SccpValue>,
+    pub exec_blocks: HashSet<BlockID>,
+    pub exec_edges: HashSet<(BlockID, BlockID)>,
+    block_queue: VecDeque<BlockID>,
+    inst_queue: VecDeque<InstID>,
+}
+
+impl<'ir> SccpSolver<'ir> {
+    pub fn new(allocs: &'ir IRAllocs) -> Self {
+        Self {
+            allocs,
+            values: HashMap::new(),
+            exec_blocks: HashSet::new(),
+            exec_edges: HashSet::new(),
+            block_queue: VecDeque::new(),
+            inst_queue: VecDeque::new(),
+        }
+    }
+
+    pub fn get_inst_value(&self, inst: InstID) -> SccpValue {
+        self.values.get(&inst).copied().unwrap_or_default()
+    }
+    pub fn get_value(&self, value: ValueSSA) -> SccpValue {
+        match value {
+            ValueSSA::ConstData(c) => SccpValue::from_const(c),
+            ValueSSA::Inst(inst) => self.get_inst_value(inst),
+            _ => SccpValue::Overdefined,
+        }
+    }
+    pub fn edge_executable(&self, from: BlockID, to: BlockID) -> bool {
+        self.exec_edges.contains(&(from, to))
+    }
+
+    pub fn solve(&mut self, entry: BlockID) {
+        if self.exec_blocks.insert(entry) {
+            self.block_queue.push_back(entry);
+        }
+        loop {
+            if let Some(block) = self.block_queue.pop_front() {
+                for (inst_id, _) in block.insts_iter(self.allocs) {
+                    self.visit_inst(inst_id);
+                }
+            } else if let Some(inst) = self.inst_queue.pop_front() {
+                let Some(parent) = inst.get_parent(self.allocs) else {
+                    continue;
+                };
+                if self.exec_blocks.contains(&parent) {
+                    self.visit_inst(inst);
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn mark_edge(&mut self, from: BlockID, to: BlockID) {
+        if !self.exec_edges.insert((from, to)) {
+            return;
+        }
+        if self.exec_blocks.insert(to) {
+            self.block_queue.push_back(to);
+            return;
+        }
+        // 已经可执行的块多了一条可执行的入边, 需要重新计算其中的 phi
+        for phi in block_phis(self.allocs, to) {
+            self.inst_queue.push_back(phi.raw_into());
+        }
+    }
+
+    fn update_value(&mut self, inst_id: InstID, new_value: SccpValue) {
+        let old = self.get_inst_value(inst_id);
+        let merged = old.meet(new_value);
+        if merged == old {
+            return;
+        }
+        self.values.insert(inst_id, merged);
+        let allocs = self.allocs;
+        for (_, u) in inst_id.deref_ir(allocs).user_iter(allocs) {
+            if let Some(UserID::Inst(user)) = u.user.get() {
+                self.inst_queue.push_back(user);
+            }
+        }
+    }
+
+    fn visit_inst(&mut self, inst_id: InstID) {
+        let allocs = self.allocs;
+        let inst = inst_id.deref_ir(allocs);
+        let Some(block) = inst.get_parent() else {
+            return;
+        };
+        let value = match inst {
+            InstObj::GuideNode(_) | InstObj::PhiInstEnd(_) => return,
+            InstObj::Unreachable(_) | InstObj::Ret(_) => return,
+            InstObj::Jump(jump) => {
+                if let Some(target) = jump.get_target(allocs) {
+                    self.mark_edge(block, target);
+                }
+                return;
+            }
+            InstObj::Br(br) => {
+                let then_bb = br.get_then(allocs);
+                let else_bb = br.get_else(allocs);
+                let targets = match self.get_value(br.get_cond(allocs)) {
+                    SccpValue::Undefined => [None, None],
+                    SccpValue::Const(ConstData::Int(c)) if c.is_nonzero() => [then_bb, None],
+                    SccpValue::Const(ConstData::Int(_)) => [else_bb, None],
+                    _ => [then_bb, else_bb],
+                };
+                for target in targets.into_iter().flatten() {
+                    self.mark_edge(block, target);
+                }
+                return;
+            }
+            InstObj::Switch(switch) => {
+                let discrim = self.get_value(switch.get_discrim(allocs));
+                let mut targets: SmallVec<[BlockID; 4]> = SmallVec::new();
+                match discrim {
+                    SccpValue::Undefined => {}
+                    SccpValue::Const(ConstData::Int(c)) => {
+                        targets.extend(switch.find_const_case_or_default(allocs, c));
+                    }
+                    _ => {
+                        targets.extend(switch.get_default_bb(allocs));
+                        targets.extend(switch.cases_iter(allocs).filter_map(|(_, _, bb)| bb));
+                    }
+                }
+                for target in targets {
+                    self.mark_edge(block, target);
+                }
+                return;
+            }
+            InstObj::Phi(phi) => {
+                let mut value = SccpValue::Undefined;
+                for &[val_use, blk_use] in phi.incoming_uses().iter() {
+                    let ValueSSA::Block(pred) = blk_use.get_operand(allocs) else {
+                        continue;
+                    };
+                    if !self.edge_executable(pred, block) {
+                        continue;
+                    }
+                    value = value.meet(self.get_value(val_use.get_operand(allocs)));
+                }
+                value
+            }
+            InstObj::BinOP(binop) => {
+                let lhs = self.get_value(binop.get_lhs(allocs));
+                let rhs = self.get_value(binop.get_rhs(allocs));
+                match (lhs, rhs) {
+                    (SccpValue::Const(l), SccpValue::Const(r)) => {
+                        sccp_fold_binop(binop.get_opcode(), &l, &r)
+                            .map_or(SccpValue::Overdefined, SccpValue::from_const)
+                    }
+                    (SccpValue::Overdefined, _) | (_, SccpValue::Overdefined) => {
+                        SccpValue::Overdefined
+                    }
+                    _ => SccpValue::Undefined,
+                }
+            }
+            InstObj::Cmp(cmp) => {
+                let lhs = self.get_value(cmp.get_lhs(allocs));
+                let rhs = self.get_value(cmp.get_rhs(allocs));
+                match (lhs, rhs) {
+                    (SccpValue::Const(l), SccpValue::Const(r)) => {
+                        match sccp_fold_cmp(cmp.cond, &l, &r) {
+                            Some(b) => SccpValue::Const(ConstData::Int(APInt::from(b))),
+                            None => SccpValue::Overdefined,
+                        }
+                    }
+                    (SccpValue::Overdefined, _) | (_, SccpValue::Overdefined) => {
+                        SccpValue::Overdefined
+                    }
+                    _ => SccpValue::Undefined,
+                }
+            }
+            InstObj::Cast(cast) => match self.get_value(cast.get_from(allocs)) {
+                SccpValue::Const(c) => sccp_fold_cast(cast.get_opcode(), &c, cast.get_valtype())
+                    .map_or(SccpValue::Overdefined, SccpValue::from_const),
+                other => other,
+            },
+            InstObj::Select(select) => match self.get_value(select.get_cond(allocs)) {
+                SccpValue::Undefined => SccpValue::Undefined,
+                SccpValue::Const(ConstData::Int(c)) if c.is_nonzero() => {
+                    self.get_value(select.get_then(allocs))
+                }
+                SccpValue::Const(ConstData::Int(_)) => self.get_value(select.get_else(allocs)),
+                _ => {
+                    let then_val = self.get_value(select.get_then(allocs));
+                    then_val.meet(self.get_value(select.get_else(allocs)))
+                }
+            },
+            _ => SccpValue::Overdefined,
+        };
+        self.update_value(inst_id, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{checking::assert_module_sane, inst::*, *},
+        typing::{ArchInfo, FPKind, FuncTypeID},
+    };
+
+    #[test]
+    fn test_sccp_lattice() {
+        let zero = SccpValue::from_const(ConstData::Zero(ScalarType::Int(32)));
+        let int0 = SccpValue::from_const(ConstData::Int(APInt::new(0u32, 32)));
+        let int1 = SccpValue::from_const(ConstData::Int(APInt::new(1u32, 32)));
+        assert_eq!(zero, int0);
+        assert_eq!(SccpValue::Undefined.meet(int1), int1);
+        assert_eq!(int0.meet(int1), SccpValue::Overdefined);
+        assert_eq!(
+            SccpValue::from_const(ConstData::Zero(ScalarType::Float(FPKind::Ieee32))),
+            SccpValue::Const(ConstData::Float(FPKind::Ieee32, 0.0))
+        );
+
+        let slt = sccp_fold_cmp(
+            CmpCond::SLT,
+            &ConstData::Int(APInt::new(-1i32 as u32, 32)),
+            &ConstData::Int(APInt::new(1u32, 32)),
+        );
+        assert_eq!(slt, Some(true));
+        let ult = sccp_fold_cmp(
+            CmpCond::LT,
+            &ConstData::Int(APInt::new(-1i32 as u32, 32)),
+            &ConstData::Int(APInt::new(1u32, 32)),
+        );
+        assert_eq!(ult, Some(false));
+    }
+
+    /// ```remusys-ir
+    /// define dso_local i32 @main() {
+    /// 0:
+    ///     %1 = add i32 1, 2
+    ///     %2 = icmp slt i32 %1, 5
+    ///     br i1 %2, label %4, label %3
+    /// 3:
+    ///     br label %4
+    /// 4:
+    ///     %5 = phi i32 [ %1, %0 ], [ 0, %3 ]
+    ///     ret i32 %5
+    /// }
+    /// ```
+    #[test]
+    fn test_sccp() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "sccp");
+        let i32ty = ValTypeID::Int(32);
+        let main_ty = FuncTypeID::new(builder.tctx(), i32ty, false, []);
+        let main = FuncID::builder(builder.tctx(), "main", main_ty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+        let entry = main.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(entry));
+        let exit = builder.split_block().unwrap();
+        let other = builder.split_block().unwrap();
+
+        let add = BinOPInstID::new(
+            builder.allocs(),
+            Opcode::Add,
+            APInt::new(1u32, 32).into(),
+            APInt::new(2u32, 32).into(),
+        );
+        builder.insert_inst(add).unwrap();
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, CmpCond::SLT, i32ty);
+        cmp.set_lhs(builder.allocs(), ValueSSA::Inst(add.raw_into()));
+        cmp.set_rhs(builder.allocs(), APInt::new(5u32, 32).into());
+        builder.insert_inst(cmp).unwrap();
+        builder
+            .focus_set_branch_to(ValueSSA::Inst(cmp.raw_into()), exit, other)
+            .unwrap();
+
+        builder.set_focus(IRFocus::Block(exit));
+        let phi = PhiInstID::from_incomings(
+            builder.allocs(),
+            i32ty,
+            [(entry, ValueSSA::Inst(add.raw_into())), (other, APInt::new(0u32, 32).into())],
+        );
+        builder.insert_inst(phi).unwrap();
+        let TerminatorID::Ret(ret) = exit.get_terminator(builder.allocs()) else {
+            panic!("exit block should end with ret");
+        };
+        ret.set_retval(builder.allocs(), ValueSSA::Inst(phi.raw_into()));
+
+        let module = builder.take();
+        let mut sccp = SCCP::new(&module);
+        sccp.run_on_func(main);
+        assert_eq!(sccp.num_folded_insts, 3);
+        assert_eq!(sccp.num_folded_branches, 1);
+
+        let allocs = &module.allocs;
+        let TerminatorID::Ret(ret) = exit.get_terminator(allocs) else {
+            panic!("exit block should end with ret");
+        };
+        assert_eq!(ret.get_retval(allocs), APInt::new(3u32, 32).into());
+        assert!(matches!(
+            entry.get_terminator(allocs),
+            TerminatorID::Jump(_)
+        ));
+        assert_module_sane(&module);
+    }
+
+    /// ```remusys-ir
+    /// define dso_local i32 @main(i32 %0) {
+    /// 1:
+    ///     %2 = add i32 1, 2
+    ///     switch i32 %2, label %3 [ i32 3, label %4
+    ///                               i32 4, label %5 ]
+    /// 3:
+    ///     br label %5
+    /// 4:
+    ///     br label %5
+    /// 5:
+    ///     %6 = phi i32 [ %2, %1 ], [ 0, %3 ], [ %0, %4 ]
+    ///     ret i32 %6
+    /// }
+    /// ```
+    #[test]
+    fn test_sccp_switch() {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "sccp_switch");
+        let i32ty = ValTypeID::Int(32);
+        let main_ty = FuncTypeID::new(builder.tctx(), i32ty, false, [i32ty]);
+        let main = FuncID::builder(builder.tctx(), "main", main_ty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+        let entry = main.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(entry));
+        let exit = builder.split_block().unwrap();
+        let other = builder.split_block().unwrap();
+        let three = builder.split_block().unwrap();
+        for bb in [other, three] {
+            builder.set_focus(IRFocus::Block(bb));
+            builder.focus_set_jump_to(exit).unwrap();
+        }
+
+        builder.set_focus(IRFocus::Block(entry));
+        let add = BinOPInstID::new(
+            builder.allocs(),
+            Opcode::Add,
+            APInt::new(1u32, 32).into(),
+            APInt::new(2u32, 32).into(),
+        );
+        builder.insert_inst(add).unwrap();
+        builder
+            .focus_set_switch_to(
+                ValueSSA::Inst(add.raw_into()),
+                other,
+                [(3, three), (4, exit)],
+            )
+            .unwrap();
+
+        builder.set_focus(IRFocus::Block(exit));
+        let phi = PhiInstID::from_incomings(
+            builder.allocs(),
+            i32ty,
+            [
+                (entry, ValueSSA::Inst(add.raw_into())),
+                (other, APInt::new(0u32, 32).into()),
+                (three, ValueSSA::FuncArg(main, 0)),
+            ],
+        );
+        builder.insert_inst(phi).unwrap();
+        let TerminatorID::Ret(ret) = exit.get_terminator(builder.allocs()) else {
+            panic!("exit block should end with ret");
+        };
+        ret.set_retval(builder.allocs(), ValueSSA::Inst(phi.raw_into()));
+
+        let module = builder.take();
+        let mut sccp = SCCP::new(&module);
+        sccp.run_on_func(main);
+        assert_eq!(sccp.num_folded_insts, 1);
+        assert_eq!(sccp.num_folded_branches, 1);
+
+        let allocs = &module.allocs;
+        let TerminatorID::Jump(jump) = entry.get_terminator(allocs) else {
+            panic!("constant switch should be folded into a jump");
+        };
+        assert_eq!(jump.get_target(allocs), Some(three));
+        // 从 entry 来的边已经不存在, phi 中对应的项也要删掉
+        let phi = phi.deref_ir(allocs);
+        assert_eq!(phi.find_incoming_value(allocs, entry), None);
+        assert_eq!(
+            phi.find_incoming_value(allocs, three),
+            Some(ValueSSA::FuncArg(main, 0))
+        );
+        assert_module_sane(&module);
+    }
+}
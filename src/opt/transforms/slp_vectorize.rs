@@ -0,0 +1,678 @@
+//! SLPVectorizer: 把直线代码中对相邻内存单元做的同构运算合并成 `FixVecType` 向量运算.
+//!
+//! 以同一个基本块中写入连续地址的若干条 `store` 为根, 逐层把各条 `store` 的操作数打包成一组
+//! (bundle), 组内指令操作码和类型都相同时整组换成一条向量指令:
+//!
+//! * 从连续地址读取的 `load` 变成一条向量 `load`;
+//! * 同操作码的 `BinOPInst` 变成一条向量 `BinOPInst`, 继续打包它们的操作数;
+//! * 其余的组用 `insertelement` 逐个拼成向量 (gather). 全是常量的组直接使用常量向量.
+//!
+//! 地址是否相邻由 [`ScalarEvolution::gep_offset`] 算出的 `基址 + 偏移` 判断.
+//! 向量化之后仍被树外使用的标量用 `extractelement` 取出.
+//!
+//! 所有向量指令都插在最后一条根 `store` 之前, 这会让树中的 `load` 和 `store` 越过中间的访存指令,
+//! 所以要求被越过的访存和它们互不重叠. 代价模型很简单: 每条指令代价为 1,
+//! 向量指令数 (含 gather 和 extract) 减去删除的标量指令数小于 [`SLPVectorizer::cost_threshold`] 时才向量化.
+
+use crate::{
+    SymbolStr,
+    base::APInt,
+    ir::{
+        BlockID, ConstData, FixVecID, FuncID, IRAllocs, IRBuilder, IRFocus, ISubInst, ISubInstID,
+        ITraceableValue, IValueConvert, InstID, InstObj, Module, Opcode, UseID, UserID, ValueSSA,
+        inst::{
+            BinOPInstID, GEPInstID, IndexExtractInstID, IndexInsertInstID, LoadInstID, StoreInstID,
+        },
+    },
+    opt::{ScalarEvolution, Scev, ScevRef, transforms::IFuncTransformPass},
+    typing::{FixVecType, IValType, ScalarType, ValTypeID},
+};
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+
+pub struct SLPVectorizer<'ir> {
+    pub module: &'ir Module,
+    /// 向量寄存器的位宽, 决定一组最多有几个元素.
+    pub max_vec_bits: usize,
+    /// 向量化后的代价 (向量指令数减去删除的标量指令数) 必须小于这个值.
+    pub cost_threshold: isize,
+    pub num_vectorized: usize,
+}
+
+impl<'ir> IFuncTransformPass for SLPVectorizer<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("SLPVectorizer")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        let blocks: Vec<BlockID> = func.blocks_iter(allocs).map(|(id, _)| id).collect();
+        // 每次向量化都会改变指令位置和标量演化信息, 完成一棵树之后重新分析
+        loop {
+            let Ok(scev) = ScalarEvolution::new(allocs, func) else {
+                return;
+            };
+            if !blocks
+                .iter()
+                .any(|&block| self.vectorize_block(&scev, block))
+            {
+                break;
+            }
+        }
+    }
+}
+
+/// 一次访存覆盖的地址范围 `base + rest + offset .. + size`.
+#[derive(Clone)]
+struct MemAccess {
+    base: ValueSSA,
+    /// 偏移中的非常量部分.
+    rest: Option<ScevRef>,
+    offset: i128,
+    size: usize,
+}
+
+impl MemAccess {
+    fn no_alias(&self, other: &MemAccess, allocs: &IRAllocs) -> bool {
+        if self.base == other.base && self.rest == other.rest {
+            let (a, b) = (self, other);
+            return a.offset + a.size as i128 <= b.offset || b.offset + b.size as i128 <= a.offset;
+        }
+        // 两个不同的全局变量或栈上对象一定不重叠
+        let identified = |base: ValueSSA| match base {
+            ValueSSA::Global(_) => true,
+            ValueSSA::Inst(inst) => matches!(inst.deref_ir(allocs), InstObj::Alloca(_)),
+            _ => false,
+        };
+        self.base != other.base && identified(self.base) && identified(other.base)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    /// 无法向量化, 用 `insertelement` 拼成向量.
+    Gather,
+    Load,
+    BinOP(Opcode),
+}
+
+struct TreeNode {
+    kind: NodeKind,
+    lanes: SmallVec<[ValueSSA; 8]>,
+    operands: SmallVec<[usize; 2]>,
+}
+
+/// 以一组连续 `store` 为根的同构树.
+struct SLPTree {
+    /// 按地址从低到高排列的根 `store`.
+    stores: SmallVec<[InstID; 8]>,
+    vecty: FixVecType,
+    nodes: Vec<TreeNode>,
+    /// 被向量化的标量指令 (包括根 `store`).
+    scalars: HashSet<InstID>,
+    bundle_nodes: HashMap<SmallVec<[ValueSSA; 8]>, usize>,
+}
+
+impl SLPTree {
+    fn vector_nodes(&self) -> impl Iterator<Item = &TreeNode> {
+        self.nodes.iter().filter(|n| n.kind != NodeKind::Gather)
+    }
+}
+
+impl<'ir> SLPVectorizer<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self {
+            module,
+            max_vec_bits: 128,
+            cost_threshold: 0,
+            num_vectorized: 0,
+        }
+    }
+
+    fn vectorize_block(&mut self, scev: &ScalarEvolution, block: BlockID) -> bool {
+        let allocs = &self.module.allocs;
+        let insts: Vec<InstID> = block.insts_iter(allocs).map(|(id, _)| id).collect();
+        let pos: HashMap<InstID, usize> =
+            insts.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        // 按 (基址, 非常量偏移, 类型) 给标量 store 分组
+        let mut groups: Vec<Vec<(MemAccess, InstID)>> = Vec::new();
+        let mut group_index: HashMap<(ValueSSA, Option<ScevRef>, ValTypeID), usize> =
+            HashMap::new();
+        for &inst in &insts {
+            let InstObj::Store(store) = inst.deref_ir(allocs) else {
+                continue;
+            };
+            let ty = store.source_ty;
+            if !matches!(ty, ValTypeID::Int(_) | ValTypeID::Float(_)) {
+                continue;
+            }
+            let Some(access) = self.access_of(scev, store.get_target(allocs), ty) else {
+                continue;
+            };
+            let key = (access.base, access.rest.clone(), ty);
+            let index = *group_index.entry(key).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[index].push((access, inst));
+        }
+
+        for mut group in groups {
+            group.sort_by_key(|(access, _)| access.offset);
+            for chain in self.store_chains(&group) {
+                if self.try_vectorize(scev, &pos, &chain) {
+                    self.num_vectorized += 1;
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// 把一组按偏移排序的 store 切分成地址连续、长度为 2 的幂的链.
+    fn store_chains(&self, group: &[(MemAccess, InstID)]) -> Vec<SmallVec<[InstID; 8]>> {
+        let mut chains = Vec::new();
+        let mut start = 0;
+        while start < group.len() {
+            let size = group[start].0.size;
+            let mut end = start + 1;
+            while end < group.len() && group[end].0.offset == group[end - 1].0.offset + size as i128
+            {
+                end += 1;
+            }
+            let max_lanes = (self.max_vec_bits / (size * 8)).max(1);
+            let mut i = start;
+            while end - i >= 2 && max_lanes >= 2 {
+                let lanes = 1 << (end - i).min(max_lanes).ilog2();
+                chains.push(group[i..i + lanes].iter().map(|&(_, inst)| inst).collect());
+                i += lanes;
+            }
+            start = end;
+        }
+        chains
+    }
+
+    fn access_of(&self, scev: &ScalarEvolution, ptr: ValueSSA, ty: ValTypeID) -> Option<MemAccess> {
+        let Module { allocs, tctx, .. } = self.module;
+        let size = ty.try_get_size(tctx)?;
+        let ValueSSA::Inst(inst) = ptr else {
+            return Some(MemAccess { base: ptr, rest: None, offset: 0, size });
+        };
+        if !matches!(inst.deref_ir(allocs), InstObj::GEP(_)) {
+            return Some(MemAccess { base: ptr, rest: None, offset: 0, size });
+        }
+        let offset = scev.gep_offset(allocs, tctx, GEPInstID::raw_from(inst))?;
+        let (rest, constant) = match &*offset.offset {
+            Scev::Const(c) => (None, c.as_signed()),
+            Scev::Add(lhs, rhs) if lhs.as_const().is_some() => {
+                (Some(rhs.clone()), lhs.as_const().unwrap().as_signed())
+            }
+            _ => (Some(offset.offset.clone()), 0),
+        };
+        Some(MemAccess { base: offset.base, rest, offset: constant, size })
+    }
+
+    fn inst_access(&self, scev: &ScalarEvolution, inst: InstID) -> Option<MemAccess> {
+        let allocs = &self.module.allocs;
+        match inst.deref_ir(allocs) {
+            InstObj::Load(load) => {
+                self.access_of(scev, load.get_source(allocs), load.get_valtype())
+            }
+            InstObj::Store(store) => {
+                self.access_of(scev, store.get_target(allocs), store.source_ty)
+            }
+            _ => None,
+        }
+    }
+
+    fn try_vectorize(
+        &self,
+        scev: &ScalarEvolution,
+        pos: &HashMap<InstID, usize>,
+        stores: &[InstID],
+    ) -> bool {
+        let allocs = &self.module.allocs;
+        let InstObj::Store(first) = stores[0].deref_ir(allocs) else {
+            return false;
+        };
+        let Ok(scalar) = ScalarType::try_from_ir(first.source_ty) else {
+            return false;
+        };
+        let vecty = FixVecType(scalar, stores.len().ilog2() as u8);
+        let mut tree = SLPTree {
+            stores: stores.iter().copied().collect(),
+            vecty,
+            nodes: Vec::new(),
+            scalars: stores.iter().copied().collect(),
+            bundle_nodes: HashMap::new(),
+        };
+        let values = stores
+            .iter()
+            .map(|&store| match store.deref_ir(allocs) {
+                InstObj::Store(store) => store.get_source(allocs),
+                _ => unreachable!(),
+            })
+            .collect();
+        let block = stores[0].get_parent(allocs);
+        if self.build_node(scev, &mut tree, block, values).is_none() {
+            return false;
+        }
+        let insert_pos = stores.iter().copied().max_by_key(|s| pos[s]).unwrap();
+        let Some(external) = self.external_uses(&tree, pos, insert_pos) else {
+            return false;
+        };
+        if !self.memory_safe(scev, &tree, pos, insert_pos) {
+            return false;
+        }
+        if self.cost(&tree, &external) >= self.cost_threshold {
+            return false;
+        }
+        self.emit(&tree, insert_pos, external);
+        true
+    }
+
+    /// 为一组标量建立树节点. 组内的值和已有节点部分重叠时放弃整棵树.
+    fn build_node(
+        &self,
+        scev: &ScalarEvolution,
+        tree: &mut SLPTree,
+        block: Option<BlockID>,
+        lanes: SmallVec<[ValueSSA; 8]>,
+    ) -> Option<usize> {
+        let allocs = &self.module.allocs;
+        if let Some(&node) = tree.bundle_nodes.get(&lanes) {
+            return Some(node);
+        }
+        let insts: Option<SmallVec<[InstID; 8]>> = lanes
+            .iter()
+            .map(|v| match v {
+                ValueSSA::Inst(inst) if inst.get_parent(allocs) == block => Some(*inst),
+                _ => None,
+            })
+            .collect();
+        if let Some(insts) = &insts
+            && insts.iter().any(|i| tree.scalars.contains(i))
+        {
+            return None;
+        }
+        let distinct = insts
+            .as_ref()
+            .is_some_and(|insts| insts.iter().collect::<HashSet<_>>().len() == insts.len());
+        let kind = match &insts {
+            Some(insts) if distinct => self.bundle_kind(scev, tree.vecty, insts),
+            _ => NodeKind::Gather,
+        };
+
+        let node = tree.nodes.len();
+        tree.nodes
+            .push(TreeNode { kind, lanes: lanes.clone(), operands: SmallVec::new() });
+        tree.bundle_nodes.insert(lanes.clone(), node);
+        if kind == NodeKind::Gather {
+            return Some(node);
+        }
+        let insts = insts.unwrap();
+        tree.scalars.extend(insts.iter().copied());
+        if let NodeKind::BinOP(_) = kind {
+            for operand in 0..2 {
+                let operand_lanes = insts
+                    .iter()
+                    .map(|&inst| match inst.deref_ir(allocs) {
+                        InstObj::BinOP(binop) if operand == 0 => binop.get_lhs(allocs),
+                        InstObj::BinOP(binop) => binop.get_rhs(allocs),
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                let child = self.build_node(scev, tree, block, operand_lanes)?;
+                tree.nodes[node].operands.push(child);
+            }
+        }
+        Some(node)
+    }
+
+    /// 判断一组互不相同的同块指令能否合并成一条向量指令.
+    fn bundle_kind(&self, scev: &ScalarEvolution, vecty: FixVecType, insts: &[InstID]) -> NodeKind {
+        let allocs = &self.module.allocs;
+        let elemty = vecty.get_elem().into_ir();
+        let objs: SmallVec<[&InstObj; 8]> = insts.iter().map(|i| i.deref_ir(allocs)).collect();
+        if objs.iter().any(|obj| obj.get_valtype() != elemty) {
+            return NodeKind::Gather;
+        }
+        match objs[0] {
+            InstObj::Load(_) if objs.iter().all(|obj| matches!(obj, InstObj::Load(_))) => {
+                let accesses: Option<Vec<MemAccess>> =
+                    insts.iter().map(|&i| self.inst_access(scev, i)).collect();
+                let Some(accesses) = accesses else {
+                    return NodeKind::Gather;
+                };
+                let first = &accesses[0];
+                let consecutive = accesses.iter().enumerate().all(|(lane, access)| {
+                    access.base == first.base
+                        && access.rest == first.rest
+                        && access.offset == first.offset + (lane * first.size) as i128
+                });
+                if consecutive { NodeKind::Load } else { NodeKind::Gather }
+            }
+            InstObj::BinOP(first) => {
+                let opcode = first.get_opcode();
+                let same = objs.iter().all(|obj| match obj {
+                    InstObj::BinOP(binop) => binop.get_opcode() == opcode,
+                    _ => false,
+                });
+                if same { NodeKind::BinOP(opcode) } else { NodeKind::Gather }
+            }
+            _ => NodeKind::Gather,
+        }
+    }
+
+    /// 被向量化的标量在树外的使用. 这些使用必须位于插入点之后, 否则返回 `None`.
+    fn external_uses(
+        &self,
+        tree: &SLPTree,
+        pos: &HashMap<InstID, usize>,
+        insert_pos: InstID,
+    ) -> Option<Vec<(usize, usize, UseID)>> {
+        let allocs = &self.module.allocs;
+        let mut external = Vec::new();
+        for (index, node) in tree.nodes.iter().enumerate() {
+            if node.kind == NodeKind::Gather {
+                continue;
+            }
+            for (lane, value) in node.lanes.iter().enumerate() {
+                let ValueSSA::Inst(inst) = value else {
+                    unreachable!("vectorized lanes are instructions");
+                };
+                for (use_id, u) in inst.deref_ir(allocs).user_iter(allocs) {
+                    let user = match u.user.get() {
+                        Some(UserID::Inst(user)) => user,
+                        _ => return None,
+                    };
+                    if tree.scalars.contains(&user) {
+                        continue;
+                    }
+                    if pos.get(&user).is_some_and(|&p| p <= pos[&insert_pos]) {
+                        return None;
+                    }
+                    external.push((index, lane, use_id));
+                }
+            }
+        }
+        Some(external)
+    }
+
+    /// 树中的访存指令都被移动到插入点, 它们越过的访存指令不能和它们重叠.
+    fn memory_safe(
+        &self,
+        scev: &ScalarEvolution,
+        tree: &SLPTree,
+        pos: &HashMap<InstID, usize>,
+        insert_pos: InstID,
+    ) -> bool {
+        let allocs = &self.module.allocs;
+        let loads: Vec<InstID> = tree
+            .nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Load)
+            .flat_map(|n| n.lanes.iter())
+            .map(|v| match v {
+                ValueSSA::Inst(inst) => *inst,
+                _ => unreachable!(),
+            })
+            .collect();
+        let block = insert_pos.get_parent(allocs).unwrap();
+        let window: Vec<(InstID, usize)> = block
+            .insts_iter(allocs)
+            .map(|(id, _)| id)
+            .filter_map(|id| pos.get(&id).map(|&p| (id, p)))
+            .filter(|&(_, p)| p <= pos[&insert_pos])
+            .collect();
+        let moved = loads
+            .iter()
+            .map(|&i| (i, false))
+            .chain(tree.stores.iter().map(|&i| (i, true)));
+        for (inst, is_store) in moved {
+            let Some(access) = self.inst_access(scev, inst) else {
+                return false;
+            };
+            for &(other, other_pos) in &window {
+                if other_pos <= pos[&inst] {
+                    continue;
+                }
+                let other_is_store = match other.deref_ir(allocs) {
+                    InstObj::Call(_) | InstObj::AmoRmw(_) => return false,
+                    InstObj::Store(_) => true,
+                    InstObj::Load(_) => false,
+                    _ => continue,
+                };
+                let other_in_tree = tree.scalars.contains(&other);
+                // 读越过读不需要检查; 树中的 load 总是在向量 store 之前执行, 相对顺序不变
+                let both_reads = !is_store && !other_is_store;
+                let order_kept = other_in_tree && (!is_store || other_is_store);
+                if both_reads || order_kept {
+                    continue;
+                }
+                let Some(other_access) = self.inst_access(scev, other) else {
+                    return false;
+                };
+                if !access.no_alias(&other_access, allocs) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn cost(&self, tree: &SLPTree, external: &[(usize, usize, UseID)]) -> isize {
+        let lanes = tree.stores.len() as isize;
+        let vector_nodes = tree.vector_nodes().count() as isize;
+        let gathers: isize = tree
+            .nodes
+            .iter()
+            .filter(|n| n.kind == NodeKind::Gather)
+            .map(|n| n.lanes.iter().filter(|v| !is_constant(**v)).count() as isize)
+            .sum();
+        let mut extracted: Vec<(usize, usize)> = external.iter().map(|&(n, l, _)| (n, l)).collect();
+        extracted.dedup();
+        let vector_cost = vector_nodes + 1 + gathers + extracted.len() as isize;
+        let scalar_cost = vector_nodes * lanes + lanes;
+        vector_cost - scalar_cost
+    }
+
+    fn emit(&self, tree: &SLPTree, insert_pos: InstID, external: Vec<(usize, usize, UseID)>) {
+        let Module { allocs, tctx, .. } = self.module;
+        let mut builder = IRBuilder::new(self.module);
+        builder.set_focus(IRFocus::Inst(insert_pos));
+        let mut values: Vec<Option<ValueSSA>> = vec![None; tree.nodes.len()];
+        let root = self.emit_node(&mut builder, tree, 0, &mut values);
+
+        let InstObj::Store(first) = tree.stores[0].deref_ir(allocs) else {
+            unreachable!();
+        };
+        let store = StoreInstID::new(allocs, root, first.get_target(allocs), first.align_log2);
+        builder
+            .insert_inst(store)
+            .expect("Internal error: failed to insert vector store");
+
+        // 树外仍然需要的标量从向量中取出
+        let mut extracts: HashMap<(usize, usize), ValueSSA> = HashMap::new();
+        for (node, lane, use_id) in external {
+            let extract = *extracts.entry((node, lane)).or_insert_with(|| {
+                let index = APInt::new(lane as u32, 32).into();
+                let extract = IndexExtractInstID::new(allocs, tctx, values[node].unwrap(), index);
+                builder
+                    .insert_inst(extract)
+                    .expect("Internal error: failed to insert extract");
+                ValueSSA::Inst(extract.raw_into())
+            });
+            use_id.set_operand(allocs, extract);
+        }
+
+        // 按逆序删除标量指令, 使用者总是先于被使用者删除
+        let block = insert_pos.get_parent(allocs).unwrap();
+        let insts: Vec<InstID> = block.insts_iter(allocs).map(|(id, _)| id).collect();
+        for inst in insts.into_iter().rev() {
+            if tree.scalars.contains(&inst) {
+                builder
+                    .remove_inst(inst)
+                    .expect("Internal error: failed to remove vectorized scalar");
+                inst.dispose(allocs).unwrap();
+            }
+        }
+    }
+
+    fn emit_node(
+        &self,
+        builder: &mut IRBuilder<&Module>,
+        tree: &SLPTree,
+        index: usize,
+        values: &mut Vec<Option<ValueSSA>>,
+    ) -> ValueSSA {
+        if let Some(value) = values[index] {
+            return value;
+        }
+        let allocs = &self.module.allocs;
+        let node = &tree.nodes[index];
+        let vecty = tree.vecty;
+        let value = match node.kind {
+            NodeKind::Gather => self.emit_gather(builder, vecty, &node.lanes),
+            NodeKind::Load => {
+                let ValueSSA::Inst(first) = node.lanes[0] else {
+                    unreachable!();
+                };
+                let InstObj::Load(first) = first.deref_ir(allocs) else {
+                    unreachable!();
+                };
+                let load = LoadInstID::new_uninit(allocs, vecty.into_ir(), first.align_log2);
+                load.set_source(allocs, first.get_source(allocs));
+                builder
+                    .insert_inst(load)
+                    .expect("Internal error: failed to insert vector load");
+                ValueSSA::Inst(load.raw_into())
+            }
+            NodeKind::BinOP(opcode) => {
+                let lhs = self.emit_node(builder, tree, node.operands[0], values);
+                let rhs = self.emit_node(builder, tree, node.operands[1], values);
+                let binop = BinOPInstID::new(allocs, opcode, lhs, rhs);
+                builder
+                    .insert_inst(binop)
+                    .expect("Internal error: failed to insert vector binop");
+                ValueSSA::Inst(binop.raw_into())
+            }
+        };
+        values[index] = Some(value);
+        value
+    }
+
+    /// 常量部分直接放进常量向量, 其余元素用 `insertelement` 逐个插入.
+    fn emit_gather(
+        &self,
+        builder: &mut IRBuilder<&Module>,
+        vecty: FixVecType,
+        lanes: &[ValueSSA],
+    ) -> ValueSSA {
+        let Module { allocs, tctx, .. } = self.module;
+        let undef = ValueSSA::ConstData(ConstData::Undef(vecty.get_elem().into_ir()));
+        let consts = FixVecID::new_uninit(allocs, vecty);
+        for (lane, &value) in lanes.iter().enumerate() {
+            let elem = if is_constant(value) { value } else { undef };
+            consts.set_elem(allocs, lane, elem);
+        }
+        let mut vector = consts.into_value();
+        for (lane, &value) in lanes.iter().enumerate() {
+            if is_constant(value) {
+                continue;
+            }
+            let index = APInt::new(lane as u32, 32).into();
+            let insert = IndexInsertInstID::new(allocs, tctx, vector, value, index);
+            builder
+                .insert_inst(insert)
+                .expect("Internal error: failed to insert insertelement");
+            vector = ValueSSA::Inst(insert.raw_into());
+        }
+        vector
+    }
+}
+
+fn is_constant(value: ValueSSA) -> bool {
+    matches!(
+        value,
+        ValueSSA::ConstData(_) | ValueSSA::ConstExpr(_) | ValueSSA::AggrZero(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{IRWriteOption, ISubGlobalID, checking::assert_module_sane, write_ir_to_file},
+        testing::cases::test_case_vec_add,
+    };
+
+    fn count_insts(module: &Module, func: FuncID, pred: impl Fn(&InstObj) -> bool) -> usize {
+        let allocs = &module.allocs;
+        func.blocks_iter(allocs)
+            .map(|(block, _)| {
+                block
+                    .insts_iter(allocs)
+                    .filter(|(_, inst)| pred(inst))
+                    .count()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_slp_vectorize() {
+        let module = test_case_vec_add().module;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        let mut pass = SLPVectorizer::new(&module);
+        pass.run_on_func(func);
+        write_ir_to_file(
+            "../target/test-slp-vectorize.ll",
+            &module,
+            IRWriteOption::loud(),
+        );
+        assert_module_sane(&module);
+        assert_eq!(pass.num_vectorized, 1);
+
+        let vecty = ValTypeID::FixVec(FixVecType(ScalarType::Int(32), 2));
+        let stores = count_insts(&module, func, |inst| matches!(inst, InstObj::Store(_)));
+        let vec_stores = count_insts(&module, func, |inst| match inst {
+            InstObj::Store(store) => store.source_ty == vecty,
+            _ => false,
+        });
+        assert_eq!((stores, vec_stores), (1, 1));
+        let vec_loads = count_insts(&module, func, |inst| {
+            matches!(inst, InstObj::Load(_)) && inst.get_valtype() == vecty
+        });
+        assert_eq!(vec_loads, 2);
+        // `x` 需要逐个插入, 返回值 `s0` 需要取出
+        let inserts = count_insts(&module, func, |inst| {
+            matches!(inst, InstObj::IndexInsert(_))
+        });
+        let extracts = count_insts(&module, func, |inst| {
+            matches!(inst, InstObj::IndexExtract(_))
+        });
+        assert_eq!((inserts, extracts), (4, 1));
+    }
+
+    #[test]
+    fn test_slp_cost_threshold() {
+        let module = test_case_vec_add().module;
+        let func = module
+            .get_global_by_name("main")
+            .map(FuncID::raw_from)
+            .expect("test case has no main function");
+        let mut pass = SLPVectorizer::new(&module);
+        pass.cost_threshold = -16;
+        pass.run_on_func(func);
+        assert_eq!(pass.num_vectorized, 0);
+        let stores = count_insts(&module, func, |inst| matches!(inst, InstObj::Store(_)));
+        assert_eq!(stores, 4);
+    }
+}
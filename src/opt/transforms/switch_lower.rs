@@ -0,0 +1,701 @@
+//! SwitchLowering: 按分支的密度把 `switch` 拆成跳转表、二分判定树和位测试.
+//!
+//! 先把各个分支按有符号值排序, 从小到大贪心地划分成簇, 见 [`plan_switch`]:
+//!
+//! * 跳转表: 分支足够多也足够密集的区间, 保留成只含这些分支的 `switch`, 由指令选择翻译成跳转表.
+//!   目标不支持跳转表时不会生成这种簇, 整条 `switch` 都会变成普通的条件跳转.
+//! * 位测试: 跨度不超过寄存器位宽、目标不超过 3 个的区间. 用 `1 << (x - lo)` 和每个目标的掩码相与来判断.
+//! * 区间: 值连续、目标相同的分支, 用一到两次比较判断.
+//!
+//! 然后以簇为叶子建立平衡的二分判定树, 每个内部结点是一条 `icmp slt` 加 `br`.
+//! 判定树记录着每个结点上判别值的取值范围, 范围已经落在簇内时省掉簇的边界检查.
+
+use crate::{
+    SymbolStr,
+    base::APInt,
+    ir::{
+        BlockID, CmpCond, FuncID, IRAllocs, ISubInstID, ISubValueSSA, InstID, Module, Opcode,
+        TerminatorID, ValueSSA,
+        inst::{BinOPInstID, BrInstID, CastInstID, CmpInstID, JumpInstID, PhiInstID, SwitchInstID},
+    },
+    opt::transforms::{IFuncTransformPass, block_phis},
+    typing::{ArchInfo, ValTypeID},
+};
+use smallvec::SmallVec;
+
+/// 选择 `switch` 降级策略的参数.
+#[derive(Debug, Clone, Copy)]
+pub struct SwitchLowerConfig {
+    /// 目标是否支持跳转表.
+    pub jump_tables: bool,
+    /// 跳转表至少包含的分支数.
+    pub min_jump_table_cases: usize,
+    /// 跳转表的表项数最多是分支数的几倍.
+    pub max_jump_table_sparsity: u64,
+    /// 位测试使用的整数位宽, 也是位测试簇的最大跨度.
+    pub bit_test_nbits: u8,
+}
+
+impl SwitchLowerConfig {
+    /// 与汇编输出选择跳转表的条件保持一致: 至少 4 个分支, 表项数小于分支数的 3 倍.
+    pub fn for_arch(arch: &ArchInfo) -> Self {
+        Self {
+            jump_tables: true,
+            min_jump_table_cases: 4,
+            max_jump_table_sparsity: 3,
+            bit_test_nbits: arch.reg_nbits.min(64) as u8,
+        }
+    }
+}
+
+/// `switch` 中一段值域上的分支及其判断方式. 值都是按判别值位宽符号扩展后的有符号数.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwitchCluster {
+    /// `lo..=hi` 中的值都跳到 `target`.
+    Range { lo: i64, hi: i64, target: BlockID },
+    /// `lo..=hi` 中的分支查表, 表中没有的值跳到 default.
+    JumpTable { lo: i64, hi: i64, cases: Vec<(i64, BlockID)> },
+    /// `lo..=hi` 中的值 `x`: `1 << (x - lo)` 与某组的掩码相交时跳到该组的目标, 否则跳到 default.
+    BitTest { lo: i64, hi: i64, groups: Vec<(u64, BlockID)> },
+}
+
+impl SwitchCluster {
+    pub fn lo(&self) -> i64 {
+        match self {
+            Self::Range { lo, .. } | Self::JumpTable { lo, .. } | Self::BitTest { lo, .. } => *lo,
+        }
+    }
+    pub fn hi(&self) -> i64 {
+        match self {
+            Self::Range { hi, .. } | Self::JumpTable { hi, .. } | Self::BitTest { hi, .. } => *hi,
+        }
+    }
+}
+
+/// `lo..=hi` 包含的值的个数.
+fn span(lo: i64, hi: i64) -> u64 {
+    (hi as i128 - lo as i128 + 1) as u64
+}
+
+/// 把按值从小到大排好、值互不相同的分支划分成簇.
+pub fn plan_switch(cases: &[(i64, BlockID)], config: &SwitchLowerConfig) -> Vec<SwitchCluster> {
+    let mut clusters = Vec::new();
+    let mut i = 0;
+    while i < cases.len() {
+        let cluster = try_jump_table(&cases[i..], config)
+            .or_else(|| try_bit_test(&cases[i..], config))
+            .unwrap_or_else(|| take_range(&cases[i..]));
+        i += cases[i..]
+            .iter()
+            .take_while(|&&(v, _)| v <= cluster.hi())
+            .count();
+        clusters.push(cluster);
+    }
+    clusters
+}
+
+/// 从 `cases[0]` 开始、尽量长的密集区间. 合并成区间以后还剩不到 `min_jump_table_cases` 段的不用跳转表.
+fn try_jump_table(cases: &[(i64, BlockID)], config: &SwitchLowerConfig) -> Option<SwitchCluster> {
+    if !config.jump_tables || config.min_jump_table_cases == 0 {
+        return None;
+    }
+    let lo = cases[0].0;
+    let n = (config.min_jump_table_cases..=cases.len())
+        .rev()
+        .find(|&n| {
+            span(lo, cases[n - 1].0) <= n as u64 * config.max_jump_table_sparsity
+                && ranges_of(&cases[..n]).count() >= config.min_jump_table_cases
+        })?;
+    let cases = cases[..n].to_vec();
+    Some(SwitchCluster::JumpTable { lo, hi: cases[n - 1].0, cases })
+}
+
+/// 从 `cases[0]` 开始、跨度不超过位宽且目标不超过 3 个的最长区间.
+/// 只有逐个比较至少要 3 次 (1 个目标)、5 次 (2 个目标) 或 6 次 (3 个目标) 时才值得用位测试.
+fn try_bit_test(cases: &[(i64, BlockID)], config: &SwitchLowerConfig) -> Option<SwitchCluster> {
+    let lo = cases[0].0;
+    let mut targets: SmallVec<[BlockID; 3]> = SmallVec::new();
+    let mut n = 0;
+    for &(value, target) in cases {
+        if span(lo, value) > config.bit_test_nbits as u64 {
+            break;
+        }
+        if !targets.contains(&target) {
+            if targets.len() == 3 {
+                break;
+            }
+            targets.push(target);
+        }
+        n += 1;
+    }
+    let cases = &cases[..n];
+    let num_cmps: usize = ranges_of(cases)
+        .map(|(lo, hi, _)| if lo == hi { 1 } else { 2 })
+        .sum();
+    let min_cmps = match targets.len() {
+        1 => 3,
+        2 => 5,
+        _ => 6,
+    };
+    if num_cmps < min_cmps {
+        return None;
+    }
+    let mut groups: Vec<(u64, BlockID)> = targets.iter().map(|&bb| (0, bb)).collect();
+    for &(value, target) in cases {
+        let group = groups.iter_mut().find(|(_, bb)| *bb == target).unwrap();
+        group.0 |= 1 << (value - lo);
+    }
+    // 分支多的目标先测试
+    groups.sort_by_key(|(mask, _)| std::cmp::Reverse(mask.count_ones()));
+    Some(SwitchCluster::BitTest { lo, hi: cases[n - 1].0, groups })
+}
+
+fn take_range(cases: &[(i64, BlockID)]) -> SwitchCluster {
+    let (lo, hi, target) = ranges_of(cases).next().unwrap();
+    SwitchCluster::Range { lo, hi, target }
+}
+
+/// 把分支合并成值连续、目标相同的区间.
+fn ranges_of(cases: &[(i64, BlockID)]) -> impl Iterator<Item = (i64, i64, BlockID)> + '_ {
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        let &(lo, target) = cases.get(i)?;
+        let mut hi = lo;
+        i += 1;
+        while let Some(&(value, bb)) = cases.get(i) {
+            if bb != target || value != hi.wrapping_add(1) {
+                break;
+            }
+            hi = value;
+            i += 1;
+        }
+        Some((lo, hi, target))
+    })
+}
+
+pub struct SwitchLowering<'ir> {
+    pub module: &'ir Module,
+    pub config: SwitchLowerConfig,
+    pub num_lowered: usize,
+}
+
+impl<'ir> IFuncTransformPass for SwitchLowering<'ir> {
+    fn get_name(&self) -> SymbolStr {
+        SymbolStr::new("SwitchLowering")
+    }
+
+    fn run_on_func(&mut self, func: FuncID) {
+        let allocs = &self.module.allocs;
+        if func.get_entry(allocs).is_none() {
+            return;
+        }
+        let switches: Vec<(BlockID, SwitchInstID)> = func
+            .blocks_iter(allocs)
+            .filter_map(|(block, _)| match block.get_terminator(allocs) {
+                TerminatorID::Switch(sw) => Some((block, sw)),
+                _ => None,
+            })
+            .collect();
+        for (block, sw) in switches {
+            if self.lower_switch(block, sw) {
+                self.num_lowered += 1;
+            }
+        }
+    }
+}
+
+impl<'ir> SwitchLowering<'ir> {
+    pub fn new(module: &'ir Module) -> Self {
+        Self::with_config(module, SwitchLowerConfig::for_arch(&module.tctx.arch))
+    }
+    pub fn with_config(module: &'ir Module, config: SwitchLowerConfig) -> Self {
+        Self { module, config, num_lowered: 0 }
+    }
+
+    /// 降级 `block` 末尾的 `sw`. 整条 `switch` 就是一张跳转表或者判别值超过 64 位时保持原样, 返回 `false`.
+    fn lower_switch(&self, block: BlockID, sw: SwitchInstID) -> bool {
+        let allocs = &self.module.allocs;
+        let bits = sw.discrim_ty(allocs).0;
+        let Some(default) = sw.get_default_bb(allocs) else {
+            return false;
+        };
+        if bits > 64 {
+            return false;
+        }
+        // 跳到 default 的分支不需要单独判断
+        let mut cases: Vec<(i64, BlockID)> = sw
+            .cases_iter(allocs)
+            .filter_map(|(_, value, bb)| {
+                let value = APInt::new(value as u64, bits).as_signed() as i64;
+                bb.filter(|&bb| bb != default).map(|bb| (value, bb))
+            })
+            .collect();
+        cases.sort_unstable_by_key(|&(value, _)| value);
+        cases.dedup_by_key(|&mut (value, _)| value);
+        let clusters = plan_switch(&cases, &self.config);
+        if let [SwitchCluster::JumpTable { cases: tab, .. }] = clusters.as_slice()
+            && tab.len() == sw.cases_iter(allocs).len()
+        {
+            return false;
+        }
+
+        // 各个目标的 Phi 在 `block` 上的传入值, 改写完以后转给新的前驱
+        let mut targets: SmallVec<[BlockID; 8]> = SmallVec::new();
+        for bb in cases.iter().map(|&(_, bb)| bb).chain([default]) {
+            if !targets.contains(&bb) {
+                targets.push(bb);
+            }
+        }
+        let incomings: Vec<(BlockID, PhiInstID, ValueSSA)> = targets
+            .iter()
+            .flat_map(|&target| {
+                block_phis(allocs, target)
+                    .into_iter()
+                    .filter_map(move |phi| {
+                        let value = phi.find_incoming_value(allocs, block)?;
+                        Some((target, phi, value))
+                    })
+            })
+            .collect();
+
+        let func = block
+            .get_parent_func(allocs)
+            .expect("Internal error: switch block is not attached to a function");
+        let mut emitter = SwitchEmitter {
+            allocs,
+            func,
+            discrim: sw.get_discrim(allocs),
+            bits,
+            default,
+            bit_test_nbits: self.config.bit_test_nbits,
+            last: block,
+            new_blocks: Vec::new(),
+        };
+        let min = i64::MIN >> (64 - bits as u32);
+        let mut code = Vec::new();
+        let term = emitter.lower_tree(&mut code, &clusters, min, !min);
+        let insts = block.get_insts(allocs);
+        let switch_inst = block.get_terminator_inst(allocs);
+        for inst in code {
+            insts
+                .node_add_prev(switch_inst, inst, &allocs.insts)
+                .expect("Internal error: failed to insert switch lowering code");
+        }
+        // 旧的 switch 由 ManagedInst 负责 dispose
+        drop(block.set_terminator_inst(allocs, term));
+
+        let mut preds: Vec<(BlockID, BlockID)> = Vec::new();
+        for &from in [block].iter().chain(&emitter.new_blocks) {
+            for to in from.get_terminator(allocs).blocks_iter(allocs).flatten() {
+                if !preds.contains(&(from, to)) {
+                    preds.push((from, to));
+                }
+            }
+        }
+        for (target, phi, value) in incomings {
+            let phi = phi.deref_ir(allocs);
+            phi.remove_incoming(allocs, block);
+            for &(from, _) in preds.iter().filter(|&&(_, to)| to == target) {
+                phi.set_incoming(allocs, from, value);
+            }
+        }
+        true
+    }
+}
+
+/// 生成判定树. 每个结点先把块内的指令收集起来, 子结点的块建好以后再确定终结指令.
+struct SwitchEmitter<'ir> {
+    allocs: &'ir IRAllocs,
+    func: FuncID,
+    discrim: ValueSSA,
+    bits: u8,
+    default: BlockID,
+    bit_test_nbits: u8,
+    /// 新块依次插在它的后面.
+    last: BlockID,
+    new_blocks: Vec<BlockID>,
+}
+
+impl<'ir> SwitchEmitter<'ir> {
+    /// 判别值已知在 `lo..=hi` 中时判断 `clusters`. 指令追加到 `code`, 返回结点的终结指令.
+    fn lower_tree(
+        &mut self,
+        code: &mut Vec<InstID>,
+        clusters: &[SwitchCluster],
+        lo: i64,
+        hi: i64,
+    ) -> InstID {
+        match clusters {
+            [] => JumpInstID::with_target(self.allocs, self.default).raw_into(),
+            [cluster] => self.lower_cluster(code, cluster, lo, hi),
+            _ => {
+                let mid = clusters.len() / 2;
+                let pivot = clusters[mid].lo();
+                let left = self.node_block(|this, code| {
+                    this.lower_tree(code, &clusters[..mid], lo, pivot - 1)
+                });
+                let right = self
+                    .node_block(|this, code| this.lower_tree(code, &clusters[mid..], pivot, hi));
+                let cond = self.icmp(code, CmpCond::SLT, self.discrim, self.konst(pivot));
+                BrInstID::new(self.allocs, cond, left, right).raw_into()
+            }
+        }
+    }
+
+    fn lower_cluster(
+        &mut self,
+        code: &mut Vec<InstID>,
+        cluster: &SwitchCluster,
+        lo: i64,
+        hi: i64,
+    ) -> InstID {
+        let (x, allocs) = (self.discrim, self.allocs);
+        let covered = lo >= cluster.lo() && hi <= cluster.hi();
+        match cluster {
+            &SwitchCluster::Range { lo: a, hi: b, target } => {
+                let cond = if covered {
+                    return JumpInstID::with_target(allocs, target).raw_into();
+                } else if a == b {
+                    self.icmp(code, CmpCond::EQ, x, self.konst(a))
+                } else if lo >= a {
+                    self.icmp(code, CmpCond::SLE, x, self.konst(b))
+                } else if hi <= b {
+                    self.icmp(code, CmpCond::SGE, x, self.konst(a))
+                } else {
+                    let offset = self.binop(code, Opcode::Sub, x, self.konst(a));
+                    self.icmp(code, CmpCond::LE, offset, self.konst(b.wrapping_sub(a)))
+                };
+                BrInstID::new(allocs, cond, target, self.default).raw_into()
+            }
+            SwitchCluster::JumpTable { cases, .. } => {
+                SwitchInstID::from_cases(allocs, x, cases.iter().copied(), self.default).raw_into()
+            }
+            &SwitchCluster::BitTest { lo: a, hi: b, ref groups } => {
+                let offset = match a {
+                    0 => x,
+                    _ => self.binop(code, Opcode::Sub, x, self.konst(a)),
+                };
+                if covered {
+                    return self.lower_bit_test(code, offset, groups);
+                }
+                let test = self.node_block(|this, code| this.lower_bit_test(code, offset, groups));
+                let cond = self.icmp(code, CmpCond::LE, offset, self.konst(b.wrapping_sub(a)));
+                BrInstID::new(allocs, cond, test, self.default).raw_into()
+            }
+        }
+    }
+
+    /// `offset` 已知小于位测试的位宽.
+    fn lower_bit_test(
+        &mut self,
+        code: &mut Vec<InstID>,
+        offset: ValueSSA,
+        groups: &[(u64, BlockID)],
+    ) -> InstID {
+        let nbits = self.bit_test_nbits;
+        let ty = ValTypeID::Int(nbits);
+        let offset = match self.bits.cmp(&nbits) {
+            std::cmp::Ordering::Less => self.cast(code, Opcode::Zext, offset, ty),
+            std::cmp::Ordering::Greater => self.cast(code, Opcode::Trunc, offset, ty),
+            std::cmp::Ordering::Equal => offset,
+        };
+        let one = APInt::new(1u64, nbits).into();
+        let bit = self.binop(code, Opcode::Shl, one, offset);
+        // 从最后一组往前建块, 第一组放在当前块里
+        let mut next = self.default;
+        for &(mask, target) in groups[1..].iter().rev() {
+            next = self.node_block(|this, code| this.lower_mask(code, bit, mask, target, next));
+        }
+        let (mask, target) = groups[0];
+        self.lower_mask(code, bit, mask, target, next)
+    }
+
+    fn lower_mask(
+        &mut self,
+        code: &mut Vec<InstID>,
+        bit: ValueSSA,
+        mask: u64,
+        target: BlockID,
+        next: BlockID,
+    ) -> InstID {
+        let nbits = self.bit_test_nbits;
+        let masked = self.binop(code, Opcode::BitAnd, bit, APInt::new(mask, nbits).into());
+        let zero = APInt::new(0u64, nbits).into();
+        let cond = self.icmp(code, CmpCond::NE, masked, zero);
+        BrInstID::new(self.allocs, cond, target, next).raw_into()
+    }
+
+    /// 新建一个块放置 `build` 生成的结点.
+    fn node_block(&mut self, build: impl FnOnce(&mut Self, &mut Vec<InstID>) -> InstID) -> BlockID {
+        let mut code = Vec::new();
+        let term = build(self, &mut code);
+        let allocs = self.allocs;
+        let block = BlockID::new_uninit(allocs);
+        block.set_terminator_inst(allocs, term);
+        self.func
+            .blocks_unwrap(allocs)
+            .node_add_next(self.last, block, &allocs.blocks)
+            .expect("Internal error: failed to insert switch lowering block");
+        self.last = block;
+        self.new_blocks.push(block);
+        let insts = block.get_insts(allocs);
+        for inst in code {
+            insts
+                .node_add_prev(block.get_terminator_inst(allocs), inst, &allocs.insts)
+                .expect("Internal error: failed to insert switch lowering code");
+        }
+        block
+    }
+
+    fn konst(&self, value: i64) -> ValueSSA {
+        APInt::new(value as u64, self.bits).into()
+    }
+    fn icmp(
+        &self,
+        code: &mut Vec<InstID>,
+        cond: CmpCond,
+        lhs: ValueSSA,
+        rhs: ValueSSA,
+    ) -> ValueSSA {
+        let allocs = self.allocs;
+        let cmp = CmpInstID::new_uninit(allocs, Opcode::Icmp, cond, lhs.get_valtype(allocs));
+        cmp.set_lhs(allocs, lhs);
+        cmp.set_rhs(allocs, rhs);
+        code.push(cmp.raw_into());
+        ValueSSA::Inst(cmp.raw_into())
+    }
+    fn binop(&self, code: &mut Vec<InstID>, op: Opcode, lhs: ValueSSA, rhs: ValueSSA) -> ValueSSA {
+        let inst = BinOPInstID::new(self.allocs, op, lhs, rhs);
+        code.push(inst.raw_into());
+        ValueSSA::Inst(inst.raw_into())
+    }
+    fn cast(&self, code: &mut Vec<InstID>, op: Opcode, from: ValueSSA, ty: ValTypeID) -> ValueSSA {
+        let inst = CastInstID::new(self.allocs, op, from, ty);
+        code.push(inst.raw_into());
+        ValueSSA::Inst(inst.raw_into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::APInt,
+        ir::{
+            ConstData, IRBuilder, IRFocus, ISubInst, ITraceableValue, InstObj,
+            checking::{assert_func_dominance, assert_module_sane},
+            inst::RetInstID,
+        },
+        opt::{sccp_fold_binop, sccp_fold_cast, sccp_fold_cmp},
+        typing::FuncTypeID,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_plan_switch() {
+        let builder = IRBuilder::new_inlined(ArchInfo::new_host(), "plan");
+        let [a, b] = [(); 2].map(|_| BlockID::new_uninit(builder.allocs()));
+        let mut config = SwitchLowerConfig::for_arch(&ArchInfo { ptr_nbits: 64, reg_nbits: 64 });
+        let dense: Vec<(i64, BlockID)> = (0..6)
+            .map(|v| (v, if v % 2 == 0 { a } else { b }))
+            .chain([(1000, a)])
+            .collect();
+        let clusters = plan_switch(&dense, &config);
+        assert_eq!(
+            clusters,
+            [
+                SwitchCluster::JumpTable { lo: 0, hi: 5, cases: dense[..6].to_vec() },
+                SwitchCluster::Range { lo: 1000, hi: 1000, target: a },
+            ]
+        );
+
+        // 没有跳转表时改用位测试, 分支一样多的目标按出现顺序测试
+        config.jump_tables = false;
+        let clusters = plan_switch(&dense, &config);
+        assert_eq!(
+            clusters[0],
+            SwitchCluster::BitTest { lo: 0, hi: 5, groups: vec![(0b010101, a), (0b101010, b)] }
+        );
+
+        // 连续区间只需要两次比较, 不值得用跳转表或位测试
+        let ranges = [(-3, a), (-2, a), (-1, a), (0, a), (7, b)];
+        config.jump_tables = true;
+        assert_eq!(
+            plan_switch(&ranges, &config),
+            [
+                SwitchCluster::Range { lo: -3, hi: 0, target: a },
+                SwitchCluster::Range { lo: 7, hi: 7, target: b },
+            ]
+        );
+    }
+
+    /// `f(x: iN)`: `x` 为 0 到 5 时交替跳到 A 和 B, 为 -100 或 100 时跳到 M, 否则跳到 D.
+    /// A 跳到 M, M 中有一个 Phi. 返回函数和块 `[entry, A, B, M, D]`.
+    fn build_switch_func(builder: &mut IRBuilder, bits: u8) -> (FuncID, [BlockID; 5]) {
+        let ty = ValTypeID::Int(bits);
+        let i32ty = ValTypeID::Int(32);
+        let fty = FuncTypeID::new(builder.tctx(), i32ty, false, [ty]);
+        let func = FuncID::builder(builder.tctx(), "f", fty)
+            .make_defined()
+            .build_id(&builder.module)
+            .unwrap();
+        let allocs = builder.allocs();
+        let entry = func.get_entry(allocs).unwrap();
+        let c = |v: u32| -> ValueSSA { APInt::new(v, 32).into() };
+        let ret_block =
+            |v: ValueSSA| BlockID::new_with_terminator(allocs, RetInstID::with_retval(allocs, v));
+        let (a_bb, b_bb, d_bb) = (
+            BlockID::new_uninit(allocs),
+            ret_block(c(2)),
+            ret_block(c(0)),
+        );
+        let phi = PhiInstID::from_incomings(allocs, i32ty, [(entry, c(7)), (a_bb, c(8))]);
+        let m_bb = ret_block(ValueSSA::Inst(phi.raw_into()));
+        a_bb.set_terminator_inst(allocs, JumpInstID::with_target(allocs, m_bb).raw_into());
+        let mut prev = entry;
+        for bb in [a_bb, b_bb, m_bb, d_bb] {
+            func.blocks_unwrap(allocs)
+                .node_add_next(prev, bb, &allocs.blocks)
+                .unwrap();
+            prev = bb;
+        }
+        let cases = (0..6)
+            .map(|v| (v, if v % 2 == 0 { a_bb } else { b_bb }))
+            .chain([(-100, m_bb), (100, m_bb)]);
+        let sw = SwitchInstID::from_cases(allocs, ValueSSA::FuncArg(func, 0), cases, d_bb);
+        drop(entry.set_terminator_inst(allocs, sw.raw_into()));
+        builder.set_focus(IRFocus::Block(m_bb));
+        builder.insert_inst(phi).unwrap();
+        (func, [entry, a_bb, b_bb, m_bb, d_bb])
+    }
+
+    /// 从 `entry` 开始执行判定代码, 返回第一个到达的 `stops` 中的块.
+    fn dispatch(allocs: &IRAllocs, entry: BlockID, stops: &[BlockID], x: APInt) -> BlockID {
+        let mut env: HashMap<InstID, ConstData> = HashMap::new();
+        let value = |env: &HashMap<InstID, ConstData>, v: ValueSSA| match v {
+            ValueSSA::FuncArg(..) => ConstData::Int(x),
+            ValueSSA::ConstData(c) => c,
+            ValueSSA::Inst(inst) => env[&inst],
+            _ => panic!("unexpected operand {v:?}"),
+        };
+        let mut block = entry;
+        loop {
+            if block != entry && stops.contains(&block) {
+                return block;
+            }
+            for (inst, obj) in block.insts_iter(allocs) {
+                let res = match obj {
+                    InstObj::BinOP(op) => {
+                        let (l, r) = (op.get_lhs(allocs), op.get_rhs(allocs));
+                        sccp_fold_binop(op.get_opcode(), &value(&env, l), &value(&env, r))
+                    }
+                    InstObj::Cast(cast) => {
+                        let from = value(&env, cast.get_from(allocs));
+                        sccp_fold_cast(cast.get_opcode(), &from, cast.get_valtype())
+                    }
+                    InstObj::Cmp(cmp) => {
+                        let (l, r) = (cmp.get_lhs(allocs), cmp.get_rhs(allocs));
+                        let res = sccp_fold_cmp(cmp.cond, &value(&env, l), &value(&env, r));
+                        res.map(|b| ConstData::Int(APInt::new(b as u8, 1)))
+                    }
+                    _ => continue,
+                };
+                env.insert(inst, res.unwrap());
+            }
+            block = match block.get_terminator(allocs) {
+                TerminatorID::Jump(jump) => jump.get_target(allocs),
+                TerminatorID::Br(br) => {
+                    let ConstData::Int(cond) = value(&env, br.get_cond(allocs)) else {
+                        panic!("branch condition is not an integer");
+                    };
+                    if cond.is_nonzero() { br.get_then(allocs) } else { br.get_else(allocs) }
+                }
+                TerminatorID::Switch(sw) => {
+                    let ConstData::Int(v) = value(&env, sw.get_discrim(allocs)) else {
+                        panic!("switch discriminant is not an integer");
+                    };
+                    sw.find_case_or_default(allocs, v.as_signed() as i64)
+                }
+                t => panic!("unexpected terminator {t:?}"),
+            }
+            .unwrap();
+        }
+    }
+
+    fn check_lowering(bits: u8, jump_tables: bool) -> (Module, FuncID) {
+        let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "switch");
+        let (func, blocks) = build_switch_func(&mut builder, bits);
+        let [entry, _, _, m_bb, _] = blocks;
+        let allocs = builder.allocs();
+        let min = i64::MIN >> (64 - bits as u32);
+        let inputs: Vec<APInt> = (-300..300)
+            .chain([min, !min, min + 1, !min - 1])
+            .filter(|&v| v >= min && v <= !min)
+            .map(|v| APInt::new(v as u64, bits))
+            .collect();
+        let expected: Vec<BlockID> = inputs
+            .iter()
+            .map(|&x| dispatch(allocs, entry, &blocks, x))
+            .collect();
+
+        let mut config = SwitchLowerConfig::for_arch(&builder.module.tctx.arch);
+        config.jump_tables = jump_tables;
+        let mut pass = SwitchLowering::with_config(&builder.module, config);
+        pass.run_on_func(func);
+        assert_eq!(pass.num_lowered, 1);
+        assert_module_sane(&builder.module);
+        assert_func_dominance(allocs, func);
+        for (&x, &expected) in inputs.iter().zip(&expected) {
+            assert_eq!(dispatch(allocs, entry, &blocks, x), expected, "x = {x:?}");
+        }
+        // 从判定代码进入 M 的边都带着原来 entry 的传入值
+        let phi = block_phis(allocs, m_bb)[0];
+        for (pred, _) in m_bb.get_preds(allocs).iter(&allocs.jts) {
+            let from = pred
+                .get_terminator(allocs)
+                .unwrap()
+                .get_parent(allocs)
+                .unwrap();
+            let expected = if from == blocks[1] { 8 } else { 7 };
+            let value = phi.find_incoming_value(allocs, from);
+            assert_eq!(value, Some(APInt::new(expected as u32, 32).into()));
+        }
+        (builder.module, func)
+    }
+
+    #[test]
+    fn test_lower_without_jump_tables() {
+        let (module, func) = check_lowering(8, false);
+        let allocs = &module.allocs;
+        assert!(
+            func.blocks_iter(allocs)
+                .all(|(bb, _)| !matches!(bb.get_terminator(allocs), TerminatorID::Switch(_)))
+        );
+        // 0 到 5 用位测试, 移位在 64 位上进行
+        let shls = func
+            .blocks_iter(allocs)
+            .flat_map(|(bb, _)| bb.insts_iter(allocs).map(|(_, obj)| obj.get_opcode()))
+            .filter(|&op| op == Opcode::Shl)
+            .count();
+        assert_eq!(shls, 1);
+    }
+
+    #[test]
+    fn test_lower_keeps_dense_jump_table() {
+        for bits in [32, 64] {
+            let (module, func) = check_lowering(bits, true);
+            let allocs = &module.allocs;
+            let switches: Vec<SwitchInstID> = func
+                .blocks_iter(allocs)
+                .filter_map(|(bb, _)| match bb.get_terminator(allocs) {
+                    TerminatorID::Switch(sw) => Some(sw),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(switches.len(), 1);
+            let mut cases: Vec<i64> = switches[0].cases_iter(allocs).map(|(_, v, _)| v).collect();
+            cases.sort_unstable();
+            assert_eq!(cases, [0, 1, 2, 3, 4, 5]);
+        }
+    }
+}
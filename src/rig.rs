@@ -0,0 +1,305 @@
+//! RIG (Remusys Instruction Generator) DSL 编译器.
+//!
+//! RIG 描述 Remusys-MIR 的指令类: 每个类有若干输入/输出操作数、一组属性和它能承载的操作码.
+//! 模板 (`template`) 按操作数类型实例化出多个类. 语法见 `rigs/RIG-DSL-Grammar.md`,
+//! 指令描述见 `rigs/mir.rig`.
+//!
+//! 编译分三步:
+//!
+//! 1. [`parse_rig`]: 源码 → 语法树 [`RigModule`], 遇到错误时跳到下一个顶层定义继续解析;
+//! 2. [`expand_rig`]: 实例化模板, 检查重名和操作码归属, 得到 [`RigExpanded`];
+//! 3. [`generate_rust`]: 生成指令类的 Rust 代码.
+//!
+//! [`compile_rig`] 把三步串起来, 供 `build.rs` 或 `rig-gen` 生成器使用.
+
+mod ast;
+mod codegen;
+mod expand;
+mod lexer;
+mod parser;
+
+pub use self::{
+    ast::*,
+    codegen::generate_rust,
+    expand::{RigExpanded, RigInstClass, RigOperandSlot, expand_rig},
+    parser::parse_rig,
+};
+
+use crate::SymbolStr;
+use std::fmt::Write;
+use thiserror::Error;
+
+/// 源码中的字节区间 `[start, end)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RigSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl RigSpan {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+    pub fn join(self, other: RigSpan) -> Self {
+        Self::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// 1 起始的行号和列号 (列按字符计).
+    pub fn line_col(&self, source: &str) -> (usize, usize) {
+        let start = self.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map_or(0, |n| n + 1);
+        let line = source[..start].matches('\n').count() + 1;
+        let col = source[line_start..start].chars().count() + 1;
+        (line, col)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RigErrorKind {
+    #[error("unexpected character `{0}`")]
+    UnexpectedChar(char),
+    #[error("unterminated block comment")]
+    UnterminatedComment,
+    #[error("unterminated string or character literal")]
+    UnterminatedLiteral,
+    #[error("expected {expected}, found {found}")]
+    Unexpected { expected: String, found: String },
+    #[error("unclosed delimiter `{0}`")]
+    UnclosedDelim(char),
+    #[error("invalid integer literal `{0}`")]
+    BadInteger(SymbolStr),
+    #[error("unknown operand kind `{0}`")]
+    UnknownOperand(SymbolStr),
+    #[error("register width {1} is not supported by `{0}`, expected 32 or 64")]
+    BadRegWidth(SymbolStr, u64),
+    #[error("field `{0}` appears more than once")]
+    DuplicateField(&'static str),
+    #[error("property `{0}` has more than one `{1}`")]
+    DuplicatePropPart(SymbolStr, &'static str),
+    #[error("property `{0}` has neither getter nor setter")]
+    PropWithoutAccessor(SymbolStr),
+    #[error("property `{0}` is stored but has no default value")]
+    PropWithoutDefault(SymbolStr),
+    #[error("`{0}` is defined more than once")]
+    DuplicateName(SymbolStr),
+    #[error("template parameter `{0}` is declared more than once")]
+    DuplicateParam(SymbolStr),
+    #[error("template `{template}` takes {expected} argument(s) but {found} were supplied")]
+    TemplateArity { template: SymbolStr, expected: usize, found: usize },
+    #[error("template argument cannot be a template parameter (`{0}`)")]
+    ParamAsArg(SymbolStr),
+    #[error("operand `{0}` is declared more than once in class `{1}`")]
+    DuplicateOperand(SymbolStr, SymbolStr),
+    #[error("property `{0}` is declared more than once in class `{1}`")]
+    DuplicateProp(SymbolStr, SymbolStr),
+    #[error("class `{0}` has more than one `init` block")]
+    DuplicateInit(SymbolStr),
+    #[error("instruction `{0}` is already claimed by class `{1}`")]
+    DuplicateInst(SymbolStr, SymbolStr),
+    #[error("class `{0}` does not list any instruction")]
+    EmptyInsts(SymbolStr),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{kind}")]
+pub struct RigError {
+    pub kind: RigErrorKind,
+    pub span: RigSpan,
+    /// 与错误相关的另一处位置, 比如重名定义的第一次出现.
+    pub note: Option<(String, RigSpan)>,
+}
+
+impl RigError {
+    pub fn new(kind: RigErrorKind, span: RigSpan) -> Self {
+        Self { kind, span, note: None }
+    }
+    pub fn with_note(mut self, note: impl Into<String>, span: RigSpan) -> Self {
+        self.note = Some((note.into(), span));
+        self
+    }
+
+    /// 按 rustc 的风格渲染错误, 附带源码行和位置标记.
+    pub fn render(&self, file_name: &str, source: &str) -> String {
+        let mut out = format!("error: {}\n", self.kind);
+        render_snippet(&mut out, file_name, source, self.span);
+        if let Some((note, span)) = &self.note {
+            writeln!(out, "note: {note}").unwrap();
+            render_snippet(&mut out, file_name, source, *span);
+        }
+        out
+    }
+}
+
+fn render_snippet(out: &mut String, file_name: &str, source: &str, span: RigSpan) {
+    let (line, col) = span.line_col(source);
+    let line_text = source.lines().nth(line - 1).unwrap_or("");
+    let gutter = line.to_string().len();
+    let pad = " ".repeat(gutter);
+    writeln!(out, "{pad}--> {file_name}:{line}:{col}").unwrap();
+    writeln!(out, "{pad} |").unwrap();
+    writeln!(out, "{line} | {line_text}").unwrap();
+    // 标记最多画到行尾
+    let line_rest = line_text.chars().count().saturating_sub(col - 1);
+    let span_len = source
+        .get(span.start..span.end)
+        .map_or(1, |s| s.chars().take_while(|&c| c != '\n').count());
+    let marks = "^".repeat(span_len.clamp(1, line_rest.max(1)));
+    let indent = " ".repeat(col - 1);
+    writeln!(out, "{pad} | {indent}{marks}").unwrap();
+}
+
+/// 一次编译中收集到的所有错误.
+#[derive(Debug, Clone, Default, Error)]
+#[error("{} error(s) in RIG source", errors.len())]
+pub struct RigErrors {
+    pub errors: Vec<RigError>,
+}
+
+impl RigErrors {
+    pub fn render(&self, file_name: &str, source: &str) -> String {
+        let mut out = String::new();
+        for err in &self.errors {
+            out.push_str(&err.render(file_name, source));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl From<Vec<RigError>> for RigErrors {
+    fn from(errors: Vec<RigError>) -> Self {
+        Self { errors }
+    }
+}
+
+/// 解析、实例化并生成 Rust 代码. `file_name` 只用于生成代码的文件头注释.
+pub fn compile_rig(source: &str, file_name: &str) -> Result<String, RigErrors> {
+    let module = parse_rig(source)?;
+    let expanded = expand_rig(&module)?;
+    Ok(generate_rust(&expanded, file_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIR_RIG: &str = include_str!("../rigs/mir.rig");
+
+    #[test]
+    fn test_expand_mir_rig() {
+        let module =
+            parse_rig(MIR_RIG).unwrap_or_else(|e| panic!("{}", e.render("mir.rig", MIR_RIG)));
+        let expanded =
+            expand_rig(&module).unwrap_or_else(|e| panic!("{}", e.render("mir.rig", MIR_RIG)));
+
+        let names: Vec<&str> = expanded.externs.iter().map(|e| e.name.as_str()).collect();
+        assert!(names.contains(&"MirCall") && names.contains(&"MirCommentedInst"));
+
+        let cond_br = expanded.find_class("CondBr").unwrap();
+        assert!(cond_br.template.is_none());
+        assert_eq!(cond_br.ins.len(), 2);
+        assert_eq!(cond_br.ins[1].kind, RigOperandKind::PState);
+        assert_eq!(
+            cond_br.props[0].default.as_ref().unwrap().text,
+            "MirCondFlag::AL"
+        );
+
+        // 模板公共字段在前, 模板参数替换为实参
+        let bin = expanded.find_class("Bin64RL").unwrap();
+        let (tmpl, args) = bin.template.as_ref().unwrap();
+        assert_eq!(tmpl, "BinaryOP");
+        assert_eq!(args[2], RigOperandKind::ImmLogic);
+        let operands: Vec<_> = bin
+            .operands()
+            .map(|o| (o.name.name.as_str(), &o.kind))
+            .collect();
+        assert_eq!(
+            operands,
+            [
+                ("rd", &RigOperandKind::GPR64),
+                ("rn", &RigOperandKind::GPR64),
+                ("rm", &RigOperandKind::ImmLogic),
+            ]
+        );
+
+        // 模板公共属性和 impl 项自己的属性合并
+        let ccmp = expanded.find_class("ICCmp32I").unwrap();
+        let props: Vec<_> = ccmp.props.iter().map(|p| p.name.name.as_str()).collect();
+        assert_eq!(props, ["cond", "nzcv"]);
+        let indexed = expanded.find_class("LoadF32Indexed").unwrap();
+        assert_eq!(indexed.outs.len(), 2);
+        assert!(
+            indexed
+                .init
+                .as_ref()
+                .unwrap()
+                .text
+                .contains("mark_operand_used")
+        );
+
+        let opcodes: Vec<_> = expanded.opcodes().collect();
+        assert!(opcodes.iter().any(|op| *op == "FCvtZU64F32"));
+        assert!(opcodes.iter().any(|op| *op == "MirGEP"));
+    }
+
+    #[test]
+    fn test_generate_mir_rig() {
+        let code = compile_rig(MIR_RIG, "rigs/mir.rig").unwrap();
+        assert!(code.starts_with("// @generated by rig-gen from `rigs/mir.rig`"));
+        assert!(code.contains("pub struct Bin64R {"));
+        assert!(code.contains("    rm_op: Cell<Option<RegOP>>,"));
+        assert!(code.contains("pub fn get_cond(&self) -> MirCondFlag {"));
+        assert!(code.contains("            MirOP::BCond | MirOP::BCCond => MirInstClass::CondBr,"));
+        assert!(code.contains("    MirCall(MirCall),"));
+        assert!(code.contains("        MirOperandKind::ImmLSP64,"));
+        assert!(code.contains(
+            "        write!(out, \"cond: {:?}\", self.get_cond())?;\n        \
+             write!(out, \", nzcv: {:?}\", self.get_nzcv())\n"
+        ));
+    }
+
+    #[test]
+    fn test_semantic_errors() {
+        let source = "\
+class A { in: { x: GPR64 }, insts: [ Foo ] }
+class B { out: { x: GPR32 }, in: { x: GPR32 }, insts: [ Foo ] }
+extern class A;
+template[T, U] Pair {
+    in: { a: T, b: U };
+    impl {
+        [GPR64] => P1 { insts: [ P1 ] },
+        [GPR64, Label] => P2 { },
+    }
+}
+";
+        let module = parse_rig(source).unwrap();
+        let errors = expand_rig(&module).unwrap_err().errors;
+        let kinds: Vec<_> = errors.iter().map(|e| e.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            [
+                RigErrorKind::DuplicateOperand("x".into(), "B".into()),
+                RigErrorKind::DuplicateInst("Foo".into(), "A".into()),
+                RigErrorKind::DuplicateName("A".into()),
+                RigErrorKind::TemplateArity { template: "Pair".into(), expected: 2, found: 1 },
+                RigErrorKind::EmptyInsts("P2".into()),
+            ]
+        );
+        let rendered = errors[1].render("test.rig", source);
+        assert_eq!(
+            rendered,
+            "\
+error: instruction `Foo` is already claimed by class `A`
+ --> test.rig:2:57
+  |
+2 | class B { out: { x: GPR32 }, in: { x: GPR32 }, insts: [ Foo ] }
+  |                                                         ^^^
+note: first claimed here
+ --> test.rig:1:38
+  |
+1 | class A { in: { x: GPR64 }, insts: [ Foo ] }
+  |                                      ^^^
+"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! RIG 语法树. 结构与 `rigs/RIG-DSL-Grammar.md` 中的 EBNF 一一对应.
+
+use crate::{SymbolStr, rig::RigSpan};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RigIdent {
+    pub name: SymbolStr,
+    pub span: RigSpan,
+}
+
+/// 一段原样保留的 Rust 代码: 属性类型、默认值表达式或访问器/初始化块.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RigCode {
+    pub text: String,
+    pub span: RigSpan,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RigModule {
+    pub items: Vec<RigItem>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RigItem {
+    Extern(RigIdent),
+    Class(RigClass),
+    Template(RigTemplate),
+}
+
+impl RigItem {
+    pub fn get_name(&self) -> &RigIdent {
+        match self {
+            RigItem::Extern(name) => name,
+            RigItem::Class(class) => &class.name,
+            RigItem::Template(tmpl) => &tmpl.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RigClass {
+    pub name: RigIdent,
+    pub fields: RigInstFields,
+}
+
+#[derive(Debug, Clone)]
+pub struct RigTemplate {
+    pub params: Vec<RigIdent>,
+    pub name: RigIdent,
+    /// 所有实例共享的字段, 会合并到每个实例的字段前面.
+    pub fields: RigInstFields,
+    pub impls: Vec<RigTemplateImpl>,
+}
+
+/// `[GPR64, GPR64] => Bin64R { ... }`
+#[derive(Debug, Clone)]
+pub struct RigTemplateImpl {
+    pub args: Vec<RigOperand>,
+    pub class_name: RigIdent,
+    pub fields: RigInstFields,
+    pub span: RigSpan,
+}
+
+/// 指令类的字段集合. 每种字段在同一个字段列表里最多出现一次.
+#[derive(Debug, Clone, Default)]
+pub struct RigInstFields {
+    pub ins: Vec<RigOperandDecl>,
+    pub outs: Vec<RigOperandDecl>,
+    pub insts: Vec<RigIdent>,
+    pub props: Vec<RigProp>,
+    /// `init: { ... }`: 构造函数末尾执行的代码, 新建的指令对象名为 `ret`.
+    pub init: Option<RigCode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RigOperandDecl {
+    pub name: RigIdent,
+    pub operand: RigOperand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RigOperand {
+    pub kind: RigOperandKind,
+    pub span: RigSpan,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RigOperandKind {
+    /// 模板参数, 实例化之后不再出现.
+    Param(SymbolStr),
+
+    GPR32,
+    GPR64,
+    GSP32,
+    GSP64,
+    XSP,
+    WSP,
+    GPR {
+        bits: u8,
+        flags: Vec<SymbolStr>,
+    },
+
+    PState,
+    PC,
+
+    FPR32,
+    FPR64,
+    FPR128,
+    FPR {
+        bits: u8,
+        flags: Vec<SymbolStr>,
+    },
+
+    Imm32,
+    Imm64,
+    ImmCalc,
+    ImmLogic,
+    ImmSMax,
+    ImmUMax,
+    ImmShift,
+    ImmLSP32,
+    ImmLSP64,
+    ImmLSP128,
+    ImmCCmp,
+    ImmMov,
+    ImmMovZNK,
+    ImmFMov32,
+    ImmFMov64,
+
+    Label,
+    Global,
+    Symbol,
+    SwitchTab,
+    Any,
+}
+
+impl RigOperandKind {
+    /// 不带参数的内建操作数类型.
+    pub const SIMPLE: &'static [RigOperandKind] = {
+        use RigOperandKind::*;
+        &[
+            GPR32, GPR64, GSP32, GSP64, XSP, WSP, PState, PC, FPR32, FPR64, FPR128, Imm32, Imm64,
+            ImmCalc, ImmLogic, ImmSMax, ImmUMax, ImmShift, ImmLSP32, ImmLSP64, ImmLSP128, ImmCCmp,
+            ImmMov, ImmMovZNK, ImmFMov32, ImmFMov64, Label, Global, Symbol, SwitchTab, Any,
+        ]
+    };
+
+    pub fn from_simple_name(name: &str) -> Option<Self> {
+        Self::SIMPLE.iter().find(|k| k.get_name() == name).cloned()
+    }
+
+    pub fn get_name(&self) -> &str {
+        use RigOperandKind::*;
+        match self {
+            Param(name) => name.as_str(),
+            GPR32 => "GPR32",
+            GPR64 => "GPR64",
+            GSP32 => "GSP32",
+            GSP64 => "GSP64",
+            XSP => "XSP",
+            WSP => "WSP",
+            GPR { .. } => "GPR",
+            PState => "PState",
+            PC => "PC",
+            FPR32 => "FPR32",
+            FPR64 => "FPR64",
+            FPR128 => "FPR128",
+            FPR { .. } => "FPR",
+            Imm32 => "Imm32",
+            Imm64 => "Imm64",
+            ImmCalc => "ImmCalc",
+            ImmLogic => "ImmLogic",
+            ImmSMax => "ImmSMax",
+            ImmUMax => "ImmUMax",
+            ImmShift => "ImmShift",
+            ImmLSP32 => "ImmLSP32",
+            ImmLSP64 => "ImmLSP64",
+            ImmLSP128 => "ImmLSP128",
+            ImmCCmp => "ImmCCmp",
+            ImmMov => "ImmMov",
+            ImmMovZNK => "ImmMovZNK",
+            ImmFMov32 => "ImmFMov32",
+            ImmFMov64 => "ImmFMov64",
+            Label => "Label",
+            Global => "Global",
+            Symbol => "Symbol",
+            SwitchTab => "SwitchTab",
+            Any => "Any",
+        }
+    }
+
+    pub fn is_param(&self) -> bool {
+        matches!(self, RigOperandKind::Param(_))
+    }
+}
+
+impl Display for RigOperandKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RigOperandKind::GPR { bits, flags } | RigOperandKind::FPR { bits, flags } => {
+                write!(f, "{}({bits}, [", self.get_name())?;
+                for (i, flag) in flags.iter().enumerate() {
+                    let sep = if i == 0 { "" } else { ", " };
+                    write!(f, "{sep}{flag}")?;
+                }
+                f.write_str("])")
+            }
+            _ => f.write_str(self.get_name()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RigProp {
+    pub name: RigIdent,
+    pub ty: RigCode,
+    pub get: Option<RigAccessor>,
+    pub set: Option<RigAccessor>,
+    pub default: Option<RigCode>,
+}
+
+impl RigProp {
+    /// 是否需要在指令对象里存储这个属性. 只有 getter 和 setter 都是自定义代码 (或不存在) 的
+    /// 计算属性不需要存储.
+    pub fn has_storage(&self) -> bool {
+        let is_auto = |acc: &Option<RigAccessor>| matches!(acc, Some(RigAccessor::Auto));
+        is_auto(&self.get) || is_auto(&self.set) || self.default.is_some()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RigAccessor {
+    /// `get;` / `set;`: 直接读写存储的属性值.
+    Auto,
+    /// `get { ... }` / `set { ... }`: 自定义代码块. getter 中可以使用 `self`,
+    /// setter 中可以使用 `self` 和新值 `value`.
+    Custom(RigCode),
+}
@@ -0,0 +1,492 @@
+//! 从实例化后的 RIG 模块生成 Rust 代码.
+//!
+//! 生成的代码包含:
+//!
+//! * 操作码枚举 `MirOP` 和指令类枚举 `MirInstClass`;
+//! * 每个指令类一个结构体, 带构造函数、操作数访问器 (`rd()` / `get_rd()` / `set_rd()`)
+//!   和属性访问器 (`get_cond()` / `set_cond()`);
+//! * 汇总所有指令类的 `MirInst` 枚举.
+//!
+//! 生成的代码要放在提供以下名字的模块里:
+//!
+//! * `MirInstCommon`: 指令公共部分, 需要 `MirInstCommon::new(opcode: MirOP)` 和 `Clone`;
+//! * `MirOperand`: 操作数, 需要 `Copy`;
+//! * `MirOperandKind`: 操作数类型, 变体与 RIG 操作数类型同名. `GPR(bits, [flags])` 生成为
+//!   `MirOperandKind::GPR { bits, flags: &[...] }`, `FPR` 同理;
+//! * `IMirSubInst`: 指令类公共接口, 生成的结构体会实现它. `extern class` 需要手写实现.
+//!   有 getter 的属性会生成 `fmt_props` 方法, 没有属性的类使用 trait 的默认实现;
+//! * 属性类型和 `init` 块里用到的名字. 属性类型需要 `Copy` 和 `Debug`.
+
+use crate::rig::{RigAccessor, RigExpanded, RigInstClass, RigOperandKind};
+use std::fmt::Write;
+
+/// 生成 Rust 代码. `source_name` 只写进文件头注释.
+pub fn generate_rust(expanded: &RigExpanded, source_name: &str) -> String {
+    let mut cg = RigCodegen { out: String::new() };
+    cg.line(&format!(
+        "// @generated by rig-gen from `{source_name}`. Do not edit by hand."
+    ));
+    cg.line("");
+    cg.line("use std::cell::Cell;");
+    cg.line("");
+    cg.emit_opcodes(expanded);
+    cg.emit_class_enum(expanded);
+    cg.emit_inst_enum(expanded);
+    for class in &expanded.classes {
+        cg.emit_class(class);
+    }
+    cg.out
+}
+
+struct RigCodegen {
+    out: String,
+}
+
+impl RigCodegen {
+    fn line(&mut self, text: &str) {
+        self.out.push_str(text);
+        self.out.push('\n');
+    }
+
+    fn emit_opcodes(&mut self, expanded: &RigExpanded) {
+        let opcodes: Vec<_> = expanded.opcodes().collect();
+        self.line("/// MIR 操作码.");
+        self.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]");
+        self.line("pub enum MirOP {");
+        for op in &opcodes {
+            writeln!(self.out, "    {op},").unwrap();
+        }
+        self.line("}");
+        self.line("");
+        self.line("impl MirOP {");
+        writeln!(
+            self.out,
+            "    pub const ALL: [MirOP; {}] = [",
+            opcodes.len()
+        )
+        .unwrap();
+        for op in &opcodes {
+            writeln!(self.out, "        MirOP::{op},").unwrap();
+        }
+        self.line("    ];");
+        self.line("");
+        self.line("    pub fn get_name(self) -> &'static str {");
+        self.line("        match self {");
+        for op in &opcodes {
+            writeln!(self.out, "            MirOP::{op} => \"{op}\",").unwrap();
+        }
+        self.line("        }");
+        self.line("    }");
+        self.line("");
+        self.line("    pub fn from_name(name: &str) -> Option<Self> {");
+        self.line("        match name {");
+        for op in &opcodes {
+            writeln!(self.out, "            \"{op}\" => Some(MirOP::{op}),").unwrap();
+        }
+        self.line("            _ => None,");
+        self.line("        }");
+        self.line("    }");
+        self.line("");
+        self.line("    /// 承载这个操作码的指令类.");
+        self.line("    pub fn get_class(self) -> MirInstClass {");
+        self.line("        match self {");
+        for class in &expanded.classes {
+            let ops: Vec<_> = class
+                .insts
+                .iter()
+                .map(|i| format!("MirOP::{}", i.name))
+                .collect();
+            let name = &class.name.name;
+            writeln!(
+                self.out,
+                "            {} => MirInstClass::{name},",
+                ops.join(" | ")
+            )
+            .unwrap();
+        }
+        for ext in &expanded.externs {
+            let name = &ext.name;
+            writeln!(
+                self.out,
+                "            MirOP::{name} => MirInstClass::{name},"
+            )
+            .unwrap();
+        }
+        self.line("        }");
+        self.line("    }");
+        self.line("}");
+        self.line("");
+    }
+
+    fn class_names(expanded: &RigExpanded) -> impl Iterator<Item = &str> {
+        let classes = expanded.classes.iter().map(|c| c.name.name.as_str());
+        classes.chain(expanded.externs.iter().map(|e| e.name.as_str()))
+    }
+
+    fn emit_class_enum(&mut self, expanded: &RigExpanded) {
+        self.line("/// MIR 指令类. 同一类的指令有相同的操作数布局和属性.");
+        self.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+        self.line("pub enum MirInstClass {");
+        for name in Self::class_names(expanded) {
+            writeln!(self.out, "    {name},").unwrap();
+        }
+        self.line("}");
+        self.line("");
+        self.line("impl MirInstClass {");
+        self.line("    pub fn get_name(self) -> &'static str {");
+        self.line("        match self {");
+        for name in Self::class_names(expanded) {
+            writeln!(self.out, "            MirInstClass::{name} => \"{name}\",").unwrap();
+        }
+        self.line("        }");
+        self.line("    }");
+        self.line("}");
+        self.line("");
+    }
+
+    fn emit_inst_enum(&mut self, expanded: &RigExpanded) {
+        let names: Vec<&str> = Self::class_names(expanded).collect();
+        self.line("#[derive(Clone)]");
+        self.line("pub enum MirInst {");
+        for name in &names {
+            writeln!(self.out, "    {name}({name}),").unwrap();
+        }
+        self.line("}");
+        self.line("");
+        self.line("impl MirInst {");
+        self.line("    pub fn get_class(&self) -> MirInstClass {");
+        self.line("        match self {");
+        for name in &names {
+            writeln!(
+                self.out,
+                "            MirInst::{name}(_) => MirInstClass::{name},"
+            )
+            .unwrap();
+        }
+        self.line("        }");
+        self.line("    }");
+        let dispatch = [
+            ("get_common", "&MirInstCommon"),
+            ("operands", "&[Cell<MirOperand>]"),
+            ("num_outs", "usize"),
+            ("operand_kinds", "&[MirOperandKind]"),
+        ];
+        for (method, ret) in dispatch {
+            self.line("");
+            writeln!(self.out, "    pub fn {method}(&self) -> {ret} {{").unwrap();
+            self.line("        match self {");
+            for name in &names {
+                writeln!(
+                    self.out,
+                    "            MirInst::{name}(inst) => inst.{method}(),"
+                )
+                .unwrap();
+            }
+            self.line("        }");
+            self.line("    }");
+        }
+        self.line("");
+        self.line(
+            "    pub fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {",
+        );
+        self.line("        match self {");
+        for name in &names {
+            writeln!(
+                self.out,
+                "            MirInst::{name}(inst) => inst.fmt_props(out),"
+            )
+            .unwrap();
+        }
+        self.line("        }");
+        self.line("    }");
+        self.line("}");
+        self.line("");
+    }
+
+    fn emit_class(&mut self, class: &RigInstClass) {
+        let name = class.name.name.as_str();
+        let num_operands = class.num_operands();
+        self.emit_class_doc(class);
+        self.line("#[derive(Clone)]");
+        writeln!(self.out, "pub struct {name} {{").unwrap();
+        self.line("    common: MirInstCommon,");
+        writeln!(
+            self.out,
+            "    operands: [Cell<MirOperand>; {num_operands}],"
+        )
+        .unwrap();
+        for prop in class.props.iter().filter(|p| p.has_storage()) {
+            writeln!(self.out, "    {}: Cell<{}>,", prop.name.name, prop.ty.text).unwrap();
+        }
+        self.line("}");
+        self.line("");
+
+        writeln!(self.out, "impl {name} {{").unwrap();
+        let opcodes: Vec<_> = class
+            .insts
+            .iter()
+            .map(|i| format!("MirOP::{}", i.name))
+            .collect();
+        writeln!(
+            self.out,
+            "    pub const OPCODES: [MirOP; {}] = [",
+            opcodes.len()
+        )
+        .unwrap();
+        for op in &opcodes {
+            writeln!(self.out, "        {op},").unwrap();
+        }
+        self.line("    ];");
+        writeln!(
+            self.out,
+            "    pub const NUM_OUTS: usize = {};",
+            class.outs.len()
+        )
+        .unwrap();
+        writeln!(
+            self.out,
+            "    pub const OPERAND_KINDS: [MirOperandKind; {num_operands}] = ["
+        )
+        .unwrap();
+        for operand in class.operands() {
+            writeln!(self.out, "        {},", operand_kind_expr(&operand.kind)).unwrap();
+        }
+        self.line("    ];");
+        self.line("");
+        self.emit_constructor(class);
+        for (index, operand) in class.operands().enumerate() {
+            let op = &operand.name.name;
+            self.line("");
+            writeln!(self.out, "    pub fn {op}(&self) -> &Cell<MirOperand> {{").unwrap();
+            writeln!(self.out, "        &self.operands[{index}]").unwrap();
+            self.line("    }");
+            writeln!(self.out, "    pub fn get_{op}(&self) -> MirOperand {{").unwrap();
+            writeln!(self.out, "        self.operands[{index}].get()").unwrap();
+            self.line("    }");
+            writeln!(self.out, "    pub fn set_{op}(&self, value: MirOperand) {{").unwrap();
+            writeln!(self.out, "        self.operands[{index}].set(value)").unwrap();
+            self.line("    }");
+        }
+        for prop in &class.props {
+            let (prop_name, ty) = (&prop.name.name, &prop.ty.text);
+            if let Some(get) = &prop.get {
+                self.line("");
+                write!(self.out, "    pub fn get_{prop_name}(&self) -> {ty}").unwrap();
+                match get {
+                    RigAccessor::Auto => {
+                        self.line(" {");
+                        writeln!(self.out, "        self.{prop_name}.get()").unwrap();
+                        self.line("    }");
+                    }
+                    RigAccessor::Custom(code) => {
+                        self.line(&format!(" {}", reindent(&code.text, "    ")))
+                    }
+                }
+            }
+            if let Some(set) = &prop.set {
+                if prop.get.is_none() {
+                    self.line("");
+                }
+                write!(self.out, "    pub fn set_{prop_name}(&self, value: {ty})").unwrap();
+                match set {
+                    RigAccessor::Auto => {
+                        self.line(" {");
+                        writeln!(self.out, "        self.{prop_name}.set(value)").unwrap();
+                        self.line("    }");
+                    }
+                    RigAccessor::Custom(code) => {
+                        self.line(&format!(" {}", reindent(&code.text, "    ")))
+                    }
+                }
+            }
+        }
+        self.line("}");
+        self.line("");
+        self.emit_sub_inst_impl(class);
+    }
+
+    fn emit_class_doc(&mut self, class: &RigInstClass) {
+        let name = class.name.name.as_str();
+        match &class.template {
+            Some((tmpl, args)) => {
+                let args: Vec<_> = args.iter().map(|a| a.to_string()).collect();
+                writeln!(
+                    self.out,
+                    "/// `{name}`: 模板 `{tmpl}[{}]` 的实例.",
+                    args.join(", ")
+                )
+                .unwrap();
+            }
+            None => writeln!(self.out, "/// `{name}`.").unwrap(),
+        }
+        let describe = |slots: &mut dyn Iterator<Item = &crate::rig::RigOperandSlot>| {
+            let parts: Vec<_> = slots
+                .map(|s| format!("`{}: {}`", s.name.name, s.kind))
+                .collect();
+            parts.join(", ")
+        };
+        self.line("///");
+        if !class.outs.is_empty() {
+            writeln!(self.out, "/// * 输出: {}", describe(&mut class.outs.iter())).unwrap();
+        }
+        if !class.ins.is_empty() {
+            writeln!(self.out, "/// * 输入: {}", describe(&mut class.ins.iter())).unwrap();
+        }
+        let insts: Vec<_> = class
+            .insts
+            .iter()
+            .map(|i| format!("`{}`", i.name))
+            .collect();
+        writeln!(self.out, "/// * 操作码: {}", insts.join(", ")).unwrap();
+    }
+
+    fn emit_constructor(&mut self, class: &RigInstClass) {
+        let name = class.name.name.as_str();
+        let mut params = vec!["opcode: MirOP".to_string()];
+        params.extend(
+            class
+                .operands()
+                .map(|o| format!("{}: MirOperand", o.name.name)),
+        );
+        writeln!(self.out, "    pub fn new({}) -> Self {{", params.join(", ")).unwrap();
+        self.line("        assert!(");
+        self.line("            Self::accepts_opcode(opcode),");
+        writeln!(
+            self.out,
+            "            \"opcode {{opcode:?}} does not belong to class {name}\""
+        )
+        .unwrap();
+        self.line("        );");
+        // 没有 init 块时直接返回构造出的对象
+        let binding = if class.init.is_some() { "let ret = " } else { "" };
+        writeln!(self.out, "        {binding}Self {{").unwrap();
+        self.line("            common: MirInstCommon::new(opcode),");
+        let cells: Vec<_> = class
+            .operands()
+            .map(|o| format!("Cell::new({})", o.name.name))
+            .collect();
+        writeln!(self.out, "            operands: [{}],", cells.join(", ")).unwrap();
+        for prop in class.props.iter().filter(|p| p.has_storage()) {
+            let default = &prop
+                .default
+                .as_ref()
+                .expect("stored property without default")
+                .text;
+            writeln!(
+                self.out,
+                "            {}: Cell::new({default}),",
+                prop.name.name
+            )
+            .unwrap();
+        }
+        if let Some(init) = &class.init {
+            self.line("        };");
+            writeln!(self.out, "        {}", reindent(&init.text, "        ")).unwrap();
+            self.line("        ret");
+        } else {
+            self.line("        }");
+        }
+        self.line("    }");
+    }
+
+    fn emit_sub_inst_impl(&mut self, class: &RigInstClass) {
+        let name = class.name.name.as_str();
+        let opcodes: Vec<_> = class
+            .insts
+            .iter()
+            .map(|i| format!("MirOP::{}", i.name))
+            .collect();
+        writeln!(self.out, "impl IMirSubInst for {name} {{").unwrap();
+        self.line("    fn get_common(&self) -> &MirInstCommon {");
+        self.line("        &self.common");
+        self.line("    }");
+        self.line("    fn operands(&self) -> &[Cell<MirOperand>] {");
+        self.line("        &self.operands");
+        self.line("    }");
+        self.line("    fn num_outs(&self) -> usize {");
+        self.line("        Self::NUM_OUTS");
+        self.line("    }");
+        self.line("    fn operand_kinds(&self) -> &[MirOperandKind] {");
+        self.line("        &Self::OPERAND_KINDS");
+        self.line("    }");
+        self.line("    fn accepts_opcode(opcode: MirOP) -> bool {");
+        writeln!(
+            self.out,
+            "        matches!(opcode, {})",
+            opcodes.join(" | ")
+        )
+        .unwrap();
+        self.line("    }");
+        self.emit_fmt_props(class);
+        self.line("    fn into_mir(self) -> MirInst {");
+        writeln!(self.out, "        MirInst::{name}(self)").unwrap();
+        self.line("    }");
+        self.line("    fn try_from_mir(inst: &MirInst) -> Option<&Self> {");
+        self.line("        match inst {");
+        writeln!(self.out, "            MirInst::{name}(inst) => Some(inst),").unwrap();
+        self.line("            _ => None,");
+        self.line("        }");
+        self.line("    }");
+        self.line("}");
+        self.line("");
+    }
+}
+
+impl RigCodegen {
+    /// 按 `name: {:?}` 的格式依次写出所有可读的属性.
+    fn emit_fmt_props(&mut self, class: &RigInstClass) {
+        let readable: Vec<_> = class.props.iter().filter(|p| p.get.is_some()).collect();
+        if readable.is_empty() {
+            return;
+        }
+        self.line("    fn fmt_props(&self, out: &mut dyn std::fmt::Write) -> std::fmt::Result {");
+        for (i, prop) in readable.iter().enumerate() {
+            let prop_name = &prop.name.name;
+            let sep = if i == 0 { "" } else { ", " };
+            let tail = if i + 1 == readable.len() { "" } else { "?;" };
+            writeln!(
+                self.out,
+                "        write!(out, \"{sep}{prop_name}: {{:?}}\", self.get_{prop_name}()){tail}"
+            )
+            .unwrap();
+        }
+        self.line("    }");
+    }
+}
+
+fn operand_kind_expr(kind: &RigOperandKind) -> String {
+    match kind {
+        RigOperandKind::GPR { bits, flags } | RigOperandKind::FPR { bits, flags } => {
+            let flags: Vec<_> = flags.iter().map(|f| format!("\"{f}\"")).collect();
+            let name = kind.get_name();
+            format!(
+                "MirOperandKind::{name} {{ bits: {bits}, flags: &[{}] }}",
+                flags.join(", ")
+            )
+        }
+        RigOperandKind::Param(param) => {
+            panic!("Internal error: template parameter `{param}` survived instantiation")
+        }
+        _ => format!("MirOperandKind::{}", kind.get_name()),
+    }
+}
+
+/// 把一个代码块的后续行重新缩进到 `indent`. 原来的缩进以最后一行 (闭合的 `}`) 为准.
+fn reindent(code: &str, indent: &str) -> String {
+    let mut lines = code.lines();
+    let first = lines.next().unwrap_or("");
+    let base = code
+        .lines()
+        .last()
+        .map_or(0, |l| l.len() - l.trim_start().len());
+    let mut out = first.to_string();
+    for line in lines {
+        let strip = line.len() - line.trim_start().len();
+        out.push('\n');
+        if !line.trim().is_empty() {
+            out.push_str(indent);
+            out.push_str(&line[strip.min(base)..]);
+        }
+    }
+    out
+}
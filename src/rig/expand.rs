@@ -0,0 +1,237 @@
+//! 模板实例化和语义检查.
+//!
+//! 模板的每个 `impl` 项实例化为一个具体的指令类: 模板的公共字段在前, `impl` 项自己的字段在后,
+//! 操作数类型中的模板参数替换为实参. 实例化之后检查:
+//!
+//! * 类名 (包括 `extern class`) 和模板名各自不能重复;
+//! * 同一个类里操作数和属性不能重名;
+//! * 每个类至少承载一个操作码, 每个操作码只属于一个类. `extern class` 本身也是一个同名操作码.
+
+use crate::{
+    SymbolStr,
+    rig::{RigError, RigErrorKind, RigErrors, RigSpan, ast::*},
+};
+use std::collections::HashMap;
+
+/// 实例化后的 RIG 模块.
+#[derive(Debug, Clone, Default)]
+pub struct RigExpanded {
+    /// 按源码顺序排列的指令类, 模板实例按 `impl` 项的顺序展开在模板所在的位置.
+    pub classes: Vec<RigInstClass>,
+    pub externs: Vec<RigIdent>,
+}
+
+impl RigExpanded {
+    pub fn find_class(&self, name: &str) -> Option<&RigInstClass> {
+        self.classes.iter().find(|c| c.name.name == name)
+    }
+
+    /// 所有操作码, 按定义顺序排列. `extern class` 的操作码排在最后.
+    pub fn opcodes(&self) -> impl Iterator<Item = &SymbolStr> {
+        let insts = self.classes.iter().flat_map(|c| c.insts.iter());
+        insts.chain(self.externs.iter()).map(|i| &i.name)
+    }
+}
+
+/// 一个具体的指令类. 操作数类型中不再含有模板参数.
+#[derive(Debug, Clone)]
+pub struct RigInstClass {
+    pub name: RigIdent,
+    /// 实例化来源: 模板名和实参.
+    pub template: Option<(SymbolStr, Vec<RigOperandKind>)>,
+    pub outs: Vec<RigOperandSlot>,
+    pub ins: Vec<RigOperandSlot>,
+    pub insts: Vec<RigIdent>,
+    pub props: Vec<RigProp>,
+    pub init: Option<RigCode>,
+}
+
+impl RigInstClass {
+    /// 全部操作数, 输出在前, 输入在后. 这也是操作数在指令对象里的存储顺序.
+    pub fn operands(&self) -> impl Iterator<Item = &RigOperandSlot> {
+        self.outs.iter().chain(self.ins.iter())
+    }
+    pub fn num_operands(&self) -> usize {
+        self.outs.len() + self.ins.len()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RigOperandSlot {
+    pub name: RigIdent,
+    pub kind: RigOperandKind,
+}
+
+pub fn expand_rig(module: &RigModule) -> Result<RigExpanded, RigErrors> {
+    let mut expander = RigExpander::default();
+    for item in &module.items {
+        match item {
+            RigItem::Extern(name) => {
+                expander.define_class(name);
+                expander.claim_inst(name, name);
+                expander.expanded.externs.push(name.clone());
+            }
+            RigItem::Class(class) => {
+                let class = expander.instantiate(&class.name, None, &[&class.fields], &[]);
+                expander.add_class(class);
+            }
+            RigItem::Template(tmpl) => expander.expand_template(tmpl),
+        }
+    }
+    let RigExpander { expanded, errors, .. } = expander;
+    if errors.is_empty() { Ok(expanded) } else { Err(errors.into()) }
+}
+
+#[derive(Default)]
+struct RigExpander {
+    expanded: RigExpanded,
+    errors: Vec<RigError>,
+    class_names: HashMap<SymbolStr, RigSpan>,
+    template_names: HashMap<SymbolStr, RigSpan>,
+    /// 操作码 → (所属类, 操作码第一次出现的位置)
+    inst_owner: HashMap<SymbolStr, (SymbolStr, RigSpan)>,
+}
+
+impl RigExpander {
+    fn define_class(&mut self, name: &RigIdent) {
+        Self::define_in(&mut self.class_names, &mut self.errors, name);
+    }
+    fn define_in(
+        names: &mut HashMap<SymbolStr, RigSpan>,
+        errors: &mut Vec<RigError>,
+        name: &RigIdent,
+    ) {
+        if let Some(&first) = names.get(&name.name) {
+            let err = RigError::new(RigErrorKind::DuplicateName(name.name.clone()), name.span)
+                .with_note("first defined here", first);
+            errors.push(err);
+        } else {
+            names.insert(name.name.clone(), name.span);
+        }
+    }
+
+    fn claim_inst(&mut self, inst: &RigIdent, class: &RigIdent) {
+        if let Some((owner, first)) = self.inst_owner.get(&inst.name) {
+            let kind = RigErrorKind::DuplicateInst(inst.name.clone(), owner.clone());
+            let err = RigError::new(kind, inst.span).with_note("first claimed here", *first);
+            self.errors.push(err);
+        } else {
+            let owner = (class.name.clone(), inst.span);
+            self.inst_owner.insert(inst.name.clone(), owner);
+        }
+    }
+
+    fn add_class(&mut self, class: RigInstClass) {
+        self.define_class(&class.name);
+        for inst in &class.insts {
+            self.claim_inst(inst, &class.name);
+        }
+        self.expanded.classes.push(class);
+    }
+
+    fn expand_template(&mut self, tmpl: &RigTemplate) {
+        Self::define_in(&mut self.template_names, &mut self.errors, &tmpl.name);
+        for imp in &tmpl.impls {
+            if imp.args.len() != tmpl.params.len() {
+                let kind = RigErrorKind::TemplateArity {
+                    template: tmpl.name.name.clone(),
+                    expected: tmpl.params.len(),
+                    found: imp.args.len(),
+                };
+                let err = RigError::new(kind, imp.span)
+                    .with_note("template defined here", tmpl.name.span);
+                self.errors.push(err);
+                continue;
+            }
+            let bindings: Vec<(SymbolStr, RigOperandKind)> = tmpl
+                .params
+                .iter()
+                .zip(&imp.args)
+                .map(|(param, arg)| (param.name.clone(), arg.kind.clone()))
+                .collect();
+            let origin = (
+                tmpl.name.name.clone(),
+                imp.args.iter().map(|a| a.kind.clone()).collect(),
+            );
+            let fields = [&tmpl.fields, &imp.fields];
+            let class = self.instantiate(&imp.class_name, Some(origin), &fields, &bindings);
+            self.add_class(class);
+        }
+    }
+
+    fn instantiate(
+        &mut self,
+        name: &RigIdent,
+        template: Option<(SymbolStr, Vec<RigOperandKind>)>,
+        fields: &[&RigInstFields],
+        bindings: &[(SymbolStr, RigOperandKind)],
+    ) -> RigInstClass {
+        let subst = |decl: &RigOperandDecl| {
+            let kind = match &decl.operand.kind {
+                RigOperandKind::Param(param) => bindings
+                    .iter()
+                    .find(|(p, _)| p == param)
+                    .map(|(_, arg)| arg.clone())
+                    .expect("Internal error: parser accepted an unbound template parameter"),
+                kind => kind.clone(),
+            };
+            RigOperandSlot { name: decl.name.clone(), kind }
+        };
+        let mut class = RigInstClass {
+            name: name.clone(),
+            template,
+            outs: fields
+                .iter()
+                .flat_map(|f| f.outs.iter().map(subst))
+                .collect(),
+            ins: fields
+                .iter()
+                .flat_map(|f| f.ins.iter().map(subst))
+                .collect(),
+            insts: fields
+                .iter()
+                .flat_map(|f| f.insts.iter().cloned())
+                .collect(),
+            props: fields
+                .iter()
+                .flat_map(|f| f.props.iter().cloned())
+                .collect(),
+            init: None,
+        };
+        for init in fields.iter().filter_map(|f| f.init.as_ref()) {
+            if class.init.is_some() {
+                let kind = RigErrorKind::DuplicateInit(name.name.clone());
+                self.errors.push(RigError::new(kind, init.span));
+            } else {
+                class.init = Some(init.clone());
+            }
+        }
+        self.check_member_names(&class);
+        if class.insts.is_empty() {
+            let kind = RigErrorKind::EmptyInsts(name.name.clone());
+            self.errors.push(RigError::new(kind, name.span));
+        }
+        class
+    }
+
+    /// 操作数和属性都会生成同名访问器, 所以放在同一个命名空间里检查.
+    fn check_member_names(&mut self, class: &RigInstClass) {
+        let mut members: HashMap<&str, RigSpan> = HashMap::new();
+        let operands = class.operands().map(|o| (&o.name, true));
+        let props = class.props.iter().map(|p| (&p.name, false));
+        for (member, is_operand) in operands.chain(props) {
+            let Some(&first) = members.get(member.name.as_str()) else {
+                members.insert(member.name.as_str(), member.span);
+                continue;
+            };
+            let (name, class_name) = (member.name.clone(), class.name.name.clone());
+            let kind = if is_operand {
+                RigErrorKind::DuplicateOperand(name, class_name)
+            } else {
+                RigErrorKind::DuplicateProp(name, class_name)
+            };
+            let err = RigError::new(kind, member.span).with_note("first declared here", first);
+            self.errors.push(err);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! RIG 词法分析.
+//!
+//! 属性类型、默认值和访问器体都是 Rust 代码片段, 所以词法分析器不区分关键字,
+//! 只切出标识符、整数、字面量和标点. 解析器根据上下文决定一个标识符是不是关键字,
+//! 遇到 Rust 片段时按括号深度截取原文.
+
+use crate::rig::{RigError, RigErrorKind, RigSpan};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RigTokenKind {
+    Ident,
+    LitInt,
+    /// 字符串或字符字面量, 只会出现在 Rust 片段里.
+    LitStr,
+    /// 生命周期 `'a`, 只会出现在 Rust 片段里.
+    Lifetime,
+    /// `=>`
+    FatArrow,
+    /// `->`
+    Arrow,
+    /// `::`
+    PathSep,
+    /// 其他单字符标点.
+    Punct(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RigToken {
+    pub kind: RigTokenKind,
+    pub span: RigSpan,
+}
+
+impl RigToken {
+    pub fn text<'s>(&self, source: &'s str) -> &'s str {
+        &source[self.span.start..self.span.end]
+    }
+    pub fn is_punct(&self, ch: char) -> bool {
+        self.kind == RigTokenKind::Punct(ch)
+    }
+}
+
+pub fn tokenize(source: &str) -> Result<Vec<RigToken>, RigError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let ch = source[pos..].chars().next().unwrap();
+        let start = pos;
+        if ch.is_whitespace() {
+            pos += ch.len_utf8();
+            continue;
+        }
+        if source[pos..].starts_with("//") {
+            pos = source[pos..].find('\n').map_or(bytes.len(), |n| pos + n);
+            continue;
+        }
+        if source[pos..].starts_with("/*") {
+            pos = skip_block_comment(source, pos)?;
+            continue;
+        }
+        let kind = if ch.is_ascii_alphabetic() || ch == '_' {
+            pos = scan_while(bytes, pos, |b| b.is_ascii_alphanumeric() || b == b'_');
+            RigTokenKind::Ident
+        } else if ch.is_ascii_digit() {
+            // 后缀 (`0u32`) 和十六进制 (`0xff`) 只会出现在 Rust 片段里, 一并吞掉
+            pos = scan_while(bytes, pos, |b| b.is_ascii_alphanumeric() || b == b'_');
+            RigTokenKind::LitInt
+        } else if ch == '"' {
+            pos = scan_quoted(source, pos, '"')?;
+            RigTokenKind::LitStr
+        } else if ch == '\'' {
+            scan_quote_or_lifetime(source, &mut pos)?
+        } else if source[pos..].starts_with("=>") {
+            pos += 2;
+            RigTokenKind::FatArrow
+        } else if source[pos..].starts_with("->") {
+            pos += 2;
+            RigTokenKind::Arrow
+        } else if source[pos..].starts_with("::") {
+            pos += 2;
+            RigTokenKind::PathSep
+        } else if ch.is_ascii_punctuation() {
+            pos += 1;
+            RigTokenKind::Punct(ch)
+        } else {
+            let span = RigSpan::new(start, start + ch.len_utf8());
+            return Err(RigError::new(RigErrorKind::UnexpectedChar(ch), span));
+        };
+        tokens.push(RigToken { kind, span: RigSpan::new(start, pos) });
+    }
+    Ok(tokens)
+}
+
+fn scan_while(bytes: &[u8], mut pos: usize, pred: impl Fn(u8) -> bool) -> usize {
+    while pos < bytes.len() && pred(bytes[pos]) {
+        pos += 1;
+    }
+    pos
+}
+
+fn skip_block_comment(source: &str, start: usize) -> Result<usize, RigError> {
+    // 和 Rust 一样, 块注释可以嵌套
+    let mut depth = 0usize;
+    let mut pos = start;
+    while pos < source.len() {
+        let rest = &source[pos..];
+        if rest.starts_with("/*") {
+            depth += 1;
+            pos += 2;
+        } else if rest.starts_with("*/") {
+            depth -= 1;
+            pos += 2;
+            if depth == 0 {
+                return Ok(pos);
+            }
+        } else {
+            pos += rest.chars().next().unwrap().len_utf8();
+        }
+    }
+    let span = RigSpan::new(start, start + 2);
+    Err(RigError::new(RigErrorKind::UnterminatedComment, span))
+}
+
+fn scan_quoted(source: &str, start: usize, quote: char) -> Result<usize, RigError> {
+    let mut chars = source[start..].char_indices().skip(1);
+    while let Some((off, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            ch if ch == quote => return Ok(start + off + 1),
+            _ => {}
+        }
+    }
+    let span = RigSpan::new(start, start + 1);
+    Err(RigError::new(RigErrorKind::UnterminatedLiteral, span))
+}
+
+fn scan_quote_or_lifetime(source: &str, pos: &mut usize) -> Result<RigTokenKind, RigError> {
+    let start = *pos;
+    let mut chars = source[start + 1..].chars();
+    let first = chars.next();
+    let second = chars.next();
+    match (first, second) {
+        // `'a'`, `'\n'`
+        (Some('\\'), _) | (Some(_), Some('\'')) => {
+            *pos = scan_quoted(source, start, '\'')?;
+            Ok(RigTokenKind::LitStr)
+        }
+        (Some(ch), _) if ch.is_ascii_alphabetic() || ch == '_' => {
+            *pos = scan_while(source.as_bytes(), start + 1, |b| {
+                b.is_ascii_alphanumeric() || b == b'_'
+            });
+            Ok(RigTokenKind::Lifetime)
+        }
+        _ => {
+            let span = RigSpan::new(start, start + 1);
+            Err(RigError::new(RigErrorKind::UnterminatedLiteral, span))
+        }
+    }
+}
@@ -0,0 +1,636 @@
+//! RIG 语法分析: 递归下降, 每个函数对应 EBNF 中的一条产生式.
+//!
+//! 与语法文档相比有几处放宽, 都是 `rigs/mir.rig` 里实际用到的写法:
+//!
+//! * 模板的公共字段之间用 `,` 分隔, 最后一个字段后的 `,` 可以省略, 字段列表以 `;` 结束;
+//! * 字段 `init: { ... }` 给出构造函数末尾执行的代码;
+//! * 操作数类型还包括 `ImmMovZNK`、`ImmFMov32` 和 `ImmFMov64`.
+
+use crate::{
+    SymbolStr,
+    rig::{
+        RigError, RigErrorKind, RigErrors, RigSpan,
+        ast::*,
+        lexer::{RigToken, RigTokenKind, tokenize},
+    },
+};
+
+type PResult<T> = Result<T, RigError>;
+
+/// 解析整个 RIG 源文件. 出错的顶层定义会被跳过, 所有错误一并返回.
+pub fn parse_rig(source: &str) -> Result<RigModule, RigErrors> {
+    let tokens = tokenize(source).map_err(|e| RigErrors::from(vec![e]))?;
+    let mut parser = RigParser { source, tokens, pos: 0, params: Vec::new() };
+    let mut module = RigModule::default();
+    let mut errors = Vec::new();
+    while !parser.is_eof() {
+        match parser.parse_item() {
+            Ok(item) => module.items.push(item),
+            Err(err) => {
+                errors.push(err);
+                parser.recover_to_item();
+            }
+        }
+    }
+    if errors.is_empty() { Ok(module) } else { Err(errors.into()) }
+}
+
+struct RigParser<'s> {
+    source: &'s str,
+    tokens: Vec<RigToken>,
+    pos: usize,
+    /// 当前所在模板的参数表, 不在模板里时为空.
+    params: Vec<SymbolStr>,
+}
+
+impl<'s> RigParser<'s> {
+    fn is_eof(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+    fn peek(&self) -> Option<RigToken> {
+        self.tokens.get(self.pos).copied()
+    }
+    fn peek_nth(&self, n: usize) -> Option<RigToken> {
+        self.tokens.get(self.pos + n).copied()
+    }
+    fn bump(&mut self) -> Option<RigToken> {
+        let tok = self.peek();
+        self.pos += tok.is_some() as usize;
+        tok
+    }
+    fn text(&self, tok: RigToken) -> &'s str {
+        tok.text(self.source)
+    }
+    /// 文件末尾的空区间, 用于报告 "意外的文件结尾".
+    fn eof_span(&self) -> RigSpan {
+        let end = self.source.trim_end().len();
+        RigSpan::new(end, end)
+    }
+
+    fn unexpected(&self, expected: impl Into<String>) -> RigError {
+        let (found, span) = match self.peek() {
+            Some(tok) => (format!("`{}`", self.text(tok)), tok.span),
+            None => ("end of input".to_string(), self.eof_span()),
+        };
+        let kind = RigErrorKind::Unexpected { expected: expected.into(), found };
+        RigError::new(kind, span)
+    }
+
+    fn is_punct(&self, ch: char) -> bool {
+        self.peek().is_some_and(|t| t.is_punct(ch))
+    }
+    fn eat_punct(&mut self, ch: char) -> bool {
+        let matched = self.is_punct(ch);
+        self.pos += matched as usize;
+        matched
+    }
+    fn expect_punct(&mut self, ch: char) -> PResult<RigSpan> {
+        match self.peek() {
+            Some(tok) if tok.is_punct(ch) => {
+                self.pos += 1;
+                Ok(tok.span)
+            }
+            _ => Err(self.unexpected(format!("`{ch}`"))),
+        }
+    }
+
+    fn is_keyword(&self, kw: &str) -> bool {
+        self.peek()
+            .is_some_and(|t| t.kind == RigTokenKind::Ident && self.text(t) == kw)
+    }
+    fn expect_keyword(&mut self, kw: &str) -> PResult<RigSpan> {
+        match self.peek() {
+            Some(tok) if tok.kind == RigTokenKind::Ident && self.text(tok) == kw => {
+                self.pos += 1;
+                Ok(tok.span)
+            }
+            _ => Err(self.unexpected(format!("`{kw}`"))),
+        }
+    }
+    fn expect_ident(&mut self, what: &str) -> PResult<RigIdent> {
+        match self.peek() {
+            Some(tok) if tok.kind == RigTokenKind::Ident => {
+                self.pos += 1;
+                Ok(RigIdent { name: SymbolStr::new(self.text(tok)), span: tok.span })
+            }
+            _ => Err(self.unexpected(what)),
+        }
+    }
+
+    /// 跳到下一个看起来像顶层定义开头的位置: `extern class`, 或者 `}` / `;` 之后的
+    /// `class` / `template`.
+    fn recover_to_item(&mut self) {
+        self.bump();
+        while let Some(tok) = self.peek() {
+            let prev = self.tokens[self.pos - 1];
+            let at_item = match self.text(tok) {
+                "extern" => self.peek_nth(1).is_some_and(|t| self.text(t) == "class"),
+                "class" | "template" => prev.is_punct('}') || prev.is_punct(';'),
+                _ => false,
+            };
+            if at_item && tok.kind == RigTokenKind::Ident {
+                return;
+            }
+            self.pos += 1;
+        }
+    }
+
+    // ==== 顶层定义 ====
+
+    fn parse_item(&mut self) -> PResult<RigItem> {
+        if self.is_keyword("extern") {
+            self.bump();
+            self.expect_keyword("class")?;
+            let name = self.expect_ident("extern class name")?;
+            self.expect_punct(';')?;
+            Ok(RigItem::Extern(name))
+        } else if self.is_keyword("class") {
+            self.bump();
+            let name = self.expect_ident("class name")?;
+            let fields = self.parse_field_list()?;
+            Ok(RigItem::Class(RigClass { name, fields }))
+        } else if self.is_keyword("template") {
+            self.parse_template().map(RigItem::Template)
+        } else {
+            Err(self.unexpected("`class`, `template` or `extern`"))
+        }
+    }
+
+    fn parse_template(&mut self) -> PResult<RigTemplate> {
+        self.expect_keyword("template")?;
+        let params = self.parse_ident_array("template parameter")?;
+        for (i, param) in params.iter().enumerate() {
+            if params[..i].iter().any(|p| p.name == param.name) {
+                let kind = RigErrorKind::DuplicateParam(param.name.clone());
+                return Err(RigError::new(kind, param.span));
+            }
+        }
+        let name = self.expect_ident("template name")?;
+        self.params = params.iter().map(|p| p.name.clone()).collect();
+        let body = self.parse_template_body();
+        self.params.clear();
+        let (fields, impls) = body?;
+        Ok(RigTemplate { params, name, fields, impls })
+    }
+
+    fn parse_template_body(&mut self) -> PResult<(RigInstFields, Vec<RigTemplateImpl>)> {
+        self.expect_punct('{')?;
+        let mut fields = RigInstFields::default();
+        while !self.eat_punct(';') {
+            self.parse_field(&mut fields)?;
+            if !self.eat_punct(',') && !self.is_punct(';') {
+                return Err(self.unexpected("`,` or `;`"));
+            }
+        }
+        self.expect_keyword("impl")?;
+        self.expect_punct('{')?;
+        let mut impls = Vec::new();
+        while !self.eat_punct('}') {
+            impls.push(self.parse_template_impl()?);
+            if !self.eat_punct(',') && !self.is_punct('}') {
+                return Err(self.unexpected("`,` or `}`"));
+            }
+        }
+        self.expect_punct('}')?;
+        Ok((fields, impls))
+    }
+
+    fn parse_template_impl(&mut self) -> PResult<RigTemplateImpl> {
+        let start = self.expect_punct('[')?;
+        let mut args = Vec::new();
+        while !self.eat_punct(']') {
+            let arg = self.parse_operand()?;
+            if let RigOperandKind::Param(name) = &arg.kind {
+                let kind = RigErrorKind::ParamAsArg(name.clone());
+                return Err(RigError::new(kind, arg.span));
+            }
+            args.push(arg);
+            if !self.eat_punct(',') && !self.is_punct(']') {
+                return Err(self.unexpected("`,` or `]`"));
+            }
+        }
+        if self.peek().map(|t| t.kind) != Some(RigTokenKind::FatArrow) {
+            return Err(self.unexpected("`=>`"));
+        }
+        self.bump();
+        let class_name = self.expect_ident("class name")?;
+        let fields = self.parse_field_list()?;
+        let span = start.join(class_name.span);
+        Ok(RigTemplateImpl { args, class_name, fields, span })
+    }
+
+    // ==== 字段 ====
+
+    fn parse_field_list(&mut self) -> PResult<RigInstFields> {
+        self.expect_punct('{')?;
+        let mut fields = RigInstFields::default();
+        while !self.eat_punct('}') {
+            self.parse_field(&mut fields)?;
+            if !self.eat_punct(',') && !self.is_punct('}') {
+                return Err(self.unexpected("`,` or `}`"));
+            }
+        }
+        Ok(fields)
+    }
+
+    fn parse_field(&mut self, fields: &mut RigInstFields) -> PResult<()> {
+        let Some(tok) = self.peek().filter(|t| t.kind == RigTokenKind::Ident) else {
+            return Err(self.unexpected("`in`, `out`, `insts`, `props` or `init`"));
+        };
+        let field: &'static str = match self.text(tok) {
+            "in" => "in",
+            "out" => "out",
+            "insts" => "insts",
+            "props" => "props",
+            "init" => "init",
+            _ => return Err(self.unexpected("`in`, `out`, `insts`, `props` or `init`")),
+        };
+        self.bump();
+        self.expect_punct(':')?;
+        let is_dup = match field {
+            "in" => !fields.ins.is_empty(),
+            "out" => !fields.outs.is_empty(),
+            "insts" => !fields.insts.is_empty(),
+            "props" => !fields.props.is_empty(),
+            _ => fields.init.is_some(),
+        };
+        if is_dup {
+            return Err(RigError::new(RigErrorKind::DuplicateField(field), tok.span));
+        }
+        match field {
+            "in" => fields.ins = self.parse_operand_decls()?,
+            "out" => fields.outs = self.parse_operand_decls()?,
+            "insts" => fields.insts = self.parse_ident_array("instruction name")?,
+            "props" => fields.props = self.parse_props()?,
+            _ => fields.init = Some(self.parse_code_block()?),
+        }
+        Ok(())
+    }
+
+    fn parse_ident_array(&mut self, what: &str) -> PResult<Vec<RigIdent>> {
+        self.expect_punct('[')?;
+        let mut idents = Vec::new();
+        while !self.eat_punct(']') {
+            idents.push(self.expect_ident(what)?);
+            if !self.eat_punct(',') && !self.is_punct(']') {
+                return Err(self.unexpected("`,` or `]`"));
+            }
+        }
+        Ok(idents)
+    }
+
+    // ==== 操作数 ====
+
+    fn parse_operand_decls(&mut self) -> PResult<Vec<RigOperandDecl>> {
+        self.expect_punct('{')?;
+        let mut decls = Vec::new();
+        while !self.eat_punct('}') {
+            let name = self.expect_ident("operand name")?;
+            self.expect_punct(':')?;
+            let operand = self.parse_operand()?;
+            decls.push(RigOperandDecl { name, operand });
+            if !self.eat_punct(',') && !self.is_punct('}') {
+                return Err(self.unexpected("`,` or `}`"));
+            }
+        }
+        Ok(decls)
+    }
+
+    fn parse_operand(&mut self) -> PResult<RigOperand> {
+        let ident = self.expect_ident("operand kind")?;
+        let name = ident.name.as_str();
+        if matches!(name, "GPR" | "FPR") && self.is_punct('(') {
+            return self.parse_config_reg(ident);
+        }
+        let kind = if let Some(kind) = RigOperandKind::from_simple_name(name) {
+            kind
+        } else if self.params.contains(&ident.name) {
+            RigOperandKind::Param(ident.name.clone())
+        } else {
+            let kind = RigErrorKind::UnknownOperand(ident.name.clone());
+            return Err(RigError::new(kind, ident.span));
+        };
+        Ok(RigOperand { kind, span: ident.span })
+    }
+
+    /// `GPR(64, [flags...])` / `FPR(32, [flags...])`
+    fn parse_config_reg(&mut self, ident: RigIdent) -> PResult<RigOperand> {
+        self.expect_punct('(')?;
+        let Some(tok) = self.peek().filter(|t| t.kind == RigTokenKind::LitInt) else {
+            return Err(self.unexpected("register width"));
+        };
+        self.bump();
+        let text = self.text(tok);
+        let bits: u64 = text
+            .parse()
+            .map_err(|_| RigError::new(RigErrorKind::BadInteger(SymbolStr::new(text)), tok.span))?;
+        if bits != 32 && bits != 64 {
+            let kind = RigErrorKind::BadRegWidth(ident.name.clone(), bits);
+            return Err(RigError::new(kind, tok.span));
+        }
+        self.expect_punct(',')?;
+        let flags = self.parse_ident_array("register flag")?;
+        let end = self.expect_punct(')')?;
+        let bits = bits as u8;
+        let flags = flags.into_iter().map(|f| f.name).collect();
+        let kind = if ident.name == "GPR" {
+            RigOperandKind::GPR { bits, flags }
+        } else {
+            RigOperandKind::FPR { bits, flags }
+        };
+        Ok(RigOperand { kind, span: ident.span.join(end) })
+    }
+
+    // ==== 属性 ====
+
+    fn parse_props(&mut self) -> PResult<Vec<RigProp>> {
+        self.expect_punct('{')?;
+        let mut props = Vec::new();
+        while !self.eat_punct('}') {
+            props.push(self.parse_prop()?);
+        }
+        Ok(props)
+    }
+
+    fn parse_prop(&mut self) -> PResult<RigProp> {
+        let name = self.expect_ident("property name")?;
+        self.expect_punct(':')?;
+        let ty = self.parse_code_type()?;
+        if self.eat_punct('=') {
+            let default = self.parse_code_expr()?;
+            self.expect_punct(';')?;
+            let (get, set) = (Some(RigAccessor::Auto), Some(RigAccessor::Auto));
+            return Ok(RigProp { name, ty, get, set, default: Some(default) });
+        }
+
+        self.expect_punct('{')?;
+        let mut prop = RigProp { name, ty, get: None, set: None, default: None };
+        while !self.eat_punct('}') {
+            let part = self.expect_ident("`get`, `set` or `default`")?;
+            let part_name: &'static str = match part.name.as_str() {
+                "get" if prop.get.is_some() => "get",
+                "set" if prop.set.is_some() => "set",
+                "default" if prop.default.is_some() => "default",
+                _ => "",
+            };
+            if !part_name.is_empty() {
+                let kind = RigErrorKind::DuplicatePropPart(prop.name.name.clone(), part_name);
+                return Err(RigError::new(kind, part.span));
+            }
+            match part.name.as_str() {
+                "get" => prop.get = Some(self.parse_accessor()?),
+                "set" => prop.set = Some(self.parse_accessor()?),
+                "default" => {
+                    self.expect_punct('=')?;
+                    prop.default = Some(self.parse_code_expr()?);
+                    self.expect_punct(';')?;
+                }
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.unexpected("`get`, `set` or `default`"));
+                }
+            }
+        }
+        self.eat_punct(';');
+        if prop.get.is_none() && prop.set.is_none() {
+            let kind = RigErrorKind::PropWithoutAccessor(prop.name.name.clone());
+            return Err(RigError::new(kind, prop.name.span));
+        }
+        if prop.default.is_none() && prop.has_storage() {
+            let kind = RigErrorKind::PropWithoutDefault(prop.name.name.clone());
+            return Err(RigError::new(kind, prop.name.span));
+        }
+        Ok(prop)
+    }
+
+    fn parse_accessor(&mut self) -> PResult<RigAccessor> {
+        if self.eat_punct(';') {
+            Ok(RigAccessor::Auto)
+        } else if self.is_punct('{') {
+            self.parse_code_block().map(RigAccessor::Custom)
+        } else {
+            Err(self.unexpected("`;` or `{`"))
+        }
+    }
+
+    // ==== Rust 代码片段 ====
+
+    fn code_from(&self, start: usize) -> RigCode {
+        let span = self.tokens[start].span.join(self.tokens[self.pos - 1].span);
+        let text = self.source[span.start..span.end].to_string();
+        RigCode { text, span }
+    }
+
+    /// 属性类型: 到深度为 0 的 `=`、`{` 或 `;` 为止. 这里 `<>` 也算括号.
+    fn parse_code_type(&mut self) -> PResult<RigCode> {
+        let start = self.pos;
+        let mut stack = Vec::new();
+        while let Some(tok) = self.peek() {
+            let RigTokenKind::Punct(ch) = tok.kind else {
+                self.pos += 1;
+                continue;
+            };
+            if stack.is_empty() && matches!(ch, '=' | '{' | ';') {
+                break;
+            }
+            self.track_delim(&mut stack, ch, tok, "<([")?;
+            self.pos += 1;
+        }
+        if let Some((open, span)) = stack.pop() {
+            return Err(RigError::new(RigErrorKind::UnclosedDelim(open), span));
+        }
+        if self.pos == start {
+            return Err(self.unexpected("property type"));
+        }
+        Ok(self.code_from(start))
+    }
+
+    /// 表达式: 到深度为 0 的 `;` 为止.
+    fn parse_code_expr(&mut self) -> PResult<RigCode> {
+        let start = self.pos;
+        let mut stack = Vec::new();
+        while let Some(tok) = self.peek() {
+            if let RigTokenKind::Punct(ch) = tok.kind {
+                if stack.is_empty() && matches!(ch, ';' | '}') {
+                    break;
+                }
+                self.track_delim(&mut stack, ch, tok, "([{")?;
+            }
+            self.pos += 1;
+        }
+        if let Some((open, span)) = stack.pop() {
+            return Err(RigError::new(RigErrorKind::UnclosedDelim(open), span));
+        }
+        if self.pos == start || !self.is_punct(';') {
+            return Err(self.unexpected("expression followed by `;`"));
+        }
+        Ok(self.code_from(start))
+    }
+
+    /// 块表达式: 一对配平的 `{ }`, 保留花括号.
+    fn parse_code_block(&mut self) -> PResult<RigCode> {
+        let start = self.pos;
+        self.expect_punct('{')?;
+        let mut stack = vec![('{', self.tokens[start].span)];
+        while let Some(tok) = self.bump() {
+            if let RigTokenKind::Punct(ch) = tok.kind {
+                self.track_delim(&mut stack, ch, tok, "([{")?;
+            }
+            if stack.is_empty() {
+                return Ok(self.code_from(start));
+            }
+        }
+        let (open, span) = stack.pop().unwrap();
+        Err(RigError::new(RigErrorKind::UnclosedDelim(open), span))
+    }
+
+    fn track_delim(
+        &self,
+        stack: &mut Vec<(char, RigSpan)>,
+        ch: char,
+        tok: RigToken,
+        opens: &str,
+    ) -> PResult<()> {
+        let closing = |open: char| match open {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            _ => '>',
+        };
+        if opens.contains(ch) {
+            stack.push((ch, tok.span));
+        } else if let Some(&(open, _)) = stack.last()
+            && closing(open) == ch
+        {
+            stack.pop();
+        } else if opens.chars().any(|open| closing(open) == ch) {
+            return Err(self.unexpected(match stack.last() {
+                Some(&(open, _)) => format!("`{}`", closing(open)),
+                None => "expression".to_string(),
+            }));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_errors(source: &str) -> Vec<(RigErrorKind, (usize, usize))> {
+        let errors = parse_rig(source).unwrap_err().errors;
+        errors
+            .into_iter()
+            .map(|e| (e.kind, e.span.line_col(source)))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_props() {
+        let source = "\
+class Props {
+    insts: [ X ],
+    props: {
+        simple: Option<Vec<u8>> = Some(vec![1, 2]);
+        computed: u32 {
+            get { self.get_simple().map_or(0, |v| v.len() as u32) }
+        }
+        checked: MirCondFlag {
+            get;
+            set { assert!(value != MirCondFlag::NV); self.checked.set(value) }
+            default = MirCondFlag::AL;
+        };
+    },
+    init: { let _ = ret.get_simple(); }
+}
+";
+        let module = parse_rig(source).unwrap();
+        let RigItem::Class(class) = &module.items[0] else { panic!() };
+        let [simple, computed, checked] = &class.fields.props[..] else { panic!() };
+        assert_eq!(simple.ty.text, "Option<Vec<u8>>");
+        assert_eq!(simple.default.as_ref().unwrap().text, "Some(vec![1, 2])");
+        assert!(matches!(simple.set, Some(RigAccessor::Auto)));
+        assert!(!computed.has_storage() && computed.set.is_none());
+        assert!(checked.has_storage());
+        assert!(
+            matches!(&checked.set, Some(RigAccessor::Custom(c)) if c.text.ends_with("value) }"))
+        );
+        assert_eq!(
+            class.fields.init.as_ref().unwrap().text,
+            "{ let _ = ret.get_simple(); }"
+        );
+    }
+
+    #[test]
+    fn test_parse_template() {
+        let source = "\
+template[R] Regs {
+    in: { src: R, any: GPR(64, [SP, ZR]) },
+    out: { dst: R, },
+    ;
+    impl {
+        [GPR64] => Regs64 { insts: [ A ] },
+        [FPR(32, [])] => RegsF32 { insts: [ B ], props: { p: u8 = 0; } }
+    }
+}
+";
+        let module = parse_rig(source).unwrap();
+        let RigItem::Template(tmpl) = &module.items[0] else { panic!() };
+        assert_eq!(
+            tmpl.fields.ins[0].operand.kind,
+            RigOperandKind::Param("R".into())
+        );
+        let any = &tmpl.fields.ins[1].operand.kind;
+        assert_eq!(any.to_string(), "GPR(64, [SP, ZR])");
+        assert_eq!(tmpl.impls.len(), 2);
+        assert_eq!(
+            tmpl.impls[1].args[0].kind,
+            RigOperandKind::FPR { bits: 32, flags: vec![] }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_recover() {
+        let source = "\
+class A { in: { x: GPR65 }, insts: [ A ] }
+class B { in: { x: GPR64 } insts: [ B ] }
+class C { insts: [ C ], insts: [ D ] }
+template[T] D { in: { x: T }; impl { [T] => D1 { insts: [ D1 ] } } }
+class E { props: { p: u8 { default = 1; } } }
+class F { props: { p: u8 = (1; } }
+extern class G
+";
+        let errors = parse_errors(source);
+        let expected = [
+            (RigErrorKind::UnknownOperand("GPR65".into()), (1, 20)),
+            (
+                RigErrorKind::Unexpected { expected: "`,` or `}`".into(), found: "`insts`".into() },
+                (2, 28),
+            ),
+            (RigErrorKind::DuplicateField("insts"), (3, 25)),
+            (RigErrorKind::ParamAsArg("T".into()), (4, 39)),
+            (RigErrorKind::PropWithoutAccessor("p".into()), (5, 20)),
+            (
+                RigErrorKind::Unexpected { expected: "`)`".into(), found: "`}`".into() },
+                (6, 32),
+            ),
+            (
+                RigErrorKind::Unexpected { expected: "`;`".into(), found: "end of input".into() },
+                (7, 15),
+            ),
+        ];
+        assert_eq!(errors, expected);
+    }
+
+    #[test]
+    fn test_lex_errors() {
+        assert_eq!(
+            parse_errors("class A { } /* open"),
+            [(RigErrorKind::UnterminatedComment, (1, 13))]
+        );
+        assert_eq!(
+            parse_errors("class A { } 类"),
+            [(RigErrorKind::UnexpectedChar('类'), (1, 13))]
+        );
+    }
+}
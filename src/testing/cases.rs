@@ -4,6 +4,58 @@ use crate::{
     typing::*,
 };
 
+/// 新建一个只有入口块的函数, 并把 `builder` 的焦点移到入口块.
+pub fn new_func(builder: &mut IRBuilder, name: &str, ret: ValTypeID, args: &[ValTypeID]) -> FuncID {
+    let fty = FuncTypeID::new(builder.tctx(), ret, false, args.iter().copied());
+    let func = FuncID::builder(builder.tctx(), name, fty)
+        .make_defined()
+        .build_id(&builder.module)
+        .unwrap();
+    let entry = func.get_entry(builder.allocs()).unwrap();
+    builder.set_focus(IRFocus::Block(entry));
+    func
+}
+
+/// 在焦点处插入指令, 返回它的值.
+pub fn insert(builder: &mut IRBuilder, inst: impl ISubInstID) -> ValueSSA {
+    builder.insert_inst(inst).unwrap();
+    ValueSSA::Inst(inst.raw_into())
+}
+
+pub fn binop(builder: &mut IRBuilder, op: Opcode, lhs: ValueSSA, rhs: ValueSSA) -> ValueSSA {
+    insert(builder, BinOPInstID::new(builder.allocs(), op, lhs, rhs))
+}
+
+pub fn cast(builder: &mut IRBuilder, op: Opcode, from: ValueSSA, ty: ValTypeID) -> ValueSSA {
+    insert(builder, CastInstID::new(builder.allocs(), op, from, ty))
+}
+
+pub fn icmp(builder: &mut IRBuilder, cond: CmpCond, lhs: ValueSSA, rhs: ValueSSA) -> ValueSSA {
+    let ty = lhs.get_valtype(builder.allocs());
+    let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, cond, ty);
+    cmp.set_lhs(builder.allocs(), lhs);
+    cmp.set_rhs(builder.allocs(), rhs);
+    insert(builder, cmp)
+}
+
+pub fn select(builder: &mut IRBuilder, cond: ValueSSA, then: ValueSSA, els: ValueSSA) -> ValueSSA {
+    insert(
+        builder,
+        SelectInstID::new(builder.allocs(), cond, then, els),
+    )
+}
+
+/// 把焦点块的终止指令换成 `ret val`.
+pub fn ret(builder: &mut IRBuilder, val: ValueSSA) {
+    let ret = RetInstID::with_retval(builder.allocs(), val);
+    builder.focus_set_terminator(ret).unwrap();
+}
+
+/// `bits` 位的整数常量, 超出位宽的部分被截掉.
+pub fn iconst(value: i128, bits: u8) -> ValueSSA {
+    APInt::new(value as u128, bits).into()
+}
+
 /// Test case 1: CFG example with a lot of branches.
 ///
 /// ```SysY
@@ -557,3 +609,992 @@ pub fn test_case_minmax() -> IRBuilder {
         .unwrap();
     builder
 }
+
+/// Test case "ArraySum": 一个计数循环, 循环变量同时用作数组下标.
+///
+/// ## C Source Code
+///
+/// ```c
+/// int main() {
+///     int a[100];
+///     int s = 0;
+///     int i = 0;
+///     while (i < 100) {
+///         a[i] = i;
+///         s = s + a[i];
+///         i = i + 1;
+///     }
+///     return s;
+/// }
+/// ```
+///
+/// ## Corresponding remusys-ir
+///
+/// ```remusys-ir
+/// define dso_local i32 @main() {
+/// 0:
+///     %1 = alloca [100 x i32], align 4    ; a
+///     %2 = alloca i32, align 4            ; s
+///     %3 = alloca i32, align 4            ; i
+///     store i32 0, ptr %2, align 4
+///     store i32 0, ptr %3, align 4
+///     br label %4
+///
+/// 4: ; while (i < 100)
+///     %5 = load i32, ptr %3, align 4
+///     %6 = icmp slt i32 %5, 100
+///     br i1 %6, label %7, label %17
+///
+/// 7:
+///     %8 = load i32, ptr %3, align 4
+///     %9 = sext i32 %8 to i64
+///     %10 = getelementptr inbounds [100 x i32], ptr %1, i64 0, i64 %9
+///     store i32 %8, ptr %10, align 4
+///     %11 = load i32, ptr %2, align 4
+///     %12 = load i32, ptr %10, align 4
+///     %13 = add nsw i32 %11, %12
+///     store i32 %13, ptr %2, align 4
+///     %14 = load i32, ptr %3, align 4
+///     %15 = add nsw i32 %14, 1
+///     store i32 %15, ptr %3, align 4
+///     br label %4
+///
+/// 17:
+///     %18 = load i32, ptr %2, align 4
+///     ret i32 %18
+/// }
+/// ```
+#[allow(unused)]
+pub fn test_case_array_sum() -> IRBuilder {
+    let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "test_case_array_sum");
+    let i32ty = ValTypeID::Int(32);
+    let ri32fty = FuncTypeID::new(builder.tctx(), i32ty, false, []);
+    let arrty = ArrayTypeID::new(builder.tctx(), i32ty, 100);
+    let main_func = FuncID::builder(builder.tctx(), "main", ri32fty)
+        .make_defined()
+        .terminate_mode(FuncTerminateMode::ReturnDefault)
+        .add_attr(Attribute::NoUndef)
+        .build_id(&builder.module)
+        .unwrap();
+
+    let entry = main_func.get_entry(builder.allocs()).unwrap();
+    builder.set_focus(IRFocus::Block(entry));
+    let load_from = |builder: &mut IRBuilder, ptr: ValueSSA| {
+        let load = LoadInstID::new_uninit(builder.allocs(), i32ty, 2);
+        load.set_source(builder.allocs(), ptr);
+        builder.insert_inst(load).unwrap();
+        ValueSSA::Inst(load.raw_into())
+    };
+    let store_to = |builder: &mut IRBuilder, val: ValueSSA, ptr: ValueSSA| {
+        let store = StoreInstID::new(builder.allocs(), val, ptr, 2);
+        builder.insert_inst(store).unwrap();
+    };
+
+    // %1 = alloca [100 x i32]; %2 = alloca i32 (s); %3 = alloca i32 (i)
+    let alloca_a = AllocaInstID::new(builder.allocs(), ValTypeID::Array(arrty), 2);
+    builder.insert_inst(alloca_a).unwrap();
+    let alloca_s = AllocaInstID::new(builder.allocs(), i32ty, 2);
+    builder.insert_inst(alloca_s).unwrap();
+    let alloca_i = AllocaInstID::new(builder.allocs(), i32ty, 2);
+    builder.insert_inst(alloca_i).unwrap();
+    let (ptr_a, ptr_s, ptr_i) = (
+        ValueSSA::Inst(alloca_a.raw_into()),
+        ValueSSA::Inst(alloca_s.raw_into()),
+        ValueSSA::Inst(alloca_i.raw_into()),
+    );
+    store_to(&mut builder, APInt::new(0u32, 32).into(), ptr_s);
+    store_to(&mut builder, APInt::new(0u32, 32).into(), ptr_i);
+
+    // entry -> header(%4) -> exit(%17)
+    let exit = builder.split_block().unwrap();
+    let header = builder.split_block().unwrap();
+
+    // Exit block: return s
+    builder.set_focus(IRFocus::Block(exit));
+    let ret_val = load_from(&mut builder, ptr_s);
+    builder
+        .focus_set_terminator(RetInstID::with_retval(builder.allocs(), ret_val))
+        .unwrap();
+
+    // Header: loop skeleton 4 -> 7 -> 4
+    builder.set_focus(IRFocus::Block(header));
+    builder.focus_set_jump_to(header).unwrap();
+    let body = builder.split_block().unwrap();
+    let load_i_5 = load_from(&mut builder, ptr_i);
+    let icmp_6 = {
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, CmpCond::SLT, i32ty);
+        cmp.set_lhs(builder.allocs(), load_i_5);
+        cmp.set_rhs(builder.allocs(), APInt::new(100u32, 32).into());
+        builder.insert_inst(cmp).unwrap();
+        cmp
+    };
+    builder
+        .focus_set_branch_to(ValueSSA::Inst(icmp_6.raw_into()), body, exit)
+        .unwrap();
+
+    // Body: a[i] = i; s = s + a[i]; i = i + 1
+    builder.set_focus(IRFocus::Block(body));
+    let load_i_8 = load_from(&mut builder, ptr_i);
+    let sext_9 = CastInstID::new(builder.allocs(), Opcode::Sext, load_i_8, ValTypeID::Int(64));
+    builder.insert_inst(sext_9).unwrap();
+    let gep_10 = GEPInstID::builder(builder.tctx(), builder.allocs(), ValTypeID::Array(arrty))
+        .base_ptr(ptr_a)
+        .add_indices(&[APInt::new(0u64, 64).into(), ValueSSA::Inst(sext_9.raw_into())])
+        .inbounds(true)
+        .build_id();
+    builder.insert_inst(gep_10).unwrap();
+    let ptr_elem = ValueSSA::Inst(gep_10.raw_into());
+    store_to(&mut builder, load_i_8, ptr_elem);
+    let load_s_11 = load_from(&mut builder, ptr_s);
+    let load_a_12 = load_from(&mut builder, ptr_elem);
+    let add_13 = BinOPInstID::new(builder.allocs(), Opcode::Add, load_s_11, load_a_12);
+    add_13.add_flags(builder.allocs(), BinOPFlags::NSW);
+    builder.insert_inst(add_13).unwrap();
+    store_to(&mut builder, ValueSSA::Inst(add_13.raw_into()), ptr_s);
+    let load_i_14 = load_from(&mut builder, ptr_i);
+    let add_15 = BinOPInstID::new(
+        builder.allocs(),
+        Opcode::Add,
+        load_i_14,
+        APInt::new(1u32, 32).into(),
+    );
+    add_15.add_flags(builder.allocs(), BinOPFlags::NSW);
+    builder.insert_inst(add_15).unwrap();
+    store_to(&mut builder, ValueSSA::Inst(add_15.raw_into()), ptr_i);
+
+    builder
+}
+
+/// Test case "MatrixFill": 两层计数循环, 内层循环用 `i * 4 + j` 作为一维数组下标.
+///
+/// ## C Source Code
+///
+/// ```c
+/// int main() {
+///     int a[40];
+///     int i = 0;
+///     while (i < 10) {
+///         int j = 0;
+///         while (j < 4) {
+///             a[i * 4 + j] = i + j;
+///             j = j + 1;
+///         }
+///         i = i + 1;
+///     }
+///     return a[39];
+/// }
+/// ```
+///
+/// ## Corresponding remusys-ir
+///
+/// ```remusys-ir
+/// define dso_local i32 @main() {
+/// 0:
+///     %1 = alloca [40 x i32], align 4     ; a
+///     %2 = alloca i32, align 4            ; i
+///     %3 = alloca i32, align 4            ; j
+///     store i32 0, ptr %2, align 4
+///     br label %4
+///
+/// 4: ; while (i < 10)
+///     %5 = load i32, ptr %2, align 4
+///     %6 = icmp slt i32 %5, 10
+///     br i1 %6, label %7, label %26
+///
+/// 7:
+///     store i32 0, ptr %3, align 4
+///     br label %8
+///
+/// 8: ; while (j < 4)
+///     %9 = load i32, ptr %3, align 4
+///     %10 = icmp slt i32 %9, 4
+///     br i1 %10, label %11, label %23
+///
+/// 11:
+///     %12 = load i32, ptr %2, align 4
+///     %13 = mul nsw i32 %12, 4
+///     %14 = load i32, ptr %3, align 4
+///     %15 = add nsw i32 %13, %14
+///     %16 = sext i32 %15 to i64
+///     %17 = getelementptr inbounds [40 x i32], ptr %1, i64 0, i64 %16
+///     %18 = add nsw i32 %12, %14
+///     store i32 %18, ptr %17, align 4
+///     %19 = add nsw i32 %14, 1
+///     store i32 %19, ptr %3, align 4
+///     br label %8
+///
+/// 23:
+///     %24 = load i32, ptr %2, align 4
+///     %25 = add nsw i32 %24, 1
+///     store i32 %25, ptr %2, align 4
+///     br label %4
+///
+/// 26:
+///     %27 = getelementptr inbounds [40 x i32], ptr %1, i64 0, i64 39
+///     %28 = load i32, ptr %27, align 4
+///     ret i32 %28
+/// }
+/// ```
+#[allow(unused)]
+pub fn test_case_matrix_fill() -> IRBuilder {
+    let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "test_case_matrix_fill");
+    let i32ty = ValTypeID::Int(32);
+    let ri32fty = FuncTypeID::new(builder.tctx(), i32ty, false, []);
+    let arrty = ArrayTypeID::new(builder.tctx(), i32ty, 40);
+    let main_func = FuncID::builder(builder.tctx(), "main", ri32fty)
+        .make_defined()
+        .terminate_mode(FuncTerminateMode::ReturnDefault)
+        .add_attr(Attribute::NoUndef)
+        .build_id(&builder.module)
+        .unwrap();
+
+    let entry = main_func.get_entry(builder.allocs()).unwrap();
+    builder.set_focus(IRFocus::Block(entry));
+    let load_from = |builder: &mut IRBuilder, ptr: ValueSSA| {
+        let load = LoadInstID::new_uninit(builder.allocs(), i32ty, 2);
+        load.set_source(builder.allocs(), ptr);
+        builder.insert_inst(load).unwrap();
+        ValueSSA::Inst(load.raw_into())
+    };
+    let store_to = |builder: &mut IRBuilder, val: ValueSSA, ptr: ValueSSA| {
+        let store = StoreInstID::new(builder.allocs(), val, ptr, 2);
+        builder.insert_inst(store).unwrap();
+    };
+    let binop_nsw = |builder: &mut IRBuilder, opcode: Opcode, lhs: ValueSSA, rhs: ValueSSA| {
+        let binop = BinOPInstID::new(builder.allocs(), opcode, lhs, rhs);
+        binop.add_flags(builder.allocs(), BinOPFlags::NSW);
+        builder.insert_inst(binop).unwrap();
+        ValueSSA::Inst(binop.raw_into())
+    };
+    let icmp_slt = |builder: &mut IRBuilder, lhs: ValueSSA, bound: u32| {
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, CmpCond::SLT, i32ty);
+        cmp.set_lhs(builder.allocs(), lhs);
+        cmp.set_rhs(builder.allocs(), APInt::new(bound, 32).into());
+        builder.insert_inst(cmp).unwrap();
+        ValueSSA::Inst(cmp.raw_into())
+    };
+    let elem_ptr = |builder: &mut IRBuilder, base: ValueSSA, index: ValueSSA| {
+        let gep = GEPInstID::builder(builder.tctx(), builder.allocs(), ValTypeID::Array(arrty))
+            .base_ptr(base)
+            .add_indices(&[APInt::new(0u64, 64).into(), index])
+            .inbounds(true)
+            .build_id();
+        builder.insert_inst(gep).unwrap();
+        ValueSSA::Inst(gep.raw_into())
+    };
+
+    // %1 = alloca [40 x i32]; %2 = alloca i32 (i); %3 = alloca i32 (j)
+    let alloca_a = AllocaInstID::new(builder.allocs(), ValTypeID::Array(arrty), 2);
+    builder.insert_inst(alloca_a).unwrap();
+    let alloca_i = AllocaInstID::new(builder.allocs(), i32ty, 2);
+    builder.insert_inst(alloca_i).unwrap();
+    let alloca_j = AllocaInstID::new(builder.allocs(), i32ty, 2);
+    builder.insert_inst(alloca_j).unwrap();
+    let (ptr_a, ptr_i, ptr_j) = (
+        ValueSSA::Inst(alloca_a.raw_into()),
+        ValueSSA::Inst(alloca_i.raw_into()),
+        ValueSSA::Inst(alloca_j.raw_into()),
+    );
+    store_to(&mut builder, APInt::new(0u32, 32).into(), ptr_i);
+
+    // entry -> outer header(%4) -> exit(%26)
+    let exit = builder.split_block().unwrap();
+    let outer_header = builder.split_block().unwrap();
+
+    // Exit block: return a[39]
+    builder.set_focus(IRFocus::Block(exit));
+    let ptr_last = elem_ptr(&mut builder, ptr_a, APInt::new(39u64, 64).into());
+    let ret_val = load_from(&mut builder, ptr_last);
+    builder
+        .focus_set_terminator(RetInstID::with_retval(builder.allocs(), ret_val))
+        .unwrap();
+
+    // Outer header: loop skeleton 4 -> 7 -> 8 -> 23 -> 4
+    builder.set_focus(IRFocus::Block(outer_header));
+    builder.focus_set_jump_to(outer_header).unwrap();
+    let outer_body = builder.split_block().unwrap();
+    let load_i_5 = load_from(&mut builder, ptr_i);
+    let icmp_6 = icmp_slt(&mut builder, load_i_5, 10);
+    builder
+        .focus_set_branch_to(icmp_6, outer_body, exit)
+        .unwrap();
+
+    builder.set_focus(IRFocus::Block(outer_body));
+    store_to(&mut builder, APInt::new(0u32, 32).into(), ptr_j);
+    let inner_header = builder.split_block().unwrap();
+    builder.set_focus(IRFocus::Block(inner_header));
+    let outer_latch = builder.split_block().unwrap();
+
+    // Outer latch: i = i + 1
+    builder.set_focus(IRFocus::Block(outer_latch));
+    let load_i_24 = load_from(&mut builder, ptr_i);
+    let add_25 = binop_nsw(
+        &mut builder,
+        Opcode::Add,
+        load_i_24,
+        APInt::new(1u32, 32).into(),
+    );
+    store_to(&mut builder, add_25, ptr_i);
+
+    // Inner header: loop skeleton 8 -> 11 -> 8
+    builder.set_focus(IRFocus::Block(inner_header));
+    builder.focus_set_jump_to(inner_header).unwrap();
+    let inner_body = builder.split_block().unwrap();
+    let load_j_9 = load_from(&mut builder, ptr_j);
+    let icmp_10 = icmp_slt(&mut builder, load_j_9, 4);
+    builder
+        .focus_set_branch_to(icmp_10, inner_body, outer_latch)
+        .unwrap();
+
+    // Inner body: a[i * 4 + j] = i + j; j = j + 1
+    builder.set_focus(IRFocus::Block(inner_body));
+    let load_i_12 = load_from(&mut builder, ptr_i);
+    let mul_13 = binop_nsw(
+        &mut builder,
+        Opcode::Mul,
+        load_i_12,
+        APInt::new(4u32, 32).into(),
+    );
+    let load_j_14 = load_from(&mut builder, ptr_j);
+    let add_15 = binop_nsw(&mut builder, Opcode::Add, mul_13, load_j_14);
+    let sext_16 = CastInstID::new(builder.allocs(), Opcode::Sext, add_15, ValTypeID::Int(64));
+    builder.insert_inst(sext_16).unwrap();
+    let gep_17 = elem_ptr(&mut builder, ptr_a, ValueSSA::Inst(sext_16.raw_into()));
+    let add_18 = binop_nsw(&mut builder, Opcode::Add, load_i_12, load_j_14);
+    store_to(&mut builder, add_18, gep_17);
+    let add_19 = binop_nsw(
+        &mut builder,
+        Opcode::Add,
+        load_j_14,
+        APInt::new(1u32, 32).into(),
+    );
+    store_to(&mut builder, add_19, ptr_j);
+
+    builder
+}
+
+/// Test case "LoopSelect": 循环体中有一个条件只取决于函数参数的分支.
+///
+/// ## C Source Code
+///
+/// ```c
+/// int main(int flag) {
+///     int s = 0;
+///     int i = 0;
+///     while (i < 10) {
+///         if (flag != 0)
+///             s = s + i;
+///         else
+///             s = s - i;
+///         i = i + 1;
+///     }
+///     return s;
+/// }
+/// ```
+///
+/// ## Corresponding remusys-ir
+///
+/// ```remusys-ir
+/// define dso_local i32 @main(i32 %0) {
+/// 1:
+///     %2 = alloca i32, align 4            ; s
+///     %3 = alloca i32, align 4            ; i
+///     store i32 0, ptr %2, align 4
+///     store i32 0, ptr %3, align 4
+///     br label %4
+///
+/// 4: ; while (i < 10)
+///     %5 = load i32, ptr %3, align 4
+///     %6 = icmp slt i32 %5, 10
+///     br i1 %6, label %7, label %22
+///
+/// 7: ; if (flag != 0)
+///     %8 = icmp ne i32 %0, 0
+///     br i1 %8, label %9, label %13
+///
+/// 9:
+///     %10 = load i32, ptr %2, align 4
+///     %11 = load i32, ptr %3, align 4
+///     %12 = add nsw i32 %10, %11
+///     store i32 %12, ptr %2, align 4
+///     br label %17
+///
+/// 13:
+///     %14 = load i32, ptr %2, align 4
+///     %15 = load i32, ptr %3, align 4
+///     %16 = sub nsw i32 %14, %15
+///     store i32 %16, ptr %2, align 4
+///     br label %17
+///
+/// 17:
+///     %18 = load i32, ptr %3, align 4
+///     %19 = add nsw i32 %18, 1
+///     store i32 %19, ptr %3, align 4
+///     br label %4
+///
+/// 22:
+///     %23 = load i32, ptr %2, align 4
+///     ret i32 %23
+/// }
+/// ```
+#[allow(unused)]
+pub fn test_case_loop_select() -> IRBuilder {
+    let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "test_case_loop_select");
+    let i32ty = ValTypeID::Int(32);
+    let fty = FuncTypeID::new(builder.tctx(), i32ty, false, [i32ty]);
+    let main_func = FuncID::builder(builder.tctx(), "main", fty)
+        .make_defined()
+        .terminate_mode(FuncTerminateMode::ReturnDefault)
+        .add_attr(Attribute::NoUndef)
+        .build_id(&builder.module)
+        .unwrap();
+    let flag = ValueSSA::FuncArg(main_func, 0);
+
+    let entry = main_func.get_entry(builder.allocs()).unwrap();
+    builder.set_focus(IRFocus::Block(entry));
+    let load_from = |builder: &mut IRBuilder, ptr: ValueSSA| {
+        let load = LoadInstID::new_uninit(builder.allocs(), i32ty, 2);
+        load.set_source(builder.allocs(), ptr);
+        builder.insert_inst(load).unwrap();
+        ValueSSA::Inst(load.raw_into())
+    };
+    let store_to = |builder: &mut IRBuilder, val: ValueSSA, ptr: ValueSSA| {
+        let store = StoreInstID::new(builder.allocs(), val, ptr, 2);
+        builder.insert_inst(store).unwrap();
+    };
+    let binop_nsw = |builder: &mut IRBuilder, opcode: Opcode, lhs: ValueSSA, rhs: ValueSSA| {
+        let binop = BinOPInstID::new(builder.allocs(), opcode, lhs, rhs);
+        binop.add_flags(builder.allocs(), BinOPFlags::NSW);
+        builder.insert_inst(binop).unwrap();
+        ValueSSA::Inst(binop.raw_into())
+    };
+    let icmp = |builder: &mut IRBuilder, cond: CmpCond, lhs: ValueSSA, rhs: u32| {
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, cond, i32ty);
+        cmp.set_lhs(builder.allocs(), lhs);
+        cmp.set_rhs(builder.allocs(), APInt::new(rhs, 32).into());
+        builder.insert_inst(cmp).unwrap();
+        ValueSSA::Inst(cmp.raw_into())
+    };
+
+    // %2 = alloca i32 (s); %3 = alloca i32 (i)
+    let alloca_s = AllocaInstID::new(builder.allocs(), i32ty, 2);
+    builder.insert_inst(alloca_s).unwrap();
+    let alloca_i = AllocaInstID::new(builder.allocs(), i32ty, 2);
+    builder.insert_inst(alloca_i).unwrap();
+    let (ptr_s, ptr_i) = (
+        ValueSSA::Inst(alloca_s.raw_into()),
+        ValueSSA::Inst(alloca_i.raw_into()),
+    );
+    store_to(&mut builder, APInt::new(0u32, 32).into(), ptr_s);
+    store_to(&mut builder, APInt::new(0u32, 32).into(), ptr_i);
+
+    // entry -> header(%4) -> exit(%22)
+    let exit = builder.split_block().unwrap();
+    let header = builder.split_block().unwrap();
+
+    // Exit block: return s
+    builder.set_focus(IRFocus::Block(exit));
+    let ret_val = load_from(&mut builder, ptr_s);
+    builder
+        .focus_set_terminator(RetInstID::with_retval(builder.allocs(), ret_val))
+        .unwrap();
+
+    // Header: loop skeleton 4 -> 7 -> {9, 13} -> 17 -> 4
+    builder.set_focus(IRFocus::Block(header));
+    builder.focus_set_jump_to(header).unwrap();
+    let body = builder.split_block().unwrap();
+    let load_i_5 = load_from(&mut builder, ptr_i);
+    let icmp_6 = icmp(&mut builder, CmpCond::SLT, load_i_5, 10);
+    builder.focus_set_branch_to(icmp_6, body, exit).unwrap();
+
+    builder.set_focus(IRFocus::Block(body));
+    let latch = builder.split_block().unwrap();
+    let then_bb = builder.split_block().unwrap();
+    builder.set_focus(IRFocus::Block(then_bb));
+    let else_bb = builder.split_block().unwrap();
+    builder.focus_set_jump_to(latch).unwrap();
+
+    // Body: if (flag != 0)
+    builder.set_focus(IRFocus::Block(body));
+    let icmp_8 = icmp(&mut builder, CmpCond::NE, flag, 0);
+    builder
+        .focus_set_branch_to(icmp_8, then_bb, else_bb)
+        .unwrap();
+
+    // Then: s = s + i
+    builder.set_focus(IRFocus::Block(then_bb));
+    let load_s_10 = load_from(&mut builder, ptr_s);
+    let load_i_11 = load_from(&mut builder, ptr_i);
+    let add_12 = binop_nsw(&mut builder, Opcode::Add, load_s_10, load_i_11);
+    store_to(&mut builder, add_12, ptr_s);
+
+    // Else: s = s - i
+    builder.set_focus(IRFocus::Block(else_bb));
+    let load_s_14 = load_from(&mut builder, ptr_s);
+    let load_i_15 = load_from(&mut builder, ptr_i);
+    let sub_16 = binop_nsw(&mut builder, Opcode::Sub, load_s_14, load_i_15);
+    store_to(&mut builder, sub_16, ptr_s);
+
+    // Latch: i = i + 1
+    builder.set_focus(IRFocus::Block(latch));
+    let load_i_18 = load_from(&mut builder, ptr_i);
+    let add_19 = binop_nsw(
+        &mut builder,
+        Opcode::Add,
+        load_i_18,
+        APInt::new(1u32, 32).into(),
+    );
+    store_to(&mut builder, add_19, ptr_i);
+
+    builder
+}
+
+/// Test case: 对相邻数组元素做相同运算的直线代码, 用于 SLP 向量化.
+///
+/// ```C
+/// int a[4], b[4], c[4];
+///
+/// int main(int x) {
+///     int s0 = a[0] + b[0];
+///     c[0] = s0 * x;
+///     c[1] = (a[1] + b[1]) * x;
+///     c[2] = (a[2] + b[2]) * x;
+///     c[3] = (a[3] + b[3]) * x;
+///     return s0;
+/// }
+/// ```
+///
+/// ```llvm
+/// @a = dso_local global [4 x i32] zeroinitializer, align 16
+/// @b = dso_local global [4 x i32] zeroinitializer, align 16
+/// @c = dso_local global [4 x i32] zeroinitializer, align 16
+///
+/// define dso_local i32 @main(i32 %0) {
+/// 1:
+///     %2 = getelementptr inbounds [4 x i32], ptr @a, i64 0, i64 0
+///     %3 = load i32, ptr %2, align 4
+///     %4 = getelementptr inbounds [4 x i32], ptr @b, i64 0, i64 0
+///     %5 = load i32, ptr %4, align 4
+///     %6 = add nsw i32 %3, %5
+///     %7 = mul nsw i32 %6, %0
+///     %8 = getelementptr inbounds [4 x i32], ptr @c, i64 0, i64 0
+///     store i32 %7, ptr %8, align 4
+///     ; ... 下标 1, 2, 3 同理
+///     ret i32 %6
+/// }
+/// ```
+#[allow(unused)]
+pub fn test_case_vec_add() -> IRBuilder {
+    let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "test_case_vec_add");
+    let i32ty = ValTypeID::Int(32);
+    let fty = FuncTypeID::new(builder.tctx(), i32ty, false, [i32ty]);
+    let arrty = ArrayTypeID::new(builder.tctx(), i32ty, 4);
+    let mut global_array = |builder: &mut IRBuilder, name: &str| {
+        let zero = ValueSSA::AggrZero(AggrType::Array(arrty));
+        let gvar = builder
+            .build_global_var(name, ValTypeID::Array(arrty), |b| {
+                b.initval(zero).align_log(4);
+            })
+            .unwrap();
+        ValueSSA::Global(gvar.raw_into())
+    };
+    let arr_a = global_array(&mut builder, "a");
+    let arr_b = global_array(&mut builder, "b");
+    let arr_c = global_array(&mut builder, "c");
+    let main_func = FuncID::builder(builder.tctx(), "main", fty)
+        .make_defined()
+        .terminate_mode(FuncTerminateMode::ReturnDefault)
+        .add_attr(Attribute::NoUndef)
+        .build_id(&builder.module)
+        .unwrap();
+    let x = ValueSSA::FuncArg(main_func, 0);
+
+    let entry = main_func.get_entry(builder.allocs()).unwrap();
+    builder.set_focus(IRFocus::Block(entry));
+    let elem_ptr = |builder: &mut IRBuilder, base: ValueSSA, index: u64| {
+        let gep = GEPInstID::builder(builder.tctx(), builder.allocs(), ValTypeID::Array(arrty))
+            .base_ptr(base)
+            .add_indices(&[APInt::new(0u64, 64).into(), APInt::new(index, 64).into()])
+            .inbounds(true)
+            .build_id();
+        builder.insert_inst(gep).unwrap();
+        ValueSSA::Inst(gep.raw_into())
+    };
+    let load_from = |builder: &mut IRBuilder, ptr: ValueSSA| {
+        let load = LoadInstID::new_uninit(builder.allocs(), i32ty, 2);
+        load.set_source(builder.allocs(), ptr);
+        builder.insert_inst(load).unwrap();
+        ValueSSA::Inst(load.raw_into())
+    };
+    let binop_nsw = |builder: &mut IRBuilder, opcode: Opcode, lhs: ValueSSA, rhs: ValueSSA| {
+        let binop = BinOPInstID::new(builder.allocs(), opcode, lhs, rhs);
+        binop.add_flags(builder.allocs(), BinOPFlags::NSW);
+        builder.insert_inst(binop).unwrap();
+        ValueSSA::Inst(binop.raw_into())
+    };
+
+    let mut sums = Vec::with_capacity(4);
+    for index in 0..4 {
+        let ptr_a = elem_ptr(&mut builder, arr_a, index);
+        let load_a = load_from(&mut builder, ptr_a);
+        let ptr_b = elem_ptr(&mut builder, arr_b, index);
+        let load_b = load_from(&mut builder, ptr_b);
+        let sum = binop_nsw(&mut builder, Opcode::Add, load_a, load_b);
+        let prod = binop_nsw(&mut builder, Opcode::Mul, sum, x);
+        let ptr_c = elem_ptr(&mut builder, arr_c, index);
+        let store = StoreInstID::new(builder.allocs(), prod, ptr_c, 2);
+        builder.insert_inst(store).unwrap();
+        sums.push(sum);
+    }
+    builder
+        .focus_set_terminator(RetInstID::with_retval(builder.allocs(), sums[0]))
+        .unwrap();
+    builder
+}
+
+/// Test case: 分支条件在部分前驱边上已知, 用于 jump threading.
+///
+/// `main` 中 `%7` 是常量 Phi, 汇合块 `%6` 的分支条件在两条入边上都已知;
+/// `dom_cond` 中汇合块再次判断了入口块已经判断过的条件.
+///
+/// ```llvm
+/// define i32 @main(i32 %0) {
+/// 1:
+///     %2 = icmp sgt i32 %0, 0
+///     br i1 %2, label %3, label %5
+/// 3:
+///     %4 = add i32 %0, 1
+///     br label %6
+/// 5:
+///     %6 = sub i32 %0, 1
+///     br label %7
+/// 7:
+///     %8 = phi i32 [ 1, %3 ], [ 0, %5 ]
+///     %9 = phi i32 [ %4, %3 ], [ %6, %5 ]
+///     %10 = icmp ne i32 %8, 0
+///     %11 = mul i32 %9, 2
+///     br i1 %10, label %12, label %14
+/// 12:
+///     %13 = add i32 %11, 1
+///     br label %16
+/// 14:
+///     %15 = sub i32 %11, 1
+///     br label %16
+/// 16:
+///     %17 = phi i32 [ %13, %12 ], [ %15, %14 ]
+///     %18 = add i32 %17, %11
+///     ret i32 %18
+/// }
+///
+/// define i32 @dom_cond(i32 %0) {
+/// 1:
+///     %2 = icmp sgt i32 %0, 0
+///     br i1 %2, label %3, label %4
+/// 3:
+///     br label %5
+/// 4:
+///     br label %5
+/// 5:
+///     %6 = phi i32 [ 1, %3 ], [ 2, %4 ]
+///     br i1 %2, label %7, label %8
+/// 7:
+///     ret i32 %6
+/// 8:
+///     %9 = add i32 %6, 1
+///     ret i32 %9
+/// }
+/// ```
+#[allow(unused)]
+pub fn test_case_jump_thread() -> IRBuilder {
+    let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "test_case_jump_thread");
+    let i32ty = ValTypeID::Int(32);
+    let fty = FuncTypeID::new(builder.tctx(), i32ty, false, [i32ty]);
+    let new_func = |builder: &mut IRBuilder, name: &str| {
+        let func = FuncID::builder(builder.tctx(), name, fty)
+            .make_defined()
+            .terminate_mode(FuncTerminateMode::ReturnDefault)
+            .build_id(&builder.module)
+            .unwrap();
+        let entry = func.get_entry(builder.allocs()).unwrap();
+        builder.set_focus(IRFocus::Block(entry));
+        func
+    };
+    let binop = |builder: &mut IRBuilder, opcode: Opcode, lhs: ValueSSA, rhs: ValueSSA| {
+        let binop = BinOPInstID::new(builder.allocs(), opcode, lhs, rhs);
+        builder.insert_inst(binop).unwrap();
+        ValueSSA::Inst(binop.raw_into())
+    };
+    let icmp = |builder: &mut IRBuilder, cond: CmpCond, lhs: ValueSSA, rhs: u32| {
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, cond, i32ty);
+        cmp.set_lhs(builder.allocs(), lhs);
+        cmp.set_rhs(builder.allocs(), APInt::new(rhs, 32).into());
+        builder.insert_inst(cmp).unwrap();
+        ValueSSA::Inst(cmp.raw_into())
+    };
+    let phi = |builder: &mut IRBuilder, incomings: [(BlockID, ValueSSA); 2]| {
+        let phi = PhiInstID::from_incomings(builder.allocs(), i32ty, incomings);
+        builder.insert_inst(phi).unwrap();
+        ValueSSA::Inst(phi.raw_into())
+    };
+    let int = |value: u32| ValueSSA::from(APInt::new(value, 32));
+    let ret = |builder: &mut IRBuilder, value: ValueSSA| {
+        builder
+            .focus_set_terminator(RetInstID::with_retval(builder.allocs(), value))
+            .unwrap();
+    };
+
+    // main: 每次拆分都在入口块之后插入新块, 所以按逆序创建
+    let main_func = new_func(&mut builder, "main");
+    let arg = ValueSSA::FuncArg(main_func, 0);
+    let merge_end = builder.split_block().unwrap();
+    let else_bb = builder.split_block().unwrap();
+    let then_bb = builder.split_block().unwrap();
+    let merge = builder.split_block().unwrap();
+    let right = builder.split_block().unwrap();
+    let left = builder.split_block().unwrap();
+
+    let cond = icmp(&mut builder, CmpCond::SGT, arg, 0);
+    builder.focus_set_branch_to(cond, left, right).unwrap();
+    builder.set_focus(IRFocus::Block(left));
+    let add_4 = binop(&mut builder, Opcode::Add, arg, int(1));
+    builder.focus_set_jump_to(merge).unwrap();
+    builder.set_focus(IRFocus::Block(right));
+    let sub_6 = binop(&mut builder, Opcode::Sub, arg, int(1));
+
+    builder.set_focus(IRFocus::Block(merge));
+    let flag = phi(&mut builder, [(left, int(1)), (right, int(0))]);
+    let value = phi(&mut builder, [(left, add_4), (right, sub_6)]);
+    let known = icmp(&mut builder, CmpCond::NE, flag, 0);
+    let mul_11 = binop(&mut builder, Opcode::Mul, value, int(2));
+    builder
+        .focus_set_branch_to(known, then_bb, else_bb)
+        .unwrap();
+
+    builder.set_focus(IRFocus::Block(then_bb));
+    let add_13 = binop(&mut builder, Opcode::Add, mul_11, int(1));
+    builder.focus_set_jump_to(merge_end).unwrap();
+    builder.set_focus(IRFocus::Block(else_bb));
+    let sub_15 = binop(&mut builder, Opcode::Sub, mul_11, int(1));
+
+    builder.set_focus(IRFocus::Block(merge_end));
+    let merged = phi(&mut builder, [(then_bb, add_13), (else_bb, sub_15)]);
+    let sum = binop(&mut builder, Opcode::Add, merged, mul_11);
+    ret(&mut builder, sum);
+
+    // dom_cond
+    let dom_func = new_func(&mut builder, "dom_cond");
+    let arg = ValueSSA::FuncArg(dom_func, 0);
+    let else_bb = builder.split_block().unwrap();
+    let then_bb = builder.split_block().unwrap();
+    let merge = builder.split_block().unwrap();
+    let right = builder.split_block().unwrap();
+    let left = builder.split_block().unwrap();
+
+    let cond = icmp(&mut builder, CmpCond::SGT, arg, 0);
+    builder.focus_set_branch_to(cond, left, right).unwrap();
+    builder.set_focus(IRFocus::Block(left));
+    builder.focus_set_jump_to(merge).unwrap();
+
+    builder.set_focus(IRFocus::Block(merge));
+    let value = phi(&mut builder, [(left, int(1)), (right, int(2))]);
+    builder.focus_set_branch_to(cond, then_bb, else_bb).unwrap();
+
+    builder.set_focus(IRFocus::Block(then_bb));
+    ret(&mut builder, value);
+    builder.set_focus(IRFocus::Block(else_bb));
+    let add_9 = binop(&mut builder, Opcode::Add, value, int(1));
+    ret(&mut builder, add_9);
+    builder
+}
+
+/// Test case: 部分冗余的表达式, 用于 PRE.
+///
+/// `%9` 在 `%5` 一侧已经计算过, 是部分冗余的; 循环中的 `%14` 是循环不变量,
+/// 循环由 `%10` 守卫, 只有进入循环时才会计算.
+///
+/// ```llvm
+/// define i32 @main(i32 %0, i32 %1, i32 %2) {
+/// 3:
+///     %4 = icmp sgt i32 %0, 0
+///     br i1 %4, label %5, label %7
+/// 5:
+///     %6 = add i32 %0, %1
+///     br label %8
+/// 7:
+///     br label %8
+/// 8:
+///     %9 = add i32 %0, %1
+///     %10 = icmp sgt i32 %2, 0
+///     br i1 %10, label %11, label %18
+/// 11:
+///     %12 = phi i32 [ 0, %8 ], [ %16, %11 ]
+///     %13 = phi i32 [ 0, %8 ], [ %15, %11 ]
+///     %14 = mul i32 %0, %1
+///     %15 = add i32 %13, %14
+///     %16 = add i32 %12, 1
+///     %17 = icmp slt i32 %16, %2
+///     br i1 %17, label %11, label %18
+/// 18:
+///     %19 = phi i32 [ 0, %8 ], [ %15, %11 ]
+///     %20 = add i32 %19, %9
+///     ret i32 %20
+/// }
+/// ```
+#[allow(unused)]
+pub fn test_case_pre() -> IRBuilder {
+    let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "test_case_pre");
+    let i32ty = ValTypeID::Int(32);
+    let fty = FuncTypeID::new(builder.tctx(), i32ty, false, [i32ty; 3]);
+    let func = FuncID::builder(builder.tctx(), "main", fty)
+        .make_defined()
+        .terminate_mode(FuncTerminateMode::ReturnDefault)
+        .build_id(&builder.module)
+        .unwrap();
+    let entry = func.get_entry(builder.allocs()).unwrap();
+    builder.set_focus(IRFocus::Block(entry));
+    let [a, b, n] = [0, 1, 2].map(|i| ValueSSA::FuncArg(func, i));
+    let int = |value: u32| ValueSSA::from(APInt::new(value, 32));
+    let binop = |builder: &mut IRBuilder, opcode: Opcode, lhs: ValueSSA, rhs: ValueSSA| {
+        let binop = BinOPInstID::new(builder.allocs(), opcode, lhs, rhs);
+        builder.insert_inst(binop).unwrap();
+        ValueSSA::Inst(binop.raw_into())
+    };
+    let icmp = |builder: &mut IRBuilder, cond: CmpCond, lhs: ValueSSA, rhs: ValueSSA| {
+        let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, cond, i32ty);
+        cmp.set_lhs(builder.allocs(), lhs);
+        cmp.set_rhs(builder.allocs(), rhs);
+        builder.insert_inst(cmp).unwrap();
+        ValueSSA::Inst(cmp.raw_into())
+    };
+
+    // 每次拆分都在入口块之后插入新块, 所以按逆序创建
+    let exit = builder.split_block().unwrap();
+    let body = builder.split_block().unwrap();
+    let merge = builder.split_block().unwrap();
+    let right = builder.split_block().unwrap();
+    let left = builder.split_block().unwrap();
+
+    let cond = icmp(&mut builder, CmpCond::SGT, a, int(0));
+    builder.focus_set_branch_to(cond, left, right).unwrap();
+    builder.set_focus(IRFocus::Block(left));
+    binop(&mut builder, Opcode::Add, a, b);
+    builder.focus_set_jump_to(merge).unwrap();
+
+    builder.set_focus(IRFocus::Block(merge));
+    let sum_9 = binop(&mut builder, Opcode::Add, a, b);
+    let guard = icmp(&mut builder, CmpCond::SGT, n, int(0));
+    builder.focus_set_branch_to(guard, body, exit).unwrap();
+
+    builder.set_focus(IRFocus::Block(body));
+    let phi_i = PhiInstID::from_incomings(builder.allocs(), i32ty, [(merge, int(0))]);
+    let phi_s = PhiInstID::from_incomings(builder.allocs(), i32ty, [(merge, int(0))]);
+    builder.insert_inst(phi_i).unwrap();
+    builder.insert_inst(phi_s).unwrap();
+    let mul_14 = binop(&mut builder, Opcode::Mul, a, b);
+    let s_15 = binop(
+        &mut builder,
+        Opcode::Add,
+        ValueSSA::Inst(phi_s.raw_into()),
+        mul_14,
+    );
+    let i_16 = binop(
+        &mut builder,
+        Opcode::Add,
+        ValueSSA::Inst(phi_i.raw_into()),
+        int(1),
+    );
+    let latch = icmp(&mut builder, CmpCond::SLT, i_16, n);
+    phi_i
+        .deref_ir(builder.allocs())
+        .set_incoming(builder.allocs(), body, i_16);
+    phi_s
+        .deref_ir(builder.allocs())
+        .set_incoming(builder.allocs(), body, s_15);
+    builder.focus_set_branch_to(latch, body, exit).unwrap();
+
+    builder.set_focus(IRFocus::Block(exit));
+    let incomings = [(merge, int(0)), (body, s_15)];
+    let phi_r = PhiInstID::from_incomings(builder.allocs(), i32ty, incomings);
+    builder.insert_inst(phi_r).unwrap();
+    let ret = binop(
+        &mut builder,
+        Opcode::Add,
+        ValueSSA::Inst(phi_r.raw_into()),
+        sum_9,
+    );
+    builder
+        .focus_set_terminator(RetInstID::with_retval(builder.allocs(), ret))
+        .unwrap();
+    builder
+}
+
+/// Test case: 每轮循环交换两个值, Phi 之间的复制构成环.
+///
+/// ```llvm
+/// define i32 @main(i32 %0, i32 %1, i32 %2) {
+/// 3:
+///     br label %4
+/// 4:
+///     %5 = phi i32 [ %0, %3 ], [ %6, %4 ]
+///     %6 = phi i32 [ %1, %3 ], [ %5, %4 ]
+///     %7 = phi i32 [ 0, %3 ], [ %8, %4 ]
+///     %8 = add i32 %7, 1
+///     %9 = icmp slt i32 %8, %2
+///     br i1 %9, label %4, label %10
+/// 10:
+///     %11 = sub i32 %5, %6
+///     ret i32 %11
+/// }
+/// ```
+#[allow(unused)]
+pub fn test_case_phi_swap() -> IRBuilder {
+    let mut builder = IRBuilder::new_inlined(ArchInfo::new_host(), "test_case_phi_swap");
+    let i32ty = ValTypeID::Int(32);
+    let fty = FuncTypeID::new(builder.tctx(), i32ty, false, [i32ty; 3]);
+    let func = FuncID::builder(builder.tctx(), "main", fty)
+        .make_defined()
+        .terminate_mode(FuncTerminateMode::ReturnDefault)
+        .build_id(&builder.module)
+        .unwrap();
+    let entry = func.get_entry(builder.allocs()).unwrap();
+    builder.set_focus(IRFocus::Block(entry));
+    let [a, b, n] = [0, 1, 2].map(|i| ValueSSA::FuncArg(func, i));
+    let int = |value: u32| ValueSSA::from(APInt::new(value, 32));
+
+    // 每次拆分都在入口块之后插入新块, 所以按逆序创建
+    let exit = builder.split_block().unwrap();
+    let body = builder.split_block().unwrap();
+
+    builder.set_focus(IRFocus::Block(body));
+    let phi_x = PhiInstID::from_incomings(builder.allocs(), i32ty, [(entry, a)]);
+    let phi_y = PhiInstID::from_incomings(builder.allocs(), i32ty, [(entry, b)]);
+    let phi_i = PhiInstID::from_incomings(builder.allocs(), i32ty, [(entry, int(0))]);
+    for phi in [phi_x, phi_y, phi_i] {
+        builder.insert_inst(phi).unwrap();
+    }
+    let [x, y, i] = [phi_x, phi_y, phi_i].map(|phi| ValueSSA::Inst(phi.raw_into()));
+    let next = BinOPInstID::new(builder.allocs(), Opcode::Add, i, int(1));
+    builder.insert_inst(next).unwrap();
+    let next = ValueSSA::Inst(next.raw_into());
+    let cmp = CmpInstID::new_uninit(builder.allocs(), Opcode::Icmp, CmpCond::SLT, i32ty);
+    cmp.set_lhs(builder.allocs(), next);
+    cmp.set_rhs(builder.allocs(), n);
+    builder.insert_inst(cmp).unwrap();
+    for (phi, value) in [(phi_x, y), (phi_y, x), (phi_i, next)] {
+        phi.deref_ir(builder.allocs())
+            .set_incoming(builder.allocs(), body, value);
+    }
+    let cond = ValueSSA::Inst(cmp.raw_into());
+    builder.focus_set_branch_to(cond, body, exit).unwrap();
+
+    builder.set_focus(IRFocus::Block(exit));
+    let diff = BinOPInstID::new(builder.allocs(), Opcode::Sub, x, y);
+    builder.insert_inst(diff).unwrap();
+    builder
+        .focus_set_terminator(RetInstID::with_retval(
+            builder.allocs(),
+            ValueSSA::Inst(diff.raw_into()),
+        ))
+        .unwrap();
+    builder
+}
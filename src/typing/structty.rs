@@ -160,8 +160,15 @@ impl StructTypeID {
         if index == 0 {
             return Some(0);
         }
-        let offsets = self.get_offsets(tctx);
-        offsets.get(index - 1).copied()
+        // `offsets[i]` 是第 i 个字段的结束位置, 下一个字段还要按自己的对齐要求向上取整.
+        let allocs = tctx.allocs.borrow();
+        let obj = self.deref(&allocs.structs);
+        let end = *obj.offsets.get(index - 1)?;
+        if obj.packed {
+            return Some(end);
+        }
+        let align = obj.fields.get(index)?.try_get_align_full(&allocs, tctx)?;
+        Some(end.next_multiple_of(align))
     }
     pub fn get_offset(self, tctx: &TypeContext, index: usize) -> usize {
         self.try_get_offset(tctx, index)
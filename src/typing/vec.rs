@@ -24,9 +24,9 @@ impl IValType for FixVecType {
     }
 
     fn format_ir<T: Write>(self, f: &TypeFormatter<T>) -> std::fmt::Result {
-        f.write_str("<")?;
+        write!(f, "<{} x ", 1 << self.1)?;
         self.0.format_ir(f)?;
-        write!(f, "x {}>", 1 << self.1)
+        f.write_str(">")
     }
 
     fn try_get_size_full(self, alloc: &TypeAllocs, tctx: &TypeContext) -> Option<usize> {